@@ -16,8 +16,8 @@ use ash::{self, vk};
 use once_cell::sync::Lazy;
 use std::sync::Arc;
 use vulkan_layer::{
-    declare_introspection_queries, Global, Layer, LayerManifest, StubDeviceInfo, StubGlobalHooks,
-    StubInstanceInfo,
+    declare_introspection_queries, DeviceLoaderDataCallback, Global, Layer, LayerManifest,
+    LayerSettings, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
 };
 
 #[derive(Default)]
@@ -29,6 +29,9 @@ impl Layer for MyLayer {
     type DeviceInfo = StubDeviceInfo;
     type InstanceInfoContainer = StubInstanceInfo;
     type DeviceInfoContainer = StubDeviceInfo;
+    type PhysicalDeviceInfo = ();
+    type QueueInfo = ();
+    type CommandBufferInfo = ();
 
     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -54,6 +57,7 @@ impl Layer for MyLayer {
         _: Option<&vk::AllocationCallbacks>,
         _: Arc<ash::Instance>,
         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+        _: &LayerSettings,
     ) -> Self::InstanceInfoContainer {
         Default::default()
     }
@@ -65,6 +69,7 @@ impl Layer for MyLayer {
         _: Option<&vk::AllocationCallbacks>,
         _: Arc<ash::Device>,
         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        _: DeviceLoaderDataCallback,
     ) -> Self::DeviceInfoContainer {
         println!("Hello from the Rust Vulkan layer!");
         Default::default()
@@ -27,7 +27,7 @@ use std::{
 };
 use tempfile::TempDir;
 
-use bindgen::EnumVariation;
+use bindgen::{Abi, EnumVariation};
 use clap::Parser;
 
 type Task = Box<dyn FnOnce() + Send + 'static>;
@@ -153,12 +153,20 @@ pub use windows::*;
                 .allowlist_type("VkNegotiateLayerInterface")
                 .allowlist_type("VkLayerDeviceCreateInfo")
                 .allowlist_type("PFN_GetPhysicalDeviceProcAddr")
+                // The function pointer is declared with VKAPI_PTR, which matches the "system" ABI
+                // on every platform, while bindgen only sees the expansion on the host platform.
+                .override_abi(Abi::System, "PFN_GetPhysicalDeviceProcAddr")
                 .allowlist_type("VkLayerInstanceLink_?")
                 .allowlist_type("PFN_vkSetInstanceLoaderData")
                 .allowlist_type("PFN_vkLayerCreateDevice")
                 .allowlist_type("PFN_vkLayerDestroyDevice")
                 .allowlist_type("VkLayerDeviceLink_?")
                 .allowlist_type("PFN_vkSetDeviceLoaderData")
+                .allowlist_type("VkChainHeader")
+                .allowlist_type("VkEnumerateInstanceExtensionPropertiesChain")
+                .allowlist_type("VkEnumerateInstanceLayerPropertiesChain")
+                .allowlist_type("VkEnumerateInstanceVersionChain")
+                .allowlist_var("VK_CURRENT_CHAIN_VERSION")
                 .generate()
                 .unwrap()
                 .write(Box::new(out_file))
@@ -188,6 +196,7 @@ pub use windows::*;
             set_common_bindgen_configs(Default::default())
                 .allowlist_type("VkLayerFunction_?")
                 .allowlist_type("VkNegotiateLayerStructType")
+                .allowlist_type("VkChainType")
                 .generate()
                 .unwrap()
                 .write(Box::new(platform_specific_out_file))
@@ -379,6 +388,54 @@ fn main() -> ExitCode {
                 });
         })
     });
+    tasks.push({
+        let target = OsString::from("command_ir/generated.rs");
+        let target_relative_path = PathBuf::from(target.clone());
+        let genvk_args = GenvkArgs {
+            working_directory: project_root_dir.clone(),
+            module: vulkan_layer_genvk_module.to_string(),
+            target: target.clone(),
+            registry: vk_xml_path.clone(),
+            out_dir: vulkan_layer_src_dir.clone(),
+        };
+        let completed_files_tx = completed_files_tx.clone();
+        Box::new(move || {
+            run_vulkan_layer_genvk(&genvk_args);
+            completed_files_tx
+                .send(target_relative_path)
+                .unwrap_or_else(|e| {
+                    panic!(
+                        "Failed to send completed file for {}: {}",
+                        target.to_string_lossy().as_ref(),
+                        e
+                    )
+                });
+        })
+    });
+    tasks.push({
+        let target = OsString::from("api_dump/generated.rs");
+        let target_relative_path = PathBuf::from(target.clone());
+        let genvk_args = GenvkArgs {
+            working_directory: project_root_dir.clone(),
+            module: vulkan_layer_genvk_module.to_string(),
+            target: target.clone(),
+            registry: vk_xml_path.clone(),
+            out_dir: vulkan_layer_src_dir.clone(),
+        };
+        let completed_files_tx = completed_files_tx.clone();
+        Box::new(move || {
+            run_vulkan_layer_genvk(&genvk_args);
+            completed_files_tx
+                .send(target_relative_path)
+                .unwrap_or_else(|e| {
+                    panic!(
+                        "Failed to send completed file for {}: {}",
+                        target.to_string_lossy().as_ref(),
+                        e
+                    )
+                });
+        })
+    });
     drop(completed_files_tx);
     let threads = tasks.into_iter().map(thread::spawn).collect::<Vec<_>>();
     let exit_code = if cli.check {
@@ -23,6 +23,8 @@ import xml.etree.ElementTree as etree
 from generator import OutputGenerator, GeneratorOptions
 from .layer_trait_generator import LayerTraitGenerator
 from .global_simple_intercept_generator import GlobalSimpleInterceptGenerator
+from .api_dump_generator import ApiDumpGenerator
+from .command_ir_generator import CommandIrGenerator
 from reg import Registry
 from vkconventions import VulkanConventions
 from spec_tools.conventions import ConventionsBase
@@ -80,6 +82,40 @@ def make_gen_opts(
                 emitExtensions=emit_extension_pat,
             ),
         ),
+        Path("command_ir/generated.rs"): (
+            CommandIrGenerator,
+            GeneratorOptions(
+                conventions=conventions,
+                filename="command_ir/generated.rs",
+                directory=directory,
+                genpath=None,
+                apiname="vulkan",
+                profile=None,
+                versions=feature_pat,
+                emitversions=feature_pat,
+                defaultExtensions="vulkan",
+                addExtensions=add_extension_pat,
+                removeExtensions=remove_extension_pat,
+                emitExtensions=emit_extension_pat,
+            ),
+        ),
+        Path("api_dump/generated.rs"): (
+            ApiDumpGenerator,
+            GeneratorOptions(
+                conventions=conventions,
+                filename="api_dump/generated.rs",
+                directory=directory,
+                genpath=None,
+                apiname="vulkan",
+                profile=None,
+                versions=feature_pat,
+                emitversions=feature_pat,
+                defaultExtensions="vulkan",
+                addExtensions=add_extension_pat,
+                removeExtensions=remove_extension_pat,
+                emitExtensions=emit_extension_pat,
+            ),
+        ),
     }
 
 
@@ -0,0 +1,535 @@
+# Copyright 2025 Google LLC
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+from __future__ import annotations
+import re
+from typing import Optional
+import reg
+from .command_ir_generator import StructMember
+from .layer_trait_generator import LayerTraitGenerator
+from .vk_xml_util import (
+    DispatchChainType,
+    RustMethod,
+    snake_case_to_upper_camel_case,
+    write_preamble,
+)
+
+
+class ApiDumpGenerator(LayerTraitGenerator):
+    """Generates the Dump implementations of the Vulkan types and the hooks of ApiDump."""
+
+    def __init__(self, *args, **kwargs):
+        super().__init__(*args, **kwargs)
+        # The array members that are only valid for some values of another member, and are ignored
+        # otherwise, so they can't be followed unconditionally.
+        self.selected_members: dict[tuple[str, str], tuple[str, str, list[str]]] = {
+            ("VkWriteDescriptorSet", "pImageInfo"): (
+                "descriptorType",
+                "vk::DescriptorType",
+                [
+                    "SAMPLER",
+                    "COMBINED_IMAGE_SAMPLER",
+                    "SAMPLED_IMAGE",
+                    "STORAGE_IMAGE",
+                    "INPUT_ATTACHMENT",
+                ],
+            ),
+            ("VkWriteDescriptorSet", "pBufferInfo"): (
+                "descriptorType",
+                "vk::DescriptorType",
+                [
+                    "UNIFORM_BUFFER",
+                    "STORAGE_BUFFER",
+                    "UNIFORM_BUFFER_DYNAMIC",
+                    "STORAGE_BUFFER_DYNAMIC",
+                ],
+            ),
+            ("VkWriteDescriptorSet", "pTexelBufferView"): (
+                "descriptorType",
+                "vk::DescriptorType",
+                ["UNIFORM_TEXEL_BUFFER", "STORAGE_TEXEL_BUFFER"],
+            ),
+            ("VkDescriptorSetLayoutBinding", "pImmutableSamplers"): (
+                "descriptorType",
+                "vk::DescriptorType",
+                ["SAMPLER", "COMBINED_IMAGE_SAMPLER"],
+            ),
+        }
+        # The pointer members that are ignored in the cases that depend on more than a single
+        # member, mapped to the function in api_dump.rs that tells whether the member is used, or
+        # None if that can't be told from the struct, e.g. the level of the command buffer, in
+        # which case only the address is dumped.
+        self.conditional_members: dict[tuple[str, str], Optional[str]] = {
+            ("VkCommandBufferBeginInfo", "pInheritanceInfo"): None,
+            ("VkGraphicsPipelineCreateInfo", "pTessellationState"): "uses_tessellation_state",
+            ("VkGraphicsPipelineCreateInfo", "pViewportState"): "uses_rasterization_state",
+            ("VkGraphicsPipelineCreateInfo", "pMultisampleState"): "uses_rasterization_state",
+            ("VkGraphicsPipelineCreateInfo", "pDepthStencilState"): "uses_rasterization_state",
+            ("VkGraphicsPipelineCreateInfo", "pColorBlendState"): "uses_rasterization_state",
+        }
+        # The platform types that ash defines as c_void, so only their addresses can be dumped.
+        self.opaque_platform_types = {
+            "AHardwareBuffer",
+            "ANativeWindow",
+            "CAMetalLayer",
+            "IDirectFB",
+            "IDirectFBSurface",
+            "SECURITY_ATTRIBUTES",
+            "_screen_context",
+            "_screen_window",
+            "wl_display",
+            "wl_surface",
+            "xcb_connection_t",
+        }
+        self.has_pointers_cache: dict[str, bool] = {}
+
+    def beginFile(self, gen_opts):
+        # Skip the preamble of the LayerTraitGenerator.
+        super(LayerTraitGenerator, self).beginFile(gen_opts)
+
+        write_preamble(self.outFile)
+        self.newline()
+        self.outFile.write(
+            "\n".join(
+                [
+                    "#![allow(missing_docs)]",
+                    "// This file is generated from the Vulkan XML API registry.",
+                    "#![allow(unused_unsafe)]",
+                    "#![allow(clippy::too_many_arguments)]",
+                    "// The raw pointer parameters are dumped as addresses, and never"
+                    " dereferenced.",
+                    "#![allow(clippy::not_unsafe_ptr_arg_deref)]",
+                    "use ash::vk::{self, Handle};",
+                    "use std::ffi::{c_int, c_void};",
+                    "",
+                    "use super::{dump_c_char_array, dump_c_str, dump_c_str_slice, dump_p_next,"
+                    " dump_ptr, dump_ptr_slice, dump_slice, uses_rasterization_state, uses_tessellation_state,"
+                    " ApiDump, Dump, DumpValue};",
+                    "use crate::{DeviceHooks, InstanceHooks, LayerVulkanCommand,"
+                    " VkLayerDeviceLink};",
+                    "",
+                ]
+            )
+        )
+        self.newline()
+
+    def endFile(self):
+        hooked_commands: dict[DispatchChainType, list[RustMethod]] = {}
+        for dispatch_type, commands in self.all_commands.items():
+            for name, command in commands.items():
+                if self.command_aliases.get_represent_name(name) != name:
+                    continue
+                # The same commands as the ones without after hooks in the LayerTraitGenerator.
+                if name in ["vkGetInstanceProcAddr", "vkGetDeviceProcAddr"]:
+                    continue
+                hooked_commands.setdefault(dispatch_type, []).append(command)
+        device_commands = hooked_commands.get(DispatchChainType.DEVICE, [])
+        instance_commands = hooked_commands.get(DispatchChainType.INSTANCE, [])
+
+        root_structs: set[str] = set()
+        for command in device_commands + instance_commands:
+            for _, param_type in self.__get_hook_params(command):
+                for rust_name in re.findall(r"vk::(\w+)", param_type):
+                    type_name = self.__resolve_type_alias(f"Vk{rust_name}")
+                    if self.__get_category(type_name) in ["struct", "union"]:
+                        root_structs.add(type_name)
+        dumped_structs = sorted(
+            self.__get_reachable_structs(root_structs), key=lambda name: name.removeprefix("Vk")
+        )
+
+        lines = self.__generate_macro_lines()
+        lines += self.__generate_macro_call_lines(
+            "impl_dump_for_handles", self.__get_types_of_category("handle")
+        )
+        lines += self.__generate_macro_call_lines(
+            "impl_dump_for_enums",
+            [name for name in self.__get_types_of_category("enum") if "FlagBits" not in name],
+        )
+        lines += self.__generate_macro_call_lines(
+            "impl_dump_for_flags", self.__get_types_of_category("bitmask")
+        )
+        std_types = sorted(
+            set(
+                member.type_name
+                for struct_name in dumped_structs
+                for member in self.__get_members(struct_name)
+                if member.type_name.startswith("StdVideo") and member.pointer_levels > 0
+            )
+        )
+        lines += self.__generate_macro_call_lines("impl_dump_for_video_std_types", std_types)
+
+        for struct_name in dumped_structs:
+            lines += self.__generate_struct_dump_lines(struct_name)
+
+        extension_structs = sorted(
+            set(
+                extension
+                for struct_name in dumped_structs
+                for extension in self.__get_extension_structs(struct_name)
+            ),
+            key=lambda name: name.removeprefix("Vk"),
+        )
+        lines += [
+            "/// Dumps the extension struct at `p_next` with its `pNext` chain. Returns [`None`] if"
+            " the `sType`",
+            "/// is unknown.",
+            "#[deny(unsafe_op_in_unsafe_fn)]",
+            "pub(crate) unsafe fn dump_extension_struct(p_next: &vk::BaseInStructure) ->"
+            " Option<DumpValue> {",
+            "    let p_next: *const vk::BaseInStructure = p_next;",
+            "    let dumped = match unsafe { (*p_next).s_type } {",
+        ]
+        for struct_name in extension_structs:
+            lines.append(
+                f"        vk::StructureType::{self.__get_structure_type(struct_name)} => unsafe {{ "
+                f"(*p_next.cast::<vk::{struct_name.removeprefix('Vk')}>()).dump() }},"
+            )
+        lines += ["        _ => return None,", "    };", "    Some(dumped)", "}", ""]
+
+        for const_name, kind, commands in [
+            ("HOOKED_DEVICE_COMMANDS", "device", device_commands),
+            ("HOOKED_INSTANCE_COMMANDS", "instance", instance_commands),
+        ]:
+            lines += [
+                f"/// The {kind} commands dumped by [`ApiDump`].",
+                f"pub(crate) const {const_name}: &[LayerVulkanCommand] = &[",
+            ]
+            lines += [
+                f"    LayerVulkanCommand::{self.__get_variant_name(command)},"
+                for command in commands
+            ]
+            lines += ["];", ""]
+
+        for trait_name, commands in [
+            ("DeviceHooks", device_commands),
+            ("InstanceHooks", instance_commands),
+        ]:
+            lines.append(f"impl {trait_name} for ApiDump {{")
+            for command in commands:
+                lines += self.__generate_hook_lines(command)
+            lines += ["}", ""]
+
+        self.outFile.write("\n".join(lines))
+        self.newline()
+
+        # Skip the endFile of the LayerTraitGenerator.
+        super(LayerTraitGenerator, self).endFile()
+
+    def __generate_macro_lines(self) -> list[str]:
+        lines: list[str] = []
+        for macro_name, arg_name, path, body in [
+            ("impl_dump_for_handles", "handle", "vk", ["DumpValue::Handle(self.as_raw())"]),
+            ("impl_dump_for_enums", "enum", "vk", ['DumpValue::Enum(format!("{self:?}"))']),
+            (
+                "impl_dump_for_flags",
+                "flags",
+                "vk",
+                [
+                    "if self.is_empty() {",
+                    '    DumpValue::Enum("0".to_owned())',
+                    "} else {",
+                    '    DumpValue::Enum(format!("{self:?}"))',
+                    "}",
+                ],
+            ),
+            (
+                "impl_dump_for_video_std_types",
+                "std_type",
+                "vk::native",
+                ['DumpValue::Opaque(format!("{self:?}"))'],
+            ),
+        ]:
+            lines += [
+                f"macro_rules! {macro_name} {{",
+                f"    ($(${arg_name}:ident),* $(,)?) => {{",
+                "        $(",
+                f"            impl Dump for {path}::${arg_name} {{",
+                "                unsafe fn dump(&self) -> DumpValue {",
+            ]
+            lines += [20 * " " + line for line in body]
+            lines += ["                }", "            }", "        )*", "    };", "}", ""]
+        return lines
+
+    def __generate_macro_call_lines(self, macro_name: str, type_names: list[str]) -> list[str]:
+        lines = [f"{macro_name}!("]
+        lines += [f"    {type_name.removeprefix('Vk')}," for type_name in type_names]
+        return lines + [");", ""]
+
+    def __generate_struct_dump_lines(self, struct_name: str) -> list[str]:
+        is_union = self.__get_category(struct_name) == "union"
+        fields: list[tuple[str, str]] = []
+        is_deprecated = False
+        for member in self.__get_members(struct_name):
+            if is_union and self.__has_pointers(member.type_name):
+                # The active member is unknown, so the pointers in the members can't be followed.
+                continue
+            is_deprecated = is_deprecated or member.deprecated
+            fields.append(
+                (member.vk_name, self.__generate_member_dump_expr(struct_name, member, is_union))
+            )
+        rust_name = struct_name.removeprefix("Vk")
+        lines = ["#[allow(deprecated)]"] if is_deprecated else []
+        lines += [
+            f"impl Dump for vk::{rust_name} {{",
+            "    unsafe fn dump(&self) -> DumpValue {",
+        ]
+        if len(fields) == 0:
+            lines.append(f'        DumpValue::Struct("{struct_name}", vec![])')
+        else:
+            lines += ["        let fields = unsafe {", "            vec!["]
+            lines += [f'                ("{name}", {expr}),' for name, expr in fields]
+            lines += [
+                "            ]",
+                "        };",
+                f'        DumpValue::Struct("{struct_name}", fields)',
+            ]
+        return lines + ["    }", "}", ""]
+
+    def __generate_member_dump_expr(
+        self, struct_name: str, member: StructMember, is_union: bool
+    ) -> str:
+        field = f"self.{member.rust_name}"
+        if member.vk_name == "pNext":
+            if member.type_name == "void":
+                return f"dump_p_next({field})"
+            return f"dump_p_next({field}.cast())"
+        if member.type_name.startswith("PFN_"):
+            return f"DumpValue::Pointer({field}.map_or(0, |f| f as usize))"
+        if member.pointer_levels == 0:
+            if member.is_array and member.type_name == "char":
+                return f"dump_c_char_array(&{field})"
+            return f"{field}.dump()"
+        if is_union:
+            # The union members are never followed, since the active member is unknown.
+            return f"{field}.dump()"
+        dump_expr = self.__generate_pointer_dump_expr(struct_name, member)
+        if dump_expr is None:
+            return f"{field}.dump()"
+        if (struct_name, member.vk_name) in self.conditional_members:
+            is_used = self.conditional_members[(struct_name, member.vk_name)]
+            if is_used is None:
+                return f"{field}.dump()"
+            return f"if {is_used}(self) {{ {dump_expr} }} else {{ {field}.dump() }}"
+        selected_member = self.selected_members.get((struct_name, member.vk_name))
+        if selected_member is not None:
+            selector, selector_type, values = selected_member
+            pattern = " | ".join(f"{selector_type}::{value}" for value in values)
+            selector_member = next(
+                m for m in self.__get_members(struct_name) if m.vk_name == selector
+            )
+            dump_expr = (
+                f"if matches!(self.{selector_member.rust_name}, {pattern}) {{ {dump_expr} }} else "
+                f"{{ {field}.dump() }}"
+            )
+        return dump_expr
+
+    # Returns the expression that dumps the pointees, or None if only the address is dumped.
+    def __generate_pointer_dump_expr(
+        self, struct_name: str, member: StructMember
+    ) -> Optional[str]:
+        field = f"self.{member.rust_name}"
+        if member.len == ["null-terminated"]:
+            return f"dump_c_str({field})"
+        if any(len_part.startswith("latexmath:") for len_part in member.len):
+            # ash types the pointers with the constant sizes as pointers to arrays, e.g.
+            # pVersionData, while the other sizes can't be computed from the members.
+            if re.fullmatch(r"[0-9A-Z_*]+", member.altlen or "") is not None:
+                return f"dump_ptr({field})"
+            return None
+        if len(member.len) == 2:
+            len_field = f"self.{self.__get_len_member(struct_name, member).rust_name}"
+            if member.len[1] == "null-terminated":
+                return f"dump_c_str_slice({field}, {len_field})"
+            # An array of pointers to single elements, e.g. ppUsageCounts.
+            assert member.len[1] == "1" and member.pointer_levels == 2
+            return f"dump_ptr_slice({field}, {len_field})"
+        if len(member.len) == 1:
+            if member.type_name == "void" and member.pointer_levels == 1:
+                # Opaque bytes, e.g. pInitialData.
+                return None
+            len_field = f"self.{self.__get_len_member(struct_name, member).rust_name}"
+            return f"dump_slice({field}, {len_field})"
+        if member.pointer_levels != 1:
+            return None
+        category = self.__get_category(member.type_name)
+        if category == "struct" or member.type_name.startswith("StdVideo"):
+            return f"dump_ptr({field})"
+        return None
+
+    def __get_len_member(self, struct_name: str, member: StructMember) -> StructMember:
+        return next(
+            len_member
+            for len_member in self.__get_members(struct_name)
+            if len_member.vk_name == member.len[0]
+        )
+
+    def __generate_hook_lines(self, command: RustMethod) -> list[str]:
+        params = self.__get_hook_params(command)
+        # The output of a command that returns VkResult is dumped as an argument, and the raw
+        # vk::Result as the result, see RustMethod.get_after_hook_result_params.
+        result_expr = "None"
+        for result_param in command.get_after_hook_result_params():
+            name, param_type = result_param.split(": ", 1)
+            name = name.removeprefix("_")
+            if name == "result":
+                result_expr = "Some(result.dump())"
+            else:
+                params.append((name, param_type))
+        arg_exprs = [self.__generate_hook_arg_dump_expr(name, type) for name, type in params]
+        signature = re.sub(
+            r"\b_(\w+):",
+            lambda match: ("r#type" if match.group(1) == "type" else match.group(1)) + ":",
+            command.to_after_hook_string(),
+        )
+        lines = [f"    {signature} {{"]
+        args = ", ".join(
+            f'("{self.__get_vk_param_name(name)}", {arg_expr})'
+            for (name, _), arg_expr in zip(params, arg_exprs)
+        )
+        lines.append(
+            f'        unsafe {{ self.write_command("{command.vk_xml_cmd.name}", &[{args}],'
+            f" {result_expr}) }};"
+        )
+        return lines + ["    }"]
+
+    # The outputs are None if the command fails, see RustMethod.get_after_hook_parameters, and
+    # are dumped as null.
+    def __generate_hook_arg_dump_expr(self, name: str, param_type: str) -> str:
+        if param_type == "&[u8]":
+            return f"{name}.as_ptr().dump()"
+        if param_type == "Option<&[u8]>":
+            return f"{name}.map(<[_]>::as_ptr).dump()"
+        match = re.fullmatch(r"(Option<)?&vk::(\w+)>?", param_type)
+        if match is not None and match.group(2) in self.opaque_platform_types:
+            if match.group(1) is not None:
+                return f"{name}.map(std::ptr::from_ref).dump()"
+            return f"std::ptr::from_ref({name}).dump()"
+        if param_type == "T":
+            return f"DumpValue::Array({name}.map(DumpValue::Bool).collect())"
+        if param_type == "&VkLayerDeviceLink":
+            return f"std::ptr::from_ref({name}).dump()"
+        return f"{name}.dump()"
+
+    # Returns the names and the types of the parameters of the after hook, without the result.
+    def __get_hook_params(self, command: RustMethod) -> list[tuple[str, str]]:
+        params: list[tuple[str, str]] = []
+        for param in command.get_after_hook_parameters():
+            name = param.name.removeprefix("_")
+            if name == "type":
+                name = "r#type"
+            if param.type.slice_of is not None and param.type.slice_of.name == "bool":
+                # The same generic type as the one in RustMethod.to_after_hook_string.
+                params.append((name, "T"))
+            else:
+                params.append((name, param.type.to_string()))
+        return params
+
+    def __get_variant_name(self, command: RustMethod) -> str:
+        return snake_case_to_upper_camel_case(command.name)
+
+    # Returns the name of the parameter in the Vulkan spec, e.g. pCreateInfo for p_create_info.
+    def __get_vk_param_name(self, name: str) -> str:
+        if name == "r#type":
+            return "type"
+        first_word, *words = name.split("_")
+        return first_word + "".join(word[:1].upper() + word[1:] for word in words)
+
+    def __get_types_of_category(self, category: str) -> list[str]:
+        return sorted(
+            (
+                name
+                for name, type_info in self.types.items()
+                if isinstance(type_info, reg.TypeInfo)
+                and type_info.elem.get("alias") is None
+                and type_info.elem.get("category") == category
+            ),
+            key=lambda name: name.removeprefix("Vk"),
+        )
+
+    def __get_reachable_structs(self, root_structs: set[str]) -> set[str]:
+        reachable_structs: set[str] = set()
+        stack = list(root_structs)
+        while len(stack) > 0:
+            struct_name = stack.pop()
+            if struct_name in reachable_structs:
+                continue
+            reachable_structs.add(struct_name)
+            for member in self.__get_members(struct_name):
+                type_name = self.__resolve_type_alias(member.type_name)
+                if self.__get_category(type_name) in ["struct", "union"]:
+                    stack.append(type_name)
+            stack += self.__get_extension_structs(struct_name)
+        return reachable_structs
+
+    # Whether dumping the struct follows pointers. Unions are never followed.
+    def __has_pointers(self, type_name: str) -> bool:
+        type_name = self.__resolve_type_alias(type_name)
+        if self.__get_category(type_name) != "struct":
+            return False
+        cached = self.has_pointers_cache.get(type_name)
+        if cached is not None:
+            return cached
+        # Break the cycles. A struct can't contain itself by value.
+        self.has_pointers_cache[type_name] = False
+        has_pointers = any(
+            member.pointer_levels > 0 or self.__has_pointers(member.type_name)
+            for member in self.__get_members(type_name)
+        )
+        self.has_pointers_cache[type_name] = has_pointers
+        return has_pointers
+
+    def __get_members(self, struct_name: str) -> list[StructMember]:
+        type_info = self.types[struct_name]
+        assert isinstance(type_info, reg.TypeInfo)
+        return [
+            StructMember.from_member_element(member)
+            for member in type_info.elem.findall("member")
+            if member.get("api") in [None, "vulkan"]
+        ]
+
+    def __get_extension_structs(self, struct_name: str) -> list[str]:
+        if not any(member.vk_name == "pNext" for member in self.__get_members(struct_name)):
+            return []
+        extension_structs: list[str] = []
+        for name, type_info in self.types.items():
+            if not isinstance(type_info, reg.TypeInfo) or type_info.elem.get("alias") is not None:
+                continue
+            struct_extends = type_info.elem.get("structextends")
+            if struct_extends is None:
+                continue
+            extended_structs = [
+                self.__resolve_type_alias(extended.strip())
+                for extended in struct_extends.split(",")
+            ]
+            if struct_name in extended_structs:
+                extension_structs.append(name)
+        return extension_structs
+
+    def __get_structure_type(self, struct_name: str) -> str:
+        s_type_member = self.types[struct_name].elem.find("member[name='sType']")
+        assert s_type_member is not None, f"{struct_name} doesn't have sType"
+        return s_type_member.get("values").removeprefix("VK_STRUCTURE_TYPE_")
+
+    def __get_category(self, type_name: str) -> Optional[str]:
+        type_info = self.types.get(self.__resolve_type_alias(type_name))
+        if not isinstance(type_info, reg.TypeInfo):
+            return None
+        return type_info.elem.get("category")
+
+    def __resolve_type_alias(self, type_name: str) -> str:
+        type_info = self.types.get(type_name)
+        while isinstance(type_info, reg.TypeInfo) and type_info.elem.get("alias") is not None:
+            type_name = type_info.elem.get("alias")
+            type_info = self.types.get(type_name)
+        return type_name
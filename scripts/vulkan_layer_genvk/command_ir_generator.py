@@ -0,0 +1,448 @@
+# Copyright 2025 Google LLC
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+from __future__ import annotations
+import re
+from typing import NamedTuple, Optional
+from xml.etree.ElementTree import Element
+import reg
+from .global_simple_intercept_generator import GlobalSimpleInterceptGenerator, VulkanCommand
+from .vk_xml_util import camel_case_to_snake_case, write_preamble
+
+
+# A member of a Vulkan struct that may need to be deep copied.
+class StructMember(NamedTuple):
+    vk_name: str
+    # The field name in ash.
+    rust_name: str
+    # The type name without the pointers, e.g. VkViewport.
+    type_name: str
+    pointer_levels: int
+    is_const: bool
+    # The len attribute split by ",", e.g. ["usageCountsCount", "1"].
+    len: list[str]
+    # Fixed size array dimensions, e.g. float blendConstants[4].
+    is_array: bool
+    # The len expression in C when len is latexmath, e.g. "2*VK_UUID_SIZE".
+    altlen: Optional[str]
+    deprecated: bool
+
+    @staticmethod
+    def from_member_element(member: Element) -> StructMember:
+        type_element = member.find("type")
+        name_element = member.find("name")
+        vk_name = "".join(name_element.itertext()).strip()
+        rust_name = camel_case_to_snake_case(vk_name)
+        # ash renames the fields that are Rust keywords.
+        if rust_name == "type":
+            rust_name = "ty"
+        type_tail = (type_element.tail or "").strip()
+        prefix = (member.text or "").strip()
+        len_attr = member.get("len")
+        return StructMember(
+            vk_name=vk_name,
+            rust_name=rust_name,
+            type_name="".join(type_element.itertext()).strip(),
+            pointer_levels=type_tail.count("*"),
+            is_const=prefix.startswith("const"),
+            len=[] if len_attr is None else [part.strip() for part in len_attr.split(",")],
+            is_array=(name_element.tail or "").strip().startswith("["),
+            altlen=member.get("altlen"),
+            deprecated=member.get("deprecated") is not None,
+        )
+
+
+class CommandIrGenerator(GlobalSimpleInterceptGenerator):
+    """Generates the deep copy implementations and the RecordedCommand enum of the command IR."""
+
+    def __init__(self, *args, **kwargs):
+        super().__init__(*args, **kwargs)
+        # The array members that are only valid for some values of another member, and are ignored
+        # otherwise, so they can't be followed unconditionally.
+        self.selected_members: dict[tuple[str, str], tuple[str, str, list[str]]] = {
+            ("VkWriteDescriptorSet", "pImageInfo"): (
+                "descriptorType",
+                "vk::DescriptorType",
+                [
+                    "SAMPLER",
+                    "COMBINED_IMAGE_SAMPLER",
+                    "SAMPLED_IMAGE",
+                    "STORAGE_IMAGE",
+                    "INPUT_ATTACHMENT",
+                ],
+            ),
+            ("VkWriteDescriptorSet", "pBufferInfo"): (
+                "descriptorType",
+                "vk::DescriptorType",
+                [
+                    "UNIFORM_BUFFER",
+                    "STORAGE_BUFFER",
+                    "UNIFORM_BUFFER_DYNAMIC",
+                    "STORAGE_BUFFER_DYNAMIC",
+                ],
+            ),
+            ("VkWriteDescriptorSet", "pTexelBufferView"): (
+                "descriptorType",
+                "vk::DescriptorType",
+                ["UNIFORM_TEXEL_BUFFER", "STORAGE_TEXEL_BUFFER"],
+            ),
+        }
+        self.needs_deep_copy_cache: dict[str, bool] = {}
+
+    def beginFile(self, gen_opts):
+        # Skip the preamble of the GlobalSimpleInterceptGenerator.
+        super(GlobalSimpleInterceptGenerator, self).beginFile(gen_opts)
+
+        write_preamble(self.outFile)
+        self.outFile.write("// This file is generated from the Vulkan XML API registry.\n")
+        self.outFile.write(
+            "\n".join(
+                [
+                    "#![allow(unused_unsafe)]",
+                    "use ash::vk;",
+                    "use std::ffi::c_void;",
+                    "",
+                    "use super::{DeepCopied, DeepCopy, DeepCopyStorage, ReplayError};",
+                    "use crate::{command_args::*, DeviceDispatchTable, LayerVulkanCommand};",
+                    "",
+                ]
+            )
+        )
+        self.newline()
+
+    def endFile(self):
+        _, not_aliased_commands = self.resolve_command_aliases()
+        recorded_commands = [command for command in not_aliased_commands if command.is_recorded()]
+
+        root_structs: set[str] = set()
+        for command in recorded_commands:
+            for _, field_type in command.get_command_args_info().fields:
+                for rust_name in re.findall(r"vk::(\w+)", field_type):
+                    struct_name = self.__resolve_type_alias(f"Vk{rust_name}")
+                    if self.__get_category(struct_name) == "struct":
+                        root_structs.add(struct_name)
+        reachable_structs = self.__get_reachable_structs(root_structs)
+        copied_structs = sorted(
+            (name for name in reachable_structs if self.__needs_deep_copy(name)),
+            key=lambda name: name.removeprefix("Vk"),
+        )
+
+        lines: list[str] = []
+        for struct_name in copied_structs:
+            lines += [
+                f"unsafe impl DeepCopy for vk::{struct_name.removeprefix('Vk')} {{",
+                "    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {",
+            ]
+            lines += [8 * " " + line for line in self.__generate_struct_deep_copy_lines(struct_name)]
+            lines += ["    }", "}", ""]
+
+        extension_structs = sorted(
+            set(
+                extension
+                for struct_name in reachable_structs
+                for extension in self.__get_extension_structs(struct_name)
+            )
+            & set(copied_structs),
+            key=lambda name: name.removeprefix("Vk"),
+        )
+        lines += [
+            "/// Deep copies the extension struct at `p_next` with its `pNext` chain. Returns"
+            " [`None`] if the",
+            "/// `sType` is unknown.",
+            "pub(crate) unsafe fn deep_copy_extension_struct(storage: &mut DeepCopyStorage,"
+            " p_next: &vk::BaseInStructure) -> Option<*const c_void> {",
+            "    let p_next: *const vk::BaseInStructure = p_next;",
+            "    let copied = match unsafe { (*p_next).s_type } {",
+        ]
+        for struct_name in extension_structs:
+            lines.append(
+                f"        vk::StructureType::{self.__get_structure_type(struct_name)} => unsafe {{ "
+                f"storage.deep_copy_ptr(p_next.cast::<vk::{struct_name.removeprefix('Vk')}>()) "
+                "}.cast(),"
+            )
+        lines += ["        _ => return None,", "    };", "    Some(copied)", "}", ""]
+
+        for command in recorded_commands:
+            lines += self.__generate_command_args_deep_copy_lines(command)
+
+        args_infos = [command.get_command_args_info() for command in recorded_commands]
+        lines += [
+            "/// The `vkCmd*` commands that are recorded into [`RecordedCommand`]s.",
+            "pub(crate) const RECORDED_COMMANDS: &[LayerVulkanCommand] = &[",
+        ]
+        lines += [f"    LayerVulkanCommand::{info.variant_name}," for info in args_infos]
+        lines += ["];", ""]
+
+        lines += [
+            "/// A `vkCmd*` command recorded by the layer framework.",
+            "///",
+            "/// Each variant owns a deep copy of the arguments of the Vulkan command of the same"
+            " name, including",
+            "/// the nested arrays and the `pNext` chains, so the command can be inspected or"
+            " replayed after the",
+            "/// original arguments are freed. The opaque host pointers whose size is unknown,"
+            " e.g. the `pData`",
+            "/// of `vkCmdPushDescriptorSetWithTemplateKHR`, the host addresses in unions, and the"
+            " pointers in",
+            "/// the video codec std structs are copied as is.",
+            "#[derive(Clone)]",
+            "pub enum RecordedCommand {",
+        ]
+        lines += [
+            f"    {info.variant_name}(DeepCopied<{info.struct_name}>)," for info in args_infos
+        ]
+        lines += [
+            "}",
+            "",
+            "// Safe because the pointers in the arguments refer to the memory owned by the DeepCopied,",
+            "// which is never written after the copy, except the opaque host pointers copied as is. Those",
+            "// are only dereferenced by the unsafe RecordedCommand::replay, whose caller guarantees that",
+            "// they are still valid. The arguments structs themselves are not Send or Sync, since their",
+            "// pointers refer to the memory of the caller of the intercepted command.",
+            "unsafe impl Send for RecordedCommand {}",
+            "unsafe impl Sync for RecordedCommand {}",
+            "",
+            "impl RecordedCommand {",
+            "    /// The name of the recorded Vulkan command, e.g. `vkCmdDraw`.",
+            "    pub fn name(&self) -> &'static str {",
+            "        match self {",
+        ]
+        lines += [
+            f'            Self::{info.variant_name}(_) => "{info.vk_command_name}",'
+            for info in args_infos
+        ]
+        lines += [
+            "        }",
+            "    }",
+            "",
+            "    pub(crate) unsafe fn replay_impl(&self, core: &ash::Device, extensions:"
+            " Option<&DeviceDispatchTable>, command_buffer: vk::CommandBuffer) -> Result<(),"
+            " ReplayError> {",
+            "        match self {",
+        ]
+        for command, info in zip(recorded_commands, args_infos):
+            args_var = "args" if len(info.fields) > 1 else "_"
+            lines.append(f"            Self::{info.variant_name}({args_var}) => {{")
+            lines += [16 * " " + line for line in self.__generate_replay_lines(command)]
+            lines.append("            }")
+        lines += ["        }", "        Ok(())", "    }", "}", ""]
+
+        self.outFile.write("\n".join(lines))
+        self.newline()
+
+        # Skip the endFile of the GlobalSimpleInterceptGenerator.
+        super(GlobalSimpleInterceptGenerator, self).endFile()
+
+    def __generate_replay_lines(self, command: VulkanCommand) -> list[str]:
+        dispatch_info = next(
+            info
+            for info in self.dispatch_infos.values()
+            if command.vk_xml_command.name in info.commands
+        )
+        field_name = dispatch_info.get_dispatch_table_field_name()
+        if dispatch_info.core_info is not None:
+            dispatch_table_expr = field_name
+        else:
+            dispatch_table_expr = (
+                f'extensions.ok_or(ReplayError::ExtensionCommand("{command.vk_xml_command.name}"))?'
+                f".{field_name}()"
+            )
+        return [f"let dispatch_table = {dispatch_table_expr};"] + command.get_replay_lines()
+
+    def __generate_command_args_deep_copy_lines(self, command: VulkanCommand) -> list[str]:
+        args_info = command.get_command_args_info()
+        body: list[str] = []
+        for field_name, field_type in args_info.fields:
+            match = re.fullmatch(r"(Vec|Option)<vk::(\w+)>|vk::(\w+)", field_type)
+            if match is None:
+                continue
+            struct_name = self.__resolve_type_alias(f"Vk{match.group(2) or match.group(3)}")
+            if not self.__needs_deep_copy(struct_name):
+                continue
+            if match.group(1) == "Vec":
+                body += [
+                    f"for element in &mut self.{field_name} {{",
+                    "    unsafe { element.deep_copy_into(storage) };",
+                    "}",
+                ]
+            elif match.group(1) == "Option":
+                body += [
+                    f"if let Some({field_name}) = &mut self.{field_name} {{",
+                    f"    unsafe {{ {field_name}.deep_copy_into(storage) }};",
+                    "}",
+                ]
+            else:
+                body.append(f"unsafe {{ self.{field_name}.deep_copy_into(storage) }};")
+        lines = [f"unsafe impl DeepCopy for {args_info.struct_name} {{"]
+        if len(body) == 0:
+            lines.append("    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}")
+        else:
+            lines.append("    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {")
+            lines += [8 * " " + line for line in body]
+            lines.append("    }")
+        return lines + ["}", ""]
+
+    def __generate_struct_deep_copy_lines(self, struct_name: str) -> list[str]:
+        lines: list[str] = []
+        for member in self.__get_members(struct_name):
+            field = f"self.{member.rust_name}"
+            if member.vk_name == "pNext":
+                copy_expr = f"unsafe {{ storage.deep_copy_p_next({field}) }}"
+                if not member.is_const:
+                    copy_expr += ".cast_mut()"
+                lines.append(f"{field} = {copy_expr};")
+                continue
+            if member.pointer_levels == 0:
+                assert not member.is_array or not self.__needs_deep_copy(member.type_name), (
+                    f"Unsupported array of structs to deep copy: {struct_name}::{member.vk_name}"
+                )
+                if not member.is_array and self.__needs_deep_copy(member.type_name):
+                    lines.append(f"unsafe {{ {field}.deep_copy_into(storage) }};")
+                continue
+            copy_expr = self.__generate_pointer_copy_expr(struct_name, member)
+            if copy_expr is None:
+                continue
+            selected_member = self.selected_members.get((struct_name, member.vk_name))
+            if selected_member is not None:
+                selector, selector_type, values = selected_member
+                pattern = " | ".join(f"{selector_type}::{value}" for value in values)
+                selector_field = f"self.{camel_case_to_snake_case(selector)}"
+                copy_expr = (
+                    f"if matches!({selector_field}, {pattern}) {{ {copy_expr} }} else "
+                    "{ std::ptr::null() }"
+                )
+            lines.append(f"{field} = {copy_expr};")
+        return lines
+
+    # Returns the expression that copies the pointer member, or None if the pointer is copied as is.
+    def __generate_pointer_copy_expr(
+        self, struct_name: str, member: StructMember
+    ) -> Optional[str]:
+        field = f"self.{member.rust_name}"
+        len_fields = [
+            f"self.{camel_case_to_snake_case(len_part)}"
+            for len_part in member.len
+            if len_part not in ["1", "null-terminated"]
+        ]
+        for len_part in member.len:
+            assert not len_part.startswith("latexmath:"), (
+                f"Unsupported len attribute of {struct_name}::{member.vk_name}: "
+                f"{','.join(member.len)}"
+            )
+        element_needs_deep_copy = self.__needs_deep_copy(member.type_name)
+        if member.len == ["null-terminated"]:
+            assert member.type_name == "char" and member.pointer_levels == 1
+            return f"unsafe {{ storage.copy_c_str({field}) }}"
+        if len(member.len) == 2:
+            # An array of pointers to single elements, e.g. ppUsageCounts.
+            assert member.len[1] == "1" and member.pointer_levels == 2
+            function = "deep_copy_ptr_slice" if element_needs_deep_copy else "copy_ptr_slice"
+            return f"unsafe {{ storage.{function}({field}, {len_fields[0]}) }}"
+        if len(member.len) == 1:
+            if member.type_name == "void" and member.pointer_levels == 1:
+                return f"unsafe {{ storage.copy_bytes({field}, {len_fields[0]}) }}"
+            # The pointees of an array of pointers are opaque, e.g. pParams of
+            # VkCuLaunchInfoNVX, so only the array is copied.
+            function = (
+                "deep_copy_slice"
+                if element_needs_deep_copy and member.pointer_levels == 1
+                else "copy_slice"
+            )
+            return f"unsafe {{ storage.{function}({field}, {len_fields[0]}) }}"
+        assert member.pointer_levels == 1, (
+            f"Unsupported pointer without len: {struct_name}::{member.vk_name}"
+        )
+        if member.type_name == "void" or self.__get_category(member.type_name) == "union":
+            # Opaque host pointers.
+            return None
+        function = "deep_copy_ptr" if element_needs_deep_copy else "copy_ptr"
+        return f"unsafe {{ storage.{function}({field}) }}"
+
+    def __get_reachable_structs(self, root_structs: set[str]) -> set[str]:
+        reachable_structs: set[str] = set()
+        stack = list(root_structs)
+        while len(stack) > 0:
+            struct_name = stack.pop()
+            if struct_name in reachable_structs:
+                continue
+            reachable_structs.add(struct_name)
+            for member in self.__get_members(struct_name):
+                type_name = self.__resolve_type_alias(member.type_name)
+                if self.__get_category(type_name) == "struct":
+                    stack.append(type_name)
+            stack += self.__get_extension_structs(struct_name)
+        return reachable_structs
+
+    def __needs_deep_copy(self, type_name: str) -> bool:
+        type_name = self.__resolve_type_alias(type_name)
+        if self.__get_category(type_name) != "struct":
+            # Unions and the video codec std structs are copied as is.
+            return False
+        cached = self.needs_deep_copy_cache.get(type_name)
+        if cached is not None:
+            return cached
+        # Break the cycles. A struct can't contain itself by value.
+        self.needs_deep_copy_cache[type_name] = False
+        needs_deep_copy = any(
+            member.pointer_levels > 0
+            or (not member.is_array and self.__needs_deep_copy(member.type_name))
+            for member in self.__get_members(type_name)
+        )
+        self.needs_deep_copy_cache[type_name] = needs_deep_copy
+        return needs_deep_copy
+
+    def __get_members(self, struct_name: str) -> list[StructMember]:
+        type_info = self.types[struct_name]
+        assert isinstance(type_info, reg.TypeInfo)
+        return [
+            StructMember.from_member_element(member)
+            for member in type_info.elem.findall("member")
+            if member.get("api") in [None, "vulkan"]
+        ]
+
+    def __get_extension_structs(self, struct_name: str) -> list[str]:
+        if not any(member.vk_name == "pNext" for member in self.__get_members(struct_name)):
+            return []
+        extension_structs: list[str] = []
+        for name, type_info in self.types.items():
+            if not isinstance(type_info, reg.TypeInfo) or type_info.elem.get("alias") is not None:
+                continue
+            struct_extends = type_info.elem.get("structextends")
+            if struct_extends is None:
+                continue
+            extended_structs = [
+                self.__resolve_type_alias(extended.strip())
+                for extended in struct_extends.split(",")
+            ]
+            if struct_name in extended_structs:
+                extension_structs.append(name)
+        return extension_structs
+
+    def __get_structure_type(self, struct_name: str) -> str:
+        s_type_member = self.types[struct_name].elem.find("member[name='sType']")
+        assert s_type_member is not None, f"{struct_name} doesn't have sType"
+        return s_type_member.get("values").removeprefix("VK_STRUCTURE_TYPE_")
+
+    def __get_category(self, type_name: str) -> Optional[str]:
+        type_info = self.types.get(self.__resolve_type_alias(type_name))
+        if not isinstance(type_info, reg.TypeInfo):
+            return None
+        return type_info.elem.get("category")
+
+    def __resolve_type_alias(self, type_name: str) -> str:
+        type_info = self.types.get(type_name)
+        while isinstance(type_info, reg.TypeInfo) and type_info.elem.get("alias") is not None:
+            type_name = type_info.elem.get("alias")
+            type_info = self.types.get(type_name)
+        return type_name
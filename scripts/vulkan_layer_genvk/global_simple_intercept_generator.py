@@ -38,7 +38,9 @@ from .vk_xml_util import (
     RustMethod,
     VkXmlToRustMethodInfo,
 )
+from .perfect_hash import build_perfect_hash_table
 from dataclasses import dataclass, field
+import re
 
 
 @dataclass
@@ -182,8 +184,18 @@ class CommandDispatchInfo:
     ) -> list[str]:
         dispatch_infos = [info for info in dispatch_infos if len(info.commands - skip_commands) > 0]
         device_dispatch_def_lines: list[str] = [
-            "pub(crate) struct DeviceDispatchTable {",
-            "    pub core: Arc<ash::Device>,",
+            "/// The dispatch table of the next layer in the device call chain.",
+            "///",
+            "/// Obtained through [`Global::next_device_dispatch`](crate::Global::next_device_dispatch).",
+            "pub struct DeviceDispatchTable {",
+            "    pub(crate) core: Arc<ash::Device>,",
+        ]
+        device_dispatch_getter_lines: list[str] = [
+            "",
+            "    /// The core commands of the next layer.",
+            "    pub fn core(&self) -> &ash::Device {",
+            "        &self.core",
+            "    }",
         ]
         device_dispatch_impl_lines: list[str] = [
             "impl DeviceDispatchTable {",
@@ -199,8 +211,21 @@ class CommandDispatchInfo:
             "            core: Arc::clone(&device),",
         ]
         instance_dispatch_def_lines: list[str] = [
-            "pub(crate) struct InstanceDispatchTable {",
-            "    pub core: Arc<ash::Instance>,",
+            "/// The dispatch table of the next layer in the instance call chain.",
+            "///",
+            (
+                "/// Obtained through [`Global::next_instance_dispatch`]"
+                "(crate::Global::next_instance_dispatch)."
+            ),
+            "pub struct InstanceDispatchTable {",
+            "    pub(crate) core: Arc<ash::Instance>,",
+        ]
+        instance_dispatch_getter_lines: list[str] = [
+            "",
+            "    /// The core commands of the next layer.",
+            "    pub fn core(&self) -> &ash::Instance {",
+            "        &self.core",
+            "    }",
         ]
         instance_dispatch_impl_lines: list[str] = [
             "impl InstanceDispatchTable {",
@@ -227,6 +252,7 @@ class CommandDispatchInfo:
             extension_info = dispatch_info.extension_info
             def_lines: list[str] = []
             impl_lines: list[str] = []
+            getter_lines: list[str] = ["", f"    /// The commands of `{extension_info.name}`."]
 
             # Remove the VK_ prefix
             field_name = dispatch_info.get_dispatch_table_field_name()
@@ -234,30 +260,32 @@ class CommandDispatchInfo:
 
             if extension_info.name in CommandDispatchInfo.deprecated_extensions:
                 def_lines.append("    #[allow(deprecated)]")
+                getter_lines.append("    #[allow(deprecated)]")
             def_lines.append(f"    {field_name}: Arc<{field_type}>,")
             impl_lines.append(
                 f"            {field_name}: Arc::new({field_type}::load(&proc_addr_loader)),"
             )
+            getter_lines += [
+                f"    pub fn {field_name}(&self) -> &{field_type} {{",
+                f"        &self.{field_name}",
+                "    }",
+            ]
 
             if any([command in instance_commands for command in dispatch_info.commands]):
                 instance_dispatch_def_lines += def_lines
                 instance_dispatch_impl_lines += impl_lines
+                instance_dispatch_getter_lines += getter_lines
             if any([command in device_commands for command in dispatch_info.commands]):
                 device_dispatch_def_lines += def_lines
                 device_dispatch_impl_lines += impl_lines
+                device_dispatch_getter_lines += getter_lines
 
         device_dispatch_def_lines.append("}")
-        device_dispatch_impl_lines += [
-            "        }",
-            "    }",
-            "}",
-        ]
+        device_dispatch_impl_lines += ["        }", "    }"] + device_dispatch_getter_lines + ["}"]
         instance_dispatch_def_lines.append("}")
-        instance_dispatch_impl_lines += [
-            "        }",
-            "    }",
-            "}",
-        ]
+        instance_dispatch_impl_lines += (
+            ["        }", "    }"] + instance_dispatch_getter_lines + ["}"]
+        )
 
         return (
             device_dispatch_def_lines
@@ -293,15 +321,456 @@ class RustFfiFunction(NamedTuple):
         return f'extern "system" fn {self.name}({params}){fn_return_type}'
 
 
+class CommandArgsInfo(NamedTuple):
+    """The arguments struct of a command that can be passed with LayerResult::Continue."""
+
+    variant_name: str
+    struct_name: str
+    vk_command_name: str
+    hooks_trait_name: str
+    hook_name: str
+    # The names and the types of the fields.
+    fields: list[tuple[str, str]]
+
+    def get_struct_lines(self) -> list[str]:
+        return (
+            [
+                f"/// The arguments of `{self.vk_command_name}` passed to the next layer.",
+                "///",
+                (
+                    f"/// See [`{self.hooks_trait_name}::{self.hook_name}`]"
+                    f"(crate::{self.hooks_trait_name}::{self.hook_name})."
+                ),
+                "#[derive(Clone)]",
+                f"pub struct {self.struct_name} {{",
+            ]
+            + [f"    pub {name}: {field_type}," for name, field_type in self.fields]
+            + ["}"]
+        )
+
+    @staticmethod
+    def get_command_args_mod_lines(args_infos: list[CommandArgsInfo]) -> list[str]:
+        lines = [
+            "/// The arguments of the Vulkan commands that a hook can rewrite with",
+            "/// [`LayerResult::Continue`](crate::LayerResult::Continue).",
+            "pub mod command_args {",
+            "    use ash::vk;",
+            "    use std::ffi::{c_int, c_void, CString};",
+        ]
+        for info in args_infos:
+            lines.append("")
+            lines += [4 * " " + line if line else line for line in info.get_struct_lines()]
+        lines.append("}")
+        return lines
+
+
 class VulkanCommand(NamedTuple):
     vk_xml_command: VkXmlCommand
     rust_fn: RustFfiFunction
     rust_method: RustMethod
+    # The statements that add the externally synchronized handles, and then the other handles used
+    # by the command, to `objects` in the trampoline.
+    extern_sync_lines: list[str]
+
+    @staticmethod
+    def generate_extern_sync_lines(
+        vk_xml_command: VkXmlCommand,
+        rust_fn: RustFfiFunction,
+        types: dict[str, reg.TypeInfo | reg.GroupInfo],
+    ) -> list[str]:
+        rust_param_names = {
+            vk_xml_param.name: rust_param.name
+            for vk_xml_param, rust_param in zip(vk_xml_command.parameters, rust_fn.parameters)
+        }
+
+        def is_handle(type_name: str) -> bool:
+            type_info = types.get(type_name)
+            return (
+                isinstance(type_info, reg.TypeInfo)
+                and type_info.elem.get("category") == "handle"
+            )
+
+        # Only vkDestroyInstance and vkDestroyDevice externally synchronize the dispatchable
+        # parents, and they are implemented manually, so the reads of the parents are not tracked.
+        untracked_read_handles = ["VkInstance", "VkPhysicalDevice", "VkDevice"]
+
+        def is_tracked_read_handle(type_name: Optional[str]) -> bool:
+            return is_handle(type_name) and type_name not in untracked_read_handles
+
+        lines: list[str] = []
+        read_lines: list[str] = []
+        for vk_xml_param in vk_xml_command.parameters:
+            rust_name = rust_param_names[vk_xml_param.name]
+            if len(vk_xml_param.extern_sync) == 0:
+                # The handles passed as the parameter or as a const array, e.g. pFences of
+                # vkWaitForFences, are read by the command.
+                param_type = vk_xml_param.type
+                if param_type.points_to is None:
+                    if is_tracked_read_handle(param_type.name):
+                        read_lines.append(f"objects.add_read({rust_name});")
+                elif (
+                    param_type.points_to.is_const
+                    and is_tracked_read_handle(param_type.points_to.name)
+                    and vk_xml_param.len_var in rust_param_names
+                ):
+                    len_name = rust_param_names[vk_xml_param.len_var]
+                    read_lines.append(
+                        f"objects.add_all_read(unsafe {{ slice_from_raw_parts({rust_name}, "
+                        f"{len_name}) }}.iter().copied());"
+                    )
+                continue
+            if vk_xml_param.extern_sync == ["true"]:
+                if vk_xml_param.type.points_to is None:
+                    lines.append(f"objects.add({rust_name});")
+                else:
+                    assert (
+                        vk_xml_param.len_var is not None
+                    ), f"Unexpected externsync pointer without len: {vk_xml_param.name}"
+                    len_name = rust_param_names[vk_xml_param.len_var]
+                    lines.append(
+                        f"objects.add_all(unsafe {{ slice_from_raw_parts({rust_name}, "
+                        f"{len_name}) }}.iter().copied());"
+                    )
+                continue
+            struct_elem = vk_xml_param.type.decayed_type().reg_type_info.elem
+            for expr in vk_xml_param.extern_sync:
+                # Only the handles one level into the structs are checked, e.g.
+                # pCreateInfos[].surface or pPresentInfo->pSwapchains[].
+                match = re.match(r"^(\w+)(\[\])?(?:->|\.)(\w+)(\[\])?$", expr)
+                if match is None:
+                    continue
+                param_name, is_param_array, member_name, is_member_array = match.groups()
+                assert param_name == vk_xml_param.name, f"Unexpected externsync: {expr}"
+                member_elem = struct_elem.find(f"member[name='{member_name}']")
+                assert member_elem is not None, f"Unknown member in externsync: {expr}"
+                # The members identified by a uint64_t, e.g. VkDebugUtilsObjectNameInfoEXT, are not
+                # checked.
+                if not is_handle(member_elem.find("type").text):
+                    continue
+                if is_param_array:
+                    len_name = rust_param_names[vk_xml_param.len_var]
+                    infos = f"unsafe {{ slice_from_raw_parts({rust_name}, {len_name}) }}.iter()"
+                else:
+                    infos = f"unsafe {{ {rust_name}.as_ref() }}.into_iter()"
+                member_rust_name = camel_case_to_snake_case(member_name)
+                if is_member_array:
+                    member_len_name = camel_case_to_snake_case(member_elem.get("len"))
+                    lines.append(
+                        f"objects.add_all({infos}.flat_map(|info| unsafe {{ slice_from_raw_parts("
+                        f"info.{member_rust_name}, info.{member_len_name}) }}).copied());"
+                    )
+                else:
+                    lines.append(f"objects.add_all({infos}.map(|info| info.{member_rust_name}));")
+        return lines + read_lines
+
+    def get_command_args_info(self) -> CommandArgsInfo:
+        vk_xml_to_rust_method_info = VkXmlToRustMethodInfo.from_vk_xml_command(self.vk_xml_command)
+        hooks_trait_name = {
+            DispatchChainType.DEVICE: "DeviceHooks",
+            DispatchChainType.INSTANCE: "InstanceHooks",
+        }[self.vk_xml_command.get_dispatch_chain_type()]
+        fields: list[tuple[str, str]] = []
+        for param in vk_xml_to_rust_method_info.parameters:
+            field = VulkanCommand.__get_command_args_field(
+                param.rust_method_param, param.main_source_vk_xml_param, self.vk_xml_command
+            )
+            if field is not None:
+                fields.append(field)
+        variant_name = snake_case_to_upper_camel_case(self.rust_fn.name)
+        return CommandArgsInfo(
+            variant_name=variant_name,
+            struct_name=f"{variant_name}Args",
+            vk_command_name=self.vk_xml_command.name,
+            hooks_trait_name=hooks_trait_name,
+            hook_name=self.rust_method.name,
+            fields=fields,
+        )
+
+    # Returns the name and the type of the field in the arguments struct for an intercepted
+    # parameter, or None if the parameter is an output, which can't be rewritten.
+    @staticmethod
+    def __get_command_args_field(
+        rust_param: RustParam, vk_xml_param: VkXmlParam, vk_xml_command: VkXmlCommand
+    ) -> Optional[tuple[str, str]]:
+        name = RustParam.param_name_from_vk_xml_param_for_ffi(vk_xml_param.name)
+        rust_type = rust_param.type
+        if rust_type.name is not None:
+            return name, rust_type.to_string()
+        if rust_type.slice_of is not None and rust_type.slice_of.name == "bool":
+            return name, "Vec<bool>"
+        if rust_type.points_to is not None:
+            return name, rust_type.to_string()
+        if rust_type.refers_to is not None:
+            if not rust_type.refers_to.is_const:
+                return None
+            if rust_type.refers_to.name == "str":
+                return name, "CString"
+            inner_type = rust_type.refers_to.to_string()
+            if rust_type.is_optional:
+                return name, f"Option<{inner_type}>"
+            return name, inner_type
+        if rust_type.slice_of is not None:
+            if not rust_type.slice_of.is_const:
+                return None
+            inner_type = f"Vec<{rust_type.slice_of.to_string()}>"
+            if rust_type.is_optional:
+                return name, f"Option<{inner_type}>"
+            return name, inner_type
+        assert False, f"Unsupported parameter {vk_xml_param.name} in {vk_xml_command.name}"
+
+    # Returns the lines that unpack the arguments of LayerResult::Continue, and the expressions of
+    # the FFI parameters passed to the next layer.
+    def __generate_continue_args(
+        self, intercept_params: list[str]
+    ) -> tuple[list[str], dict[str, str]]:
+        ffi_params = {param.name: param.type.to_string() for param in self.rust_fn.parameters}
+        args_info = self.get_command_args_info()
+        field_names = set(name for name, _ in args_info.fields)
+        ffi_exprs: dict[str, str] = {}
+        prelude_lines: list[str] = []
+        # Maps the length expression to the slices sharing it, and whether the slice is optional.
+        slices_by_len: dict[str, list[tuple[str, bool]]] = {}
+        output_lens: set[str] = set()
+        for intercept_param in intercept_params:
+            if intercept_param in field_names:
+                ffi_exprs[intercept_param] = f"args.{intercept_param}"
+            elif match := re.fullmatch(r"(\w+) == vk::TRUE", intercept_param):
+                name = match.group(1)
+                ffi_exprs[name] = f"vk::Bool32::from(args.{name})"
+            elif match := re.fullmatch(
+                r"unsafe \{ (\w+)\.as_ref\(\) \}\.unwrap\(\)", intercept_param
+            ):
+                name = match.group(1)
+                ffi_exprs[name] = f"&args.{name}"
+            elif match := re.fullmatch(r"unsafe \{ (\w+)\.as_ref\(\) \}", intercept_param):
+                name = match.group(1)
+                ffi_exprs[name] = (
+                    f"args.{name}.as_ref().map_or(std::ptr::null(), std::ptr::from_ref)"
+                )
+            elif match := re.fullmatch(
+                (
+                    r"unsafe \{ (?:maybe_)?uninit_slice_from_raw_parts_mut"
+                    r"\(\w+(?: as \*mut u8)?, (.*)\) \}"
+                ),
+                intercept_param,
+            ):
+                output_lens.add(match.group(1))
+            elif match := re.fullmatch(
+                r"unsafe \{ (maybe_)?slice_from_raw_parts\((\w+)( as \*const u8)?, (.*)\) \}",
+                intercept_param,
+            ):
+                optional = match.group(1) is not None
+                name = match.group(2)
+                if optional:
+                    ffi_exprs[name] = f"args.{name}.as_ref().map_or(std::ptr::null(), Vec::as_ptr)"
+                elif match.group(3) is not None:
+                    ffi_exprs[name] = f"args.{name}.as_ptr().cast()"
+                else:
+                    ffi_exprs[name] = f"args.{name}.as_ptr()"
+                slices_by_len.setdefault(match.group(4), []).append((name, optional))
+            elif match := re.fullmatch(
+                r"unsafe \{ bool_iterator_from_raw_parts\((\w+), (.*)\) \}", intercept_param
+            ):
+                name = match.group(1)
+                prelude_lines.append(
+                    f"let rewritten_{name}: Vec<vk::Bool32> = "
+                    f"args.{name}.iter().copied().map(vk::Bool32::from).collect();"
+                )
+                ffi_exprs[name] = f"rewritten_{name}.as_ptr()"
+                slices_by_len.setdefault(match.group(2), []).append((name, False))
+            elif match := re.fullmatch(
+                r"unsafe \{ CStr::from_ptr\((\w+)\) \}\.to_str\(\)\.unwrap\(\)", intercept_param
+            ):
+                name = match.group(1)
+                ffi_exprs[name] = f"args.{name}.as_ptr()"
+            else:
+                # Output parameters are passed to the next layer as is.
+                assert "ptr_as_uninit_mut" in intercept_param, (
+                    f"Unsupported intercept parameter {intercept_param} in "
+                    f"{self.vk_xml_command.name}"
+                )
+
+        assert_lines: list[str] = []
+        len_lines: list[str] = []
+        for len_expr, slices in slices_by_len.items():
+            others = slices
+            if len_expr in ffi_params and len_expr not in output_lens and len_expr not in ffi_exprs:
+                # The length is only used by the input slices, so it's derived from the arguments.
+                required_slices = [name for name, optional in slices if not optional]
+                assert len(required_slices) > 0
+                first = required_slices[0]
+                if ffi_params[len_expr] == "usize":
+                    len_lines.append(f"let rewritten_{len_expr} = args.{first}.len();")
+                else:
+                    len_lines.append(
+                        f"let rewritten_{len_expr} = args.{first}.len().try_into().unwrap();"
+                    )
+                ffi_exprs[len_expr] = f"rewritten_{len_expr}"
+                expected = f"args.{first}.len()"
+                others = [(name, optional) for name, optional in slices if name != first]
+            elif len_expr in ffi_exprs:
+                expected = f"usize::try_from(args.{len_expr}).unwrap()"
+            elif len_expr in ffi_params and ffi_params[len_expr] == "usize":
+                expected = len_expr
+            elif match := re.fullmatch(
+                r"unsafe \{ (\w+)\.as_ref\(\) \}\.unwrap\(\)\.(\w+)", len_expr
+            ):
+                # The length is a field of another rewritten parameter.
+                expected = f"usize::try_from(args.{match.group(1)}.{match.group(2)}).unwrap()"
+            elif len_expr == "samples.as_raw().div_ceil(32)":
+                expected = "usize::try_from(args.samples.as_raw().div_ceil(32)).unwrap()"
+            else:
+                expected = f"usize::try_from({len_expr}).unwrap()"
+            for name, optional in others:
+                if optional:
+                    assert_lines.append(
+                        f"assert!(args.{name}.as_ref().is_none_or(|{name}| {name}.len() == "
+                        f"{expected}));"
+                    )
+                else:
+                    assert_lines.append(f"assert_eq!(args.{name}.len(), {expected});")
+
+        return assert_lines + len_lines + prelude_lines, ffi_exprs
+
+    # Returns the lines that assert that the rewritten arguments don't change the length of an
+    # output array, e.g. pAllocateInfo->commandBufferCount of vkAllocateCommandBuffers, because the
+    # array is still allocated by the caller with the original length.
+    def __generate_output_len_assert_lines(self) -> list[str]:
+        field_names = set(name for name, _ in self.get_command_args_info().fields)
+        lines: list[str] = []
+        for vk_xml_param in self.vk_xml_command.parameters:
+            if vk_xml_param.type.points_to is None or vk_xml_param.type.points_to.is_const:
+                continue
+            if vk_xml_param.len_var is None or "->" not in vk_xml_param.len_var:
+                continue
+            len_struct_name, len_field_name = vk_xml_param.len_var.split("->")
+            len_struct_param = self.__find_param_by_name(len_struct_name)
+            assert len_struct_param is not None, f"Unknown length: {vk_xml_param.len_var}"
+            len_struct_rust_name = len_struct_param[1].name
+            if len_struct_rust_name not in field_names:
+                continue
+            len_field_rust_name = camel_case_to_snake_case(len_field_name)
+            lines.append(
+                f"assert_eq!(args.{len_struct_rust_name}.{len_field_rust_name}, "
+                f"unsafe {{ {len_struct_rust_name}.as_ref() }}.unwrap().{len_field_rust_name}, "
+                f'"The rewritten arguments can\'t change the length of {vk_xml_param.name}.");'
+            )
+        return lines
+
+    def is_recorded(self) -> bool:
+        """Whether the command is recorded into the command IR when the recording is enabled."""
+        return (
+            self.vk_xml_command.get_dispatch_chain_type() == DispatchChainType.DEVICE
+            and self.vk_xml_command.name.startswith("vkCmd")
+        )
+
+    # Returns the expression that creates the arguments struct from the intercepted parameters.
+    def __generate_command_args_expr(self, intercept_params: list[str]) -> str:
+        args_info = self.get_command_args_info()
+        field_names = set(name for name, _ in args_info.fields)
+        field_exprs: list[str] = []
+        for intercept_param in intercept_params:
+            if intercept_param in field_names:
+                field_exprs.append(intercept_param)
+            elif match := re.fullmatch(r"(\w+) == vk::TRUE", intercept_param):
+                field_exprs.append(f"{match.group(1)}: {intercept_param}")
+            elif match := re.fullmatch(
+                r"unsafe \{ (\w+)\.as_ref\(\) \}\.unwrap\(\)", intercept_param
+            ):
+                field_exprs.append(f"{match.group(1)}: *{intercept_param}")
+            elif match := re.fullmatch(r"unsafe \{ (\w+)\.as_ref\(\) \}", intercept_param):
+                field_exprs.append(f"{match.group(1)}: {intercept_param}.copied()")
+            elif match := re.fullmatch(
+                r"unsafe \{ slice_from_raw_parts\((\w+)( as \*const u8)?, .*\) \}",
+                intercept_param,
+            ):
+                field_exprs.append(f"{match.group(1)}: {intercept_param}.to_vec()")
+            elif match := re.fullmatch(
+                r"unsafe \{ maybe_slice_from_raw_parts\((\w+)( as \*const u8)?, .*\) \}",
+                intercept_param,
+            ):
+                field_exprs.append(f"{match.group(1)}: {intercept_param}.map(<[_]>::to_vec)")
+            elif match := re.fullmatch(
+                r"unsafe \{ bool_iterator_from_raw_parts\((\w+), .*\) \}", intercept_param
+            ):
+                field_exprs.append(f"{match.group(1)}: {intercept_param}.collect()")
+            else:
+                assert False, (
+                    f"Unsupported recorded parameter {intercept_param} in "
+                    f"{self.vk_xml_command.name}"
+                )
+        return f"command_args::{args_info.struct_name} {{ {', '.join(field_exprs)} }}"
+
+    def get_replay_lines(self) -> list[str]:
+        """Returns the lines that record the command in a RecordedCommand::replay_impl match arm.
+
+        `args` refers to the recorded arguments, `command_buffer` to the command buffer to record
+        into, and `dispatch_table` to the dispatch table that provides the command.
+        """
+        prelude_lines, ffi_exprs = self.__generate_continue_args(self.get_intercept_params())
+        ffi_exprs[self.rust_fn.parameters[0].name] = self.rust_fn.parameters[0].name
+        args = [ffi_exprs.get(param.name, param.name) for param in self.rust_fn.parameters]
+        next_call_expr = f"unsafe {{ (dispatch_table.{self.rust_fn.name})({', '.join(args)}) }}"
+        if self.rust_fn.return_type == "()":
+            return prelude_lines + [f"{next_call_expr};"]
+        assert self.vk_xml_command.return_type == "VkResult", (
+            f"Unsupported return type {self.vk_xml_command.return_type} of "
+            f"{self.vk_xml_command.name}"
+        )
+        return prelude_lines + [
+            f"let res = {next_call_expr};",
+            "res.result().map_err(ReplayError::CommandFailed)?;",
+        ]
 
     def __find_param_by_name(self, name: str) -> Optional[tuple[VkXmlParam, RustParam]]:
         all_params = zip(self.vk_xml_command.parameters, self.rust_fn.parameters)
         return next((param for param in all_params if param[0].name == name), None)
 
+    # Return the expression of the length of the FFI return parameter, and whether the length is
+    # returned through a pointer parameter, e.g. pSwapchainImageCount of vkGetSwapchainImagesKHR.
+    def __generate_ret_param_len_expr(self, ret_xml_param: VkXmlParam) -> tuple[str, bool]:
+        need_to_assign_len_param = False
+        if "->" in ret_xml_param.len_var:
+            len_components = ret_xml_param.len_var.split("->")
+            assert (
+                len(len_components) == 2
+            ), f"Unsupported length parameter: {ret_xml_param.len_var}"
+            len_struct_name, len_field_name = len_components
+            len_struct_param = self.__find_param_by_name(len_struct_name)
+            assert (
+                len_struct_param is not None
+            ), f"Failed to find the length parameter: {len_struct_name}"
+            len_struct_xml_param, len_struct_rust_param = len_struct_param
+            assert not len_struct_xml_param.type.is_optional, (
+                f"The length of {ret_xml_param.name}, {len_struct_name} in "
+                f"{ret_xml_param.len_var}, is optional. This is currently not supported."
+            )
+            len_expr = (
+                f"unsafe {{ {len_struct_rust_param.name}.as_ref().unwrap() }}."
+                f"{camel_case_to_snake_case(len_field_name)}.try_into().unwrap()"
+            )
+        else:
+            len_param = self.__find_param_by_name(ret_xml_param.len_var)
+            assert (
+                len_param is not None
+            ), f"Failed to find the length parameter: {ret_xml_param.len_var}."
+            len_xml_param, len_rust_param = len_param
+            len_xml_type = len_xml_param.type
+            assert not len_xml_type.is_optional, "Can't handle optional length for now."
+            if len_xml_type.name in ["uint32_t", "size_t"]:
+                len_expr = len_rust_param.name
+                if len_xml_type.name != "size_t":
+                    len_expr = f"{len_rust_param.name}.try_into().unwrap()"
+            elif len_xml_type.points_to is not None and len_xml_type.points_to.name in [
+                "uint32_t",
+                "size_t",
+            ]:
+                len_expr = len_rust_param.name
+                need_to_assign_len_param = True
+            else:
+                assert False, f"Unhandled length parameter type: {len_xml_type}"
+        return len_expr, need_to_assign_len_param
+
     # Return lines of code to assign the variable of ret_var to the FFI return parameter correctly,
     # e.g. assign the result of create_image to the p_image pointer of the FFI parameter.
     def __generate_assign_to_ret_param_lines(
@@ -314,48 +783,8 @@ class VulkanCommand(NamedTuple):
         ret_xml_param = self.vk_xml_command.parameters[ret_param_index]
         ret_rust_param = self.rust_fn.parameters[ret_param_index]
         ret_param_xml_type = ret_xml_param.type
-        need_to_assign_len_param = False
         if ret_param_xml_type.len == VkXmlLenKind.VARIABLE:
-            len_expr: str = None
-            if "->" in ret_xml_param.len_var:
-                len_components = ret_xml_param.len_var.split("->")
-                assert (
-                    len(len_components) == 2
-                ), f"Unsupported length parameter: {ret_xml_param.len_var}"
-                len_struct_name, len_field_name = len_components
-                len_struct_param = self.__find_param_by_name(len_struct_name)
-                assert (
-                    len_struct_param is not None
-                ), f"Failed to find the length parameter: {len_struct_name}"
-                len_struct_xml_param, len_struct_rust_param = len_struct_param
-                assert not len_struct_xml_param.type.is_optional, (
-                    f"The length of {ret_xml_param.name}, {len_struct_name} in "
-                    f"{ret_xml_param.len_var}, is optional. This is currently not supported."
-                )
-                len_expr = (
-                    f"unsafe {{ {len_struct_rust_param.name}.as_ref().unwrap() }}."
-                    f"{camel_case_to_snake_case(len_field_name)}.try_into().unwrap()"
-                )
-            else:
-                len_param = self.__find_param_by_name(ret_xml_param.len_var)
-                assert (
-                    len_param is not None
-                ), f"Failed to find the length parameter: {ret_xml_param.len_var}."
-                len_xml_param, len_rust_param = len_param
-                len_xml_type = len_xml_param.type
-                assert not len_xml_type.is_optional, "Can't handle optional length for now."
-                if len_xml_type.name in ["uint32_t", "size_t"]:
-                    len_expr = len_rust_param.name
-                    if len_xml_type.name != "size_t":
-                        len_expr = f"{len_rust_param.name}.try_into().unwrap()"
-                elif len_xml_type.points_to is not None and len_xml_type.points_to.name in [
-                    "uint32_t",
-                    "size_t",
-                ]:
-                    len_expr = len_rust_param.name
-                    need_to_assign_len_param = True
-                else:
-                    assert False, f"Unhandled length parameter type: {len_xml_type}"
+            len_expr, need_to_assign_len_param = self.__generate_ret_param_len_expr(ret_xml_param)
 
             lines = []
             if ret_param_xml_type.points_to.name == "void":
@@ -412,12 +841,86 @@ class VulkanCommand(NamedTuple):
         else:
             assert False, f"Unsupported return type with length type: {ret_param_xml_type.len}"
 
+    # Return the expression to read the FFI return parameter after the next layer returns, which is
+    # the reverse of __generate_assign_to_ret_param_lines, e.g. read the VkImage from the p_image
+    # pointer of the FFI parameter after vkCreateImage returns. If the length of the returned array
+    # is returned through a pointer parameter, the caller may only query the length, so the
+    # condition for the array to be available is returned as well.
+    def __generate_read_ret_param_expr(self, ret_param_index: int) -> tuple[str, Optional[str]]:
+        assert ret_param_index < len(
+            self.vk_xml_command.parameters
+        ), "Parameter index out of range."
+        ret_xml_param = self.vk_xml_command.parameters[ret_param_index]
+        ret_rust_param = self.rust_fn.parameters[ret_param_index]
+        ret_param_xml_type = ret_xml_param.type
+        if ret_param_xml_type.len == VkXmlLenKind.VARIABLE:
+            assert (
+                ret_param_xml_type.points_to.name != "void"
+            ), f"Returning void data from {self.vk_xml_command.name} is not supported."
+            len_expr, need_to_assign_len_param = self.__generate_ret_param_len_expr(ret_xml_param)
+            if not need_to_assign_len_param:
+                return (
+                    f"unsafe {{ std::slice::from_raw_parts({ret_rust_param.name}, {len_expr}) }}",
+                    None,
+                )
+            len_xml_param, _ = self.__find_param_by_name(ret_xml_param.len_var)
+            len_expr = f"*unsafe {{ {len_expr}.as_ref() }}.unwrap()"
+            if len_xml_param.type.points_to.name != "size_t":
+                len_expr = f"({len_expr}).try_into().unwrap()"
+            return (
+                f"unsafe {{ std::slice::from_raw_parts({ret_rust_param.name}, {len_expr}) }}",
+                f"!{ret_rust_param.name}.is_null()",
+            )
+        elif ret_param_xml_type.len is None:
+            read_expr = f"*unsafe {{ {ret_rust_param.name}.as_ref() }}.unwrap()"
+            if ret_param_xml_type.points_to.name == "VkRemoteAddressNV":
+                # See RustMethod.get_after_hook_result_params.
+                read_expr = (
+                    f"*unsafe {{ {ret_rust_param.name}.cast::<*mut c_void>().as_ref() }}.unwrap()"
+                )
+            elif ret_param_xml_type.points_to.name == "VkBool32":
+                read_expr = f"{read_expr} == vk::TRUE"
+            elif (
+                ret_param_xml_type.points_to.points_to is not None
+                and ret_param_xml_type.points_to.is_optional
+            ):
+                read_expr = f"NonNull::new({read_expr}).map(NonNull::as_ptr)"
+            return read_expr, None
+        else:
+            assert False, f"Unsupported return type with length type: {ret_param_xml_type.len}"
+
+    def get_intercept_params(self, for_after_hook: bool = False) -> list[str]:
+        """Returns the expressions of the parameters passed to the hooks.
+
+        If for_after_hook is True, the outputs are read as initialized values, see
+        RustMethod.get_after_hook_parameters."""
+        vk_xml_to_rust_method_info = VkXmlToRustMethodInfo.from_vk_xml_command(self.vk_xml_command)
+        intercept_params: list[str] = []
+        param_transformer = ParamTransformer.create(for_after_hook)
+        for param in vk_xml_to_rust_method_info.parameters:
+            rust_param = param.rust_method_param
+            xml_param = param.main_source_vk_xml_param
+            assert param_transformer.type_matches(
+                rust_param.type, xml_param.type, self.vk_xml_command
+            ), (
+                f"Failed to transform parameter {xml_param.name}, {rust_param.name} in "
+                f"{self.vk_xml_command.name}."
+            )
+            arg_exp = param_transformer.transform(rust_param, xml_param, self.vk_xml_command)
+            intercept_params.append(arg_exp)
+        return intercept_params
+
     def get_rust_fn_impl_lines(self, dispatch_infos: list[CommandDispatchInfo]) -> list[str]:
         lines = ["let global = Self::instance();"]
         lines.append(f"// {self.vk_xml_command.name}")
         assert (
             len(self.vk_xml_command.parameters) > 0
         ), "Vulkan commands should have at least one parameter."
+        # The span lives until the trampoline returns, so it covers the hooks and the next layer.
+        lines.append(
+            f'let span = command_span!("{self.vk_xml_command.name}", '
+            f"{self.rust_fn.parameters[0].name});"
+        )
         dispatch_chain_type = self.vk_xml_command.get_dispatch_chain_type()
         dispatch_chain_var = None
         if dispatch_chain_type == DispatchChainType.DEVICE:
@@ -445,23 +948,35 @@ class VulkanCommand(NamedTuple):
                 f"let dispatch_table = &{dispatch_chain_var}.dispatch_table."
                 f"{dispatch_info.get_dispatch_table_field_name()};"
             ),
+            # The observer and the statistics cover the hooks and the next layer.
+            (
+                "let observation = global.enter_command("
+                f"LayerVulkanCommand::{self.get_command_args_info().variant_name}, "
+                f"{self.rust_fn.parameters[0].name}, {dispatch_chain_var}.stats.as_ref());"
+            ),
         ]
+        # The handles are used by the current thread until the trampoline returns.
+        if len(self.extern_sync_lines) > 0:
+            lines.append(
+                "let _extern_sync = global.enter_extern_sync("
+                f"LayerVulkanCommand::{self.get_command_args_info().variant_name}, |objects| {{"
+            )
+            lines += ["    " + line for line in self.extern_sync_lines]
+            lines.append("});")
+        # The injected fault skips the hooks and the next layer.
+        if self.vk_xml_command.return_type == "VkResult":
+            lines += [
+                (
+                    "if let Some(result) = global.inject_fault("
+                    f"LayerVulkanCommand::{self.get_command_args_info().variant_name}) {{"
+                ),
+                "    return observation.exit(span.record_result(result));",
+                "}",
+            ]
 
         vk_xml_to_rust_method_info = VkXmlToRustMethodInfo.from_vk_xml_command(self.vk_xml_command)
 
-        intercept_params: list[str] = []
-        param_transformer = ParamTransformer.create()
-        for param in vk_xml_to_rust_method_info.parameters:
-            rust_param = param.rust_method_param
-            xml_param = param.main_source_vk_xml_param
-            assert param_transformer.type_matches(
-                rust_param.type, xml_param.type, self.vk_xml_command
-            ), (
-                f"Failed to transform parameter {xml_param.name}, {rust_param.name} in "
-                f"{self.vk_xml_command.name}."
-            )
-            arg_exp = param_transformer.transform(rust_param, xml_param, self.vk_xml_command)
-            intercept_params.append(arg_exp)
+        intercept_params = self.get_intercept_params()
 
         param_names = [param.name for param in self.vk_xml_command.parameters]
 
@@ -502,7 +1017,119 @@ class VulkanCommand(NamedTuple):
 
             return [ret_var]
 
+        # Returns the expressions of the results passed to the after hook, and the condition to
+        # call the after hook. If the command returns VkResult, the output is only read if the
+        # command succeeds, including the success codes other than VK_SUCCESS, e.g.
+        # VK_SUBOPTIMAL_KHR, and the raw VkResult is passed as well.
+        def generate_after_hook_result_exprs(ret_var: str) -> tuple[list[str], Optional[str]]:
+            return_info = vk_xml_to_rust_method_info.return_info
+            if self.vk_xml_command.return_type == "VkResult":
+                if len(return_info.main_source_vk_xml_params) == 0:
+                    return [ret_var], None
+                assert len(return_info.main_source_vk_xml_params) == 1
+                param_index = param_names.index(return_info.main_source_vk_xml_params[0].name)
+                read_expr, condition = self.__generate_read_ret_param_expr(param_index)
+                succeeded_expr = f"{ret_var}.as_raw() >= 0"
+                if condition is not None:
+                    succeeded_expr = f"{succeeded_expr} && {condition}"
+                return [f"({succeeded_expr}).then(|| {read_expr})", ret_var], None
+            elif self.vk_xml_command.return_type == "void":
+                if len(return_info.main_source_vk_xml_params) == 0:
+                    return [], None
+                assert len(return_info.main_source_vk_xml_params) == 1
+                param_index = param_names.index(return_info.main_source_vk_xml_params[0].name)
+                read_expr, condition = self.__generate_read_ret_param_expr(param_index)
+                return [read_expr], condition
+            elif self.vk_xml_command.return_type == "VkBool32":
+                return [f"{ret_var} == vk::TRUE"], None
+            return [ret_var], None
+
         rust_ffi_param_names = [param.name for param in self.rust_fn.parameters]
+
+        def generate_next_call_expr(args: list[str]) -> str:
+            return (
+                "observation.call_next(|| unsafe { "
+                f"(dispatch_table.{self.rust_fn.name})({', '.join(args)}) }})"
+            )
+
+        # Returns the expressions of the parameters passed to the after hook. The outputs of a
+        # command that returns VkResult are only read if the command succeeds.
+        def generate_after_hook_params(ret_var: str) -> list[str]:
+            after_hook_params: list[str] = []
+            for param, after_hook_param, expr in zip(
+                self.rust_method.parameters,
+                self.rust_method.get_after_hook_parameters(),
+                self.get_intercept_params(for_after_hook=True),
+            ):
+                if param.type != after_hook_param.type and self.vk_xml_command.return_type == (
+                    "VkResult"
+                ):
+                    if param.type.is_optional:
+                        expr = f"({ret_var}.as_raw() >= 0).then(|| {expr}).flatten()"
+                    elif after_hook_param.type.is_optional:
+                        expr = f"({ret_var}.as_raw() >= 0).then(|| {expr})"
+                after_hook_params.append(expr)
+            return after_hook_params
+
+        after_hook_result_exprs, after_hook_condition = generate_after_hook_result_exprs("res")
+        after_hook_args = generate_after_hook_params("res") + after_hook_result_exprs
+        # Don't hold the hooks across the call to the next layer, because HooksRefType can be a lock
+        # guard.
+        after_hook_lines = [
+            (
+                f"{dispatch_chain_var}.customized_info.borrow().hooks()."
+                f"{self.rust_method.get_after_hook_name()}({', '.join(after_hook_args)});"
+            )
+        ]
+        if after_hook_condition is not None:
+            after_hook_lines = (
+                [f"if {after_hook_condition} {{"]
+                + [4 * " " + line for line in after_hook_lines]
+                + ["}"]
+            )
+
+        # Records the arguments created by command_args_expr after the next layer returns.
+        def generate_record_lines(command_args_expr: str) -> list[str]:
+            if not self.is_recorded():
+                return []
+            args_info = self.get_command_args_info()
+            record_line = (
+                f"global.record_command(LayerVulkanCommand::{args_info.variant_name}, "
+                f"{rust_ffi_param_names[0]}, || RecordedCommand::"
+                f"{args_info.variant_name}(unsafe {{ DeepCopied::new({command_args_expr}) }}));"
+            )
+            if self.rust_fn.return_type == "()":
+                return [record_line]
+            return ["if res == vk::Result::SUCCESS {", f"    {record_line}", "}"]
+
+        # The after hook always observes the parameters passed to the intercepted command.
+        def generate_call_next_lines(args: list[str], command_args_expr: str) -> list[str]:
+            next_call_expr = generate_next_call_expr(args)
+            record_lines = generate_record_lines(command_args_expr)
+            if self.rust_fn.return_type == "()":
+                return [f"{next_call_expr};"] + record_lines + after_hook_lines
+            return [f"let res = {next_call_expr};"] + record_lines + after_hook_lines + ["res"]
+
+        unhandled_lines = generate_call_next_lines(
+            rust_ffi_param_names,
+            self.__generate_command_args_expr(intercept_params) if self.is_recorded() else "",
+        )
+        args_info = self.get_command_args_info()
+        args_var = "args" if len(args_info.fields) > 0 else "_"
+        continue_prelude_lines, continue_ffi_exprs = self.__generate_continue_args(
+            intercept_params
+        )
+        continue_lines = self.__generate_output_len_assert_lines() + continue_prelude_lines
+        continue_lines += generate_call_next_lines(
+            [continue_ffi_exprs.get(name, name) for name in rust_ffi_param_names], "args"
+        )
+        # The value returned to the caller is reported to the observer, and recorded in the span
+        # unless the command returns void.
+        match_prefix, match_suffix = "", ""
+        exit_lines = ["observation.exit(());"]
+        if self.rust_fn.return_type != "()":
+            match_prefix, match_suffix = "observation.exit(span.record_result(", "))"
+            exit_lines = []
         lines += (
             [
                 (
@@ -510,18 +1137,26 @@ class VulkanCommand(NamedTuple):
                     f" {dispatch_chain_var}.customized_info.borrow().hooks().{self.rust_fn.name}("
                     f"{', '.join(intercept_params)});"
                 ),
-                "match layer_result {",
+                "span.record_handled(matches!(layer_result, LayerResult::Handled(_)));",
+                f"{match_prefix}match layer_result {{",
                 "    LayerResult::Handled(res) => {",
             ]
             + [8 * " " + line for line in generate_ret_expr("res")]
             + [
                 "    }",
-                (
-                    f"    LayerResult::Unhandled => unsafe {{ (dispatch_table.{self.rust_fn.name})("
-                    f"{', '.join(rust_ffi_param_names)}) }},"
-                ),
-                "}",
+                "    LayerResult::Unhandled => {",
             ]
+            + [8 * " " + line for line in unhandled_lines]
+            + [
+                "    }",
+                f"    LayerResult::Continue({args_var}) => {{",
+            ]
+            + [8 * " " + line for line in continue_lines]
+            + [
+                "    }",
+                f"}}{match_suffix}",
+            ]
+            + exit_lines
         )
         return lines
 
@@ -552,6 +1187,18 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
                 "vkEnumerateDeviceLayerProperties",
             ]
         )
+        # Commands whose generated implementation is wrapped by a manually implemented function to
+        # track the VkQueue and VkCommandBuffer handles.
+        self.object_tracking_cmd: dict[str, str] = {
+            "vkGetDeviceQueue": "get_device_queue_and_track",
+            "vkGetDeviceQueue2": "get_device_queue2_and_track",
+            "vkAllocateCommandBuffers": "allocate_command_buffers_and_track",
+            "vkBeginCommandBuffer": "begin_command_buffer_and_track",
+            "vkResetCommandBuffer": "reset_command_buffer_and_track",
+            "vkResetCommandPool": "reset_command_pool_and_track",
+            "vkFreeCommandBuffers": "free_command_buffers_and_track",
+            "vkDestroyCommandPool": "destroy_command_pool_and_track",
+        }
         self.command_aliases: VulkanAliases = VulkanAliases()
 
     def beginFile(self, gen_opts):
@@ -567,21 +1214,23 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
                     "#![allow(unused_unsafe)]",
                     (
                         "use std::{borrow:: Borrow, ffi::{c_int, c_void, c_char, CStr},"
-                        " ptr::NonNull, sync::Arc, collections::HashSet};"
+                        " ptr::NonNull, sync::Arc};"
                     ),
                     "use ash::vk;",
-                    "use smallvec::smallvec;",
                     "",
                     (
                         "use crate::{DeviceInfo, fill_vk_out_array, Global, InstanceInfo, Layer, "
                         "LayerResult, LayerVulkanCommand, InstanceHooks, DeviceHooks, "
-                        "vk_utils::{slice_from_raw_parts, ptr_as_uninit_mut}};"
+                        "vk_utils::{slice_from_raw_parts, ptr_as_uninit_mut}, "
+                        "command_ir::{DeepCopied, RecordedCommand}};"
                     ),
                     (
                         "use super::{get_instance_proc_addr_loader, get_device_proc_addr_loader, "
-                        "VulkanCommand, TryFromExtensionError, ApiVersion, Feature, "
+                        "VulkanCommand, TryFromExtensionError, ApiVersion, CommandLookupTable, Feature, "
                         "bool_iterator_from_raw_parts, maybe_slice_from_raw_parts, "
-                        "maybe_uninit_slice_from_raw_parts_mut, uninit_slice_from_raw_parts_mut};"
+                        "maybe_output_slice_from_raw_parts, maybe_uninit_slice_from_raw_parts_mut, "
+                        "uninit_slice_from_raw_parts_mut, "
+                        "command_span};"
                     ),
                     "",
                 ]
@@ -589,9 +1238,10 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
         )
         self.newline()
 
-    def endFile(self):
+    # Points the aliased commands to the same VulkanCommand, and returns the names of the aliased
+    # commands, and the commands that are not aliases.
+    def resolve_command_aliases(self) -> tuple[set[str], list[VulkanCommand]]:
         all_commands = self.instance_commands | self.device_commands
-        # Handle aliases
         for command in all_commands:
             represent_name = self.command_aliases.get_represent_name(command)
             assert represent_name is not None, f"{command} is never added to the command aliases."
@@ -612,6 +1262,10 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
         not_aliased_commands = [
             cmd for name, cmd in all_commands.items() if name not in aliased_commands
         ]
+        return aliased_commands, not_aliased_commands
+
+    def endFile(self):
+        aliased_commands, not_aliased_commands = self.resolve_command_aliases()
         # Not all dispatch will be used. For some extension, e.g. VK_EXT_shader_object, all commands
         # are in other extensions like VK_EXT_extended_dynamic_state, so we don't need to create a
         # dispatch table for it.
@@ -653,7 +1307,6 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
             indent: int,
             commands: dict[str, VulkanCommand],
             dispatch_infos: dict[str, CommandDispatchInfo],
-            hooked_commands_var: str,
         ) -> str:
             command_to_dispatch_infos: dict[str, list[CommandDispatchInfo]] = {}
             for dispatch_info in dispatch_infos.values():
@@ -676,11 +1329,24 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
                 "vkEnumeratePhysicalDeviceGroups",
                 "vkEnumeratePhysicalDeviceGroupsKHR",
                 "vkEnumeratePhysicalDevices",
+                "vkGetDeviceQueue",
+                "vkGetDeviceQueue2",
+                "vkAllocateCommandBuffers",
+                "vkBeginCommandBuffer",
+                "vkResetCommandBuffer",
+                "vkResetCommandPool",
+                "vkFreeCommandBuffers",
+                "vkDestroyCommandPool",
             ]
             for proc_name, command in command_items:
                 # Use the actual name, because ash doesn't generate type names for aliased types.
                 fp_type_name = f"vk::PFN_{command.vk_xml_command.name}"
                 rust_fn_name = f"Self::{command.rust_fn.name}"
+                if command.vk_xml_command.name in self.object_tracking_cmd:
+                    # The manually implemented function calls into the generated one, and updates
+                    # the tracked objects. Aliases share the same function.
+                    tracking_fn_name = self.object_tracking_cmd[command.vk_xml_command.name]
+                    rust_fn_name = f"Self::{tracking_fn_name}"
                 features = [
                     dispatch_info.get_enum_name()
                     for dispatch_info in command_to_dispatch_infos[proc_name]
@@ -689,19 +1355,16 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
                     len(features) > 0
                 ), "Every command must have at least one dispatch_info associated."
                 hook_command_variant_value = snake_case_to_upper_camel_case(command.rust_fn.name)
-                hooked_expr: str = ""
+                command_expr: str = ""
                 if proc_name in always_hooked_commands:
-                    hooked_expr = "true"
+                    command_expr = "None"
                 else:
-                    hooked_expr = (
-                        f"{hooked_commands_var}.contains("
-                        f"&LayerVulkanCommand::{hook_command_variant_value})"
-                    )
+                    command_expr = f"Some(LayerVulkanCommand::{hook_command_variant_value})"
                 lines += [
                     "VulkanCommand {",
                     f'    name: "{proc_name}",',
-                    f'    features: smallvec![{", ".join(features)}],',
-                    f"    hooked: {hooked_expr},",
+                    f'    features: &[{", ".join(features)}],',
+                    f"    command: {command_expr},",
                     (
                         f"    proc: unsafe {{ std::mem::transmute::<{fp_type_name},"
                         f" vk::PFN_vkVoidFunction>({rust_fn_name})}},"
@@ -710,6 +1373,48 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
                 ]
             return "".join([" " * indent + line + "\n" for line in lines])
 
+        def generate_command_lookup_table(static_name: str, commands: dict[str, VulkanCommand]):
+            # The keys must be in the same order as the entries generated by
+            # generate_vulkan_command_entries.
+            table = build_perfect_hash_table(sorted(commands.keys()))
+            displacements = ", ".join(f"({d1}, {d2})" for d1, d2 in table.displacements)
+            indices = ", ".join(str(index) for index in table.indices)
+            return "\n".join(
+                [
+                    f"pub(crate) static {static_name}: CommandLookupTable = CommandLookupTable {{",
+                    f"    seed: {table.seed},",
+                    f"    displacements: &[{displacements}],",
+                    f"    indices: &[{indices}],",
+                    "};",
+                    "",
+                ]
+            )
+
+        self.newline()
+        self.outFile.write(
+            generate_command_lookup_table("DEVICE_COMMAND_LOOKUP_TABLE", self.device_commands)
+        )
+        self.newline()
+        self.outFile.write(
+            generate_command_lookup_table("INSTANCE_COMMAND_LOOKUP_TABLE", self.instance_commands)
+        )
+        self.newline()
+
+        self.outFile.write(
+            "\n".join(
+                [
+                    "/// The intercepted commands that return a `VkResult`, which faults can be injected into.",
+                    "pub(crate) const FAULT_INJECTABLE_COMMANDS: &[LayerVulkanCommand] = &[",
+                ]
+                + [
+                    f"    LayerVulkanCommand::{vulkan_command.get_command_args_info().variant_name},"
+                    for vulkan_command in not_aliased_commands
+                    if vulkan_command.vk_xml_command.name not in self.manually_implemented_cmd
+                    and vulkan_command.vk_xml_command.return_type == "VkResult"
+                ]
+                + ["];", ""]
+            )
+        )
         self.newline()
 
         self.outFile.write(
@@ -717,53 +1422,66 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
                 [
                     "impl<T: Layer> Global<T> {",
                     (
-                        "    pub(crate) fn create_device_commands(&self, instance_info:"
-                        " &T::InstanceInfo, device_info: Option<&T::DeviceInfo>) ->"
-                        " Box<[VulkanCommand]> {"
+                        "    /// All device commands sorted by name. Use [`DEVICE_COMMAND_LOOKUP_TABLE`]"
+                        " to look up."
                     ),
-                    "        let hooked_commands = self.layer_info",
-                    "            .hooked_device_commands(instance_info, device_info)",
-                    "            .collect::<HashSet<_>>();",
-                    "        Box::new([",
+                    "    pub(crate) const DEVICE_COMMANDS: &'static [VulkanCommand] = &[",
                 ]
                 + [""]
             )
         )
         self.outFile.write(
-            generate_vulkan_command_entries(
-                12, self.device_commands, self.dispatch_infos, "hooked_commands"
-            )
+            generate_vulkan_command_entries(8, self.device_commands, self.dispatch_infos)
         )
-        self.outFile.write("        ])\n")
-        self.outFile.write("    }\n")
+        self.outFile.write("    ];\n")
+        self.newline()
 
         self.outFile.write(
             "\n".join(
                 [
                     (
-                        "    pub(crate) fn create_instance_commands(&self, instance_info:"
-                        " &T::InstanceInfo) -> Box<[VulkanCommand]> {"
+                        "    /// All instance commands sorted by name. Use"
+                        " [`INSTANCE_COMMAND_LOOKUP_TABLE`] to look up."
                     ),
-                    "        let hooked_commands = self.layer_info",
-                    "            .hooked_instance_commands(instance_info)",
-                    "            .collect::<HashSet<_>>();",
-                    "        Box::new([",
+                    "    pub(crate) const INSTANCE_COMMANDS: &'static [VulkanCommand] = &[",
                 ]
                 + [""]
             )
         )
         self.outFile.write(
-            generate_vulkan_command_entries(
-                12, self.instance_commands, self.dispatch_infos, "hooked_commands"
+            generate_vulkan_command_entries(8, self.instance_commands, self.dispatch_infos)
+        )
+        self.outFile.write("    ];\n")
+        self.newline()
+
+        self.outFile.write(
+            "\n".join(
+                [
+                    (
+                        "    /// The intercepted commands with handles checked by the thread safety"
+                        " checker, either"
+                    ),
+                    "    /// externally synchronized or only read by the command.",
+                    "    pub(crate) const EXTERN_SYNC_COMMANDS: &'static [LayerVulkanCommand] = &[",
+                ]
+                + [
+                    f"        LayerVulkanCommand::{vulkan_command.get_command_args_info().variant_name},"
+                    for vulkan_command in not_aliased_commands
+                    if vulkan_command.vk_xml_command.name not in self.manually_implemented_cmd
+                    and len(vulkan_command.extern_sync_lines) > 0
+                ]
+                + ["    ];", ""]
             )
         )
-        self.outFile.write("        ])\n")
-        self.outFile.write("    }\n")
 
         for vulkan_command in not_aliased_commands:
             if vulkan_command.vk_xml_command.name in self.manually_implemented_cmd:
                 continue
-            self.outFile.write(f"    {vulkan_command.rust_fn.get_def_str()} {{\n")
+            visibility = ""
+            if vulkan_command.vk_xml_command.name in self.object_tracking_cmd:
+                # Called by the manually implemented function in the parent module.
+                visibility = "pub(crate) "
+            self.outFile.write(f"    {visibility}{vulkan_command.rust_fn.get_def_str()} {{\n")
             impl = "".join(
                 [
                     " " * 8 + line + "\n"
@@ -775,6 +1493,20 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
             self.outFile.write(impl)
             self.outFile.write("    }\n")
         self.outFile.write("}\n")
+        self.newline()
+
+        self.outFile.write(
+            "\n".join(
+                CommandArgsInfo.get_command_args_mod_lines(
+                    [
+                        vulkan_command.get_command_args_info()
+                        for vulkan_command in not_aliased_commands
+                        if vulkan_command.vk_xml_command.name not in self.manually_implemented_cmd
+                    ]
+                )
+            )
+        )
+        self.newline()
 
         super().endFile()
 
@@ -797,6 +1529,9 @@ class GlobalSimpleInterceptGenerator(OutputGenerator):
             vk_xml_command=vk_xml_cmd,
             rust_fn=rust_ffi_function,
             rust_method=RustMethod.from_vk_xml_command(vk_xml_cmd),
+            extern_sync_lines=VulkanCommand.generate_extern_sync_lines(
+                vk_xml_cmd, rust_ffi_function, self.types
+            ),
         )
 
         self.command_aliases.add_alias(name, alias)
@@ -856,31 +1591,37 @@ class ParamTransformer(ABC):
         raise NotImplementedError
 
     @staticmethod
-    def create() -> ParamTransformer:
+    def create(for_after_hook: bool = False) -> ParamTransformer:
+        """Creates the transformer of the parameters passed to the hooks.
+
+        If for_after_hook is True, the outputs are transformed to initialized references and
+        slices, because the next layer has written them when the after hook is called."""
         return ComposedParamTransformer(
             [
                 ArrayParamTransformer(),
                 BoolParamTransformer(),
                 PrimitiveParamTransformer(),
-                NoLenPtrParamTransformer(),
+                NoLenPtrParamTransformer(for_after_hook),
                 NullTerminatedLenPtrParamTransformer(),
-                VoidDataSliceParamTransformer(),
+                VoidDataSliceParamTransformer(for_after_hook),
                 BoolSliceParamTransformer(),
-                GeneralSliceParamTransformer(),
+                GeneralSliceParamTransformer(for_after_hook),
             ]
         )
 
     @staticmethod
-    def get_ptr_to_slice_fn(vk_xml_type: VkXmlType) -> str:
+    def get_ptr_to_slice_fn(vk_xml_type: VkXmlType, for_after_hook: bool = False) -> str:
         element_xml_type = vk_xml_type.points_to
         assert element_xml_type is not None
         if vk_xml_type.is_optional:
             if element_xml_type.is_const:
                 return "maybe_slice_from_raw_parts"
+            elif for_after_hook:
+                return "maybe_output_slice_from_raw_parts"
             else:
                 return "maybe_uninit_slice_from_raw_parts_mut"
         else:
-            if element_xml_type.is_const:
+            if element_xml_type.is_const or for_after_hook:
                 return "slice_from_raw_parts"
             else:
                 return "uninit_slice_from_raw_parts_mut"
@@ -936,6 +1677,10 @@ class PrimitiveParamTransformer(ParamTransformer):
 
 
 class NoLenPtrParamTransformer(ParamTransformer):
+    def __init__(self, for_after_hook: bool = False):
+        super().__init__()
+        self.for_after_hook = for_after_hook
+
     def type_matches(
         self, rust_type: RustType, vk_xml_type: VkXmlType, vk_xml_command: VkXmlCommand
     ) -> bool:
@@ -951,7 +1696,7 @@ class NoLenPtrParamTransformer(ParamTransformer):
             return ffi_param_var
 
         def get_ptr_to_opt_ref_expr() -> str:
-            if vk_xml_param.type.points_to.is_const:
+            if vk_xml_param.type.points_to.is_const or self.for_after_hook:
                 return f"unsafe {{ {ffi_param_var}.as_ref() }}"
             return f"unsafe {{ ptr_as_uninit_mut({ffi_param_var}) }}"
 
@@ -1012,6 +1757,10 @@ class BoolSliceParamTransformer(ParamTransformer):
 
 
 class VoidDataSliceParamTransformer(ParamTransformer):
+    def __init__(self, for_after_hook: bool = False):
+        super().__init__()
+        self.for_after_hook = for_after_hook
+
     def type_matches(
         self, rust_type: RustType, vk_xml_type: VkXmlType, vk_xml_command: VkXmlCommand
     ) -> bool:
@@ -1030,7 +1779,9 @@ class VoidDataSliceParamTransformer(ParamTransformer):
         len_var_xml_param = params_by_name.get(vk_xml_param.len_var)
         assert len_var_xml_param is not None
         assert not len_var_xml_param.type.is_optional
-        ptr_to_slice_fn = ParamTransformer.get_ptr_to_slice_fn(vk_xml_param.type)
+        ptr_to_slice_fn = ParamTransformer.get_ptr_to_slice_fn(
+            vk_xml_param.type, self.for_after_hook
+        )
         rust_ffi_len_var_name = RustParam.param_name_from_vk_xml_param_for_ffi(
             len_var_xml_param.name
         )
@@ -1046,13 +1797,21 @@ class VoidDataSliceParamTransformer(ParamTransformer):
             len_expr = f"{rust_ffi_len_var_name}"
 
         rust_ffi_param_name = RustParam.param_name_from_vk_xml_param_for_ffi(vk_xml_param.name)
-        data_ptr_type = "*const u8" if vk_xml_param.type.points_to.is_const else "*mut u8"
+        data_ptr_type = (
+            "*const u8"
+            if vk_xml_param.type.points_to.is_const or self.for_after_hook
+            else "*mut u8"
+        )
         return (
             f"unsafe {{ {ptr_to_slice_fn}({rust_ffi_param_name} as {data_ptr_type}, {len_expr}) }}"
         )
 
 
 class GeneralSliceParamTransformer(ParamTransformer):
+    def __init__(self, for_after_hook: bool = False):
+        super().__init__()
+        self.for_after_hook = for_after_hook
+
     def type_matches(
         self, rust_type: RustType, vk_xml_type: VkXmlType, vk_xml_command: VkXmlCommand
     ) -> bool:
@@ -1127,7 +1886,9 @@ class GeneralSliceParamTransformer(ParamTransformer):
             assert False, f"Unsupported length variable type: {len_var_xml_param.type}"
 
         len_expr = generate_len_expr()
-        ptr_to_slice_fn = ParamTransformer.get_ptr_to_slice_fn(vk_xml_param.type)
+        ptr_to_slice_fn = ParamTransformer.get_ptr_to_slice_fn(
+            vk_xml_param.type, self.for_after_hook
+        )
         return f"unsafe {{ {ptr_to_slice_fn}({rust_ffi_param_name}, {len_expr}) }}"
 
 
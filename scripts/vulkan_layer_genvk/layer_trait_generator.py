@@ -49,7 +49,7 @@ class LayerTraitGenerator(OutputGenerator):
                     "",
                     "use ash::{vk, prelude::VkResult};",
                     "",
-                    "use crate::VkLayerDeviceLink;",
+                    "use crate::{command_args::*, ExternSync, ExternSyncParam, VkLayerDeviceLink};",
                     "use super::{LayerResult, TryFromVulkanCommandError};",
                 ]
             )
@@ -78,6 +78,7 @@ class LayerTraitGenerator(OutputGenerator):
             "#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Clone)]",
             "pub enum VulkanCommand {",
         ]
+        enum_variant_names: list[str] = []
         try_from_command_impl = [
             "impl TryFrom<&str> for VulkanCommand {",
             "    type Error = TryFromVulkanCommandError;",
@@ -85,25 +86,88 @@ class LayerTraitGenerator(OutputGenerator):
             "    fn try_from(value: &str) -> Result<Self, Self::Error> {",
             "        match value {",
         ]
+        extern_sync_params_impl = [
+            (
+                "    /// The parameters annotated with `externsync` in vk.xml, which the"
+                " application must"
+            ),
+            "    /// synchronize externally when calling the command.",
+            "    pub fn extern_sync_params(&self) -> &'static [ExternSyncParam] {",
+            "        match self {",
+        ]
+
+        # The layer framework returns its own function pointers from these commands instead of the
+        # results of the next layer, so there is nothing to observe.
+        commands_without_after_hooks = ["vkGetInstanceProcAddr", "vkGetDeviceProcAddr"]
+        # The layer framework handles these commands specially, so the parameters can't be
+        # rewritten with LayerResult::Continue.
+        commands_without_command_args = commands_without_after_hooks + ["vkCreateDevice"]
 
         for dispatch_type, commands in self.all_commands.items():
             for name, command in commands.items():
                 if name not in not_aliased_commands:
                     continue
+                enum_variant_name = snake_case_to_upper_camel_case(command.name)
                 hooks_trait_lines = dispatch_chain_type_to_lines.get(dispatch_type, None)
                 if hooks_trait_lines is not None:
+                    args_type = None
+                    if name not in commands_without_command_args:
+                        args_type = f"{enum_variant_name}Args"
                     hooks_trait_lines += [
-                        f"    {command.to_string()} {{",
+                        f"    {command.to_string(args_type)} {{",
                         "        LayerResult::Unhandled",
                         "    }",
                     ]
+                    if name not in commands_without_after_hooks:
+                        hooks_trait_lines.append(f"    {command.to_after_hook_string()} {{}}")
                 original_name = command.vk_xml_cmd.name
-                enum_variant_name = snake_case_to_upper_camel_case(command.name)
+                enum_variant_names.append(enum_variant_name)
                 command_enum.append(f"    {enum_variant_name},")
                 try_from_command_impl.append(
                     f'            "{original_name}" => Ok(VulkanCommand::{enum_variant_name}),'
                 )
-        command_enum += ["}"]
+                extern_sync_params = []
+                for param in command.vk_xml_cmd.parameters:
+                    if len(param.extern_sync) == 0:
+                        continue
+                    if param.extern_sync == ["true"]:
+                        extern_sync = "ExternSync::Param"
+                    else:
+                        exprs = ", ".join(f'"{expr}"' for expr in param.extern_sync)
+                        extern_sync = f"ExternSync::Members(&[{exprs}])"
+                    extern_sync_params.append(
+                        f'ExternSyncParam {{ name: "{param.name}", extern_sync: {extern_sync} }}'
+                    )
+                if len(extern_sync_params) > 0:
+                    extern_sync_params_impl.append(
+                        f"            VulkanCommand::{enum_variant_name} => "
+                        f"&[{', '.join(extern_sync_params)}],"
+                    )
+        extern_sync_params_impl += [
+            "            _ => &[],",
+            "        }",
+            "    }",
+        ]
+        command_enum += [
+            "}",
+            "",
+            "impl VulkanCommand {",
+            "    /// The number of the variants.",
+            f"    pub(crate) const COUNT: usize = {len(enum_variant_names)};",
+            "",
+            "    /// All the variants, ordered by the discriminant.",
+            "    pub(crate) const ALL: [VulkanCommand; VulkanCommand::COUNT] = [",
+            *(f"        VulkanCommand::{name}," for name in enum_variant_names),
+            "    ];",
+            "",
+            *extern_sync_params_impl,
+            "}",
+            "",
+            "// The variants are numbered from 0 without gaps, so the last one must be COUNT - 1.",
+            "const _: () = assert!(",
+            f"    VulkanCommand::{enum_variant_names[-1]} as usize == VulkanCommand::COUNT - 1",
+            ");",
+        ]
         try_from_command_impl += [
             "            _ => Err(TryFromVulkanCommandError::UnknownCommand(value.to_owned())),",
             "        }",
@@ -144,10 +208,6 @@ class LayerTraitGenerator(OutputGenerator):
             # Layer and extension properties should be provided via trait consts
             "vkEnumerateDeviceLayerProperties",
             "vkEnumerateDeviceExtensionProperties",
-            # TODO: pre-instance functions are not supported now, but such support should be added.
-            "vkEnumerateInstanceExtensionProperties",
-            "vkEnumerateInstanceLayerProperties",
-            "vkEnumerateInstanceVersion",
         ]
         if name in should_skip:
             return
@@ -0,0 +1,145 @@
+# Copyright 2023 Google LLC
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Builds the perfect hash tables used to look up Vulkan commands by name.
+
+The algorithm is the "hash, displace, and compress" one used by the phf crate. Each key is hashed
+with a seed to (g, f1, f2). g selects a bucket, and each bucket has a pair of displacements
+(d1, d2), so that (d2 + f1 * d1 + f2) % len maps every key to a distinct slot. If no displacements
+can be found for a bucket, we retry with another seed. Must be kept in sync with
+CommandLookupTable in vulkan-layer/src/global_simple_intercept.rs.
+"""
+
+from __future__ import annotations
+from typing import NamedTuple
+import unittest
+
+_U32_MASK = (1 << 32) - 1
+_U64_MASK = (1 << 64) - 1
+# The average number of keys in a bucket.
+_LAMBDA = 4
+_MAX_DISPLACEMENT = 64
+_MAX_SEED = 1024
+
+
+class CommandHash(NamedTuple):
+    g: int
+    f1: int
+    f2: int
+
+
+def command_hash(name: str, seed: int) -> CommandHash:
+    # FNV-1a
+    h = 0xCBF29CE484222325 ^ seed
+    for byte in name.encode("utf-8"):
+        h ^= byte
+        h = (h * 0x100000001B3) & _U64_MASK
+    # The high bits of FNV-1a are poorly distributed for short keys, so mix the hash.
+    h = _mix(h)
+    return CommandHash(g=h >> 32, f1=h & _U32_MASK, f2=_mix(h) & _U32_MASK)
+
+
+def _mix(z: int) -> int:
+    # The finalizer of splitmix64.
+    z = ((z ^ (z >> 30)) * 0xBF58476D1CE4E5B9) & _U64_MASK
+    z = ((z ^ (z >> 27)) * 0x94D049BB133111EB) & _U64_MASK
+    return z ^ (z >> 31)
+
+
+def displace(command_hash: CommandHash, d1: int, d2: int, length: int) -> int:
+    return ((d2 + command_hash.f1 * d1 + command_hash.f2) & _U32_MASK) % length
+
+
+class PerfectHashTable(NamedTuple):
+    seed: int
+    # Indexed by the bucket.
+    displacements: list[tuple[int, int]]
+    # Maps the slot to the index of the key in the input list.
+    indices: list[int]
+
+
+def build_perfect_hash_table(keys: list[str]) -> PerfectHashTable:
+    assert len(keys) == len(set(keys)), "keys must be unique"
+    assert len(keys) > 0, "at least one key is required"
+    for seed in range(_MAX_SEED):
+        table = _try_build_perfect_hash_table(keys, seed)
+        if table is not None:
+            return table
+    raise RuntimeError("failed to build the perfect hash table")
+
+
+def _try_build_perfect_hash_table(keys: list[str], seed: int) -> PerfectHashTable | None:
+    length = len(keys)
+    bucket_count = (length + _LAMBDA - 1) // _LAMBDA
+    hashes = [command_hash(key, seed) for key in keys]
+    buckets: list[list[int]] = [[] for _ in range(bucket_count)]
+    for key_index, key_hash in enumerate(hashes):
+        buckets[key_hash.g % bucket_count].append(key_index)
+    displacements = [(0, 0)] * bucket_count
+    indices: list[int | None] = [None] * length
+    # Place the largest buckets first, when most of the slots are free.
+    for bucket_index in sorted(range(bucket_count), key=lambda i: -len(buckets[i])):
+        bucket = buckets[bucket_index]
+        if not bucket:
+            continue
+        placed = False
+        for d1 in range(_MAX_DISPLACEMENT):
+            for d2 in range(length):
+                slots = [displace(hashes[key_index], d1, d2, length) for key_index in bucket]
+                if len(set(slots)) != len(slots) or any(
+                    indices[slot] is not None for slot in slots
+                ):
+                    continue
+                for key_index, slot in zip(bucket, slots):
+                    indices[slot] = key_index
+                displacements[bucket_index] = (d1, d2)
+                placed = True
+                break
+            if placed:
+                break
+        if not placed:
+            return None
+    assert all(index is not None for index in indices)
+    return PerfectHashTable(
+        seed=seed, displacements=displacements, indices=[int(i) for i in indices]
+    )
+
+
+def lookup(table: PerfectHashTable, keys: list[str], key: str) -> int | None:
+    key_hash = command_hash(key, table.seed)
+    d1, d2 = table.displacements[key_hash.g % len(table.displacements)]
+    index = table.indices[displace(key_hash, d1, d2, len(table.indices))]
+    if keys[index] != key:
+        return None
+    return index
+
+
+class TestPerfectHash(unittest.TestCase):
+    def test_all_keys_should_be_found(self):
+        keys = [f"vkCommand{i}" for i in range(300)]
+        table = build_perfect_hash_table(keys)
+        for i, key in enumerate(keys):
+            self.assertEqual(lookup(table, keys, key), i)
+
+    def test_unknown_key_should_not_be_found(self):
+        keys = ["vkCreateInstance", "vkDestroyInstance", "vkCreateDevice"]
+        table = build_perfect_hash_table(keys)
+        self.assertIsNone(lookup(table, keys, "vkDestroyDevice"))
+
+    def test_hash_should_match_known_value(self):
+        # The same values are tested in the Rust side to keep the 2 implementations in sync.
+        self.assertEqual(
+            command_hash("vkCreateDevice", 0),
+            CommandHash(g=3720463724, f1=3450434761, f2=3690812648),
+        )
@@ -330,6 +330,9 @@ class VkXmlParam(NamedTuple):
     type: VkXmlType
     name: str
     len_var: Optional[str] = None
+    # The externsync attribute split by ",", e.g. ["true"] if the parameter itself is externally
+    # synchronized, or ["pCreateInfo->surface", "pCreateInfo->oldSwapchain"].
+    extern_sync: list[str] = []
 
     @staticmethod
     def from_param_element(
@@ -417,7 +420,14 @@ class VkXmlParam(NamedTuple):
                     vk_xml_type.len = VkXmlLenKind.VARIABLE
                     len_var = len_attr
 
-        return VkXmlParam(type=vk_xml_type, name=param_name, len_var=len_var)
+        extern_sync_attr = param.get("externsync")
+        extern_sync = []
+        if extern_sync_attr is not None:
+            extern_sync = [expr.strip() for expr in extern_sync_attr.split(",")]
+
+        return VkXmlParam(
+            type=vk_xml_type, name=param_name, len_var=len_var, extern_sync=extern_sync
+        )
 
 
 class VkXmlCommand(NamedTuple):
@@ -905,7 +915,100 @@ class RustMethod(NamedTuple):
             vk_xml_cmd=vk_xml_cmd,
         )
 
-    def to_string(self) -> str:
+    def get_after_hook_name(self) -> str:
+        return f"after_{self.name}"
+
+    def get_after_hook_result_params(self) -> list[str]:
+        """Returns the result parameters of the after hook, which are empty if the command doesn't
+        return anything.
+
+        The type is the same as the type wrapped by LayerResult, except that Vec<T> is replaced
+        with &[T], so that the trampoline doesn't allocate to call the after hook. If the command
+        returns VkResult, VkResult<T> is split into the output, Option<T>, which is named after the
+        output parameter and is None if the command fails, and the raw vk::Result, so that the
+        output is available with the success codes other than VK_SUCCESS, e.g.
+        VK_SUBOPTIMAL_KHR."""
+        assert self.return_type.startswith("LayerResult<") and self.return_type.endswith(">")
+        result_type = self.return_type.removeprefix("LayerResult<").removesuffix(">")
+        if result_type == "()":
+            return []
+        # ash defines VkRemoteAddressNV as c_void instead of a pointer, which can't be moved, so
+        # pass the address as a pointer instead.
+        result_type = result_type.replace("vk::RemoteAddressNV", "*mut c_void")
+        result_type = re.sub(r"Vec<([^<>]*)>", r"&[\1]", result_type)
+        if not result_type.startswith("VkResult<"):
+            return [f"_result: {result_type}"]
+        output_type = result_type.removeprefix("VkResult<").removesuffix(">")
+        if output_type == "()":
+            return ["_result: vk::Result"]
+        return_info = VkXmlToRustMethodInfo.from_vk_xml_command(self.vk_xml_cmd).return_info
+        assert len(return_info.main_source_vk_xml_params) == 1
+        output_name = camel_case_to_snake_case(return_info.main_source_vk_xml_params[0].name)
+        return [f"_{output_name}: Option<{output_type}>", "_result: vk::Result"]
+
+    def to_string(self, args_type: Optional[str] = None) -> str:
+        """Returns the signature of the hook.
+
+        If args_type is not None, it's passed to LayerResult as the type of the rewritten
+        arguments."""
+        return_type = self.return_type
+        if args_type is not None:
+            return_type = f"{return_type.removesuffix('>')}, {args_type}>"
+        return self.__to_string(self.name, self.parameters, [], return_type)
+
+    def get_after_hook_parameters(self) -> list[RustParam]:
+        """Returns the parameters of the after hook.
+
+        The outputs are initialized when the after hook is called, so &mut MaybeUninit<T> is
+        replaced with &T, and &mut [MaybeUninit<T>] is replaced with &[T]. The output arrays are
+        sliced to the count returned by the next layer. If the command returns VkResult, the
+        outputs are undefined when the command fails, so they are wrapped in Option, except for the
+        counts of the output arrays, which are always initialized by the caller."""
+        vk_xml_to_rust_method_info = VkXmlToRustMethodInfo.from_vk_xml_command(self.vk_xml_cmd)
+        len_vars = {
+            param.len_var for param in self.vk_xml_cmd.parameters if param.len_var is not None
+        }
+        may_fail = self.vk_xml_cmd.return_type == "VkResult"
+        params: list[RustParam] = []
+        for param in vk_xml_to_rust_method_info.parameters:
+            rust_param = param.rust_method_param
+            param_type = rust_param.type
+            if param_type.refers_to is not None and not param_type.refers_to.is_const:
+                template_info = param_type.refers_to.template_info
+                assert template_info is not None and template_info.template_name == "MaybeUninit"
+                assert (
+                    not param_type.is_optional
+                ), f"Optional output {rust_param.name} in {self.vk_xml_cmd.name} is not supported."
+                is_count = param.main_source_vk_xml_param.name in len_vars
+                param_type = RustType(
+                    is_optional=may_fail and not is_count,
+                    refers_to=template_info.template_params[0]._replace(is_const=True),
+                )
+            elif param_type.slice_of is not None and not param_type.slice_of.is_const:
+                template_info = param_type.slice_of.template_info
+                assert template_info is not None and template_info.template_name == "MaybeUninit"
+                param_type = RustType(
+                    is_optional=param_type.is_optional or may_fail,
+                    slice_of=template_info.template_params[0]._replace(is_const=True),
+                )
+            params.append(rust_param._replace(type=param_type))
+        return params
+
+    def to_after_hook_string(self) -> str:
+        return self.__to_string(
+            self.get_after_hook_name(),
+            self.get_after_hook_parameters(),
+            self.get_after_hook_result_params(),
+            None,
+        )
+
+    def __to_string(
+        self,
+        name: str,
+        parameters: list[RustParam],
+        extra_params: list[str],
+        return_type: Optional[str],
+    ) -> str:
         class TypeParam(NamedTuple):
             name: str
             restriction: str
@@ -930,10 +1033,12 @@ class RustMethod(NamedTuple):
                 return f"{param.name}: {type_param}"
             return param.to_string()
 
-        params = ", ".join([param_to_string(param) for param in self.parameters])
+        params = ", ".join(
+            [param_to_string(param) for param in parameters] + extra_params
+        )
         # Prevent the Rust linter from complaining explicit -> ()
         assert (
-            self.return_type != "()"
+            return_type != "()"
         ), "Unexpected rust return type: (). All return type should be LayerResult<T>"
         generic_params: str = ""
         if len(used_type_params) > 0:
@@ -941,7 +1046,10 @@ class RustMethod(NamedTuple):
                 [f"{type_param.name}: {type_param.restriction}" for type_param in used_type_params]
             )
             generic_params = f"<{generic_params}>"
-        return f"fn {self.name}{generic_params}(&self, {params}) -> {self.return_type}"
+        fn_return_type = ""
+        if return_type is not None:
+            fn_return_type = f" -> {return_type}"
+        return f"fn {name}{generic_params}(&self, {params}){fn_return_type}"
 
 
 def generate_unhandled_command_comments(
@@ -982,6 +1090,21 @@ class TestUtils:
         return VkXmlCommand.from_cmd_info(vk_xml.cmd_dict[command_name], vk_xml.type_infos)
 
 
+class TestVkXmlParam(unittest.TestCase):
+    def test_extern_sync(self):
+        vk_xml_command = TestUtils.get_vk_xml_command("vkFreeCommandBuffers")
+        self.assertEqual(vk_xml_command.parameters[0].extern_sync, [])
+        self.assertEqual(vk_xml_command.parameters[1].extern_sync, ["true"])
+        self.assertEqual(vk_xml_command.parameters[3].extern_sync, ["true"])
+
+    def test_extern_sync_expressions(self):
+        vk_xml_command = TestUtils.get_vk_xml_command("vkCreateSwapchainKHR")
+        self.assertEqual(
+            vk_xml_command.parameters[1].extern_sync,
+            ["pCreateInfo->surface", "pCreateInfo->oldSwapchain"],
+        )
+
+
 class TestRustMethod(unittest.TestCase):
     def test_get_image_sparse_memory_requirements2(self):
         vk_xml_command = TestUtils.get_vk_xml_command("vkGetImageSparseMemoryRequirements2")
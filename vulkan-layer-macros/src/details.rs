@@ -15,7 +15,10 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use std::iter::zip;
-use syn::{spanned::Spanned, Error, Ident, ImplItem, ItemImpl, Type};
+use syn::{
+    ext::IdentExt, spanned::Spanned, Data, DataStruct, DeriveInput, Error, Expr, ExprLit,
+    ExprUnary, Fields, Ident, ImplItem, ItemImpl, Lit, LitStr, Meta, Type, UnOp,
+};
 
 fn snake_case_to_upper_camel_case(input: &str) -> String {
     let first_char = match input.chars().next() {
@@ -43,17 +46,28 @@ fn snake_case_to_upper_camel_case(input: &str) -> String {
 
 pub fn autoinfo(item: &ItemImpl, target_trait: &TokenStream2) -> Result<TokenStream2, Error> {
     let type_name = item.self_ty.as_ref();
-    let hooked_commands = item.items.iter().filter_map(|item| {
+    let mut hooked_commands = vec![];
+    for item in &item.items {
         let function = if let ImplItem::Fn(function) = item {
             function
         } else {
-            return None;
+            continue;
         };
         let func_name = function.sig.ident.to_string();
-        let enum_variant_name = snake_case_to_upper_camel_case(&func_name);
-        let enum_variant_name = Ident::new(&enum_variant_name, function.span());
-        Some(quote!(::vulkan_layer::LayerVulkanCommand::#enum_variant_name))
-    });
+        // The after hook is called by the same trampoline as the hook before the next layer.
+        let func_name = func_name.strip_prefix("after_").unwrap_or(&func_name);
+        let enum_variant_name = snake_case_to_upper_camel_case(func_name);
+        if hooked_commands
+            .iter()
+            .any(|hooked_command: &Ident| *hooked_command == enum_variant_name)
+        {
+            continue;
+        }
+        hooked_commands.push(Ident::new(&enum_variant_name, function.span()));
+    }
+    let hooked_commands = hooked_commands
+        .iter()
+        .map(|enum_variant_name| quote!(::vulkan_layer::LayerVulkanCommand::#enum_variant_name));
     Ok(quote! {
         impl #target_trait for #type_name {
             type HooksType = Self;
@@ -182,5 +196,276 @@ pub fn declare_introspection_queries_impl(global_type: &Type) -> Result<TokenStr
                 #global_type::get_device_proc_addr(device, p_name)
             }
         }
+
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "`instance` must be a valid `VkInstance` handle, and `p_name` must be a null-terminated "]
+        #[doc = "UTF-8 string."]
+        #[deny(unsafe_op_in_unsafe_fn)]
+        #[no_mangle]
+        pub unsafe extern "system" fn vk_layerGetPhysicalDeviceProcAddr(
+            instance: ::ash::vk::Instance,
+            p_name: *const ::std::ffi::c_char,
+        ) -> ::ash::vk::PFN_vkVoidFunction {
+            // Safe, because the caller is supposed to follow the exact same safety requirement.
+            unsafe {
+                #global_type::get_physical_device_proc_addr(instance, p_name)
+            }
+        }
+
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "`p_version_struct` must be a valid pointer to a `VkNegotiateLayerInterface` struct. See "]
+        #[doc = "<https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#layer-version-negotiation>"]
+        #[deny(unsafe_op_in_unsafe_fn)]
+        #[no_mangle]
+        pub unsafe extern "system" fn vkNegotiateLoaderLayerInterfaceVersion(
+            p_version_struct: *mut ::vulkan_layer::VkNegotiateLayerInterface,
+        ) -> ::ash::vk::Result {
+            // Safe, because the caller is supposed to follow the exact same safety requirement.
+            unsafe {
+                #global_type::negotiate_loader_layer_interface_version(p_version_struct)
+            }
+        }
+
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "`p_chain` must be a valid pointer to the chain node passed from the loader. See valid "]
+        #[doc = "usage of `vkEnumerateInstanceVersion` at "]
+        #[doc = "<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceVersion.html>"]
+        #[deny(unsafe_op_in_unsafe_fn)]
+        #[no_mangle]
+        pub unsafe extern "system" fn vk_layerEnumerateInstanceVersion(
+            p_chain: *const ::vulkan_layer::VkEnumerateInstanceVersionChain,
+            p_api_version: *mut u32,
+        ) -> ::ash::vk::Result {
+            // Safe, because the caller is supposed to follow the exact same safety requirement.
+            unsafe {
+                #global_type::pre_instance_enumerate_instance_version(p_chain, p_api_version)
+            }
+        }
+
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "`p_chain` must be a valid pointer to the chain node passed from the loader. See valid "]
+        #[doc = "usage of `vkEnumerateInstanceExtensionProperties` at "]
+        #[doc = "<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html>"]
+        #[deny(unsafe_op_in_unsafe_fn)]
+        #[no_mangle]
+        pub unsafe extern "system" fn vk_layerEnumerateInstanceExtensionProperties(
+            p_chain: *const ::vulkan_layer::VkEnumerateInstanceExtensionPropertiesChain,
+            p_layer_name: *const ::std::ffi::c_char,
+            p_property_count: *mut u32,
+            p_properties: *mut ::ash::vk::ExtensionProperties,
+        ) -> ::ash::vk::Result {
+            // Safe, because the caller is supposed to follow the exact same safety requirement.
+            unsafe {
+                #global_type::pre_instance_enumerate_instance_extension_properties(
+                    p_chain,
+                    p_layer_name,
+                    p_property_count,
+                    p_properties,
+                )
+            }
+        }
+
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "`p_chain` must be a valid pointer to the chain node passed from the loader. See valid "]
+        #[doc = "usage of `vkEnumerateInstanceLayerProperties` at "]
+        #[doc = "<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html>"]
+        #[deny(unsafe_op_in_unsafe_fn)]
+        #[no_mangle]
+        pub unsafe extern "system" fn vk_layerEnumerateInstanceLayerProperties(
+            p_chain: *const ::vulkan_layer::VkEnumerateInstanceLayerPropertiesChain,
+            p_property_count: *mut u32,
+            p_properties: *mut ::ash::vk::LayerProperties,
+        ) -> ::ash::vk::Result {
+            // Safe, because the caller is supposed to follow the exact same safety requirement.
+            unsafe {
+                #global_type::pre_instance_enumerate_instance_layer_properties(
+                    p_chain,
+                    p_property_count,
+                    p_properties,
+                )
+            }
+        }
+    })
+}
+
+/// Converts the `default` attribute of a layer setting to the text form used by the settings file.
+fn layer_setting_default_to_string(expr: &Expr) -> Result<String, Error> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(lit) => Ok(lit.value()),
+            Lit::Int(lit) => Ok(lit.base10_digits().to_owned()),
+            Lit::Float(lit) => Ok(lit.base10_digits().to_owned()),
+            Lit::Bool(lit) => Ok(lit.value.to_string()),
+            _ => Err(Error::new(
+                lit.span(),
+                "expect a string, integer, float or boolean literal",
+            )),
+        },
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) if matches!(
+            expr.as_ref(),
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(_) | Lit::Float(_),
+                ..
+            })
+        ) =>
+        {
+            Ok(format!("-{}", layer_setting_default_to_string(expr)?))
+        }
+        _ => Err(Error::new(
+            expr.span(),
+            "expect a string, integer, float or boolean literal",
+        )),
+    }
+}
+
+/// Checks that the `default` attribute of a layer setting can be read as `field_type` in the same
+/// way as `vulkan_layer::LayerSettings` reads a string setting. Only the built-in
+/// `vulkan_layer::LayerSettingType` implementations are checked, because the macro only sees the
+/// field type by name.
+fn validate_layer_setting_default(
+    field_type: &Type,
+    expr: &Expr,
+    default: &str,
+) -> Result<(), Error> {
+    let Type::Path(type_path) = field_type else {
+        return Ok(());
+    };
+    let Some(type_name) = type_path.path.segments.last() else {
+        return Ok(());
+    };
+    let type_name = type_name.ident.to_string();
+    let default = default.trim();
+    let is_valid = match type_name.as_str() {
+        "bool" => {
+            default.eq_ignore_ascii_case("true")
+                || default.eq_ignore_ascii_case("false")
+                || default.parse::<i64>().is_ok()
+        }
+        "i32" => default
+            .parse::<i128>()
+            .is_ok_and(|value| i32::try_from(value).is_ok()),
+        "i64" => default
+            .parse::<i128>()
+            .is_ok_and(|value| i64::try_from(value).is_ok()),
+        "u32" => default
+            .parse::<i128>()
+            .is_ok_and(|value| u32::try_from(value).is_ok()),
+        "u64" => default
+            .parse::<i128>()
+            .is_ok_and(|value| u64::try_from(value).is_ok()),
+        "f32" | "f64" => default.parse::<f64>().is_ok(),
+        _ => true,
+    };
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::new(
+            expr.span(),
+            format!("invalid default value {:?} for {}", default, type_name),
+        ))
+    }
+}
+
+pub fn derive_layer_settings_impl(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "LayerSettings can only be derived for structs with named fields",
+            ))
+        }
+    };
+    let mut settings_metadata = vec![];
+    let mut field_initializers = vec![];
+    for field in fields {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_type = &field.ty;
+        let mut key = field_name.unraw().to_string();
+        let mut label = None;
+        let mut default = None;
+        for attr in &field.attrs {
+            if !attr.path().is_ident("layer_setting") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("key") {
+                    key = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("label") {
+                    label = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("default") {
+                    let expr = meta.value()?.parse::<Expr>()?;
+                    let default_string = layer_setting_default_to_string(&expr)?;
+                    validate_layer_setting_default(field_type, &expr, &default_string)?;
+                    default = Some(default_string);
+                } else {
+                    return Err(meta.error("unsupported layer_setting attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        let description = field
+            .attrs
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                Meta::NameValue(name_value) if name_value.path.is_ident("doc") => {
+                    match &name_value.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(doc), ..
+                        }) => Some(doc.value().trim().to_owned()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let label = label.unwrap_or_else(|| key.clone());
+        let default = match default {
+            Some(default) => quote!(#default),
+            None => quote!(<#field_type as ::vulkan_layer::LayerSettingType>::DEFAULT),
+        };
+        settings_metadata.push(quote! {
+            ::vulkan_layer::LayerSettingMetadata {
+                key: #key,
+                label: #label,
+                description: #description,
+                kind: <#field_type as ::vulkan_layer::LayerSettingType>::KIND,
+                default: #default,
+            }
+        });
+        field_initializers.push(quote! {
+            #field_name: layer_settings.get_or_default::<#field_type>(#key, #default)
+        });
+    }
+    let type_name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::vulkan_layer::LayerSettingsSchema for #type_name #type_generics
+        #where_clause
+        {
+            const SETTINGS: &'static [::vulkan_layer::LayerSettingMetadata] = &[
+                #(#settings_metadata),*
+            ];
+
+            fn from_layer_settings(layer_settings: &::vulkan_layer::LayerSettings) -> Self {
+                Self {
+                    #(#field_initializers),*
+                }
+            }
+        }
     })
 }
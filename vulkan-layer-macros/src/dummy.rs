@@ -14,7 +14,7 @@
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::Type;
+use syn::{DeriveInput, Type};
 
 pub fn dummy_autoinfo_impl(name: &Type, target_trait: &TokenStream2) -> TokenStream2 {
     quote! {
@@ -31,3 +31,19 @@ pub fn dummy_autoinfo_impl(name: &Type, target_trait: &TokenStream2) -> TokenStr
         }
     }
 }
+
+pub fn dummy_layer_settings_impl(input: &DeriveInput) -> TokenStream2 {
+    let type_name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::vulkan_layer::LayerSettingsSchema for #type_name #type_generics
+        #where_clause
+        {
+            const SETTINGS: &'static [::vulkan_layer::LayerSettingMetadata] = &[];
+
+            fn from_layer_settings(_: &::vulkan_layer::LayerSettings) -> Self {
+                unimplemented!()
+            }
+        }
+    }
+}
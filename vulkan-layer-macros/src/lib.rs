@@ -19,7 +19,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, ItemImpl, Type};
+use syn::{parse_macro_input, DeriveInput, ItemImpl, Type};
 
 mod details;
 mod dummy;
@@ -95,7 +95,8 @@ pub fn auto_globalhooksinfo_impl(_: TokenStream, item: TokenStream) -> TokenStre
 /// `vulkan_layer::InstanceHooks` trait, and will implement the `vulkan_layer::InstanceInfo` trait
 /// for the type:
 /// * `InstanceInfo::hooked_commands` returns a list of the overridden methods that appear in the
-///   implementation item.
+///   implementation item. An overridden `after_*` method adds the same command as the method
+///   without the `after_` prefix.
 /// * `InstanceInfo::HooksType` and `InstanceInfo::HooksRefType` are defined as `Self` and `&Self`.
 /// * `InstanceInfo::hooks` returns `self`.
 ///
@@ -105,7 +106,8 @@ pub fn auto_globalhooksinfo_impl(_: TokenStream, item: TokenStream) -> TokenStre
 /// use ash::vk;
 /// use std::mem::MaybeUninit;
 /// use vulkan_layer::{
-///     auto_instanceinfo_impl, InstanceHooks, InstanceInfo, LayerResult, LayerVulkanCommand,
+///     auto_instanceinfo_impl, command_args::GetPhysicalDeviceFeaturesArgs, InstanceHooks,
+///     InstanceInfo, LayerResult, LayerVulkanCommand,
 /// };
 ///
 /// #[derive(Default)]
@@ -117,7 +119,7 @@ pub fn auto_globalhooksinfo_impl(_: TokenStream, item: TokenStream) -> TokenStre
 ///         &self,
 ///         _physical_device: vk::PhysicalDevice,
 ///         _p_features: &mut MaybeUninit<vk::PhysicalDeviceFeatures>,
-///     ) -> LayerResult<()> {
+///     ) -> LayerResult<(), GetPhysicalDeviceFeaturesArgs> {
 ///         LayerResult::Unhandled
 ///     }
 /// }
@@ -156,7 +158,8 @@ pub fn auto_instanceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream
 /// `vulkan_layer::DeviceHooks` trait, and will implement the `vulkan_layer::DeviceInfo` trait for
 /// the type:
 /// * `DeviceInfo::hooked_commands` returns a list of the overridden methods that appear in the
-///   implementation item.
+///   implementation item. An overridden `after_*` method adds the same command as the method
+///   without the `after_` prefix.
 /// * `DeviceInfo::HooksType` and `DeviceInfo::HooksRefType` are defined as `Self` and `&Self`.
 /// * `DeviceInfo::hooks` returns `self`.
 ///
@@ -165,7 +168,8 @@ pub fn auto_instanceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream
 /// ```
 /// use ash::vk;
 /// use vulkan_layer::{
-///     auto_deviceinfo_impl, DeviceHooks, DeviceInfo, LayerResult, LayerVulkanCommand,
+///     auto_deviceinfo_impl, command_args::CreateImageArgs, DeviceHooks, DeviceInfo, LayerResult,
+///     LayerVulkanCommand,
 /// };
 ///
 /// #[derive(Default)]
@@ -177,7 +181,7 @@ pub fn auto_instanceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream
 ///         &self,
 ///         _p_create_info: &vk::ImageCreateInfo,
 ///         _p_allocator: Option<&vk::AllocationCallbacks>,
-///     ) -> LayerResult<ash::prelude::VkResult<vk::Image>> {
+///     ) -> LayerResult<ash::prelude::VkResult<vk::Image>, CreateImageArgs> {
 ///         LayerResult::Unhandled
 ///     }
 /// }
@@ -228,11 +232,22 @@ pub fn auto_deviceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream {
 /// * `vkEnumerateDeviceExtensionProperties`
 /// * `vkGetInstanceProcAddr`
 /// * `vkGetDeviceProcAddr`
+///
+/// In addition, `vkNegotiateLoaderLayerInterfaceVersion` is exported, so that the desktop loader
+/// can negotiate the
+/// [loader-layer interface version 2](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#layer-version-negotiation)
+/// with the layer, and obtain the `vkGet*ProcAddr` entry points without looking up symbols.
+/// `vk_layerGetPhysicalDeviceProcAddr` is also exported for the loader to query physical device
+/// commands unknown to the loader. `vk_layerEnumerateInstanceVersion`,
+/// `vk_layerEnumerateInstanceExtensionProperties`, and
+/// `vk_layerEnumerateInstanceLayerProperties` are exported as the
+/// [pre-instance functions](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#pre-instance-functions)
+/// of implicit layers.
 /// # Examples
 ///
 /// ```
 /// # use std::sync::Arc;
-/// # use vulkan_layer::{StubGlobalHooks, StubInstanceInfo, StubDeviceInfo, Layer, Global, declare_introspection_queries, LayerManifest};
+/// # use vulkan_layer::{StubGlobalHooks, StubInstanceInfo, StubDeviceInfo, Layer, Global, declare_introspection_queries, LayerManifest, LayerSettings, DeviceLoaderDataCallback};
 /// # use once_cell::sync::Lazy;
 /// # use ash::{vk, self};
 /// #
@@ -246,6 +261,9 @@ pub fn auto_deviceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream {
 /// #     type DeviceInfo = StubDeviceInfo;
 /// #     type InstanceInfoContainer = StubInstanceInfo;
 /// #     type DeviceInfoContainer = StubDeviceInfo;
+/// #     type PhysicalDeviceInfo = ();
+/// #     type QueueInfo = ();
+/// #     type CommandBufferInfo = ();
 /// #     
 /// #     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
 /// #         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -266,6 +284,7 @@ pub fn auto_deviceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream {
 /// #         _: Option<&vk::AllocationCallbacks>,
 /// #         _: Arc<ash::Instance>,
 /// #         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+/// #         _: &LayerSettings,
 /// #     ) -> Self::InstanceInfoContainer {
 /// #         Default::default()
 /// #     }
@@ -277,6 +296,7 @@ pub fn auto_deviceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream {
 /// #         _: Option<&vk::AllocationCallbacks>,
 /// #         _: Arc<ash::Device>,
 /// #         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+/// #         _: DeviceLoaderDataCallback,
 /// #     ) -> Self::DeviceInfoContainer {
 /// #         Default::default()
 /// #     }
@@ -290,6 +310,24 @@ pub fn auto_deviceinfo_impl(_: TokenStream, item: TokenStream) -> TokenStream {
 /// # let _: vk::PFN_vkEnumerateDeviceExtensionProperties = vkEnumerateDeviceExtensionProperties;
 /// # let _: vk::PFN_vkGetInstanceProcAddr = vkGetInstanceProcAddr;
 /// # let _: vk::PFN_vkGetDeviceProcAddr = vkGetDeviceProcAddr;
+/// # let _: vk::PFN_vkGetInstanceProcAddr = vk_layerGetPhysicalDeviceProcAddr;
+/// # let _: unsafe extern "system" fn(*mut vulkan_layer::VkNegotiateLayerInterface) -> vk::Result =
+/// #     vkNegotiateLoaderLayerInterfaceVersion;
+/// # let _: unsafe extern "system" fn(
+/// #     *const vulkan_layer::VkEnumerateInstanceVersionChain,
+/// #     *mut u32,
+/// # ) -> vk::Result = vk_layerEnumerateInstanceVersion;
+/// # let _: unsafe extern "system" fn(
+/// #     *const vulkan_layer::VkEnumerateInstanceExtensionPropertiesChain,
+/// #     *const std::ffi::c_char,
+/// #     *mut u32,
+/// #     *mut vk::ExtensionProperties,
+/// # ) -> vk::Result = vk_layerEnumerateInstanceExtensionProperties;
+/// # let _: unsafe extern "system" fn(
+/// #     *const vulkan_layer::VkEnumerateInstanceLayerPropertiesChain,
+/// #     *mut u32,
+/// #     *mut vk::LayerProperties,
+/// # ) -> vk::Result = vk_layerEnumerateInstanceLayerProperties;
 /// ```
 #[proc_macro]
 pub fn declare_introspection_queries(item: TokenStream) -> TokenStream {
@@ -298,3 +336,76 @@ pub fn declare_introspection_queries(item: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
+
+/// Derive the implementation of the `vulkan_layer::LayerSettingsSchema` trait for a struct with
+/// named fields.
+///
+/// Each field is a layer setting, and the field type must implement
+/// `vulkan_layer::LayerSettingType`. The setting key is the field name, which is prefixed with the
+/// layer settings prefix in the settings file and the environment variables, e.g. the `log_level`
+/// field of `VK_LAYER_GOOGLE_example` is read from `google_example.log_level` in
+/// `vk_layer_settings.txt`, and from the `VK_GOOGLE_EXAMPLE_LOG_LEVEL` environment variable. See
+/// `vulkan_layer::LayerSettings::load` for the precedence of the sources. The doc comment of the
+/// field is used as the description of the setting in the layer manifest.
+///
+/// The field can be customized with the `#[layer_setting(...)]` attribute:
+/// * `key = "..."` overrides the setting key.
+/// * `label = "..."` sets the name presented to the user in the layer manifest. The key is used
+///   if absent.
+/// * `default = <literal>` sets the default value, which is used if the setting is absent or
+///   invalid. If absent, `vulkan_layer::LayerSettingType::DEFAULT` of the field type is used, e.g.
+///   `false`, `0` and the empty string. The default value of a `bool`, integer or floating-point
+///   field is checked at compile time, e.g. a negative default for a `u32` field fails to compile.
+///   The default value of a custom `vulkan_layer::LayerSettingType` can only be checked at
+///   runtime, when `vulkan_layer::LayerSettings::get_or_default` panics if it's invalid.
+///
+/// # Examples
+///
+/// ```
+/// use vulkan_layer::{LayerSettingValue, LayerSettings, LayerSettingsSchema};
+///
+/// #[derive(LayerSettings)]
+/// struct MySettings {
+///     /// Whether to validate the synchronization.
+///     #[layer_setting(default = true)]
+///     validate_sync: bool,
+///     #[layer_setting(key = "max_frames", default = 16)]
+///     frame_count: u32,
+/// }
+///
+/// let mut api_settings = LayerSettings::default();
+/// api_settings.insert("max_frames", LayerSettingValue::Uint32(vec![4]));
+/// let settings = MySettings::from_layer_settings(&api_settings);
+/// assert!(settings.validate_sync);
+/// assert_eq!(settings.frame_count, 4);
+/// assert_eq!(
+///     MySettings::SETTINGS[0].description,
+///     "Whether to validate the synchronization."
+/// );
+/// ```
+///
+/// An invalid default value is rejected at compile time:
+///
+/// ```compile_fail
+/// use vulkan_layer::LayerSettings;
+///
+/// #[derive(LayerSettings)]
+/// struct MySettings {
+///     #[layer_setting(default = -1)]
+///     frame_count: u32,
+/// }
+/// ```
+#[proc_macro_derive(LayerSettings, attributes(layer_setting))]
+pub fn derive_layer_settings(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    details::derive_layer_settings_impl(&input)
+        .unwrap_or_else(|e| {
+            let dummy = dummy::dummy_layer_settings_impl(&input);
+            let compile_error = e.to_compile_error();
+            quote! {
+                #dummy
+                #compile_error
+            }
+        })
+        .into()
+}
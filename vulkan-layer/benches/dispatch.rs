@@ -0,0 +1,246 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Measures the overhead of the per-call dispatch of an intercepted command when many threads
+//! record commands on the same `VkDevice` concurrently, and the overhead of tracking the command
+//! buffers when many threads allocate and free command buffers concurrently.
+//!
+//! Run with `cargo bench -p vulkan-layer --bench dispatch`. The number of calls per thread can be
+//! overridden with the first command line argument. Every allocation and free is counted as a
+//! call.
+
+use ash::vk::{self, Handle};
+use once_cell::sync::Lazy;
+use std::{
+    ffi::c_void,
+    sync::{Arc, Barrier},
+    thread,
+    time::{Duration, Instant},
+};
+use vulkan_layer::{
+    auto_deviceinfo_impl, command_args::CmdDrawArgs, DeviceHooks, DeviceLoaderDataCallback, Global,
+    Layer, LayerManifest, LayerResult, LayerSettings, StubGlobalHooks, StubInstanceInfo,
+};
+
+// Reuse the test ICD from the integration tests. The unit tests in the module are not built for a
+// bench target, so some imports are unused.
+#[path = "../tests/utils/mod.rs"]
+#[allow(unused_imports)]
+pub mod utils;
+
+use utils::{ArcDelInstanceContextExt, DeviceContext, InstanceCreateInfoExt};
+
+const DEFAULT_CALLS_PER_THREAD: u32 = 1_000_000;
+// Allocating and freeing a command buffer also goes through the test ICD, which is much slower
+// than a handled vkCmdDraw.
+const CHURN_CALLS_DIVISOR: u32 = 100;
+const THREAD_COUNTS: [usize; 6] = [1, 2, 4, 8, 16, 32];
+
+#[derive(Default)]
+struct BenchDeviceInfo;
+
+#[auto_deviceinfo_impl]
+impl DeviceHooks for BenchDeviceInfo {
+    fn cmd_draw(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _vertex_count: u32,
+        _instance_count: u32,
+        _first_vertex: u32,
+        _first_instance: u32,
+    ) -> LayerResult<(), CmdDrawArgs> {
+        // Don't call into the test ICD, so that only the dispatch overhead is measured.
+        LayerResult::Handled(())
+    }
+}
+
+#[derive(Default)]
+struct BenchLayer(StubGlobalHooks);
+
+impl Layer for BenchLayer {
+    type GlobalHooksInfo = StubGlobalHooks;
+    type InstanceInfo = StubInstanceInfo;
+    type DeviceInfo = BenchDeviceInfo;
+    type InstanceInfoContainer = StubInstanceInfo;
+    type DeviceInfoContainer = BenchDeviceInfo;
+    type PhysicalDeviceInfo = ();
+    type QueueInfo = ();
+    type CommandBufferInfo = ();
+
+    fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
+        static GLOBAL: Lazy<Global<BenchLayer>> = Lazy::new(Default::default);
+        &*GLOBAL
+    }
+
+    fn manifest() -> LayerManifest {
+        let mut manifest = LayerManifest::default();
+        manifest.name = "VK_LAYER_GOOGLE_dispatch_bench";
+        manifest.spec_version = vk::API_VERSION_1_1;
+        manifest
+    }
+
+    fn global_hooks_info(&self) -> &Self::GlobalHooksInfo {
+        &self.0
+    }
+
+    fn create_instance_info(
+        &self,
+        _: &vk::InstanceCreateInfo,
+        _: Option<&vk::AllocationCallbacks>,
+        _: Arc<ash::Instance>,
+        _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+        _: &LayerSettings,
+    ) -> Self::InstanceInfoContainer {
+        Default::default()
+    }
+
+    fn create_device_info(
+        &self,
+        _: vk::PhysicalDevice,
+        _: &vk::DeviceCreateInfo,
+        _: Option<&vk::AllocationCallbacks>,
+        _: Arc<ash::Device>,
+        _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        _: DeviceLoaderDataCallback,
+    ) -> Self::DeviceInfoContainer {
+        Default::default()
+    }
+}
+
+/// A command buffer handle that shares the loader dispatch pointer with its device, just like the
+/// ones created by the driver. The test ICD doesn't implement `vkAllocateCommandBuffers`.
+struct FakeCommandBuffer(Box<*const c_void>);
+
+// The dispatch pointer is only read.
+unsafe impl Send for FakeCommandBuffer {}
+
+impl FakeCommandBuffer {
+    fn new(device: vk::Device) -> Self {
+        // All dispatchable objects start with the loader dispatch pointer.
+        let dispatch_ptr = unsafe { *(device.as_raw() as *const *const c_void) };
+        Self(Box::new(dispatch_ptr))
+    }
+
+    fn handle(&self) -> vk::CommandBuffer {
+        vk::CommandBuffer::from_raw(&*self.0 as *const *const c_void as u64)
+    }
+}
+
+// Runs `body` with every state on its own thread, and returns the time until all threads finish.
+fn measure<S: Send>(states: Vec<S>, body: impl Fn(S) + Sync) -> Duration {
+    let barrier = Barrier::new(states.len() + 1);
+    thread::scope(|scope| {
+        let threads = states
+            .into_iter()
+            .map(|state| {
+                let barrier = &barrier;
+                let body = &body;
+                scope.spawn(move || {
+                    barrier.wait();
+                    body(state);
+                })
+            })
+            .collect::<Vec<_>>();
+        // Start before releasing the threads, which may finish before this thread is scheduled
+        // again after the barrier.
+        let start = Instant::now();
+        barrier.wait();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        start.elapsed()
+    })
+}
+
+fn run_draw(device: &ash::Device, thread_count: usize, calls_per_thread: u32) -> Duration {
+    let command_buffers = (0..thread_count)
+        .map(|_| FakeCommandBuffer::new(device.handle()))
+        .collect();
+    measure(command_buffers, |command_buffer: FakeCommandBuffer| {
+        for i in 0..calls_per_thread {
+            unsafe { device.cmd_draw(command_buffer.handle(), 3, 1, i, 0) };
+        }
+    })
+}
+
+// Every thread allocates and frees a command buffer from its own pool, so that only the command
+// buffer tracking of the layer is shared between the threads.
+fn run_churn(device: &ash::Device, thread_count: usize, calls_per_thread: u32) -> Duration {
+    let command_pools = (0..thread_count)
+        .map(|_| {
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let elapsed = measure(command_pools.clone(), |command_pool| {
+        let allocate_info = vk::CommandBufferAllocateInfo::builder()
+            .command_pool(command_pool)
+            .level(vk::CommandBufferLevel::PRIMARY)
+            .command_buffer_count(1);
+        for _ in 0..calls_per_thread / 2 {
+            let command_buffers =
+                unsafe { device.allocate_command_buffers(&allocate_info) }.unwrap();
+            unsafe { device.free_command_buffers(command_pool, &command_buffers) };
+        }
+    });
+    for command_pool in command_pools {
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+    elapsed
+}
+
+fn print_results(
+    name: &str,
+    device: &ash::Device,
+    calls_per_thread: u32,
+    run: impl Fn(&ash::Device, usize, u32) -> Duration,
+) {
+    // Warm up.
+    run(device, 1, calls_per_thread / 10);
+    println!("{name}");
+    println!("threads  calls/thread  total(ms)  ns/call  Mcalls/s");
+    for thread_count in THREAD_COUNTS {
+        let elapsed = run(device, thread_count, calls_per_thread);
+        let total_calls = thread_count as f64 * f64::from(calls_per_thread);
+        println!(
+            "{:>7}  {:>12}  {:>9.1}  {:>7.1}  {:>8.2}",
+            thread_count,
+            calls_per_thread,
+            elapsed.as_secs_f64() * 1e3,
+            elapsed.as_secs_f64() * 1e9 * thread_count as f64 / total_calls,
+            total_calls / elapsed.as_secs_f64() / 1e6,
+        );
+    }
+}
+
+fn main() {
+    // Ignore the arguments passed by cargo, e.g. --bench.
+    let calls_per_thread = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(DEFAULT_CALLS_PER_THREAD);
+    let ctx = vk::InstanceCreateInfo::builder()
+        .default_instance::<(BenchLayer,)>()
+        .default_device()
+        .unwrap();
+    let DeviceContext { device, .. } = ctx.as_ref();
+    print_results("vkCmdDraw", device, calls_per_thread, run_draw);
+    println!();
+    print_results(
+        "vkAllocateCommandBuffers + vkFreeCommandBuffers",
+        device,
+        calls_per_thread / CHURN_CALLS_DIVISOR,
+        run_churn,
+    );
+}
@@ -0,0 +1,775 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A ready-made [`DeviceHooks`](crate::DeviceHooks) and [`InstanceHooks`](crate::InstanceHooks)
+//! implementation that dumps the intercepted commands, similar to the LunarG `api_dump` layer.
+//!
+//! The formatting of the parameters and the return values of every after hook is generated from
+//! the Vulkan XML API registry. The handles are dumped as their raw values, the enums and the
+//! flags as their symbolic names, and the arrays, the structs and the `pNext` chains are walked
+//! recursively.
+
+use ash::vk;
+use log::{info, warn};
+use std::{
+    ffi::{c_char, c_void, CStr},
+    fmt::{Debug, Write as _},
+    fs::File,
+    io::{self, LineWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use crate::{layer_manifest::JsonValue, DeviceInfo, InstanceInfo, LayerVulkanCommand};
+
+mod generated;
+use generated::*;
+
+/// The dumped form of a parameter, a return value or a struct member.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DumpValue {
+    /// A null pointer, or an output that the command doesn't write.
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    /// The raw value of a Vulkan handle.
+    Handle(u64),
+    /// An address that is not followed, e.g. a `void*` or a function pointer.
+    Pointer(usize),
+    /// The symbolic name of an enum value, or the names of the set bits of a flags value joined by
+    /// `" | "`.
+    Enum(String),
+    /// A value formatted by its [`Debug`] implementation, e.g. the video codec std structs.
+    Opaque(String),
+    Array(Vec<DumpValue>),
+    /// The Vulkan type name and the members in declaration order.
+    Struct(&'static str, Vec<(&'static str, DumpValue)>),
+}
+
+impl DumpValue {
+    fn write_text(&self, out: &mut String) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(value) => write!(out, "{value}").unwrap(),
+            Self::Int(value) => write!(out, "{value}").unwrap(),
+            Self::UInt(value) => write!(out, "{value}").unwrap(),
+            Self::Float(value) => write!(out, "{value:?}").unwrap(),
+            Self::String(value) => write!(out, "{value:?}").unwrap(),
+            Self::Handle(value) => write!(out, "{value:#x}").unwrap(),
+            Self::Pointer(value) => write!(out, "{value:#x}").unwrap(),
+            Self::Enum(value) | Self::Opaque(value) => out.push_str(value),
+            Self::Array(elements) => {
+                out.push('[');
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    element.write_text(out);
+                }
+                out.push(']');
+            }
+            Self::Struct(name, members) => {
+                out.push_str(name);
+                out.push_str(" {");
+                for (i, (member_name, value)) in members.iter().enumerate() {
+                    out.push_str(if i > 0 { ", " } else { " " });
+                    out.push_str(member_name);
+                    out.push_str(": ");
+                    value.write_text(out);
+                }
+                out.push_str(" }");
+            }
+        }
+    }
+
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(value) => write!(out, "{value}").unwrap(),
+            Self::Int(value) => write!(out, "{value}").unwrap(),
+            Self::UInt(value) => write!(out, "{value}").unwrap(),
+            Self::Float(value) if value.is_finite() => write!(out, "{value}").unwrap(),
+            // JSON numbers can't be infinite or NaN.
+            Self::Float(value) => JsonValue::write_string(out, &value.to_string()),
+            Self::Handle(value) => JsonValue::write_string(out, &format!("{value:#x}")),
+            Self::Pointer(value) => JsonValue::write_string(out, &format!("{value:#x}")),
+            Self::String(value) | Self::Enum(value) | Self::Opaque(value) => {
+                JsonValue::write_string(out, value)
+            }
+            Self::Array(elements) => {
+                out.push('[');
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    element.write_json(out);
+                }
+                out.push(']');
+            }
+            Self::Struct(_, members) => write_json_object(out, members),
+        }
+    }
+}
+
+fn write_json_object(out: &mut String, members: &[(&str, DumpValue)]) {
+    out.push('{');
+    for (i, (name, value)) in members.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        JsonValue::write_string(out, name);
+        out.push(':');
+        value.write_json(out);
+    }
+    out.push('}');
+}
+
+/// A type that can be dumped as a [`DumpValue`].
+///
+/// Implemented for the Vulkan types reachable from the parameters of the after hooks, most of
+/// which are generated.
+pub(crate) trait Dump {
+    /// Returns the dumped form of `self`.
+    ///
+    /// # Safety
+    ///
+    /// The pointers reachable from `self` must be valid as if `self` were passed to or returned
+    /// from a Vulkan command.
+    unsafe fn dump(&self) -> DumpValue;
+}
+
+macro_rules! impl_dump_for_numbers {
+    ($variant:ident, $dumped_type:ty, $($number:ty),*) => {
+        $(
+            impl Dump for $number {
+                unsafe fn dump(&self) -> DumpValue {
+                    DumpValue::$variant(<$dumped_type>::from(*self))
+                }
+            }
+        )*
+    };
+}
+
+impl_dump_for_numbers!(UInt, u64, u8, u16, u32, u64);
+impl_dump_for_numbers!(Int, i64, i16, i32, i64);
+impl_dump_for_numbers!(Float, f64, f64);
+
+impl Dump for f32 {
+    unsafe fn dump(&self) -> DumpValue {
+        // Go through the shortest decimal form, so that e.g. 0.1 is not dumped as
+        // 0.10000000149011612.
+        DumpValue::Float(self.to_string().parse().unwrap())
+    }
+}
+
+impl Dump for usize {
+    unsafe fn dump(&self) -> DumpValue {
+        DumpValue::UInt(*self as u64)
+    }
+}
+
+impl Dump for bool {
+    unsafe fn dump(&self) -> DumpValue {
+        DumpValue::Bool(*self)
+    }
+}
+
+impl Dump for str {
+    unsafe fn dump(&self) -> DumpValue {
+        DumpValue::String(self.to_owned())
+    }
+}
+
+impl<T: ?Sized> Dump for *const T {
+    unsafe fn dump(&self) -> DumpValue {
+        DumpValue::Pointer(self.cast::<c_void>() as usize)
+    }
+}
+
+impl<T: ?Sized> Dump for *mut T {
+    unsafe fn dump(&self) -> DumpValue {
+        DumpValue::Pointer(self.cast::<c_void>() as usize)
+    }
+}
+
+impl<T: Dump + ?Sized> Dump for &T {
+    unsafe fn dump(&self) -> DumpValue {
+        unsafe { (**self).dump() }
+    }
+}
+
+impl<T: Dump> Dump for Option<T> {
+    unsafe fn dump(&self) -> DumpValue {
+        match self {
+            Some(value) => unsafe { value.dump() },
+            None => DumpValue::Null,
+        }
+    }
+}
+
+impl<T: Dump> Dump for [T] {
+    unsafe fn dump(&self) -> DumpValue {
+        DumpValue::Array(
+            self.iter()
+                .map(|element| unsafe { element.dump() })
+                .collect(),
+        )
+    }
+}
+
+impl<T: Dump, const N: usize> Dump for [T; N] {
+    unsafe fn dump(&self) -> DumpValue {
+        unsafe { self.as_slice().dump() }
+    }
+}
+
+/// Dumps the `pNext` chain at `p_next`. The elements with an unknown `sType` are dumped as
+/// `VkBaseInStructure`s.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn dump_p_next(p_next: *const c_void) -> DumpValue {
+    let Some(element) = (unsafe { p_next.cast::<vk::BaseInStructure>().as_ref() }) else {
+        return DumpValue::Null;
+    };
+    // The dumped element dumps the rest of the chain.
+    if let Some(dumped) = unsafe { dump_extension_struct(element) } {
+        return dumped;
+    }
+    DumpValue::Struct(
+        "VkBaseInStructure",
+        vec![
+            ("sType", unsafe { element.s_type.dump() }),
+            ("pNext", unsafe { dump_p_next(element.p_next.cast()) }),
+        ],
+    )
+}
+
+/// Dumps `len` elements at `data`, or [`DumpValue::Null`] if `data` is null.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn dump_slice<T: Dump>(
+    data: *const T,
+    len: impl TryInto<usize, Error = impl Debug>,
+) -> DumpValue {
+    if data.is_null() {
+        return DumpValue::Null;
+    }
+    let len: usize = len
+        .try_into()
+        .expect("len must be within the range of usize");
+    unsafe { std::slice::from_raw_parts(data, len).dump() }
+}
+
+/// Dumps the element at `data`, or [`DumpValue::Null`] if `data` is null.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn dump_ptr<T: Dump>(data: *const T) -> DumpValue {
+    unsafe { data.as_ref().dump() }
+}
+
+/// Dumps the elements that the array of `len` pointers at `data` points to.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn dump_ptr_slice<T: Dump>(
+    data: *const *const T,
+    len: impl TryInto<usize, Error = impl Debug>,
+) -> DumpValue {
+    if data.is_null() {
+        return DumpValue::Null;
+    }
+    let len: usize = len
+        .try_into()
+        .expect("len must be within the range of usize");
+    let elements = unsafe { std::slice::from_raw_parts(data, len) };
+    DumpValue::Array(
+        elements
+            .iter()
+            .map(|element| unsafe { dump_ptr(*element) })
+            .collect(),
+    )
+}
+
+/// Dumps the null-terminated string at `data`, or [`DumpValue::Null`] if `data` is null.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn dump_c_str(data: *const c_char) -> DumpValue {
+    if data.is_null() {
+        return DumpValue::Null;
+    }
+    let value = unsafe { CStr::from_ptr(data) };
+    DumpValue::String(value.to_string_lossy().into_owned())
+}
+
+/// Dumps the `len` null-terminated strings that the array at `data` points to.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn dump_c_str_slice(
+    data: *const *const c_char,
+    len: impl TryInto<usize, Error = impl Debug>,
+) -> DumpValue {
+    if data.is_null() {
+        return DumpValue::Null;
+    }
+    let len: usize = len
+        .try_into()
+        .expect("len must be within the range of usize");
+    let elements = unsafe { std::slice::from_raw_parts(data, len) };
+    DumpValue::Array(
+        elements
+            .iter()
+            .map(|element| unsafe { dump_c_str(*element) })
+            .collect(),
+    )
+}
+
+/// Dumps the string in a fixed size `char` array, e.g. `VkExtensionProperties::extensionName`.
+fn dump_c_char_array(value: &[c_char]) -> DumpValue {
+    let bytes = value
+        .iter()
+        .map(|c| *c as u8)
+        .take_while(|c| *c != 0)
+        .collect::<Vec<_>>();
+    DumpValue::String(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Whether `pTessellationState` of `create_info` is used, i.e. the pipeline has tessellation
+/// shader stages.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn uses_tessellation_state(create_info: &vk::GraphicsPipelineCreateInfo) -> bool {
+    if create_info.p_stages.is_null() {
+        return false;
+    }
+    let stages = unsafe {
+        std::slice::from_raw_parts(
+            create_info.p_stages,
+            create_info
+                .stage_count
+                .try_into()
+                .expect("len must be within the range of usize"),
+        )
+    };
+    stages.iter().any(|stage| {
+        stage.stage.intersects(
+            vk::ShaderStageFlags::TESSELLATION_CONTROL
+                | vk::ShaderStageFlags::TESSELLATION_EVALUATION,
+        )
+    })
+}
+
+/// Whether the states after the rasterization of `create_info`, e.g. `pViewportState`, are used,
+/// i.e. the rasterizer discard is not statically enabled.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn uses_rasterization_state(create_info: &vk::GraphicsPipelineCreateInfo) -> bool {
+    let Some(rasterization_state) = (unsafe { create_info.p_rasterization_state.as_ref() }) else {
+        return true;
+    };
+    if rasterization_state.rasterizer_discard_enable == vk::FALSE {
+        return true;
+    }
+    let Some(dynamic_state) = (unsafe { create_info.p_dynamic_state.as_ref() }) else {
+        return false;
+    };
+    if dynamic_state.p_dynamic_states.is_null() {
+        return false;
+    }
+    let dynamic_states = unsafe {
+        std::slice::from_raw_parts(
+            dynamic_state.p_dynamic_states,
+            dynamic_state
+                .dynamic_state_count
+                .try_into()
+                .expect("len must be within the range of usize"),
+        )
+    };
+    dynamic_states.contains(&vk::DynamicState::RASTERIZER_DISCARD_ENABLE)
+}
+
+/// The format of the commands dumped by [`ApiDump`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiDumpFormat {
+    /// One human-readable line per command, e.g.
+    /// `vkDestroyImage(image: 0x2a, pAllocator: null)`. The return value, if any, follows `->`.
+    Text,
+    /// One JSON object per line, with the `command` name, the `args` object and the `result`
+    /// member if the command returns anything. Handles and pointers are hexadecimal strings.
+    Json,
+}
+
+enum ApiDumpOutput {
+    Log,
+    Writer(Mutex<Box<dyn Write + Send>>),
+}
+
+/// Dumps every intercepted command with its parameters and return value, similar to the LunarG
+/// `api_dump` layer.
+///
+/// [`ApiDump`] implements [`DeviceHooks`](crate::DeviceHooks), [`DeviceInfo`],
+/// [`InstanceHooks`](crate::InstanceHooks) and [`InstanceInfo`], and hooks every command that has
+/// an after hook, so it can be used as [`Layer::DeviceInfo`](crate::Layer::DeviceInfo) and
+/// [`Layer::InstanceInfo`](crate::Layer::InstanceInfo) directly. A layer that has its own hooks can
+/// forward the after hooks it's interested in to an [`ApiDump`] instead.
+///
+/// A command is dumped after it returns from the next layer, so the outputs, e.g. the created
+/// handles, are dumped as well. The outputs are dumped as `null` if the command fails. The commands
+/// handled by the layer itself are not dumped, because the after hooks are not called for them.
+///
+/// Cloning an [`ApiDump`] is cheap, and the clones write to the same output, e.g. an instance and
+/// its devices can share one file.
+///
+/// # Examples
+///
+/// ```
+/// use ash::vk::{self, Handle};
+/// use std::sync::{Arc, Mutex};
+/// use vulkan_layer::{ApiDump, ApiDumpFormat, DeviceHooks};
+///
+/// #[derive(Clone, Default)]
+/// struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+///
+/// impl std::io::Write for SharedBuffer {
+///     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+///         self.0.lock().unwrap().write(buf)
+///     }
+///
+///     fn flush(&mut self) -> std::io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let buffer = SharedBuffer::default();
+/// let api_dump = ApiDump::to_writer(ApiDumpFormat::Text, buffer.clone());
+/// api_dump.after_destroy_image(vk::Image::from_raw(0x2a), None);
+/// assert_eq!(
+///     String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap(),
+///     "vkDestroyImage(image: 0x2a, pAllocator: null)\n"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ApiDump {
+    format: ApiDumpFormat,
+    output: Arc<ApiDumpOutput>,
+}
+
+impl ApiDump {
+    /// Dumps each command as an info level [`log`] record.
+    pub fn to_log(format: ApiDumpFormat) -> Self {
+        Self {
+            format,
+            output: Arc::new(ApiDumpOutput::Log),
+        }
+    }
+
+    /// Writes each command as a line to `writer`.
+    pub fn to_writer(format: ApiDumpFormat, writer: impl Write + Send + 'static) -> Self {
+        Self {
+            format,
+            output: Arc::new(ApiDumpOutput::Writer(Mutex::new(Box::new(writer)))),
+        }
+    }
+
+    /// Creates or truncates the file at `path`, and writes each command as a line to it. Each line
+    /// is flushed immediately, so that the file is complete even if the application crashes.
+    pub fn to_file(format: ApiDumpFormat, path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::to_writer(
+            format,
+            LineWriter::new(File::create(path)?),
+        ))
+    }
+
+    fn format_command(
+        &self,
+        name: &str,
+        args: &[(&str, DumpValue)],
+        result: Option<DumpValue>,
+    ) -> String {
+        let mut line = String::new();
+        match self.format {
+            ApiDumpFormat::Text => {
+                line.push_str(name);
+                line.push('(');
+                for (i, (arg_name, value)) in args.iter().enumerate() {
+                    if i > 0 {
+                        line.push_str(", ");
+                    }
+                    line.push_str(arg_name);
+                    line.push_str(": ");
+                    value.write_text(&mut line);
+                }
+                line.push(')');
+                if let Some(result) = result {
+                    line.push_str(" -> ");
+                    result.write_text(&mut line);
+                }
+            }
+            ApiDumpFormat::Json => {
+                line.push_str("{\"command\":");
+                JsonValue::write_string(&mut line, name);
+                line.push_str(",\"args\":");
+                write_json_object(&mut line, args);
+                if let Some(result) = result {
+                    line.push_str(",\"result\":");
+                    result.write_json(&mut line);
+                }
+                line.push('}');
+            }
+        }
+        line
+    }
+
+    fn write_command(&self, name: &str, args: &[(&str, DumpValue)], result: Option<DumpValue>) {
+        let line = self.format_command(name, args, result);
+        match self.output.as_ref() {
+            ApiDumpOutput::Log => info!("{line}"),
+            ApiDumpOutput::Writer(writer) => {
+                if let Err(e) = writeln!(writer.lock().unwrap(), "{line}") {
+                    warn!("Failed to write the API dump of {name}: {e}");
+                }
+            }
+        }
+    }
+}
+
+impl DeviceInfo for ApiDump {
+    type HooksType = Self;
+    type HooksRefType<'a> = &'a Self;
+
+    fn hooked_commands() -> &'static [LayerVulkanCommand] {
+        HOOKED_DEVICE_COMMANDS
+    }
+
+    fn hooks(&self) -> &Self {
+        self
+    }
+}
+
+impl InstanceInfo for ApiDump {
+    type HooksType = Self;
+    type HooksRefType<'a> = &'a Self;
+
+    fn hooked_commands() -> &'static [LayerVulkanCommand] {
+        HOOKED_INSTANCE_COMMANDS
+    }
+
+    fn hooks(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeviceHooks, InstanceHooks};
+    use ash::vk::Handle;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn lines(&self) -> Vec<String> {
+            String::from_utf8(self.0.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .map(str::to_owned)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_text_should_dump_flags_arrays_and_p_next_chain() {
+        let queue_family_indices = [0, 1];
+        let mut external_memory_info = vk::ExternalMemoryBufferCreateInfo::builder()
+            .handle_types(vk::ExternalMemoryHandleTypeFlags::OPAQUE_FD);
+        let create_info = vk::BufferCreateInfo::builder()
+            .size(256)
+            .usage(vk::BufferUsageFlags::TRANSFER_DST | vk::BufferUsageFlags::STORAGE_BUFFER)
+            .sharing_mode(vk::SharingMode::CONCURRENT)
+            .queue_family_indices(&queue_family_indices)
+            .push_next(&mut external_memory_info);
+        let buffer = SharedBuffer::default();
+        let api_dump = ApiDump::to_writer(ApiDumpFormat::Text, buffer.clone());
+        api_dump.after_create_buffer(
+            &create_info,
+            None,
+            Some(vk::Buffer::from_raw(0x42)),
+            vk::Result::SUCCESS,
+        );
+        assert_eq!(
+            buffer.lines(),
+            [concat!(
+                "vkCreateBuffer(pCreateInfo: VkBufferCreateInfo { sType: BUFFER_CREATE_INFO, ",
+                "pNext: VkExternalMemoryBufferCreateInfo { ",
+                "sType: EXTERNAL_MEMORY_BUFFER_CREATE_INFO, pNext: null, handleTypes: OPAQUE_FD }, ",
+                "flags: 0, size: 256, usage: TRANSFER_DST | STORAGE_BUFFER, ",
+                "sharingMode: CONCURRENT, queueFamilyIndexCount: 2, pQueueFamilyIndices: [0, 1] }, ",
+                "pAllocator: null, pBuffer: 0x42) -> SUCCESS"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_json_should_dump_one_object_per_command() {
+        let unknown = vk::BaseInStructure {
+            s_type: vk::StructureType::from_raw(0x7fff_fff0),
+            p_next: std::ptr::null(),
+        };
+        let mut allocate_info = vk::MemoryAllocateInfo::builder()
+            .allocation_size(4096)
+            .memory_type_index(1)
+            .build();
+        allocate_info.p_next = std::ptr::from_ref(&unknown).cast();
+        let buffer = SharedBuffer::default();
+        let api_dump = ApiDump::to_writer(ApiDumpFormat::Json, buffer.clone());
+        api_dump.after_allocate_memory(
+            &allocate_info,
+            None,
+            None,
+            vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+        );
+        api_dump.after_destroy_image(vk::Image::from_raw(0x2a), None);
+
+        let lines = buffer.lines();
+        assert_eq!(lines.len(), 2);
+        let command: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(
+            command,
+            serde_json::json!({
+                "command": "vkAllocateMemory",
+                "args": {
+                    "pAllocateInfo": {
+                        "sType": "MEMORY_ALLOCATE_INFO",
+                        "pNext": { "sType": "2147483632", "pNext": null },
+                        "allocationSize": 4096,
+                        "memoryTypeIndex": 1
+                    },
+                    "pAllocator": null,
+                    "pMemory": null
+                },
+                "result": "ERROR_OUT_OF_DEVICE_MEMORY"
+            })
+        );
+        let command: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+        assert_eq!(
+            command,
+            serde_json::json!({
+                "command": "vkDestroyImage",
+                "args": { "image": "0x2a", "pAllocator": null }
+            })
+        );
+    }
+
+    #[test]
+    fn test_ignored_pointers_should_not_be_followed() {
+        // Primary command buffers ignore pInheritanceInfo, so it can dangle.
+        let mut begin_info = vk::CommandBufferBeginInfo::builder()
+            .flags(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT)
+            .build();
+        begin_info.p_inheritance_info = 0xdead_0000 as *const _;
+        let buffer = SharedBuffer::default();
+        let api_dump = ApiDump::to_writer(ApiDumpFormat::Text, buffer.clone());
+        api_dump.after_begin_command_buffer(
+            vk::CommandBuffer::from_raw(0x10),
+            &begin_info,
+            vk::Result::SUCCESS,
+        );
+        assert_eq!(
+            buffer.lines(),
+            [concat!(
+                "vkBeginCommandBuffer(commandBuffer: 0x10, pBeginInfo: VkCommandBufferBeginInfo { ",
+                "sType: COMMAND_BUFFER_BEGIN_INFO, pNext: null, flags: ONE_TIME_SUBMIT, ",
+                "pInheritanceInfo: 0xdead0000 }) -> SUCCESS"
+            )]
+        );
+
+        // A pipeline without tessellation stages and with the rasterizer discard enabled ignores
+        // the tessellation state and the states after the rasterization.
+        let rasterization_state = vk::PipelineRasterizationStateCreateInfo::builder()
+            .rasterizer_discard_enable(true)
+            .build();
+        let mut create_info = vk::GraphicsPipelineCreateInfo::builder()
+            .rasterization_state(&rasterization_state)
+            .build();
+        create_info.p_tessellation_state = 0xdead_0010 as *const _;
+        create_info.p_viewport_state = 0xdead_0020 as *const _;
+        create_info.p_multisample_state = 0xdead_0030 as *const _;
+        create_info.p_depth_stencil_state = 0xdead_0040 as *const _;
+        create_info.p_color_blend_state = 0xdead_0050 as *const _;
+        let buffer = SharedBuffer::default();
+        let api_dump = ApiDump::to_writer(ApiDumpFormat::Text, buffer.clone());
+        api_dump.after_create_graphics_pipelines(
+            vk::PipelineCache::null(),
+            &[create_info],
+            None,
+            Some(&[vk::Pipeline::from_raw(0x20)]),
+            vk::Result::SUCCESS,
+        );
+        let lines = buffer.lines();
+        assert_eq!(lines.len(), 1);
+        for expected in [
+            "pTessellationState: 0xdead0010",
+            "pViewportState: 0xdead0020",
+            "pMultisampleState: 0xdead0030",
+            "pDepthStencilState: 0xdead0040",
+            "pColorBlendState: 0xdead0050",
+        ] {
+            assert!(
+                lines[0].contains(expected),
+                "{expected} not in {}",
+                lines[0]
+            );
+        }
+    }
+
+    #[test]
+    fn test_null_output_arrays_should_be_dumped_as_null() {
+        let properties = [vk::QueueFamilyProperties::builder()
+            .queue_flags(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE)
+            .queue_count(4)
+            .build()];
+        let buffer = SharedBuffer::default();
+        let api_dump = ApiDump::to_writer(ApiDumpFormat::Text, buffer.clone());
+        api_dump.after_get_physical_device_queue_family_properties(
+            vk::PhysicalDevice::from_raw(0x10),
+            &1,
+            Some(&properties),
+        );
+        api_dump.after_get_physical_device_queue_family_properties(
+            vk::PhysicalDevice::from_raw(0x10),
+            &1,
+            None,
+        );
+        assert_eq!(
+            buffer.lines(),
+            [
+                concat!(
+                    "vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice: 0x10, ",
+                    "pQueueFamilyPropertyCount: 1, pQueueFamilyProperties: [",
+                    "VkQueueFamilyProperties { queueFlags: GRAPHICS | COMPUTE, queueCount: 4, ",
+                    "timestampValidBits: 0, minImageTransferGranularity: ",
+                    "VkExtent3D { width: 0, height: 0, depth: 0 } }])"
+                ),
+                concat!(
+                    "vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice: 0x10, ",
+                    "pQueueFamilyPropertyCount: 1, pQueueFamilyProperties: null)"
+                ),
+            ]
+        );
+    }
+}
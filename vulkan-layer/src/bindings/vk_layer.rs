@@ -23,12 +23,34 @@ use ash::vk;
 
 mod generated;
 
+/// Bindings for the C `PFN_GetPhysicalDeviceProcAddr` type defined in the `vk_layer.h` file.
+pub use generated::PFN_GetPhysicalDeviceProcAddr;
 /// Bindings for the C `PFN_vkLayerCreateDevice` type defined in the `vk_layer.h` file.
 pub use generated::PFN_vkLayerCreateDevice;
 /// Bindings for the C `PFN_vkLayerDestroyDevice` type defined in the `vk_layer.h` file.
 pub use generated::PFN_vkLayerDestroyDevice;
+/// Bindings for the C `PFN_vkSetDeviceLoaderData` type defined in the `vk_layer.h` file.
+pub use generated::PFN_vkSetDeviceLoaderData;
 /// Bindings for the C `PFN_vkSetInstanceLoaderData` type defined in the `vk_layer.h` file.
 pub use generated::PFN_vkSetInstanceLoaderData;
+/// Bindings for the C `VkChainHeader` type defined in the `vk_layer.h` file.
+///
+/// The common header of the pre-instance function chain structures, e.g.
+/// [`VkEnumerateInstanceVersionChain`].
+pub use generated::VkChainHeader;
+/// Bindings for the C `VkChainType` type defined in the `vk_layer.h` file.
+///
+/// Identifies the pre-instance function chain structure type in [`VkChainHeader`].
+pub use generated::VkChainType;
+/// A list node of the pre-instance `vkEnumerateInstanceExtensionProperties` call chain. Bindings
+/// for the C `VkEnumerateInstanceExtensionPropertiesChain` type defined in the `vk_layer.h` file.
+pub use generated::VkEnumerateInstanceExtensionPropertiesChain;
+/// A list node of the pre-instance `vkEnumerateInstanceLayerProperties` call chain. Bindings for
+/// the C `VkEnumerateInstanceLayerPropertiesChain` type defined in the `vk_layer.h` file.
+pub use generated::VkEnumerateInstanceLayerPropertiesChain;
+/// A list node of the pre-instance `vkEnumerateInstanceVersion` call chain. Bindings for the C
+/// `VkEnumerateInstanceVersionChain` type defined in the `vk_layer.h` file.
+pub use generated::VkEnumerateInstanceVersionChain;
 /// Sub type of structure for instance and device loader ext of CreateInfo. Bindings for the C
 /// `VkLayerDeviceCreateInfo` type defined in the `vk_layer.h` file.
 ///
@@ -47,6 +69,18 @@ pub use generated::VkLayerFunction;
 /// A list node that contains the next entity's vkGetInstanceProcAddr used by a layer. One
 /// possible payload of [`VkLayerInstanceCreateInfo`].
 pub use generated::VkLayerInstanceLink;
+/// Bindings for the C `VkNegotiateLayerInterface` type defined in the `vk_layer.h` file.
+///
+/// Used by the loader and the layer to agree on the loader-layer interface version in
+/// `vkNegotiateLoaderLayerInterfaceVersion`, and by the layer to report its `vkGet*ProcAddr` entry
+/// points when the negotiated version is 2 or greater.
+pub use generated::VkNegotiateLayerInterface;
+/// Bindings for the C `VkNegotiateLayerStructType` type defined in the `vk_layer.h` file.
+///
+/// Identifies the structure type of [`VkNegotiateLayerInterface`].
+pub use generated::VkNegotiateLayerStructType;
+/// The version of the pre-instance function chain structures implemented by the layer framework.
+pub use generated::VK_CURRENT_CHAIN_VERSION;
 
 type VkInstance = vk::Instance;
 type VkPhysicalDevice = vk::PhysicalDevice;
@@ -55,6 +89,8 @@ type VkStructureType = vk::StructureType;
 type VkResult = vk::Result;
 type VkDeviceCreateInfo = vk::DeviceCreateInfo;
 type VkAllocationCallbacks = vk::AllocationCallbacks;
+type VkExtensionProperties = vk::ExtensionProperties;
+type VkLayerProperties = vk::LayerProperties;
 
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -119,3 +155,60 @@ unsafe impl vk::TaggedStructure for VkLayerDeviceCreateInfo {
 }
 
 unsafe impl vk::ExtendsDeviceCreateInfo for VkLayerDeviceCreateInfo {}
+
+impl VkEnumerateInstanceExtensionPropertiesChain {
+    /// Calls into the `vkEnumerateInstanceExtensionProperties` of the next layer in the chain.
+    ///
+    /// # Safety
+    /// `self` must be a valid chain node passed from the loader or the previous layer, and the
+    /// arguments must follow the valid usage of `vkEnumerateInstanceExtensionProperties`.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn call_down(
+        &self,
+        p_layer_name: *const ::std::os::raw::c_char,
+        p_property_count: *mut u32,
+        p_properties: *mut vk::ExtensionProperties,
+    ) -> vk::Result {
+        let next_layer = self
+            .pfnNextLayer
+            .expect("pfnNextLayer of the chain must be set by the loader.");
+        // Safe because the caller guarantees that the chain and arguments are valid.
+        unsafe { next_layer(self.pNextLink, p_layer_name, p_property_count, p_properties) }
+    }
+}
+
+impl VkEnumerateInstanceLayerPropertiesChain {
+    /// Calls into the `vkEnumerateInstanceLayerProperties` of the next layer in the chain.
+    ///
+    /// # Safety
+    /// `self` must be a valid chain node passed from the loader or the previous layer, and the
+    /// arguments must follow the valid usage of `vkEnumerateInstanceLayerProperties`.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn call_down(
+        &self,
+        p_property_count: *mut u32,
+        p_properties: *mut vk::LayerProperties,
+    ) -> vk::Result {
+        let next_layer = self
+            .pfnNextLayer
+            .expect("pfnNextLayer of the chain must be set by the loader.");
+        // Safe because the caller guarantees that the chain and arguments are valid.
+        unsafe { next_layer(self.pNextLink, p_property_count, p_properties) }
+    }
+}
+
+impl VkEnumerateInstanceVersionChain {
+    /// Calls into the `vkEnumerateInstanceVersion` of the next layer in the chain.
+    ///
+    /// # Safety
+    /// `self` must be a valid chain node passed from the loader or the previous layer, and
+    /// `p_api_version` must be a valid pointer to a `u32`.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn call_down(&self, p_api_version: *mut u32) -> vk::Result {
+        let next_layer = self
+            .pfnNextLayer
+            .expect("pfnNextLayer of the chain must be set by the loader.");
+        // Safe because the caller guarantees that the chain and arguments are valid.
+        unsafe { next_layer(self.pNextLink, p_api_version) }
+    }
+}
@@ -26,7 +26,7 @@ pub use windows::*;
 /* automatically generated by rust-bindgen 0.69.4 */
 
 pub type PFN_GetPhysicalDeviceProcAddr = ::std::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         instance: VkInstance,
         pName: *const ::std::os::raw::c_char,
     ) -> PFN_vkVoidFunction,
@@ -404,3 +404,277 @@ impl Default for VkLayerDeviceCreateInfo {
         }
     }
 }
+pub const VK_CURRENT_CHAIN_VERSION: u32 = 1;
+#[repr(C)]
+pub struct VkChainHeader {
+    pub type_: VkChainType,
+    pub version: u32,
+    pub size: u32,
+}
+#[test]
+fn bindgen_test_layout_VkChainHeader() {
+    const UNINIT: ::std::mem::MaybeUninit<VkChainHeader> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<VkChainHeader>(),
+        12usize,
+        concat!("Size of: ", stringify!(VkChainHeader))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<VkChainHeader>(),
+        4usize,
+        concat!("Alignment of ", stringify!(VkChainHeader))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkChainHeader),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkChainHeader),
+            "::",
+            stringify!(version)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkChainHeader),
+            "::",
+            stringify!(size)
+        )
+    );
+}
+impl Default for VkChainHeader {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+pub struct VkEnumerateInstanceExtensionPropertiesChain {
+    pub header: VkChainHeader,
+    pub pfnNextLayer: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *const VkEnumerateInstanceExtensionPropertiesChain,
+            arg2: *const ::std::os::raw::c_char,
+            arg3: *mut u32,
+            arg4: *mut VkExtensionProperties,
+        ) -> VkResult,
+    >,
+    pub pNextLink: *const VkEnumerateInstanceExtensionPropertiesChain,
+}
+#[test]
+fn bindgen_test_layout_VkEnumerateInstanceExtensionPropertiesChain() {
+    const UNINIT: ::std::mem::MaybeUninit<VkEnumerateInstanceExtensionPropertiesChain> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<VkEnumerateInstanceExtensionPropertiesChain>(),
+        32usize,
+        concat!(
+            "Size of: ",
+            stringify!(VkEnumerateInstanceExtensionPropertiesChain)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<VkEnumerateInstanceExtensionPropertiesChain>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(VkEnumerateInstanceExtensionPropertiesChain)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).header) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceExtensionPropertiesChain),
+            "::",
+            stringify!(header)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pfnNextLayer) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceExtensionPropertiesChain),
+            "::",
+            stringify!(pfnNextLayer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pNextLink) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceExtensionPropertiesChain),
+            "::",
+            stringify!(pNextLink)
+        )
+    );
+}
+impl Default for VkEnumerateInstanceExtensionPropertiesChain {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+pub struct VkEnumerateInstanceLayerPropertiesChain {
+    pub header: VkChainHeader,
+    pub pfnNextLayer: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *const VkEnumerateInstanceLayerPropertiesChain,
+            arg2: *mut u32,
+            arg3: *mut VkLayerProperties,
+        ) -> VkResult,
+    >,
+    pub pNextLink: *const VkEnumerateInstanceLayerPropertiesChain,
+}
+#[test]
+fn bindgen_test_layout_VkEnumerateInstanceLayerPropertiesChain() {
+    const UNINIT: ::std::mem::MaybeUninit<VkEnumerateInstanceLayerPropertiesChain> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<VkEnumerateInstanceLayerPropertiesChain>(),
+        32usize,
+        concat!(
+            "Size of: ",
+            stringify!(VkEnumerateInstanceLayerPropertiesChain)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<VkEnumerateInstanceLayerPropertiesChain>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(VkEnumerateInstanceLayerPropertiesChain)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).header) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceLayerPropertiesChain),
+            "::",
+            stringify!(header)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pfnNextLayer) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceLayerPropertiesChain),
+            "::",
+            stringify!(pfnNextLayer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pNextLink) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceLayerPropertiesChain),
+            "::",
+            stringify!(pNextLink)
+        )
+    );
+}
+impl Default for VkEnumerateInstanceLayerPropertiesChain {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+pub struct VkEnumerateInstanceVersionChain {
+    pub header: VkChainHeader,
+    pub pfnNextLayer: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *const VkEnumerateInstanceVersionChain,
+            arg2: *mut u32,
+        ) -> VkResult,
+    >,
+    pub pNextLink: *const VkEnumerateInstanceVersionChain,
+}
+#[test]
+fn bindgen_test_layout_VkEnumerateInstanceVersionChain() {
+    const UNINIT: ::std::mem::MaybeUninit<VkEnumerateInstanceVersionChain> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<VkEnumerateInstanceVersionChain>(),
+        32usize,
+        concat!("Size of: ", stringify!(VkEnumerateInstanceVersionChain))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<VkEnumerateInstanceVersionChain>(),
+        8usize,
+        concat!("Alignment of ", stringify!(VkEnumerateInstanceVersionChain))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).header) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceVersionChain),
+            "::",
+            stringify!(header)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pfnNextLayer) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceVersionChain),
+            "::",
+            stringify!(pfnNextLayer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pNextLink) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(VkEnumerateInstanceVersionChain),
+            "::",
+            stringify!(pNextLink)
+        )
+    );
+}
+impl Default for VkEnumerateInstanceVersionChain {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
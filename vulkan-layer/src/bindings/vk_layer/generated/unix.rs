@@ -41,3 +41,21 @@ impl VkLayerFunction_ {
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct VkLayerFunction_(pub ::std::os::raw::c_uint);
 pub use self::VkLayerFunction_ as VkLayerFunction;
+impl VkChainType {
+    pub const VK_CHAIN_TYPE_UNKNOWN: VkChainType = VkChainType(0);
+}
+impl VkChainType {
+    pub const VK_CHAIN_TYPE_ENUMERATE_INSTANCE_EXTENSION_PROPERTIES: VkChainType = VkChainType(1);
+}
+impl VkChainType {
+    pub const VK_CHAIN_TYPE_ENUMERATE_INSTANCE_LAYER_PROPERTIES: VkChainType = VkChainType(2);
+}
+impl VkChainType {
+    pub const VK_CHAIN_TYPE_ENUMERATE_INSTANCE_VERSION: VkChainType = VkChainType(3);
+}
+impl VkChainType {
+    pub const VK_CHAIN_TYPE_MAX_ENUM: VkChainType = VkChainType(2147483647);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct VkChainType(pub ::std::os::raw::c_uint);
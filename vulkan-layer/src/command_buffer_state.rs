@@ -0,0 +1,837 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The built-in tracker of the state bound to a `VkCommandBuffer` during the recording.
+//!
+//! The tracking is enabled by [`Layer::track_command_buffer_state`]. The layer framework then
+//! intercepts the `vkCmd*` commands in [`TRACKED_COMMANDS`] even if the layer implementation
+//! doesn't hook them, and updates the [`CommandBufferState`] when the command returns from the
+//! next layer, where the command is also recorded, so the hooks of a command observe the state
+//! before that command, and the `after_*` hooks observe the state after it. A command handled by
+//! a hook with [`LayerResult::Handled`](crate::LayerResult::Handled) never reaches the next layer,
+//! so it doesn't update the state.
+
+use ash::vk;
+use std::collections::BTreeMap;
+
+use crate::{Global, Layer, LayerVulkanCommand, RecordedCommand, VulkanBaseInStructChain};
+
+/// The `vkCmd*` commands that update the [`CommandBufferState`].
+pub(crate) const TRACKED_COMMANDS: &[LayerVulkanCommand] = &[
+    LayerVulkanCommand::CmdBindPipeline,
+    LayerVulkanCommand::CmdBindDescriptorSets,
+    LayerVulkanCommand::CmdBindVertexBuffers,
+    LayerVulkanCommand::CmdBindVertexBuffers2,
+    LayerVulkanCommand::CmdBindIndexBuffer,
+    LayerVulkanCommand::CmdSetViewport,
+    LayerVulkanCommand::CmdSetScissor,
+    LayerVulkanCommand::CmdSetLineWidth,
+    LayerVulkanCommand::CmdSetDepthBias,
+    LayerVulkanCommand::CmdSetBlendConstants,
+    LayerVulkanCommand::CmdSetDepthBounds,
+    LayerVulkanCommand::CmdSetStencilCompareMask,
+    LayerVulkanCommand::CmdSetStencilWriteMask,
+    LayerVulkanCommand::CmdSetStencilReference,
+    LayerVulkanCommand::CmdSetCullMode,
+    LayerVulkanCommand::CmdSetFrontFace,
+    LayerVulkanCommand::CmdSetPrimitiveTopology,
+    LayerVulkanCommand::CmdSetViewportWithCount,
+    LayerVulkanCommand::CmdSetScissorWithCount,
+    LayerVulkanCommand::CmdSetDepthTestEnable,
+    LayerVulkanCommand::CmdSetDepthWriteEnable,
+    LayerVulkanCommand::CmdSetDepthCompareOp,
+    LayerVulkanCommand::CmdSetDepthBoundsTestEnable,
+    LayerVulkanCommand::CmdSetStencilTestEnable,
+    LayerVulkanCommand::CmdSetStencilOp,
+    LayerVulkanCommand::CmdSetRasterizerDiscardEnable,
+    LayerVulkanCommand::CmdSetDepthBiasEnable,
+    LayerVulkanCommand::CmdSetPrimitiveRestartEnable,
+    LayerVulkanCommand::CmdSetPatchControlPointsExt,
+    LayerVulkanCommand::CmdSetLogicOpExt,
+    LayerVulkanCommand::CmdBeginRenderPass,
+    LayerVulkanCommand::CmdBeginRenderPass2,
+    LayerVulkanCommand::CmdNextSubpass,
+    LayerVulkanCommand::CmdNextSubpass2,
+    LayerVulkanCommand::CmdEndRenderPass,
+    LayerVulkanCommand::CmdEndRenderPass2,
+    LayerVulkanCommand::CmdBeginRendering,
+    LayerVulkanCommand::CmdEndRendering,
+    LayerVulkanCommand::CmdExecuteCommands,
+];
+
+/// A descriptor set bound by `vkCmdBindDescriptorSets`.
+#[derive(Clone, Debug)]
+pub struct BoundDescriptorSet {
+    /// The `VkPipelineLayout` used to bind the descriptor set.
+    pub layout: vk::PipelineLayout,
+    /// The bound `VkDescriptorSet`.
+    pub descriptor_set: vk::DescriptorSet,
+    /// All the dynamic offsets passed to the `vkCmdBindDescriptorSets` call that binds this
+    /// descriptor set. The framework doesn't track the descriptor set layouts, so the offsets are
+    /// not split among the descriptor sets bound by the same call.
+    pub dynamic_offsets: Vec<u32>,
+}
+
+/// A vertex buffer bound by `vkCmdBindVertexBuffers` or `vkCmdBindVertexBuffers2`.
+#[derive(Clone, Copy, Debug)]
+pub struct VertexBufferBinding {
+    /// The bound `VkBuffer`.
+    pub buffer: vk::Buffer,
+    /// The start offset in `buffer`.
+    pub offset: vk::DeviceSize,
+    /// The size passed to `vkCmdBindVertexBuffers2`. [`None`] if not specified.
+    pub size: Option<vk::DeviceSize>,
+    /// The stride passed to `vkCmdBindVertexBuffers2`. [`None`] if not specified.
+    pub stride: Option<vk::DeviceSize>,
+}
+
+/// The index buffer bound by `vkCmdBindIndexBuffer`.
+#[derive(Clone, Copy, Debug)]
+pub struct IndexBufferBinding {
+    /// The bound `VkBuffer`.
+    pub buffer: vk::Buffer,
+    /// The start offset in `buffer`.
+    pub offset: vk::DeviceSize,
+    /// The type of the indices.
+    pub index_type: vk::IndexType,
+}
+
+/// The depth bias factors set by `vkCmdSetDepthBias`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DepthBias {
+    /// `depthBiasConstantFactor`.
+    pub constant_factor: f32,
+    /// `depthBiasClamp`.
+    pub clamp: f32,
+    /// `depthBiasSlopeFactor`.
+    pub slope_factor: f32,
+}
+
+/// The stencil operations set by `vkCmdSetStencilOp`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StencilOps {
+    /// `failOp`.
+    pub fail_op: vk::StencilOp,
+    /// `passOp`.
+    pub pass_op: vk::StencilOp,
+    /// `depthFailOp`.
+    pub depth_fail_op: vk::StencilOp,
+    /// `compareOp`.
+    pub compare_op: vk::CompareOp,
+}
+
+/// A stencil value set separately for the front and the back faces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StencilFaceValues<T = u32> {
+    /// The value for the front faces. [`None`] if never set.
+    pub front: Option<T>,
+    /// The value for the back faces. [`None`] if never set.
+    pub back: Option<T>,
+}
+
+// Not derived, because the derived implementation requires T: Default.
+impl<T> Default for StencilFaceValues<T> {
+    fn default() -> Self {
+        Self {
+            front: None,
+            back: None,
+        }
+    }
+}
+
+impl<T: Copy> StencilFaceValues<T> {
+    fn set(&mut self, face_mask: vk::StencilFaceFlags, value: T) {
+        if face_mask.contains(vk::StencilFaceFlags::FRONT) {
+            self.front = Some(value);
+        }
+        if face_mask.contains(vk::StencilFaceFlags::BACK) {
+            self.back = Some(value);
+        }
+    }
+}
+
+/// The dynamic states set by the `vkCmdSet*` commands.
+///
+/// Covers the dynamic states of Vulkan 1.0, Vulkan 1.3, `VK_EXT_extended_dynamic_state` and
+/// `VK_EXT_extended_dynamic_state2`. The other dynamic states, e.g. the ones of
+/// `VK_EXT_extended_dynamic_state3` and `VK_EXT_vertex_input_dynamic_state`, are not tracked.
+///
+/// A state is [`None`] or absent if it is not set since the beginning of the recording or the last
+/// `vkCmdExecuteCommands`.
+#[derive(Clone, Debug, Default)]
+pub struct DynamicState {
+    /// The viewports indexed by the viewport index. `vkCmdSetViewportWithCount` replaces all the
+    /// viewports.
+    pub viewports: BTreeMap<u32, vk::Viewport>,
+    /// The scissors indexed by the scissor index. `vkCmdSetScissorWithCount` replaces all the
+    /// scissors.
+    pub scissors: BTreeMap<u32, vk::Rect2D>,
+    /// The line width.
+    pub line_width: Option<f32>,
+    /// The depth bias factors.
+    pub depth_bias: Option<DepthBias>,
+    /// The blend constants.
+    pub blend_constants: Option<[f32; 4]>,
+    /// The minimum and the maximum depth bounds.
+    pub depth_bounds: Option<(f32, f32)>,
+    /// The stencil compare masks.
+    pub stencil_compare_mask: StencilFaceValues,
+    /// The stencil write masks.
+    pub stencil_write_mask: StencilFaceValues,
+    /// The stencil references.
+    pub stencil_reference: StencilFaceValues,
+    /// The cull mode.
+    pub cull_mode: Option<vk::CullModeFlags>,
+    /// The front face orientation.
+    pub front_face: Option<vk::FrontFace>,
+    /// The primitive topology.
+    pub primitive_topology: Option<vk::PrimitiveTopology>,
+    /// Whether the depth test is enabled.
+    pub depth_test_enable: Option<bool>,
+    /// Whether the depth writes are enabled.
+    pub depth_write_enable: Option<bool>,
+    /// The depth comparison operator.
+    pub depth_compare_op: Option<vk::CompareOp>,
+    /// Whether the depth bounds test is enabled.
+    pub depth_bounds_test_enable: Option<bool>,
+    /// Whether the stencil test is enabled.
+    pub stencil_test_enable: Option<bool>,
+    /// The stencil operations.
+    pub stencil_op: StencilFaceValues<StencilOps>,
+    /// Whether the primitives are discarded before the rasterization.
+    pub rasterizer_discard_enable: Option<bool>,
+    /// Whether the depth bias is enabled.
+    pub depth_bias_enable: Option<bool>,
+    /// Whether the primitive restart is enabled.
+    pub primitive_restart_enable: Option<bool>,
+    /// The number of control points per patch, set by `vkCmdSetPatchControlPointsEXT`.
+    pub patch_control_points: Option<u32>,
+    /// The logical operation, set by `vkCmdSetLogicOpEXT`.
+    pub logic_op: Option<vk::LogicOp>,
+}
+
+/// The active render pass instance or dynamic rendering scope.
+#[derive(Clone, Copy, Debug)]
+pub enum RenderScope {
+    /// Inside a render pass instance begun by `vkCmdBeginRenderPass` or `vkCmdBeginRenderPass2`,
+    /// or inherited by a secondary command buffer from `VkCommandBufferInheritanceInfo`.
+    RenderPass {
+        /// The `VkRenderPass`.
+        render_pass: vk::RenderPass,
+        /// The `VkFramebuffer`. Can be `VK_NULL_HANDLE` in a secondary command buffer.
+        framebuffer: vk::Framebuffer,
+        /// The index of the current subpass.
+        subpass: u32,
+        /// The render area. [`None`] if the render pass instance is inherited.
+        render_area: Option<vk::Rect2D>,
+    },
+    /// Inside a dynamic rendering scope begun by `vkCmdBeginRendering`, or inherited by a
+    /// secondary command buffer from `VkCommandBufferInheritanceRenderingInfo`.
+    DynamicRendering {
+        /// The `VkRenderingFlags`.
+        flags: vk::RenderingFlags,
+        /// The view mask for multiview.
+        view_mask: u32,
+        /// The number of color attachments.
+        color_attachment_count: u32,
+        /// The render area. [`None`] if the dynamic rendering scope is inherited.
+        render_area: Option<vk::Rect2D>,
+    },
+}
+
+/// The state bound to a `VkCommandBuffer` during the recording, tracked by the layer framework if
+/// [`Layer::track_command_buffer_state`] returns `true`.
+///
+/// Obtained through [`CommandBufferInfoRef::state`](crate::CommandBufferInfoRef::state). The state
+/// is reset when the command buffer is reset. Following the Vulkan spec, the bound pipelines,
+/// descriptor sets, vertex and index buffers and dynamic states of a primary command buffer become
+/// unknown after `vkCmdExecuteCommands`, so they are cleared, while the render scope is kept. A
+/// secondary command buffer begun with `VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT` starts
+/// with the inherited render scope.
+///
+/// The state reflects the commands passed to the next layer: the arguments rewritten by
+/// [`LayerResult::Continue`](crate::LayerResult::Continue) are tracked, and the commands handled
+/// with [`LayerResult::Handled`](crate::LayerResult::Handled) are not.
+#[derive(Clone, Debug, Default)]
+pub struct CommandBufferState {
+    pipelines: BTreeMap<vk::PipelineBindPoint, vk::Pipeline>,
+    descriptor_sets: BTreeMap<vk::PipelineBindPoint, BTreeMap<u32, BoundDescriptorSet>>,
+    vertex_buffers: BTreeMap<u32, VertexBufferBinding>,
+    index_buffer: Option<IndexBufferBinding>,
+    dynamic_state: DynamicState,
+    render_scope: Option<RenderScope>,
+    executed_command_buffers: Vec<vk::CommandBuffer>,
+}
+
+impl CommandBufferState {
+    /// The pipeline bound to `pipeline_bind_point`.
+    pub fn pipeline(&self, pipeline_bind_point: vk::PipelineBindPoint) -> Option<vk::Pipeline> {
+        self.pipelines.get(&pipeline_bind_point).copied()
+    }
+
+    /// The descriptor set bound to the set number `set` of `pipeline_bind_point`.
+    pub fn descriptor_set(
+        &self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        set: u32,
+    ) -> Option<&BoundDescriptorSet> {
+        self.descriptor_sets.get(&pipeline_bind_point)?.get(&set)
+    }
+
+    /// All the descriptor sets bound to `pipeline_bind_point` with their set numbers, in the order
+    /// of the set numbers.
+    ///
+    /// The framework doesn't check the pipeline layout compatibility, so a descriptor set bound
+    /// with a layout incompatible with the later bound ones is still returned. Use
+    /// [`BoundDescriptorSet::layout`] to check the compatibility if needed.
+    pub fn descriptor_sets(
+        &self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+    ) -> impl Iterator<Item = (u32, &BoundDescriptorSet)> {
+        self.descriptor_sets
+            .get(&pipeline_bind_point)
+            .into_iter()
+            .flat_map(|descriptor_sets| {
+                descriptor_sets
+                    .iter()
+                    .map(|(set, descriptor_set)| (*set, descriptor_set))
+            })
+    }
+
+    /// The vertex buffer bound to the vertex input binding `binding`.
+    pub fn vertex_buffer(&self, binding: u32) -> Option<&VertexBufferBinding> {
+        self.vertex_buffers.get(&binding)
+    }
+
+    /// All the bound vertex buffers with their binding numbers, in the order of the binding
+    /// numbers.
+    pub fn vertex_buffers(&self) -> impl Iterator<Item = (u32, &VertexBufferBinding)> {
+        self.vertex_buffers
+            .iter()
+            .map(|(binding, vertex_buffer)| (*binding, vertex_buffer))
+    }
+
+    /// The bound index buffer.
+    pub fn index_buffer(&self) -> Option<&IndexBufferBinding> {
+        self.index_buffer.as_ref()
+    }
+
+    /// The dynamic states.
+    pub fn dynamic_state(&self) -> &DynamicState {
+        &self.dynamic_state
+    }
+
+    /// The active render pass instance or dynamic rendering scope. [`None`] if outside of any
+    /// render scope.
+    pub fn render_scope(&self) -> Option<&RenderScope> {
+        self.render_scope.as_ref()
+    }
+
+    /// The secondary command buffers recorded into this command buffer with
+    /// `vkCmdExecuteCommands`, in the order of the recording.
+    pub fn executed_command_buffers(&self) -> &[vk::CommandBuffer] {
+        &self.executed_command_buffers
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Default::default();
+    }
+
+    pub(crate) fn begin(
+        &mut self,
+        level: vk::CommandBufferLevel,
+        begin_info: &vk::CommandBufferBeginInfo,
+    ) {
+        self.reset();
+        if level != vk::CommandBufferLevel::SECONDARY
+            || !begin_info
+                .flags
+                .contains(vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE)
+        {
+            return;
+        }
+        let Some(inheritance_info) = (unsafe { begin_info.p_inheritance_info.as_ref() }) else {
+            return;
+        };
+        if inheritance_info.render_pass != vk::RenderPass::null() {
+            self.render_scope = Some(RenderScope::RenderPass {
+                render_pass: inheritance_info.render_pass,
+                framebuffer: inheritance_info.framebuffer,
+                subpass: inheritance_info.subpass,
+                render_area: None,
+            });
+            return;
+        }
+        let mut p_next_chain: VulkanBaseInStructChain =
+            unsafe { (inheritance_info.p_next as *const vk::BaseInStructure).as_ref() }.into();
+        self.render_scope = p_next_chain.find_map(|in_struct| {
+            let in_struct = in_struct as *const vk::BaseInStructure;
+            unsafe {
+                ash::match_in_struct!(match in_struct {
+                    rendering_info @ vk::CommandBufferInheritanceRenderingInfo => {
+                        Some(RenderScope::DynamicRendering {
+                            flags: rendering_info.flags,
+                            view_mask: rendering_info.view_mask,
+                            color_attachment_count: rendering_info.color_attachment_count,
+                            render_area: None,
+                        })
+                    }
+                    _ => {
+                        None
+                    }
+                })
+            }
+        });
+    }
+
+    /// Updates the state with a command passed to the next layer.
+    pub(crate) fn update(&mut self, command: &RecordedCommand) {
+        match command {
+            RecordedCommand::CmdBindPipeline(args) => {
+                self.bind_pipeline(args.pipeline_bind_point, args.pipeline)
+            }
+            RecordedCommand::CmdBindDescriptorSets(args) => self.bind_descriptor_sets(
+                args.pipeline_bind_point,
+                args.layout,
+                args.first_set,
+                &args.p_descriptor_sets,
+                &args.p_dynamic_offsets,
+            ),
+            RecordedCommand::CmdBindVertexBuffers(args) => self.bind_vertex_buffers(
+                args.first_binding,
+                &args.p_buffers,
+                &args.p_offsets,
+                None,
+                None,
+            ),
+            RecordedCommand::CmdBindVertexBuffers2(args) => self.bind_vertex_buffers(
+                args.first_binding,
+                &args.p_buffers,
+                &args.p_offsets,
+                args.p_sizes.as_deref(),
+                args.p_strides.as_deref(),
+            ),
+            RecordedCommand::CmdBindIndexBuffer(args) => {
+                self.bind_index_buffer(args.buffer, args.offset, args.index_type)
+            }
+            RecordedCommand::CmdSetViewport(args) => {
+                self.set_viewports(args.first_viewport, &args.p_viewports)
+            }
+            RecordedCommand::CmdSetScissor(args) => {
+                self.set_scissors(args.first_scissor, &args.p_scissors)
+            }
+            RecordedCommand::CmdSetLineWidth(args) => {
+                self.dynamic_state.line_width = Some(args.line_width)
+            }
+            RecordedCommand::CmdSetDepthBias(args) => {
+                self.dynamic_state.depth_bias = Some(DepthBias {
+                    constant_factor: args.depth_bias_constant_factor,
+                    clamp: args.depth_bias_clamp,
+                    slope_factor: args.depth_bias_slope_factor,
+                })
+            }
+            RecordedCommand::CmdSetBlendConstants(args) => {
+                self.dynamic_state.blend_constants = Some(args.blend_constants)
+            }
+            RecordedCommand::CmdSetDepthBounds(args) => {
+                self.dynamic_state.depth_bounds =
+                    Some((args.min_depth_bounds, args.max_depth_bounds))
+            }
+            RecordedCommand::CmdSetStencilCompareMask(args) => self
+                .dynamic_state
+                .stencil_compare_mask
+                .set(args.face_mask, args.compare_mask),
+            RecordedCommand::CmdSetStencilWriteMask(args) => self
+                .dynamic_state
+                .stencil_write_mask
+                .set(args.face_mask, args.write_mask),
+            RecordedCommand::CmdSetStencilReference(args) => self
+                .dynamic_state
+                .stencil_reference
+                .set(args.face_mask, args.reference),
+            RecordedCommand::CmdSetCullMode(args) => {
+                self.dynamic_state.cull_mode = Some(args.cull_mode)
+            }
+            RecordedCommand::CmdSetFrontFace(args) => {
+                self.dynamic_state.front_face = Some(args.front_face)
+            }
+            RecordedCommand::CmdSetPrimitiveTopology(args) => {
+                self.dynamic_state.primitive_topology = Some(args.primitive_topology)
+            }
+            RecordedCommand::CmdSetViewportWithCount(args) => {
+                self.set_viewports_with_count(&args.p_viewports)
+            }
+            RecordedCommand::CmdSetScissorWithCount(args) => {
+                self.set_scissors_with_count(&args.p_scissors)
+            }
+            RecordedCommand::CmdSetDepthTestEnable(args) => {
+                self.dynamic_state.depth_test_enable = Some(args.depth_test_enable)
+            }
+            RecordedCommand::CmdSetDepthWriteEnable(args) => {
+                self.dynamic_state.depth_write_enable = Some(args.depth_write_enable)
+            }
+            RecordedCommand::CmdSetDepthCompareOp(args) => {
+                self.dynamic_state.depth_compare_op = Some(args.depth_compare_op)
+            }
+            RecordedCommand::CmdSetDepthBoundsTestEnable(args) => {
+                self.dynamic_state.depth_bounds_test_enable = Some(args.depth_bounds_test_enable)
+            }
+            RecordedCommand::CmdSetStencilTestEnable(args) => {
+                self.dynamic_state.stencil_test_enable = Some(args.stencil_test_enable)
+            }
+            RecordedCommand::CmdSetStencilOp(args) => self.dynamic_state.stencil_op.set(
+                args.face_mask,
+                StencilOps {
+                    fail_op: args.fail_op,
+                    pass_op: args.pass_op,
+                    depth_fail_op: args.depth_fail_op,
+                    compare_op: args.compare_op,
+                },
+            ),
+            RecordedCommand::CmdSetRasterizerDiscardEnable(args) => {
+                self.dynamic_state.rasterizer_discard_enable = Some(args.rasterizer_discard_enable)
+            }
+            RecordedCommand::CmdSetDepthBiasEnable(args) => {
+                self.dynamic_state.depth_bias_enable = Some(args.depth_bias_enable)
+            }
+            RecordedCommand::CmdSetPrimitiveRestartEnable(args) => {
+                self.dynamic_state.primitive_restart_enable = Some(args.primitive_restart_enable)
+            }
+            RecordedCommand::CmdSetPatchControlPointsExt(args) => {
+                self.dynamic_state.patch_control_points = Some(args.patch_control_points)
+            }
+            RecordedCommand::CmdSetLogicOpExt(args) => {
+                self.dynamic_state.logic_op = Some(args.logic_op)
+            }
+            RecordedCommand::CmdBeginRenderPass(args) => {
+                self.begin_render_pass(&args.p_render_pass_begin)
+            }
+            RecordedCommand::CmdBeginRenderPass2(args) => {
+                self.begin_render_pass(&args.p_render_pass_begin)
+            }
+            RecordedCommand::CmdNextSubpass(_) | RecordedCommand::CmdNextSubpass2(_) => {
+                self.next_subpass()
+            }
+            RecordedCommand::CmdEndRenderPass(_)
+            | RecordedCommand::CmdEndRenderPass2(_)
+            | RecordedCommand::CmdEndRendering(_) => self.end_render_scope(),
+            RecordedCommand::CmdBeginRendering(args) => {
+                self.begin_rendering(&args.p_rendering_info)
+            }
+            RecordedCommand::CmdExecuteCommands(args) => {
+                self.execute_commands(&args.p_command_buffers)
+            }
+            _ => {}
+        }
+    }
+
+    fn bind_pipeline(
+        &mut self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        pipeline: vk::Pipeline,
+    ) {
+        self.pipelines.insert(pipeline_bind_point, pipeline);
+    }
+
+    fn bind_descriptor_sets(
+        &mut self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        layout: vk::PipelineLayout,
+        first_set: u32,
+        descriptor_sets: &[vk::DescriptorSet],
+        dynamic_offsets: &[u32],
+    ) {
+        let bound_descriptor_sets = self.descriptor_sets.entry(pipeline_bind_point).or_default();
+        for (set, descriptor_set) in (first_set..).zip(descriptor_sets) {
+            bound_descriptor_sets.insert(
+                set,
+                BoundDescriptorSet {
+                    layout,
+                    descriptor_set: *descriptor_set,
+                    dynamic_offsets: dynamic_offsets.to_vec(),
+                },
+            );
+        }
+    }
+
+    fn bind_vertex_buffers(
+        &mut self,
+        first_binding: u32,
+        buffers: &[vk::Buffer],
+        offsets: &[vk::DeviceSize],
+        sizes: Option<&[vk::DeviceSize]>,
+        strides: Option<&[vk::DeviceSize]>,
+    ) {
+        for (i, (buffer, offset)) in buffers.iter().zip(offsets).enumerate() {
+            self.vertex_buffers.insert(
+                first_binding + u32::try_from(i).unwrap(),
+                VertexBufferBinding {
+                    buffer: *buffer,
+                    offset: *offset,
+                    size: sizes.map(|sizes| sizes[i]),
+                    stride: strides.map(|strides| strides[i]),
+                },
+            );
+        }
+    }
+
+    fn bind_index_buffer(
+        &mut self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        index_type: vk::IndexType,
+    ) {
+        self.index_buffer = Some(IndexBufferBinding {
+            buffer,
+            offset,
+            index_type,
+        });
+    }
+
+    fn set_viewports(&mut self, first_viewport: u32, viewports: &[vk::Viewport]) {
+        self.dynamic_state
+            .viewports
+            .extend((first_viewport..).zip(viewports.iter().copied()));
+    }
+
+    fn set_scissors(&mut self, first_scissor: u32, scissors: &[vk::Rect2D]) {
+        self.dynamic_state
+            .scissors
+            .extend((first_scissor..).zip(scissors.iter().copied()));
+    }
+
+    fn begin_render_pass(&mut self, render_pass_begin: &vk::RenderPassBeginInfo) {
+        self.render_scope = Some(RenderScope::RenderPass {
+            render_pass: render_pass_begin.render_pass,
+            framebuffer: render_pass_begin.framebuffer,
+            subpass: 0,
+            render_area: Some(render_pass_begin.render_area),
+        });
+    }
+
+    fn next_subpass(&mut self) {
+        if let Some(RenderScope::RenderPass { subpass, .. }) = &mut self.render_scope {
+            *subpass += 1;
+        }
+    }
+
+    fn begin_rendering(&mut self, rendering_info: &vk::RenderingInfo) {
+        self.render_scope = Some(RenderScope::DynamicRendering {
+            flags: rendering_info.flags,
+            view_mask: rendering_info.view_mask,
+            color_attachment_count: rendering_info.color_attachment_count,
+            render_area: Some(rendering_info.render_area),
+        });
+    }
+
+    fn end_render_scope(&mut self) {
+        self.render_scope = None;
+    }
+
+    fn set_viewports_with_count(&mut self, viewports: &[vk::Viewport]) {
+        self.dynamic_state.viewports.clear();
+        self.set_viewports(0, viewports);
+    }
+
+    fn set_scissors_with_count(&mut self, scissors: &[vk::Rect2D]) {
+        self.dynamic_state.scissors.clear();
+        self.set_scissors(0, scissors);
+    }
+
+    fn execute_commands(&mut self, command_buffers: &[vk::CommandBuffer]) {
+        self.pipelines.clear();
+        self.descriptor_sets.clear();
+        self.vertex_buffers.clear();
+        self.index_buffer = None;
+        self.dynamic_state = Default::default();
+        self.executed_command_buffers
+            .extend_from_slice(command_buffers);
+    }
+}
+
+impl<T: Layer> Global<T> {
+    /// The commands intercepted for the command buffer state tracking, in addition to the ones
+    /// hooked by the layer implementation.
+    pub(crate) fn command_buffer_state_commands(&self) -> impl Iterator<Item = LayerVulkanCommand> {
+        let enabled = self.layer_info.track_command_buffer_state();
+        TRACKED_COMMANDS.iter().filter(move |_| enabled).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{command_args::*, DeepCopied};
+    use ash::vk::Handle;
+
+    #[test]
+    fn test_bind_descriptor_sets_should_keep_sets_not_overwritten() {
+        let mut state = CommandBufferState::default();
+        let layout = vk::PipelineLayout::from_raw(1);
+        state.bind_descriptor_sets(
+            vk::PipelineBindPoint::GRAPHICS,
+            layout,
+            0,
+            &[
+                vk::DescriptorSet::from_raw(10),
+                vk::DescriptorSet::from_raw(11),
+            ],
+            &[256],
+        );
+        state.bind_descriptor_sets(
+            vk::PipelineBindPoint::GRAPHICS,
+            layout,
+            1,
+            &[vk::DescriptorSet::from_raw(21)],
+            &[],
+        );
+        let descriptor_sets = state
+            .descriptor_sets(vk::PipelineBindPoint::GRAPHICS)
+            .map(|(set, bound)| (set, bound.descriptor_set.as_raw()))
+            .collect::<Vec<_>>();
+        assert_eq!(descriptor_sets, [(0, 10), (1, 21)]);
+        assert_eq!(
+            state
+                .descriptor_set(vk::PipelineBindPoint::GRAPHICS, 0)
+                .unwrap()
+                .dynamic_offsets,
+            [256]
+        );
+        assert!(state
+            .descriptor_set(vk::PipelineBindPoint::COMPUTE, 0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_stencil_values_should_be_set_per_face() {
+        let mut stencil_reference = StencilFaceValues::default();
+        stencil_reference.set(vk::StencilFaceFlags::FRONT_AND_BACK, 1);
+        stencil_reference.set(vk::StencilFaceFlags::BACK, 2);
+        assert_eq!(
+            stencil_reference,
+            StencilFaceValues {
+                front: Some(1),
+                back: Some(2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_execute_commands_should_clear_bound_state_and_keep_render_scope() {
+        let mut state = CommandBufferState::default();
+        state.begin_render_pass(
+            &vk::RenderPassBeginInfo::builder().render_pass(vk::RenderPass::from_raw(1)),
+        );
+        state.next_subpass();
+        state.bind_pipeline(vk::PipelineBindPoint::GRAPHICS, vk::Pipeline::from_raw(2));
+        state.bind_index_buffer(vk::Buffer::from_raw(3), 0, vk::IndexType::UINT16);
+        state.set_viewports(1, &[vk::Viewport::default()]);
+        let secondary = vk::CommandBuffer::from_raw(4);
+        state.execute_commands(&[secondary]);
+
+        assert!(state.pipeline(vk::PipelineBindPoint::GRAPHICS).is_none());
+        assert!(state.index_buffer().is_none());
+        assert!(state.dynamic_state().viewports.is_empty());
+        assert!(matches!(
+            state.render_scope(),
+            Some(RenderScope::RenderPass { subpass: 1, .. })
+        ));
+        assert_eq!(state.executed_command_buffers(), [secondary]);
+        state.end_render_scope();
+        assert!(state.render_scope().is_none());
+    }
+
+    #[test]
+    fn test_update_should_track_extended_dynamic_states() {
+        let command_buffer = vk::CommandBuffer::from_raw(1);
+        let mut state = CommandBufferState::default();
+        state.set_scissors(2, &[vk::Rect2D::default()]);
+        let commands = [
+            RecordedCommand::CmdSetCullMode(unsafe {
+                DeepCopied::new(CmdSetCullModeArgs {
+                    command_buffer,
+                    cull_mode: vk::CullModeFlags::BACK,
+                })
+            }),
+            RecordedCommand::CmdSetDepthTestEnable(unsafe {
+                DeepCopied::new(CmdSetDepthTestEnableArgs {
+                    command_buffer,
+                    depth_test_enable: true,
+                })
+            }),
+            RecordedCommand::CmdSetStencilOp(unsafe {
+                DeepCopied::new(CmdSetStencilOpArgs {
+                    command_buffer,
+                    face_mask: vk::StencilFaceFlags::FRONT,
+                    fail_op: vk::StencilOp::ZERO,
+                    pass_op: vk::StencilOp::REPLACE,
+                    depth_fail_op: vk::StencilOp::KEEP,
+                    compare_op: vk::CompareOp::ALWAYS,
+                })
+            }),
+            RecordedCommand::CmdSetScissorWithCount(unsafe {
+                DeepCopied::new(CmdSetScissorWithCountArgs {
+                    command_buffer,
+                    p_scissors: vec![vk::Rect2D::default(); 2],
+                })
+            }),
+        ];
+        for command in &commands {
+            state.update(command);
+        }
+
+        let dynamic_state = state.dynamic_state();
+        assert_eq!(dynamic_state.cull_mode, Some(vk::CullModeFlags::BACK));
+        assert_eq!(dynamic_state.depth_test_enable, Some(true));
+        assert!(dynamic_state.depth_write_enable.is_none());
+        assert_eq!(
+            dynamic_state
+                .stencil_op
+                .front
+                .map(|stencil_ops| stencil_ops.pass_op),
+            Some(vk::StencilOp::REPLACE)
+        );
+        assert!(dynamic_state.stencil_op.back.is_none());
+        // vkCmdSetScissorWithCount replaces all the scissors.
+        assert_eq!(dynamic_state.scissors.keys().collect::<Vec<_>>(), [&0, &1]);
+    }
+
+    #[test]
+    fn test_begin_should_inherit_render_scope_for_secondary_command_buffers() {
+        let mut rendering_info = vk::CommandBufferInheritanceRenderingInfo::builder()
+            .view_mask(0b11)
+            .color_attachment_formats(&[vk::Format::R8G8B8A8_UNORM]);
+        let inheritance_info =
+            vk::CommandBufferInheritanceInfo::builder().push_next(&mut rendering_info);
+        let begin_info = vk::CommandBufferBeginInfo::builder()
+            .flags(vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE)
+            .inheritance_info(&inheritance_info);
+
+        let mut state = CommandBufferState::default();
+        state.begin(vk::CommandBufferLevel::SECONDARY, &begin_info);
+        assert!(matches!(
+            state.render_scope(),
+            Some(RenderScope::DynamicRendering {
+                view_mask: 0b11,
+                color_attachment_count: 1,
+                render_area: None,
+                ..
+            })
+        ));
+
+        // The inheritance info is ignored for primary command buffers.
+        state.begin(vk::CommandBufferLevel::PRIMARY, &begin_info);
+        assert!(state.render_scope().is_none());
+    }
+}
@@ -0,0 +1,490 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The typed representation of the `vkCmd*` commands recorded into a `VkCommandBuffer`.
+//!
+//! The recording is enabled by [`Layer::record_command_buffers`]. The layer framework then
+//! intercepts all the `vkCmd*` commands in [`RECORDED_COMMANDS`] even if the layer implementation
+//! doesn't hook them, and appends a [`RecordedCommand`] to the command buffer after the command
+//! returns from the next layer.
+
+use ash::vk;
+use log::warn;
+use std::{
+    alloc::Layout,
+    ffi::{c_char, c_void, CStr},
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+use thiserror::Error;
+
+use crate::{
+    command_buffer_state::TRACKED_COMMANDS, DeviceDispatchTable, Global, Layer, LayerVulkanCommand,
+};
+
+mod generated;
+pub use generated::*;
+
+/// A type that can be copied together with the memory that its pointers refer to.
+///
+/// Implemented for the Vulkan structs reachable from the parameters of the `vkCmd*` commands, and
+/// the [`command_args`](crate::command_args) of those commands. The `pNext` chains are copied
+/// element by element. Elements with an unknown `sType` are dropped from the copied chain.
+///
+/// # Safety
+///
+/// [`DeepCopy::deep_copy_into`] must replace every pointer that it follows with a pointer to the
+/// memory allocated from the [`DeepCopyStorage`].
+pub unsafe trait DeepCopy {
+    /// Replaces the pointers in `self` with pointers to copies of their pointees allocated from
+    /// `storage`.
+    ///
+    /// # Safety
+    ///
+    /// The pointers reachable from `self` must be valid as if `self` were passed to a Vulkan
+    /// command.
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage);
+}
+
+/// The memory that the pointers in a deep copied value refer to.
+///
+/// The allocations are only freed when the storage is dropped, and moving the storage doesn't
+/// move them.
+#[derive(Default)]
+pub struct DeepCopyStorage {
+    allocations: Vec<(NonNull<u8>, Layout)>,
+}
+
+// Safe because the storage exclusively owns the allocations, which are only read through the
+// pointers in the value that owns the storage, and never through the storage itself. The storage
+// only frees them on drop, which requires exclusive access. Whether the deep copied value can be
+// sent or shared across threads is decided by the value: DeepCopied<T> is only Send or Sync if T
+// is.
+unsafe impl Send for DeepCopyStorage {}
+unsafe impl Sync for DeepCopyStorage {}
+
+impl Drop for DeepCopyStorage {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.drain(..) {
+            unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) };
+        }
+    }
+}
+
+impl DeepCopyStorage {
+    /// Copies `len` elements at `data`. Returns a null pointer if `data` is null or `len` is 0.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn copy_slice<T: Copy>(
+        &mut self,
+        data: *const T,
+        len: impl TryInto<usize, Error = impl Debug>,
+    ) -> *const T {
+        unsafe { self.copy_slice_mut(data, len) }
+    }
+
+    #[deny(unsafe_op_in_unsafe_fn)]
+    unsafe fn copy_slice_mut<T: Copy>(
+        &mut self,
+        data: *const T,
+        len: impl TryInto<usize, Error = impl Debug>,
+    ) -> *mut T {
+        let len: usize = len
+            .try_into()
+            .expect("len must be within the range of usize");
+        if data.is_null() || len == 0 {
+            return std::ptr::null_mut();
+        }
+        let layout = Layout::array::<T>(len).expect("the copied array is too large");
+        if layout.size() == 0 {
+            return NonNull::dangling().as_ptr();
+        }
+        let Some(ptr) = NonNull::new(unsafe { std::alloc::alloc(layout) }) else {
+            std::alloc::handle_alloc_error(layout)
+        };
+        self.allocations.push((ptr, layout));
+        let ptr = ptr.as_ptr().cast::<T>();
+        unsafe { std::ptr::copy_nonoverlapping(data, ptr, len) };
+        ptr
+    }
+
+    /// Deep copies `len` elements at `data`. Returns a null pointer if `data` is null or `len` is
+    /// 0.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn deep_copy_slice<T: DeepCopy + Copy>(
+        &mut self,
+        data: *const T,
+        len: impl TryInto<usize, Error = impl Debug>,
+    ) -> *const T {
+        let len: usize = len
+            .try_into()
+            .expect("len must be within the range of usize");
+        let ptr = unsafe { self.copy_slice_mut(data, len) };
+        if !ptr.is_null() {
+            for element in unsafe { std::slice::from_raw_parts_mut(ptr, len) } {
+                unsafe { element.deep_copy_into(self) };
+            }
+        }
+        ptr
+    }
+
+    /// Copies the element at `data`. Returns a null pointer if `data` is null.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn copy_ptr<T: Copy>(&mut self, data: *const T) -> *const T {
+        unsafe { self.copy_slice(data, 1usize) }
+    }
+
+    /// Deep copies the element at `data`. Returns a null pointer if `data` is null.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn deep_copy_ptr<T: DeepCopy + Copy>(&mut self, data: *const T) -> *const T {
+        unsafe { self.deep_copy_slice(data, 1usize) }
+    }
+
+    /// Copies `len` elements that the array of pointers at `data` points to.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn copy_ptr_slice<T: Copy>(
+        &mut self,
+        data: *const *const T,
+        len: impl TryInto<usize, Error = impl Debug>,
+    ) -> *const *const T {
+        let len: usize = len
+            .try_into()
+            .expect("len must be within the range of usize");
+        let ptr = unsafe { self.copy_slice_mut(data, len) };
+        if !ptr.is_null() {
+            for element in unsafe { std::slice::from_raw_parts_mut(ptr, len) } {
+                *element = unsafe { self.copy_ptr(*element) };
+            }
+        }
+        ptr
+    }
+
+    /// Copies `size` bytes at `data`.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn copy_bytes(
+        &mut self,
+        data: *const c_void,
+        size: impl TryInto<usize, Error = impl Debug>,
+    ) -> *const c_void {
+        unsafe { self.copy_slice(data.cast::<u8>(), size) }.cast()
+    }
+
+    /// Copies the null-terminated string at `data`. Returns a null pointer if `data` is null.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn copy_c_str(&mut self, data: *const c_char) -> *const c_char {
+        if data.is_null() {
+            return std::ptr::null();
+        }
+        let bytes = unsafe { CStr::from_ptr(data) }.to_bytes_with_nul();
+        unsafe { self.copy_slice(bytes.as_ptr(), bytes.len()) }.cast()
+    }
+
+    /// Deep copies the `pNext` chain at `p_next`, and drops the elements with an unknown `sType`.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn deep_copy_p_next(&mut self, mut p_next: *const c_void) -> *const c_void {
+        while let Some(element) = unsafe { p_next.cast::<vk::BaseInStructure>().as_ref() } {
+            // The copied element copies the rest of the chain.
+            if let Some(copied) = unsafe { deep_copy_extension_struct(self, element) } {
+                return copied;
+            }
+            warn!(
+                "Drop the unknown struct {:?} from the copied pNext chain.",
+                element.s_type
+            );
+            p_next = element.p_next.cast();
+        }
+        std::ptr::null()
+    }
+}
+
+/// A value whose pointers refer to the memory owned by the value itself.
+///
+/// Dereferences to the deep copied value. It's undefined behavior to pass the value to a Vulkan
+/// command if its pointers are changed to memory that doesn't outlive the call.
+///
+/// It is [`Send`] and [`Sync`] if `T` is. The arguments structs in
+/// [`command_args`](crate::command_args) that carry pointers are not, but [`RecordedCommand`] is.
+pub struct DeepCopied<T> {
+    value: T,
+    // Only kept to own the memory that the pointers in `value` refer to.
+    _storage: DeepCopyStorage,
+}
+
+impl<T: DeepCopy> DeepCopied<T> {
+    /// Deep copies `value`.
+    ///
+    /// # Safety
+    ///
+    /// The pointers reachable from `value` must be valid as if `value` were passed to a Vulkan
+    /// command.
+    pub unsafe fn new(mut value: T) -> Self {
+        let mut storage = DeepCopyStorage::default();
+        unsafe { value.deep_copy_into(&mut storage) };
+        Self {
+            value,
+            _storage: storage,
+        }
+    }
+}
+
+impl<T: DeepCopy + Clone> Clone for DeepCopied<T> {
+    fn clone(&self) -> Self {
+        // Safe because the pointers in self.value refer to self.storage.
+        unsafe { Self::new(self.value.clone()) }
+    }
+}
+
+impl<T> Deref for DeepCopied<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for DeepCopied<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// The error returned by [`RecordedCommand::replay`].
+#[derive(Error, Debug)]
+pub enum ReplayError {
+    /// The command is not in the core Vulkan API, and can only be replayed with
+    /// [`RecordedCommand::replay_with_dispatch_table`].
+    #[error("`{0}` is not a core command")]
+    ExtensionCommand(&'static str),
+    /// The replayed command returned an error, e.g. `vkCmdSetPerformanceMarkerINTEL`.
+    #[error("the replayed command failed: {0}")]
+    CommandFailed(vk::Result),
+}
+
+impl RecordedCommand {
+    /// Records the command again into `command_buffer` through the core commands of `device`.
+    ///
+    /// Returns [`ReplayError::ExtensionCommand`] without recording anything if the command is only
+    /// provided by an extension, e.g. `vkCmdDrawMeshTasksEXT`.
+    ///
+    /// # Safety
+    ///
+    /// `command_buffer` must be in the recording state and created from `device`, and the command
+    /// must be valid to be recorded into `command_buffer`, e.g. the handles it uses must be still
+    /// alive.
+    pub unsafe fn replay(
+        &self,
+        device: &ash::Device,
+        command_buffer: vk::CommandBuffer,
+    ) -> Result<(), ReplayError> {
+        unsafe { self.replay_impl(device, None, command_buffer) }
+    }
+
+    /// Records the command again into `command_buffer` through `dispatch_table`, which also
+    /// provides the commands from the extensions. Never returns [`ReplayError::ExtensionCommand`].
+    ///
+    /// # Safety
+    ///
+    /// Same as [`RecordedCommand::replay`]. The extension that provides the command must be
+    /// enabled on the device.
+    pub unsafe fn replay_with_dispatch_table(
+        &self,
+        dispatch_table: &DeviceDispatchTable,
+        command_buffer: vk::CommandBuffer,
+    ) -> Result<(), ReplayError> {
+        unsafe { self.replay_impl(dispatch_table.core(), Some(dispatch_table), command_buffer) }
+    }
+}
+
+impl<T: Layer> Global<T> {
+    /// The commands intercepted for the command recording, in addition to the ones hooked by the
+    /// layer implementation.
+    pub(crate) fn command_recording_commands(&self) -> impl Iterator<Item = LayerVulkanCommand> {
+        let enabled = self.layer_info.record_command_buffers();
+        RECORDED_COMMANDS.iter().filter(move |_| enabled).cloned()
+    }
+
+    /// Appends the command returned by `f` to the recorded commands of `command_buffer`, and
+    /// updates the [`CommandBufferState`](crate::CommandBufferState) of `command_buffer` with it.
+    /// Only called after the command is passed to the next layer. `f` is only called if the
+    /// recording is enabled for the command buffer, or `command` is tracked by the command buffer
+    /// state tracking.
+    pub(crate) fn record_command(
+        &self,
+        command: LayerVulkanCommand,
+        command_buffer: vk::CommandBuffer,
+        f: impl FnOnce() -> RecordedCommand,
+    ) {
+        let track_state =
+            self.layer_info.track_command_buffer_state() && TRACKED_COMMANDS.contains(&command);
+        // All the vkCmd* commands call this function, so avoid the lookup if nothing is recorded or
+        // tracked.
+        if !track_state && !self.layer_info.record_command_buffers() {
+            return;
+        }
+        let Some(command_buffer_info) = self.command_buffer_map.get(&command_buffer) else {
+            return;
+        };
+        let state = command_buffer_info.state.as_ref().filter(|_| track_state);
+        let recorded_commands = command_buffer_info.recorded_commands.as_ref();
+        if state.is_none() && recorded_commands.is_none() {
+            return;
+        }
+        let recorded_command = f();
+        if let Some(state) = state {
+            state.lock().unwrap().update(&recorded_command);
+        }
+        if let Some(recorded_commands) = recorded_commands {
+            recorded_commands.lock().unwrap().push(recorded_command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command_args::{CmdBeginRenderPassArgs, CmdInsertDebugUtilsLabelExtArgs};
+    use ash::vk::Handle;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_deep_copy_should_copy_arrays_and_p_next_chain() {
+        let clear_values = [
+            vk::ClearValue {
+                color: vk::ClearColorValue {
+                    float32: [0.0, 0.5, 1.0, 1.0],
+                },
+            },
+            vk::ClearValue {
+                depth_stencil: vk::ClearDepthStencilValue {
+                    depth: 1.0,
+                    stencil: 0,
+                },
+            },
+        ];
+        let attachments = [vk::ImageView::from_raw(0x10), vk::ImageView::from_raw(0x20)];
+        let mut attachment_begin_info =
+            vk::RenderPassAttachmentBeginInfo::builder().attachments(&attachments);
+        let render_pass_begin = vk::RenderPassBeginInfo::builder()
+            .render_pass(vk::RenderPass::from_raw(0x30))
+            .clear_values(&clear_values)
+            .push_next(&mut attachment_begin_info)
+            .build();
+        let args = unsafe {
+            DeepCopied::new(CmdBeginRenderPassArgs {
+                command_buffer: vk::CommandBuffer::from_raw(0x40),
+                p_render_pass_begin: render_pass_begin,
+                contents: vk::SubpassContents::INLINE,
+            })
+        };
+        let copied = &args.p_render_pass_begin;
+        assert_eq!(copied.render_pass, vk::RenderPass::from_raw(0x30));
+        assert_eq!(copied.clear_value_count, 2);
+        assert_ne!(copied.p_clear_values, clear_values.as_ptr());
+        let copied_clear_values = unsafe { std::slice::from_raw_parts(copied.p_clear_values, 2) };
+        assert_eq!(
+            unsafe { copied_clear_values[0].color.float32 },
+            [0.0, 0.5, 1.0, 1.0]
+        );
+        assert_eq!(unsafe { copied_clear_values[1].depth_stencil.depth }, 1.0);
+
+        assert_ne!(copied.p_next, render_pass_begin.p_next);
+        let copied_attachment_begin_info = unsafe {
+            copied
+                .p_next
+                .cast::<vk::RenderPassAttachmentBeginInfo>()
+                .as_ref()
+        }
+        .unwrap();
+        assert_eq!(
+            copied_attachment_begin_info.s_type,
+            vk::StructureType::RENDER_PASS_ATTACHMENT_BEGIN_INFO
+        );
+        assert_ne!(
+            copied_attachment_begin_info.p_attachments,
+            attachments.as_ptr()
+        );
+        assert_eq!(
+            unsafe {
+                std::slice::from_raw_parts(
+                    copied_attachment_begin_info.p_attachments,
+                    copied_attachment_begin_info.attachment_count as usize,
+                )
+            },
+            attachments
+        );
+        assert!(copied_attachment_begin_info.p_next.is_null());
+    }
+
+    #[test]
+    fn test_deep_copy_should_drop_unknown_structs_from_p_next_chain() {
+        let mut unknown = vk::BaseInStructure {
+            s_type: vk::StructureType::from_raw(0x7fff_fff0),
+            p_next: std::ptr::null(),
+        };
+        let sample_locations_begin_info = vk::RenderPassSampleLocationsBeginInfoEXT::default();
+        let mut render_pass_begin = vk::RenderPassBeginInfo::default();
+        unknown.p_next = std::ptr::from_ref(&sample_locations_begin_info).cast();
+        render_pass_begin.p_next = std::ptr::from_ref(&unknown).cast();
+        let args = unsafe {
+            DeepCopied::new(CmdBeginRenderPassArgs {
+                command_buffer: vk::CommandBuffer::null(),
+                p_render_pass_begin: render_pass_begin,
+                contents: vk::SubpassContents::INLINE,
+            })
+        };
+        let copied = unsafe {
+            args.p_render_pass_begin
+                .p_next
+                .cast::<vk::BaseInStructure>()
+                .as_ref()
+        }
+        .unwrap();
+        assert_eq!(
+            copied.s_type,
+            vk::StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT
+        );
+        assert!(copied.p_next.is_null());
+    }
+
+    #[test]
+    fn test_cloned_deep_copy_should_own_its_memory() {
+        let label_name = CString::new("label").unwrap();
+        let label = vk::DebugUtilsLabelEXT::builder()
+            .label_name(&label_name)
+            .build();
+        let args = unsafe {
+            DeepCopied::new(CmdInsertDebugUtilsLabelExtArgs {
+                command_buffer: vk::CommandBuffer::null(),
+                p_label_info: label,
+            })
+        };
+        drop(label_name);
+        let cloned = args.clone();
+        assert_ne!(
+            cloned.p_label_info.p_label_name,
+            args.p_label_info.p_label_name
+        );
+        drop(args);
+        assert_eq!(
+            unsafe { CStr::from_ptr(cloned.p_label_info.p_label_name) },
+            c"label"
+        );
+    }
+
+    #[test]
+    fn test_recorded_command_should_be_send_and_sync() {
+        struct Test<T: Send + Sync>(std::marker::PhantomData<T>);
+        let _: Test<RecordedCommand> = Test(Default::default());
+    }
+}
@@ -0,0 +1,4734 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(missing_docs)]
+// This file is generated from the Vulkan XML API registry.
+#![allow(unused_unsafe)]
+use ash::vk;
+use std::ffi::c_void;
+
+use super::{DeepCopied, DeepCopy, DeepCopyStorage, ReplayError};
+use crate::{command_args::*, DeviceDispatchTable, LayerVulkanCommand};
+
+unsafe impl DeepCopy for vk::AccelerationStructureInfoNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_geometries =
+            unsafe { storage.deep_copy_slice(self.p_geometries, self.geometry_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::AttachmentSampleLocationsEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.sample_locations_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for vk::BlitImageInfo2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_regions = unsafe { storage.deep_copy_slice(self.p_regions, self.region_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::BufferCopy2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::BufferImageCopy2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::BufferMemoryBarrier {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::BufferMemoryBarrier2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CoarseSampleOrderCustomNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_sample_locations =
+            unsafe { storage.copy_slice(self.p_sample_locations, self.sample_location_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ConditionalRenderingBeginInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyAccelerationStructureInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyAccelerationStructureToMemoryInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyBufferInfo2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_regions = unsafe { storage.deep_copy_slice(self.p_regions, self.region_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyBufferToImageInfo2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_regions = unsafe { storage.deep_copy_slice(self.p_regions, self.region_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyCommandTransformInfoQCOM {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyImageInfo2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_regions = unsafe { storage.deep_copy_slice(self.p_regions, self.region_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyImageToBufferInfo2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_regions = unsafe { storage.deep_copy_slice(self.p_regions, self.region_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyMemoryToAccelerationStructureInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyMemoryToMicromapInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyMicromapInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CopyMicromapToMemoryInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::CuLaunchInfoNVX {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_params = unsafe { storage.copy_slice(self.p_params, self.param_count) };
+        self.p_extras = unsafe { storage.copy_slice(self.p_extras, self.extra_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::DebugMarkerMarkerInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_marker_name = unsafe { storage.copy_c_str(self.p_marker_name) };
+    }
+}
+
+unsafe impl DeepCopy for vk::DebugUtilsLabelEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_label_name = unsafe { storage.copy_c_str(self.p_label_name) };
+    }
+}
+
+unsafe impl DeepCopy for vk::DependencyInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_memory_barriers =
+            unsafe { storage.deep_copy_slice(self.p_memory_barriers, self.memory_barrier_count) };
+        self.p_buffer_memory_barriers = unsafe {
+            storage.deep_copy_slice(
+                self.p_buffer_memory_barriers,
+                self.buffer_memory_barrier_count,
+            )
+        };
+        self.p_image_memory_barriers = unsafe {
+            storage.deep_copy_slice(
+                self.p_image_memory_barriers,
+                self.image_memory_barrier_count,
+            )
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::DescriptorBufferBindingInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) }.cast_mut();
+    }
+}
+
+unsafe impl DeepCopy for vk::DeviceGroupRenderPassBeginInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_device_render_areas = unsafe {
+            storage.copy_slice(self.p_device_render_areas, self.device_render_area_count)
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::GeneratedCommandsInfoNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_streams = unsafe { storage.copy_slice(self.p_streams, self.stream_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::GeometryAABBNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::GeometryDataNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.triangles.deep_copy_into(storage) };
+        unsafe { self.aabbs.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for vk::GeometryNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        unsafe { self.geometry.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for vk::GeometryTrianglesNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ImageBlit2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ImageCopy2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ImageMemoryBarrier {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ImageMemoryBarrier2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ImageResolve2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::MemoryBarrier {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::MemoryBarrier2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::MicromapBuildInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_usage_counts =
+            unsafe { storage.copy_slice(self.p_usage_counts, self.usage_counts_count) };
+        self.pp_usage_counts =
+            unsafe { storage.copy_ptr_slice(self.pp_usage_counts, self.usage_counts_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::MultisampledRenderToSingleSampledInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::MultiviewPerViewAttributesInfoNVX {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::MultiviewPerViewRenderAreasRenderPassBeginInfoQCOM {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_per_view_render_areas = unsafe {
+            storage.copy_slice(
+                self.p_per_view_render_areas,
+                self.per_view_render_area_count,
+            )
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::OpticalFlowExecuteInfoNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) }.cast_mut();
+        self.p_regions = unsafe { storage.copy_slice(self.p_regions, self.region_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::PerformanceMarkerInfoINTEL {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::PerformanceOverrideInfoINTEL {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::PerformanceStreamMarkerInfoINTEL {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderPassAttachmentBeginInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_attachments =
+            unsafe { storage.copy_slice(self.p_attachments, self.attachment_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderPassBeginInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_clear_values =
+            unsafe { storage.copy_slice(self.p_clear_values, self.clear_value_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderPassSampleLocationsBeginInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_attachment_initial_sample_locations = unsafe {
+            storage.deep_copy_slice(
+                self.p_attachment_initial_sample_locations,
+                self.attachment_initial_sample_locations_count,
+            )
+        };
+        self.p_post_subpass_sample_locations = unsafe {
+            storage.deep_copy_slice(
+                self.p_post_subpass_sample_locations,
+                self.post_subpass_sample_locations_count,
+            )
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderPassTransformBeginInfoQCOM {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) }.cast_mut();
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderingAttachmentInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderingFragmentDensityMapAttachmentInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderingFragmentShadingRateAttachmentInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::RenderingInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_color_attachments = unsafe {
+            storage.deep_copy_slice(self.p_color_attachments, self.color_attachment_count)
+        };
+        self.p_depth_attachment = unsafe { storage.deep_copy_ptr(self.p_depth_attachment) };
+        self.p_stencil_attachment = unsafe { storage.deep_copy_ptr(self.p_stencil_attachment) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ResolveImageInfo2 {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_regions = unsafe { storage.deep_copy_slice(self.p_regions, self.region_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::SampleLocationsInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_sample_locations =
+            unsafe { storage.copy_slice(self.p_sample_locations, self.sample_locations_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::ShadingRatePaletteNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_shading_rate_palette_entries = unsafe {
+            storage.copy_slice(
+                self.p_shading_rate_palette_entries,
+                self.shading_rate_palette_entry_count,
+            )
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::SubpassBeginInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::SubpassEndInfo {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::SubpassFragmentDensityMapOffsetEndInfoQCOM {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_fragment_density_offsets = unsafe {
+            storage.copy_slice(
+                self.p_fragment_density_offsets,
+                self.fragment_density_offset_count,
+            )
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::SubpassSampleLocationsEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.sample_locations_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VertexInputAttributeDescription2EXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) }.cast_mut();
+    }
+}
+
+unsafe impl DeepCopy for vk::VertexInputBindingDescription2EXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) }.cast_mut();
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoBeginCodingInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_reference_slots =
+            unsafe { storage.deep_copy_slice(self.p_reference_slots, self.reference_slot_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoCodingControlInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoDecodeH264DpbSlotInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_info = unsafe { storage.copy_ptr(self.p_std_reference_info) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoDecodeH264PictureInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_picture_info = unsafe { storage.copy_ptr(self.p_std_picture_info) };
+        self.p_slice_offsets =
+            unsafe { storage.copy_slice(self.p_slice_offsets, self.slice_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoDecodeH265DpbSlotInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_info = unsafe { storage.copy_ptr(self.p_std_reference_info) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoDecodeH265PictureInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_picture_info = unsafe { storage.copy_ptr(self.p_std_picture_info) };
+        self.p_slice_segment_offsets =
+            unsafe { storage.copy_slice(self.p_slice_segment_offsets, self.slice_segment_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoDecodeInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        unsafe { self.dst_picture_resource.deep_copy_into(storage) };
+        self.p_setup_reference_slot = unsafe { storage.deep_copy_ptr(self.p_setup_reference_slot) };
+        self.p_reference_slots =
+            unsafe { storage.deep_copy_slice(self.p_reference_slots, self.reference_slot_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH264DpbSlotInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_info = unsafe { storage.copy_ptr(self.p_std_reference_info) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH264NaluSliceInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_final_lists =
+            unsafe { storage.copy_ptr(self.p_std_reference_final_lists) };
+        self.p_std_slice_header = unsafe { storage.copy_ptr(self.p_std_slice_header) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH264RateControlInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH264RateControlLayerInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH264VclFrameInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_final_lists =
+            unsafe { storage.copy_ptr(self.p_std_reference_final_lists) };
+        self.p_nalu_slice_entries = unsafe {
+            storage.deep_copy_slice(self.p_nalu_slice_entries, self.nalu_slice_entry_count)
+        };
+        self.p_std_picture_info = unsafe { storage.copy_ptr(self.p_std_picture_info) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH265DpbSlotInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_info = unsafe { storage.copy_ptr(self.p_std_reference_info) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH265NaluSliceSegmentInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_final_lists =
+            unsafe { storage.copy_ptr(self.p_std_reference_final_lists) };
+        self.p_std_slice_segment_header =
+            unsafe { storage.copy_ptr(self.p_std_slice_segment_header) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH265RateControlInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH265RateControlLayerInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeH265VclFrameInfoEXT {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_std_reference_final_lists =
+            unsafe { storage.copy_ptr(self.p_std_reference_final_lists) };
+        self.p_nalu_slice_segment_entries = unsafe {
+            storage.deep_copy_slice(
+                self.p_nalu_slice_segment_entries,
+                self.nalu_slice_segment_entry_count,
+            )
+        };
+        self.p_std_picture_info = unsafe { storage.copy_ptr(self.p_std_picture_info) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        unsafe { self.src_picture_resource.deep_copy_into(storage) };
+        self.p_setup_reference_slot = unsafe { storage.deep_copy_ptr(self.p_setup_reference_slot) };
+        self.p_reference_slots =
+            unsafe { storage.deep_copy_slice(self.p_reference_slots, self.reference_slot_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeRateControlInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_layers = unsafe { storage.deep_copy_slice(self.p_layers, self.layer_count) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEncodeRateControlLayerInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoEndCodingInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoPictureResourceInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+    }
+}
+
+unsafe impl DeepCopy for vk::VideoReferenceSlotInfoKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_picture_resource = unsafe { storage.deep_copy_ptr(self.p_picture_resource) };
+    }
+}
+
+unsafe impl DeepCopy for vk::WriteDescriptorSet {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_image_info = if matches!(
+            self.descriptor_type,
+            vk::DescriptorType::SAMPLER
+                | vk::DescriptorType::COMBINED_IMAGE_SAMPLER
+                | vk::DescriptorType::SAMPLED_IMAGE
+                | vk::DescriptorType::STORAGE_IMAGE
+                | vk::DescriptorType::INPUT_ATTACHMENT
+        ) {
+            unsafe { storage.copy_slice(self.p_image_info, self.descriptor_count) }
+        } else {
+            std::ptr::null()
+        };
+        self.p_buffer_info = if matches!(
+            self.descriptor_type,
+            vk::DescriptorType::UNIFORM_BUFFER
+                | vk::DescriptorType::STORAGE_BUFFER
+                | vk::DescriptorType::UNIFORM_BUFFER_DYNAMIC
+                | vk::DescriptorType::STORAGE_BUFFER_DYNAMIC
+        ) {
+            unsafe { storage.copy_slice(self.p_buffer_info, self.descriptor_count) }
+        } else {
+            std::ptr::null()
+        };
+        self.p_texel_buffer_view = if matches!(
+            self.descriptor_type,
+            vk::DescriptorType::UNIFORM_TEXEL_BUFFER | vk::DescriptorType::STORAGE_TEXEL_BUFFER
+        ) {
+            unsafe { storage.copy_slice(self.p_texel_buffer_view, self.descriptor_count) }
+        } else {
+            std::ptr::null()
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::WriteDescriptorSetAccelerationStructureKHR {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_acceleration_structures = unsafe {
+            storage.copy_slice(
+                self.p_acceleration_structures,
+                self.acceleration_structure_count,
+            )
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::WriteDescriptorSetAccelerationStructureNV {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_acceleration_structures = unsafe {
+            storage.copy_slice(
+                self.p_acceleration_structures,
+                self.acceleration_structure_count,
+            )
+        };
+    }
+}
+
+unsafe impl DeepCopy for vk::WriteDescriptorSetInlineUniformBlock {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        self.p_next = unsafe { storage.deep_copy_p_next(self.p_next) };
+        self.p_data = unsafe { storage.copy_bytes(self.p_data, self.data_size) };
+    }
+}
+
+/// Deep copies the extension struct at `p_next` with its `pNext` chain. Returns [`None`] if the
+/// `sType` is unknown.
+pub(crate) unsafe fn deep_copy_extension_struct(
+    storage: &mut DeepCopyStorage,
+    p_next: &vk::BaseInStructure,
+) -> Option<*const c_void> {
+    let p_next: *const vk::BaseInStructure = p_next;
+    let copied = match unsafe { (*p_next).s_type } {
+        vk::StructureType::COPY_COMMAND_TRANSFORM_INFO_QCOM => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::CopyCommandTransformInfoQCOM>()) }
+                .cast()
+        }
+        vk::StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::DeviceGroupRenderPassBeginInfo>()) }
+                .cast()
+        }
+        vk::StructureType::MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_INFO_EXT => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::MultisampledRenderToSingleSampledInfoEXT>())
+        }
+        .cast(),
+        vk::StructureType::MULTIVIEW_PER_VIEW_ATTRIBUTES_INFO_NVX => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::MultiviewPerViewAttributesInfoNVX>()) }
+                .cast()
+        }
+        vk::StructureType::MULTIVIEW_PER_VIEW_RENDER_AREAS_RENDER_PASS_BEGIN_INFO_QCOM => unsafe {
+            storage.deep_copy_ptr(
+                p_next.cast::<vk::MultiviewPerViewRenderAreasRenderPassBeginInfoQCOM>(),
+            )
+        }
+        .cast(),
+        vk::StructureType::RENDER_PASS_ATTACHMENT_BEGIN_INFO => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::RenderPassAttachmentBeginInfo>()) }
+                .cast()
+        }
+        vk::StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::RenderPassSampleLocationsBeginInfoEXT>())
+        }
+        .cast(),
+        vk::StructureType::RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::RenderPassTransformBeginInfoQCOM>()) }
+                .cast()
+        }
+        vk::StructureType::RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_INFO_EXT => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::RenderingFragmentDensityMapAttachmentInfoEXT>())
+        }
+        .cast(),
+        vk::StructureType::RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR => unsafe {
+            storage
+                .deep_copy_ptr(p_next.cast::<vk::RenderingFragmentShadingRateAttachmentInfoKHR>())
+        }
+        .cast(),
+        vk::StructureType::SAMPLE_LOCATIONS_INFO_EXT => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::SampleLocationsInfoEXT>()) }.cast()
+        }
+        vk::StructureType::SUBPASS_FRAGMENT_DENSITY_MAP_OFFSET_END_INFO_QCOM => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::SubpassFragmentDensityMapOffsetEndInfoQCOM>())
+        }
+        .cast(),
+        vk::StructureType::VIDEO_DECODE_H264_DPB_SLOT_INFO_KHR => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoDecodeH264DpbSlotInfoKHR>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_DECODE_H264_PICTURE_INFO_KHR => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoDecodeH264PictureInfoKHR>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_DECODE_H265_DPB_SLOT_INFO_KHR => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoDecodeH265DpbSlotInfoKHR>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_DECODE_H265_PICTURE_INFO_KHR => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoDecodeH265PictureInfoKHR>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_H264_DPB_SLOT_INFO_EXT => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH264DpbSlotInfoEXT>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_H264_RATE_CONTROL_INFO_EXT => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH264RateControlInfoEXT>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_H264_RATE_CONTROL_LAYER_INFO_EXT => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH264RateControlLayerInfoEXT>())
+        }
+        .cast(),
+        vk::StructureType::VIDEO_ENCODE_H264_VCL_FRAME_INFO_EXT => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH264VclFrameInfoEXT>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_H265_DPB_SLOT_INFO_EXT => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH265DpbSlotInfoEXT>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_H265_RATE_CONTROL_INFO_EXT => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH265RateControlInfoEXT>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_H265_RATE_CONTROL_LAYER_INFO_EXT => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH265RateControlLayerInfoEXT>())
+        }
+        .cast(),
+        vk::StructureType::VIDEO_ENCODE_H265_VCL_FRAME_INFO_EXT => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeH265VclFrameInfoEXT>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_RATE_CONTROL_INFO_KHR => {
+            unsafe { storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeRateControlInfoKHR>()) }
+                .cast()
+        }
+        vk::StructureType::VIDEO_ENCODE_RATE_CONTROL_LAYER_INFO_KHR => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::VideoEncodeRateControlLayerInfoKHR>())
+        }
+        .cast(),
+        vk::StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::WriteDescriptorSetAccelerationStructureKHR>())
+        }
+        .cast(),
+        vk::StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::WriteDescriptorSetAccelerationStructureNV>())
+        }
+        .cast(),
+        vk::StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK => unsafe {
+            storage.deep_copy_ptr(p_next.cast::<vk::WriteDescriptorSetInlineUniformBlock>())
+        }
+        .cast(),
+        _ => return None,
+    };
+    Some(copied)
+}
+
+unsafe impl DeepCopy for CmdBindPipelineArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetViewportArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetScissorArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetLineWidthArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthBiasArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetBlendConstantsArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthBoundsArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetStencilCompareMaskArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetStencilWriteMaskArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetStencilReferenceArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBindDescriptorSetsArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBindIndexBufferArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBindVertexBuffersArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawIndexedArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawIndirectArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawIndexedIndirectArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDispatchArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDispatchIndirectArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyBufferArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyImageArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBlitImageArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyBufferToImageArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyImageToBufferArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdUpdateBufferArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdFillBufferArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdClearColorImageArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdClearDepthStencilImageArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdClearAttachmentsArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdResolveImageArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetEventArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdResetEventArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdWaitEventsArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_memory_barriers {
+            unsafe { element.deep_copy_into(storage) };
+        }
+        for element in &mut self.p_buffer_memory_barriers {
+            unsafe { element.deep_copy_into(storage) };
+        }
+        for element in &mut self.p_image_memory_barriers {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdPipelineBarrierArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_memory_barriers {
+            unsafe { element.deep_copy_into(storage) };
+        }
+        for element in &mut self.p_buffer_memory_barriers {
+            unsafe { element.deep_copy_into(storage) };
+        }
+        for element in &mut self.p_image_memory_barriers {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdBeginQueryArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdEndQueryArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdResetQueryPoolArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdWriteTimestampArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyQueryPoolResultsArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdPushConstantsArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBeginRenderPassArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_render_pass_begin.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdNextSubpassArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdEndRenderPassArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdExecuteCommandsArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDeviceMaskArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDispatchBaseArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawIndirectCountArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawIndexedIndirectCountArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBeginRenderPass2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_render_pass_begin.deep_copy_into(storage) };
+        unsafe { self.p_subpass_begin_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdNextSubpass2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_subpass_begin_info.deep_copy_into(storage) };
+        unsafe { self.p_subpass_end_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdEndRenderPass2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_subpass_end_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdSetEvent2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_dependency_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdResetEvent2Args {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdWaitEvents2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_dependency_infos {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdPipelineBarrier2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_dependency_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdWriteTimestamp2Args {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyBuffer2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_copy_buffer_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyImage2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_copy_image_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyBufferToImage2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_copy_buffer_to_image_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyImageToBuffer2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_copy_image_to_buffer_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdBlitImage2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_blit_image_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdResolveImage2Args {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_resolve_image_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdBeginRenderingArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_rendering_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdEndRenderingArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCullModeArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetFrontFaceArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetPrimitiveTopologyArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetViewportWithCountArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetScissorWithCountArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBindVertexBuffers2Args {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthTestEnableArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthWriteEnableArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthCompareOpArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthBoundsTestEnableArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetStencilTestEnableArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetStencilOpArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetRasterizerDiscardEnableArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthBiasEnableArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetPrimitiveRestartEnableArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBeginVideoCodingKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_begin_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdEndVideoCodingKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_end_coding_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdControlVideoCodingKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_coding_control_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdDecodeVideoKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_decode_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdPushDescriptorSetKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_descriptor_writes {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdPushDescriptorSetWithTemplateKhrArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetFragmentShadingRateKhrArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdEncodeVideoKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_encode_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdWriteBufferMarker2AmdArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdTraceRaysIndirect2KhrArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDebugMarkerBeginExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_marker_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdDebugMarkerEndExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDebugMarkerInsertExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_marker_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdBindTransformFeedbackBuffersExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBeginTransformFeedbackExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdEndTransformFeedbackExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBeginQueryIndexedExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdEndQueryIndexedExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawIndirectByteCountExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCuLaunchKernelNvxArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_launch_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdBeginConditionalRenderingExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_conditional_rendering_begin.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdEndConditionalRenderingExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetViewportWScalingNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDiscardRectangleExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBeginDebugUtilsLabelExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_label_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdEndDebugUtilsLabelExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdInsertDebugUtilsLabelExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_label_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdSetSampleLocationsExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_sample_locations_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdBindShadingRateImageNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetViewportShadingRatePaletteNvArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_shading_rate_palettes {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdSetCoarseSampleOrderNvArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_custom_sample_orders {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdBuildAccelerationStructureNvArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyAccelerationStructureNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdTraceRaysNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdWriteAccelerationStructuresPropertiesNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdWriteBufferMarkerAmdArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMeshTasksNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMeshTasksIndirectNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMeshTasksIndirectCountNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetExclusiveScissorNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCheckpointNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetPerformanceMarkerIntelArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_marker_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdSetPerformanceStreamMarkerIntelArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_marker_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdSetPerformanceOverrideIntelArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_override_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdSetLineStippleExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdPreprocessGeneratedCommandsNvArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_generated_commands_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdExecuteGeneratedCommandsNvArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_generated_commands_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdBindPipelineShaderGroupNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBindDescriptorBuffersExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_binding_infos {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdSetDescriptorBufferOffsetsExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBindDescriptorBufferEmbeddedSamplersExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetFragmentShadingRateEnumNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetVertexInputExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_vertex_binding_descriptions {
+            unsafe { element.deep_copy_into(storage) };
+        }
+        for element in &mut self.p_vertex_attribute_descriptions {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdSubpassShadingHuaweiArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBindInvocationMaskHuaweiArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetPatchControlPointsExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetLogicOpExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetColorWriteEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMultiExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMultiIndexedExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdBuildMicromapsExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        for element in &mut self.p_infos {
+            unsafe { element.deep_copy_into(storage) };
+        }
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyMicromapExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyMicromapToMemoryExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyMemoryToMicromapExtArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdWriteMicromapsPropertiesExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyMemoryIndirectNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdCopyMemoryToImageIndirectNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDecompressMemoryNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDecompressMemoryIndirectCountNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetTessellationDomainOriginExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthClampEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetPolygonModeExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetRasterizationSamplesExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetSampleMaskExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetAlphaToCoverageEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetAlphaToOneEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetLogicOpEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetColorBlendEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetColorBlendEquationExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetColorWriteMaskExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetRasterizationStreamExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetConservativeRasterizationModeExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetExtraPrimitiveOverestimationSizeExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthClipEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetSampleLocationsEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetColorBlendAdvancedExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetProvokingVertexModeExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetLineRasterizationModeExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetLineStippleEnableExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetDepthClipNegativeOneToOneExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetViewportWScalingEnableNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetViewportSwizzleNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCoverageToColorEnableNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCoverageToColorLocationNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCoverageModulationModeNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCoverageModulationTableEnableNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCoverageModulationTableNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetShadingRateImageEnableNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetRepresentativeFragmentTestEnableNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetCoverageReductionModeNvArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdOpticalFlowExecuteNvArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_execute_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyAccelerationStructureKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyAccelerationStructureToMemoryKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdCopyMemoryToAccelerationStructureKhrArgs {
+    unsafe fn deep_copy_into(&mut self, storage: &mut DeepCopyStorage) {
+        unsafe { self.p_info.deep_copy_into(storage) };
+    }
+}
+
+unsafe impl DeepCopy for CmdTraceRaysKhrArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdTraceRaysIndirectKhrArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdSetRayTracingPipelineStackSizeKhrArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMeshTasksExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMeshTasksIndirectExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+unsafe impl DeepCopy for CmdDrawMeshTasksIndirectCountExtArgs {
+    unsafe fn deep_copy_into(&mut self, _: &mut DeepCopyStorage) {}
+}
+
+/// The `vkCmd*` commands that are recorded into [`RecordedCommand`]s.
+pub(crate) const RECORDED_COMMANDS: &[LayerVulkanCommand] = &[
+    LayerVulkanCommand::CmdBindPipeline,
+    LayerVulkanCommand::CmdSetViewport,
+    LayerVulkanCommand::CmdSetScissor,
+    LayerVulkanCommand::CmdSetLineWidth,
+    LayerVulkanCommand::CmdSetDepthBias,
+    LayerVulkanCommand::CmdSetBlendConstants,
+    LayerVulkanCommand::CmdSetDepthBounds,
+    LayerVulkanCommand::CmdSetStencilCompareMask,
+    LayerVulkanCommand::CmdSetStencilWriteMask,
+    LayerVulkanCommand::CmdSetStencilReference,
+    LayerVulkanCommand::CmdBindDescriptorSets,
+    LayerVulkanCommand::CmdBindIndexBuffer,
+    LayerVulkanCommand::CmdBindVertexBuffers,
+    LayerVulkanCommand::CmdDraw,
+    LayerVulkanCommand::CmdDrawIndexed,
+    LayerVulkanCommand::CmdDrawIndirect,
+    LayerVulkanCommand::CmdDrawIndexedIndirect,
+    LayerVulkanCommand::CmdDispatch,
+    LayerVulkanCommand::CmdDispatchIndirect,
+    LayerVulkanCommand::CmdCopyBuffer,
+    LayerVulkanCommand::CmdCopyImage,
+    LayerVulkanCommand::CmdBlitImage,
+    LayerVulkanCommand::CmdCopyBufferToImage,
+    LayerVulkanCommand::CmdCopyImageToBuffer,
+    LayerVulkanCommand::CmdUpdateBuffer,
+    LayerVulkanCommand::CmdFillBuffer,
+    LayerVulkanCommand::CmdClearColorImage,
+    LayerVulkanCommand::CmdClearDepthStencilImage,
+    LayerVulkanCommand::CmdClearAttachments,
+    LayerVulkanCommand::CmdResolveImage,
+    LayerVulkanCommand::CmdSetEvent,
+    LayerVulkanCommand::CmdResetEvent,
+    LayerVulkanCommand::CmdWaitEvents,
+    LayerVulkanCommand::CmdPipelineBarrier,
+    LayerVulkanCommand::CmdBeginQuery,
+    LayerVulkanCommand::CmdEndQuery,
+    LayerVulkanCommand::CmdResetQueryPool,
+    LayerVulkanCommand::CmdWriteTimestamp,
+    LayerVulkanCommand::CmdCopyQueryPoolResults,
+    LayerVulkanCommand::CmdPushConstants,
+    LayerVulkanCommand::CmdBeginRenderPass,
+    LayerVulkanCommand::CmdNextSubpass,
+    LayerVulkanCommand::CmdEndRenderPass,
+    LayerVulkanCommand::CmdExecuteCommands,
+    LayerVulkanCommand::CmdSetDeviceMask,
+    LayerVulkanCommand::CmdDispatchBase,
+    LayerVulkanCommand::CmdDrawIndirectCount,
+    LayerVulkanCommand::CmdDrawIndexedIndirectCount,
+    LayerVulkanCommand::CmdBeginRenderPass2,
+    LayerVulkanCommand::CmdNextSubpass2,
+    LayerVulkanCommand::CmdEndRenderPass2,
+    LayerVulkanCommand::CmdSetEvent2,
+    LayerVulkanCommand::CmdResetEvent2,
+    LayerVulkanCommand::CmdWaitEvents2,
+    LayerVulkanCommand::CmdPipelineBarrier2,
+    LayerVulkanCommand::CmdWriteTimestamp2,
+    LayerVulkanCommand::CmdCopyBuffer2,
+    LayerVulkanCommand::CmdCopyImage2,
+    LayerVulkanCommand::CmdCopyBufferToImage2,
+    LayerVulkanCommand::CmdCopyImageToBuffer2,
+    LayerVulkanCommand::CmdBlitImage2,
+    LayerVulkanCommand::CmdResolveImage2,
+    LayerVulkanCommand::CmdBeginRendering,
+    LayerVulkanCommand::CmdEndRendering,
+    LayerVulkanCommand::CmdSetCullMode,
+    LayerVulkanCommand::CmdSetFrontFace,
+    LayerVulkanCommand::CmdSetPrimitiveTopology,
+    LayerVulkanCommand::CmdSetViewportWithCount,
+    LayerVulkanCommand::CmdSetScissorWithCount,
+    LayerVulkanCommand::CmdBindVertexBuffers2,
+    LayerVulkanCommand::CmdSetDepthTestEnable,
+    LayerVulkanCommand::CmdSetDepthWriteEnable,
+    LayerVulkanCommand::CmdSetDepthCompareOp,
+    LayerVulkanCommand::CmdSetDepthBoundsTestEnable,
+    LayerVulkanCommand::CmdSetStencilTestEnable,
+    LayerVulkanCommand::CmdSetStencilOp,
+    LayerVulkanCommand::CmdSetRasterizerDiscardEnable,
+    LayerVulkanCommand::CmdSetDepthBiasEnable,
+    LayerVulkanCommand::CmdSetPrimitiveRestartEnable,
+    LayerVulkanCommand::CmdBeginVideoCodingKhr,
+    LayerVulkanCommand::CmdEndVideoCodingKhr,
+    LayerVulkanCommand::CmdControlVideoCodingKhr,
+    LayerVulkanCommand::CmdDecodeVideoKhr,
+    LayerVulkanCommand::CmdPushDescriptorSetKhr,
+    LayerVulkanCommand::CmdPushDescriptorSetWithTemplateKhr,
+    LayerVulkanCommand::CmdSetFragmentShadingRateKhr,
+    LayerVulkanCommand::CmdEncodeVideoKhr,
+    LayerVulkanCommand::CmdWriteBufferMarker2Amd,
+    LayerVulkanCommand::CmdTraceRaysIndirect2Khr,
+    LayerVulkanCommand::CmdDebugMarkerBeginExt,
+    LayerVulkanCommand::CmdDebugMarkerEndExt,
+    LayerVulkanCommand::CmdDebugMarkerInsertExt,
+    LayerVulkanCommand::CmdBindTransformFeedbackBuffersExt,
+    LayerVulkanCommand::CmdBeginTransformFeedbackExt,
+    LayerVulkanCommand::CmdEndTransformFeedbackExt,
+    LayerVulkanCommand::CmdBeginQueryIndexedExt,
+    LayerVulkanCommand::CmdEndQueryIndexedExt,
+    LayerVulkanCommand::CmdDrawIndirectByteCountExt,
+    LayerVulkanCommand::CmdCuLaunchKernelNvx,
+    LayerVulkanCommand::CmdBeginConditionalRenderingExt,
+    LayerVulkanCommand::CmdEndConditionalRenderingExt,
+    LayerVulkanCommand::CmdSetViewportWScalingNv,
+    LayerVulkanCommand::CmdSetDiscardRectangleExt,
+    LayerVulkanCommand::CmdBeginDebugUtilsLabelExt,
+    LayerVulkanCommand::CmdEndDebugUtilsLabelExt,
+    LayerVulkanCommand::CmdInsertDebugUtilsLabelExt,
+    LayerVulkanCommand::CmdSetSampleLocationsExt,
+    LayerVulkanCommand::CmdBindShadingRateImageNv,
+    LayerVulkanCommand::CmdSetViewportShadingRatePaletteNv,
+    LayerVulkanCommand::CmdSetCoarseSampleOrderNv,
+    LayerVulkanCommand::CmdBuildAccelerationStructureNv,
+    LayerVulkanCommand::CmdCopyAccelerationStructureNv,
+    LayerVulkanCommand::CmdTraceRaysNv,
+    LayerVulkanCommand::CmdWriteAccelerationStructuresPropertiesNv,
+    LayerVulkanCommand::CmdWriteBufferMarkerAmd,
+    LayerVulkanCommand::CmdDrawMeshTasksNv,
+    LayerVulkanCommand::CmdDrawMeshTasksIndirectNv,
+    LayerVulkanCommand::CmdDrawMeshTasksIndirectCountNv,
+    LayerVulkanCommand::CmdSetExclusiveScissorNv,
+    LayerVulkanCommand::CmdSetCheckpointNv,
+    LayerVulkanCommand::CmdSetPerformanceMarkerIntel,
+    LayerVulkanCommand::CmdSetPerformanceStreamMarkerIntel,
+    LayerVulkanCommand::CmdSetPerformanceOverrideIntel,
+    LayerVulkanCommand::CmdSetLineStippleExt,
+    LayerVulkanCommand::CmdPreprocessGeneratedCommandsNv,
+    LayerVulkanCommand::CmdExecuteGeneratedCommandsNv,
+    LayerVulkanCommand::CmdBindPipelineShaderGroupNv,
+    LayerVulkanCommand::CmdBindDescriptorBuffersExt,
+    LayerVulkanCommand::CmdSetDescriptorBufferOffsetsExt,
+    LayerVulkanCommand::CmdBindDescriptorBufferEmbeddedSamplersExt,
+    LayerVulkanCommand::CmdSetFragmentShadingRateEnumNv,
+    LayerVulkanCommand::CmdSetVertexInputExt,
+    LayerVulkanCommand::CmdSubpassShadingHuawei,
+    LayerVulkanCommand::CmdBindInvocationMaskHuawei,
+    LayerVulkanCommand::CmdSetPatchControlPointsExt,
+    LayerVulkanCommand::CmdSetLogicOpExt,
+    LayerVulkanCommand::CmdSetColorWriteEnableExt,
+    LayerVulkanCommand::CmdDrawMultiExt,
+    LayerVulkanCommand::CmdDrawMultiIndexedExt,
+    LayerVulkanCommand::CmdBuildMicromapsExt,
+    LayerVulkanCommand::CmdCopyMicromapExt,
+    LayerVulkanCommand::CmdCopyMicromapToMemoryExt,
+    LayerVulkanCommand::CmdCopyMemoryToMicromapExt,
+    LayerVulkanCommand::CmdWriteMicromapsPropertiesExt,
+    LayerVulkanCommand::CmdCopyMemoryIndirectNv,
+    LayerVulkanCommand::CmdCopyMemoryToImageIndirectNv,
+    LayerVulkanCommand::CmdDecompressMemoryNv,
+    LayerVulkanCommand::CmdDecompressMemoryIndirectCountNv,
+    LayerVulkanCommand::CmdSetTessellationDomainOriginExt,
+    LayerVulkanCommand::CmdSetDepthClampEnableExt,
+    LayerVulkanCommand::CmdSetPolygonModeExt,
+    LayerVulkanCommand::CmdSetRasterizationSamplesExt,
+    LayerVulkanCommand::CmdSetSampleMaskExt,
+    LayerVulkanCommand::CmdSetAlphaToCoverageEnableExt,
+    LayerVulkanCommand::CmdSetAlphaToOneEnableExt,
+    LayerVulkanCommand::CmdSetLogicOpEnableExt,
+    LayerVulkanCommand::CmdSetColorBlendEnableExt,
+    LayerVulkanCommand::CmdSetColorBlendEquationExt,
+    LayerVulkanCommand::CmdSetColorWriteMaskExt,
+    LayerVulkanCommand::CmdSetRasterizationStreamExt,
+    LayerVulkanCommand::CmdSetConservativeRasterizationModeExt,
+    LayerVulkanCommand::CmdSetExtraPrimitiveOverestimationSizeExt,
+    LayerVulkanCommand::CmdSetDepthClipEnableExt,
+    LayerVulkanCommand::CmdSetSampleLocationsEnableExt,
+    LayerVulkanCommand::CmdSetColorBlendAdvancedExt,
+    LayerVulkanCommand::CmdSetProvokingVertexModeExt,
+    LayerVulkanCommand::CmdSetLineRasterizationModeExt,
+    LayerVulkanCommand::CmdSetLineStippleEnableExt,
+    LayerVulkanCommand::CmdSetDepthClipNegativeOneToOneExt,
+    LayerVulkanCommand::CmdSetViewportWScalingEnableNv,
+    LayerVulkanCommand::CmdSetViewportSwizzleNv,
+    LayerVulkanCommand::CmdSetCoverageToColorEnableNv,
+    LayerVulkanCommand::CmdSetCoverageToColorLocationNv,
+    LayerVulkanCommand::CmdSetCoverageModulationModeNv,
+    LayerVulkanCommand::CmdSetCoverageModulationTableEnableNv,
+    LayerVulkanCommand::CmdSetCoverageModulationTableNv,
+    LayerVulkanCommand::CmdSetShadingRateImageEnableNv,
+    LayerVulkanCommand::CmdSetRepresentativeFragmentTestEnableNv,
+    LayerVulkanCommand::CmdSetCoverageReductionModeNv,
+    LayerVulkanCommand::CmdOpticalFlowExecuteNv,
+    LayerVulkanCommand::CmdCopyAccelerationStructureKhr,
+    LayerVulkanCommand::CmdCopyAccelerationStructureToMemoryKhr,
+    LayerVulkanCommand::CmdCopyMemoryToAccelerationStructureKhr,
+    LayerVulkanCommand::CmdTraceRaysKhr,
+    LayerVulkanCommand::CmdTraceRaysIndirectKhr,
+    LayerVulkanCommand::CmdSetRayTracingPipelineStackSizeKhr,
+    LayerVulkanCommand::CmdDrawMeshTasksExt,
+    LayerVulkanCommand::CmdDrawMeshTasksIndirectExt,
+    LayerVulkanCommand::CmdDrawMeshTasksIndirectCountExt,
+];
+
+/// A `vkCmd*` command recorded by the layer framework.
+///
+/// Each variant owns a deep copy of the arguments of the Vulkan command of the same name, including
+/// the nested arrays and the `pNext` chains, so the command can be inspected or replayed after the
+/// original arguments are freed. The opaque host pointers whose size is unknown, e.g. the `pData`
+/// of `vkCmdPushDescriptorSetWithTemplateKHR`, the host addresses in unions, and the pointers in
+/// the video codec std structs are copied as is.
+#[derive(Clone)]
+pub enum RecordedCommand {
+    CmdBindPipeline(DeepCopied<CmdBindPipelineArgs>),
+    CmdSetViewport(DeepCopied<CmdSetViewportArgs>),
+    CmdSetScissor(DeepCopied<CmdSetScissorArgs>),
+    CmdSetLineWidth(DeepCopied<CmdSetLineWidthArgs>),
+    CmdSetDepthBias(DeepCopied<CmdSetDepthBiasArgs>),
+    CmdSetBlendConstants(DeepCopied<CmdSetBlendConstantsArgs>),
+    CmdSetDepthBounds(DeepCopied<CmdSetDepthBoundsArgs>),
+    CmdSetStencilCompareMask(DeepCopied<CmdSetStencilCompareMaskArgs>),
+    CmdSetStencilWriteMask(DeepCopied<CmdSetStencilWriteMaskArgs>),
+    CmdSetStencilReference(DeepCopied<CmdSetStencilReferenceArgs>),
+    CmdBindDescriptorSets(DeepCopied<CmdBindDescriptorSetsArgs>),
+    CmdBindIndexBuffer(DeepCopied<CmdBindIndexBufferArgs>),
+    CmdBindVertexBuffers(DeepCopied<CmdBindVertexBuffersArgs>),
+    CmdDraw(DeepCopied<CmdDrawArgs>),
+    CmdDrawIndexed(DeepCopied<CmdDrawIndexedArgs>),
+    CmdDrawIndirect(DeepCopied<CmdDrawIndirectArgs>),
+    CmdDrawIndexedIndirect(DeepCopied<CmdDrawIndexedIndirectArgs>),
+    CmdDispatch(DeepCopied<CmdDispatchArgs>),
+    CmdDispatchIndirect(DeepCopied<CmdDispatchIndirectArgs>),
+    CmdCopyBuffer(DeepCopied<CmdCopyBufferArgs>),
+    CmdCopyImage(DeepCopied<CmdCopyImageArgs>),
+    CmdBlitImage(DeepCopied<CmdBlitImageArgs>),
+    CmdCopyBufferToImage(DeepCopied<CmdCopyBufferToImageArgs>),
+    CmdCopyImageToBuffer(DeepCopied<CmdCopyImageToBufferArgs>),
+    CmdUpdateBuffer(DeepCopied<CmdUpdateBufferArgs>),
+    CmdFillBuffer(DeepCopied<CmdFillBufferArgs>),
+    CmdClearColorImage(DeepCopied<CmdClearColorImageArgs>),
+    CmdClearDepthStencilImage(DeepCopied<CmdClearDepthStencilImageArgs>),
+    CmdClearAttachments(DeepCopied<CmdClearAttachmentsArgs>),
+    CmdResolveImage(DeepCopied<CmdResolveImageArgs>),
+    CmdSetEvent(DeepCopied<CmdSetEventArgs>),
+    CmdResetEvent(DeepCopied<CmdResetEventArgs>),
+    CmdWaitEvents(DeepCopied<CmdWaitEventsArgs>),
+    CmdPipelineBarrier(DeepCopied<CmdPipelineBarrierArgs>),
+    CmdBeginQuery(DeepCopied<CmdBeginQueryArgs>),
+    CmdEndQuery(DeepCopied<CmdEndQueryArgs>),
+    CmdResetQueryPool(DeepCopied<CmdResetQueryPoolArgs>),
+    CmdWriteTimestamp(DeepCopied<CmdWriteTimestampArgs>),
+    CmdCopyQueryPoolResults(DeepCopied<CmdCopyQueryPoolResultsArgs>),
+    CmdPushConstants(DeepCopied<CmdPushConstantsArgs>),
+    CmdBeginRenderPass(DeepCopied<CmdBeginRenderPassArgs>),
+    CmdNextSubpass(DeepCopied<CmdNextSubpassArgs>),
+    CmdEndRenderPass(DeepCopied<CmdEndRenderPassArgs>),
+    CmdExecuteCommands(DeepCopied<CmdExecuteCommandsArgs>),
+    CmdSetDeviceMask(DeepCopied<CmdSetDeviceMaskArgs>),
+    CmdDispatchBase(DeepCopied<CmdDispatchBaseArgs>),
+    CmdDrawIndirectCount(DeepCopied<CmdDrawIndirectCountArgs>),
+    CmdDrawIndexedIndirectCount(DeepCopied<CmdDrawIndexedIndirectCountArgs>),
+    CmdBeginRenderPass2(DeepCopied<CmdBeginRenderPass2Args>),
+    CmdNextSubpass2(DeepCopied<CmdNextSubpass2Args>),
+    CmdEndRenderPass2(DeepCopied<CmdEndRenderPass2Args>),
+    CmdSetEvent2(DeepCopied<CmdSetEvent2Args>),
+    CmdResetEvent2(DeepCopied<CmdResetEvent2Args>),
+    CmdWaitEvents2(DeepCopied<CmdWaitEvents2Args>),
+    CmdPipelineBarrier2(DeepCopied<CmdPipelineBarrier2Args>),
+    CmdWriteTimestamp2(DeepCopied<CmdWriteTimestamp2Args>),
+    CmdCopyBuffer2(DeepCopied<CmdCopyBuffer2Args>),
+    CmdCopyImage2(DeepCopied<CmdCopyImage2Args>),
+    CmdCopyBufferToImage2(DeepCopied<CmdCopyBufferToImage2Args>),
+    CmdCopyImageToBuffer2(DeepCopied<CmdCopyImageToBuffer2Args>),
+    CmdBlitImage2(DeepCopied<CmdBlitImage2Args>),
+    CmdResolveImage2(DeepCopied<CmdResolveImage2Args>),
+    CmdBeginRendering(DeepCopied<CmdBeginRenderingArgs>),
+    CmdEndRendering(DeepCopied<CmdEndRenderingArgs>),
+    CmdSetCullMode(DeepCopied<CmdSetCullModeArgs>),
+    CmdSetFrontFace(DeepCopied<CmdSetFrontFaceArgs>),
+    CmdSetPrimitiveTopology(DeepCopied<CmdSetPrimitiveTopologyArgs>),
+    CmdSetViewportWithCount(DeepCopied<CmdSetViewportWithCountArgs>),
+    CmdSetScissorWithCount(DeepCopied<CmdSetScissorWithCountArgs>),
+    CmdBindVertexBuffers2(DeepCopied<CmdBindVertexBuffers2Args>),
+    CmdSetDepthTestEnable(DeepCopied<CmdSetDepthTestEnableArgs>),
+    CmdSetDepthWriteEnable(DeepCopied<CmdSetDepthWriteEnableArgs>),
+    CmdSetDepthCompareOp(DeepCopied<CmdSetDepthCompareOpArgs>),
+    CmdSetDepthBoundsTestEnable(DeepCopied<CmdSetDepthBoundsTestEnableArgs>),
+    CmdSetStencilTestEnable(DeepCopied<CmdSetStencilTestEnableArgs>),
+    CmdSetStencilOp(DeepCopied<CmdSetStencilOpArgs>),
+    CmdSetRasterizerDiscardEnable(DeepCopied<CmdSetRasterizerDiscardEnableArgs>),
+    CmdSetDepthBiasEnable(DeepCopied<CmdSetDepthBiasEnableArgs>),
+    CmdSetPrimitiveRestartEnable(DeepCopied<CmdSetPrimitiveRestartEnableArgs>),
+    CmdBeginVideoCodingKhr(DeepCopied<CmdBeginVideoCodingKhrArgs>),
+    CmdEndVideoCodingKhr(DeepCopied<CmdEndVideoCodingKhrArgs>),
+    CmdControlVideoCodingKhr(DeepCopied<CmdControlVideoCodingKhrArgs>),
+    CmdDecodeVideoKhr(DeepCopied<CmdDecodeVideoKhrArgs>),
+    CmdPushDescriptorSetKhr(DeepCopied<CmdPushDescriptorSetKhrArgs>),
+    CmdPushDescriptorSetWithTemplateKhr(DeepCopied<CmdPushDescriptorSetWithTemplateKhrArgs>),
+    CmdSetFragmentShadingRateKhr(DeepCopied<CmdSetFragmentShadingRateKhrArgs>),
+    CmdEncodeVideoKhr(DeepCopied<CmdEncodeVideoKhrArgs>),
+    CmdWriteBufferMarker2Amd(DeepCopied<CmdWriteBufferMarker2AmdArgs>),
+    CmdTraceRaysIndirect2Khr(DeepCopied<CmdTraceRaysIndirect2KhrArgs>),
+    CmdDebugMarkerBeginExt(DeepCopied<CmdDebugMarkerBeginExtArgs>),
+    CmdDebugMarkerEndExt(DeepCopied<CmdDebugMarkerEndExtArgs>),
+    CmdDebugMarkerInsertExt(DeepCopied<CmdDebugMarkerInsertExtArgs>),
+    CmdBindTransformFeedbackBuffersExt(DeepCopied<CmdBindTransformFeedbackBuffersExtArgs>),
+    CmdBeginTransformFeedbackExt(DeepCopied<CmdBeginTransformFeedbackExtArgs>),
+    CmdEndTransformFeedbackExt(DeepCopied<CmdEndTransformFeedbackExtArgs>),
+    CmdBeginQueryIndexedExt(DeepCopied<CmdBeginQueryIndexedExtArgs>),
+    CmdEndQueryIndexedExt(DeepCopied<CmdEndQueryIndexedExtArgs>),
+    CmdDrawIndirectByteCountExt(DeepCopied<CmdDrawIndirectByteCountExtArgs>),
+    CmdCuLaunchKernelNvx(DeepCopied<CmdCuLaunchKernelNvxArgs>),
+    CmdBeginConditionalRenderingExt(DeepCopied<CmdBeginConditionalRenderingExtArgs>),
+    CmdEndConditionalRenderingExt(DeepCopied<CmdEndConditionalRenderingExtArgs>),
+    CmdSetViewportWScalingNv(DeepCopied<CmdSetViewportWScalingNvArgs>),
+    CmdSetDiscardRectangleExt(DeepCopied<CmdSetDiscardRectangleExtArgs>),
+    CmdBeginDebugUtilsLabelExt(DeepCopied<CmdBeginDebugUtilsLabelExtArgs>),
+    CmdEndDebugUtilsLabelExt(DeepCopied<CmdEndDebugUtilsLabelExtArgs>),
+    CmdInsertDebugUtilsLabelExt(DeepCopied<CmdInsertDebugUtilsLabelExtArgs>),
+    CmdSetSampleLocationsExt(DeepCopied<CmdSetSampleLocationsExtArgs>),
+    CmdBindShadingRateImageNv(DeepCopied<CmdBindShadingRateImageNvArgs>),
+    CmdSetViewportShadingRatePaletteNv(DeepCopied<CmdSetViewportShadingRatePaletteNvArgs>),
+    CmdSetCoarseSampleOrderNv(DeepCopied<CmdSetCoarseSampleOrderNvArgs>),
+    CmdBuildAccelerationStructureNv(DeepCopied<CmdBuildAccelerationStructureNvArgs>),
+    CmdCopyAccelerationStructureNv(DeepCopied<CmdCopyAccelerationStructureNvArgs>),
+    CmdTraceRaysNv(DeepCopied<CmdTraceRaysNvArgs>),
+    CmdWriteAccelerationStructuresPropertiesNv(
+        DeepCopied<CmdWriteAccelerationStructuresPropertiesNvArgs>,
+    ),
+    CmdWriteBufferMarkerAmd(DeepCopied<CmdWriteBufferMarkerAmdArgs>),
+    CmdDrawMeshTasksNv(DeepCopied<CmdDrawMeshTasksNvArgs>),
+    CmdDrawMeshTasksIndirectNv(DeepCopied<CmdDrawMeshTasksIndirectNvArgs>),
+    CmdDrawMeshTasksIndirectCountNv(DeepCopied<CmdDrawMeshTasksIndirectCountNvArgs>),
+    CmdSetExclusiveScissorNv(DeepCopied<CmdSetExclusiveScissorNvArgs>),
+    CmdSetCheckpointNv(DeepCopied<CmdSetCheckpointNvArgs>),
+    CmdSetPerformanceMarkerIntel(DeepCopied<CmdSetPerformanceMarkerIntelArgs>),
+    CmdSetPerformanceStreamMarkerIntel(DeepCopied<CmdSetPerformanceStreamMarkerIntelArgs>),
+    CmdSetPerformanceOverrideIntel(DeepCopied<CmdSetPerformanceOverrideIntelArgs>),
+    CmdSetLineStippleExt(DeepCopied<CmdSetLineStippleExtArgs>),
+    CmdPreprocessGeneratedCommandsNv(DeepCopied<CmdPreprocessGeneratedCommandsNvArgs>),
+    CmdExecuteGeneratedCommandsNv(DeepCopied<CmdExecuteGeneratedCommandsNvArgs>),
+    CmdBindPipelineShaderGroupNv(DeepCopied<CmdBindPipelineShaderGroupNvArgs>),
+    CmdBindDescriptorBuffersExt(DeepCopied<CmdBindDescriptorBuffersExtArgs>),
+    CmdSetDescriptorBufferOffsetsExt(DeepCopied<CmdSetDescriptorBufferOffsetsExtArgs>),
+    CmdBindDescriptorBufferEmbeddedSamplersExt(
+        DeepCopied<CmdBindDescriptorBufferEmbeddedSamplersExtArgs>,
+    ),
+    CmdSetFragmentShadingRateEnumNv(DeepCopied<CmdSetFragmentShadingRateEnumNvArgs>),
+    CmdSetVertexInputExt(DeepCopied<CmdSetVertexInputExtArgs>),
+    CmdSubpassShadingHuawei(DeepCopied<CmdSubpassShadingHuaweiArgs>),
+    CmdBindInvocationMaskHuawei(DeepCopied<CmdBindInvocationMaskHuaweiArgs>),
+    CmdSetPatchControlPointsExt(DeepCopied<CmdSetPatchControlPointsExtArgs>),
+    CmdSetLogicOpExt(DeepCopied<CmdSetLogicOpExtArgs>),
+    CmdSetColorWriteEnableExt(DeepCopied<CmdSetColorWriteEnableExtArgs>),
+    CmdDrawMultiExt(DeepCopied<CmdDrawMultiExtArgs>),
+    CmdDrawMultiIndexedExt(DeepCopied<CmdDrawMultiIndexedExtArgs>),
+    CmdBuildMicromapsExt(DeepCopied<CmdBuildMicromapsExtArgs>),
+    CmdCopyMicromapExt(DeepCopied<CmdCopyMicromapExtArgs>),
+    CmdCopyMicromapToMemoryExt(DeepCopied<CmdCopyMicromapToMemoryExtArgs>),
+    CmdCopyMemoryToMicromapExt(DeepCopied<CmdCopyMemoryToMicromapExtArgs>),
+    CmdWriteMicromapsPropertiesExt(DeepCopied<CmdWriteMicromapsPropertiesExtArgs>),
+    CmdCopyMemoryIndirectNv(DeepCopied<CmdCopyMemoryIndirectNvArgs>),
+    CmdCopyMemoryToImageIndirectNv(DeepCopied<CmdCopyMemoryToImageIndirectNvArgs>),
+    CmdDecompressMemoryNv(DeepCopied<CmdDecompressMemoryNvArgs>),
+    CmdDecompressMemoryIndirectCountNv(DeepCopied<CmdDecompressMemoryIndirectCountNvArgs>),
+    CmdSetTessellationDomainOriginExt(DeepCopied<CmdSetTessellationDomainOriginExtArgs>),
+    CmdSetDepthClampEnableExt(DeepCopied<CmdSetDepthClampEnableExtArgs>),
+    CmdSetPolygonModeExt(DeepCopied<CmdSetPolygonModeExtArgs>),
+    CmdSetRasterizationSamplesExt(DeepCopied<CmdSetRasterizationSamplesExtArgs>),
+    CmdSetSampleMaskExt(DeepCopied<CmdSetSampleMaskExtArgs>),
+    CmdSetAlphaToCoverageEnableExt(DeepCopied<CmdSetAlphaToCoverageEnableExtArgs>),
+    CmdSetAlphaToOneEnableExt(DeepCopied<CmdSetAlphaToOneEnableExtArgs>),
+    CmdSetLogicOpEnableExt(DeepCopied<CmdSetLogicOpEnableExtArgs>),
+    CmdSetColorBlendEnableExt(DeepCopied<CmdSetColorBlendEnableExtArgs>),
+    CmdSetColorBlendEquationExt(DeepCopied<CmdSetColorBlendEquationExtArgs>),
+    CmdSetColorWriteMaskExt(DeepCopied<CmdSetColorWriteMaskExtArgs>),
+    CmdSetRasterizationStreamExt(DeepCopied<CmdSetRasterizationStreamExtArgs>),
+    CmdSetConservativeRasterizationModeExt(DeepCopied<CmdSetConservativeRasterizationModeExtArgs>),
+    CmdSetExtraPrimitiveOverestimationSizeExt(
+        DeepCopied<CmdSetExtraPrimitiveOverestimationSizeExtArgs>,
+    ),
+    CmdSetDepthClipEnableExt(DeepCopied<CmdSetDepthClipEnableExtArgs>),
+    CmdSetSampleLocationsEnableExt(DeepCopied<CmdSetSampleLocationsEnableExtArgs>),
+    CmdSetColorBlendAdvancedExt(DeepCopied<CmdSetColorBlendAdvancedExtArgs>),
+    CmdSetProvokingVertexModeExt(DeepCopied<CmdSetProvokingVertexModeExtArgs>),
+    CmdSetLineRasterizationModeExt(DeepCopied<CmdSetLineRasterizationModeExtArgs>),
+    CmdSetLineStippleEnableExt(DeepCopied<CmdSetLineStippleEnableExtArgs>),
+    CmdSetDepthClipNegativeOneToOneExt(DeepCopied<CmdSetDepthClipNegativeOneToOneExtArgs>),
+    CmdSetViewportWScalingEnableNv(DeepCopied<CmdSetViewportWScalingEnableNvArgs>),
+    CmdSetViewportSwizzleNv(DeepCopied<CmdSetViewportSwizzleNvArgs>),
+    CmdSetCoverageToColorEnableNv(DeepCopied<CmdSetCoverageToColorEnableNvArgs>),
+    CmdSetCoverageToColorLocationNv(DeepCopied<CmdSetCoverageToColorLocationNvArgs>),
+    CmdSetCoverageModulationModeNv(DeepCopied<CmdSetCoverageModulationModeNvArgs>),
+    CmdSetCoverageModulationTableEnableNv(DeepCopied<CmdSetCoverageModulationTableEnableNvArgs>),
+    CmdSetCoverageModulationTableNv(DeepCopied<CmdSetCoverageModulationTableNvArgs>),
+    CmdSetShadingRateImageEnableNv(DeepCopied<CmdSetShadingRateImageEnableNvArgs>),
+    CmdSetRepresentativeFragmentTestEnableNv(
+        DeepCopied<CmdSetRepresentativeFragmentTestEnableNvArgs>,
+    ),
+    CmdSetCoverageReductionModeNv(DeepCopied<CmdSetCoverageReductionModeNvArgs>),
+    CmdOpticalFlowExecuteNv(DeepCopied<CmdOpticalFlowExecuteNvArgs>),
+    CmdCopyAccelerationStructureKhr(DeepCopied<CmdCopyAccelerationStructureKhrArgs>),
+    CmdCopyAccelerationStructureToMemoryKhr(
+        DeepCopied<CmdCopyAccelerationStructureToMemoryKhrArgs>,
+    ),
+    CmdCopyMemoryToAccelerationStructureKhr(
+        DeepCopied<CmdCopyMemoryToAccelerationStructureKhrArgs>,
+    ),
+    CmdTraceRaysKhr(DeepCopied<CmdTraceRaysKhrArgs>),
+    CmdTraceRaysIndirectKhr(DeepCopied<CmdTraceRaysIndirectKhrArgs>),
+    CmdSetRayTracingPipelineStackSizeKhr(DeepCopied<CmdSetRayTracingPipelineStackSizeKhrArgs>),
+    CmdDrawMeshTasksExt(DeepCopied<CmdDrawMeshTasksExtArgs>),
+    CmdDrawMeshTasksIndirectExt(DeepCopied<CmdDrawMeshTasksIndirectExtArgs>),
+    CmdDrawMeshTasksIndirectCountExt(DeepCopied<CmdDrawMeshTasksIndirectCountExtArgs>),
+}
+
+// Safe because the pointers in the arguments refer to the memory owned by the DeepCopied,
+// which is never written after the copy, except the opaque host pointers copied as is. Those
+// are only dereferenced by the unsafe RecordedCommand::replay, whose caller guarantees that
+// they are still valid. The arguments structs themselves are not Send or Sync, since their
+// pointers refer to the memory of the caller of the intercepted command.
+unsafe impl Send for RecordedCommand {}
+unsafe impl Sync for RecordedCommand {}
+
+impl RecordedCommand {
+    /// The name of the recorded Vulkan command, e.g. `vkCmdDraw`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CmdBindPipeline(_) => "vkCmdBindPipeline",
+            Self::CmdSetViewport(_) => "vkCmdSetViewport",
+            Self::CmdSetScissor(_) => "vkCmdSetScissor",
+            Self::CmdSetLineWidth(_) => "vkCmdSetLineWidth",
+            Self::CmdSetDepthBias(_) => "vkCmdSetDepthBias",
+            Self::CmdSetBlendConstants(_) => "vkCmdSetBlendConstants",
+            Self::CmdSetDepthBounds(_) => "vkCmdSetDepthBounds",
+            Self::CmdSetStencilCompareMask(_) => "vkCmdSetStencilCompareMask",
+            Self::CmdSetStencilWriteMask(_) => "vkCmdSetStencilWriteMask",
+            Self::CmdSetStencilReference(_) => "vkCmdSetStencilReference",
+            Self::CmdBindDescriptorSets(_) => "vkCmdBindDescriptorSets",
+            Self::CmdBindIndexBuffer(_) => "vkCmdBindIndexBuffer",
+            Self::CmdBindVertexBuffers(_) => "vkCmdBindVertexBuffers",
+            Self::CmdDraw(_) => "vkCmdDraw",
+            Self::CmdDrawIndexed(_) => "vkCmdDrawIndexed",
+            Self::CmdDrawIndirect(_) => "vkCmdDrawIndirect",
+            Self::CmdDrawIndexedIndirect(_) => "vkCmdDrawIndexedIndirect",
+            Self::CmdDispatch(_) => "vkCmdDispatch",
+            Self::CmdDispatchIndirect(_) => "vkCmdDispatchIndirect",
+            Self::CmdCopyBuffer(_) => "vkCmdCopyBuffer",
+            Self::CmdCopyImage(_) => "vkCmdCopyImage",
+            Self::CmdBlitImage(_) => "vkCmdBlitImage",
+            Self::CmdCopyBufferToImage(_) => "vkCmdCopyBufferToImage",
+            Self::CmdCopyImageToBuffer(_) => "vkCmdCopyImageToBuffer",
+            Self::CmdUpdateBuffer(_) => "vkCmdUpdateBuffer",
+            Self::CmdFillBuffer(_) => "vkCmdFillBuffer",
+            Self::CmdClearColorImage(_) => "vkCmdClearColorImage",
+            Self::CmdClearDepthStencilImage(_) => "vkCmdClearDepthStencilImage",
+            Self::CmdClearAttachments(_) => "vkCmdClearAttachments",
+            Self::CmdResolveImage(_) => "vkCmdResolveImage",
+            Self::CmdSetEvent(_) => "vkCmdSetEvent",
+            Self::CmdResetEvent(_) => "vkCmdResetEvent",
+            Self::CmdWaitEvents(_) => "vkCmdWaitEvents",
+            Self::CmdPipelineBarrier(_) => "vkCmdPipelineBarrier",
+            Self::CmdBeginQuery(_) => "vkCmdBeginQuery",
+            Self::CmdEndQuery(_) => "vkCmdEndQuery",
+            Self::CmdResetQueryPool(_) => "vkCmdResetQueryPool",
+            Self::CmdWriteTimestamp(_) => "vkCmdWriteTimestamp",
+            Self::CmdCopyQueryPoolResults(_) => "vkCmdCopyQueryPoolResults",
+            Self::CmdPushConstants(_) => "vkCmdPushConstants",
+            Self::CmdBeginRenderPass(_) => "vkCmdBeginRenderPass",
+            Self::CmdNextSubpass(_) => "vkCmdNextSubpass",
+            Self::CmdEndRenderPass(_) => "vkCmdEndRenderPass",
+            Self::CmdExecuteCommands(_) => "vkCmdExecuteCommands",
+            Self::CmdSetDeviceMask(_) => "vkCmdSetDeviceMask",
+            Self::CmdDispatchBase(_) => "vkCmdDispatchBase",
+            Self::CmdDrawIndirectCount(_) => "vkCmdDrawIndirectCount",
+            Self::CmdDrawIndexedIndirectCount(_) => "vkCmdDrawIndexedIndirectCount",
+            Self::CmdBeginRenderPass2(_) => "vkCmdBeginRenderPass2",
+            Self::CmdNextSubpass2(_) => "vkCmdNextSubpass2",
+            Self::CmdEndRenderPass2(_) => "vkCmdEndRenderPass2",
+            Self::CmdSetEvent2(_) => "vkCmdSetEvent2",
+            Self::CmdResetEvent2(_) => "vkCmdResetEvent2",
+            Self::CmdWaitEvents2(_) => "vkCmdWaitEvents2",
+            Self::CmdPipelineBarrier2(_) => "vkCmdPipelineBarrier2",
+            Self::CmdWriteTimestamp2(_) => "vkCmdWriteTimestamp2",
+            Self::CmdCopyBuffer2(_) => "vkCmdCopyBuffer2",
+            Self::CmdCopyImage2(_) => "vkCmdCopyImage2",
+            Self::CmdCopyBufferToImage2(_) => "vkCmdCopyBufferToImage2",
+            Self::CmdCopyImageToBuffer2(_) => "vkCmdCopyImageToBuffer2",
+            Self::CmdBlitImage2(_) => "vkCmdBlitImage2",
+            Self::CmdResolveImage2(_) => "vkCmdResolveImage2",
+            Self::CmdBeginRendering(_) => "vkCmdBeginRendering",
+            Self::CmdEndRendering(_) => "vkCmdEndRendering",
+            Self::CmdSetCullMode(_) => "vkCmdSetCullMode",
+            Self::CmdSetFrontFace(_) => "vkCmdSetFrontFace",
+            Self::CmdSetPrimitiveTopology(_) => "vkCmdSetPrimitiveTopology",
+            Self::CmdSetViewportWithCount(_) => "vkCmdSetViewportWithCount",
+            Self::CmdSetScissorWithCount(_) => "vkCmdSetScissorWithCount",
+            Self::CmdBindVertexBuffers2(_) => "vkCmdBindVertexBuffers2",
+            Self::CmdSetDepthTestEnable(_) => "vkCmdSetDepthTestEnable",
+            Self::CmdSetDepthWriteEnable(_) => "vkCmdSetDepthWriteEnable",
+            Self::CmdSetDepthCompareOp(_) => "vkCmdSetDepthCompareOp",
+            Self::CmdSetDepthBoundsTestEnable(_) => "vkCmdSetDepthBoundsTestEnable",
+            Self::CmdSetStencilTestEnable(_) => "vkCmdSetStencilTestEnable",
+            Self::CmdSetStencilOp(_) => "vkCmdSetStencilOp",
+            Self::CmdSetRasterizerDiscardEnable(_) => "vkCmdSetRasterizerDiscardEnable",
+            Self::CmdSetDepthBiasEnable(_) => "vkCmdSetDepthBiasEnable",
+            Self::CmdSetPrimitiveRestartEnable(_) => "vkCmdSetPrimitiveRestartEnable",
+            Self::CmdBeginVideoCodingKhr(_) => "vkCmdBeginVideoCodingKHR",
+            Self::CmdEndVideoCodingKhr(_) => "vkCmdEndVideoCodingKHR",
+            Self::CmdControlVideoCodingKhr(_) => "vkCmdControlVideoCodingKHR",
+            Self::CmdDecodeVideoKhr(_) => "vkCmdDecodeVideoKHR",
+            Self::CmdPushDescriptorSetKhr(_) => "vkCmdPushDescriptorSetKHR",
+            Self::CmdPushDescriptorSetWithTemplateKhr(_) => "vkCmdPushDescriptorSetWithTemplateKHR",
+            Self::CmdSetFragmentShadingRateKhr(_) => "vkCmdSetFragmentShadingRateKHR",
+            Self::CmdEncodeVideoKhr(_) => "vkCmdEncodeVideoKHR",
+            Self::CmdWriteBufferMarker2Amd(_) => "vkCmdWriteBufferMarker2AMD",
+            Self::CmdTraceRaysIndirect2Khr(_) => "vkCmdTraceRaysIndirect2KHR",
+            Self::CmdDebugMarkerBeginExt(_) => "vkCmdDebugMarkerBeginEXT",
+            Self::CmdDebugMarkerEndExt(_) => "vkCmdDebugMarkerEndEXT",
+            Self::CmdDebugMarkerInsertExt(_) => "vkCmdDebugMarkerInsertEXT",
+            Self::CmdBindTransformFeedbackBuffersExt(_) => "vkCmdBindTransformFeedbackBuffersEXT",
+            Self::CmdBeginTransformFeedbackExt(_) => "vkCmdBeginTransformFeedbackEXT",
+            Self::CmdEndTransformFeedbackExt(_) => "vkCmdEndTransformFeedbackEXT",
+            Self::CmdBeginQueryIndexedExt(_) => "vkCmdBeginQueryIndexedEXT",
+            Self::CmdEndQueryIndexedExt(_) => "vkCmdEndQueryIndexedEXT",
+            Self::CmdDrawIndirectByteCountExt(_) => "vkCmdDrawIndirectByteCountEXT",
+            Self::CmdCuLaunchKernelNvx(_) => "vkCmdCuLaunchKernelNVX",
+            Self::CmdBeginConditionalRenderingExt(_) => "vkCmdBeginConditionalRenderingEXT",
+            Self::CmdEndConditionalRenderingExt(_) => "vkCmdEndConditionalRenderingEXT",
+            Self::CmdSetViewportWScalingNv(_) => "vkCmdSetViewportWScalingNV",
+            Self::CmdSetDiscardRectangleExt(_) => "vkCmdSetDiscardRectangleEXT",
+            Self::CmdBeginDebugUtilsLabelExt(_) => "vkCmdBeginDebugUtilsLabelEXT",
+            Self::CmdEndDebugUtilsLabelExt(_) => "vkCmdEndDebugUtilsLabelEXT",
+            Self::CmdInsertDebugUtilsLabelExt(_) => "vkCmdInsertDebugUtilsLabelEXT",
+            Self::CmdSetSampleLocationsExt(_) => "vkCmdSetSampleLocationsEXT",
+            Self::CmdBindShadingRateImageNv(_) => "vkCmdBindShadingRateImageNV",
+            Self::CmdSetViewportShadingRatePaletteNv(_) => "vkCmdSetViewportShadingRatePaletteNV",
+            Self::CmdSetCoarseSampleOrderNv(_) => "vkCmdSetCoarseSampleOrderNV",
+            Self::CmdBuildAccelerationStructureNv(_) => "vkCmdBuildAccelerationStructureNV",
+            Self::CmdCopyAccelerationStructureNv(_) => "vkCmdCopyAccelerationStructureNV",
+            Self::CmdTraceRaysNv(_) => "vkCmdTraceRaysNV",
+            Self::CmdWriteAccelerationStructuresPropertiesNv(_) => {
+                "vkCmdWriteAccelerationStructuresPropertiesNV"
+            }
+            Self::CmdWriteBufferMarkerAmd(_) => "vkCmdWriteBufferMarkerAMD",
+            Self::CmdDrawMeshTasksNv(_) => "vkCmdDrawMeshTasksNV",
+            Self::CmdDrawMeshTasksIndirectNv(_) => "vkCmdDrawMeshTasksIndirectNV",
+            Self::CmdDrawMeshTasksIndirectCountNv(_) => "vkCmdDrawMeshTasksIndirectCountNV",
+            Self::CmdSetExclusiveScissorNv(_) => "vkCmdSetExclusiveScissorNV",
+            Self::CmdSetCheckpointNv(_) => "vkCmdSetCheckpointNV",
+            Self::CmdSetPerformanceMarkerIntel(_) => "vkCmdSetPerformanceMarkerINTEL",
+            Self::CmdSetPerformanceStreamMarkerIntel(_) => "vkCmdSetPerformanceStreamMarkerINTEL",
+            Self::CmdSetPerformanceOverrideIntel(_) => "vkCmdSetPerformanceOverrideINTEL",
+            Self::CmdSetLineStippleExt(_) => "vkCmdSetLineStippleEXT",
+            Self::CmdPreprocessGeneratedCommandsNv(_) => "vkCmdPreprocessGeneratedCommandsNV",
+            Self::CmdExecuteGeneratedCommandsNv(_) => "vkCmdExecuteGeneratedCommandsNV",
+            Self::CmdBindPipelineShaderGroupNv(_) => "vkCmdBindPipelineShaderGroupNV",
+            Self::CmdBindDescriptorBuffersExt(_) => "vkCmdBindDescriptorBuffersEXT",
+            Self::CmdSetDescriptorBufferOffsetsExt(_) => "vkCmdSetDescriptorBufferOffsetsEXT",
+            Self::CmdBindDescriptorBufferEmbeddedSamplersExt(_) => {
+                "vkCmdBindDescriptorBufferEmbeddedSamplersEXT"
+            }
+            Self::CmdSetFragmentShadingRateEnumNv(_) => "vkCmdSetFragmentShadingRateEnumNV",
+            Self::CmdSetVertexInputExt(_) => "vkCmdSetVertexInputEXT",
+            Self::CmdSubpassShadingHuawei(_) => "vkCmdSubpassShadingHUAWEI",
+            Self::CmdBindInvocationMaskHuawei(_) => "vkCmdBindInvocationMaskHUAWEI",
+            Self::CmdSetPatchControlPointsExt(_) => "vkCmdSetPatchControlPointsEXT",
+            Self::CmdSetLogicOpExt(_) => "vkCmdSetLogicOpEXT",
+            Self::CmdSetColorWriteEnableExt(_) => "vkCmdSetColorWriteEnableEXT",
+            Self::CmdDrawMultiExt(_) => "vkCmdDrawMultiEXT",
+            Self::CmdDrawMultiIndexedExt(_) => "vkCmdDrawMultiIndexedEXT",
+            Self::CmdBuildMicromapsExt(_) => "vkCmdBuildMicromapsEXT",
+            Self::CmdCopyMicromapExt(_) => "vkCmdCopyMicromapEXT",
+            Self::CmdCopyMicromapToMemoryExt(_) => "vkCmdCopyMicromapToMemoryEXT",
+            Self::CmdCopyMemoryToMicromapExt(_) => "vkCmdCopyMemoryToMicromapEXT",
+            Self::CmdWriteMicromapsPropertiesExt(_) => "vkCmdWriteMicromapsPropertiesEXT",
+            Self::CmdCopyMemoryIndirectNv(_) => "vkCmdCopyMemoryIndirectNV",
+            Self::CmdCopyMemoryToImageIndirectNv(_) => "vkCmdCopyMemoryToImageIndirectNV",
+            Self::CmdDecompressMemoryNv(_) => "vkCmdDecompressMemoryNV",
+            Self::CmdDecompressMemoryIndirectCountNv(_) => "vkCmdDecompressMemoryIndirectCountNV",
+            Self::CmdSetTessellationDomainOriginExt(_) => "vkCmdSetTessellationDomainOriginEXT",
+            Self::CmdSetDepthClampEnableExt(_) => "vkCmdSetDepthClampEnableEXT",
+            Self::CmdSetPolygonModeExt(_) => "vkCmdSetPolygonModeEXT",
+            Self::CmdSetRasterizationSamplesExt(_) => "vkCmdSetRasterizationSamplesEXT",
+            Self::CmdSetSampleMaskExt(_) => "vkCmdSetSampleMaskEXT",
+            Self::CmdSetAlphaToCoverageEnableExt(_) => "vkCmdSetAlphaToCoverageEnableEXT",
+            Self::CmdSetAlphaToOneEnableExt(_) => "vkCmdSetAlphaToOneEnableEXT",
+            Self::CmdSetLogicOpEnableExt(_) => "vkCmdSetLogicOpEnableEXT",
+            Self::CmdSetColorBlendEnableExt(_) => "vkCmdSetColorBlendEnableEXT",
+            Self::CmdSetColorBlendEquationExt(_) => "vkCmdSetColorBlendEquationEXT",
+            Self::CmdSetColorWriteMaskExt(_) => "vkCmdSetColorWriteMaskEXT",
+            Self::CmdSetRasterizationStreamExt(_) => "vkCmdSetRasterizationStreamEXT",
+            Self::CmdSetConservativeRasterizationModeExt(_) => {
+                "vkCmdSetConservativeRasterizationModeEXT"
+            }
+            Self::CmdSetExtraPrimitiveOverestimationSizeExt(_) => {
+                "vkCmdSetExtraPrimitiveOverestimationSizeEXT"
+            }
+            Self::CmdSetDepthClipEnableExt(_) => "vkCmdSetDepthClipEnableEXT",
+            Self::CmdSetSampleLocationsEnableExt(_) => "vkCmdSetSampleLocationsEnableEXT",
+            Self::CmdSetColorBlendAdvancedExt(_) => "vkCmdSetColorBlendAdvancedEXT",
+            Self::CmdSetProvokingVertexModeExt(_) => "vkCmdSetProvokingVertexModeEXT",
+            Self::CmdSetLineRasterizationModeExt(_) => "vkCmdSetLineRasterizationModeEXT",
+            Self::CmdSetLineStippleEnableExt(_) => "vkCmdSetLineStippleEnableEXT",
+            Self::CmdSetDepthClipNegativeOneToOneExt(_) => "vkCmdSetDepthClipNegativeOneToOneEXT",
+            Self::CmdSetViewportWScalingEnableNv(_) => "vkCmdSetViewportWScalingEnableNV",
+            Self::CmdSetViewportSwizzleNv(_) => "vkCmdSetViewportSwizzleNV",
+            Self::CmdSetCoverageToColorEnableNv(_) => "vkCmdSetCoverageToColorEnableNV",
+            Self::CmdSetCoverageToColorLocationNv(_) => "vkCmdSetCoverageToColorLocationNV",
+            Self::CmdSetCoverageModulationModeNv(_) => "vkCmdSetCoverageModulationModeNV",
+            Self::CmdSetCoverageModulationTableEnableNv(_) => {
+                "vkCmdSetCoverageModulationTableEnableNV"
+            }
+            Self::CmdSetCoverageModulationTableNv(_) => "vkCmdSetCoverageModulationTableNV",
+            Self::CmdSetShadingRateImageEnableNv(_) => "vkCmdSetShadingRateImageEnableNV",
+            Self::CmdSetRepresentativeFragmentTestEnableNv(_) => {
+                "vkCmdSetRepresentativeFragmentTestEnableNV"
+            }
+            Self::CmdSetCoverageReductionModeNv(_) => "vkCmdSetCoverageReductionModeNV",
+            Self::CmdOpticalFlowExecuteNv(_) => "vkCmdOpticalFlowExecuteNV",
+            Self::CmdCopyAccelerationStructureKhr(_) => "vkCmdCopyAccelerationStructureKHR",
+            Self::CmdCopyAccelerationStructureToMemoryKhr(_) => {
+                "vkCmdCopyAccelerationStructureToMemoryKHR"
+            }
+            Self::CmdCopyMemoryToAccelerationStructureKhr(_) => {
+                "vkCmdCopyMemoryToAccelerationStructureKHR"
+            }
+            Self::CmdTraceRaysKhr(_) => "vkCmdTraceRaysKHR",
+            Self::CmdTraceRaysIndirectKhr(_) => "vkCmdTraceRaysIndirectKHR",
+            Self::CmdSetRayTracingPipelineStackSizeKhr(_) => {
+                "vkCmdSetRayTracingPipelineStackSizeKHR"
+            }
+            Self::CmdDrawMeshTasksExt(_) => "vkCmdDrawMeshTasksEXT",
+            Self::CmdDrawMeshTasksIndirectExt(_) => "vkCmdDrawMeshTasksIndirectEXT",
+            Self::CmdDrawMeshTasksIndirectCountExt(_) => "vkCmdDrawMeshTasksIndirectCountEXT",
+        }
+    }
+
+    pub(crate) unsafe fn replay_impl(
+        &self,
+        core: &ash::Device,
+        extensions: Option<&DeviceDispatchTable>,
+        command_buffer: vk::CommandBuffer,
+    ) -> Result<(), ReplayError> {
+        match self {
+            Self::CmdBindPipeline(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_bind_pipeline)(
+                        command_buffer,
+                        args.pipeline_bind_point,
+                        args.pipeline,
+                    )
+                };
+            }
+            Self::CmdSetViewport(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_viewport_count = args.p_viewports.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_viewport)(
+                        command_buffer,
+                        args.first_viewport,
+                        rewritten_viewport_count,
+                        args.p_viewports.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetScissor(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_scissor_count = args.p_scissors.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_scissor)(
+                        command_buffer,
+                        args.first_scissor,
+                        rewritten_scissor_count,
+                        args.p_scissors.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetLineWidth(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe { (dispatch_table.cmd_set_line_width)(command_buffer, args.line_width) };
+            }
+            Self::CmdSetDepthBias(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_bias)(
+                        command_buffer,
+                        args.depth_bias_constant_factor,
+                        args.depth_bias_clamp,
+                        args.depth_bias_slope_factor,
+                    )
+                };
+            }
+            Self::CmdSetBlendConstants(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_set_blend_constants)(command_buffer, &args.blend_constants)
+                };
+            }
+            Self::CmdSetDepthBounds(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_bounds)(
+                        command_buffer,
+                        args.min_depth_bounds,
+                        args.max_depth_bounds,
+                    )
+                };
+            }
+            Self::CmdSetStencilCompareMask(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_set_stencil_compare_mask)(
+                        command_buffer,
+                        args.face_mask,
+                        args.compare_mask,
+                    )
+                };
+            }
+            Self::CmdSetStencilWriteMask(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_set_stencil_write_mask)(
+                        command_buffer,
+                        args.face_mask,
+                        args.write_mask,
+                    )
+                };
+            }
+            Self::CmdSetStencilReference(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_set_stencil_reference)(
+                        command_buffer,
+                        args.face_mask,
+                        args.reference,
+                    )
+                };
+            }
+            Self::CmdBindDescriptorSets(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_descriptor_set_count =
+                    args.p_descriptor_sets.len().try_into().unwrap();
+                let rewritten_dynamic_offset_count =
+                    args.p_dynamic_offsets.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_bind_descriptor_sets)(
+                        command_buffer,
+                        args.pipeline_bind_point,
+                        args.layout,
+                        args.first_set,
+                        rewritten_descriptor_set_count,
+                        args.p_descriptor_sets.as_ptr(),
+                        rewritten_dynamic_offset_count,
+                        args.p_dynamic_offsets.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdBindIndexBuffer(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_bind_index_buffer)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.index_type,
+                    )
+                };
+            }
+            Self::CmdBindVertexBuffers(args) => {
+                let dispatch_table = core.fp_v1_0();
+                assert_eq!(args.p_offsets.len(), args.p_buffers.len());
+                let rewritten_binding_count = args.p_buffers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_bind_vertex_buffers)(
+                        command_buffer,
+                        args.first_binding,
+                        rewritten_binding_count,
+                        args.p_buffers.as_ptr(),
+                        args.p_offsets.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdDraw(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_draw)(
+                        command_buffer,
+                        args.vertex_count,
+                        args.instance_count,
+                        args.first_vertex,
+                        args.first_instance,
+                    )
+                };
+            }
+            Self::CmdDrawIndexed(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_draw_indexed)(
+                        command_buffer,
+                        args.index_count,
+                        args.instance_count,
+                        args.first_index,
+                        args.vertex_offset,
+                        args.first_instance,
+                    )
+                };
+            }
+            Self::CmdDrawIndirect(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_draw_indirect)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.draw_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdDrawIndexedIndirect(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_draw_indexed_indirect)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.draw_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdDispatch(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_dispatch)(
+                        command_buffer,
+                        args.group_countx,
+                        args.group_county,
+                        args.group_countz,
+                    )
+                };
+            }
+            Self::CmdDispatchIndirect(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_dispatch_indirect)(command_buffer, args.buffer, args.offset)
+                };
+            }
+            Self::CmdCopyBuffer(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_region_count = args.p_regions.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_copy_buffer)(
+                        command_buffer,
+                        args.src_buffer,
+                        args.dst_buffer,
+                        rewritten_region_count,
+                        args.p_regions.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdCopyImage(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_region_count = args.p_regions.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_copy_image)(
+                        command_buffer,
+                        args.src_image,
+                        args.src_image_layout,
+                        args.dst_image,
+                        args.dst_image_layout,
+                        rewritten_region_count,
+                        args.p_regions.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdBlitImage(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_region_count = args.p_regions.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_blit_image)(
+                        command_buffer,
+                        args.src_image,
+                        args.src_image_layout,
+                        args.dst_image,
+                        args.dst_image_layout,
+                        rewritten_region_count,
+                        args.p_regions.as_ptr(),
+                        args.filter,
+                    )
+                };
+            }
+            Self::CmdCopyBufferToImage(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_region_count = args.p_regions.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_copy_buffer_to_image)(
+                        command_buffer,
+                        args.src_buffer,
+                        args.dst_image,
+                        args.dst_image_layout,
+                        rewritten_region_count,
+                        args.p_regions.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdCopyImageToBuffer(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_region_count = args.p_regions.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_copy_image_to_buffer)(
+                        command_buffer,
+                        args.src_image,
+                        args.src_image_layout,
+                        args.dst_buffer,
+                        rewritten_region_count,
+                        args.p_regions.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdUpdateBuffer(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_data_size = args.p_data.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_update_buffer)(
+                        command_buffer,
+                        args.dst_buffer,
+                        args.dst_offset,
+                        rewritten_data_size,
+                        args.p_data.as_ptr().cast(),
+                    )
+                };
+            }
+            Self::CmdFillBuffer(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_fill_buffer)(
+                        command_buffer,
+                        args.dst_buffer,
+                        args.dst_offset,
+                        args.size,
+                        args.data,
+                    )
+                };
+            }
+            Self::CmdClearColorImage(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_range_count = args.p_ranges.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_clear_color_image)(
+                        command_buffer,
+                        args.image,
+                        args.image_layout,
+                        &args.p_color,
+                        rewritten_range_count,
+                        args.p_ranges.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdClearDepthStencilImage(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_range_count = args.p_ranges.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_clear_depth_stencil_image)(
+                        command_buffer,
+                        args.image,
+                        args.image_layout,
+                        &args.p_depth_stencil,
+                        rewritten_range_count,
+                        args.p_ranges.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdClearAttachments(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_attachment_count = args.p_attachments.len().try_into().unwrap();
+                let rewritten_rect_count = args.p_rects.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_clear_attachments)(
+                        command_buffer,
+                        rewritten_attachment_count,
+                        args.p_attachments.as_ptr(),
+                        rewritten_rect_count,
+                        args.p_rects.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdResolveImage(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_region_count = args.p_regions.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_resolve_image)(
+                        command_buffer,
+                        args.src_image,
+                        args.src_image_layout,
+                        args.dst_image,
+                        args.dst_image_layout,
+                        rewritten_region_count,
+                        args.p_regions.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetEvent(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_set_event)(command_buffer, args.event, args.stage_mask)
+                };
+            }
+            Self::CmdResetEvent(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_reset_event)(command_buffer, args.event, args.stage_mask)
+                };
+            }
+            Self::CmdWaitEvents(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_event_count = args.p_events.len().try_into().unwrap();
+                let rewritten_memory_barrier_count =
+                    args.p_memory_barriers.len().try_into().unwrap();
+                let rewritten_buffer_memory_barrier_count =
+                    args.p_buffer_memory_barriers.len().try_into().unwrap();
+                let rewritten_image_memory_barrier_count =
+                    args.p_image_memory_barriers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_wait_events)(
+                        command_buffer,
+                        rewritten_event_count,
+                        args.p_events.as_ptr(),
+                        args.src_stage_mask,
+                        args.dst_stage_mask,
+                        rewritten_memory_barrier_count,
+                        args.p_memory_barriers.as_ptr(),
+                        rewritten_buffer_memory_barrier_count,
+                        args.p_buffer_memory_barriers.as_ptr(),
+                        rewritten_image_memory_barrier_count,
+                        args.p_image_memory_barriers.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdPipelineBarrier(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_memory_barrier_count =
+                    args.p_memory_barriers.len().try_into().unwrap();
+                let rewritten_buffer_memory_barrier_count =
+                    args.p_buffer_memory_barriers.len().try_into().unwrap();
+                let rewritten_image_memory_barrier_count =
+                    args.p_image_memory_barriers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_pipeline_barrier)(
+                        command_buffer,
+                        args.src_stage_mask,
+                        args.dst_stage_mask,
+                        args.dependency_flags,
+                        rewritten_memory_barrier_count,
+                        args.p_memory_barriers.as_ptr(),
+                        rewritten_buffer_memory_barrier_count,
+                        args.p_buffer_memory_barriers.as_ptr(),
+                        rewritten_image_memory_barrier_count,
+                        args.p_image_memory_barriers.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdBeginQuery(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_begin_query)(
+                        command_buffer,
+                        args.query_pool,
+                        args.query,
+                        args.flags,
+                    )
+                };
+            }
+            Self::CmdEndQuery(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_end_query)(command_buffer, args.query_pool, args.query)
+                };
+            }
+            Self::CmdResetQueryPool(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_reset_query_pool)(
+                        command_buffer,
+                        args.query_pool,
+                        args.first_query,
+                        args.query_count,
+                    )
+                };
+            }
+            Self::CmdWriteTimestamp(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_write_timestamp)(
+                        command_buffer,
+                        args.pipeline_stage,
+                        args.query_pool,
+                        args.query,
+                    )
+                };
+            }
+            Self::CmdCopyQueryPoolResults(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_copy_query_pool_results)(
+                        command_buffer,
+                        args.query_pool,
+                        args.first_query,
+                        args.query_count,
+                        args.dst_buffer,
+                        args.dst_offset,
+                        args.stride,
+                        args.flags,
+                    )
+                };
+            }
+            Self::CmdPushConstants(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_size = args.p_values.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_push_constants)(
+                        command_buffer,
+                        args.layout,
+                        args.stage_flags,
+                        args.offset,
+                        rewritten_size,
+                        args.p_values.as_ptr().cast(),
+                    )
+                };
+            }
+            Self::CmdBeginRenderPass(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe {
+                    (dispatch_table.cmd_begin_render_pass)(
+                        command_buffer,
+                        &args.p_render_pass_begin,
+                        args.contents,
+                    )
+                };
+            }
+            Self::CmdNextSubpass(args) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe { (dispatch_table.cmd_next_subpass)(command_buffer, args.contents) };
+            }
+            Self::CmdEndRenderPass(_) => {
+                let dispatch_table = core.fp_v1_0();
+                unsafe { (dispatch_table.cmd_end_render_pass)(command_buffer) };
+            }
+            Self::CmdExecuteCommands(args) => {
+                let dispatch_table = core.fp_v1_0();
+                let rewritten_command_buffer_count =
+                    args.p_command_buffers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_execute_commands)(
+                        command_buffer,
+                        rewritten_command_buffer_count,
+                        args.p_command_buffers.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetDeviceMask(args) => {
+                let dispatch_table = core.fp_v1_1();
+                unsafe { (dispatch_table.cmd_set_device_mask)(command_buffer, args.device_mask) };
+            }
+            Self::CmdDispatchBase(args) => {
+                let dispatch_table = core.fp_v1_1();
+                unsafe {
+                    (dispatch_table.cmd_dispatch_base)(
+                        command_buffer,
+                        args.base_groupx,
+                        args.base_groupy,
+                        args.base_groupz,
+                        args.group_countx,
+                        args.group_county,
+                        args.group_countz,
+                    )
+                };
+            }
+            Self::CmdDrawIndirectCount(args) => {
+                let dispatch_table = core.fp_v1_2();
+                unsafe {
+                    (dispatch_table.cmd_draw_indirect_count)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.count_buffer,
+                        args.count_buffer_offset,
+                        args.max_draw_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdDrawIndexedIndirectCount(args) => {
+                let dispatch_table = core.fp_v1_2();
+                unsafe {
+                    (dispatch_table.cmd_draw_indexed_indirect_count)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.count_buffer,
+                        args.count_buffer_offset,
+                        args.max_draw_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdBeginRenderPass2(args) => {
+                let dispatch_table = core.fp_v1_2();
+                unsafe {
+                    (dispatch_table.cmd_begin_render_pass2)(
+                        command_buffer,
+                        &args.p_render_pass_begin,
+                        &args.p_subpass_begin_info,
+                    )
+                };
+            }
+            Self::CmdNextSubpass2(args) => {
+                let dispatch_table = core.fp_v1_2();
+                unsafe {
+                    (dispatch_table.cmd_next_subpass2)(
+                        command_buffer,
+                        &args.p_subpass_begin_info,
+                        &args.p_subpass_end_info,
+                    )
+                };
+            }
+            Self::CmdEndRenderPass2(args) => {
+                let dispatch_table = core.fp_v1_2();
+                unsafe {
+                    (dispatch_table.cmd_end_render_pass2)(command_buffer, &args.p_subpass_end_info)
+                };
+            }
+            Self::CmdSetEvent2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_event2)(
+                        command_buffer,
+                        args.event,
+                        &args.p_dependency_info,
+                    )
+                };
+            }
+            Self::CmdResetEvent2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_reset_event2)(command_buffer, args.event, args.stage_mask)
+                };
+            }
+            Self::CmdWaitEvents2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                assert_eq!(args.p_dependency_infos.len(), args.p_events.len());
+                let rewritten_event_count = args.p_events.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_wait_events2)(
+                        command_buffer,
+                        rewritten_event_count,
+                        args.p_events.as_ptr(),
+                        args.p_dependency_infos.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdPipelineBarrier2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_pipeline_barrier2)(command_buffer, &args.p_dependency_info)
+                };
+            }
+            Self::CmdWriteTimestamp2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_write_timestamp2)(
+                        command_buffer,
+                        args.stage,
+                        args.query_pool,
+                        args.query,
+                    )
+                };
+            }
+            Self::CmdCopyBuffer2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_copy_buffer2)(command_buffer, &args.p_copy_buffer_info)
+                };
+            }
+            Self::CmdCopyImage2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_copy_image2)(command_buffer, &args.p_copy_image_info)
+                };
+            }
+            Self::CmdCopyBufferToImage2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_copy_buffer_to_image2)(
+                        command_buffer,
+                        &args.p_copy_buffer_to_image_info,
+                    )
+                };
+            }
+            Self::CmdCopyImageToBuffer2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_copy_image_to_buffer2)(
+                        command_buffer,
+                        &args.p_copy_image_to_buffer_info,
+                    )
+                };
+            }
+            Self::CmdBlitImage2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_blit_image2)(command_buffer, &args.p_blit_image_info)
+                };
+            }
+            Self::CmdResolveImage2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_resolve_image2)(command_buffer, &args.p_resolve_image_info)
+                };
+            }
+            Self::CmdBeginRendering(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_begin_rendering)(command_buffer, &args.p_rendering_info)
+                };
+            }
+            Self::CmdEndRendering(_) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe { (dispatch_table.cmd_end_rendering)(command_buffer) };
+            }
+            Self::CmdSetCullMode(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe { (dispatch_table.cmd_set_cull_mode)(command_buffer, args.cull_mode) };
+            }
+            Self::CmdSetFrontFace(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe { (dispatch_table.cmd_set_front_face)(command_buffer, args.front_face) };
+            }
+            Self::CmdSetPrimitiveTopology(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_primitive_topology)(
+                        command_buffer,
+                        args.primitive_topology,
+                    )
+                };
+            }
+            Self::CmdSetViewportWithCount(args) => {
+                let dispatch_table = core.fp_v1_3();
+                let rewritten_viewport_count = args.p_viewports.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_viewport_with_count)(
+                        command_buffer,
+                        rewritten_viewport_count,
+                        args.p_viewports.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetScissorWithCount(args) => {
+                let dispatch_table = core.fp_v1_3();
+                let rewritten_scissor_count = args.p_scissors.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_scissor_with_count)(
+                        command_buffer,
+                        rewritten_scissor_count,
+                        args.p_scissors.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdBindVertexBuffers2(args) => {
+                let dispatch_table = core.fp_v1_3();
+                assert_eq!(args.p_offsets.len(), args.p_buffers.len());
+                assert!(args
+                    .p_sizes
+                    .as_ref()
+                    .is_none_or(|p_sizes| p_sizes.len() == args.p_buffers.len()));
+                assert!(args
+                    .p_strides
+                    .as_ref()
+                    .is_none_or(|p_strides| p_strides.len() == args.p_buffers.len()));
+                let rewritten_binding_count = args.p_buffers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_bind_vertex_buffers2)(
+                        command_buffer,
+                        args.first_binding,
+                        rewritten_binding_count,
+                        args.p_buffers.as_ptr(),
+                        args.p_offsets.as_ptr(),
+                        args.p_sizes.as_ref().map_or(std::ptr::null(), Vec::as_ptr),
+                        args.p_strides
+                            .as_ref()
+                            .map_or(std::ptr::null(), Vec::as_ptr),
+                    )
+                };
+            }
+            Self::CmdSetDepthTestEnable(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_test_enable)(
+                        command_buffer,
+                        vk::Bool32::from(args.depth_test_enable),
+                    )
+                };
+            }
+            Self::CmdSetDepthWriteEnable(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_write_enable)(
+                        command_buffer,
+                        vk::Bool32::from(args.depth_write_enable),
+                    )
+                };
+            }
+            Self::CmdSetDepthCompareOp(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_compare_op)(command_buffer, args.depth_compare_op)
+                };
+            }
+            Self::CmdSetDepthBoundsTestEnable(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_bounds_test_enable)(
+                        command_buffer,
+                        vk::Bool32::from(args.depth_bounds_test_enable),
+                    )
+                };
+            }
+            Self::CmdSetStencilTestEnable(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_stencil_test_enable)(
+                        command_buffer,
+                        vk::Bool32::from(args.stencil_test_enable),
+                    )
+                };
+            }
+            Self::CmdSetStencilOp(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_stencil_op)(
+                        command_buffer,
+                        args.face_mask,
+                        args.fail_op,
+                        args.pass_op,
+                        args.depth_fail_op,
+                        args.compare_op,
+                    )
+                };
+            }
+            Self::CmdSetRasterizerDiscardEnable(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_rasterizer_discard_enable)(
+                        command_buffer,
+                        vk::Bool32::from(args.rasterizer_discard_enable),
+                    )
+                };
+            }
+            Self::CmdSetDepthBiasEnable(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_bias_enable)(
+                        command_buffer,
+                        vk::Bool32::from(args.depth_bias_enable),
+                    )
+                };
+            }
+            Self::CmdSetPrimitiveRestartEnable(args) => {
+                let dispatch_table = core.fp_v1_3();
+                unsafe {
+                    (dispatch_table.cmd_set_primitive_restart_enable)(
+                        command_buffer,
+                        vk::Bool32::from(args.primitive_restart_enable),
+                    )
+                };
+            }
+            Self::CmdBeginVideoCodingKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdBeginVideoCodingKHR"))?
+                    .khr_video_queue();
+                unsafe {
+                    (dispatch_table.cmd_begin_video_coding_khr)(command_buffer, &args.p_begin_info)
+                };
+            }
+            Self::CmdEndVideoCodingKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdEndVideoCodingKHR"))?
+                    .khr_video_queue();
+                unsafe {
+                    (dispatch_table.cmd_end_video_coding_khr)(
+                        command_buffer,
+                        &args.p_end_coding_info,
+                    )
+                };
+            }
+            Self::CmdControlVideoCodingKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdControlVideoCodingKHR"))?
+                    .khr_video_queue();
+                unsafe {
+                    (dispatch_table.cmd_control_video_coding_khr)(
+                        command_buffer,
+                        &args.p_coding_control_info,
+                    )
+                };
+            }
+            Self::CmdDecodeVideoKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDecodeVideoKHR"))?
+                    .khr_video_decode_queue();
+                unsafe {
+                    (dispatch_table.cmd_decode_video_khr)(command_buffer, &args.p_decode_info)
+                };
+            }
+            Self::CmdPushDescriptorSetKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdPushDescriptorSetKHR"))?
+                    .khr_push_descriptor();
+                let rewritten_descriptor_write_count =
+                    args.p_descriptor_writes.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_push_descriptor_set_khr)(
+                        command_buffer,
+                        args.pipeline_bind_point,
+                        args.layout,
+                        args.set,
+                        rewritten_descriptor_write_count,
+                        args.p_descriptor_writes.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdPushDescriptorSetWithTemplateKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdPushDescriptorSetWithTemplateKHR",
+                    ))?
+                    .khr_push_descriptor();
+                unsafe {
+                    (dispatch_table.cmd_push_descriptor_set_with_template_khr)(
+                        command_buffer,
+                        args.descriptor_update_template,
+                        args.layout,
+                        args.set,
+                        args.p_data,
+                    )
+                };
+            }
+            Self::CmdSetFragmentShadingRateKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetFragmentShadingRateKHR",
+                    ))?
+                    .khr_fragment_shading_rate();
+                unsafe {
+                    (dispatch_table.cmd_set_fragment_shading_rate_khr)(
+                        command_buffer,
+                        &args.p_fragment_size,
+                        &args.combiner_ops,
+                    )
+                };
+            }
+            Self::CmdEncodeVideoKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdEncodeVideoKHR"))?
+                    .khr_video_encode_queue();
+                unsafe {
+                    (dispatch_table.cmd_encode_video_khr)(command_buffer, &args.p_encode_info)
+                };
+            }
+            Self::CmdWriteBufferMarker2Amd(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdWriteBufferMarker2AMD"))?
+                    .khr_synchronization2();
+                unsafe {
+                    (dispatch_table.cmd_write_buffer_marker2_amd)(
+                        command_buffer,
+                        args.stage,
+                        args.dst_buffer,
+                        args.dst_offset,
+                        args.marker,
+                    )
+                };
+            }
+            Self::CmdTraceRaysIndirect2Khr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdTraceRaysIndirect2KHR"))?
+                    .khr_ray_tracing_maintenance1();
+                unsafe {
+                    (dispatch_table.cmd_trace_rays_indirect2_khr)(
+                        command_buffer,
+                        args.indirect_device_address,
+                    )
+                };
+            }
+            Self::CmdDebugMarkerBeginExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDebugMarkerBeginEXT"))?
+                    .ext_debug_marker();
+                unsafe {
+                    (dispatch_table.cmd_debug_marker_begin_ext)(command_buffer, &args.p_marker_info)
+                };
+            }
+            Self::CmdDebugMarkerEndExt(_) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDebugMarkerEndEXT"))?
+                    .ext_debug_marker();
+                unsafe { (dispatch_table.cmd_debug_marker_end_ext)(command_buffer) };
+            }
+            Self::CmdDebugMarkerInsertExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDebugMarkerInsertEXT"))?
+                    .ext_debug_marker();
+                unsafe {
+                    (dispatch_table.cmd_debug_marker_insert_ext)(
+                        command_buffer,
+                        &args.p_marker_info,
+                    )
+                };
+            }
+            Self::CmdBindTransformFeedbackBuffersExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBindTransformFeedbackBuffersEXT",
+                    ))?
+                    .ext_transform_feedback();
+                assert_eq!(args.p_offsets.len(), args.p_buffers.len());
+                assert!(args
+                    .p_sizes
+                    .as_ref()
+                    .is_none_or(|p_sizes| p_sizes.len() == args.p_buffers.len()));
+                let rewritten_binding_count = args.p_buffers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_bind_transform_feedback_buffers_ext)(
+                        command_buffer,
+                        args.first_binding,
+                        rewritten_binding_count,
+                        args.p_buffers.as_ptr(),
+                        args.p_offsets.as_ptr(),
+                        args.p_sizes.as_ref().map_or(std::ptr::null(), Vec::as_ptr),
+                    )
+                };
+            }
+            Self::CmdBeginTransformFeedbackExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBeginTransformFeedbackEXT",
+                    ))?
+                    .ext_transform_feedback();
+                assert!(args
+                    .p_counter_buffer_offsets
+                    .as_ref()
+                    .is_none_or(|p_counter_buffer_offsets| p_counter_buffer_offsets.len()
+                        == args.p_counter_buffers.len()));
+                let rewritten_counter_buffer_count =
+                    args.p_counter_buffers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_begin_transform_feedback_ext)(
+                        command_buffer,
+                        args.first_counter_buffer,
+                        rewritten_counter_buffer_count,
+                        args.p_counter_buffers.as_ptr(),
+                        args.p_counter_buffer_offsets
+                            .as_ref()
+                            .map_or(std::ptr::null(), Vec::as_ptr),
+                    )
+                };
+            }
+            Self::CmdEndTransformFeedbackExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdEndTransformFeedbackEXT",
+                    ))?
+                    .ext_transform_feedback();
+                assert!(args
+                    .p_counter_buffer_offsets
+                    .as_ref()
+                    .is_none_or(|p_counter_buffer_offsets| p_counter_buffer_offsets.len()
+                        == args.p_counter_buffers.len()));
+                let rewritten_counter_buffer_count =
+                    args.p_counter_buffers.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_end_transform_feedback_ext)(
+                        command_buffer,
+                        args.first_counter_buffer,
+                        rewritten_counter_buffer_count,
+                        args.p_counter_buffers.as_ptr(),
+                        args.p_counter_buffer_offsets
+                            .as_ref()
+                            .map_or(std::ptr::null(), Vec::as_ptr),
+                    )
+                };
+            }
+            Self::CmdBeginQueryIndexedExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdBeginQueryIndexedEXT"))?
+                    .ext_transform_feedback();
+                unsafe {
+                    (dispatch_table.cmd_begin_query_indexed_ext)(
+                        command_buffer,
+                        args.query_pool,
+                        args.query,
+                        args.flags,
+                        args.index,
+                    )
+                };
+            }
+            Self::CmdEndQueryIndexedExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdEndQueryIndexedEXT"))?
+                    .ext_transform_feedback();
+                unsafe {
+                    (dispatch_table.cmd_end_query_indexed_ext)(
+                        command_buffer,
+                        args.query_pool,
+                        args.query,
+                        args.index,
+                    )
+                };
+            }
+            Self::CmdDrawIndirectByteCountExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdDrawIndirectByteCountEXT",
+                    ))?
+                    .ext_transform_feedback();
+                unsafe {
+                    (dispatch_table.cmd_draw_indirect_byte_count_ext)(
+                        command_buffer,
+                        args.instance_count,
+                        args.first_instance,
+                        args.counter_buffer,
+                        args.counter_buffer_offset,
+                        args.counter_offset,
+                        args.vertex_stride,
+                    )
+                };
+            }
+            Self::CmdCuLaunchKernelNvx(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdCuLaunchKernelNVX"))?
+                    .nvx_binary_import();
+                unsafe {
+                    (dispatch_table.cmd_cu_launch_kernel_nvx)(command_buffer, &args.p_launch_info)
+                };
+            }
+            Self::CmdBeginConditionalRenderingExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBeginConditionalRenderingEXT",
+                    ))?
+                    .ext_conditional_rendering();
+                unsafe {
+                    (dispatch_table.cmd_begin_conditional_rendering_ext)(
+                        command_buffer,
+                        &args.p_conditional_rendering_begin,
+                    )
+                };
+            }
+            Self::CmdEndConditionalRenderingExt(_) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdEndConditionalRenderingEXT",
+                    ))?
+                    .ext_conditional_rendering();
+                unsafe { (dispatch_table.cmd_end_conditional_rendering_ext)(command_buffer) };
+            }
+            Self::CmdSetViewportWScalingNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetViewportWScalingNV"))?
+                    .nv_clip_space_w_scaling();
+                let rewritten_viewport_count = args.p_viewport_w_scalings.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_viewport_w_scaling_nv)(
+                        command_buffer,
+                        args.first_viewport,
+                        rewritten_viewport_count,
+                        args.p_viewport_w_scalings.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetDiscardRectangleExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetDiscardRectangleEXT"))?
+                    .ext_discard_rectangles();
+                let rewritten_discard_rectangle_count =
+                    args.p_discard_rectangles.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_discard_rectangle_ext)(
+                        command_buffer,
+                        args.first_discard_rectangle,
+                        rewritten_discard_rectangle_count,
+                        args.p_discard_rectangles.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdBeginDebugUtilsLabelExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBeginDebugUtilsLabelEXT",
+                    ))?
+                    .ext_debug_utils();
+                unsafe {
+                    (dispatch_table.cmd_begin_debug_utils_label_ext)(
+                        command_buffer,
+                        &args.p_label_info,
+                    )
+                };
+            }
+            Self::CmdEndDebugUtilsLabelExt(_) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdEndDebugUtilsLabelEXT"))?
+                    .ext_debug_utils();
+                unsafe { (dispatch_table.cmd_end_debug_utils_label_ext)(command_buffer) };
+            }
+            Self::CmdInsertDebugUtilsLabelExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdInsertDebugUtilsLabelEXT",
+                    ))?
+                    .ext_debug_utils();
+                unsafe {
+                    (dispatch_table.cmd_insert_debug_utils_label_ext)(
+                        command_buffer,
+                        &args.p_label_info,
+                    )
+                };
+            }
+            Self::CmdSetSampleLocationsExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetSampleLocationsEXT"))?
+                    .ext_sample_locations();
+                unsafe {
+                    (dispatch_table.cmd_set_sample_locations_ext)(
+                        command_buffer,
+                        &args.p_sample_locations_info,
+                    )
+                };
+            }
+            Self::CmdBindShadingRateImageNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdBindShadingRateImageNV"))?
+                    .nv_shading_rate_image();
+                unsafe {
+                    (dispatch_table.cmd_bind_shading_rate_image_nv)(
+                        command_buffer,
+                        args.image_view,
+                        args.image_layout,
+                    )
+                };
+            }
+            Self::CmdSetViewportShadingRatePaletteNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetViewportShadingRatePaletteNV",
+                    ))?
+                    .nv_shading_rate_image();
+                let rewritten_viewport_count =
+                    args.p_shading_rate_palettes.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_viewport_shading_rate_palette_nv)(
+                        command_buffer,
+                        args.first_viewport,
+                        rewritten_viewport_count,
+                        args.p_shading_rate_palettes.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetCoarseSampleOrderNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetCoarseSampleOrderNV"))?
+                    .nv_shading_rate_image();
+                let rewritten_custom_sample_order_count =
+                    args.p_custom_sample_orders.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_coarse_sample_order_nv)(
+                        command_buffer,
+                        args.sample_order_type,
+                        rewritten_custom_sample_order_count,
+                        args.p_custom_sample_orders.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdBuildAccelerationStructureNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBuildAccelerationStructureNV",
+                    ))?
+                    .nv_ray_tracing();
+                unsafe {
+                    (dispatch_table.cmd_build_acceleration_structure_nv)(
+                        command_buffer,
+                        &args.p_info,
+                        args.instance_data,
+                        args.instance_offset,
+                        vk::Bool32::from(args.update),
+                        args.dst,
+                        args.src,
+                        args.scratch,
+                        args.scratch_offset,
+                    )
+                };
+            }
+            Self::CmdCopyAccelerationStructureNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdCopyAccelerationStructureNV",
+                    ))?
+                    .nv_ray_tracing();
+                unsafe {
+                    (dispatch_table.cmd_copy_acceleration_structure_nv)(
+                        command_buffer,
+                        args.dst,
+                        args.src,
+                        args.mode,
+                    )
+                };
+            }
+            Self::CmdTraceRaysNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdTraceRaysNV"))?
+                    .nv_ray_tracing();
+                unsafe {
+                    (dispatch_table.cmd_trace_rays_nv)(
+                        command_buffer,
+                        args.raygen_shader_binding_table_buffer,
+                        args.raygen_shader_binding_offset,
+                        args.miss_shader_binding_table_buffer,
+                        args.miss_shader_binding_offset,
+                        args.miss_shader_binding_stride,
+                        args.hit_shader_binding_table_buffer,
+                        args.hit_shader_binding_offset,
+                        args.hit_shader_binding_stride,
+                        args.callable_shader_binding_table_buffer,
+                        args.callable_shader_binding_offset,
+                        args.callable_shader_binding_stride,
+                        args.width,
+                        args.height,
+                        args.depth,
+                    )
+                };
+            }
+            Self::CmdWriteAccelerationStructuresPropertiesNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdWriteAccelerationStructuresPropertiesNV",
+                    ))?
+                    .nv_ray_tracing();
+                let rewritten_acceleration_structure_count =
+                    args.p_acceleration_structures.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_write_acceleration_structures_properties_nv)(
+                        command_buffer,
+                        rewritten_acceleration_structure_count,
+                        args.p_acceleration_structures.as_ptr(),
+                        args.query_type,
+                        args.query_pool,
+                        args.first_query,
+                    )
+                };
+            }
+            Self::CmdWriteBufferMarkerAmd(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdWriteBufferMarkerAMD"))?
+                    .amd_buffer_marker();
+                unsafe {
+                    (dispatch_table.cmd_write_buffer_marker_amd)(
+                        command_buffer,
+                        args.pipeline_stage,
+                        args.dst_buffer,
+                        args.dst_offset,
+                        args.marker,
+                    )
+                };
+            }
+            Self::CmdDrawMeshTasksNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDrawMeshTasksNV"))?
+                    .nv_mesh_shader();
+                unsafe {
+                    (dispatch_table.cmd_draw_mesh_tasks_nv)(
+                        command_buffer,
+                        args.task_count,
+                        args.first_task,
+                    )
+                };
+            }
+            Self::CmdDrawMeshTasksIndirectNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdDrawMeshTasksIndirectNV",
+                    ))?
+                    .nv_mesh_shader();
+                unsafe {
+                    (dispatch_table.cmd_draw_mesh_tasks_indirect_nv)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.draw_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdDrawMeshTasksIndirectCountNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdDrawMeshTasksIndirectCountNV",
+                    ))?
+                    .nv_mesh_shader();
+                unsafe {
+                    (dispatch_table.cmd_draw_mesh_tasks_indirect_count_nv)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.count_buffer,
+                        args.count_buffer_offset,
+                        args.max_draw_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdSetExclusiveScissorNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetExclusiveScissorNV"))?
+                    .nv_scissor_exclusive();
+                let rewritten_exclusive_scissor_count =
+                    args.p_exclusive_scissors.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_exclusive_scissor_nv)(
+                        command_buffer,
+                        args.first_exclusive_scissor,
+                        rewritten_exclusive_scissor_count,
+                        args.p_exclusive_scissors.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetCheckpointNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetCheckpointNV"))?
+                    .nv_device_diagnostic_checkpoints();
+                unsafe {
+                    (dispatch_table.cmd_set_checkpoint_nv)(command_buffer, args.p_checkpoint_marker)
+                };
+            }
+            Self::CmdSetPerformanceMarkerIntel(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetPerformanceMarkerINTEL",
+                    ))?
+                    .intel_performance_query();
+                let res = unsafe {
+                    (dispatch_table.cmd_set_performance_marker_intel)(
+                        command_buffer,
+                        &args.p_marker_info,
+                    )
+                };
+                res.result().map_err(ReplayError::CommandFailed)?;
+            }
+            Self::CmdSetPerformanceStreamMarkerIntel(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetPerformanceStreamMarkerINTEL",
+                    ))?
+                    .intel_performance_query();
+                let res = unsafe {
+                    (dispatch_table.cmd_set_performance_stream_marker_intel)(
+                        command_buffer,
+                        &args.p_marker_info,
+                    )
+                };
+                res.result().map_err(ReplayError::CommandFailed)?;
+            }
+            Self::CmdSetPerformanceOverrideIntel(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetPerformanceOverrideINTEL",
+                    ))?
+                    .intel_performance_query();
+                let res = unsafe {
+                    (dispatch_table.cmd_set_performance_override_intel)(
+                        command_buffer,
+                        &args.p_override_info,
+                    )
+                };
+                res.result().map_err(ReplayError::CommandFailed)?;
+            }
+            Self::CmdSetLineStippleExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetLineStippleEXT"))?
+                    .ext_line_rasterization();
+                unsafe {
+                    (dispatch_table.cmd_set_line_stipple_ext)(
+                        command_buffer,
+                        args.line_stipple_factor,
+                        args.line_stipple_pattern,
+                    )
+                };
+            }
+            Self::CmdPreprocessGeneratedCommandsNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdPreprocessGeneratedCommandsNV",
+                    ))?
+                    .nv_device_generated_commands();
+                unsafe {
+                    (dispatch_table.cmd_preprocess_generated_commands_nv)(
+                        command_buffer,
+                        &args.p_generated_commands_info,
+                    )
+                };
+            }
+            Self::CmdExecuteGeneratedCommandsNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdExecuteGeneratedCommandsNV",
+                    ))?
+                    .nv_device_generated_commands();
+                unsafe {
+                    (dispatch_table.cmd_execute_generated_commands_nv)(
+                        command_buffer,
+                        vk::Bool32::from(args.is_preprocessed),
+                        &args.p_generated_commands_info,
+                    )
+                };
+            }
+            Self::CmdBindPipelineShaderGroupNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBindPipelineShaderGroupNV",
+                    ))?
+                    .nv_device_generated_commands();
+                unsafe {
+                    (dispatch_table.cmd_bind_pipeline_shader_group_nv)(
+                        command_buffer,
+                        args.pipeline_bind_point,
+                        args.pipeline,
+                        args.group_index,
+                    )
+                };
+            }
+            Self::CmdBindDescriptorBuffersExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBindDescriptorBuffersEXT",
+                    ))?
+                    .ext_descriptor_buffer();
+                let rewritten_buffer_count = args.p_binding_infos.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_bind_descriptor_buffers_ext)(
+                        command_buffer,
+                        rewritten_buffer_count,
+                        args.p_binding_infos.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetDescriptorBufferOffsetsExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetDescriptorBufferOffsetsEXT",
+                    ))?
+                    .ext_descriptor_buffer();
+                assert_eq!(args.p_offsets.len(), args.p_buffer_indices.len());
+                let rewritten_set_count = args.p_buffer_indices.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_descriptor_buffer_offsets_ext)(
+                        command_buffer,
+                        args.pipeline_bind_point,
+                        args.layout,
+                        args.first_set,
+                        rewritten_set_count,
+                        args.p_buffer_indices.as_ptr(),
+                        args.p_offsets.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdBindDescriptorBufferEmbeddedSamplersExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+                    ))?
+                    .ext_descriptor_buffer();
+                unsafe {
+                    (dispatch_table.cmd_bind_descriptor_buffer_embedded_samplers_ext)(
+                        command_buffer,
+                        args.pipeline_bind_point,
+                        args.layout,
+                        args.set,
+                    )
+                };
+            }
+            Self::CmdSetFragmentShadingRateEnumNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetFragmentShadingRateEnumNV",
+                    ))?
+                    .nv_fragment_shading_rate_enums();
+                unsafe {
+                    (dispatch_table.cmd_set_fragment_shading_rate_enum_nv)(
+                        command_buffer,
+                        args.shading_rate,
+                        &args.combiner_ops,
+                    )
+                };
+            }
+            Self::CmdSetVertexInputExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetVertexInputEXT"))?
+                    .ext_vertex_input_dynamic_state();
+                let rewritten_vertex_binding_description_count =
+                    args.p_vertex_binding_descriptions.len().try_into().unwrap();
+                let rewritten_vertex_attribute_description_count = args
+                    .p_vertex_attribute_descriptions
+                    .len()
+                    .try_into()
+                    .unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_vertex_input_ext)(
+                        command_buffer,
+                        rewritten_vertex_binding_description_count,
+                        args.p_vertex_binding_descriptions.as_ptr(),
+                        rewritten_vertex_attribute_description_count,
+                        args.p_vertex_attribute_descriptions.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSubpassShadingHuawei(_) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSubpassShadingHUAWEI"))?
+                    .huawei_subpass_shading();
+                unsafe { (dispatch_table.cmd_subpass_shading_huawei)(command_buffer) };
+            }
+            Self::CmdBindInvocationMaskHuawei(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdBindInvocationMaskHUAWEI",
+                    ))?
+                    .huawei_invocation_mask();
+                unsafe {
+                    (dispatch_table.cmd_bind_invocation_mask_huawei)(
+                        command_buffer,
+                        args.image_view,
+                        args.image_layout,
+                    )
+                };
+            }
+            Self::CmdSetPatchControlPointsExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetPatchControlPointsEXT",
+                    ))?
+                    .ext_extended_dynamic_state2();
+                unsafe {
+                    (dispatch_table.cmd_set_patch_control_points_ext)(
+                        command_buffer,
+                        args.patch_control_points,
+                    )
+                };
+            }
+            Self::CmdSetLogicOpExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetLogicOpEXT"))?
+                    .ext_extended_dynamic_state2();
+                unsafe { (dispatch_table.cmd_set_logic_op_ext)(command_buffer, args.logic_op) };
+            }
+            Self::CmdSetColorWriteEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetColorWriteEnableEXT"))?
+                    .ext_color_write_enable();
+                let rewritten_attachment_count =
+                    args.p_color_write_enables.len().try_into().unwrap();
+                let rewritten_p_color_write_enables: Vec<vk::Bool32> = args
+                    .p_color_write_enables
+                    .iter()
+                    .copied()
+                    .map(vk::Bool32::from)
+                    .collect();
+                unsafe {
+                    (dispatch_table.cmd_set_color_write_enable_ext)(
+                        command_buffer,
+                        rewritten_attachment_count,
+                        rewritten_p_color_write_enables.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdDrawMultiExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDrawMultiEXT"))?
+                    .ext_multi_draw();
+                let rewritten_draw_count = args.p_vertex_info.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_draw_multi_ext)(
+                        command_buffer,
+                        rewritten_draw_count,
+                        args.p_vertex_info.as_ptr(),
+                        args.instance_count,
+                        args.first_instance,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdDrawMultiIndexedExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDrawMultiIndexedEXT"))?
+                    .ext_multi_draw();
+                let rewritten_draw_count = args.p_index_info.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_draw_multi_indexed_ext)(
+                        command_buffer,
+                        rewritten_draw_count,
+                        args.p_index_info.as_ptr(),
+                        args.instance_count,
+                        args.first_instance,
+                        args.stride,
+                        args.p_vertex_offset
+                            .as_ref()
+                            .map_or(std::ptr::null(), std::ptr::from_ref),
+                    )
+                };
+            }
+            Self::CmdBuildMicromapsExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdBuildMicromapsEXT"))?
+                    .ext_opacity_micromap();
+                let rewritten_info_count = args.p_infos.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_build_micromaps_ext)(
+                        command_buffer,
+                        rewritten_info_count,
+                        args.p_infos.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdCopyMicromapExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdCopyMicromapEXT"))?
+                    .ext_opacity_micromap();
+                unsafe { (dispatch_table.cmd_copy_micromap_ext)(command_buffer, &args.p_info) };
+            }
+            Self::CmdCopyMicromapToMemoryExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdCopyMicromapToMemoryEXT",
+                    ))?
+                    .ext_opacity_micromap();
+                unsafe {
+                    (dispatch_table.cmd_copy_micromap_to_memory_ext)(command_buffer, &args.p_info)
+                };
+            }
+            Self::CmdCopyMemoryToMicromapExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdCopyMemoryToMicromapEXT",
+                    ))?
+                    .ext_opacity_micromap();
+                unsafe {
+                    (dispatch_table.cmd_copy_memory_to_micromap_ext)(command_buffer, &args.p_info)
+                };
+            }
+            Self::CmdWriteMicromapsPropertiesExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdWriteMicromapsPropertiesEXT",
+                    ))?
+                    .ext_opacity_micromap();
+                let rewritten_micromap_count = args.p_micromaps.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_write_micromaps_properties_ext)(
+                        command_buffer,
+                        rewritten_micromap_count,
+                        args.p_micromaps.as_ptr(),
+                        args.query_type,
+                        args.query_pool,
+                        args.first_query,
+                    )
+                };
+            }
+            Self::CmdCopyMemoryIndirectNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdCopyMemoryIndirectNV"))?
+                    .nv_copy_memory_indirect();
+                unsafe {
+                    (dispatch_table.cmd_copy_memory_indirect_nv)(
+                        command_buffer,
+                        args.copy_buffer_address,
+                        args.copy_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdCopyMemoryToImageIndirectNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdCopyMemoryToImageIndirectNV",
+                    ))?
+                    .nv_copy_memory_indirect();
+                let rewritten_copy_count = args.p_image_subresources.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_copy_memory_to_image_indirect_nv)(
+                        command_buffer,
+                        args.copy_buffer_address,
+                        rewritten_copy_count,
+                        args.stride,
+                        args.dst_image,
+                        args.dst_image_layout,
+                        args.p_image_subresources.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdDecompressMemoryNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDecompressMemoryNV"))?
+                    .nv_memory_decompression();
+                let rewritten_decompress_region_count =
+                    args.p_decompress_memory_regions.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_decompress_memory_nv)(
+                        command_buffer,
+                        rewritten_decompress_region_count,
+                        args.p_decompress_memory_regions.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdDecompressMemoryIndirectCountNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdDecompressMemoryIndirectCountNV",
+                    ))?
+                    .nv_memory_decompression();
+                unsafe {
+                    (dispatch_table.cmd_decompress_memory_indirect_count_nv)(
+                        command_buffer,
+                        args.indirect_commands_address,
+                        args.indirect_commands_count_address,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdSetTessellationDomainOriginExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetTessellationDomainOriginEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_tessellation_domain_origin_ext)(
+                        command_buffer,
+                        args.domain_origin,
+                    )
+                };
+            }
+            Self::CmdSetDepthClampEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetDepthClampEnableEXT"))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_clamp_enable_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.depth_clamp_enable),
+                    )
+                };
+            }
+            Self::CmdSetPolygonModeExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetPolygonModeEXT"))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_polygon_mode_ext)(command_buffer, args.polygon_mode)
+                };
+            }
+            Self::CmdSetRasterizationSamplesExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetRasterizationSamplesEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_rasterization_samples_ext)(
+                        command_buffer,
+                        args.rasterization_samples,
+                    )
+                };
+            }
+            Self::CmdSetSampleMaskExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetSampleMaskEXT"))?
+                    .ext_extended_dynamic_state3();
+                assert_eq!(
+                    args.p_sample_mask.len(),
+                    usize::try_from(args.samples.as_raw().div_ceil(32)).unwrap()
+                );
+                unsafe {
+                    (dispatch_table.cmd_set_sample_mask_ext)(
+                        command_buffer,
+                        args.samples,
+                        args.p_sample_mask.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetAlphaToCoverageEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetAlphaToCoverageEnableEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_alpha_to_coverage_enable_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.alpha_to_coverage_enable),
+                    )
+                };
+            }
+            Self::CmdSetAlphaToOneEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetAlphaToOneEnableEXT"))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_alpha_to_one_enable_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.alpha_to_one_enable),
+                    )
+                };
+            }
+            Self::CmdSetLogicOpEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetLogicOpEnableEXT"))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_logic_op_enable_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.logic_op_enable),
+                    )
+                };
+            }
+            Self::CmdSetColorBlendEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetColorBlendEnableEXT"))?
+                    .ext_extended_dynamic_state3();
+                let rewritten_attachment_count =
+                    args.p_color_blend_enables.len().try_into().unwrap();
+                let rewritten_p_color_blend_enables: Vec<vk::Bool32> = args
+                    .p_color_blend_enables
+                    .iter()
+                    .copied()
+                    .map(vk::Bool32::from)
+                    .collect();
+                unsafe {
+                    (dispatch_table.cmd_set_color_blend_enable_ext)(
+                        command_buffer,
+                        args.first_attachment,
+                        rewritten_attachment_count,
+                        rewritten_p_color_blend_enables.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetColorBlendEquationExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetColorBlendEquationEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                let rewritten_attachment_count =
+                    args.p_color_blend_equations.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_color_blend_equation_ext)(
+                        command_buffer,
+                        args.first_attachment,
+                        rewritten_attachment_count,
+                        args.p_color_blend_equations.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetColorWriteMaskExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetColorWriteMaskEXT"))?
+                    .ext_extended_dynamic_state3();
+                let rewritten_attachment_count = args.p_color_write_masks.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_color_write_mask_ext)(
+                        command_buffer,
+                        args.first_attachment,
+                        rewritten_attachment_count,
+                        args.p_color_write_masks.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetRasterizationStreamExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetRasterizationStreamEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_rasterization_stream_ext)(
+                        command_buffer,
+                        args.rasterization_stream,
+                    )
+                };
+            }
+            Self::CmdSetConservativeRasterizationModeExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetConservativeRasterizationModeEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_conservative_rasterization_mode_ext)(
+                        command_buffer,
+                        args.conservative_rasterization_mode,
+                    )
+                };
+            }
+            Self::CmdSetExtraPrimitiveOverestimationSizeExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_extra_primitive_overestimation_size_ext)(
+                        command_buffer,
+                        args.extra_primitive_overestimation_size,
+                    )
+                };
+            }
+            Self::CmdSetDepthClipEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetDepthClipEnableEXT"))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_clip_enable_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.depth_clip_enable),
+                    )
+                };
+            }
+            Self::CmdSetSampleLocationsEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetSampleLocationsEnableEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_sample_locations_enable_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.sample_locations_enable),
+                    )
+                };
+            }
+            Self::CmdSetColorBlendAdvancedExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetColorBlendAdvancedEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                let rewritten_attachment_count =
+                    args.p_color_blend_advanced.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_color_blend_advanced_ext)(
+                        command_buffer,
+                        args.first_attachment,
+                        rewritten_attachment_count,
+                        args.p_color_blend_advanced.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetProvokingVertexModeExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetProvokingVertexModeEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_provoking_vertex_mode_ext)(
+                        command_buffer,
+                        args.provoking_vertex_mode,
+                    )
+                };
+            }
+            Self::CmdSetLineRasterizationModeExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetLineRasterizationModeEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_line_rasterization_mode_ext)(
+                        command_buffer,
+                        args.line_rasterization_mode,
+                    )
+                };
+            }
+            Self::CmdSetLineStippleEnableExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetLineStippleEnableEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_line_stipple_enable_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.stippled_line_enable),
+                    )
+                };
+            }
+            Self::CmdSetDepthClipNegativeOneToOneExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetDepthClipNegativeOneToOneEXT",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_depth_clip_negative_one_to_one_ext)(
+                        command_buffer,
+                        vk::Bool32::from(args.negative_one_to_one),
+                    )
+                };
+            }
+            Self::CmdSetViewportWScalingEnableNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetViewportWScalingEnableNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_viewport_w_scaling_enable_nv)(
+                        command_buffer,
+                        vk::Bool32::from(args.viewport_w_scaling_enable),
+                    )
+                };
+            }
+            Self::CmdSetViewportSwizzleNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdSetViewportSwizzleNV"))?
+                    .ext_extended_dynamic_state3();
+                let rewritten_viewport_count = args.p_viewport_swizzles.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_viewport_swizzle_nv)(
+                        command_buffer,
+                        args.first_viewport,
+                        rewritten_viewport_count,
+                        args.p_viewport_swizzles.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetCoverageToColorEnableNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetCoverageToColorEnableNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_coverage_to_color_enable_nv)(
+                        command_buffer,
+                        vk::Bool32::from(args.coverage_to_color_enable),
+                    )
+                };
+            }
+            Self::CmdSetCoverageToColorLocationNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetCoverageToColorLocationNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_coverage_to_color_location_nv)(
+                        command_buffer,
+                        args.coverage_to_color_location,
+                    )
+                };
+            }
+            Self::CmdSetCoverageModulationModeNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetCoverageModulationModeNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_coverage_modulation_mode_nv)(
+                        command_buffer,
+                        args.coverage_modulation_mode,
+                    )
+                };
+            }
+            Self::CmdSetCoverageModulationTableEnableNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetCoverageModulationTableEnableNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_coverage_modulation_table_enable_nv)(
+                        command_buffer,
+                        vk::Bool32::from(args.coverage_modulation_table_enable),
+                    )
+                };
+            }
+            Self::CmdSetCoverageModulationTableNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetCoverageModulationTableNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                let rewritten_coverage_modulation_table_count =
+                    args.p_coverage_modulation_table.len().try_into().unwrap();
+                unsafe {
+                    (dispatch_table.cmd_set_coverage_modulation_table_nv)(
+                        command_buffer,
+                        rewritten_coverage_modulation_table_count,
+                        args.p_coverage_modulation_table.as_ptr(),
+                    )
+                };
+            }
+            Self::CmdSetShadingRateImageEnableNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetShadingRateImageEnableNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_shading_rate_image_enable_nv)(
+                        command_buffer,
+                        vk::Bool32::from(args.shading_rate_image_enable),
+                    )
+                };
+            }
+            Self::CmdSetRepresentativeFragmentTestEnableNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetRepresentativeFragmentTestEnableNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_representative_fragment_test_enable_nv)(
+                        command_buffer,
+                        vk::Bool32::from(args.representative_fragment_test_enable),
+                    )
+                };
+            }
+            Self::CmdSetCoverageReductionModeNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetCoverageReductionModeNV",
+                    ))?
+                    .ext_extended_dynamic_state3();
+                unsafe {
+                    (dispatch_table.cmd_set_coverage_reduction_mode_nv)(
+                        command_buffer,
+                        args.coverage_reduction_mode,
+                    )
+                };
+            }
+            Self::CmdOpticalFlowExecuteNv(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdOpticalFlowExecuteNV"))?
+                    .nv_optical_flow();
+                unsafe {
+                    (dispatch_table.cmd_optical_flow_execute_nv)(
+                        command_buffer,
+                        args.session,
+                        &args.p_execute_info,
+                    )
+                };
+            }
+            Self::CmdCopyAccelerationStructureKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdCopyAccelerationStructureKHR",
+                    ))?
+                    .khr_acceleration_structure();
+                unsafe {
+                    (dispatch_table.cmd_copy_acceleration_structure_khr)(
+                        command_buffer,
+                        &args.p_info,
+                    )
+                };
+            }
+            Self::CmdCopyAccelerationStructureToMemoryKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdCopyAccelerationStructureToMemoryKHR",
+                    ))?
+                    .khr_acceleration_structure();
+                unsafe {
+                    (dispatch_table.cmd_copy_acceleration_structure_to_memory_khr)(
+                        command_buffer,
+                        &args.p_info,
+                    )
+                };
+            }
+            Self::CmdCopyMemoryToAccelerationStructureKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdCopyMemoryToAccelerationStructureKHR",
+                    ))?
+                    .khr_acceleration_structure();
+                unsafe {
+                    (dispatch_table.cmd_copy_memory_to_acceleration_structure_khr)(
+                        command_buffer,
+                        &args.p_info,
+                    )
+                };
+            }
+            Self::CmdTraceRaysKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdTraceRaysKHR"))?
+                    .khr_ray_tracing_pipeline();
+                unsafe {
+                    (dispatch_table.cmd_trace_rays_khr)(
+                        command_buffer,
+                        &args.p_raygen_shader_binding_table,
+                        &args.p_miss_shader_binding_table,
+                        &args.p_hit_shader_binding_table,
+                        &args.p_callable_shader_binding_table,
+                        args.width,
+                        args.height,
+                        args.depth,
+                    )
+                };
+            }
+            Self::CmdTraceRaysIndirectKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdTraceRaysIndirectKHR"))?
+                    .khr_ray_tracing_pipeline();
+                unsafe {
+                    (dispatch_table.cmd_trace_rays_indirect_khr)(
+                        command_buffer,
+                        &args.p_raygen_shader_binding_table,
+                        &args.p_miss_shader_binding_table,
+                        &args.p_hit_shader_binding_table,
+                        &args.p_callable_shader_binding_table,
+                        args.indirect_device_address,
+                    )
+                };
+            }
+            Self::CmdSetRayTracingPipelineStackSizeKhr(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdSetRayTracingPipelineStackSizeKHR",
+                    ))?
+                    .khr_ray_tracing_pipeline();
+                unsafe {
+                    (dispatch_table.cmd_set_ray_tracing_pipeline_stack_size_khr)(
+                        command_buffer,
+                        args.pipeline_stack_size,
+                    )
+                };
+            }
+            Self::CmdDrawMeshTasksExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand("vkCmdDrawMeshTasksEXT"))?
+                    .ext_mesh_shader();
+                unsafe {
+                    (dispatch_table.cmd_draw_mesh_tasks_ext)(
+                        command_buffer,
+                        args.group_countx,
+                        args.group_county,
+                        args.group_countz,
+                    )
+                };
+            }
+            Self::CmdDrawMeshTasksIndirectExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdDrawMeshTasksIndirectEXT",
+                    ))?
+                    .ext_mesh_shader();
+                unsafe {
+                    (dispatch_table.cmd_draw_mesh_tasks_indirect_ext)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.draw_count,
+                        args.stride,
+                    )
+                };
+            }
+            Self::CmdDrawMeshTasksIndirectCountExt(args) => {
+                let dispatch_table = extensions
+                    .ok_or(ReplayError::ExtensionCommand(
+                        "vkCmdDrawMeshTasksIndirectCountEXT",
+                    ))?
+                    .ext_mesh_shader();
+                unsafe {
+                    (dispatch_table.cmd_draw_mesh_tasks_indirect_count_ext)(
+                        command_buffer,
+                        args.buffer,
+                        args.offset,
+                        args.count_buffer,
+                        args.count_buffer_offset,
+                        args.max_draw_count,
+                        args.stride,
+                    )
+                };
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A catch-all observer called around the intercepted commands.
+//!
+//! The observer is provided by [`Layer::command_observer`]. The layer framework then intercepts
+//! all the commands returned by [`CommandObserver::observed_commands`] even if the layer
+//! implementation doesn't hook them, and calls [`CommandObserver::on_enter`] and
+//! [`CommandObserver::on_exit`] around the whole command, including the hooks of the layer
+//! implementation and the call to the next layer.
+
+use ash::vk;
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    global_simple_intercept::HookedCommands, stats::StatsCollector, Global, Layer,
+    LayerVulkanCommand,
+};
+
+/// The dispatchable handle that a command is called on, i.e. the first parameter of the command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DispatchableHandle {
+    /// A `VkInstance`.
+    Instance(vk::Instance),
+    /// A `VkPhysicalDevice`.
+    PhysicalDevice(vk::PhysicalDevice),
+    /// A `VkDevice`.
+    Device(vk::Device),
+    /// A `VkQueue`.
+    Queue(vk::Queue),
+    /// A `VkCommandBuffer`.
+    CommandBuffer(vk::CommandBuffer),
+}
+
+macro_rules! impl_from_handle {
+    ($($variant:ident),*) => {
+        $(
+            impl From<vk::$variant> for DispatchableHandle {
+                fn from(handle: vk::$variant) -> Self {
+                    Self::$variant(handle)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_handle!(Instance, PhysicalDevice, Device, Queue, CommandBuffer);
+
+/// The set of commands that a [`CommandObserver`] is called for.
+#[derive(Clone, Debug, Default)]
+pub enum ObservedCommands {
+    /// Every command that goes through the layer framework.
+    #[default]
+    All,
+    /// Only the listed commands.
+    Only(Vec<LayerVulkanCommand>),
+}
+
+/// An observer called around every observed command, without one method per command.
+///
+/// Useful for the cross-cutting concerns like logging, profiling and statistics, where the
+/// arguments of the commands are not interesting.
+///
+/// The observer is only called for the commands dispatched through a `VkInstance` or a `VkDevice`
+/// that the layer framework intercepts through the generated command tables. The global commands,
+/// e.g. `vkCreateInstance`, and the commands that the layer framework implements itself, e.g.
+/// `vkCreateDevice`, `vkDestroyDevice` and `vkGetDeviceProcAddr`, are not observed.
+pub trait CommandObserver: Send + Sync {
+    /// The commands that this observer is called for.
+    ///
+    /// This function is called once when the [`Global`] is initialized. By default it returns
+    /// [`ObservedCommands::All`].
+    fn observed_commands(&self) -> ObservedCommands {
+        ObservedCommands::All
+    }
+
+    /// Called when `command` is called on `handle`, before any hook of the layer implementation.
+    fn on_enter(&self, command: LayerVulkanCommand, handle: DispatchableHandle);
+
+    /// Called right before `command` returns to the caller.
+    ///
+    /// `result` is the [`vk::Result`] returned to the caller, or [`None`] if `command` doesn't
+    /// return a `VkResult`. `elapsed` is the time spent since the matching
+    /// [`CommandObserver::on_enter`] call returned.
+    fn on_exit(&self, command: LayerVulkanCommand, result: Option<vk::Result>, elapsed: Duration);
+}
+
+/// The value returned by an intercepted command, which is reported to
+/// [`CommandObserver::on_exit`].
+pub(crate) trait CommandResult {
+    fn vk_result(&self) -> Option<vk::Result>;
+}
+
+impl CommandResult for () {
+    fn vk_result(&self) -> Option<vk::Result> {
+        None
+    }
+}
+
+impl CommandResult for vk::Result {
+    fn vk_result(&self) -> Option<vk::Result> {
+        Some(*self)
+    }
+}
+
+impl CommandResult for u32 {
+    fn vk_result(&self) -> Option<vk::Result> {
+        None
+    }
+}
+
+impl CommandResult for u64 {
+    fn vk_result(&self) -> Option<vk::Result> {
+        None
+    }
+}
+
+/// An in-flight command reported to the [`CommandObserver`] and the [`StatsCollector`]. Created
+/// by [`Global::enter_command`].
+pub(crate) struct CommandObservation<'a> {
+    command: LayerVulkanCommand,
+    observer: Option<&'a dyn CommandObserver>,
+    stats: Option<&'a StatsCollector>,
+    // None if neither the observer nor the statistics need the time.
+    start: Option<Instant>,
+    next_layer_time: Cell<Option<Duration>>,
+}
+
+impl CommandObservation<'_> {
+    /// Calls the next layer with `f`, and measures the time spent in it if needed.
+    pub(crate) fn call_next<R>(&self, f: impl FnOnce() -> R) -> R {
+        if self.stats.is_none() {
+            return f();
+        }
+        let start = Instant::now();
+        let res = f();
+        self.next_layer_time.set(Some(start.elapsed()));
+        res
+    }
+
+    /// Reports `result` to the [`CommandObserver`] and the [`StatsCollector`] if the command is
+    /// observed, and returns `result`.
+    pub(crate) fn exit<R: CommandResult>(&self, result: R) -> R {
+        let Some(start) = self.start else {
+            return result;
+        };
+        let elapsed = start.elapsed();
+        let vk_result = result.vk_result();
+        if let Some(stats) = self.stats {
+            let next_layer_time = self.next_layer_time.get();
+            stats.record(
+                self.command.clone(),
+                vk_result,
+                elapsed.saturating_sub(next_layer_time.unwrap_or_default()),
+                next_layer_time,
+            );
+        }
+        if let Some(observer) = self.observer {
+            observer.on_exit(self.command.clone(), vk_result, elapsed);
+        }
+        result
+    }
+}
+
+impl HookedCommands {
+    pub(crate) fn from_observed_commands(observed_commands: ObservedCommands) -> Self {
+        match observed_commands {
+            ObservedCommands::All => Self::all(),
+            ObservedCommands::Only(commands) => commands.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Layer> Global<T> {
+    /// Calls [`CommandObserver::on_enter`] if `command` is observed. The returned
+    /// [`CommandObservation`] must be exited with the value returned to the caller.
+    ///
+    /// `stats` is the [`StatsCollector`] of the `VkInstance` or `VkDevice` that `handle` belongs
+    /// to, if the statistics are enabled.
+    pub(crate) fn enter_command<'a>(
+        &'a self,
+        command: LayerVulkanCommand,
+        handle: impl Into<DispatchableHandle>,
+        stats: Option<&'a StatsCollector>,
+    ) -> CommandObservation<'a> {
+        let observer = self
+            .layer_info
+            .command_observer()
+            .filter(|_| self.observed_commands.contains(&command));
+        if let Some(observer) = observer {
+            observer.on_enter(command.clone(), handle.into());
+        }
+        let start = (observer.is_some() || stats.is_some()).then(Instant::now);
+        CommandObservation {
+            command,
+            observer,
+            stats,
+            start,
+            next_layer_time: Cell::new(None),
+        }
+    }
+}
@@ -0,0 +1,461 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ptr::null_mut,
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        Arc, Mutex, RwLock,
+    },
+};
+
+/// The number of reader counters per epoch. Threads are spread among the counters, so that
+/// readers on different threads usually don't write to the same cache line.
+const READER_SHARD_COUNT: usize = 16;
+
+/// The number of buckets in a snapshot is `1 << BUCKET_BITS`.
+const BUCKET_BITS: u32 = 6;
+const BUCKET_COUNT: usize = 1 << BUCKET_BITS;
+
+#[derive(Default)]
+#[repr(align(64))]
+struct ReaderShard(AtomicUsize);
+
+/// Returns the index of the reader counter used by the current thread.
+fn reader_shard_index() -> usize {
+    static NEXT_READER_SHARD_INDEX: AtomicUsize = AtomicUsize::new(0);
+    thread_local! {
+        static READER_SHARD_INDEX: usize =
+            NEXT_READER_SHARD_INDEX.fetch_add(1, Ordering::Relaxed) % READER_SHARD_COUNT;
+    }
+    READER_SHARD_INDEX
+        .try_with(|index| *index)
+        .unwrap_or_default()
+}
+
+/// A cheap multiplicative hasher that spreads the keys among the buckets. The keys are Vulkan
+/// handles or dispatch keys, which are usually aligned pointers, so the low bits carry little
+/// information, and the high bits of the product are used.
+#[derive(Default)]
+struct BucketHasher(u64);
+
+impl Hasher for BucketHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.write_u64((*byte).into());
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 = (self.0.rotate_left(5) ^ i).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn bucket_index(key: &impl Hash) -> usize {
+    let mut hasher = BucketHasher::default();
+    key.hash(&mut hasher);
+    (hasher.finish() >> (u64::BITS - BUCKET_BITS)) as usize
+}
+
+type Bucket<K, V> = Arc<BTreeMap<K, Arc<V>>>;
+
+/// An immutable snapshot of a [`DispatchMap`], and the map that [`DispatchMap::update`] modifies.
+///
+/// The entries are spread among a fixed number of buckets by the hash of the keys. Cloning the
+/// snapshot only clones the [`Arc`] of each bucket, and a bucket is copied on its first
+/// modification, so an update only copies the buckets it modifies rather than the whole map.
+pub(crate) struct DispatchMapSnapshot<K, V> {
+    // An empty bucket is None, so that an empty snapshot doesn't allocate any bucket.
+    buckets: Box<[Option<Bucket<K, V>>]>,
+}
+
+impl<K, V> Default for DispatchMapSnapshot<K, V> {
+    fn default() -> Self {
+        Self {
+            buckets: (0..BUCKET_COUNT).map(|_| None).collect(),
+        }
+    }
+}
+
+impl<K, V> Clone for DispatchMapSnapshot<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            buckets: self.buckets.clone(),
+        }
+    }
+}
+
+impl<K: Ord + Hash + Clone, V> DispatchMapSnapshot<K, V> {
+    fn bucket(&self, key: &K) -> Option<&BTreeMap<K, Arc<V>>> {
+        self.buckets[bucket_index(key)].as_deref()
+    }
+
+    fn bucket_mut(&mut self, key: &K) -> &mut BTreeMap<K, Arc<V>> {
+        Arc::make_mut(self.buckets[bucket_index(key)].get_or_insert_with(Default::default))
+    }
+
+    /// Drops the bucket of `key` if it's empty, so that empty buckets are always `None`.
+    fn shrink_bucket(&mut self, key: &K) {
+        let bucket = &mut self.buckets[bucket_index(key)];
+        if bucket.as_ref().is_some_and(|bucket| bucket.is_empty()) {
+            *bucket = None;
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.buckets.iter().all(Option::is_none)
+    }
+
+    pub(crate) fn get(&self, key: &K) -> Option<&Arc<V>> {
+        self.bucket(key)?.get(key)
+    }
+
+    pub(crate) fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts the value, and returns the old value of the key if any.
+    pub(crate) fn insert(&mut self, key: K, value: Arc<V>) -> Option<Arc<V>> {
+        self.bucket_mut(&key).insert(key, value)
+    }
+
+    /// Returns the value of the key, and inserts the value returned by `f` if the key is absent.
+    pub(crate) fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> Arc<V>) -> &Arc<V> {
+        if !self.contains_key(&key) {
+            self.insert(key.clone(), f());
+        }
+        self.get(&key).unwrap()
+    }
+
+    pub(crate) fn remove(&mut self, key: &K) -> Option<Arc<V>> {
+        // Avoid copying the bucket if there is nothing to remove.
+        if !self.contains_key(key) {
+            return None;
+        }
+        let value = self.bucket_mut(key).remove(key);
+        self.shrink_bucket(key);
+        value
+    }
+
+    /// Retains only the entries for which `f` returns `true`. Only the buckets with removed
+    /// entries are copied.
+    pub(crate) fn retain(&mut self, mut f: impl FnMut(&K, &Arc<V>) -> bool) {
+        let removed_keys = self
+            .buckets
+            .iter()
+            .flatten()
+            .flat_map(|bucket| bucket.iter())
+            .filter(|(key, value)| !f(key, value))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        for key in removed_keys {
+            self.remove(&key);
+        }
+    }
+
+    /// Returns all the entries in the order of the keys.
+    fn entries(&self) -> Vec<(&K, &Arc<V>)> {
+        let mut entries = self
+            .buckets
+            .iter()
+            .flatten()
+            .flat_map(|bucket| bucket.iter())
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(key, _)| *key);
+        entries
+    }
+}
+
+/// A read-mostly map from the dispatch key or the handle to the object info, e.g. the
+/// `VkDevice` dispatch table.
+///
+/// The lookup happens on every intercepted Vulkan command, while the map is only modified when a
+/// dispatchable object is created or destroyed, so the lookup must not take a lock. The map is
+/// stored as an immutable [`DispatchMapSnapshot`]. [`DispatchMap::get`] reads the current
+/// snapshot without any lock: it only increments and decrements a reader counter that is usually
+/// exclusive to the current thread, and the counter is decremented before [`DispatchMap::get`]
+/// returns. [`DispatchMap::update`] copies the snapshot, modifies the copy, publishes it, and then
+/// waits until no reader can observe the old snapshot before freeing it, similar to RCU. The
+/// readers never block, and never hold the counter after the lookup, so the wait is short.
+/// Because the old snapshot is freed before [`DispatchMap::update`] returns, the [`Arc`] of a
+/// removed entry is not kept alive by the snapshots after [`DispatchMap::update`] returns. The
+/// copy only copies the buckets that are modified, so frequent updates of a large map, e.g. the
+/// `VkCommandBuffer` map, stay cheap.
+///
+/// No snapshot is allocated if the map is empty, so the drop of an empty [`DispatchMap`] is no-op
+/// like [`LazyCollection`](crate::lazy_collection::LazyCollection).
+pub(crate) struct DispatchMap<K, V> {
+    snapshot: AtomicPtr<DispatchMapSnapshot<K, V>>,
+    // The reader counters indexed by the epoch and the reader shard index.
+    readers: [[ReaderShard; READER_SHARD_COUNT]; 2],
+    epoch: AtomicUsize,
+    writer: Mutex<()>,
+    // Readers access the snapshot concurrently, and the writer drops the snapshot on any thread,
+    // which have the same requirements as RwLock.
+    marker: PhantomData<RwLock<DispatchMapSnapshot<K, V>>>,
+}
+
+impl<K, V> Default for DispatchMap<K, V> {
+    fn default() -> Self {
+        Self {
+            snapshot: AtomicPtr::new(null_mut()),
+            readers: Default::default(),
+            epoch: AtomicUsize::new(0),
+            writer: Mutex::new(()),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V> Drop for DispatchMap<K, V> {
+    fn drop(&mut self) {
+        let snapshot = *self.snapshot.get_mut();
+        if !snapshot.is_null() {
+            // Safe, because the snapshot is always allocated by Box::into_raw in update, and there
+            // can't be any readers when we have an exclusive reference.
+            drop(unsafe { Box::from_raw(snapshot) });
+        }
+    }
+}
+
+struct ReaderGuard<'a>(&'a AtomicUsize);
+
+impl Drop for ReaderGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Release);
+    }
+}
+
+impl<K: Ord + Hash + Clone, V> DispatchMap<K, V> {
+    /// Calls `f` with the current snapshot without taking any lock. The snapshot is [`None`] if
+    /// the map is empty.
+    fn read<R>(&self, f: impl FnOnce(Option<&DispatchMapSnapshot<K, V>>) -> R) -> R {
+        let epoch = self.epoch.load(Ordering::SeqCst) & 1;
+        let reader_counter = &self.readers[epoch][reader_shard_index()].0;
+        reader_counter.fetch_add(1, Ordering::SeqCst);
+        let _reader_guard = ReaderGuard(reader_counter);
+        let snapshot = self.snapshot.load(Ordering::SeqCst);
+        // Safe, because the writer won't free the snapshot until the reader counter is
+        // decremented.
+        f(unsafe { snapshot.as_ref() })
+    }
+
+    /// Returns the value corresponding to the key without taking any lock.
+    ///
+    /// The returned [`Arc`] doesn't block [`DispatchMap::update`], so it can be held for an
+    /// unbounded time, e.g. across the call into the next layer.
+    pub(crate) fn get(&self, key: &K) -> Option<Arc<V>> {
+        self.read(|snapshot| snapshot?.get(key).map(Arc::clone))
+    }
+
+    /// Returns all the values in the order of the keys without taking any lock.
+    ///
+    /// The values are collected from a single snapshot, so they are consistent with each other,
+    /// but the map may have been modified when this function returns.
+    pub(crate) fn values(&self) -> Vec<Arc<V>> {
+        self.read(|snapshot| {
+            snapshot
+                .map(|snapshot| {
+                    snapshot
+                        .entries()
+                        .into_iter()
+                        .map(|(_, value)| Arc::clone(value))
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    /// Modifies the map with `f`, and publishes the modified map to the readers.
+    ///
+    /// Writers are serialized. When this function returns, the old map is dropped, and all the
+    /// following [`DispatchMap::get`] calls will observe the modified map.
+    pub(crate) fn update<R>(&self, f: impl FnOnce(&mut DispatchMapSnapshot<K, V>) -> R) -> R {
+        let _writer_guard = self.writer.lock().unwrap();
+        // Only the writer modifies the snapshot pointer, and we hold the writer lock.
+        let old_snapshot = self.snapshot.load(Ordering::Acquire);
+        // Safe, because the writer lock prevents the snapshot from being freed.
+        let mut map = unsafe { old_snapshot.as_ref() }
+            .cloned()
+            .unwrap_or_default();
+        let res = f(&mut map);
+        let new_snapshot = if map.is_empty() {
+            null_mut()
+        } else {
+            Box::into_raw(Box::new(map))
+        };
+        self.snapshot.store(new_snapshot, Ordering::SeqCst);
+        if !old_snapshot.is_null() {
+            self.wait_for_readers();
+            // Safe, because no reader can observe the old snapshot after wait_for_readers.
+            drop(unsafe { Box::from_raw(old_snapshot) });
+        }
+        res
+    }
+
+    /// Waits until all readers that may observe the old snapshot exit.
+    fn wait_for_readers(&self) {
+        // A reader that observes the old snapshot incremented its counter before the new snapshot
+        // was published, so checking the counters of both epochs after publishing is enough. The
+        // epoch is flipped before each check so that new readers use the other counters, and the
+        // checked counters eventually drop to zero.
+        for _ in 0..2 {
+            let epoch = self.epoch.fetch_add(1, Ordering::SeqCst) & 1;
+            for reader_shard in &self.readers[epoch] {
+                while reader_shard.0.load(Ordering::SeqCst) != 0 {
+                    std::thread::yield_now();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::AtomicBool, thread};
+
+    use super::*;
+
+    #[test]
+    fn test_get_should_return_the_updated_value() {
+        let map = DispatchMap::<u32, u32>::default();
+        assert!(map.get(&1).is_none());
+        let previous = map.update(|map| map.insert(1, Arc::new(42)));
+        assert!(previous.is_none());
+        assert_eq!(map.get(&1).as_deref(), Some(&42));
+        let removed = map.update(|map| map.remove(&1)).unwrap();
+        assert!(map.get(&1).is_none());
+        // The removed value must not be shared with the old snapshot.
+        assert_eq!(Arc::try_unwrap(removed).ok(), Some(42));
+    }
+
+    #[test]
+    fn test_values_should_return_all_values_in_key_order() {
+        let map = DispatchMap::<u32, u32>::default();
+        assert!(map.values().is_empty());
+        map.update(|map| {
+            map.insert(2, Arc::new(20));
+            map.insert(1, Arc::new(10));
+        });
+        let values = map.values();
+        assert_eq!(
+            values.iter().map(|value| **value).collect::<Vec<_>>(),
+            vec![10, 20]
+        );
+    }
+
+    #[test]
+    fn test_update_shouldnt_wait_for_returned_values() {
+        let map = DispatchMap::<u32, u32>::default();
+        map.update(|map| map.insert(1, Arc::new(42)));
+        let value = map.get(&1).unwrap();
+        // Would deadlock if the returned value kept a reader alive.
+        map.update(|map| map.remove(&1));
+        assert_eq!(*value, 42);
+        assert!(map.get(&1).is_none());
+    }
+
+    #[test]
+    fn test_update_should_only_copy_modified_buckets() {
+        let mut snapshot = DispatchMapSnapshot::<u64, u64>::default();
+        for key in 0..1024 {
+            snapshot.insert(key, Arc::new(key));
+        }
+        let mut modified_snapshot = snapshot.clone();
+        modified_snapshot.insert(1024, Arc::new(1024));
+        let modified_bucket_index = bucket_index(&1024u64);
+        for (index, (bucket, modified_bucket)) in snapshot
+            .buckets
+            .iter()
+            .zip(modified_snapshot.buckets.iter())
+            .enumerate()
+        {
+            let (Some(bucket), Some(modified_bucket)) = (bucket, modified_bucket) else {
+                panic!("Bucket {} is empty.", index);
+            };
+            assert_eq!(
+                Arc::ptr_eq(bucket, modified_bucket),
+                index != modified_bucket_index
+            );
+        }
+        assert!(snapshot.get(&1024).is_none());
+        assert_eq!(
+            modified_snapshot.get(&1024).map(|value| **value),
+            Some(1024)
+        );
+    }
+
+    #[test]
+    fn test_retain_should_remove_rejected_entries() {
+        let map = DispatchMap::<u32, u32>::default();
+        map.update(|map| {
+            for key in 0..100 {
+                map.insert(key, Arc::new(key));
+            }
+            map.retain(|key, _| key % 2 == 0);
+        });
+        assert_eq!(
+            map.values().iter().map(|value| **value).collect::<Vec<_>>(),
+            (0..100).step_by(2).collect::<Vec<_>>()
+        );
+        map.update(|map| map.retain(|_, _| false));
+        assert!(map.values().is_empty());
+    }
+
+    #[test]
+    fn test_empty_map_shouldnt_leak() {
+        // We rely on the Miri test to detect the resource leak.
+        let map = DispatchMap::<u32, u32>::default();
+        std::mem::forget(map);
+
+        let map = DispatchMap::<u32, u32>::default();
+        map.update(|map| map.insert(1, Arc::new(42)));
+        map.update(|map| map.retain(|_, _| false));
+        std::mem::forget(map);
+    }
+
+    #[test]
+    fn test_concurrent_get_and_update() {
+        let map = DispatchMap::<u32, u32>::default();
+        map.update(|map| map.insert(0, Arc::new(0)));
+        let stop = AtomicBool::new(false);
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    while !stop.load(Ordering::Relaxed) {
+                        assert_eq!(map.get(&0).as_deref(), Some(&0));
+                    }
+                });
+            }
+            for i in 1..100 {
+                map.update(|map| map.insert(i, Arc::new(i)));
+                assert_eq!(map.get(&i).as_deref(), Some(&i));
+                let removed = map.update(|map| map.remove(&i)).unwrap();
+                assert_eq!(Arc::try_unwrap(removed).ok(), Some(i));
+            }
+            stop.store(true, Ordering::Relaxed);
+        });
+    }
+}
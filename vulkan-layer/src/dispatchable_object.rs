@@ -0,0 +1,89 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dispatchable Vulkan handles and the keys used to look up the per-instance and per-device
+//! information.
+//!
+//! The items are public so that they can appear in the bounds of public methods of
+//! [`Global`](crate::Global), but this module is private, so they can't be named or implemented
+//! outside of this crate.
+
+use ash::vk;
+use std::ffi::c_void;
+
+/// A Vulkan dispatchable handle, whose first pointer-sized field is the loader dispatch table
+/// pointer.
+///
+/// `VkInstance` and `VkPhysicalDevice` share the dispatch key of the owning instance, and
+/// `VkDevice`, `VkQueue` and `VkCommandBuffer` share the dispatch key of the owning device.
+pub trait DispatchableObject: vk::Handle + Copy {
+    type DispatchKey: From<usize>;
+
+    fn get_dispatch_key(&self) -> Self::DispatchKey {
+        assert_eq!(
+            std::mem::size_of::<Self>(),
+            std::mem::size_of::<*const *const c_void>()
+        );
+        // Safe, because all dispatchable objects can be cast to void **. See details at
+        // https://github.com/KhronosGroup/Vulkan-Loader/blob/35b005a5792f6e4c2931d62a37324923f1a71c79/docs/LoaderDriverInterface.md#driver-dispatchable-object-creation.
+        let key = unsafe {
+            // We use transmute instead of Handle::as_raw here to avoid integer to pointer cast, and
+            // allow the miri tests with tree borrows to work with this test. See
+            // https://github.com/ash-rs/ash/issues/996 for details.
+            let dispatch_table_ptr = std::mem::transmute_copy::<Self, *const *const c_void>(self);
+            std::ptr::read(dispatch_table_ptr)
+        };
+        (key as usize).into()
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InstanceDispatchKey(usize);
+
+impl From<usize> for InstanceDispatchKey {
+    fn from(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl DispatchableObject for vk::Instance {
+    type DispatchKey = InstanceDispatchKey;
+}
+
+impl DispatchableObject for vk::PhysicalDevice {
+    type DispatchKey = InstanceDispatchKey;
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DeviceDispatchKey(usize);
+
+impl From<usize> for DeviceDispatchKey {
+    fn from(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl DispatchableObject for vk::Device {
+    type DispatchKey = DeviceDispatchKey;
+}
+
+impl DispatchableObject for vk::CommandBuffer {
+    type DispatchKey = DeviceDispatchKey;
+}
+
+impl DispatchableObject for vk::Queue {
+    type DispatchKey = DeviceDispatchKey;
+}
@@ -0,0 +1,531 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Injects errors into the commands that return a `VkResult`.
+//!
+//! The injector is provided by [`Layer::fault_injector`]. The layer framework then intercepts all
+//! the commands that return a `VkResult` even if the layer implementation doesn't hook them. When
+//! a [`FaultRule`] fires, the command returns the error of the rule right away, without calling
+//! the hooks of the layer implementation or the next layer.
+
+use ash::vk;
+use std::{str::FromStr, sync::Mutex};
+use thiserror::Error;
+
+use crate::{
+    global_simple_intercept::FAULT_INJECTABLE_COMMANDS, Global, Layer, LayerSettingType,
+    LayerSettings, LayerVulkanCommand,
+};
+
+/// When a [`FaultRule`] fires. Only the calls of the commands that match the rule are counted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FaultTrigger {
+    /// Fires on the Nth call only, counting from 1.
+    NthCall(u64),
+    /// Fires on every call with the given probability. The pseudo-random sequence is generated
+    /// from `seed`, so that the same calls fail in every run.
+    Probability {
+        /// The probability in `[0, 1]`.
+        probability: f64,
+        /// The seed of the pseudo-random sequence.
+        seed: u64,
+    },
+    /// Lets the first `budget` calls through, and fires on every call after that.
+    AfterBudget(u64),
+}
+
+/// A rule of the [`FaultInjector`].
+///
+/// A rule can also be parsed from a string in the form of `<command>:<result>:<trigger>`:
+/// * `<command>` is the name of a command that returns a `VkResult`, e.g. `vkAllocateMemory`, or
+///   `*` for all such commands.
+/// * `<result>` is the name of an error `VkResult` with or without the `VK_` prefix, e.g.
+///   `ERROR_OUT_OF_DEVICE_MEMORY`, or the raw negative value, e.g. `-2`. Success codes are
+///   rejected, because the outputs of the command are not written when a fault is injected.
+/// * `<trigger>` is one of `nth=<N>`, `budget=<N>` or `probability=<P>[:seed=<S>]`. The seed is
+///   0 if not specified.
+///
+/// # Examples
+/// ```
+/// use ash::vk;
+/// use vulkan_layer::{FaultRule, FaultTrigger, LayerVulkanCommand};
+///
+/// let rule: FaultRule = "vkAllocateMemory:VK_ERROR_OUT_OF_DEVICE_MEMORY:nth=3"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(
+///     rule,
+///     FaultRule {
+///         command: Some(LayerVulkanCommand::AllocateMemory),
+///         result: vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+///         trigger: FaultTrigger::NthCall(3),
+///     }
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaultRule {
+    /// The command that the rule applies to, or [`None`] for all the commands that return a
+    /// `VkResult`.
+    pub command: Option<LayerVulkanCommand>,
+    /// The error returned when the rule fires. A success code would be returned without writing
+    /// the outputs of the command, so [`FromStr`] rejects them.
+    pub result: vk::Result,
+    /// When the rule fires.
+    pub trigger: FaultTrigger,
+}
+
+/// The error returned when a [`FaultRule`] fails to parse.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FaultRuleParseError {
+    /// The rule is not in the form of `<command>:<result>:<trigger>`.
+    #[error("expect a fault rule in the form of <command>:<result>:<trigger>, got {0:?}")]
+    InvalidFormat(String),
+    /// The command name is unknown.
+    #[error("unknown command {0:?}")]
+    UnknownCommand(String),
+    /// The command doesn't return a `VkResult`, so no fault can be injected into it.
+    #[error("command {0:?} doesn't return a VkResult")]
+    NotFaultInjectable(String),
+    /// The `VkResult` name is unknown.
+    #[error("unknown VkResult {0:?}")]
+    UnknownResult(String),
+    /// The `VkResult` is a success code instead of an error.
+    #[error("VkResult {0:?} is not an error")]
+    NotAnError(String),
+    /// The trigger is invalid.
+    #[error("invalid fault trigger {0:?}")]
+    InvalidTrigger(String),
+}
+
+// The errors that can be named in a rule, besides the raw values.
+const NAMED_RESULTS: &[vk::Result] = &[
+    vk::Result::ERROR_OUT_OF_HOST_MEMORY,
+    vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+    vk::Result::ERROR_INITIALIZATION_FAILED,
+    vk::Result::ERROR_DEVICE_LOST,
+    vk::Result::ERROR_MEMORY_MAP_FAILED,
+    vk::Result::ERROR_LAYER_NOT_PRESENT,
+    vk::Result::ERROR_EXTENSION_NOT_PRESENT,
+    vk::Result::ERROR_FEATURE_NOT_PRESENT,
+    vk::Result::ERROR_INCOMPATIBLE_DRIVER,
+    vk::Result::ERROR_TOO_MANY_OBJECTS,
+    vk::Result::ERROR_FORMAT_NOT_SUPPORTED,
+    vk::Result::ERROR_FRAGMENTED_POOL,
+    vk::Result::ERROR_UNKNOWN,
+    vk::Result::ERROR_OUT_OF_POOL_MEMORY,
+    vk::Result::ERROR_INVALID_EXTERNAL_HANDLE,
+    vk::Result::ERROR_FRAGMENTATION,
+    vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS,
+    vk::Result::ERROR_SURFACE_LOST_KHR,
+    vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR,
+    vk::Result::ERROR_OUT_OF_DATE_KHR,
+    vk::Result::ERROR_INCOMPATIBLE_DISPLAY_KHR,
+    vk::Result::ERROR_VALIDATION_FAILED_EXT,
+    vk::Result::ERROR_INVALID_SHADER_NV,
+    vk::Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT,
+    vk::Result::ERROR_COMPRESSION_EXHAUSTED_EXT,
+];
+
+// The success codes, which are named only to report them as not errors.
+const SUCCESS_RESULTS: &[vk::Result] = &[
+    vk::Result::SUCCESS,
+    vk::Result::NOT_READY,
+    vk::Result::TIMEOUT,
+    vk::Result::EVENT_SET,
+    vk::Result::EVENT_RESET,
+    vk::Result::INCOMPLETE,
+    vk::Result::PIPELINE_COMPILE_REQUIRED,
+    vk::Result::SUBOPTIMAL_KHR,
+    vk::Result::THREAD_IDLE_KHR,
+    vk::Result::THREAD_DONE_KHR,
+    vk::Result::OPERATION_DEFERRED_KHR,
+    vk::Result::OPERATION_NOT_DEFERRED_KHR,
+];
+
+fn parse_result(value: &str) -> Result<vk::Result, FaultRuleParseError> {
+    let result = match value.parse::<i32>() {
+        Ok(raw) => vk::Result::from_raw(raw),
+        Err(_) => {
+            let name = value.strip_prefix("VK_").unwrap_or(value);
+            NAMED_RESULTS
+                .iter()
+                .chain(SUCCESS_RESULTS)
+                .find(|result| format!("{result:?}") == name)
+                .copied()
+                .ok_or_else(|| FaultRuleParseError::UnknownResult(value.to_owned()))?
+        }
+    };
+    if result.as_raw() >= 0 {
+        return Err(FaultRuleParseError::NotAnError(value.to_owned()));
+    }
+    Ok(result)
+}
+
+fn parse_trigger(trigger: &str, seed: Option<&str>) -> Option<FaultTrigger> {
+    let (kind, value) = trigger.split_once('=')?;
+    match (kind, seed) {
+        ("nth", None) => value
+            .parse()
+            .ok()
+            .filter(|n| *n > 0)
+            .map(FaultTrigger::NthCall),
+        ("budget", None) => value.parse().ok().map(FaultTrigger::AfterBudget),
+        ("probability", seed) => {
+            let probability = value
+                .parse()
+                .ok()
+                .filter(|probability| (0.0..=1.0).contains(probability))?;
+            let seed = match seed {
+                Some(seed) => seed.strip_prefix("seed=")?.parse().ok()?,
+                None => 0,
+            };
+            Some(FaultTrigger::Probability { probability, seed })
+        }
+        _ => None,
+    }
+}
+
+impl FromStr for FaultRule {
+    type Err = FaultRuleParseError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let parts = rule.split(':').map(str::trim).collect::<Vec<_>>();
+        let (command, result, trigger, seed) = match parts[..] {
+            [command, result, trigger] => (command, result, trigger, None),
+            [command, result, trigger, seed] => (command, result, trigger, Some(seed)),
+            _ => return Err(FaultRuleParseError::InvalidFormat(rule.to_owned())),
+        };
+        let command = match command {
+            "*" => None,
+            command => {
+                let parsed_command = LayerVulkanCommand::try_from(command)
+                    .map_err(|_| FaultRuleParseError::UnknownCommand(command.to_owned()))?;
+                if !FAULT_INJECTABLE_COMMANDS.contains(&parsed_command) {
+                    return Err(FaultRuleParseError::NotFaultInjectable(command.to_owned()));
+                }
+                Some(parsed_command)
+            }
+        };
+        let result = parse_result(result)?;
+        let trigger = parse_trigger(trigger, seed)
+            .ok_or_else(|| FaultRuleParseError::InvalidTrigger(parts[2..].join(":")))?;
+        Ok(Self {
+            command,
+            result,
+            trigger,
+        })
+    }
+}
+
+struct FaultRuleState {
+    rule: FaultRule,
+    calls: u64,
+    random_state: u64,
+}
+
+impl FaultRuleState {
+    fn new(rule: FaultRule) -> Self {
+        let random_state = match rule.trigger {
+            FaultTrigger::Probability { seed, .. } => seed,
+            _ => 0,
+        };
+        Self {
+            rule,
+            calls: 0,
+            random_state,
+        }
+    }
+
+    // SplitMix64, which is good enough for the fault injection and doesn't need a dependency.
+    fn next_random(&mut self) -> f64 {
+        self.random_state = self.random_state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.random_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Counts the call, and returns whether the rule fires.
+    fn on_call(&mut self, command: &LayerVulkanCommand) -> bool {
+        if self
+            .rule
+            .command
+            .as_ref()
+            .is_some_and(|rule_command| rule_command != command)
+        {
+            return false;
+        }
+        self.calls += 1;
+        match self.rule.trigger {
+            FaultTrigger::NthCall(n) => self.calls == n,
+            FaultTrigger::Probability { probability, .. } => self.next_random() < probability,
+            FaultTrigger::AfterBudget(budget) => self.calls > budget,
+        }
+    }
+}
+
+/// Injects errors into the commands that return a `VkResult` according to a list of
+/// [`FaultRule`]s.
+///
+/// The rules can be changed at any time, e.g. from the hooks of the layer implementation, or
+/// loaded from the layer settings with [`FaultInjector::add_rules_from_settings`]. If more than one
+/// rule fires on a call, the rule added first wins. A rule counts the calls even if another rule
+/// wins.
+#[derive(Default)]
+pub struct FaultInjector {
+    rules: Mutex<Vec<FaultRuleState>>,
+}
+
+impl FaultInjector {
+    /// The key of the layer setting read by [`FaultInjector::add_rules_from_settings`].
+    pub const SETTING_KEY: &'static str = "fault_injection";
+
+    /// Creates a [`FaultInjector`] without any rule.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a rule. The calls before the rule is added are not counted.
+    pub fn add_rule(&self, rule: FaultRule) {
+        self.rules.lock().unwrap().push(FaultRuleState::new(rule));
+    }
+
+    /// Removes all the rules.
+    pub fn clear_rules(&self) {
+        self.rules.lock().unwrap().clear();
+    }
+
+    /// The current rules in the order they were added.
+    pub fn rules(&self) -> Vec<FaultRule> {
+        self.rules
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|state| state.rule.clone())
+            .collect()
+    }
+
+    /// Adds the rules in the [`FaultInjector::SETTING_KEY`] layer setting, a comma separated list
+    /// of rules in the string form documented in [`FaultRule`].
+    ///
+    /// Usually called from [`Layer::create_instance_info`] with the settings of the `VkInstance`.
+    /// No rule is added if any of the rules fails to parse.
+    pub fn add_rules_from_settings(
+        &self,
+        layer_settings: &LayerSettings,
+    ) -> Result<(), FaultRuleParseError> {
+        let Some(rules) = Vec::<String>::from_layer_settings(layer_settings, Self::SETTING_KEY)
+        else {
+            return Ok(());
+        };
+        let rules = rules
+            .iter()
+            .map(|rule| rule.parse())
+            .collect::<Result<Vec<FaultRule>, _>>()?;
+        self.rules
+            .lock()
+            .unwrap()
+            .extend(rules.into_iter().map(FaultRuleState::new));
+        Ok(())
+    }
+
+    /// Counts the call of `command`, and returns the result of the first rule that fires.
+    pub(crate) fn on_call(&self, command: &LayerVulkanCommand) -> Option<vk::Result> {
+        let mut result = None;
+        for state in self.rules.lock().unwrap().iter_mut() {
+            if state.on_call(command) && result.is_none() {
+                result = Some(state.rule.result);
+            }
+        }
+        result
+    }
+}
+
+impl<T: Layer> Global<T> {
+    /// The commands intercepted for the fault injection, in addition to the ones hooked by the
+    /// layer implementation.
+    pub(crate) fn fault_injection_commands(&self) -> impl Iterator<Item = LayerVulkanCommand> {
+        let enabled = self.layer_info.fault_injector().is_some();
+        FAULT_INJECTABLE_COMMANDS
+            .iter()
+            .filter(move |_| enabled)
+            .cloned()
+    }
+
+    /// Returns the result to return from `command` right away if a fault is injected.
+    pub(crate) fn inject_fault(&self, command: LayerVulkanCommand) -> Option<vk::Result> {
+        self.layer_info.fault_injector()?.on_call(&command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LayerSettingValue;
+
+    fn fire_sequence(
+        injector: &FaultInjector,
+        command: LayerVulkanCommand,
+        calls: usize,
+    ) -> Vec<bool> {
+        (0..calls)
+            .map(|_| injector.on_call(&command).is_some())
+            .collect()
+    }
+
+    #[test]
+    fn test_fault_rule_should_parse_all_triggers() {
+        assert_eq!(
+            "*:ERROR_DEVICE_LOST:budget=10".parse(),
+            Ok(FaultRule {
+                command: None,
+                result: vk::Result::ERROR_DEVICE_LOST,
+                trigger: FaultTrigger::AfterBudget(10),
+            })
+        );
+        assert_eq!(
+            "vkQueueSubmit:-4:probability=0.5:seed=7".parse(),
+            Ok(FaultRule {
+                command: Some(LayerVulkanCommand::QueueSubmit),
+                result: vk::Result::ERROR_DEVICE_LOST,
+                trigger: FaultTrigger::Probability {
+                    probability: 0.5,
+                    seed: 7
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_fault_rule_should_report_parse_errors() {
+        assert_eq!(
+            "vkQueueSubmit:ERROR_DEVICE_LOST".parse::<FaultRule>(),
+            Err(FaultRuleParseError::InvalidFormat(
+                "vkQueueSubmit:ERROR_DEVICE_LOST".to_owned()
+            ))
+        );
+        assert_eq!(
+            "vkUnknown:ERROR_DEVICE_LOST:nth=1".parse::<FaultRule>(),
+            Err(FaultRuleParseError::UnknownCommand("vkUnknown".to_owned()))
+        );
+        assert_eq!(
+            "vkCmdDraw:ERROR_DEVICE_LOST:nth=1".parse::<FaultRule>(),
+            Err(FaultRuleParseError::NotFaultInjectable(
+                "vkCmdDraw".to_owned()
+            ))
+        );
+        assert_eq!(
+            "*:VK_SUBOPTIMAL_KHR:nth=1".parse::<FaultRule>(),
+            Err(FaultRuleParseError::NotAnError(
+                "VK_SUBOPTIMAL_KHR".to_owned()
+            ))
+        );
+        assert_eq!(
+            "*:SUCCESS:nth=1".parse::<FaultRule>(),
+            Err(FaultRuleParseError::NotAnError("SUCCESS".to_owned()))
+        );
+        assert_eq!(
+            "*:1000001003:nth=1".parse::<FaultRule>(),
+            Err(FaultRuleParseError::NotAnError("1000001003".to_owned()))
+        );
+        assert_eq!(
+            "*:ERROR_UNKNOWN_THING:nth=1".parse::<FaultRule>(),
+            Err(FaultRuleParseError::UnknownResult(
+                "ERROR_UNKNOWN_THING".to_owned()
+            ))
+        );
+        assert_eq!(
+            "*:ERROR_DEVICE_LOST:nth=0".parse::<FaultRule>(),
+            Err(FaultRuleParseError::InvalidTrigger("nth=0".to_owned()))
+        );
+        assert_eq!(
+            "*:ERROR_DEVICE_LOST:budget=1:seed=2".parse::<FaultRule>(),
+            Err(FaultRuleParseError::InvalidTrigger(
+                "budget=1:seed=2".to_owned()
+            ))
+        );
+        assert_eq!(
+            "*:ERROR_DEVICE_LOST:probability=2".parse::<FaultRule>(),
+            Err(FaultRuleParseError::InvalidTrigger(
+                "probability=2".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_nth_call_and_budget_should_only_count_matching_commands() {
+        let injector = FaultInjector::new();
+        injector.add_rule("vkQueueSubmit:ERROR_DEVICE_LOST:nth=2".parse().unwrap());
+        injector.add_rule(
+            "vkAllocateMemory:ERROR_OUT_OF_DEVICE_MEMORY:budget=1"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            fire_sequence(&injector, LayerVulkanCommand::QueueSubmit, 3),
+            [false, true, false]
+        );
+        assert_eq!(
+            fire_sequence(&injector, LayerVulkanCommand::AllocateMemory, 3),
+            [false, true, true]
+        );
+        assert_eq!(
+            injector.on_call(&LayerVulkanCommand::AllocateMemory),
+            Some(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY)
+        );
+        injector.clear_rules();
+        assert_eq!(injector.on_call(&LayerVulkanCommand::AllocateMemory), None);
+    }
+
+    #[test]
+    fn test_probability_should_be_deterministic_under_seed() {
+        let rule: FaultRule = "*:ERROR_DEVICE_LOST:probability=0.5:seed=42"
+            .parse()
+            .unwrap();
+        let first_injector = FaultInjector::new();
+        first_injector.add_rule(rule.clone());
+        let second_injector = FaultInjector::new();
+        second_injector.add_rule(rule);
+        let first = fire_sequence(&first_injector, LayerVulkanCommand::QueueSubmit, 1000);
+        let second = fire_sequence(&second_injector, LayerVulkanCommand::QueueSubmit, 1000);
+        assert_eq!(first, second);
+        let fired = first.iter().filter(|fired| **fired).count();
+        assert!(
+            (400..600).contains(&fired),
+            "{fired} out of 1000 calls fired"
+        );
+    }
+
+    #[test]
+    fn test_add_rules_from_settings_should_add_nothing_on_error() {
+        let injector = FaultInjector::new();
+        let mut layer_settings = LayerSettings::default();
+        layer_settings.insert(
+            FaultInjector::SETTING_KEY,
+            LayerSettingValue::String(vec![
+                "vkQueueSubmit:ERROR_DEVICE_LOST:nth=1, *:ERROR_UNKNOWN:budget=5".to_owned(),
+            ]),
+        );
+        injector.add_rules_from_settings(&layer_settings).unwrap();
+        assert_eq!(injector.rules().len(), 2);
+
+        layer_settings.insert(
+            FaultInjector::SETTING_KEY,
+            LayerSettingValue::String(vec!["*:ERROR_DEVICE_LOST:nth=1,invalid".to_owned()]),
+        );
+        assert!(injector.add_rules_from_settings(&layer_settings).is_err());
+        assert_eq!(injector.rules().len(), 2);
+    }
+}
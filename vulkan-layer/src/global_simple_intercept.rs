@@ -20,13 +20,12 @@ use std::{
 
 use ash::vk;
 
-use smallvec::SmallVec;
 use thiserror::Error;
 
 pub mod generated;
 pub use generated::*;
 
-use crate::vk_utils::ptr_as_uninit_mut;
+use crate::{vk_utils::ptr_as_uninit_mut, LayerVulkanCommand};
 
 #[derive(Error, Debug)]
 pub enum TryFromExtensionError {
@@ -104,11 +103,128 @@ impl From<ApiVersion> for u32 {
 
 pub(crate) struct VulkanCommand {
     pub name: &'static str,
-    pub features: SmallVec<[Feature; 2]>,
-    pub hooked: bool,
+    pub features: &'static [Feature],
+    /// The command that the layer implementation uses to express the intent to intercept this
+    /// command. [`None`] if this command is always intercepted by the layer framework, e.g.
+    /// `vkCreateDevice`.
+    pub command: Option<LayerVulkanCommand>,
     pub proc: vk::PFN_vkVoidFunction,
 }
 
+impl VulkanCommand {
+    pub(crate) fn is_hooked(&self, hooked_commands: &HookedCommands) -> bool {
+        match &self.command {
+            Some(command) => hooked_commands.contains(command),
+            None => true,
+        }
+    }
+}
+
+/// A set of [`LayerVulkanCommand`]s intercepted by the layer implementation, stored as a bitset
+/// indexed by the enum discriminant.
+///
+/// Calculated on every `vkCreateInstance` and `vkCreateDevice`, so that the command tables
+/// themselves can be static.
+#[derive(Clone)]
+pub(crate) struct HookedCommands([u64; LayerVulkanCommand::COUNT.div_ceil(64)]);
+
+impl HookedCommands {
+    fn bit(command: &LayerVulkanCommand) -> (usize, u64) {
+        let index = command.clone() as usize;
+        (index / 64, 1 << (index % 64))
+    }
+
+    pub(crate) fn insert(&mut self, command: &LayerVulkanCommand) {
+        let (word, mask) = Self::bit(command);
+        self.0[word] |= mask;
+    }
+
+    pub(crate) fn contains(&self, command: &LayerVulkanCommand) -> bool {
+        let (word, mask) = Self::bit(command);
+        self.0[word] & mask != 0
+    }
+
+    /// A set that contains every command.
+    pub(crate) fn all() -> Self {
+        Self([u64::MAX; LayerVulkanCommand::COUNT.div_ceil(64)])
+    }
+
+    pub(crate) fn union(&mut self, other: &Self) {
+        for (word, other_word) in self.0.iter_mut().zip(other.0.iter()) {
+            *word |= other_word;
+        }
+    }
+}
+
+impl Default for HookedCommands {
+    fn default() -> Self {
+        Self([0; LayerVulkanCommand::COUNT.div_ceil(64)])
+    }
+}
+
+impl FromIterator<LayerVulkanCommand> for HookedCommands {
+    fn from_iter<I: IntoIterator<Item = LayerVulkanCommand>>(iter: I) -> Self {
+        let mut hooked_commands = Self::default();
+        for command in iter {
+            hooked_commands.insert(&command);
+        }
+        hooked_commands
+    }
+}
+
+/// A perfect hash table generated at codegen time to look up a [`VulkanCommand`] by name.
+///
+/// The algorithm is the "hash, displace, and compress" one used by the phf crate. The name is
+/// hashed with `seed` to `(g, f1, f2)`. `g` selects a bucket, and the displacements `(d1, d2)` of
+/// the bucket map the name to the slot `(d2 + f1 * d1 + f2) % indices.len()`, which stores the
+/// index to the command table. Different names never share a slot, so only one string comparison
+/// is needed. Must be kept in sync with `scripts/vulkan_layer_genvk/perfect_hash.py`.
+pub(crate) struct CommandLookupTable {
+    pub seed: u64,
+    pub displacements: &'static [(u16, u16)],
+    pub indices: &'static [u16],
+}
+
+impl CommandLookupTable {
+    fn hash(name: &str, seed: u64) -> (u32, u32, u32) {
+        fn mix(mut z: u64) -> u64 {
+            // The finalizer of splitmix64.
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+        // FNV-1a
+        let mut h = 0xcbf29ce484222325 ^ seed;
+        for byte in name.bytes() {
+            h ^= u64::from(byte);
+            h = h.wrapping_mul(0x100000001b3);
+        }
+        // The high bits of FNV-1a are poorly distributed for short keys, so mix the hash.
+        let h = mix(h);
+        ((h >> 32) as u32, h as u32, mix(h) as u32)
+    }
+
+    /// Finds the command named `name` in `commands`, which must be the command table this lookup
+    /// table is generated from.
+    pub(crate) fn get<'a>(
+        &self,
+        commands: &'a [VulkanCommand],
+        name: &str,
+    ) -> Option<&'a VulkanCommand> {
+        let (g, f1, f2) = Self::hash(name, self.seed);
+        let (d1, d2) = self.displacements[g as usize % self.displacements.len()];
+        let slot = u32::from(d2)
+            .wrapping_add(f1.wrapping_mul(d1.into()))
+            .wrapping_add(f2) as usize
+            % self.indices.len();
+        let command = &commands[usize::from(self.indices[slot])];
+        if command.name != name {
+            return None;
+        }
+        Some(command)
+    }
+}
+
 fn get_instance_proc_addr_loader(
     get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
     instance: &ash::Instance,
@@ -163,7 +279,7 @@ fn get_device_proc_addr_loader(
 /// # Panics
 /// * Panics if `size` can't be converted to usize.
 #[deny(unsafe_op_in_unsafe_fn)]
-unsafe fn maybe_uninit_slice_from_raw_parts_mut<'a, T>(
+pub(crate) unsafe fn maybe_uninit_slice_from_raw_parts_mut<'a, T>(
     p_out_array: *mut T,
     p_size: *const (impl TryInto<usize, Error = impl Debug> + Copy),
 ) -> Option<&'a mut [MaybeUninit<T>]> {
@@ -177,6 +293,26 @@ unsafe fn maybe_uninit_slice_from_raw_parts_mut<'a, T>(
     Some(unsafe { uninit_slice_from_raw_parts_mut(p_out_array, size) })
 }
 
+/// Converts an output array that the next layer has written to a slice, e.g. `pProperties` of
+/// `vkGetPhysicalDeviceQueueFamilyProperties`. The length of the slice is the element count the
+/// next layer has written to `p_size`. If `p_out_array` or `p_size` is null, [`None`] is returned.
+///
+/// # Safety
+/// `p_size` must be either null or point to a valid data to read. If `p_out_array` is not null,
+/// the first `*p_size` elements of `p_out_array` must be initialized, and the safety requirements
+/// of [`std::slice::from_raw_parts`] must be met if `*p_size` is not 0.
+///
+/// # Panics
+/// * Panics if `size` can't be converted to usize.
+#[deny(unsafe_op_in_unsafe_fn)]
+pub(crate) unsafe fn maybe_output_slice_from_raw_parts<'a, T>(
+    p_out_array: *const T,
+    p_size: *const (impl TryInto<usize, Error = impl Debug> + Copy),
+) -> Option<&'a [T]> {
+    let size = unsafe { p_size.as_ref() }.copied()?;
+    unsafe { maybe_slice_from_raw_parts(p_out_array, size) }
+}
+
 /// Forms a slice from a pointer and a length.
 ///
 /// In contrast to [`std::slice::from_raw_parts`], this does not require that `data` must be
@@ -273,6 +409,75 @@ unsafe fn bool_iterator_from_raw_parts(
     slice.iter().map(|v| *v == vk::TRUE)
 }
 
+/// Enters the [`CommandSpan`] of an intercepted command, e.g.
+/// `command_span!("vkCreateImage", device)`.
+///
+/// The span is named after the command, so that subscribers that group spans by names, e.g. flame
+/// graphs, show one entry per command.
+#[cfg(feature = "tracing")]
+macro_rules! command_span {
+    ($command:literal, $handle:expr) => {
+        $crate::global_simple_intercept::CommandSpan::new(
+            tracing::trace_span!(
+                $command,
+                handle = ?$handle,
+                handled = tracing::field::Empty,
+                result = tracing::field::Empty,
+            )
+            .entered(),
+        )
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! command_span {
+    ($command:literal, $handle:expr) => {
+        $crate::global_simple_intercept::CommandSpan::new()
+    };
+}
+
+pub(crate) use command_span;
+
+/// The `tracing` span that covers a call to an intercepted command, including the hooks and the
+/// call to the next layer. The span is exited when it's dropped.
+///
+/// Without the `tracing` feature, this is an empty type, and all the methods are no-op.
+pub(crate) struct CommandSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+}
+
+impl CommandSpan {
+    #[cfg(feature = "tracing")]
+    pub(crate) fn new(span: tracing::span::EnteredSpan) -> Self {
+        Self { span }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    #[inline(always)]
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+
+    /// Records whether the layer returned [`LayerResult::Handled`](crate::LayerResult::Handled)
+    /// instead of calling into the next layer.
+    #[inline(always)]
+    pub(crate) fn record_handled(&self, handled: bool) {
+        #[cfg(feature = "tracing")]
+        self.span.record("handled", handled);
+        #[cfg(not(feature = "tracing"))]
+        let _ = handled;
+    }
+
+    /// Records the value returned to the caller of the command, and passes it through.
+    #[inline(always)]
+    pub(crate) fn record_result<T: Debug>(&self, result: T) -> T {
+        #[cfg(feature = "tracing")]
+        self.span.record("result", tracing::field::debug(&result));
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,6 +522,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn maybe_output_slice_from_raw_parts_should_use_the_written_size() {
+        let data = [1, 2, 3, 4];
+        let size = 2u32;
+        let output = unsafe { maybe_output_slice_from_raw_parts(data.as_ptr(), &size) };
+        assert_eq!(output, Some(&data[..2]));
+        let output = unsafe { maybe_output_slice_from_raw_parts(std::ptr::null::<i32>(), &size) };
+        assert!(output.is_none());
+        let output =
+            unsafe { maybe_output_slice_from_raw_parts(data.as_ptr(), std::ptr::null::<u32>()) };
+        assert!(output.is_none());
+    }
+
     #[test]
     fn maybe_uninit_slice_from_raw_parts_mut_null_data_ptr() {
         let input_ptr: *mut i32 = std::ptr::null_mut();
@@ -442,6 +660,42 @@ mod tests {
         unsafe { maybe_slice_from_raw_parts(std::ptr::NonNull::<u8>::dangling().as_ptr(), -1) };
     }
 
+    #[test]
+    fn command_lookup_table_hash_should_match_codegen() {
+        // The same values are tested in scripts/vulkan_layer_genvk/perfect_hash.py.
+        assert_eq!(
+            CommandLookupTable::hash("vkCreateDevice", 0),
+            (3720463724, 3450434761, 3690812648)
+        );
+    }
+
+    #[test]
+    fn hooked_commands_should_contain_inserted_commands() {
+        let hooked_commands = [
+            LayerVulkanCommand::CreateInstance,
+            LayerVulkanCommand::CmdDrawMeshTasksIndirectCountExt,
+        ]
+        .into_iter()
+        .collect::<HookedCommands>();
+        assert!(hooked_commands.contains(&LayerVulkanCommand::CreateInstance));
+        assert!(hooked_commands.contains(&LayerVulkanCommand::CmdDrawMeshTasksIndirectCountExt));
+        assert!(!hooked_commands.contains(&LayerVulkanCommand::CreateDevice));
+    }
+
+    #[test]
+    fn hooked_commands_union_should_contain_commands_from_both_sets() {
+        let mut hooked_commands = [LayerVulkanCommand::CreateInstance]
+            .into_iter()
+            .collect::<HookedCommands>();
+        hooked_commands.union(&[LayerVulkanCommand::CreateDevice].into_iter().collect());
+        assert!(hooked_commands.contains(&LayerVulkanCommand::CreateInstance));
+        assert!(hooked_commands.contains(&LayerVulkanCommand::CreateDevice));
+        assert!(!hooked_commands.contains(&LayerVulkanCommand::DestroyImage));
+
+        hooked_commands.union(&HookedCommands::all());
+        assert!(hooked_commands.contains(&LayerVulkanCommand::DestroyImage));
+    }
+
     #[test]
     fn extension_try_from_should_return_error_on_unknown_extension() {
         let unknown_extension = "VK_UNKNOWN_unknown";
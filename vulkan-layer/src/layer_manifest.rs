@@ -0,0 +1,568 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serialization of [`LayerManifest`] to the
+//! [layer manifest file](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#layer-manifest-file-format).
+
+use crate::{
+    layer_settings_prefix, ExtensionProperties, LayerManifest, LayerSettingKind,
+    LayerSettingMetadata, LayerSettingValue, LayerSettings, EXT_LAYER_SETTINGS_NAME,
+    EXT_LAYER_SETTINGS_SPEC_VERSION,
+};
+use ash::vk;
+use std::{fmt::Write, fs, io, path::Path};
+
+/// The layer manifest file format version generated by [`LayerManifest::to_json`].
+const FILE_FORMAT_VERSION: &str = "1.2.1";
+
+/// The subset of JSON values used by the layer manifest file.
+pub(crate) enum JsonValue {
+    String(String),
+    /// A boolean or a number written as is.
+    Literal(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl From<&str> for JsonValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl JsonValue {
+    pub(crate) fn write_string(out: &mut String, value: &str) {
+        out.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+
+    fn write_indent(out: &mut String, indent: usize) {
+        out.extend(std::iter::repeat_n("    ", indent));
+    }
+
+    fn write(&self, out: &mut String, indent: usize) {
+        match self {
+            Self::String(value) => Self::write_string(out, value),
+            Self::Literal(value) => out.push_str(value),
+            Self::Array(elements) if elements.is_empty() => out.push_str("[]"),
+            Self::Object(members) if members.is_empty() => out.push_str("{}"),
+            Self::Array(elements) => {
+                out.push_str("[\n");
+                for (i, element) in elements.iter().enumerate() {
+                    Self::write_indent(out, indent + 1);
+                    element.write(out, indent + 1);
+                    out.push_str(if i + 1 == elements.len() { "\n" } else { ",\n" });
+                }
+                Self::write_indent(out, indent);
+                out.push(']');
+            }
+            Self::Object(members) => {
+                out.push_str("{\n");
+                for (i, (name, value)) in members.iter().enumerate() {
+                    Self::write_indent(out, indent + 1);
+                    Self::write_string(out, name);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                    out.push_str(if i + 1 == members.len() { "\n" } else { ",\n" });
+                }
+                Self::write_indent(out, indent);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn api_version_to_string(version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        vk::api_version_major(version),
+        vk::api_version_minor(version),
+        vk::api_version_patch(version)
+    )
+}
+
+fn environment_to_json((name, value): (&str, &str)) -> JsonValue {
+    JsonValue::Object(vec![(name.to_owned(), value.into())])
+}
+
+/// Converts the text form of the default value of a setting to a JSON value of the setting type.
+fn setting_default_to_json(kind: LayerSettingKind, default: &str) -> JsonValue {
+    let mut layer_settings = LayerSettings::default();
+    layer_settings.insert(
+        "default",
+        LayerSettingValue::String(vec![default.to_owned()]),
+    );
+    let literal = match kind {
+        LayerSettingKind::Bool => layer_settings
+            .bool("default")
+            .map(|value| value.to_string()),
+        LayerSettingKind::Int => layer_settings
+            .int64("default")
+            .map(|value| value.to_string()),
+        LayerSettingKind::Float => layer_settings
+            .float64("default")
+            .filter(|value| value.is_finite())
+            .map(|value| value.to_string()),
+        LayerSettingKind::String | LayerSettingKind::File => None,
+    };
+    match literal {
+        Some(literal) => JsonValue::Literal(literal),
+        None => default.into(),
+    }
+}
+
+fn setting_to_json(layer_name: &str, setting: &LayerSettingMetadata) -> JsonValue {
+    let setting_type = match setting.kind {
+        LayerSettingKind::Bool => "BOOL",
+        LayerSettingKind::Int => "INT",
+        LayerSettingKind::Float => "FLOAT",
+        LayerSettingKind::String => "STRING",
+        LayerSettingKind::File => "FILE_LOAD",
+    };
+    let env = format!("VK_{}_{}", layer_settings_prefix(layer_name), setting.key).to_uppercase();
+    JsonValue::Object(vec![
+        ("key".to_owned(), setting.key.into()),
+        ("env".to_owned(), env.into()),
+        ("label".to_owned(), setting.label.into()),
+        ("description".to_owned(), setting.description.into()),
+        ("type".to_owned(), setting_type.into()),
+        (
+            "default".to_owned(),
+            setting_default_to_json(setting.kind, setting.default),
+        ),
+    ])
+}
+
+/// The `"pre_instance_functions"` JSON node that maps the pre-instance functions to the symbols
+/// exported by [`declare_introspection_queries`](crate::declare_introspection_queries).
+fn pre_instance_functions() -> JsonValue {
+    JsonValue::Object(
+        [
+            "vkEnumerateInstanceExtensionProperties",
+            "vkEnumerateInstanceLayerProperties",
+            "vkEnumerateInstanceVersion",
+        ]
+        .into_iter()
+        .map(|name| (name.to_owned(), name.replacen("vk", "vk_layer", 1).into()))
+        .collect(),
+    )
+}
+
+impl LayerManifest {
+    fn extensions_to_json(
+        &self,
+        extensions: &[ExtensionProperties],
+        with_entrypoints: bool,
+    ) -> Vec<JsonValue> {
+        extensions
+            .iter()
+            .map(|ExtensionProperties { name, spec_version }| {
+                let name_str: &str = name.clone().into();
+                let mut extension = vec![
+                    ("name".to_owned(), name_str.into()),
+                    ("spec_version".to_owned(), spec_version.to_string().into()),
+                ];
+                let entrypoints = self
+                    .device_extension_entrypoints
+                    .iter()
+                    .find(|(extension_name, _)| extension_name == name)
+                    .filter(|_| with_entrypoints);
+                if let Some((_, entrypoints)) = entrypoints {
+                    let entrypoints = entrypoints.iter().map(|entrypoint| (*entrypoint).into());
+                    extension.push((
+                        "entrypoints".to_owned(),
+                        JsonValue::Array(entrypoints.collect()),
+                    ));
+                }
+                JsonValue::Object(extension)
+            })
+            .collect()
+    }
+
+    /// Generates the layer manifest file content in the loader file format 1.2.1.
+    ///
+    /// `library_path` is written to the `"library_path"` JSON node as is. It is either a path
+    /// relative to the manifest file, or an absolute path. Every extension in
+    /// [`LayerManifest::device_extension_entrypoints`] must also be listed in
+    /// [`LayerManifest::device_extensions`], which is checked by a `debug_assert!`. The
+    /// `"pre_instance_functions"` JSON node is only written for implicit layers, i.e. when
+    /// [`LayerManifest::disable_environment`] is set, because the loader ignores pre-instance
+    /// functions of explicit layers. The `"features"` JSON node is written from
+    /// [`LayerManifest::settings`] with the environment variable of each setting documented in
+    /// [`LayerSettings::from_environment`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ash::vk;
+    /// # use vulkan_layer::LayerManifest;
+    /// let mut manifest = LayerManifest::default();
+    /// manifest.name = "VK_LAYER_VENDOR_rust_example";
+    /// manifest.spec_version = vk::API_VERSION_1_1;
+    /// manifest.description = "Rust test layer";
+    /// let json = manifest.to_json("./libVkLayer_vendor_rust_example.so");
+    /// assert!(json.contains(r#""api_version": "1.1.0""#));
+    /// ```
+    pub fn to_json(&self, library_path: &str) -> String {
+        for (extension_name, _) in self.device_extension_entrypoints {
+            debug_assert!(
+                self.device_extensions
+                    .iter()
+                    .any(|extension| extension.name == *extension_name),
+                "The entrypoints of {:?} are listed, but the extension is not in device_extensions.",
+                extension_name
+            );
+        }
+        let mut layer = vec![
+            ("name".to_owned(), self.name.into()),
+            ("type".to_owned(), "INSTANCE".into()),
+            ("library_path".to_owned(), library_path.into()),
+            (
+                "api_version".to_owned(),
+                api_version_to_string(self.spec_version).into(),
+            ),
+            (
+                "implementation_version".to_owned(),
+                self.implementation_version.to_string().into(),
+            ),
+            ("description".to_owned(), self.description.into()),
+        ];
+        let mut instance_extensions = self.extensions_to_json(self.instance_extensions, false);
+        if self.layer_settings_extension {
+            instance_extensions.push(JsonValue::Object(vec![
+                (
+                    "name".to_owned(),
+                    EXT_LAYER_SETTINGS_NAME.to_str().unwrap().into(),
+                ),
+                (
+                    "spec_version".to_owned(),
+                    EXT_LAYER_SETTINGS_SPEC_VERSION.to_string().into(),
+                ),
+            ]));
+        }
+        if !instance_extensions.is_empty() {
+            layer.push((
+                "instance_extensions".to_owned(),
+                JsonValue::Array(instance_extensions),
+            ));
+        }
+        if !self.device_extensions.is_empty() {
+            layer.push((
+                "device_extensions".to_owned(),
+                JsonValue::Array(self.extensions_to_json(self.device_extensions, true)),
+            ));
+        }
+        if let Some(enable_environment) = self.enable_environment {
+            layer.push((
+                "enable_environment".to_owned(),
+                environment_to_json(enable_environment),
+            ));
+        }
+        if let Some(disable_environment) = self.disable_environment {
+            layer.push((
+                "disable_environment".to_owned(),
+                environment_to_json(disable_environment),
+            ));
+            layer.push((
+                "pre_instance_functions".to_owned(),
+                pre_instance_functions(),
+            ));
+        }
+        if !self.settings.is_empty() {
+            let settings = self
+                .settings
+                .iter()
+                .map(|setting| setting_to_json(self.name, setting))
+                .collect();
+            layer.push((
+                "features".to_owned(),
+                JsonValue::Object(vec![("settings".to_owned(), JsonValue::Array(settings))]),
+            ));
+        }
+        let manifest = JsonValue::Object(vec![
+            ("file_format_version".to_owned(), FILE_FORMAT_VERSION.into()),
+            ("layer".to_owned(), JsonValue::Object(layer)),
+        ]);
+        let mut json = String::new();
+        manifest.write(&mut json, 0);
+        json.push('\n');
+        json
+    }
+}
+
+/// Writes the layer manifest file for a layer `cdylib` from a build script.
+///
+/// `library_name` is the name of the `cdylib` target, e.g. `VkLayer_vendor_rust_example`. The
+/// `"library_path"` JSON node is set to the platform-specific file name of that library relative
+/// to the manifest file, e.g. `./libVkLayer_vendor_rust_example.so` on Linux and
+/// `.\VkLayer_vendor_rust_example.dll` on Windows. The target platform is read from the
+/// `CARGO_CFG_TARGET_OS` environment variable set by cargo for build scripts, and falls back to
+/// the current platform if the environment variable is absent.
+///
+/// The layer implementation is recommended to share the function that creates the
+/// [`LayerManifest`] between [`Layer::manifest`](crate::Layer::manifest) and the build script, so
+/// that the manifest file never disagrees with the layer at runtime.
+///
+/// # Examples
+/// In `build.rs`:
+/// ```no_run
+/// # use ash::vk;
+/// # use vulkan_layer::{write_layer_manifest_json, LayerManifest};
+/// let mut manifest = LayerManifest::default();
+/// manifest.name = "VK_LAYER_VENDOR_rust_example";
+/// manifest.spec_version = vk::API_VERSION_1_1;
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// write_layer_manifest_json(
+///     &manifest,
+///     "VkLayer_vendor_rust_example",
+///     std::path::Path::new(&out_dir).join("rust_example_layer.json"),
+/// )
+/// .unwrap();
+/// ```
+pub fn write_layer_manifest_json(
+    manifest: &LayerManifest,
+    library_name: &str,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    let target_os =
+        std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_else(|_| std::env::consts::OS.to_owned());
+    let library_path = match target_os.as_str() {
+        "windows" => format!(".\\{}.dll", library_name),
+        "macos" | "ios" => format!("./lib{}.dylib", library_name),
+        _ => format!("./lib{}.so", library_name),
+    };
+    fs::write(path, manifest.to_json(&library_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Extension;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn to_json_should_round_trip_all_fields() {
+        let manifest = LayerManifest {
+            name: "VK_LAYER_VENDOR_rust_test",
+            spec_version: vk::make_api_version(0, 1, 3, 261),
+            implementation_version: 2,
+            description: "A \"quoted\" description\\\n\u{1}",
+            instance_extensions: &[ExtensionProperties {
+                name: Extension::EXTDebugUtils,
+                spec_version: 2,
+            }],
+            device_extensions: &[
+                ExtensionProperties {
+                    name: Extension::EXTToolingInfo,
+                    spec_version: 1,
+                },
+                ExtensionProperties {
+                    name: Extension::KHRSwapchain,
+                    spec_version: 70,
+                },
+            ],
+            device_extension_entrypoints: &[
+                (
+                    Extension::EXTToolingInfo,
+                    &["vkGetPhysicalDeviceToolPropertiesEXT"],
+                ),
+                (Extension::KHRSwapchain, &["vkCreateSwapchainKHR"]),
+            ],
+            enable_environment: Some(("ENABLE_RUST_TEST_LAYER", "1")),
+            disable_environment: Some(("DISABLE_RUST_TEST_LAYER", "1")),
+            layer_settings_extension: true,
+            settings: &[
+                LayerSettingMetadata {
+                    key: "log_level",
+                    label: "Log level",
+                    description: "Log messages at or above this level.",
+                    kind: LayerSettingKind::Int,
+                    default: "2",
+                },
+                LayerSettingMetadata {
+                    key: "enabled",
+                    label: "enabled",
+                    description: "",
+                    kind: LayerSettingKind::Bool,
+                    default: "true",
+                },
+                LayerSettingMetadata {
+                    key: "ratio",
+                    label: "ratio",
+                    description: "",
+                    kind: LayerSettingKind::Float,
+                    default: "0.5",
+                },
+                LayerSettingMetadata {
+                    key: "log_file",
+                    label: "log_file",
+                    description: "",
+                    kind: LayerSettingKind::File,
+                    default: "",
+                },
+            ],
+        };
+
+        let json = manifest.to_json("./libVkLayer_rust_test.so");
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "file_format_version": "1.2.1",
+                "layer": {
+                    "name": "VK_LAYER_VENDOR_rust_test",
+                    "type": "INSTANCE",
+                    "library_path": "./libVkLayer_rust_test.so",
+                    "api_version": "1.3.261",
+                    "implementation_version": "2",
+                    "description": "A \"quoted\" description\\\n\u{1}",
+                    "instance_extensions": [
+                        { "name": "VK_EXT_debug_utils", "spec_version": "2" },
+                        { "name": "VK_EXT_layer_settings", "spec_version": "2" }
+                    ],
+                    "device_extensions": [
+                        {
+                            "name": "VK_EXT_tooling_info",
+                            "spec_version": "1",
+                            "entrypoints": ["vkGetPhysicalDeviceToolPropertiesEXT"]
+                        },
+                        {
+                            "name": "VK_KHR_swapchain",
+                            "spec_version": "70",
+                            "entrypoints": ["vkCreateSwapchainKHR"]
+                        }
+                    ],
+                    "enable_environment": { "ENABLE_RUST_TEST_LAYER": "1" },
+                    "disable_environment": { "DISABLE_RUST_TEST_LAYER": "1" },
+                    "pre_instance_functions": {
+                        "vkEnumerateInstanceExtensionProperties":
+                            "vk_layerEnumerateInstanceExtensionProperties",
+                        "vkEnumerateInstanceLayerProperties": "vk_layerEnumerateInstanceLayerProperties",
+                        "vkEnumerateInstanceVersion": "vk_layerEnumerateInstanceVersion"
+                    },
+                    "features": {
+                        "settings": [
+                            {
+                                "key": "log_level",
+                                "env": "VK_VENDOR_RUST_TEST_LOG_LEVEL",
+                                "label": "Log level",
+                                "description": "Log messages at or above this level.",
+                                "type": "INT",
+                                "default": 2
+                            },
+                            {
+                                "key": "enabled",
+                                "env": "VK_VENDOR_RUST_TEST_ENABLED",
+                                "label": "enabled",
+                                "description": "",
+                                "type": "BOOL",
+                                "default": true
+                            },
+                            {
+                                "key": "ratio",
+                                "env": "VK_VENDOR_RUST_TEST_RATIO",
+                                "label": "ratio",
+                                "description": "",
+                                "type": "FLOAT",
+                                "default": 0.5
+                            },
+                            {
+                                "key": "log_file",
+                                "env": "VK_VENDOR_RUST_TEST_LOG_FILE",
+                                "label": "log_file",
+                                "description": "",
+                                "type": "FILE_LOAD",
+                                "default": ""
+                            }
+                        ]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn to_json_should_omit_empty_optional_fields() {
+        let manifest = LayerManifest {
+            name: "VK_LAYER_VENDOR_rust_test",
+            ..Default::default()
+        };
+        let value: Value = serde_json::from_str(&manifest.to_json("test.so")).unwrap();
+        let layer = value["layer"].as_object().unwrap();
+        for field in [
+            "instance_extensions",
+            "device_extensions",
+            "enable_environment",
+            "disable_environment",
+            "pre_instance_functions",
+            "features",
+        ] {
+            assert!(!layer.contains_key(field), "{} should be omitted", field);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "not in device_extensions")]
+    fn to_json_should_reject_entrypoints_of_unlisted_extensions() {
+        let manifest = LayerManifest {
+            name: "VK_LAYER_VENDOR_rust_test",
+            device_extensions: &[ExtensionProperties {
+                name: Extension::EXTToolingInfo,
+                spec_version: 1,
+            }],
+            device_extension_entrypoints: &[(
+                Extension::EXTDebugUtils,
+                &["vkSetDebugUtilsObjectNameEXT"],
+            )],
+            ..Default::default()
+        };
+        manifest.to_json("test.so");
+    }
+
+    #[test]
+    fn write_layer_manifest_json_should_write_the_manifest_file() {
+        let manifest = LayerManifest {
+            name: "VK_LAYER_VENDOR_rust_test",
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join(format!(
+            "vulkan_layer_manifest_test_{}.json",
+            std::process::id()
+        ));
+        write_layer_manifest_json(&manifest, "VkLayer_rust_test", &path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let value: Value = serde_json::from_str(&content).unwrap();
+        let library_path = value["layer"]["library_path"].as_str().unwrap();
+        assert!(library_path.contains("VkLayer_rust_test"));
+        assert_eq!(value["layer"]["name"], "VK_LAYER_VENDOR_rust_test");
+    }
+}
@@ -0,0 +1,966 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support of the
+//! [`VK_EXT_layer_settings`](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_layer_settings.html)
+//! extension.
+//!
+//! `ash` doesn't provide the bindings of `VK_EXT_layer_settings` yet, so the FFI types are
+//! defined here following the `ash` naming convention.
+
+use std::{
+    collections::{btree_map, BTreeMap},
+    env,
+    ffi::{c_char, c_void, CStr, OsString},
+    fs, io,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use ash::vk;
+use log::warn;
+
+use crate::{vk_utils::slice_from_raw_parts, VulkanBaseInStructChain};
+
+/// The name of the `VK_EXT_layer_settings` extension.
+pub const EXT_LAYER_SETTINGS_NAME: &CStr = c"VK_EXT_layer_settings";
+
+/// The spec version of the `VK_EXT_layer_settings` extension implemented by the layer framework.
+pub const EXT_LAYER_SETTINGS_SPEC_VERSION: u32 = 2;
+
+/// The file name of the Khronos layer settings file.
+pub const LAYER_SETTINGS_FILE_NAME: &str = "vk_layer_settings.txt";
+
+/// The environment variable that overrides the location of [`LAYER_SETTINGS_FILE_NAME`]. It can
+/// either point to the file itself or to the directory that contains the file.
+pub const LAYER_SETTINGS_PATH_ENV: &str = "VK_LAYER_SETTINGS_PATH";
+
+/// The `VK_STRUCTURE_TYPE_LAYER_SETTINGS_CREATE_INFO_EXT` structure type.
+pub const STRUCTURE_TYPE_LAYER_SETTINGS_CREATE_INFO_EXT: vk::StructureType =
+    vk::StructureType::from_raw(1_000_496_000);
+
+/// Bindings for the C
+/// [`VkLayerSettingTypeEXT`](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkLayerSettingTypeEXT.html)
+/// type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(transparent)]
+pub struct LayerSettingTypeEXT(i32);
+
+impl LayerSettingTypeEXT {
+    /// Constructs the enum from the raw value.
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+
+    /// Returns the raw value of the enum.
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+
+    /// `VK_LAYER_SETTING_TYPE_BOOL32_EXT`: the values are `VkBool32`.
+    pub const BOOL32: Self = Self(0);
+    /// `VK_LAYER_SETTING_TYPE_INT32_EXT`: the values are `int32_t`.
+    pub const INT32: Self = Self(1);
+    /// `VK_LAYER_SETTING_TYPE_INT64_EXT`: the values are `int64_t`.
+    pub const INT64: Self = Self(2);
+    /// `VK_LAYER_SETTING_TYPE_UINT32_EXT`: the values are `uint32_t`.
+    pub const UINT32: Self = Self(3);
+    /// `VK_LAYER_SETTING_TYPE_UINT64_EXT`: the values are `uint64_t`.
+    pub const UINT64: Self = Self(4);
+    /// `VK_LAYER_SETTING_TYPE_FLOAT32_EXT`: the values are `float`.
+    pub const FLOAT32: Self = Self(5);
+    /// `VK_LAYER_SETTING_TYPE_FLOAT64_EXT`: the values are `double`.
+    pub const FLOAT64: Self = Self(6);
+    /// `VK_LAYER_SETTING_TYPE_STRING_EXT`: the values are null-terminated UTF-8 strings.
+    pub const STRING: Self = Self(7);
+}
+
+/// Bindings for the C
+/// [`VkLayerSettingEXT`](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkLayerSettingEXT.html)
+/// type.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct LayerSettingEXT {
+    /// A pointer to a null-terminated UTF-8 string naming the layer to configure the setting from.
+    pub p_layer_name: *const c_char,
+    /// A pointer to a null-terminated UTF-8 string naming the setting to configure.
+    pub p_setting_name: *const c_char,
+    /// The type of values that `p_values` contains.
+    pub ty: LayerSettingTypeEXT,
+    /// The number of values used to configure the layer setting.
+    pub value_count: u32,
+    /// A pointer to an array of `value_count` values of the type indicated by `ty`.
+    pub p_values: *const c_void,
+}
+
+impl Default for LayerSettingEXT {
+    fn default() -> Self {
+        Self {
+            p_layer_name: std::ptr::null(),
+            p_setting_name: std::ptr::null(),
+            ty: LayerSettingTypeEXT::BOOL32,
+            value_count: 0,
+            p_values: std::ptr::null(),
+        }
+    }
+}
+
+/// Bindings for the C
+/// [`VkLayerSettingsCreateInfoEXT`](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkLayerSettingsCreateInfoEXT.html)
+/// type.
+///
+/// The structure can be chained to [`vk::InstanceCreateInfo`] with
+/// [`vk::InstanceCreateInfoBuilder::push_next`].
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct LayerSettingsCreateInfoEXT<'a> {
+    /// `VK_STRUCTURE_TYPE_LAYER_SETTINGS_CREATE_INFO_EXT`.
+    pub s_type: vk::StructureType,
+    /// `NULL` or a pointer to a structure extending this structure.
+    pub p_next: *const c_void,
+    /// The number of settings to configure.
+    pub setting_count: u32,
+    /// A pointer to an array of `setting_count` [`LayerSettingEXT`] values specifying the settings
+    /// to be configured.
+    pub p_settings: *const LayerSettingEXT,
+    _marker: PhantomData<&'a [LayerSettingEXT]>,
+}
+
+impl<'a> LayerSettingsCreateInfoEXT<'a> {
+    /// Creates the structure from a slice of [`LayerSettingEXT`].
+    pub fn new(settings: &'a [LayerSettingEXT]) -> Self {
+        Self {
+            s_type: STRUCTURE_TYPE_LAYER_SETTINGS_CREATE_INFO_EXT,
+            p_next: std::ptr::null(),
+            setting_count: settings.len().try_into().unwrap(),
+            p_settings: settings.as_ptr(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl Default for LayerSettingsCreateInfoEXT<'_> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+unsafe impl vk::ExtendsInstanceCreateInfo for LayerSettingsCreateInfoEXT<'_> {}
+
+/// The decoded values of a layer setting.
+///
+/// A setting always has an array of values. The variant is decided by the
+/// [`LayerSettingEXT::ty`] field provided by the application.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayerSettingValue {
+    /// `VK_LAYER_SETTING_TYPE_BOOL32_EXT` values.
+    Bool(Vec<bool>),
+    /// `VK_LAYER_SETTING_TYPE_INT32_EXT` values.
+    Int32(Vec<i32>),
+    /// `VK_LAYER_SETTING_TYPE_INT64_EXT` values.
+    Int64(Vec<i64>),
+    /// `VK_LAYER_SETTING_TYPE_UINT32_EXT` values.
+    Uint32(Vec<u32>),
+    /// `VK_LAYER_SETTING_TYPE_UINT64_EXT` values.
+    Uint64(Vec<u64>),
+    /// `VK_LAYER_SETTING_TYPE_FLOAT32_EXT` values.
+    Float32(Vec<f32>),
+    /// `VK_LAYER_SETTING_TYPE_FLOAT64_EXT` values.
+    Float64(Vec<f64>),
+    /// `VK_LAYER_SETTING_TYPE_STRING_EXT` values. Also used for file paths.
+    String(Vec<String>),
+}
+
+impl LayerSettingValue {
+    /// Decodes the values of a [`LayerSettingEXT`].
+    ///
+    /// Returns `None` if the type is unknown or a string is not valid UTF-8.
+    ///
+    /// # Safety
+    /// `setting.p_values` must be a valid pointer to an array of `setting.value_count` values of
+    /// the type indicated by `setting.ty`, if `setting.value_count` is not 0.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    unsafe fn from_raw(setting: &LayerSettingEXT) -> Option<Self> {
+        // Safe because the caller guarantees that `p_values` points to `value_count` elements of
+        // the type indicated by `ty`.
+        unsafe fn values<'a, T>(setting: &LayerSettingEXT) -> &'a [T] {
+            unsafe { slice_from_raw_parts(setting.p_values.cast::<T>(), setting.value_count) }
+        }
+        let value = unsafe {
+            match setting.ty {
+                LayerSettingTypeEXT::BOOL32 => Self::Bool(
+                    values::<vk::Bool32>(setting)
+                        .iter()
+                        .map(|value| *value != vk::FALSE)
+                        .collect(),
+                ),
+                LayerSettingTypeEXT::INT32 => Self::Int32(values(setting).to_vec()),
+                LayerSettingTypeEXT::INT64 => Self::Int64(values(setting).to_vec()),
+                LayerSettingTypeEXT::UINT32 => Self::Uint32(values(setting).to_vec()),
+                LayerSettingTypeEXT::UINT64 => Self::Uint64(values(setting).to_vec()),
+                LayerSettingTypeEXT::FLOAT32 => Self::Float32(values(setting).to_vec()),
+                LayerSettingTypeEXT::FLOAT64 => Self::Float64(values(setting).to_vec()),
+                LayerSettingTypeEXT::STRING => Self::String(
+                    values::<*const c_char>(setting)
+                        .iter()
+                        .map(|value| CStr::from_ptr(*value).to_str().map(str::to_owned))
+                        .collect::<Result<_, _>>()
+                        .ok()?,
+                ),
+                _ => return None,
+            }
+        };
+        Some(value)
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Bool(values) => values.len(),
+            Self::Int32(values) => values.len(),
+            Self::Int64(values) => values.len(),
+            Self::Uint32(values) => values.len(),
+            Self::Uint64(values) => values.len(),
+            Self::Float32(values) => values.len(),
+            Self::Float64(values) => values.len(),
+            Self::String(values) => values.len(),
+        }
+    }
+
+    /// Returns `true` if the setting has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn first_as_i128(&self) -> Option<i128> {
+        match self {
+            Self::Bool(values) => values.first().map(|value| *value as i128),
+            Self::Int32(values) => values.first().map(|value| *value as i128),
+            Self::Int64(values) => values.first().map(|value| *value as i128),
+            Self::Uint32(values) => values.first().map(|value| *value as i128),
+            Self::Uint64(values) => values.first().map(|value| *value as i128),
+            Self::Float32(_) | Self::Float64(_) => None,
+            Self::String(values) => values.first()?.trim().parse().ok(),
+        }
+    }
+
+    fn first_as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float32(values) => values.first().map(|value| *value as f64),
+            Self::Float64(values) => values.first().copied(),
+            Self::String(values) => values.first()?.trim().parse().ok(),
+            _ => self.first_as_i128().map(|value| value as f64),
+        }
+    }
+
+    fn first_as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(values) => values.first().copied(),
+            Self::String(values) => match values.first()?.trim() {
+                value if value.eq_ignore_ascii_case("true") => Some(true),
+                value if value.eq_ignore_ascii_case("false") => Some(false),
+                value => value.parse::<i64>().ok().map(|value| value != 0),
+            },
+            _ => self.first_as_i128().map(|value| value != 0),
+        }
+    }
+}
+
+/// The settings of this layer specified by the application through `VK_EXT_layer_settings`.
+///
+/// The layer framework collects every [`LayerSettingEXT`] whose `p_layer_name` matches
+/// [`LayerManifest::name`](crate::LayerManifest::name) from all [`LayerSettingsCreateInfoEXT`]
+/// structures in the `VkInstanceCreateInfo::pNext` chain, and passes the result to
+/// [`Layer::create_instance_info`](crate::Layer::create_instance_info). If the same setting is
+/// specified more than once, the last one wins.
+///
+/// The typed accessors read the first value of a setting, and convert between types the same way
+/// as the Khronos layer settings library: integers are range checked, booleans are converted from
+/// and to 0 and 1, and strings are parsed. An accessor returns `None` if the setting is absent or
+/// can't be converted to the requested type.
+///
+/// # Examples
+/// ```
+/// use vulkan_layer::{LayerSettingValue, LayerSettings};
+///
+/// let mut settings = LayerSettings::default();
+/// settings.insert("validate_sync", LayerSettingValue::String(vec!["true".to_owned()]));
+/// settings.insert("max_frames", LayerSettingValue::Int32(vec![16]));
+/// assert_eq!(settings.bool("validate_sync"), Some(true));
+/// assert_eq!(settings.uint32("max_frames"), Some(16));
+/// assert_eq!(settings.float32("max_frames"), Some(16.0));
+/// assert_eq!(settings.string("missing"), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayerSettings {
+    values: BTreeMap<String, LayerSettingValue>,
+}
+
+impl LayerSettings {
+    /// Collects the settings of the layer named `layer_name` from the `pNext` chain of
+    /// `create_info`.
+    ///
+    /// # Safety
+    /// `create_info` must follow the valid usage of `VkInstanceCreateInfo`, in particular, every
+    /// [`LayerSettingsCreateInfoEXT`] in the `pNext` chain must be valid.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub(crate) unsafe fn from_instance_create_info(
+        layer_name: &str,
+        create_info: &vk::InstanceCreateInfo,
+    ) -> Self {
+        let mut layer_settings = Self::default();
+        // Safe because the caller guarantees that the pNext chain is valid.
+        let p_next_chain: VulkanBaseInStructChain =
+            unsafe { create_info.p_next.cast::<vk::BaseInStructure>().as_ref() }.into();
+        for element in p_next_chain {
+            if element.s_type != STRUCTURE_TYPE_LAYER_SETTINGS_CREATE_INFO_EXT {
+                continue;
+            }
+            let settings_create_info = element as *const vk::BaseInStructure;
+            // Safe because the structure type is VK_STRUCTURE_TYPE_LAYER_SETTINGS_CREATE_INFO_EXT.
+            let settings_create_info =
+                unsafe { &*settings_create_info.cast::<LayerSettingsCreateInfoEXT>() };
+            // Safe because the caller guarantees that `p_settings` is a valid pointer to an array
+            // of `setting_count` elements.
+            let settings = unsafe {
+                slice_from_raw_parts(
+                    settings_create_info.p_settings,
+                    settings_create_info.setting_count,
+                )
+            };
+            for setting in settings {
+                if setting.p_layer_name.is_null() || setting.p_setting_name.is_null() {
+                    continue;
+                }
+                // Safe because both names must be valid null-terminated UTF-8 strings according
+                // to VUID-VkLayerSettingEXT-pLayerName-parameter and
+                // VUID-VkLayerSettingEXT-pSettingName-parameter.
+                let (setting_layer_name, setting_name) = unsafe {
+                    (
+                        CStr::from_ptr(setting.p_layer_name),
+                        CStr::from_ptr(setting.p_setting_name),
+                    )
+                };
+                if setting_layer_name.to_bytes() != layer_name.as_bytes() {
+                    continue;
+                }
+                let Ok(setting_name) = setting_name.to_str() else {
+                    warn!(
+                        "Ignore the layer setting with a non-UTF-8 name {:?}",
+                        setting_name
+                    );
+                    continue;
+                };
+                // Safe because the caller guarantees that the setting is valid.
+                match unsafe { LayerSettingValue::from_raw(setting) } {
+                    Some(value) => layer_settings.insert(setting_name, value),
+                    None => warn!(
+                        "Failed to decode the layer setting {} with type {:?}",
+                        setting_name, setting.ty
+                    ),
+                }
+            }
+        }
+        layer_settings
+    }
+
+    /// Collects the settings of the layer named `layer_name` from the environment variables.
+    ///
+    /// The setting `key` is read from the `VK_<PREFIX>_<KEY>` environment variable, where
+    /// `<PREFIX>` is the [settings prefix](layer_settings_prefix) of the layer in upper case, e.g.
+    /// `VK_KHRONOS_VALIDATION_DEBUG_ACTION` for the `debug_action` setting of
+    /// `VK_LAYER_KHRONOS_validation`. The values are kept as strings.
+    pub fn from_environment(layer_name: &str) -> Self {
+        Self::from_environment_variables(layer_name, env::vars_os())
+    }
+
+    fn from_environment_variables(
+        layer_name: &str,
+        variables: impl IntoIterator<Item = (OsString, OsString)>,
+    ) -> Self {
+        let prefix = format!("VK_{}_", layer_settings_prefix(layer_name).to_uppercase());
+        let mut layer_settings = Self::default();
+        for (name, value) in variables {
+            // Variables with non-UTF-8 names can't have the prefix.
+            let Some(key) = name.to_str().and_then(|name| name.strip_prefix(&prefix)) else {
+                continue;
+            };
+            let Ok(value) = value.into_string() else {
+                warn!(
+                    "Ignore the environment variable {} with a non-UTF-8 value.",
+                    name.to_string_lossy()
+                );
+                continue;
+            };
+            if key.is_empty() {
+                continue;
+            }
+            layer_settings.insert(key.to_lowercase(), LayerSettingValue::String(vec![value]));
+        }
+        layer_settings
+    }
+
+    /// Collects the settings of the layer named `layer_name` from the Khronos layer settings file.
+    ///
+    /// The file is located by the [`LAYER_SETTINGS_PATH_ENV`] environment variable if set,
+    /// otherwise [`LAYER_SETTINGS_FILE_NAME`] in the current working directory is used. An empty
+    /// [`LayerSettings`] is returned if the file doesn't exist. See
+    /// [`LayerSettings::parse_settings_file`] for the file format.
+    pub fn from_settings_file(layer_name: &str) -> Self {
+        let path = match env::var_os(LAYER_SETTINGS_PATH_ENV) {
+            Some(path) => {
+                let path = PathBuf::from(path);
+                if path.is_dir() {
+                    path.join(LAYER_SETTINGS_FILE_NAME)
+                } else {
+                    path
+                }
+            }
+            None => PathBuf::from(LAYER_SETTINGS_FILE_NAME),
+        };
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse_settings_file(layer_name, &content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                warn!(
+                    "Failed to read the layer settings file {}: {}",
+                    path.display(),
+                    e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Parses the settings of the layer named `layer_name` from the content of a Khronos layer
+    /// settings file.
+    ///
+    /// Each line of the file is in the `<prefix>.<key> = <value>` format, where `<prefix>` is the
+    /// [settings prefix](layer_settings_prefix) of the layer. Text after `#` is a comment. Lines
+    /// of other layers are ignored. The values are kept as strings.
+    ///
+    /// # Examples
+    /// ```
+    /// use vulkan_layer::LayerSettings;
+    ///
+    /// let settings = LayerSettings::parse_settings_file(
+    ///     "VK_LAYER_GOOGLE_example",
+    ///     "# Comment\n\
+    ///      google_example.log_level = 2\n\
+    ///      khronos_validation.log_level = 3\n",
+    /// );
+    /// assert_eq!(settings.uint32("log_level"), Some(2));
+    /// ```
+    pub fn parse_settings_file(layer_name: &str, content: &str) -> Self {
+        let prefix = format!("{}.", layer_settings_prefix(layer_name));
+        let mut layer_settings = Self::default();
+        for line in content.lines() {
+            let line = match line.split_once('#') {
+                Some((line, _)) => line,
+                None => line,
+            };
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let Some(key) = key.trim().strip_prefix(&prefix) else {
+                continue;
+            };
+            if key.is_empty() {
+                continue;
+            }
+            layer_settings.insert(
+                key,
+                LayerSettingValue::String(vec![value.trim().to_owned()]),
+            );
+        }
+        layer_settings
+    }
+
+    /// Combines the settings of the layer named `layer_name` from all sources.
+    ///
+    /// The precedence from the highest to the lowest is:
+    /// 1. The environment variables, see [`LayerSettings::from_environment`].
+    /// 2. The layer settings file, see [`LayerSettings::from_settings_file`].
+    /// 3. `api_settings`, which usually comes from `VK_EXT_layer_settings` and is passed to
+    ///    [`Layer::create_instance_info`](crate::Layer::create_instance_info).
+    ///
+    /// This follows the Khronos layer settings library, so that the user can override the settings
+    /// hard-coded in the application without recompiling it.
+    pub fn load(layer_name: &str, api_settings: &LayerSettings) -> Self {
+        let mut layer_settings = api_settings.clone();
+        layer_settings.extend(Self::from_settings_file(layer_name));
+        layer_settings.extend(Self::from_environment(layer_name));
+        layer_settings
+    }
+
+    /// Reads the setting `name` as `T`, or parses `default` as `T` if the setting is absent or
+    /// invalid.
+    ///
+    /// Used by `#[derive(LayerSettings)]`.
+    ///
+    /// # Panics
+    /// Panics if `default` can't be parsed as `T`. `#[derive(LayerSettings)]` rejects such
+    /// defaults at compile time for the built-in `bool`, integer and floating-point settings.
+    pub fn get_or_default<T: LayerSettingType>(&self, name: &str, default: &str) -> T {
+        if let Some(value) = T::from_layer_settings(self, name) {
+            return value;
+        }
+        if self.contains(name) {
+            warn!(
+                "Invalid value {:?} for the layer setting {}. Use the default value {:?}.",
+                self.get(name),
+                name,
+                default
+            );
+        }
+        let mut default_settings = Self::default();
+        default_settings.insert(name, LayerSettingValue::String(vec![default.to_owned()]));
+        T::from_layer_settings(&default_settings, name).unwrap_or_else(|| {
+            panic!(
+                "Invalid default value {:?} for the layer setting {}",
+                default, name
+            )
+        })
+    }
+
+    /// Sets the values of a setting, overriding the existing values if any.
+    pub fn insert(&mut self, name: impl Into<String>, value: LayerSettingValue) {
+        self.values.insert(name.into(), value);
+    }
+
+    /// Returns the raw values of the setting `name`.
+    pub fn get(&self, name: &str) -> Option<&LayerSettingValue> {
+        self.values.get(name)
+    }
+
+    /// Returns `true` if the setting `name` is specified.
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Returns `true` if no settings are specified.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates all settings in the order of the setting names.
+    pub fn iter(&self) -> btree_map::Iter<'_, String, LayerSettingValue> {
+        self.values.iter()
+    }
+
+    /// Reads the setting `name` as a boolean.
+    pub fn bool(&self, name: &str) -> Option<bool> {
+        self.get(name)?.first_as_bool()
+    }
+
+    /// Reads the setting `name` as an `int32_t`.
+    pub fn int32(&self, name: &str) -> Option<i32> {
+        self.get(name)?.first_as_i128()?.try_into().ok()
+    }
+
+    /// Reads the setting `name` as an `int64_t`.
+    pub fn int64(&self, name: &str) -> Option<i64> {
+        self.get(name)?.first_as_i128()?.try_into().ok()
+    }
+
+    /// Reads the setting `name` as a `uint32_t`.
+    pub fn uint32(&self, name: &str) -> Option<u32> {
+        self.get(name)?.first_as_i128()?.try_into().ok()
+    }
+
+    /// Reads the setting `name` as a `uint64_t`.
+    pub fn uint64(&self, name: &str) -> Option<u64> {
+        self.get(name)?.first_as_i128()?.try_into().ok()
+    }
+
+    /// Reads the setting `name` as a `float`.
+    pub fn float32(&self, name: &str) -> Option<f32> {
+        self.get(name)?.first_as_f64().map(|value| value as f32)
+    }
+
+    /// Reads the setting `name` as a `double`.
+    pub fn float64(&self, name: &str) -> Option<f64> {
+        self.get(name)?.first_as_f64()
+    }
+
+    /// Reads the setting `name` as a string. Only string settings can be read as strings.
+    pub fn string(&self, name: &str) -> Option<&str> {
+        self.strings(name)?.first().map(String::as_str)
+    }
+
+    /// Reads all values of the setting `name` as strings, e.g. a list of message IDs to mute.
+    pub fn strings(&self, name: &str) -> Option<&[String]> {
+        match self.get(name)? {
+            LayerSettingValue::String(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Reads the setting `name` as a file path. File settings are passed as strings.
+    pub fn file(&self, name: &str) -> Option<&Path> {
+        self.string(name).map(Path::new)
+    }
+}
+
+impl Extend<(String, LayerSettingValue)> for LayerSettings {
+    fn extend<I: IntoIterator<Item = (String, LayerSettingValue)>>(&mut self, iter: I) {
+        self.values.extend(iter);
+    }
+}
+
+impl IntoIterator for LayerSettings {
+    type Item = (String, LayerSettingValue);
+    type IntoIter = btree_map::IntoIter<String, LayerSettingValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a LayerSettings {
+    type Item = (&'a String, &'a LayerSettingValue);
+    type IntoIter = btree_map::Iter<'a, String, LayerSettingValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Returns the prefix of the setting keys of the layer named `layer_name` used by the Khronos
+/// layer settings file, e.g. `khronos_validation` for `VK_LAYER_KHRONOS_validation`.
+///
+/// The prefix is the layer name without the `VK_LAYER_` prefix in lower case.
+pub fn layer_settings_prefix(layer_name: &str) -> String {
+    layer_name
+        .strip_prefix("VK_LAYER_")
+        .unwrap_or(layer_name)
+        .to_lowercase()
+}
+
+/// The type of a layer setting in the `"settings"` JSON node of the layer manifest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayerSettingKind {
+    /// A boolean setting. The `"BOOL"` setting type.
+    Bool,
+    /// An integer setting. The `"INT"` setting type.
+    Int,
+    /// A floating-point setting. The `"FLOAT"` setting type.
+    Float,
+    /// A string setting. The `"STRING"` setting type.
+    String,
+    /// A path to a file. The `"FILE_LOAD"` setting type.
+    File,
+}
+
+/// The description of a layer setting, generated by `#[derive(LayerSettings)]`.
+///
+/// [`LayerManifest::to_json`](crate::LayerManifest::to_json) writes
+/// [`LayerManifest::settings`](crate::LayerManifest::settings) to the `"features"` JSON node of
+/// the manifest, so that tools like Vulkan Configurator can present the settings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayerSettingMetadata {
+    /// The key of the setting without the layer prefix.
+    pub key: &'static str,
+    /// The name of the setting presented to the user.
+    pub label: &'static str,
+    /// A description of the setting.
+    pub description: &'static str,
+    /// The type of the setting.
+    pub kind: LayerSettingKind,
+    /// The default value of the setting in the text form used by the settings file.
+    pub default: &'static str,
+}
+
+/// A type that can be used as the field type of a `#[derive(LayerSettings)]` struct.
+///
+/// Lists of strings are written as comma separated strings in the settings file and environment
+/// variables.
+pub trait LayerSettingType: Sized {
+    /// The type of the setting in the layer manifest.
+    const KIND: LayerSettingKind;
+
+    /// The default value in the text form if no default value is specified.
+    const DEFAULT: &'static str;
+
+    /// Reads the setting `name` from `layer_settings`. Returns `None` if the setting is absent or
+    /// can't be converted to this type.
+    fn from_layer_settings(layer_settings: &LayerSettings, name: &str) -> Option<Self>;
+}
+
+macro_rules! impl_layer_setting_type {
+    ($type:ty, $kind:ident, $default:literal, $accessor:ident) => {
+        impl LayerSettingType for $type {
+            const KIND: LayerSettingKind = LayerSettingKind::$kind;
+            const DEFAULT: &'static str = $default;
+
+            fn from_layer_settings(layer_settings: &LayerSettings, name: &str) -> Option<Self> {
+                layer_settings.$accessor(name)
+            }
+        }
+    };
+}
+
+impl_layer_setting_type!(bool, Bool, "false", bool);
+impl_layer_setting_type!(i32, Int, "0", int32);
+impl_layer_setting_type!(i64, Int, "0", int64);
+impl_layer_setting_type!(u32, Int, "0", uint32);
+impl_layer_setting_type!(u64, Int, "0", uint64);
+impl_layer_setting_type!(f32, Float, "0.0", float32);
+impl_layer_setting_type!(f64, Float, "0.0", float64);
+
+impl LayerSettingType for String {
+    const KIND: LayerSettingKind = LayerSettingKind::String;
+    const DEFAULT: &'static str = "";
+
+    fn from_layer_settings(layer_settings: &LayerSettings, name: &str) -> Option<Self> {
+        layer_settings.string(name).map(str::to_owned)
+    }
+}
+
+impl LayerSettingType for PathBuf {
+    const KIND: LayerSettingKind = LayerSettingKind::File;
+    const DEFAULT: &'static str = "";
+
+    fn from_layer_settings(layer_settings: &LayerSettings, name: &str) -> Option<Self> {
+        layer_settings.file(name).map(Path::to_path_buf)
+    }
+}
+
+impl LayerSettingType for Vec<String> {
+    const KIND: LayerSettingKind = LayerSettingKind::String;
+    const DEFAULT: &'static str = "";
+
+    fn from_layer_settings(layer_settings: &LayerSettings, name: &str) -> Option<Self> {
+        let values = layer_settings
+            .strings(name)?
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned)
+            .collect();
+        Some(values)
+    }
+}
+
+/// A typed layer settings schema, usually implemented with `#[derive(LayerSettings)]`.
+///
+/// # Examples
+/// ```
+/// use std::path::PathBuf;
+/// use vulkan_layer::{LayerSettingValue, LayerSettings, LayerSettingsSchema};
+///
+/// #[derive(LayerSettings)]
+/// struct MySettings {
+///     /// Log messages at or above this level.
+///     #[layer_setting(default = 2)]
+///     log_level: u32,
+///     /// The file to write the log to.
+///     #[layer_setting(key = "log_file", label = "Log file")]
+///     log_path: PathBuf,
+///     /// Message IDs to mute.
+///     muted_messages: Vec<String>,
+/// }
+///
+/// let mut api_settings = LayerSettings::default();
+/// api_settings.insert("muted_messages", LayerSettingValue::String(vec!["a, b".to_owned()]));
+/// let settings = MySettings::from_layer_settings(&api_settings);
+/// assert_eq!(settings.log_level, 2);
+/// assert_eq!(settings.log_path, PathBuf::new());
+/// assert_eq!(settings.muted_messages, ["a", "b"]);
+/// assert_eq!(MySettings::SETTINGS[1].key, "log_file");
+/// ```
+pub trait LayerSettingsSchema: Sized {
+    /// The descriptions of all settings. Can be assigned to
+    /// [`LayerManifest::settings`](crate::LayerManifest::settings).
+    const SETTINGS: &'static [LayerSettingMetadata];
+
+    /// Reads all settings from `layer_settings`. Absent or invalid settings are set to their
+    /// default values.
+    fn from_layer_settings(layer_settings: &LayerSettings) -> Self;
+
+    /// Reads all settings of the layer named `layer_name` from all sources with the precedence
+    /// documented in [`LayerSettings::load`].
+    fn load(layer_name: &str, api_settings: &LayerSettings) -> Self {
+        Self::from_layer_settings(&LayerSettings::load(layer_name, api_settings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_instance_create_info_should_only_collect_settings_of_the_layer() {
+        let enabled = vk::TRUE;
+        let level = [3i32];
+        let paths = [c"a.txt".as_ptr(), c"b.txt".as_ptr()];
+        let settings = [
+            LayerSettingEXT {
+                p_layer_name: c"VK_LAYER_GOOGLE_test".as_ptr(),
+                p_setting_name: c"enabled".as_ptr(),
+                ty: LayerSettingTypeEXT::BOOL32,
+                value_count: 1,
+                p_values: &enabled as *const _ as *const c_void,
+            },
+            LayerSettingEXT {
+                p_layer_name: c"VK_LAYER_GOOGLE_other".as_ptr(),
+                p_setting_name: c"level".as_ptr(),
+                ty: LayerSettingTypeEXT::INT32,
+                value_count: 1,
+                p_values: level.as_ptr().cast(),
+            },
+            LayerSettingEXT {
+                p_layer_name: c"VK_LAYER_GOOGLE_test".as_ptr(),
+                p_setting_name: c"paths".as_ptr(),
+                ty: LayerSettingTypeEXT::STRING,
+                value_count: paths.len() as u32,
+                p_values: paths.as_ptr().cast(),
+            },
+        ];
+        let mut settings_create_info = LayerSettingsCreateInfoEXT::new(&settings);
+        let create_info = vk::InstanceCreateInfo::builder().push_next(&mut settings_create_info);
+
+        let layer_settings = unsafe {
+            LayerSettings::from_instance_create_info("VK_LAYER_GOOGLE_test", &create_info)
+        };
+        assert_eq!(
+            layer_settings
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            ["enabled", "paths"]
+        );
+        assert_eq!(layer_settings.bool("enabled"), Some(true));
+        assert_eq!(layer_settings.int32("level"), None);
+        assert_eq!(
+            layer_settings.strings("paths"),
+            Some(&["a.txt".to_owned(), "b.txt".to_owned()][..])
+        );
+        assert_eq!(layer_settings.file("paths"), Some(Path::new("a.txt")));
+    }
+
+    #[test]
+    fn from_environment_variables_should_only_collect_variables_with_the_layer_prefix() {
+        let layer_settings = LayerSettings::from_environment_variables(
+            "VK_LAYER_GOOGLE_test",
+            [
+                ("VK_GOOGLE_TEST_LOG_LEVEL", "3"),
+                ("VK_GOOGLE_TEST_", "ignored"),
+                ("VK_KHRONOS_VALIDATION_LOG_LEVEL", "4"),
+                ("PATH", "/usr/bin"),
+            ]
+            .map(|(name, value)| (name.into(), value.into())),
+        );
+        assert_eq!(
+            layer_settings.iter().collect::<Vec<_>>(),
+            [(
+                &"log_level".to_owned(),
+                &LayerSettingValue::String(vec!["3".to_owned()])
+            )]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn from_environment_variables_should_skip_non_utf8_variables() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let layer_settings = LayerSettings::from_environment_variables(
+            "VK_LAYER_GOOGLE_test",
+            [
+                (
+                    OsString::from_vec(b"VK_GOOGLE_TEST_\xff".to_vec()),
+                    "1".into(),
+                ),
+                (
+                    "VK_GOOGLE_TEST_LOG_FILE".into(),
+                    OsString::from_vec(b"\xff.txt".to_vec()),
+                ),
+                ("VK_GOOGLE_TEST_LOG_LEVEL".into(), "3".into()),
+            ],
+        );
+        assert_eq!(
+            layer_settings.iter().collect::<Vec<_>>(),
+            [(
+                &"log_level".to_owned(),
+                &LayerSettingValue::String(vec!["3".to_owned()])
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_settings_file_should_skip_comments_and_other_layers() {
+        let layer_settings = LayerSettings::parse_settings_file(
+            "VK_LAYER_GOOGLE_test",
+            "# google_test.commented = 1\n\
+             google_test.enabled=true # trailing comment\n\
+             \n\
+             google_test.message_ids = a, b\n\
+             khronos_validation.enabled = false\n\
+             google_test.no_equal_sign\n",
+        );
+        assert_eq!(
+            layer_settings
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            ["enabled", "message_ids"]
+        );
+        assert_eq!(layer_settings.bool("enabled"), Some(true));
+        assert_eq!(
+            Vec::<String>::from_layer_settings(&layer_settings, "message_ids"),
+            Some(vec!["a".to_owned(), "b".to_owned()])
+        );
+    }
+
+    #[test]
+    fn get_or_default_should_fall_back_to_the_default_value() {
+        let mut layer_settings = LayerSettings::default();
+        layer_settings.insert("invalid", LayerSettingValue::String(vec!["abc".to_owned()]));
+        layer_settings.insert("valid", LayerSettingValue::Uint32(vec![7]));
+        assert_eq!(layer_settings.get_or_default::<u32>("valid", "1"), 7);
+        assert_eq!(layer_settings.get_or_default::<u32>("invalid", "1"), 1);
+        assert_eq!(layer_settings.get_or_default::<u32>("missing", "1"), 1);
+        assert_eq!(layer_settings.get_or_default::<String>("missing", ""), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default value")]
+    fn get_or_default_should_panic_with_invalid_default_value() {
+        LayerSettings::default().get_or_default::<bool>("missing", "maybe");
+    }
+
+    #[test]
+    fn typed_accessors_should_convert_between_types() {
+        let mut settings = LayerSettings::default();
+        settings.insert("negative", LayerSettingValue::Int64(vec![-1]));
+        settings.insert("large", LayerSettingValue::Uint64(vec![u64::MAX]));
+        settings.insert("text", LayerSettingValue::String(vec![" 42 ".to_owned()]));
+        settings.insert("float", LayerSettingValue::Float64(vec![0.5]));
+        settings.insert("empty", LayerSettingValue::Bool(vec![]));
+
+        assert_eq!(settings.int32("negative"), Some(-1));
+        assert_eq!(settings.uint32("negative"), None);
+        assert_eq!(settings.bool("negative"), Some(true));
+        assert_eq!(settings.uint64("large"), Some(u64::MAX));
+        assert_eq!(settings.int64("large"), None);
+        assert_eq!(settings.uint32("text"), Some(42));
+        assert_eq!(settings.float32("text"), Some(42.0));
+        assert_eq!(settings.float32("float"), Some(0.5));
+        assert_eq!(settings.int32("float"), None);
+        assert_eq!(settings.string("float"), None);
+        assert_eq!(settings.bool("empty"), None);
+    }
+}
@@ -14,9 +14,25 @@
 
 //! This module includes all the traits that require the layer implementation to implement.
 
-use crate::{bindings::vk_layer::VkLayerInstanceLink, global_simple_intercept::Extension, Global};
-use ash::vk;
-use std::{borrow::Borrow, ffi::CString, ops::Deref, sync::Arc};
+use crate::{
+    bindings::vk_layer::{
+        PFN_vkSetDeviceLoaderData, PFN_vkSetInstanceLoaderData,
+        VkEnumerateInstanceExtensionPropertiesChain, VkEnumerateInstanceLayerPropertiesChain,
+        VkEnumerateInstanceVersionChain, VkLayerInstanceLink,
+    },
+    global_simple_intercept::Extension,
+    layer_settings::{LayerSettingMetadata, LayerSettings},
+    CommandObserver, FaultInjector, Global, QueueProperties, ThreadSafetyChecker,
+};
+use ash::{prelude::VkResult, vk};
+use std::{
+    borrow::Borrow,
+    convert::Infallible,
+    ffi::{c_void, CStr, CString},
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::Arc,
+};
 use thiserror::Error;
 
 pub mod generated;
@@ -29,9 +45,26 @@ pub use generated::*;
 /// layer implementation returns [`LayerResult::Handled`], the layer framework won't call into the
 /// next layer call chain. Otherwise, if [`LayerResult::Unhandled`] is returned, the layer framework
 /// will call into the next layer call chain with the original parameters.
+///
+/// To observe the results of the next layer call chain, e.g. to record the `VkImage` returned from
+/// `vkCreateImage`, the layer implementation can override the `after_*` counterpart of the hook,
+/// e.g. [`DeviceHooks::after_create_image`]. The `after_*` method is called after the next layer
+/// call chain returns with the original parameters and the results. The output parameters are
+/// passed as initialized values or references, e.g. `u32` instead of `&mut MaybeUninit<u32>`, and
+/// an output array is passed as a slice of the length the next layer call chain wrote, e.g.
+/// `Option<&[vk::Image]>` for `vkGetSwapchainImagesKHR`, which is `None` if the caller only queries
+/// the length. For commands that return `VkResult`, the outputs are wrapped in an `Option`, which
+/// is `None` if the command fails, i.e. returns a negative `VkResult`, and the raw `vk::Result` is
+/// passed as the last parameter, so that the outputs returned with success codes, e.g.
+/// `VK_SUBOPTIMAL_KHR` from `vkAcquireNextImageKHR`, are still observed. The `after_*` method isn't
+/// called if [`LayerResult::Handled`] is returned.
+///
+/// To call into the next layer call chain from a hook, e.g. to issue extra commands before
+/// returning [`LayerResult::Handled`], use the dispatch tables returned by
+/// [`Global::next_instance_dispatch`] and [`Global::next_device_dispatch`].
 #[must_use]
 #[derive(Clone)]
-pub enum LayerResult<T> {
+pub enum LayerResult<T, Args = Infallible> {
     /// The layer implementation has handled this Vulkan command, so the layer framework shouldn't
     /// try to call into the next call chain.
     ///
@@ -44,10 +77,63 @@ pub enum LayerResult<T> {
     /// call into the next call chain with the original parameters.
     ///
     /// If the layer implementation doesn't want to bother calling into the next call chain, and
-    /// wants the layer framework to do the job, this variant can be used. Note that if the layer
-    /// implementation needs to inform the layer framework that there is an error and needs to
+    /// wants the layer framework to do the job, this variant can be used. The `after_*`
+    /// counterpart of the hook will be called after the next call chain returns. Note that if the
+    /// layer implementation needs to inform the layer framework that there is an error and needs to
     /// return early, [`LayerResult::Handled`] should be used.
     Unhandled,
+    /// The layer implementation has rewritten the parameters of this Vulkan command, so the layer
+    /// framework should call into the next call chain with the rewritten parameters.
+    ///
+    /// This can be used if the layer implementation only wants to change some parameters, e.g. to
+    /// add a usage flag to the `VkImageCreateInfo` passed to `vkCreateImage`. The output parameters
+    /// are still written back to the caller like [`LayerResult::Unhandled`], and the `after_*`
+    /// counterpart of the hook is called with the original parameters.
+    ///
+    /// `Args` is the arguments struct of the intercepted command in
+    /// [`command_args`](crate::command_args), e.g.
+    /// [`CreateImageArgs`](crate::command_args::CreateImageArgs) for `vkCreateImage`. The commands
+    /// that the layer framework handles specially, e.g. `vkCreateDevice`, can't be rewritten, and
+    /// their hooks return a `LayerResult` whose `Args` is [`Infallible`], so this variant can't be
+    /// constructed.
+    ///
+    /// Pointers in the rewritten parameters, e.g. the `p_next` chain of a create info, must stay
+    /// valid until the intercepted command returns. Slices that share the same length in the
+    /// Vulkan API must have the same length.
+    ///
+    /// # Panics
+    ///
+    /// The layer framework panics if the rewritten parameters change the length of an array that
+    /// the caller allocates for the results, e.g. the `command_buffer_count` of the
+    /// `VkCommandBufferAllocateInfo` passed to `vkAllocateCommandBuffers`, or if slices that share
+    /// the same length have different lengths.
+    Continue(Args),
+}
+
+impl<T, Args> LayerResult<T, Args> {
+    /// Creates a [`LayerResult::Continue`] that calls into the next call chain with `args`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ash::{prelude::VkResult, vk};
+    /// # use vulkan_layer::{command_args::CreateImageArgs, LayerResult};
+    /// fn create_image(
+    ///     create_info: &vk::ImageCreateInfo,
+    ///     p_allocator: Option<&vk::AllocationCallbacks>,
+    /// ) -> LayerResult<VkResult<vk::Image>, CreateImageArgs> {
+    ///     LayerResult::continue_with(CreateImageArgs {
+    ///         p_create_info: vk::ImageCreateInfo {
+    ///             usage: create_info.usage | vk::ImageUsageFlags::TRANSFER_SRC,
+    ///             ..*create_info
+    ///         },
+    ///         p_allocator: p_allocator.copied(),
+    ///     })
+    /// }
+    /// ```
+    pub fn continue_with(args: Args) -> Self {
+        Self::Continue(args)
+    }
 }
 
 #[derive(Error, Debug)]
@@ -72,7 +158,10 @@ pub enum TryFromVulkanCommandError {
 /// A layer that needs to intercept the `vkCreateImage` function.
 /// ```
 /// # use ash::{vk, prelude::VkResult};
-/// # use vulkan_layer::{DeviceHooks, DeviceInfo, LayerVulkanCommand as VulkanCommand, LayerResult};
+/// # use vulkan_layer::{
+/// #     command_args::CreateImageArgs, DeviceHooks, DeviceInfo, LayerResult,
+/// #     LayerVulkanCommand as VulkanCommand,
+/// # };
 /// #
 /// struct MyLayerDeviceInfo;
 ///
@@ -81,7 +170,7 @@ pub enum TryFromVulkanCommandError {
 ///         &self,
 ///         _p_create_info: &vk::ImageCreateInfo,
 ///         _p_allocator: Option<&vk::AllocationCallbacks>,
-///     ) -> LayerResult<VkResult<vk::Image>> {
+///     ) -> LayerResult<VkResult<vk::Image>, CreateImageArgs> {
 ///         LayerResult::Unhandled
 ///     }
 /// }
@@ -171,9 +260,9 @@ pub trait DeviceInfo: Send + Sync {
     /// use once_cell::sync::Lazy;
     /// use std::sync::Arc;
     /// use vulkan_layer::{
-    ///     DeviceHooks, DeviceInfo, Global, Layer, LayerManifest, LayerResult,
-    ///     LayerVulkanCommand as VulkanCommand, StubGlobalHooks, StubInstanceInfo,
-    ///     VulkanBaseInStructChain,
+    ///     command_args::CreateImageArgs, DeviceHooks, DeviceInfo, DeviceLoaderDataCallback, Global,
+    ///     Layer, LayerManifest, LayerResult, LayerSettings, LayerVulkanCommand as VulkanCommand,
+    ///     StubGlobalHooks, StubInstanceInfo, VulkanBaseInStructChain,
     /// };
     ///
     /// struct MyLayerDeviceInfo {
@@ -185,7 +274,7 @@ pub trait DeviceInfo: Send + Sync {
     ///         &self,
     ///         create_info: &vk::ImageCreateInfo,
     ///         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ///     ) -> LayerResult<VkResult<vk::Image>> {
+    ///     ) -> LayerResult<VkResult<vk::Image>, CreateImageArgs> {
     ///         if create_info.format == vk::Format::ASTC_4X4_UNORM_BLOCK {
     ///             println!("ASTC 4x4 UNORM image created.");
     ///         }
@@ -216,6 +305,9 @@ pub trait DeviceInfo: Send + Sync {
     ///     type DeviceInfo = MyLayerDeviceInfo;
     /// #     type InstanceInfoContainer = StubInstanceInfo;
     ///     type DeviceInfoContainer = MyLayerDeviceInfo;
+    ///     type PhysicalDeviceInfo = ();
+    ///     type QueueInfo = ();
+    ///     type CommandBufferInfo = ();
     /// #
     /// #     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
     /// #         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -236,6 +328,7 @@ pub trait DeviceInfo: Send + Sync {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Instance>,
     /// #         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    /// #         _: &LayerSettings,
     /// #     ) -> Self::InstanceInfoContainer {
     /// #         Default::default()
     /// #     }
@@ -247,6 +340,7 @@ pub trait DeviceInfo: Send + Sync {
     ///         _: Option<&vk::AllocationCallbacks>,
     ///         _: Arc<ash::Device>,
     ///         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    ///         _: DeviceLoaderDataCallback,
     ///     ) -> Self::DeviceInfoContainer {
     ///         let mut p_next_chain: VulkanBaseInStructChain =
     ///             unsafe { (create_info.p_next as *const vk::BaseInStructure).as_ref() }.into();
@@ -324,7 +418,8 @@ pub trait DeviceInfo: Send + Sync {
 /// ```
 /// use ash::{prelude::VkResult, vk};
 /// use vulkan_layer::{
-///     InstanceHooks, InstanceInfo, LayerResult, LayerVulkanCommand as VulkanCommand,
+///     command_args::CreateWin32SurfaceKhrArgs, InstanceHooks, InstanceInfo, LayerResult,
+///     LayerVulkanCommand as VulkanCommand,
 /// };
 ///
 /// struct MyLayerInstanceHooks;
@@ -334,7 +429,7 @@ pub trait DeviceInfo: Send + Sync {
 ///         &self,
 ///         _p_create_info: &vk::Win32SurfaceCreateInfoKHR,
 ///         _p_allocator: Option<&vk::AllocationCallbacks>,
-///     ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+///     ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateWin32SurfaceKhrArgs> {
 ///         LayerResult::Unhandled
 ///     }
 /// }
@@ -430,7 +525,8 @@ pub trait InstanceInfo: Send + Sync {
     /// use once_cell::sync::Lazy;
     /// use std::{ffi::CStr, sync::Arc};
     /// use vulkan_layer::{
-    ///     Global, InstanceHooks, InstanceInfo, Layer, LayerManifest, LayerResult,
+    ///     command_args::DestroySurfaceKhrArgs, DeviceLoaderDataCallback, Global, InstanceHooks,
+    ///     InstanceInfo, Layer, LayerManifest, LayerResult, LayerSettings,
     ///     LayerVulkanCommand as VulkanCommand, StubDeviceInfo, StubGlobalHooks,
     /// };
     ///
@@ -443,7 +539,7 @@ pub trait InstanceInfo: Send + Sync {
     ///         &self,
     ///         _surface: vk::SurfaceKHR,
     ///         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ///     ) -> LayerResult<()> {
+    ///     ) -> LayerResult<(), DestroySurfaceKhrArgs> {
     ///         LayerResult::Unhandled
     ///     }
     /// }
@@ -471,6 +567,9 @@ pub trait InstanceInfo: Send + Sync {
     /// #     type DeviceInfo = StubDeviceInfo;
     ///     type InstanceInfoContainer = MyLayerInstanceInfo;
     /// #     type DeviceInfoContainer = StubDeviceInfo;
+    /// #     type PhysicalDeviceInfo = ();
+    /// #     type QueueInfo = ();
+    /// #     type CommandBufferInfo = ();
     /// #
     /// #     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
     /// #         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -491,6 +590,7 @@ pub trait InstanceInfo: Send + Sync {
     ///         _: Option<&vk::AllocationCallbacks>,
     ///         _: Arc<ash::Instance>,
     ///         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    ///         _: &LayerSettings,
     ///     ) -> Self::InstanceInfoContainer {
     ///         let enabled_extensions = if create_info.enabled_extension_count > 0 {
     ///             unsafe {
@@ -537,6 +637,7 @@ pub trait InstanceInfo: Send + Sync {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Device>,
     /// #         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    /// #         _: DeviceLoaderDataCallback,
     /// #     ) -> Self::DeviceInfoContainer {
     /// #         Default::default()
     /// #     }
@@ -560,7 +661,11 @@ pub trait InstanceInfo: Send + Sync {
 /// * `vkEnumerateInstanceLayerProperties`
 /// * `vkCreateInstance`
 ///
-/// Currently, it is only supported to intercept the `vkCreateInstance` function.
+/// `vkEnumerateInstanceVersion`, `vkEnumerateInstanceExtensionProperties`, and
+/// `vkEnumerateInstanceLayerProperties` are intercepted through the
+/// [pre-instance functions](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#pre-instance-functions)
+/// interface of the Vulkan loader, which is only available to implicit layers. See
+/// [`LayerManifest::disable_environment`] for how to declare an implicit layer.
 pub trait GlobalHooks: Send + Sync {
     /// The logic to intercept the `vkCreateInstance` function.
     ///
@@ -667,6 +772,78 @@ pub trait GlobalHooks: Send + Sync {
     ) -> LayerResult<ash::prelude::VkResult<()>> {
         LayerResult::Unhandled
     }
+
+    /// The logic to intercept the pre-instance `vkEnumerateInstanceVersion` function.
+    ///
+    /// The implementations should return [`LayerResult::Unhandled`] if the layer framework should
+    /// call into the next layer with the original arguments, otherwise should return
+    /// [`LayerResult::Handled`] with the return value of the command. The implementation can use
+    /// [`VkEnumerateInstanceVersionChain::call_down`] to call into the next layer.
+    ///
+    /// # Examples
+    /// A layer that clamps the instance version to Vulkan 1.1.
+    /// ```
+    /// use ash::vk;
+    /// use std::mem::MaybeUninit;
+    /// use vulkan_layer::{GlobalHooks, LayerResult, VkEnumerateInstanceVersionChain};
+    ///
+    /// struct MyGlobalHooks;
+    ///
+    /// impl GlobalHooks for MyGlobalHooks {
+    ///     fn enumerate_instance_version(
+    ///         &self,
+    ///         chain: &VkEnumerateInstanceVersionChain,
+    ///         p_api_version: &mut MaybeUninit<u32>,
+    ///     ) -> LayerResult<vk::Result> {
+    ///         let res = unsafe { chain.call_down(p_api_version.as_mut_ptr()) };
+    ///         if res == vk::Result::SUCCESS {
+    ///             let api_version = unsafe { p_api_version.assume_init_mut() };
+    ///             *api_version = (*api_version).min(vk::API_VERSION_1_1);
+    ///         }
+    ///         LayerResult::Handled(res)
+    ///     }
+    /// }
+    /// ```
+    fn enumerate_instance_version(
+        &self,
+        _chain: &VkEnumerateInstanceVersionChain,
+        _p_api_version: &mut MaybeUninit<u32>,
+    ) -> LayerResult<vk::Result> {
+        LayerResult::Unhandled
+    }
+
+    /// The logic to intercept the pre-instance `vkEnumerateInstanceExtensionProperties` function.
+    ///
+    /// The implementations should return [`LayerResult::Unhandled`] if the layer framework should
+    /// call into the next layer with the original arguments, otherwise should return
+    /// [`LayerResult::Handled`] with the return value of the command, e.g. `VK_INCOMPLETE`. The
+    /// implementation can use [`VkEnumerateInstanceExtensionPropertiesChain::call_down`] to call
+    /// into the next layer, and filter the result.
+    fn enumerate_instance_extension_properties(
+        &self,
+        _chain: &VkEnumerateInstanceExtensionPropertiesChain,
+        _p_layer_name: Option<&CStr>,
+        _p_property_count: &mut MaybeUninit<u32>,
+        _p_properties: Option<&mut [MaybeUninit<vk::ExtensionProperties>]>,
+    ) -> LayerResult<vk::Result> {
+        LayerResult::Unhandled
+    }
+
+    /// The logic to intercept the pre-instance `vkEnumerateInstanceLayerProperties` function.
+    ///
+    /// The implementations should return [`LayerResult::Unhandled`] if the layer framework should
+    /// call into the next layer with the original arguments, otherwise should return
+    /// [`LayerResult::Handled`] with the return value of the command, e.g. `VK_INCOMPLETE`. The
+    /// implementation can use [`VkEnumerateInstanceLayerPropertiesChain::call_down`] to call into
+    /// the next layer, and filter the result.
+    fn enumerate_instance_layer_properties(
+        &self,
+        _chain: &VkEnumerateInstanceLayerPropertiesChain,
+        _p_property_count: &mut MaybeUninit<u32>,
+        _p_properties: Option<&mut [MaybeUninit<vk::LayerProperties>]>,
+    ) -> LayerResult<vk::Result> {
+        LayerResult::Unhandled
+    }
 }
 
 /// A trait for the layer implementation to provide metadata of [`GlobalHooks`] for the layer
@@ -839,6 +1016,204 @@ impl From<ExtensionProperties> for vk::ExtensionProperties {
     }
 }
 
+/// A wrapper of the `pfnSetDeviceLoaderData` loader callback for a specific `VkDevice`.
+///
+/// Every dispatchable object created by the layer implementation itself instead of by the next
+/// layer(e.g. a `VkCommandBuffer` allocated by the layer for internal use, or a `VkQueue` obtained
+/// by the layer that the application never sees) must have its loader dispatch pointer
+/// initialized before it is passed to any other Vulkan commands. See
+/// [the Vulkan loader doc](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#creating-new-dispatchable-objects)
+/// for details.
+///
+/// The layer framework captures the `VK_LOADER_DATA_CALLBACK` payload of the
+/// `VkLayerDeviceCreateInfo` in `vkCreateDevice`, and passes this type to
+/// [`Layer::create_device_info`]. The same callback can be retrieved later through
+/// [`Global::device_loader_data_callback`].
+///
+/// Prefer [`DeviceLoaderDataCallback::get_device_queue`] and
+/// [`DeviceLoaderDataCallback::allocate_command_buffers`], which obtain the objects from the next
+/// layer and initialize the loader dispatch pointer in one go.
+#[derive(Clone)]
+pub struct DeviceLoaderDataCallback {
+    device: Arc<ash::Device>,
+    // The queueFamilyIndex and queueCount of the VkDeviceQueueCreateInfo structs with empty flags
+    // that the device is created with.
+    queue_counts: Arc<[(u32, u32)]>,
+    set_device_loader_data: PFN_vkSetDeviceLoaderData,
+}
+
+impl DeviceLoaderDataCallback {
+    pub(crate) fn new(
+        device: Arc<ash::Device>,
+        create_info: &vk::DeviceCreateInfo,
+        set_device_loader_data: PFN_vkSetDeviceLoaderData,
+    ) -> Self {
+        let queue_create_infos = if create_info.queue_create_info_count == 0 {
+            &[]
+        } else {
+            // Safe because create_info is the valid VkDeviceCreateInfo that the device is created
+            // with.
+            unsafe {
+                std::slice::from_raw_parts(
+                    create_info.p_queue_create_infos,
+                    create_info.queue_create_info_count as usize,
+                )
+            }
+        };
+        let queue_counts = queue_create_infos
+            .iter()
+            .filter(|queue_create_info| queue_create_info.flags.is_empty())
+            .map(|queue_create_info| {
+                (
+                    queue_create_info.queue_family_index,
+                    queue_create_info.queue_count,
+                )
+            })
+            .collect();
+        Self {
+            device,
+            queue_counts,
+            set_device_loader_data,
+        }
+    }
+
+    /// The `VkDevice` that this callback is bound to.
+    pub fn device(&self) -> vk::Device {
+        self.device.handle()
+    }
+
+    /// Retrieves a `VkQueue` from the next layer with `vkGetDeviceQueue`, and initializes its
+    /// loader dispatch pointer.
+    ///
+    /// `VK_ERROR_INITIALIZATION_FAILED` is returned if the queue isn't created with empty flags in
+    /// the `VkDeviceCreateInfo` that the application passes to `vkCreateDevice`, or if the loader
+    /// doesn't provide the `pfnSetDeviceLoaderData` callback.
+    pub fn get_device_queue(
+        &self,
+        queue_family_index: u32,
+        queue_index: u32,
+    ) -> VkResult<vk::Queue> {
+        let is_queue_created = self.queue_counts.iter().any(|(family_index, queue_count)| {
+            *family_index == queue_family_index && queue_index < *queue_count
+        });
+        if !is_queue_created {
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        }
+        // Safe because the queue is created with the device.
+        let queue = unsafe {
+            self.device
+                .get_device_queue(queue_family_index, queue_index)
+        };
+        // Safe because the queue is just retrieved from the device.
+        unsafe { self.set_device_loader_data(queue) }?;
+        Ok(queue)
+    }
+
+    /// Allocates `VkCommandBuffer`s from the next layer with `vkAllocateCommandBuffers`, and
+    /// initializes their loader dispatch pointers.
+    ///
+    /// If the loader dispatch pointers can't be initialized, e.g. the loader doesn't provide the
+    /// `pfnSetDeviceLoaderData` callback, the command buffers are freed and
+    /// `VK_ERROR_INITIALIZATION_FAILED` is returned.
+    ///
+    /// # Safety
+    /// `allocate_info` must be a valid `VkCommandBufferAllocateInfo` for
+    /// [`DeviceLoaderDataCallback::device`]. No requirement is put on the loader dispatch pointers.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn allocate_command_buffers(
+        &self,
+        allocate_info: &vk::CommandBufferAllocateInfo,
+    ) -> VkResult<Vec<vk::CommandBuffer>> {
+        // Safe because the caller guarantees that allocate_info is valid.
+        let command_buffers = unsafe { self.device.allocate_command_buffers(allocate_info) }?;
+        for command_buffer in &command_buffers {
+            // Safe because the command buffer is just allocated from the device.
+            if let Err(e) = unsafe { self.set_device_loader_data(*command_buffer) } {
+                // Safe because the command buffers are allocated from this command pool, and
+                // haven't been used.
+                unsafe {
+                    self.device
+                        .free_command_buffers(allocate_info.command_pool, &command_buffers)
+                };
+                return Err(e);
+            }
+        }
+        Ok(command_buffers)
+    }
+
+    /// Initializes the loader dispatch pointer of `object` so that it matches the owner
+    /// `VkDevice`.
+    ///
+    /// `VK_ERROR_INITIALIZATION_FAILED` is returned if the loader doesn't provide the
+    /// `pfnSetDeviceLoaderData` callback.
+    ///
+    /// # Safety
+    /// `object` must be a valid `VkQueue` or `VkCommandBuffer` handle created from
+    /// [`DeviceLoaderDataCallback::device`] by the layer implementation itself.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn set_device_loader_data(&self, object: impl vk::Handle) -> VkResult<()> {
+        let object = object.as_raw() as usize as *mut c_void;
+        let Some(set_device_loader_data) = self.set_device_loader_data else {
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        };
+        // Safe because the caller guarantees that object is a dispatchable object created from
+        // the device.
+        unsafe { set_device_loader_data(self.device.handle(), object) }.result()
+    }
+}
+
+/// A wrapper of the `pfnSetInstanceLoaderData` loader callback for a specific `VkInstance`.
+///
+/// The instance level counterpart of [`DeviceLoaderDataCallback`]. The layer framework captures
+/// the `VK_LOADER_DATA_CALLBACK` payload of the `VkLayerInstanceCreateInfo` in `vkCreateInstance`,
+/// and the callback can be invoked through [`Global::set_instance_loader_data`].
+#[derive(Clone, Copy)]
+pub struct InstanceLoaderDataCallback {
+    instance: vk::Instance,
+    set_instance_loader_data: PFN_vkSetInstanceLoaderData,
+}
+
+impl InstanceLoaderDataCallback {
+    pub(crate) fn new(
+        instance: vk::Instance,
+        set_instance_loader_data: PFN_vkSetInstanceLoaderData,
+    ) -> Self {
+        Self {
+            instance,
+            set_instance_loader_data,
+        }
+    }
+
+    /// The `VkInstance` that this callback is bound to.
+    pub fn instance(&self) -> vk::Instance {
+        self.instance
+    }
+
+    /// Initializes the loader dispatch pointer of `object` so that it matches the owner
+    /// `VkInstance`.
+    ///
+    /// `VK_ERROR_INITIALIZATION_FAILED` is returned if the loader doesn't provide the
+    /// `pfnSetInstanceLoaderData` callback.
+    ///
+    /// Unlike [`DeviceLoaderDataCallback`], there is no safe counterpart: the only instance level
+    /// dispatchable objects that a layer creates itself are the `VkPhysicalDevice`s that the layer
+    /// allocates on its own, and the layer framework can't tell whether such a handle is valid.
+    ///
+    /// # Safety
+    /// `object` must be a valid `VkPhysicalDevice` handle created from
+    /// [`InstanceLoaderDataCallback::instance`] by the layer implementation itself.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn set_instance_loader_data(&self, object: impl vk::Handle) -> VkResult<()> {
+        let object = object.as_raw() as usize as *mut c_void;
+        let Some(set_instance_loader_data) = self.set_instance_loader_data else {
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        };
+        // Safe because the caller guarantees that object is a dispatchable object created from
+        // the instance.
+        unsafe { set_instance_loader_data(self.instance, object) }.result()
+    }
+}
+
 /// A Rust bindings of the
 /// [layer manifest file](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#layer-manifest-file-format).
 ///
@@ -853,7 +1228,9 @@ impl From<ExtensionProperties> for vk::ExtensionProperties {
 /// manifest.name = "VK_LAYER_VENDOR_rust_example";
 /// manifest.spec_version = vk::API_VERSION_1_1;
 /// ```
-// TODO: add the capability to serialize to json.
+///
+/// Use [`LayerManifest::to_json`] or [`write_layer_manifest_json`](crate::write_layer_manifest_json)
+/// to generate the JSON manifest file consumed by the Vulkan loader.
 #[non_exhaustive]
 #[derive(Default, Clone)]
 pub struct LayerManifest {
@@ -896,6 +1273,58 @@ pub struct LayerManifest {
     /// removes the extensions mentioned here from the
     /// `VkDeviceCreateInfo::ppEnabledExtensionNames` list.
     pub device_extensions: &'static [ExtensionProperties],
+
+    /// Contains the list of instance extension names supported by this layer. The
+    /// `"instance_extensions"` JSON node.
+    ///
+    /// An array of one or more elements is required if any instance extensions are supported by a
+    /// layer; otherwise the array should be empty. The layer framework reports these extensions in
+    /// `vkEnumerateInstanceExtensionProperties` with the layer name, and in `vkCreateInstance`,
+    /// removes them from the `VkInstanceCreateInfo::ppEnabledExtensionNames` list passed to the
+    /// next layer. Commands of these extensions are considered enabled in `vkGetInstanceProcAddr`
+    /// if the application enables the extensions.
+    pub instance_extensions: &'static [ExtensionProperties],
+
+    /// The device commands exposed by each device extension in
+    /// [`device_extensions`](LayerManifest::device_extensions). The `"entrypoints"` JSON node of
+    /// each `"device_extensions"` element.
+    ///
+    /// Each element pairs an extension with the names of its commands, e.g.
+    /// `(Extension::EXTToolingInfo, &["vkGetPhysicalDeviceToolPropertiesEXT"])`. Extensions that
+    /// are not listed here have no `"entrypoints"` node in the manifest. Every extension listed
+    /// here must also be in [`device_extensions`](LayerManifest::device_extensions).
+    pub device_extension_entrypoints: &'static [(Extension, &'static [&'static str])],
+
+    /// The environment variable and its value to enable an implicit layer. The
+    /// `"enable_environment"` JSON node.
+    ///
+    /// The implicit layer is only loaded if the environment variable is set to the value.
+    pub enable_environment: Option<(&'static str, &'static str)>,
+
+    /// The environment variable and its value to disable an implicit layer. The
+    /// `"disable_environment"` JSON node.
+    ///
+    /// Required by implicit layers. The implicit layer is not loaded if the environment variable
+    /// is set to the value.
+    pub disable_environment: Option<(&'static str, &'static str)>,
+
+    /// Whether the layer reports the
+    /// [`VK_EXT_layer_settings`](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_layer_settings.html)
+    /// instance extension.
+    ///
+    /// The layer framework always decodes the `VkLayerSettingsCreateInfoEXT` structures passed to
+    /// `vkCreateInstance` for [`Layer::create_instance_info`]. If this field is `true`,
+    /// `VK_EXT_layer_settings` is also reported in `vkEnumerateInstanceExtensionProperties` with
+    /// the layer name and in the `"instance_extensions"` JSON node, and is removed from the
+    /// `VkInstanceCreateInfo::ppEnabledExtensionNames` list passed to the next layer.
+    pub layer_settings_extension: bool,
+
+    /// The descriptions of the layer settings. The `"settings"` JSON node in the `"features"` JSON
+    /// node.
+    ///
+    /// Usually assigned from [`LayerSettingsSchema::SETTINGS`](crate::LayerSettingsSchema::SETTINGS)
+    /// of a `#[derive(LayerSettings)]` struct. The layer framework doesn't use it at runtime.
+    pub settings: &'static [LayerSettingMetadata],
 }
 
 /// The [`Layer`] trait provides all layer implementation information for the layer framework.
@@ -988,7 +1417,8 @@ pub struct LayerManifest {
 /// use once_cell::sync::Lazy;
 /// use std::sync::Arc;
 /// use vulkan_layer::{
-///     Global, Layer, LayerManifest, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
+///     DeviceLoaderDataCallback, Global, Layer, LayerManifest, LayerSettings, StubDeviceInfo,
+///     StubGlobalHooks, StubInstanceInfo,
 /// };
 ///
 /// #[derive(Default)]
@@ -1000,6 +1430,9 @@ pub struct LayerManifest {
 ///     type DeviceInfo = StubDeviceInfo;
 ///     type InstanceInfoContainer = StubInstanceInfo;
 ///     type DeviceInfoContainer = StubDeviceInfo;
+///     type PhysicalDeviceInfo = ();
+///     type QueueInfo = ();
+///     type CommandBufferInfo = ();
 ///
 ///     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
 ///         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -1023,6 +1456,7 @@ pub struct LayerManifest {
 ///         _: Option<&vk::AllocationCallbacks>,
 ///         _: Arc<ash::Instance>,
 ///         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+///         _: &LayerSettings,
 ///     ) -> Self::InstanceInfoContainer {
 ///         Default::default()
 ///     }
@@ -1034,6 +1468,7 @@ pub struct LayerManifest {
 ///         _: Option<&vk::AllocationCallbacks>,
 ///         _: Arc<ash::Device>,
 ///         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+///         _: DeviceLoaderDataCallback,
 ///     ) -> Self::DeviceInfoContainer {
 ///         println!("Hello from the Rust Vulkan layer!");
 ///         Default::default()
@@ -1048,7 +1483,8 @@ pub struct LayerManifest {
 /// use once_cell::sync::Lazy;
 /// use std::sync::Arc;
 /// use vulkan_layer::{
-///     Global, Layer, LayerManifest, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
+///     DeviceLoaderDataCallback, Global, Layer, LayerManifest, LayerSettings, StubDeviceInfo,
+///     StubGlobalHooks, StubInstanceInfo,
 /// };
 ///
 /// struct MyLayer(StubGlobalHooks);
@@ -1066,6 +1502,9 @@ pub struct LayerManifest {
 ///     type DeviceInfo = StubDeviceInfo;
 ///     type InstanceInfoContainer = StubInstanceInfo;
 ///     type DeviceInfoContainer = StubDeviceInfo;
+///     type PhysicalDeviceInfo = ();
+///     type QueueInfo = ();
+///     type CommandBufferInfo = ();
 ///
 ///     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
 ///         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -1089,6 +1528,7 @@ pub struct LayerManifest {
 ///         _: Option<&vk::AllocationCallbacks>,
 ///         _: Arc<ash::Instance>,
 ///         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+///         _: &LayerSettings,
 ///     ) -> Self::InstanceInfoContainer {
 ///         Default::default()
 ///     }
@@ -1100,6 +1540,7 @@ pub struct LayerManifest {
 ///         _: Option<&vk::AllocationCallbacks>,
 ///         _: Arc<ash::Device>,
 ///         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+///         _: DeviceLoaderDataCallback,
 ///     ) -> Self::DeviceInfoContainer {
 ///         info!("Hello from the Rust Vulkan layer!");
 ///         Default::default()
@@ -1155,7 +1596,8 @@ pub trait Layer: Sync + Default + 'static {
     /// use once_cell::sync::Lazy;
     /// use std::sync::Arc;
     /// use vulkan_layer::{
-    ///     Global, Layer, LayerManifest, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
+    ///     DeviceLoaderDataCallback, Global, Layer, LayerManifest, LayerSettings, StubDeviceInfo,
+    ///     StubGlobalHooks, StubInstanceInfo,
     /// };
     ///
     /// #[derive(Default)]
@@ -1171,6 +1613,7 @@ pub trait Layer: Sync + Default + 'static {
     ///         _: Option<&vk::AllocationCallbacks>,
     ///         _: Arc<ash::Instance>,
     ///         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    ///         _: &LayerSettings,
     ///     ) -> Self::InstanceInfoContainer {
     ///         Arc::default()
     ///     }
@@ -1179,6 +1622,9 @@ pub trait Layer: Sync + Default + 'static {
     /// #     type GlobalHooksInfo = StubGlobalHooks;
     /// #     type DeviceInfo = StubDeviceInfo;
     /// #     type DeviceInfoContainer = StubDeviceInfo;
+    /// #     type PhysicalDeviceInfo = ();
+    /// #     type QueueInfo = ();
+    /// #     type CommandBufferInfo = ();
     /// #
     /// #     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
     /// #         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -1200,6 +1646,7 @@ pub trait Layer: Sync + Default + 'static {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Device>,
     /// #         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    /// #         _: DeviceLoaderDataCallback,
     /// #     ) -> Self::DeviceInfoContainer {
     /// #         Default::default()
     /// #     }
@@ -1220,7 +1667,8 @@ pub trait Layer: Sync + Default + 'static {
     /// use once_cell::sync::Lazy;
     /// use std::sync::Arc;
     /// use vulkan_layer::{
-    ///     Global, Layer, LayerManifest, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
+    ///     DeviceLoaderDataCallback, Global, Layer, LayerManifest, LayerSettings, StubDeviceInfo,
+    ///     StubGlobalHooks, StubInstanceInfo,
     /// };
     ///
     /// #[derive(Default)]
@@ -1229,6 +1677,9 @@ pub trait Layer: Sync + Default + 'static {
     /// impl Layer for MyLayer {
     ///     type DeviceInfo = StubDeviceInfo;
     ///     type DeviceInfoContainer = Arc<StubDeviceInfo>;
+    ///     type PhysicalDeviceInfo = ();
+    ///     type QueueInfo = ();
+    ///     type CommandBufferInfo = ();
     ///
     ///     fn create_device_info(
     ///         &self,
@@ -1237,6 +1688,7 @@ pub trait Layer: Sync + Default + 'static {
     ///         _: Option<&vk::AllocationCallbacks>,
     ///         _: Arc<ash::Device>,
     ///         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    ///         _: DeviceLoaderDataCallback,
     ///     ) -> Self::DeviceInfoContainer {
     ///         Arc::default()
     ///     }
@@ -1265,6 +1717,7 @@ pub trait Layer: Sync + Default + 'static {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Instance>,
     /// #         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    /// #         _: &LayerSettings,
     /// #     ) -> Self::InstanceInfoContainer {
     /// #         Default::default()
     /// #     }
@@ -1272,6 +1725,42 @@ pub trait Layer: Sync + Default + 'static {
     /// ```
     type DeviceInfoContainer: Borrow<Self::DeviceInfo> + Sync + Send;
 
+    /// The type that holds the layer state of a `VkPhysicalDevice`, e.g. the cached or spoofed
+    /// format properties.
+    ///
+    /// Created by [`Layer::create_physical_device_info`] when the `VkPhysicalDevice` is first
+    /// returned by `vkEnumeratePhysicalDevices` or `vkEnumeratePhysicalDeviceGroups`, and dropped
+    /// when the owner `VkInstance` is destroyed. Use [`Global::physical_device_info`] to obtain it,
+    /// e.g. in the [`InstanceHooks`] methods that take a `physical_device` parameter.
+    ///
+    /// If the layer implementation doesn't need any per physical device state, `()` can be used.
+    type PhysicalDeviceInfo: Default + Sync + Send;
+
+    /// The type that holds the layer state of a `VkQueue`, e.g. the submissions in flight.
+    ///
+    /// Created by [`Layer::create_queue_info`] when the `VkQueue` is first retrieved by
+    /// `vkGetDeviceQueue` or `vkGetDeviceQueue2`, and dropped when the owner `VkDevice` is
+    /// destroyed. Use [`Global::queue_info`] to obtain it, e.g. in [`DeviceHooks::queue_submit`].
+    ///
+    /// If the layer implementation doesn't need any per queue state, `()` can be used.
+    type QueueInfo: Default + Sync + Send;
+
+    /// The type that holds the layer state of a `VkCommandBuffer`, e.g. the recorded commands.
+    ///
+    /// Created by [`Layer::create_command_buffer_info`] when the `VkCommandBuffer` is allocated,
+    /// reset by [`Layer::reset_command_buffer_info`] when the `VkCommandBuffer` is reset, and
+    /// dropped when the `VkCommandBuffer` is freed, either explicitly by `vkFreeCommandBuffers`, or
+    /// implicitly by `vkDestroyCommandPool` or `vkDestroyDevice`. Use
+    /// [`Global::command_buffer_info`] to obtain it, e.g. in [`DeviceHooks::cmd_draw`]. The hooks
+    /// only have shared access to the info, so the state that changes during the recording needs
+    /// interior mutability. The info is created after
+    /// [`DeviceHooks::after_allocate_command_buffers`] is called, so
+    /// [`Global::command_buffer_info`] returns [`None`] for the allocated command buffers in that
+    /// hook.
+    ///
+    /// If the layer implementation doesn't need any per command buffer state, `()` can be used.
+    type CommandBufferInfo: Default + Sync + Send;
+
     /// Returns the
     /// [layer manifest](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#layer-manifest-file-format)
     /// to the layer framework.
@@ -1305,7 +1794,8 @@ pub trait Layer: Sync + Default + 'static {
     /// use once_cell::sync::Lazy;
     /// use std::sync::Arc;
     /// use vulkan_layer::{
-    ///     Global, Layer, LayerManifest, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
+    ///     DeviceLoaderDataCallback, Global, Layer, LayerManifest, LayerSettings, StubDeviceInfo,
+    ///     StubGlobalHooks, StubInstanceInfo,
     /// };
     ///
     /// #[derive(Default)]
@@ -1323,6 +1813,9 @@ pub trait Layer: Sync + Default + 'static {
     /// #     type DeviceInfo = StubDeviceInfo;
     /// #     type InstanceInfoContainer = StubInstanceInfo;
     /// #     type DeviceInfoContainer = StubDeviceInfo;
+    /// #     type PhysicalDeviceInfo = ();
+    /// #     type QueueInfo = ();
+    /// #     type CommandBufferInfo = ();
     /// #
     /// #     fn manifest() -> LayerManifest {
     /// #         let mut manifest = LayerManifest::default();
@@ -1341,6 +1834,7 @@ pub trait Layer: Sync + Default + 'static {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Instance>,
     /// #         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    /// #         _: &LayerSettings,
     /// #     ) -> Self::InstanceInfoContainer {
     /// #         Default::default()
     /// #     }
@@ -1352,6 +1846,7 @@ pub trait Layer: Sync + Default + 'static {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Device>,
     /// #         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    /// #         _: DeviceLoaderDataCallback,
     /// #     ) -> Self::DeviceInfoContainer {
     /// #         println!("Hello from the Rust Vulkan layer!");
     /// #         Default::default()
@@ -1391,14 +1886,190 @@ pub trait Layer: Sync + Default + 'static {
     ///   table.
     /// * `next_get_instance_proc_addr` is the `vkGetInstanceProcAddr` function pointer of the next
     ///   layer obtained from the [`VkLayerInstanceLink`] linked list.
+    /// * `layer_settings` contains the settings of this layer decoded from the
+    ///   `VkLayerSettingsCreateInfoEXT` structures in the `pNext` chain of `create_info`. The layer
+    ///   implementation doesn't need to walk the `pNext` chain for its settings.
     fn create_instance_info(
         &self,
         create_info: &vk::InstanceCreateInfo,
         allocator: Option<&vk::AllocationCallbacks>,
         instance: Arc<ash::Instance>,
         next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+        layer_settings: &LayerSettings,
     ) -> Self::InstanceInfoContainer;
 
+    /// The factory method for the [`Layer::PhysicalDeviceInfo`] type.
+    ///
+    /// This function is called by the layer framework in `vkEnumeratePhysicalDevices` and
+    /// `vkEnumeratePhysicalDeviceGroups`, after the next layer returns a `VkPhysicalDevice` that
+    /// this layer hasn't seen. It is called at most once for each `VkPhysicalDevice` during the
+    /// lifetime of the owner `VkInstance`. By default it returns [`Default::default`].
+    ///
+    /// # Arguments
+    /// * `instance_info` is the [`Layer::InstanceInfo`] of the owner `VkInstance`.
+    /// * `physical_device` is the `VkPhysicalDevice` returned by the next layer.
+    /// * `properties` is the result of `vkGetPhysicalDeviceProperties` on `physical_device` from
+    ///   the next layer.
+    fn create_physical_device_info(
+        &self,
+        _instance_info: &Self::InstanceInfo,
+        _physical_device: vk::PhysicalDevice,
+        _properties: &vk::PhysicalDeviceProperties,
+    ) -> Self::PhysicalDeviceInfo {
+        Default::default()
+    }
+
+    /// The factory method for the [`Layer::QueueInfo`] type.
+    ///
+    /// This function is called by the layer framework in `vkGetDeviceQueue` and
+    /// `vkGetDeviceQueue2`, after the next layer returns a `VkQueue` that this layer hasn't seen.
+    /// It is usually called once for each `VkQueue` during the lifetime of the owner `VkDevice`,
+    /// but if multiple threads retrieve the same `VkQueue` at the same time, only one of the
+    /// created infos is kept. By default it returns [`Default::default`].
+    ///
+    /// # Arguments
+    /// * `device_info` is the [`Layer::DeviceInfo`] of the owner `VkDevice`.
+    /// * `queue` is the `VkQueue` returned by the next layer.
+    /// * `properties` contains the family index, the queue index and the flags of `queue`.
+    fn create_queue_info(
+        &self,
+        _device_info: &Self::DeviceInfo,
+        _queue: vk::Queue,
+        _properties: &QueueProperties,
+    ) -> Self::QueueInfo {
+        Default::default()
+    }
+
+    /// The factory method for the [`Layer::CommandBufferInfo`] type.
+    ///
+    /// This function is called by the layer framework in `vkAllocateCommandBuffers` for every
+    /// allocated `VkCommandBuffer`, after the next layer returns successfully and
+    /// [`DeviceHooks::after_allocate_command_buffers`] is called. By default it returns
+    /// [`Default::default`].
+    ///
+    /// # Arguments
+    /// * `device_info` is the [`Layer::DeviceInfo`] of the owner `VkDevice`.
+    /// * `command_buffer` is the allocated `VkCommandBuffer`.
+    /// * `allocate_info` is the `VkCommandBufferAllocateInfo` passed to
+    ///   `vkAllocateCommandBuffers`.
+    fn create_command_buffer_info(
+        &self,
+        _device_info: &Self::DeviceInfo,
+        _command_buffer: vk::CommandBuffer,
+        _allocate_info: &vk::CommandBufferAllocateInfo,
+    ) -> Self::CommandBufferInfo {
+        Default::default()
+    }
+
+    /// Called when a `VkCommandBuffer` is reset, to return the [`Layer::CommandBufferInfo`] to the
+    /// state right after the allocation.
+    ///
+    /// This function is called by the layer framework
+    /// * in `vkBeginCommandBuffer` before any hook is called, because the command buffer is
+    ///   implicitly reset. It is also called if the command buffer is in the initial state, and
+    ///   even if `vkBeginCommandBuffer` then fails, so that the hooks of `vkBeginCommandBuffer`
+    ///   record into a reset info.
+    /// * in `vkResetCommandBuffer` after the next layer returns successfully.
+    /// * in `vkResetCommandPool` for every command buffer allocated from the pool, after the next
+    ///   layer returns successfully.
+    ///
+    /// By default it does nothing.
+    fn reset_command_buffer_info(&self, _command_buffer_info: &Self::CommandBufferInfo) {}
+
+    /// Whether the layer framework tracks the [`CommandBufferState`](crate::CommandBufferState) of
+    /// every `VkCommandBuffer`.
+    ///
+    /// If `true`, the layer framework intercepts the `vkCmd*` commands that bind pipelines,
+    /// descriptor sets, vertex and index buffers, set the dynamic states, and begin or end the
+    /// render scopes, even if the layer implementation doesn't hook them. The hooks can then query
+    /// the state through [`CommandBufferInfoRef::state`](crate::CommandBufferInfoRef::state).
+    ///
+    /// This function is called in `vkCreateInstance`, `vkCreateDevice` and
+    /// `vkAllocateCommandBuffers`, and should return the same value every time. By default it
+    /// returns `false`, and nothing is tracked.
+    fn track_command_buffer_state(&self) -> bool {
+        false
+    }
+
+    /// Whether the layer framework records the `vkCmd*` commands of every `VkCommandBuffer` into
+    /// [`RecordedCommand`](crate::RecordedCommand)s.
+    ///
+    /// If `true`, the layer framework intercepts all the `vkCmd*` commands, even if the layer
+    /// implementation doesn't hook them, and deep copies the arguments after the command returns
+    /// from the next layer. The hooks can then inspect the commands through
+    /// [`CommandBufferInfoRef::recorded_commands`](crate::CommandBufferInfoRef::recorded_commands),
+    /// or replay them into another command buffer with
+    /// [`RecordedCommand::replay`](crate::RecordedCommand::replay). The commands are cleared when
+    /// the command buffer is reset.
+    ///
+    /// This function is called in `vkCreateInstance`, `vkCreateDevice`, `vkAllocateCommandBuffers`
+    /// and every `vkCmd*` command, and should return the same value every time. By default it
+    /// returns `false`, and nothing is recorded.
+    fn record_command_buffers(&self) -> bool {
+        false
+    }
+
+    /// Whether the layer framework collects the [`CommandStats`](crate::CommandStats) of every
+    /// command called on every `VkInstance` and `VkDevice`.
+    ///
+    /// If `true`, the layer framework intercepts all the commands, even if the layer
+    /// implementation doesn't hook them, and counts the calls and the error results, and measures
+    /// the time spent in the layer and in the next layer. The statistics can be queried with
+    /// [`Global::stats_snapshot`], and are logged when the `VkInstance` or the `VkDevice` is
+    /// destroyed.
+    ///
+    /// This function is called in `vkCreateInstance` and `vkCreateDevice`, and should return the
+    /// same value every time. By default it returns `false`, and nothing is collected.
+    fn collect_command_stats(&self) -> bool {
+        false
+    }
+
+    /// The [`FaultInjector`] that injects errors into the commands that return a `VkResult`.
+    ///
+    /// If [`Some`], the layer framework intercepts all the commands that return a `VkResult`, even
+    /// if the layer implementation doesn't hook them. When a
+    /// [`FaultRule`](crate::FaultRule) fires, the command returns the error of the rule without
+    /// calling the hooks of the layer implementation or the next layer. The rules can be changed
+    /// at any time through the returned injector.
+    ///
+    /// This function is called in `vkCreateInstance`, `vkCreateDevice` and every command that
+    /// returns a `VkResult`, and should return the same injector every time. By default it returns
+    /// [`None`], and no fault is injected.
+    fn fault_injector(&self) -> Option<&FaultInjector> {
+        None
+    }
+
+    /// The [`ThreadSafetyChecker`] that checks the external synchronization requirements of the
+    /// commands.
+    ///
+    /// If [`Some`], the layer framework intercepts all the commands with parameters annotated with
+    /// `externsync` in vk.xml, even if the layer implementation doesn't hook them, and reports the
+    /// externally synchronized handles used by two threads at the same time. See
+    /// [`LayerVulkanCommand::extern_sync_params`](crate::LayerVulkanCommand::extern_sync_params)
+    /// for the parameters.
+    ///
+    /// This function is called in `vkCreateInstance`, `vkCreateDevice` and every checked command,
+    /// and should return the same checker every time. By default it returns [`None`], and nothing
+    /// is checked.
+    fn thread_safety_checker(&self) -> Option<&ThreadSafetyChecker> {
+        None
+    }
+
+    /// The [`CommandObserver`] called around every observed command.
+    ///
+    /// If [`Some`], the layer framework intercepts all the commands returned by
+    /// [`CommandObserver::observed_commands`], even if the layer implementation doesn't hook them,
+    /// and calls [`CommandObserver::on_enter`] before the hooks and [`CommandObserver::on_exit`]
+    /// right before the command returns. This allows implementing logging or profiling for all
+    /// commands without one hook per command.
+    ///
+    /// This function is called when the [`Global`] is initialized and on every intercepted
+    /// command, and should return the same observer every time. By default it returns [`None`],
+    /// and nothing is observed.
+    fn command_observer(&self) -> Option<&dyn CommandObserver> {
+        None
+    }
+
     /// The factory method for the [`DeviceInfo`] type.
     ///
     /// This function is called by the layer framework in `vkCreateDevice`, after the
@@ -1422,6 +2093,11 @@ pub trait Layer: Sync + Default + 'static {
     ///   table.
     /// * `next_get_device_proc_addr` is the `vkGetDeviceProcAddr` function pointer of the next
     ///   layer obtained from the [`VkLayerDeviceLink`][crate::VkLayerDeviceLink] linked list.
+    /// * `loader_data_callback` is used to initialize the loader dispatch pointer of the
+    ///   dispatchable objects created by the layer implementation itself from this device, e.g.
+    ///   through [`DeviceLoaderDataCallback::get_device_queue`]. The layer implementation can store
+    ///   it in the [`DeviceInfo`] type for later use, or retrieve it later with
+    ///   [`Global::device_loader_data_callback`].
     fn create_device_info(
         &self,
         physical_device: vk::PhysicalDevice,
@@ -1429,6 +2105,7 @@ pub trait Layer: Sync + Default + 'static {
         allocator: Option<&vk::AllocationCallbacks>,
         device: Arc<ash::Device>,
         next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        loader_data_callback: DeviceLoaderDataCallback,
     ) -> Self::DeviceInfoContainer;
 
     /// Returns an iterator of
@@ -1452,7 +2129,8 @@ pub trait Layer: Sync + Default + 'static {
     /// use once_cell::sync::Lazy;
     /// use std::{ffi::CStr, sync::Arc};
     /// use vulkan_layer::{
-    ///     Global, InstanceHooks, InstanceInfo, Layer, LayerManifest, LayerResult,
+    ///     command_args::DestroySurfaceKhrArgs, DeviceLoaderDataCallback, Global, InstanceHooks,
+    ///     InstanceInfo, Layer, LayerManifest, LayerResult, LayerSettings,
     ///     LayerVulkanCommand as VulkanCommand, StubDeviceInfo, StubGlobalHooks,
     /// };
     ///
@@ -1465,7 +2143,7 @@ pub trait Layer: Sync + Default + 'static {
     ///         &self,
     ///         _surface: vk::SurfaceKHR,
     ///         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ///     ) -> LayerResult<()> {
+    ///     ) -> LayerResult<(), DestroySurfaceKhrArgs> {
     ///         LayerResult::Unhandled
     ///     }
     /// }
@@ -1493,6 +2171,9 @@ pub trait Layer: Sync + Default + 'static {
     /// #     type DeviceInfo = StubDeviceInfo;
     ///     type InstanceInfoContainer = MyLayerInstanceInfo;
     /// #     type DeviceInfoContainer = StubDeviceInfo;
+    /// #     type PhysicalDeviceInfo = ();
+    /// #     type QueueInfo = ();
+    /// #     type CommandBufferInfo = ();
     /// #
     /// #     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
     /// #         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -1513,6 +2194,7 @@ pub trait Layer: Sync + Default + 'static {
     ///         _: Option<&vk::AllocationCallbacks>,
     ///         _: Arc<ash::Instance>,
     ///         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    ///         _: &LayerSettings,
     ///     ) -> Self::InstanceInfoContainer {
     ///         let enabled_extensions = if create_info.enabled_extension_count > 0 {
     ///             unsafe {
@@ -1559,6 +2241,7 @@ pub trait Layer: Sync + Default + 'static {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Device>,
     /// #         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    /// #         _: DeviceLoaderDataCallback,
     /// #     ) -> Self::DeviceInfoContainer {
     /// #         Default::default()
     /// #     }
@@ -1599,9 +2282,9 @@ pub trait Layer: Sync + Default + 'static {
     /// use once_cell::sync::Lazy;
     /// use std::sync::Arc;
     /// use vulkan_layer::{
-    ///     DeviceHooks, DeviceInfo, Global, Layer, LayerManifest, LayerResult,
-    ///     LayerVulkanCommand as VulkanCommand, StubGlobalHooks, StubInstanceInfo,
-    ///     VulkanBaseInStructChain,
+    ///     command_args::CreateImageArgs, DeviceHooks, DeviceInfo, DeviceLoaderDataCallback, Global,
+    ///     Layer, LayerManifest, LayerResult, LayerSettings, LayerVulkanCommand as VulkanCommand,
+    ///     StubGlobalHooks, StubInstanceInfo, VulkanBaseInStructChain,
     /// };
     ///
     /// struct MyLayerDeviceInfo {
@@ -1613,7 +2296,7 @@ pub trait Layer: Sync + Default + 'static {
     ///         &self,
     ///         create_info: &vk::ImageCreateInfo,
     ///         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ///     ) -> LayerResult<VkResult<vk::Image>> {
+    ///     ) -> LayerResult<VkResult<vk::Image>, CreateImageArgs> {
     ///         if create_info.format == vk::Format::ASTC_4X4_UNORM_BLOCK {
     ///             println!("ASTC 4x4 UNORM image created.");
     ///         }
@@ -1644,6 +2327,9 @@ pub trait Layer: Sync + Default + 'static {
     ///     type DeviceInfo = MyLayerDeviceInfo;
     /// #     type InstanceInfoContainer = StubInstanceInfo;
     ///     type DeviceInfoContainer = MyLayerDeviceInfo;
+    ///     type PhysicalDeviceInfo = ();
+    ///     type QueueInfo = ();
+    ///     type CommandBufferInfo = ();
     /// #
     /// #     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
     /// #         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -1664,6 +2350,7 @@ pub trait Layer: Sync + Default + 'static {
     /// #         _: Option<&vk::AllocationCallbacks>,
     /// #         _: Arc<ash::Instance>,
     /// #         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    /// #         _: &LayerSettings,
     /// #     ) -> Self::InstanceInfoContainer {
     /// #         Default::default()
     /// #     }
@@ -1675,6 +2362,7 @@ pub trait Layer: Sync + Default + 'static {
     ///         _: Option<&vk::AllocationCallbacks>,
     ///         _: Arc<ash::Device>,
     ///         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    ///         _: DeviceLoaderDataCallback,
     ///     ) -> Self::DeviceInfoContainer {
     ///         let mut p_next_chain: VulkanBaseInStructChain =
     ///             unsafe { (create_info.p_next as *const vk::BaseInStructure).as_ref() }.into();
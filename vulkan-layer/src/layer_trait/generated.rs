@@ -23,7 +23,7 @@ use std::{
 use ash::{prelude::VkResult, vk};
 
 use super::{LayerResult, TryFromVulkanCommandError};
-use crate::VkLayerDeviceLink;
+use crate::{command_args::*, ExternSync, ExternSyncParam, VkLayerDeviceLink};
 
 // Unhandled commands:
 // * vkMapMemory2KHR: The ash Rust binding doesn't have proper bindings yet.
@@ -48,6 +48,9 @@ use crate::VkLayerDeviceLink;
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Clone)]
 pub enum VulkanCommand {
     CreateInstance,
+    EnumerateInstanceExtensionProperties,
+    EnumerateInstanceLayerProperties,
+    EnumerateInstanceVersion,
     GetPhysicalDeviceFeatures,
     GetPhysicalDeviceFormatProperties,
     GetPhysicalDeviceImageFormatProperties,
@@ -583,12 +586,1714 @@ pub enum VulkanCommand {
     CmdDrawMeshTasksIndirectCountExt,
 }
 
+impl VulkanCommand {
+    /// The number of the variants.
+    pub(crate) const COUNT: usize = 537;
+
+    /// All the variants, ordered by the discriminant.
+    pub(crate) const ALL: [VulkanCommand; VulkanCommand::COUNT] = [
+        VulkanCommand::CreateInstance,
+        VulkanCommand::EnumerateInstanceExtensionProperties,
+        VulkanCommand::EnumerateInstanceLayerProperties,
+        VulkanCommand::EnumerateInstanceVersion,
+        VulkanCommand::GetPhysicalDeviceFeatures,
+        VulkanCommand::GetPhysicalDeviceFormatProperties,
+        VulkanCommand::GetPhysicalDeviceImageFormatProperties,
+        VulkanCommand::GetPhysicalDeviceProperties,
+        VulkanCommand::GetPhysicalDeviceQueueFamilyProperties,
+        VulkanCommand::GetPhysicalDeviceMemoryProperties,
+        VulkanCommand::GetInstanceProcAddr,
+        VulkanCommand::CreateDevice,
+        VulkanCommand::GetPhysicalDeviceSparseImageFormatProperties,
+        VulkanCommand::GetPhysicalDeviceFeatures2,
+        VulkanCommand::GetPhysicalDeviceProperties2,
+        VulkanCommand::GetPhysicalDeviceFormatProperties2,
+        VulkanCommand::GetPhysicalDeviceImageFormatProperties2,
+        VulkanCommand::GetPhysicalDeviceQueueFamilyProperties2,
+        VulkanCommand::GetPhysicalDeviceMemoryProperties2,
+        VulkanCommand::GetPhysicalDeviceSparseImageFormatProperties2,
+        VulkanCommand::GetPhysicalDeviceExternalBufferProperties,
+        VulkanCommand::GetPhysicalDeviceExternalFenceProperties,
+        VulkanCommand::GetPhysicalDeviceExternalSemaphoreProperties,
+        VulkanCommand::GetPhysicalDeviceToolProperties,
+        VulkanCommand::DestroySurfaceKhr,
+        VulkanCommand::GetPhysicalDeviceSurfaceSupportKhr,
+        VulkanCommand::GetPhysicalDeviceSurfaceCapabilitiesKhr,
+        VulkanCommand::GetPhysicalDeviceSurfaceFormatsKhr,
+        VulkanCommand::GetPhysicalDeviceSurfacePresentModesKhr,
+        VulkanCommand::GetPhysicalDevicePresentRectanglesKhr,
+        VulkanCommand::GetPhysicalDeviceDisplayPropertiesKhr,
+        VulkanCommand::GetPhysicalDeviceDisplayPlanePropertiesKhr,
+        VulkanCommand::GetDisplayPlaneSupportedDisplaysKhr,
+        VulkanCommand::GetDisplayModePropertiesKhr,
+        VulkanCommand::CreateDisplayModeKhr,
+        VulkanCommand::GetDisplayPlaneCapabilitiesKhr,
+        VulkanCommand::CreateDisplayPlaneSurfaceKhr,
+        VulkanCommand::CreateXlibSurfaceKhr,
+        VulkanCommand::GetPhysicalDeviceXlibPresentationSupportKhr,
+        VulkanCommand::CreateXcbSurfaceKhr,
+        VulkanCommand::GetPhysicalDeviceXcbPresentationSupportKhr,
+        VulkanCommand::CreateWaylandSurfaceKhr,
+        VulkanCommand::GetPhysicalDeviceWaylandPresentationSupportKhr,
+        VulkanCommand::CreateAndroidSurfaceKhr,
+        VulkanCommand::CreateWin32SurfaceKhr,
+        VulkanCommand::GetPhysicalDeviceWin32PresentationSupportKhr,
+        VulkanCommand::GetPhysicalDeviceVideoCapabilitiesKhr,
+        VulkanCommand::GetPhysicalDeviceVideoFormatPropertiesKhr,
+        VulkanCommand::EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKhr,
+        VulkanCommand::GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKhr,
+        VulkanCommand::GetPhysicalDeviceSurfaceCapabilities2Khr,
+        VulkanCommand::GetPhysicalDeviceSurfaceFormats2Khr,
+        VulkanCommand::GetPhysicalDeviceDisplayProperties2Khr,
+        VulkanCommand::GetPhysicalDeviceDisplayPlaneProperties2Khr,
+        VulkanCommand::GetDisplayModeProperties2Khr,
+        VulkanCommand::GetDisplayPlaneCapabilities2Khr,
+        VulkanCommand::GetPhysicalDeviceFragmentShadingRatesKhr,
+        VulkanCommand::CreateDebugReportCallbackExt,
+        VulkanCommand::DestroyDebugReportCallbackExt,
+        VulkanCommand::DebugReportMessageExt,
+        VulkanCommand::CreateStreamDescriptorSurfaceGgp,
+        VulkanCommand::GetPhysicalDeviceExternalImageFormatPropertiesNv,
+        VulkanCommand::CreateViSurfaceNn,
+        VulkanCommand::ReleaseDisplayExt,
+        VulkanCommand::AcquireXlibDisplayExt,
+        VulkanCommand::GetRandROutputDisplayExt,
+        VulkanCommand::GetPhysicalDeviceSurfaceCapabilities2Ext,
+        VulkanCommand::CreateIosSurfaceMvk,
+        VulkanCommand::CreateMacOsSurfaceMvk,
+        VulkanCommand::CreateDebugUtilsMessengerExt,
+        VulkanCommand::DestroyDebugUtilsMessengerExt,
+        VulkanCommand::SubmitDebugUtilsMessageExt,
+        VulkanCommand::GetPhysicalDeviceMultisamplePropertiesExt,
+        VulkanCommand::GetPhysicalDeviceCalibrateableTimeDomainsExt,
+        VulkanCommand::CreateImagePipeSurfaceFuchsia,
+        VulkanCommand::CreateMetalSurfaceExt,
+        VulkanCommand::GetPhysicalDeviceCooperativeMatrixPropertiesNv,
+        VulkanCommand::GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNv,
+        VulkanCommand::GetPhysicalDeviceSurfacePresentModes2Ext,
+        VulkanCommand::CreateHeadlessSurfaceExt,
+        VulkanCommand::AcquireDrmDisplayExt,
+        VulkanCommand::GetDrmDisplayExt,
+        VulkanCommand::AcquireWinrtDisplayNv,
+        VulkanCommand::GetWinrtDisplayNv,
+        VulkanCommand::CreateDirectFbSurfaceExt,
+        VulkanCommand::GetPhysicalDeviceDirectFbPresentationSupportExt,
+        VulkanCommand::CreateScreenSurfaceQnx,
+        VulkanCommand::GetPhysicalDeviceScreenPresentationSupportQnx,
+        VulkanCommand::GetPhysicalDeviceOpticalFlowImageFormatsNv,
+        VulkanCommand::GetDeviceProcAddr,
+        VulkanCommand::GetDeviceQueue,
+        VulkanCommand::QueueSubmit,
+        VulkanCommand::QueueWaitIdle,
+        VulkanCommand::DeviceWaitIdle,
+        VulkanCommand::AllocateMemory,
+        VulkanCommand::FreeMemory,
+        VulkanCommand::MapMemory,
+        VulkanCommand::UnmapMemory,
+        VulkanCommand::FlushMappedMemoryRanges,
+        VulkanCommand::InvalidateMappedMemoryRanges,
+        VulkanCommand::GetDeviceMemoryCommitment,
+        VulkanCommand::BindBufferMemory,
+        VulkanCommand::BindImageMemory,
+        VulkanCommand::GetBufferMemoryRequirements,
+        VulkanCommand::GetImageMemoryRequirements,
+        VulkanCommand::GetImageSparseMemoryRequirements,
+        VulkanCommand::QueueBindSparse,
+        VulkanCommand::CreateFence,
+        VulkanCommand::DestroyFence,
+        VulkanCommand::ResetFences,
+        VulkanCommand::GetFenceStatus,
+        VulkanCommand::WaitForFences,
+        VulkanCommand::CreateSemaphore,
+        VulkanCommand::DestroySemaphore,
+        VulkanCommand::CreateEvent,
+        VulkanCommand::DestroyEvent,
+        VulkanCommand::GetEventStatus,
+        VulkanCommand::SetEvent,
+        VulkanCommand::ResetEvent,
+        VulkanCommand::CreateQueryPool,
+        VulkanCommand::DestroyQueryPool,
+        VulkanCommand::GetQueryPoolResults,
+        VulkanCommand::CreateBuffer,
+        VulkanCommand::DestroyBuffer,
+        VulkanCommand::CreateBufferView,
+        VulkanCommand::DestroyBufferView,
+        VulkanCommand::CreateImage,
+        VulkanCommand::DestroyImage,
+        VulkanCommand::GetImageSubresourceLayout,
+        VulkanCommand::CreateImageView,
+        VulkanCommand::DestroyImageView,
+        VulkanCommand::CreateShaderModule,
+        VulkanCommand::DestroyShaderModule,
+        VulkanCommand::CreatePipelineCache,
+        VulkanCommand::DestroyPipelineCache,
+        VulkanCommand::GetPipelineCacheData,
+        VulkanCommand::MergePipelineCaches,
+        VulkanCommand::CreateGraphicsPipelines,
+        VulkanCommand::CreateComputePipelines,
+        VulkanCommand::DestroyPipeline,
+        VulkanCommand::CreatePipelineLayout,
+        VulkanCommand::DestroyPipelineLayout,
+        VulkanCommand::CreateSampler,
+        VulkanCommand::DestroySampler,
+        VulkanCommand::CreateDescriptorSetLayout,
+        VulkanCommand::DestroyDescriptorSetLayout,
+        VulkanCommand::CreateDescriptorPool,
+        VulkanCommand::DestroyDescriptorPool,
+        VulkanCommand::ResetDescriptorPool,
+        VulkanCommand::AllocateDescriptorSets,
+        VulkanCommand::FreeDescriptorSets,
+        VulkanCommand::UpdateDescriptorSets,
+        VulkanCommand::CreateFramebuffer,
+        VulkanCommand::DestroyFramebuffer,
+        VulkanCommand::CreateRenderPass,
+        VulkanCommand::DestroyRenderPass,
+        VulkanCommand::GetRenderAreaGranularity,
+        VulkanCommand::CreateCommandPool,
+        VulkanCommand::DestroyCommandPool,
+        VulkanCommand::ResetCommandPool,
+        VulkanCommand::AllocateCommandBuffers,
+        VulkanCommand::FreeCommandBuffers,
+        VulkanCommand::BeginCommandBuffer,
+        VulkanCommand::EndCommandBuffer,
+        VulkanCommand::ResetCommandBuffer,
+        VulkanCommand::CmdBindPipeline,
+        VulkanCommand::CmdSetViewport,
+        VulkanCommand::CmdSetScissor,
+        VulkanCommand::CmdSetLineWidth,
+        VulkanCommand::CmdSetDepthBias,
+        VulkanCommand::CmdSetBlendConstants,
+        VulkanCommand::CmdSetDepthBounds,
+        VulkanCommand::CmdSetStencilCompareMask,
+        VulkanCommand::CmdSetStencilWriteMask,
+        VulkanCommand::CmdSetStencilReference,
+        VulkanCommand::CmdBindDescriptorSets,
+        VulkanCommand::CmdBindIndexBuffer,
+        VulkanCommand::CmdBindVertexBuffers,
+        VulkanCommand::CmdDraw,
+        VulkanCommand::CmdDrawIndexed,
+        VulkanCommand::CmdDrawIndirect,
+        VulkanCommand::CmdDrawIndexedIndirect,
+        VulkanCommand::CmdDispatch,
+        VulkanCommand::CmdDispatchIndirect,
+        VulkanCommand::CmdCopyBuffer,
+        VulkanCommand::CmdCopyImage,
+        VulkanCommand::CmdBlitImage,
+        VulkanCommand::CmdCopyBufferToImage,
+        VulkanCommand::CmdCopyImageToBuffer,
+        VulkanCommand::CmdUpdateBuffer,
+        VulkanCommand::CmdFillBuffer,
+        VulkanCommand::CmdClearColorImage,
+        VulkanCommand::CmdClearDepthStencilImage,
+        VulkanCommand::CmdClearAttachments,
+        VulkanCommand::CmdResolveImage,
+        VulkanCommand::CmdSetEvent,
+        VulkanCommand::CmdResetEvent,
+        VulkanCommand::CmdWaitEvents,
+        VulkanCommand::CmdPipelineBarrier,
+        VulkanCommand::CmdBeginQuery,
+        VulkanCommand::CmdEndQuery,
+        VulkanCommand::CmdResetQueryPool,
+        VulkanCommand::CmdWriteTimestamp,
+        VulkanCommand::CmdCopyQueryPoolResults,
+        VulkanCommand::CmdPushConstants,
+        VulkanCommand::CmdBeginRenderPass,
+        VulkanCommand::CmdNextSubpass,
+        VulkanCommand::CmdEndRenderPass,
+        VulkanCommand::CmdExecuteCommands,
+        VulkanCommand::BindBufferMemory2,
+        VulkanCommand::BindImageMemory2,
+        VulkanCommand::GetDeviceGroupPeerMemoryFeatures,
+        VulkanCommand::CmdSetDeviceMask,
+        VulkanCommand::CmdDispatchBase,
+        VulkanCommand::GetImageMemoryRequirements2,
+        VulkanCommand::GetBufferMemoryRequirements2,
+        VulkanCommand::GetImageSparseMemoryRequirements2,
+        VulkanCommand::TrimCommandPool,
+        VulkanCommand::GetDeviceQueue2,
+        VulkanCommand::CreateSamplerYcbcrConversion,
+        VulkanCommand::DestroySamplerYcbcrConversion,
+        VulkanCommand::CreateDescriptorUpdateTemplate,
+        VulkanCommand::DestroyDescriptorUpdateTemplate,
+        VulkanCommand::UpdateDescriptorSetWithTemplate,
+        VulkanCommand::GetDescriptorSetLayoutSupport,
+        VulkanCommand::CmdDrawIndirectCount,
+        VulkanCommand::CmdDrawIndexedIndirectCount,
+        VulkanCommand::CreateRenderPass2,
+        VulkanCommand::CmdBeginRenderPass2,
+        VulkanCommand::CmdNextSubpass2,
+        VulkanCommand::CmdEndRenderPass2,
+        VulkanCommand::ResetQueryPool,
+        VulkanCommand::GetSemaphoreCounterValue,
+        VulkanCommand::WaitSemaphores,
+        VulkanCommand::SignalSemaphore,
+        VulkanCommand::GetBufferDeviceAddress,
+        VulkanCommand::GetBufferOpaqueCaptureAddress,
+        VulkanCommand::GetDeviceMemoryOpaqueCaptureAddress,
+        VulkanCommand::CreatePrivateDataSlot,
+        VulkanCommand::DestroyPrivateDataSlot,
+        VulkanCommand::SetPrivateData,
+        VulkanCommand::GetPrivateData,
+        VulkanCommand::CmdSetEvent2,
+        VulkanCommand::CmdResetEvent2,
+        VulkanCommand::CmdWaitEvents2,
+        VulkanCommand::CmdPipelineBarrier2,
+        VulkanCommand::CmdWriteTimestamp2,
+        VulkanCommand::QueueSubmit2,
+        VulkanCommand::CmdCopyBuffer2,
+        VulkanCommand::CmdCopyImage2,
+        VulkanCommand::CmdCopyBufferToImage2,
+        VulkanCommand::CmdCopyImageToBuffer2,
+        VulkanCommand::CmdBlitImage2,
+        VulkanCommand::CmdResolveImage2,
+        VulkanCommand::CmdBeginRendering,
+        VulkanCommand::CmdEndRendering,
+        VulkanCommand::CmdSetCullMode,
+        VulkanCommand::CmdSetFrontFace,
+        VulkanCommand::CmdSetPrimitiveTopology,
+        VulkanCommand::CmdSetViewportWithCount,
+        VulkanCommand::CmdSetScissorWithCount,
+        VulkanCommand::CmdBindVertexBuffers2,
+        VulkanCommand::CmdSetDepthTestEnable,
+        VulkanCommand::CmdSetDepthWriteEnable,
+        VulkanCommand::CmdSetDepthCompareOp,
+        VulkanCommand::CmdSetDepthBoundsTestEnable,
+        VulkanCommand::CmdSetStencilTestEnable,
+        VulkanCommand::CmdSetStencilOp,
+        VulkanCommand::CmdSetRasterizerDiscardEnable,
+        VulkanCommand::CmdSetDepthBiasEnable,
+        VulkanCommand::CmdSetPrimitiveRestartEnable,
+        VulkanCommand::GetDeviceBufferMemoryRequirements,
+        VulkanCommand::GetDeviceImageMemoryRequirements,
+        VulkanCommand::GetDeviceImageSparseMemoryRequirements,
+        VulkanCommand::CreateSwapchainKhr,
+        VulkanCommand::DestroySwapchainKhr,
+        VulkanCommand::GetSwapchainImagesKhr,
+        VulkanCommand::AcquireNextImageKhr,
+        VulkanCommand::QueuePresentKhr,
+        VulkanCommand::GetDeviceGroupPresentCapabilitiesKhr,
+        VulkanCommand::GetDeviceGroupSurfacePresentModesKhr,
+        VulkanCommand::AcquireNextImage2Khr,
+        VulkanCommand::CreateSharedSwapchainsKhr,
+        VulkanCommand::CreateVideoSessionKhr,
+        VulkanCommand::DestroyVideoSessionKhr,
+        VulkanCommand::GetVideoSessionMemoryRequirementsKhr,
+        VulkanCommand::BindVideoSessionMemoryKhr,
+        VulkanCommand::CreateVideoSessionParametersKhr,
+        VulkanCommand::UpdateVideoSessionParametersKhr,
+        VulkanCommand::DestroyVideoSessionParametersKhr,
+        VulkanCommand::CmdBeginVideoCodingKhr,
+        VulkanCommand::CmdEndVideoCodingKhr,
+        VulkanCommand::CmdControlVideoCodingKhr,
+        VulkanCommand::CmdDecodeVideoKhr,
+        VulkanCommand::GetMemoryWin32HandleKhr,
+        VulkanCommand::GetMemoryWin32HandlePropertiesKhr,
+        VulkanCommand::GetMemoryFdKhr,
+        VulkanCommand::GetMemoryFdPropertiesKhr,
+        VulkanCommand::ImportSemaphoreWin32HandleKhr,
+        VulkanCommand::GetSemaphoreWin32HandleKhr,
+        VulkanCommand::ImportSemaphoreFdKhr,
+        VulkanCommand::GetSemaphoreFdKhr,
+        VulkanCommand::CmdPushDescriptorSetKhr,
+        VulkanCommand::CmdPushDescriptorSetWithTemplateKhr,
+        VulkanCommand::GetSwapchainStatusKhr,
+        VulkanCommand::ImportFenceWin32HandleKhr,
+        VulkanCommand::GetFenceWin32HandleKhr,
+        VulkanCommand::ImportFenceFdKhr,
+        VulkanCommand::GetFenceFdKhr,
+        VulkanCommand::AcquireProfilingLockKhr,
+        VulkanCommand::ReleaseProfilingLockKhr,
+        VulkanCommand::CmdSetFragmentShadingRateKhr,
+        VulkanCommand::WaitForPresentKhr,
+        VulkanCommand::CreateDeferredOperationKhr,
+        VulkanCommand::DestroyDeferredOperationKhr,
+        VulkanCommand::GetDeferredOperationMaxConcurrencyKhr,
+        VulkanCommand::GetDeferredOperationResultKhr,
+        VulkanCommand::DeferredOperationJoinKhr,
+        VulkanCommand::GetPipelineExecutablePropertiesKhr,
+        VulkanCommand::GetPipelineExecutableStatisticsKhr,
+        VulkanCommand::GetPipelineExecutableInternalRepresentationsKhr,
+        VulkanCommand::CmdEncodeVideoKhr,
+        VulkanCommand::CmdWriteBufferMarker2Amd,
+        VulkanCommand::GetQueueCheckpointData2Nv,
+        VulkanCommand::CmdTraceRaysIndirect2Khr,
+        VulkanCommand::GetSwapchainGrallocUsageAndroid,
+        VulkanCommand::AcquireImageAndroid,
+        VulkanCommand::QueueSignalReleaseImageAndroid,
+        VulkanCommand::GetSwapchainGrallocUsage2Android,
+        VulkanCommand::DebugMarkerSetObjectTagExt,
+        VulkanCommand::DebugMarkerSetObjectNameExt,
+        VulkanCommand::CmdDebugMarkerBeginExt,
+        VulkanCommand::CmdDebugMarkerEndExt,
+        VulkanCommand::CmdDebugMarkerInsertExt,
+        VulkanCommand::CmdBindTransformFeedbackBuffersExt,
+        VulkanCommand::CmdBeginTransformFeedbackExt,
+        VulkanCommand::CmdEndTransformFeedbackExt,
+        VulkanCommand::CmdBeginQueryIndexedExt,
+        VulkanCommand::CmdEndQueryIndexedExt,
+        VulkanCommand::CmdDrawIndirectByteCountExt,
+        VulkanCommand::CreateCuModuleNvx,
+        VulkanCommand::CreateCuFunctionNvx,
+        VulkanCommand::DestroyCuModuleNvx,
+        VulkanCommand::DestroyCuFunctionNvx,
+        VulkanCommand::CmdCuLaunchKernelNvx,
+        VulkanCommand::GetImageViewHandleNvx,
+        VulkanCommand::GetImageViewAddressNvx,
+        VulkanCommand::GetShaderInfoAmd,
+        VulkanCommand::GetMemoryWin32HandleNv,
+        VulkanCommand::CmdBeginConditionalRenderingExt,
+        VulkanCommand::CmdEndConditionalRenderingExt,
+        VulkanCommand::CmdSetViewportWScalingNv,
+        VulkanCommand::DisplayPowerControlExt,
+        VulkanCommand::RegisterDeviceEventExt,
+        VulkanCommand::RegisterDisplayEventExt,
+        VulkanCommand::GetSwapchainCounterExt,
+        VulkanCommand::GetRefreshCycleDurationGoogle,
+        VulkanCommand::GetPastPresentationTimingGoogle,
+        VulkanCommand::CmdSetDiscardRectangleExt,
+        VulkanCommand::SetHdrMetadataExt,
+        VulkanCommand::SetDebugUtilsObjectNameExt,
+        VulkanCommand::SetDebugUtilsObjectTagExt,
+        VulkanCommand::QueueBeginDebugUtilsLabelExt,
+        VulkanCommand::QueueEndDebugUtilsLabelExt,
+        VulkanCommand::QueueInsertDebugUtilsLabelExt,
+        VulkanCommand::CmdBeginDebugUtilsLabelExt,
+        VulkanCommand::CmdEndDebugUtilsLabelExt,
+        VulkanCommand::CmdInsertDebugUtilsLabelExt,
+        VulkanCommand::GetAndroidHardwareBufferPropertiesAndroid,
+        VulkanCommand::GetMemoryAndroidHardwareBufferAndroid,
+        VulkanCommand::CmdSetSampleLocationsExt,
+        VulkanCommand::GetImageDrmFormatModifierPropertiesExt,
+        VulkanCommand::CreateValidationCacheExt,
+        VulkanCommand::DestroyValidationCacheExt,
+        VulkanCommand::MergeValidationCachesExt,
+        VulkanCommand::GetValidationCacheDataExt,
+        VulkanCommand::CmdBindShadingRateImageNv,
+        VulkanCommand::CmdSetViewportShadingRatePaletteNv,
+        VulkanCommand::CmdSetCoarseSampleOrderNv,
+        VulkanCommand::CreateAccelerationStructureNv,
+        VulkanCommand::DestroyAccelerationStructureNv,
+        VulkanCommand::GetAccelerationStructureMemoryRequirementsNv,
+        VulkanCommand::BindAccelerationStructureMemoryNv,
+        VulkanCommand::CmdBuildAccelerationStructureNv,
+        VulkanCommand::CmdCopyAccelerationStructureNv,
+        VulkanCommand::CmdTraceRaysNv,
+        VulkanCommand::CreateRayTracingPipelinesNv,
+        VulkanCommand::GetRayTracingShaderGroupHandlesKhr,
+        VulkanCommand::GetAccelerationStructureHandleNv,
+        VulkanCommand::CmdWriteAccelerationStructuresPropertiesNv,
+        VulkanCommand::CompileDeferredNv,
+        VulkanCommand::GetMemoryHostPointerPropertiesExt,
+        VulkanCommand::CmdWriteBufferMarkerAmd,
+        VulkanCommand::GetCalibratedTimestampsExt,
+        VulkanCommand::CmdDrawMeshTasksNv,
+        VulkanCommand::CmdDrawMeshTasksIndirectNv,
+        VulkanCommand::CmdDrawMeshTasksIndirectCountNv,
+        VulkanCommand::CmdSetExclusiveScissorNv,
+        VulkanCommand::CmdSetCheckpointNv,
+        VulkanCommand::GetQueueCheckpointDataNv,
+        VulkanCommand::InitializePerformanceApiIntel,
+        VulkanCommand::UninitializePerformanceApiIntel,
+        VulkanCommand::CmdSetPerformanceMarkerIntel,
+        VulkanCommand::CmdSetPerformanceStreamMarkerIntel,
+        VulkanCommand::CmdSetPerformanceOverrideIntel,
+        VulkanCommand::AcquirePerformanceConfigurationIntel,
+        VulkanCommand::ReleasePerformanceConfigurationIntel,
+        VulkanCommand::QueueSetPerformanceConfigurationIntel,
+        VulkanCommand::GetPerformanceParameterIntel,
+        VulkanCommand::SetLocalDimmingAmd,
+        VulkanCommand::AcquireFullScreenExclusiveModeExt,
+        VulkanCommand::ReleaseFullScreenExclusiveModeExt,
+        VulkanCommand::GetDeviceGroupSurfacePresentModes2Ext,
+        VulkanCommand::CmdSetLineStippleExt,
+        VulkanCommand::ReleaseSwapchainImagesExt,
+        VulkanCommand::GetGeneratedCommandsMemoryRequirementsNv,
+        VulkanCommand::CmdPreprocessGeneratedCommandsNv,
+        VulkanCommand::CmdExecuteGeneratedCommandsNv,
+        VulkanCommand::CmdBindPipelineShaderGroupNv,
+        VulkanCommand::CreateIndirectCommandsLayoutNv,
+        VulkanCommand::DestroyIndirectCommandsLayoutNv,
+        VulkanCommand::ExportMetalObjectsExt,
+        VulkanCommand::GetDescriptorSetLayoutSizeExt,
+        VulkanCommand::GetDescriptorSetLayoutBindingOffsetExt,
+        VulkanCommand::GetDescriptorExt,
+        VulkanCommand::CmdBindDescriptorBuffersExt,
+        VulkanCommand::CmdSetDescriptorBufferOffsetsExt,
+        VulkanCommand::CmdBindDescriptorBufferEmbeddedSamplersExt,
+        VulkanCommand::GetBufferOpaqueCaptureDescriptorDataExt,
+        VulkanCommand::GetImageOpaqueCaptureDescriptorDataExt,
+        VulkanCommand::GetImageViewOpaqueCaptureDescriptorDataExt,
+        VulkanCommand::GetSamplerOpaqueCaptureDescriptorDataExt,
+        VulkanCommand::GetAccelerationStructureOpaqueCaptureDescriptorDataExt,
+        VulkanCommand::CmdSetFragmentShadingRateEnumNv,
+        VulkanCommand::GetImageSubresourceLayout2Ext,
+        VulkanCommand::CmdSetVertexInputExt,
+        VulkanCommand::GetMemoryZirconHandleFuchsia,
+        VulkanCommand::GetMemoryZirconHandlePropertiesFuchsia,
+        VulkanCommand::ImportSemaphoreZirconHandleFuchsia,
+        VulkanCommand::GetSemaphoreZirconHandleFuchsia,
+        VulkanCommand::CreateBufferCollectionFuchsia,
+        VulkanCommand::SetBufferCollectionImageConstraintsFuchsia,
+        VulkanCommand::SetBufferCollectionBufferConstraintsFuchsia,
+        VulkanCommand::DestroyBufferCollectionFuchsia,
+        VulkanCommand::GetBufferCollectionPropertiesFuchsia,
+        VulkanCommand::GetDeviceSubpassShadingMaxWorkgroupSizeHuawei,
+        VulkanCommand::CmdSubpassShadingHuawei,
+        VulkanCommand::CmdBindInvocationMaskHuawei,
+        VulkanCommand::GetMemoryRemoteAddressNv,
+        VulkanCommand::GetPipelinePropertiesExt,
+        VulkanCommand::CmdSetPatchControlPointsExt,
+        VulkanCommand::CmdSetLogicOpExt,
+        VulkanCommand::CmdSetColorWriteEnableExt,
+        VulkanCommand::CmdDrawMultiExt,
+        VulkanCommand::CmdDrawMultiIndexedExt,
+        VulkanCommand::CreateMicromapExt,
+        VulkanCommand::DestroyMicromapExt,
+        VulkanCommand::CmdBuildMicromapsExt,
+        VulkanCommand::BuildMicromapsExt,
+        VulkanCommand::CopyMicromapExt,
+        VulkanCommand::CopyMicromapToMemoryExt,
+        VulkanCommand::CopyMemoryToMicromapExt,
+        VulkanCommand::WriteMicromapsPropertiesExt,
+        VulkanCommand::CmdCopyMicromapExt,
+        VulkanCommand::CmdCopyMicromapToMemoryExt,
+        VulkanCommand::CmdCopyMemoryToMicromapExt,
+        VulkanCommand::CmdWriteMicromapsPropertiesExt,
+        VulkanCommand::GetDeviceMicromapCompatibilityExt,
+        VulkanCommand::GetMicromapBuildSizesExt,
+        VulkanCommand::SetDeviceMemoryPriorityExt,
+        VulkanCommand::GetDescriptorSetLayoutHostMappingInfoValve,
+        VulkanCommand::GetDescriptorSetHostMappingValve,
+        VulkanCommand::CmdCopyMemoryIndirectNv,
+        VulkanCommand::CmdCopyMemoryToImageIndirectNv,
+        VulkanCommand::CmdDecompressMemoryNv,
+        VulkanCommand::CmdDecompressMemoryIndirectCountNv,
+        VulkanCommand::CmdSetTessellationDomainOriginExt,
+        VulkanCommand::CmdSetDepthClampEnableExt,
+        VulkanCommand::CmdSetPolygonModeExt,
+        VulkanCommand::CmdSetRasterizationSamplesExt,
+        VulkanCommand::CmdSetSampleMaskExt,
+        VulkanCommand::CmdSetAlphaToCoverageEnableExt,
+        VulkanCommand::CmdSetAlphaToOneEnableExt,
+        VulkanCommand::CmdSetLogicOpEnableExt,
+        VulkanCommand::CmdSetColorBlendEnableExt,
+        VulkanCommand::CmdSetColorBlendEquationExt,
+        VulkanCommand::CmdSetColorWriteMaskExt,
+        VulkanCommand::CmdSetRasterizationStreamExt,
+        VulkanCommand::CmdSetConservativeRasterizationModeExt,
+        VulkanCommand::CmdSetExtraPrimitiveOverestimationSizeExt,
+        VulkanCommand::CmdSetDepthClipEnableExt,
+        VulkanCommand::CmdSetSampleLocationsEnableExt,
+        VulkanCommand::CmdSetColorBlendAdvancedExt,
+        VulkanCommand::CmdSetProvokingVertexModeExt,
+        VulkanCommand::CmdSetLineRasterizationModeExt,
+        VulkanCommand::CmdSetLineStippleEnableExt,
+        VulkanCommand::CmdSetDepthClipNegativeOneToOneExt,
+        VulkanCommand::CmdSetViewportWScalingEnableNv,
+        VulkanCommand::CmdSetViewportSwizzleNv,
+        VulkanCommand::CmdSetCoverageToColorEnableNv,
+        VulkanCommand::CmdSetCoverageToColorLocationNv,
+        VulkanCommand::CmdSetCoverageModulationModeNv,
+        VulkanCommand::CmdSetCoverageModulationTableEnableNv,
+        VulkanCommand::CmdSetCoverageModulationTableNv,
+        VulkanCommand::CmdSetShadingRateImageEnableNv,
+        VulkanCommand::CmdSetRepresentativeFragmentTestEnableNv,
+        VulkanCommand::CmdSetCoverageReductionModeNv,
+        VulkanCommand::GetShaderModuleIdentifierExt,
+        VulkanCommand::GetShaderModuleCreateInfoIdentifierExt,
+        VulkanCommand::CreateOpticalFlowSessionNv,
+        VulkanCommand::DestroyOpticalFlowSessionNv,
+        VulkanCommand::BindOpticalFlowSessionImageNv,
+        VulkanCommand::CmdOpticalFlowExecuteNv,
+        VulkanCommand::GetFramebufferTilePropertiesQcom,
+        VulkanCommand::GetDynamicRenderingTilePropertiesQcom,
+        VulkanCommand::CreateAccelerationStructureKhr,
+        VulkanCommand::DestroyAccelerationStructureKhr,
+        VulkanCommand::CopyAccelerationStructureKhr,
+        VulkanCommand::CopyAccelerationStructureToMemoryKhr,
+        VulkanCommand::CopyMemoryToAccelerationStructureKhr,
+        VulkanCommand::WriteAccelerationStructuresPropertiesKhr,
+        VulkanCommand::CmdCopyAccelerationStructureKhr,
+        VulkanCommand::CmdCopyAccelerationStructureToMemoryKhr,
+        VulkanCommand::CmdCopyMemoryToAccelerationStructureKhr,
+        VulkanCommand::GetAccelerationStructureDeviceAddressKhr,
+        VulkanCommand::CmdWriteAccelerationStructuresPropertiesKhr,
+        VulkanCommand::GetDeviceAccelerationStructureCompatibilityKhr,
+        VulkanCommand::GetAccelerationStructureBuildSizesKhr,
+        VulkanCommand::CmdTraceRaysKhr,
+        VulkanCommand::CreateRayTracingPipelinesKhr,
+        VulkanCommand::GetRayTracingCaptureReplayShaderGroupHandlesKhr,
+        VulkanCommand::CmdTraceRaysIndirectKhr,
+        VulkanCommand::GetRayTracingShaderGroupStackSizeKhr,
+        VulkanCommand::CmdSetRayTracingPipelineStackSizeKhr,
+        VulkanCommand::CmdDrawMeshTasksExt,
+        VulkanCommand::CmdDrawMeshTasksIndirectExt,
+        VulkanCommand::CmdDrawMeshTasksIndirectCountExt,
+    ];
+
+    /// The parameters annotated with `externsync` in vk.xml, which the application must
+    /// synchronize externally when calling the command.
+    pub fn extern_sync_params(&self) -> &'static [ExternSyncParam] {
+        match self {
+            VulkanCommand::DestroySurfaceKhr => &[ExternSyncParam {
+                name: "surface",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CreateDisplayModeKhr => &[ExternSyncParam {
+                name: "display",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyDebugReportCallbackExt => &[ExternSyncParam {
+                name: "callback",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyDebugUtilsMessengerExt => &[ExternSyncParam {
+                name: "messenger",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::QueueSubmit => &[
+                ExternSyncParam {
+                    name: "queue",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "fence",
+                    extern_sync: ExternSync::Param,
+                },
+            ],
+            VulkanCommand::QueueWaitIdle => &[ExternSyncParam {
+                name: "queue",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::FreeMemory => &[ExternSyncParam {
+                name: "memory",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::MapMemory => &[ExternSyncParam {
+                name: "memory",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::UnmapMemory => &[ExternSyncParam {
+                name: "memory",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::BindBufferMemory => &[ExternSyncParam {
+                name: "buffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::BindImageMemory => &[ExternSyncParam {
+                name: "image",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::QueueBindSparse => &[
+                ExternSyncParam {
+                    name: "queue",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "fence",
+                    extern_sync: ExternSync::Param,
+                },
+            ],
+            VulkanCommand::DestroyFence => &[ExternSyncParam {
+                name: "fence",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::ResetFences => &[ExternSyncParam {
+                name: "pFences",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroySemaphore => &[ExternSyncParam {
+                name: "semaphore",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyEvent => &[ExternSyncParam {
+                name: "event",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::SetEvent => &[ExternSyncParam {
+                name: "event",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::ResetEvent => &[ExternSyncParam {
+                name: "event",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyQueryPool => &[ExternSyncParam {
+                name: "queryPool",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyBuffer => &[ExternSyncParam {
+                name: "buffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyBufferView => &[ExternSyncParam {
+                name: "bufferView",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyImage => &[ExternSyncParam {
+                name: "image",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyImageView => &[ExternSyncParam {
+                name: "imageView",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyShaderModule => &[ExternSyncParam {
+                name: "shaderModule",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyPipelineCache => &[ExternSyncParam {
+                name: "pipelineCache",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::MergePipelineCaches => &[ExternSyncParam {
+                name: "dstCache",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyPipeline => &[ExternSyncParam {
+                name: "pipeline",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyPipelineLayout => &[ExternSyncParam {
+                name: "pipelineLayout",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroySampler => &[ExternSyncParam {
+                name: "sampler",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyDescriptorSetLayout => &[ExternSyncParam {
+                name: "descriptorSetLayout",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyDescriptorPool => &[ExternSyncParam {
+                name: "descriptorPool",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::ResetDescriptorPool => &[ExternSyncParam {
+                name: "descriptorPool",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::AllocateDescriptorSets => &[ExternSyncParam {
+                name: "pAllocateInfo",
+                extern_sync: ExternSync::Members(&["pAllocateInfo->descriptorPool"]),
+            }],
+            VulkanCommand::FreeDescriptorSets => &[
+                ExternSyncParam {
+                    name: "descriptorPool",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "pDescriptorSets",
+                    extern_sync: ExternSync::Param,
+                },
+            ],
+            VulkanCommand::UpdateDescriptorSets => &[
+                ExternSyncParam {
+                    name: "pDescriptorWrites",
+                    extern_sync: ExternSync::Members(&["pDescriptorWrites[].dstSet"]),
+                },
+                ExternSyncParam {
+                    name: "pDescriptorCopies",
+                    extern_sync: ExternSync::Members(&["pDescriptorCopies[].dstSet"]),
+                },
+            ],
+            VulkanCommand::DestroyFramebuffer => &[ExternSyncParam {
+                name: "framebuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyRenderPass => &[ExternSyncParam {
+                name: "renderPass",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyCommandPool => &[ExternSyncParam {
+                name: "commandPool",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::ResetCommandPool => &[ExternSyncParam {
+                name: "commandPool",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::AllocateCommandBuffers => &[ExternSyncParam {
+                name: "pAllocateInfo",
+                extern_sync: ExternSync::Members(&["pAllocateInfo->commandPool"]),
+            }],
+            VulkanCommand::FreeCommandBuffers => &[
+                ExternSyncParam {
+                    name: "commandPool",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "pCommandBuffers",
+                    extern_sync: ExternSync::Param,
+                },
+            ],
+            VulkanCommand::BeginCommandBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::EndCommandBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::ResetCommandBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindPipeline => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetViewport => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetScissor => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetLineWidth => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthBias => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetBlendConstants => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthBounds => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetStencilCompareMask => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetStencilWriteMask => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetStencilReference => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindDescriptorSets => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindIndexBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindVertexBuffers => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDraw => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawIndexed => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawIndirect => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawIndexedIndirect => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDispatch => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDispatchIndirect => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyImage => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBlitImage => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyBufferToImage => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyImageToBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdUpdateBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdFillBuffer => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdClearColorImage => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdClearDepthStencilImage => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdClearAttachments => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdResolveImage => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetEvent => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdResetEvent => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWaitEvents => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdPipelineBarrier => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginQuery => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndQuery => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdResetQueryPool => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWriteTimestamp => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyQueryPoolResults => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdPushConstants => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginRenderPass => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdNextSubpass => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndRenderPass => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdExecuteCommands => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDeviceMask => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDispatchBase => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::TrimCommandPool => &[ExternSyncParam {
+                name: "commandPool",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroySamplerYcbcrConversion => &[ExternSyncParam {
+                name: "ycbcrConversion",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyDescriptorUpdateTemplate => &[ExternSyncParam {
+                name: "descriptorUpdateTemplate",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::UpdateDescriptorSetWithTemplate => &[ExternSyncParam {
+                name: "descriptorSet",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawIndirectCount => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawIndexedIndirectCount => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginRenderPass2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdNextSubpass2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndRenderPass2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyPrivateDataSlot => &[ExternSyncParam {
+                name: "privateDataSlot",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetEvent2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdResetEvent2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWaitEvents2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdPipelineBarrier2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWriteTimestamp2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::QueueSubmit2 => &[
+                ExternSyncParam {
+                    name: "queue",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "fence",
+                    extern_sync: ExternSync::Param,
+                },
+            ],
+            VulkanCommand::CmdCopyBuffer2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyImage2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyBufferToImage2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyImageToBuffer2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBlitImage2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdResolveImage2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginRendering => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndRendering => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCullMode => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetFrontFace => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetPrimitiveTopology => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetViewportWithCount => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetScissorWithCount => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindVertexBuffers2 => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthTestEnable => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthWriteEnable => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthCompareOp => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthBoundsTestEnable => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetStencilTestEnable => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetStencilOp => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetRasterizerDiscardEnable => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthBiasEnable => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetPrimitiveRestartEnable => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CreateSwapchainKhr => &[ExternSyncParam {
+                name: "pCreateInfo",
+                extern_sync: ExternSync::Members(&[
+                    "pCreateInfo->surface",
+                    "pCreateInfo->oldSwapchain",
+                ]),
+            }],
+            VulkanCommand::DestroySwapchainKhr => &[ExternSyncParam {
+                name: "swapchain",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::AcquireNextImageKhr => &[
+                ExternSyncParam {
+                    name: "swapchain",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "semaphore",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "fence",
+                    extern_sync: ExternSync::Param,
+                },
+            ],
+            VulkanCommand::QueuePresentKhr => &[
+                ExternSyncParam {
+                    name: "queue",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "pPresentInfo",
+                    extern_sync: ExternSync::Members(&[
+                        "pPresentInfo->pWaitSemaphores[]",
+                        "pPresentInfo->pSwapchains[]",
+                    ]),
+                },
+            ],
+            VulkanCommand::CreateSharedSwapchainsKhr => &[ExternSyncParam {
+                name: "pCreateInfos",
+                extern_sync: ExternSync::Members(&[
+                    "pCreateInfos[].surface",
+                    "pCreateInfos[].oldSwapchain",
+                ]),
+            }],
+            VulkanCommand::DestroyVideoSessionKhr => &[ExternSyncParam {
+                name: "videoSession",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::BindVideoSessionMemoryKhr => &[ExternSyncParam {
+                name: "videoSession",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::UpdateVideoSessionParametersKhr => &[ExternSyncParam {
+                name: "videoSessionParameters",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyVideoSessionParametersKhr => &[ExternSyncParam {
+                name: "videoSessionParameters",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginVideoCodingKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndVideoCodingKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdControlVideoCodingKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDecodeVideoKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdPushDescriptorSetKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdPushDescriptorSetWithTemplateKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::GetSwapchainStatusKhr => &[ExternSyncParam {
+                name: "swapchain",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetFragmentShadingRateKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::WaitForPresentKhr => &[ExternSyncParam {
+                name: "swapchain",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyDeferredOperationKhr => &[ExternSyncParam {
+                name: "operation",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEncodeVideoKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWriteBufferMarker2Amd => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdTraceRaysIndirect2Khr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DebugMarkerSetObjectTagExt => &[ExternSyncParam {
+                name: "pTagInfo",
+                extern_sync: ExternSync::Members(&["pTagInfo->object"]),
+            }],
+            VulkanCommand::DebugMarkerSetObjectNameExt => &[ExternSyncParam {
+                name: "pNameInfo",
+                extern_sync: ExternSync::Members(&["pNameInfo->object"]),
+            }],
+            VulkanCommand::CmdDebugMarkerBeginExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDebugMarkerEndExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDebugMarkerInsertExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindTransformFeedbackBuffersExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginTransformFeedbackExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndTransformFeedbackExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginQueryIndexedExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndQueryIndexedExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawIndirectByteCountExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyCuModuleNvx => &[ExternSyncParam {
+                name: "module",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyCuFunctionNvx => &[ExternSyncParam {
+                name: "function",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCuLaunchKernelNvx => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBeginConditionalRenderingExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndConditionalRenderingExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetViewportWScalingNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::GetRefreshCycleDurationGoogle => &[ExternSyncParam {
+                name: "swapchain",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::GetPastPresentationTimingGoogle => &[ExternSyncParam {
+                name: "swapchain",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDiscardRectangleExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::SetDebugUtilsObjectNameExt => &[ExternSyncParam {
+                name: "pNameInfo",
+                extern_sync: ExternSync::Members(&["pNameInfo->objectHandle"]),
+            }],
+            VulkanCommand::SetDebugUtilsObjectTagExt => &[ExternSyncParam {
+                name: "pTagInfo",
+                extern_sync: ExternSync::Members(&["pTagInfo->objectHandle"]),
+            }],
+            VulkanCommand::CmdBeginDebugUtilsLabelExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdEndDebugUtilsLabelExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdInsertDebugUtilsLabelExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetSampleLocationsExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyValidationCacheExt => &[ExternSyncParam {
+                name: "validationCache",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::MergeValidationCachesExt => &[ExternSyncParam {
+                name: "dstCache",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindShadingRateImageNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetViewportShadingRatePaletteNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCoarseSampleOrderNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyAccelerationStructureNv => &[ExternSyncParam {
+                name: "accelerationStructure",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBuildAccelerationStructureNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyAccelerationStructureNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdTraceRaysNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWriteAccelerationStructuresPropertiesNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWriteBufferMarkerAmd => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMeshTasksNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMeshTasksIndirectNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMeshTasksIndirectCountNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetExclusiveScissorNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCheckpointNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetPerformanceMarkerIntel => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetPerformanceStreamMarkerIntel => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetPerformanceOverrideIntel => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetLineStippleExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::ReleaseSwapchainImagesExt => &[ExternSyncParam {
+                name: "pReleaseInfo",
+                extern_sync: ExternSync::Members(&["pReleaseInfo->swapchain"]),
+            }],
+            VulkanCommand::CmdPreprocessGeneratedCommandsNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdExecuteGeneratedCommandsNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindPipelineShaderGroupNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyIndirectCommandsLayoutNv => &[ExternSyncParam {
+                name: "indirectCommandsLayout",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindDescriptorBuffersExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDescriptorBufferOffsetsExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindDescriptorBufferEmbeddedSamplersExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetFragmentShadingRateEnumNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetVertexInputExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyBufferCollectionFuchsia => &[ExternSyncParam {
+                name: "collection",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSubpassShadingHuawei => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBindInvocationMaskHuawei => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetPatchControlPointsExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetLogicOpExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetColorWriteEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMultiExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMultiIndexedExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyMicromapExt => &[ExternSyncParam {
+                name: "micromap",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdBuildMicromapsExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyMicromapExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyMicromapToMemoryExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyMemoryToMicromapExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWriteMicromapsPropertiesExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyMemoryIndirectNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyMemoryToImageIndirectNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDecompressMemoryNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDecompressMemoryIndirectCountNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetTessellationDomainOriginExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthClampEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetPolygonModeExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetRasterizationSamplesExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetSampleMaskExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetAlphaToCoverageEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetAlphaToOneEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetLogicOpEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetColorBlendEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetColorBlendEquationExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetColorWriteMaskExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetRasterizationStreamExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetConservativeRasterizationModeExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetExtraPrimitiveOverestimationSizeExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthClipEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetSampleLocationsEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetColorBlendAdvancedExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetProvokingVertexModeExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetLineRasterizationModeExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetLineStippleEnableExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetDepthClipNegativeOneToOneExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetViewportWScalingEnableNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetViewportSwizzleNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCoverageToColorEnableNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCoverageToColorLocationNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCoverageModulationModeNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCoverageModulationTableEnableNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCoverageModulationTableNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetShadingRateImageEnableNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetRepresentativeFragmentTestEnableNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetCoverageReductionModeNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyOpticalFlowSessionNv => &[ExternSyncParam {
+                name: "session",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdOpticalFlowExecuteNv => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::DestroyAccelerationStructureKhr => &[ExternSyncParam {
+                name: "accelerationStructure",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyAccelerationStructureKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyAccelerationStructureToMemoryKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdCopyMemoryToAccelerationStructureKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdWriteAccelerationStructuresPropertiesKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdTraceRaysKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdTraceRaysIndirectKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdSetRayTracingPipelineStackSizeKhr => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMeshTasksExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMeshTasksIndirectExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            VulkanCommand::CmdDrawMeshTasksIndirectCountExt => &[ExternSyncParam {
+                name: "commandBuffer",
+                extern_sync: ExternSync::Param,
+            }],
+            _ => &[],
+        }
+    }
+}
+
+// The variants are numbered from 0 without gaps, so the last one must be COUNT - 1.
+const _: () =
+    assert!(VulkanCommand::CmdDrawMeshTasksIndirectCountExt as usize == VulkanCommand::COUNT - 1);
+
 impl TryFrom<&str> for VulkanCommand {
     type Error = TryFromVulkanCommandError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
             "vkCreateInstance" => Ok(VulkanCommand::CreateInstance),
+            "vkEnumerateInstanceExtensionProperties" => {
+                Ok(VulkanCommand::EnumerateInstanceExtensionProperties)
+            }
+            "vkEnumerateInstanceLayerProperties" => {
+                Ok(VulkanCommand::EnumerateInstanceLayerProperties)
+            }
+            "vkEnumerateInstanceVersion" => Ok(VulkanCommand::EnumerateInstanceVersion),
             "vkGetPhysicalDeviceFeatures" => Ok(VulkanCommand::GetPhysicalDeviceFeatures),
             "vkGetPhysicalDeviceFormatProperties" => {
                 Ok(VulkanCommand::GetPhysicalDeviceFormatProperties)
@@ -1419,97 +3124,185 @@ pub trait DeviceHooks: Send + Sync {
         &self,
         _queue_family_index: u32,
         _queue_index: u32,
-    ) -> LayerResult<vk::Queue> {
+    ) -> LayerResult<vk::Queue, GetDeviceQueueArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_queue(
+        &self,
+        _queue_family_index: u32,
+        _queue_index: u32,
+        _result: vk::Queue,
+    ) {
+    }
     fn queue_submit(
         &self,
         _queue: vk::Queue,
         _p_submits: &[vk::SubmitInfo],
         _fence: vk::Fence,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, QueueSubmitArgs> {
         LayerResult::Unhandled
     }
-    fn queue_wait_idle(&self, _queue: vk::Queue) -> LayerResult<VkResult<()>> {
+    fn after_queue_submit(
+        &self,
+        _queue: vk::Queue,
+        _p_submits: &[vk::SubmitInfo],
+        _fence: vk::Fence,
+        _result: vk::Result,
+    ) {
+    }
+    fn queue_wait_idle(&self, _queue: vk::Queue) -> LayerResult<VkResult<()>, QueueWaitIdleArgs> {
         LayerResult::Unhandled
     }
-    fn device_wait_idle(&self) -> LayerResult<VkResult<()>> {
+    fn after_queue_wait_idle(&self, _queue: vk::Queue, _result: vk::Result) {}
+    fn device_wait_idle(&self) -> LayerResult<VkResult<()>, DeviceWaitIdleArgs> {
         LayerResult::Unhandled
     }
+    fn after_device_wait_idle(&self, _result: vk::Result) {}
     fn allocate_memory(
         &self,
         _p_allocate_info: &vk::MemoryAllocateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DeviceMemory>> {
+    ) -> LayerResult<VkResult<vk::DeviceMemory>, AllocateMemoryArgs> {
         LayerResult::Unhandled
     }
+    fn after_allocate_memory(
+        &self,
+        _p_allocate_info: &vk::MemoryAllocateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_memory: Option<vk::DeviceMemory>,
+        _result: vk::Result,
+    ) {
+    }
     fn free_memory(
         &self,
         _memory: vk::DeviceMemory,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), FreeMemoryArgs> {
         LayerResult::Unhandled
     }
+    fn after_free_memory(
+        &self,
+        _memory: vk::DeviceMemory,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn map_memory(
         &self,
         _memory: vk::DeviceMemory,
         _offset: vk::DeviceSize,
         _size: vk::DeviceSize,
         _flags: vk::MemoryMapFlags,
-    ) -> LayerResult<VkResult<Option<*mut c_void>>> {
+    ) -> LayerResult<VkResult<Option<*mut c_void>>, MapMemoryArgs> {
         LayerResult::Unhandled
     }
-    fn unmap_memory(&self, _memory: vk::DeviceMemory) -> LayerResult<()> {
+    fn after_map_memory(
+        &self,
+        _memory: vk::DeviceMemory,
+        _offset: vk::DeviceSize,
+        _size: vk::DeviceSize,
+        _flags: vk::MemoryMapFlags,
+        _pp_data: Option<Option<*mut c_void>>,
+        _result: vk::Result,
+    ) {
+    }
+    fn unmap_memory(&self, _memory: vk::DeviceMemory) -> LayerResult<(), UnmapMemoryArgs> {
         LayerResult::Unhandled
     }
+    fn after_unmap_memory(&self, _memory: vk::DeviceMemory) {}
     fn flush_mapped_memory_ranges(
         &self,
         _p_memory_ranges: &[vk::MappedMemoryRange],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, FlushMappedMemoryRangesArgs> {
         LayerResult::Unhandled
     }
+    fn after_flush_mapped_memory_ranges(
+        &self,
+        _p_memory_ranges: &[vk::MappedMemoryRange],
+        _result: vk::Result,
+    ) {
+    }
     fn invalidate_mapped_memory_ranges(
         &self,
         _p_memory_ranges: &[vk::MappedMemoryRange],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, InvalidateMappedMemoryRangesArgs> {
         LayerResult::Unhandled
     }
+    fn after_invalidate_mapped_memory_ranges(
+        &self,
+        _p_memory_ranges: &[vk::MappedMemoryRange],
+        _result: vk::Result,
+    ) {
+    }
     fn get_device_memory_commitment(
         &self,
         _memory: vk::DeviceMemory,
-    ) -> LayerResult<vk::DeviceSize> {
+    ) -> LayerResult<vk::DeviceSize, GetDeviceMemoryCommitmentArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_memory_commitment(
+        &self,
+        _memory: vk::DeviceMemory,
+        _result: vk::DeviceSize,
+    ) {
+    }
     fn bind_buffer_memory(
         &self,
         _buffer: vk::Buffer,
         _memory: vk::DeviceMemory,
         _memory_offset: vk::DeviceSize,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BindBufferMemoryArgs> {
         LayerResult::Unhandled
     }
+    fn after_bind_buffer_memory(
+        &self,
+        _buffer: vk::Buffer,
+        _memory: vk::DeviceMemory,
+        _memory_offset: vk::DeviceSize,
+        _result: vk::Result,
+    ) {
+    }
     fn bind_image_memory(
         &self,
         _image: vk::Image,
         _memory: vk::DeviceMemory,
         _memory_offset: vk::DeviceSize,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BindImageMemoryArgs> {
         LayerResult::Unhandled
     }
+    fn after_bind_image_memory(
+        &self,
+        _image: vk::Image,
+        _memory: vk::DeviceMemory,
+        _memory_offset: vk::DeviceSize,
+        _result: vk::Result,
+    ) {
+    }
     fn get_buffer_memory_requirements(
         &self,
         _buffer: vk::Buffer,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetBufferMemoryRequirementsArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_buffer_memory_requirements(
+        &self,
+        _buffer: vk::Buffer,
+        _p_memory_requirements: &vk::MemoryRequirements,
+    ) {
+    }
     fn get_image_memory_requirements(
         &self,
         _image: vk::Image,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetImageMemoryRequirementsArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_memory_requirements(
+        &self,
+        _image: vk::Image,
+        _p_memory_requirements: &vk::MemoryRequirements,
+    ) {
+    }
     fn get_image_sparse_memory_requirements(
         &self,
         _image: vk::Image,
@@ -1517,96 +3310,180 @@ pub trait DeviceHooks: Send + Sync {
         _p_sparse_memory_requirements: Option<
             &mut [MaybeUninit<vk::SparseImageMemoryRequirements>],
         >,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetImageSparseMemoryRequirementsArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_sparse_memory_requirements(
+        &self,
+        _image: vk::Image,
+        _p_sparse_memory_requirement_count: &u32,
+        _p_sparse_memory_requirements: Option<&[vk::SparseImageMemoryRequirements]>,
+    ) {
+    }
     fn queue_bind_sparse(
         &self,
         _queue: vk::Queue,
         _p_bind_info: &[vk::BindSparseInfo],
         _fence: vk::Fence,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, QueueBindSparseArgs> {
         LayerResult::Unhandled
     }
+    fn after_queue_bind_sparse(
+        &self,
+        _queue: vk::Queue,
+        _p_bind_info: &[vk::BindSparseInfo],
+        _fence: vk::Fence,
+        _result: vk::Result,
+    ) {
+    }
     fn create_fence(
         &self,
         _p_create_info: &vk::FenceCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Fence>> {
+    ) -> LayerResult<VkResult<vk::Fence>, CreateFenceArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_fence(
+        &self,
+        _p_create_info: &vk::FenceCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_fence: Option<vk::Fence>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_fence(
         &self,
         _fence: vk::Fence,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyFenceArgs> {
         LayerResult::Unhandled
     }
-    fn reset_fences(&self, _p_fences: &[vk::Fence]) -> LayerResult<VkResult<()>> {
+    fn after_destroy_fence(
+        &self,
+        _fence: vk::Fence,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
+    fn reset_fences(&self, _p_fences: &[vk::Fence]) -> LayerResult<VkResult<()>, ResetFencesArgs> {
         LayerResult::Unhandled
     }
-    fn get_fence_status(&self, _fence: vk::Fence) -> LayerResult<VkResult<()>> {
+    fn after_reset_fences(&self, _p_fences: &[vk::Fence], _result: vk::Result) {}
+    fn get_fence_status(&self, _fence: vk::Fence) -> LayerResult<VkResult<()>, GetFenceStatusArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_fence_status(&self, _fence: vk::Fence, _result: vk::Result) {}
     fn wait_for_fences(
         &self,
         _p_fences: &[vk::Fence],
         _wait_all: bool,
         _timeout: u64,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, WaitForFencesArgs> {
         LayerResult::Unhandled
     }
+    fn after_wait_for_fences(
+        &self,
+        _p_fences: &[vk::Fence],
+        _wait_all: bool,
+        _timeout: u64,
+        _result: vk::Result,
+    ) {
+    }
     fn create_semaphore(
         &self,
         _p_create_info: &vk::SemaphoreCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Semaphore>> {
+    ) -> LayerResult<VkResult<vk::Semaphore>, CreateSemaphoreArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_semaphore(
+        &self,
+        _p_create_info: &vk::SemaphoreCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_semaphore: Option<vk::Semaphore>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_semaphore(
         &self,
         _semaphore: vk::Semaphore,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroySemaphoreArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_semaphore(
+        &self,
+        _semaphore: vk::Semaphore,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_event(
         &self,
         _p_create_info: &vk::EventCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Event>> {
+    ) -> LayerResult<VkResult<vk::Event>, CreateEventArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_event(
+        &self,
+        _p_create_info: &vk::EventCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_event: Option<vk::Event>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_event(
         &self,
         _event: vk::Event,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyEventArgs> {
         LayerResult::Unhandled
     }
-    fn get_event_status(&self, _event: vk::Event) -> LayerResult<VkResult<()>> {
+    fn after_destroy_event(
+        &self,
+        _event: vk::Event,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
+    fn get_event_status(&self, _event: vk::Event) -> LayerResult<VkResult<()>, GetEventStatusArgs> {
         LayerResult::Unhandled
     }
-    fn set_event(&self, _event: vk::Event) -> LayerResult<VkResult<()>> {
+    fn after_get_event_status(&self, _event: vk::Event, _result: vk::Result) {}
+    fn set_event(&self, _event: vk::Event) -> LayerResult<VkResult<()>, SetEventArgs> {
         LayerResult::Unhandled
     }
-    fn reset_event(&self, _event: vk::Event) -> LayerResult<VkResult<()>> {
+    fn after_set_event(&self, _event: vk::Event, _result: vk::Result) {}
+    fn reset_event(&self, _event: vk::Event) -> LayerResult<VkResult<()>, ResetEventArgs> {
         LayerResult::Unhandled
     }
+    fn after_reset_event(&self, _event: vk::Event, _result: vk::Result) {}
     fn create_query_pool(
         &self,
         _p_create_info: &vk::QueryPoolCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::QueryPool>> {
+    ) -> LayerResult<VkResult<vk::QueryPool>, CreateQueryPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_query_pool(
+        &self,
+        _p_create_info: &vk::QueryPoolCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_query_pool: Option<vk::QueryPool>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_query_pool(
         &self,
         _query_pool: vk::QueryPool,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyQueryPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_query_pool(
+        &self,
+        _query_pool: vk::QueryPool,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_query_pool_results(
         &self,
         _query_pool: vk::QueryPool,
@@ -1615,387 +3492,761 @@ pub trait DeviceHooks: Send + Sync {
         _p_data: &mut [MaybeUninit<u8>],
         _stride: vk::DeviceSize,
         _flags: vk::QueryResultFlags,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetQueryPoolResultsArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_query_pool_results(
+        &self,
+        _query_pool: vk::QueryPool,
+        _first_query: u32,
+        _query_count: u32,
+        _p_data: Option<&[u8]>,
+        _stride: vk::DeviceSize,
+        _flags: vk::QueryResultFlags,
+        _result: vk::Result,
+    ) {
+    }
     fn create_buffer(
         &self,
         _p_create_info: &vk::BufferCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Buffer>> {
+    ) -> LayerResult<VkResult<vk::Buffer>, CreateBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_buffer(
+        &self,
+        _p_create_info: &vk::BufferCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_buffer: Option<vk::Buffer>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_buffer(
         &self,
         _buffer: vk::Buffer,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_buffer(
+        &self,
+        _buffer: vk::Buffer,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_buffer_view(
         &self,
         _p_create_info: &vk::BufferViewCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::BufferView>> {
+    ) -> LayerResult<VkResult<vk::BufferView>, CreateBufferViewArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_buffer_view(
+        &self,
+        _p_create_info: &vk::BufferViewCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_view: Option<vk::BufferView>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_buffer_view(
         &self,
         _buffer_view: vk::BufferView,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyBufferViewArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_buffer_view(
+        &self,
+        _buffer_view: vk::BufferView,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_image(
         &self,
         _p_create_info: &vk::ImageCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Image>> {
+    ) -> LayerResult<VkResult<vk::Image>, CreateImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_image(
+        &self,
+        _p_create_info: &vk::ImageCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_image: Option<vk::Image>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_image(
         &self,
         _image: vk::Image,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_image(
+        &self,
+        _image: vk::Image,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_image_subresource_layout(
         &self,
         _image: vk::Image,
         _p_subresource: &vk::ImageSubresource,
         _p_layout: &mut MaybeUninit<vk::SubresourceLayout>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetImageSubresourceLayoutArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_subresource_layout(
+        &self,
+        _image: vk::Image,
+        _p_subresource: &vk::ImageSubresource,
+        _p_layout: &vk::SubresourceLayout,
+    ) {
+    }
     fn create_image_view(
         &self,
         _p_create_info: &vk::ImageViewCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::ImageView>> {
+    ) -> LayerResult<VkResult<vk::ImageView>, CreateImageViewArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_image_view(
+        &self,
+        _p_create_info: &vk::ImageViewCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_view: Option<vk::ImageView>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_image_view(
         &self,
         _image_view: vk::ImageView,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyImageViewArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_image_view(
+        &self,
+        _image_view: vk::ImageView,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_shader_module(
         &self,
         _p_create_info: &vk::ShaderModuleCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::ShaderModule>> {
+    ) -> LayerResult<VkResult<vk::ShaderModule>, CreateShaderModuleArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_shader_module(
+        &self,
+        _p_create_info: &vk::ShaderModuleCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_shader_module: Option<vk::ShaderModule>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_shader_module(
         &self,
         _shader_module: vk::ShaderModule,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyShaderModuleArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_shader_module(
+        &self,
+        _shader_module: vk::ShaderModule,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_pipeline_cache(
         &self,
         _p_create_info: &vk::PipelineCacheCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::PipelineCache>> {
+    ) -> LayerResult<VkResult<vk::PipelineCache>, CreatePipelineCacheArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_pipeline_cache(
+        &self,
+        _p_create_info: &vk::PipelineCacheCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_pipeline_cache: Option<vk::PipelineCache>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_pipeline_cache(
         &self,
         _pipeline_cache: vk::PipelineCache,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyPipelineCacheArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_pipeline_cache(
+        &self,
+        _pipeline_cache: vk::PipelineCache,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_pipeline_cache_data(
         &self,
         _pipeline_cache: vk::PipelineCache,
         _p_data_size: &mut MaybeUninit<usize>,
         _p_data: Option<&mut [MaybeUninit<u8>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPipelineCacheDataArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_pipeline_cache_data(
+        &self,
+        _pipeline_cache: vk::PipelineCache,
+        _p_data_size: &usize,
+        _p_data: Option<&[u8]>,
+        _result: vk::Result,
+    ) {
+    }
     fn merge_pipeline_caches(
         &self,
         _dst_cache: vk::PipelineCache,
         _p_src_caches: &[vk::PipelineCache],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, MergePipelineCachesArgs> {
         LayerResult::Unhandled
     }
+    fn after_merge_pipeline_caches(
+        &self,
+        _dst_cache: vk::PipelineCache,
+        _p_src_caches: &[vk::PipelineCache],
+        _result: vk::Result,
+    ) {
+    }
     fn create_graphics_pipelines(
         &self,
         _pipeline_cache: vk::PipelineCache,
         _p_create_infos: &[vk::GraphicsPipelineCreateInfo],
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>> {
+    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>, CreateGraphicsPipelinesArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_graphics_pipelines(
+        &self,
+        _pipeline_cache: vk::PipelineCache,
+        _p_create_infos: &[vk::GraphicsPipelineCreateInfo],
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_pipelines: Option<&[vk::Pipeline]>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_compute_pipelines(
         &self,
         _pipeline_cache: vk::PipelineCache,
         _p_create_infos: &[vk::ComputePipelineCreateInfo],
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>> {
+    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>, CreateComputePipelinesArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_compute_pipelines(
+        &self,
+        _pipeline_cache: vk::PipelineCache,
+        _p_create_infos: &[vk::ComputePipelineCreateInfo],
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_pipelines: Option<&[vk::Pipeline]>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_pipeline(
         &self,
         _pipeline: vk::Pipeline,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyPipelineArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_pipeline(
+        &self,
+        _pipeline: vk::Pipeline,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_pipeline_layout(
         &self,
         _p_create_info: &vk::PipelineLayoutCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::PipelineLayout>> {
+    ) -> LayerResult<VkResult<vk::PipelineLayout>, CreatePipelineLayoutArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_pipeline_layout(
+        &self,
+        _p_create_info: &vk::PipelineLayoutCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_pipeline_layout: Option<vk::PipelineLayout>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_pipeline_layout(
         &self,
         _pipeline_layout: vk::PipelineLayout,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyPipelineLayoutArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_pipeline_layout(
+        &self,
+        _pipeline_layout: vk::PipelineLayout,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_sampler(
         &self,
         _p_create_info: &vk::SamplerCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Sampler>> {
+    ) -> LayerResult<VkResult<vk::Sampler>, CreateSamplerArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_sampler(
+        &self,
+        _p_create_info: &vk::SamplerCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_sampler: Option<vk::Sampler>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_sampler(
         &self,
         _sampler: vk::Sampler,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroySamplerArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_sampler(
+        &self,
+        _sampler: vk::Sampler,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_descriptor_set_layout(
         &self,
         _p_create_info: &vk::DescriptorSetLayoutCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DescriptorSetLayout>> {
+    ) -> LayerResult<VkResult<vk::DescriptorSetLayout>, CreateDescriptorSetLayoutArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_descriptor_set_layout(
+        &self,
+        _p_create_info: &vk::DescriptorSetLayoutCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_set_layout: Option<vk::DescriptorSetLayout>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_descriptor_set_layout(
         &self,
         _descriptor_set_layout: vk::DescriptorSetLayout,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyDescriptorSetLayoutArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_descriptor_set_layout(
+        &self,
+        _descriptor_set_layout: vk::DescriptorSetLayout,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_descriptor_pool(
         &self,
         _p_create_info: &vk::DescriptorPoolCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DescriptorPool>> {
+    ) -> LayerResult<VkResult<vk::DescriptorPool>, CreateDescriptorPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_descriptor_pool(
+        &self,
+        _p_create_info: &vk::DescriptorPoolCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_descriptor_pool: Option<vk::DescriptorPool>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_descriptor_pool(
         &self,
         _descriptor_pool: vk::DescriptorPool,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyDescriptorPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_descriptor_pool(
+        &self,
+        _descriptor_pool: vk::DescriptorPool,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn reset_descriptor_pool(
         &self,
         _descriptor_pool: vk::DescriptorPool,
         _flags: vk::DescriptorPoolResetFlags,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ResetDescriptorPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_reset_descriptor_pool(
+        &self,
+        _descriptor_pool: vk::DescriptorPool,
+        _flags: vk::DescriptorPoolResetFlags,
+        _result: vk::Result,
+    ) {
+    }
     fn allocate_descriptor_sets(
         &self,
         _p_allocate_info: &vk::DescriptorSetAllocateInfo,
-    ) -> LayerResult<VkResult<Vec<vk::DescriptorSet>>> {
+    ) -> LayerResult<VkResult<Vec<vk::DescriptorSet>>, AllocateDescriptorSetsArgs> {
         LayerResult::Unhandled
     }
+    fn after_allocate_descriptor_sets(
+        &self,
+        _p_allocate_info: &vk::DescriptorSetAllocateInfo,
+        _p_descriptor_sets: Option<&[vk::DescriptorSet]>,
+        _result: vk::Result,
+    ) {
+    }
     fn free_descriptor_sets(
         &self,
         _descriptor_pool: vk::DescriptorPool,
         _p_descriptor_sets: &[vk::DescriptorSet],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, FreeDescriptorSetsArgs> {
         LayerResult::Unhandled
     }
+    fn after_free_descriptor_sets(
+        &self,
+        _descriptor_pool: vk::DescriptorPool,
+        _p_descriptor_sets: &[vk::DescriptorSet],
+        _result: vk::Result,
+    ) {
+    }
     fn update_descriptor_sets(
         &self,
         _p_descriptor_writes: &[vk::WriteDescriptorSet],
         _p_descriptor_copies: &[vk::CopyDescriptorSet],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), UpdateDescriptorSetsArgs> {
         LayerResult::Unhandled
     }
+    fn after_update_descriptor_sets(
+        &self,
+        _p_descriptor_writes: &[vk::WriteDescriptorSet],
+        _p_descriptor_copies: &[vk::CopyDescriptorSet],
+    ) {
+    }
     fn create_framebuffer(
         &self,
         _p_create_info: &vk::FramebufferCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Framebuffer>> {
+    ) -> LayerResult<VkResult<vk::Framebuffer>, CreateFramebufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_framebuffer(
+        &self,
+        _p_create_info: &vk::FramebufferCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_framebuffer: Option<vk::Framebuffer>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_framebuffer(
         &self,
         _framebuffer: vk::Framebuffer,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyFramebufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_framebuffer(
+        &self,
+        _framebuffer: vk::Framebuffer,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_render_pass(
         &self,
         _p_create_info: &vk::RenderPassCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::RenderPass>> {
+    ) -> LayerResult<VkResult<vk::RenderPass>, CreateRenderPassArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_render_pass(
+        &self,
+        _p_create_info: &vk::RenderPassCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_render_pass: Option<vk::RenderPass>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_render_pass(
         &self,
         _render_pass: vk::RenderPass,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyRenderPassArgs> {
         LayerResult::Unhandled
     }
-    fn get_render_area_granularity(
+    fn after_destroy_render_pass(
         &self,
         _render_pass: vk::RenderPass,
-        _p_granularity: &mut MaybeUninit<vk::Extent2D>,
-    ) -> LayerResult<()> {
-        LayerResult::Unhandled
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
+    fn get_render_area_granularity(
+        &self,
+        _render_pass: vk::RenderPass,
+        _p_granularity: &mut MaybeUninit<vk::Extent2D>,
+    ) -> LayerResult<(), GetRenderAreaGranularityArgs> {
+        LayerResult::Unhandled
+    }
+    fn after_get_render_area_granularity(
+        &self,
+        _render_pass: vk::RenderPass,
+        _p_granularity: &vk::Extent2D,
+    ) {
     }
     fn create_command_pool(
         &self,
         _p_create_info: &vk::CommandPoolCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::CommandPool>> {
+    ) -> LayerResult<VkResult<vk::CommandPool>, CreateCommandPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_command_pool(
+        &self,
+        _p_create_info: &vk::CommandPoolCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_command_pool: Option<vk::CommandPool>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_command_pool(
         &self,
         _command_pool: vk::CommandPool,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyCommandPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_command_pool(
+        &self,
+        _command_pool: vk::CommandPool,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn reset_command_pool(
         &self,
         _command_pool: vk::CommandPool,
         _flags: vk::CommandPoolResetFlags,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ResetCommandPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_reset_command_pool(
+        &self,
+        _command_pool: vk::CommandPool,
+        _flags: vk::CommandPoolResetFlags,
+        _result: vk::Result,
+    ) {
+    }
     fn allocate_command_buffers(
         &self,
         _p_allocate_info: &vk::CommandBufferAllocateInfo,
-    ) -> LayerResult<VkResult<Vec<vk::CommandBuffer>>> {
+    ) -> LayerResult<VkResult<Vec<vk::CommandBuffer>>, AllocateCommandBuffersArgs> {
         LayerResult::Unhandled
     }
+    fn after_allocate_command_buffers(
+        &self,
+        _p_allocate_info: &vk::CommandBufferAllocateInfo,
+        _p_command_buffers: Option<&[vk::CommandBuffer]>,
+        _result: vk::Result,
+    ) {
+    }
     fn free_command_buffers(
         &self,
         _command_pool: vk::CommandPool,
         _p_command_buffers: &[vk::CommandBuffer],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), FreeCommandBuffersArgs> {
         LayerResult::Unhandled
     }
+    fn after_free_command_buffers(
+        &self,
+        _command_pool: vk::CommandPool,
+        _p_command_buffers: &[vk::CommandBuffer],
+    ) {
+    }
     fn begin_command_buffer(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_begin_info: &vk::CommandBufferBeginInfo,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BeginCommandBufferArgs> {
         LayerResult::Unhandled
     }
-    fn end_command_buffer(&self, _command_buffer: vk::CommandBuffer) -> LayerResult<VkResult<()>> {
+    fn after_begin_command_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_begin_info: &vk::CommandBufferBeginInfo,
+        _result: vk::Result,
+    ) {
+    }
+    fn end_command_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+    ) -> LayerResult<VkResult<()>, EndCommandBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_end_command_buffer(&self, _command_buffer: vk::CommandBuffer, _result: vk::Result) {}
     fn reset_command_buffer(
         &self,
         _command_buffer: vk::CommandBuffer,
         _flags: vk::CommandBufferResetFlags,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ResetCommandBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_reset_command_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _flags: vk::CommandBufferResetFlags,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_bind_pipeline(
         &self,
         _command_buffer: vk::CommandBuffer,
         _pipeline_bind_point: vk::PipelineBindPoint,
         _pipeline: vk::Pipeline,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindPipelineArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_pipeline(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_bind_point: vk::PipelineBindPoint,
+        _pipeline: vk::Pipeline,
+    ) {
+    }
     fn cmd_set_viewport(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_viewport: u32,
         _p_viewports: &[vk::Viewport],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetViewportArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_viewport(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_viewport: u32,
+        _p_viewports: &[vk::Viewport],
+    ) {
+    }
     fn cmd_set_scissor(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_scissor: u32,
         _p_scissors: &[vk::Rect2D],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetScissorArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_scissor(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_scissor: u32,
+        _p_scissors: &[vk::Rect2D],
+    ) {
+    }
     fn cmd_set_line_width(
         &self,
         _command_buffer: vk::CommandBuffer,
         _line_width: f32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetLineWidthArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_line_width(&self, _command_buffer: vk::CommandBuffer, _line_width: f32) {}
     fn cmd_set_depth_bias(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_bias_constant_factor: f32,
         _depth_bias_clamp: f32,
         _depth_bias_slope_factor: f32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthBiasArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_bias(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_bias_constant_factor: f32,
+        _depth_bias_clamp: f32,
+        _depth_bias_slope_factor: f32,
+    ) {
+    }
     fn cmd_set_blend_constants(
         &self,
         _command_buffer: vk::CommandBuffer,
         _blend_constants: &[f32; 4],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetBlendConstantsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_blend_constants(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _blend_constants: &[f32; 4],
+    ) {
+    }
     fn cmd_set_depth_bounds(
         &self,
         _command_buffer: vk::CommandBuffer,
         _min_depth_bounds: f32,
         _max_depth_bounds: f32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthBoundsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_bounds(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _min_depth_bounds: f32,
+        _max_depth_bounds: f32,
+    ) {
+    }
     fn cmd_set_stencil_compare_mask(
         &self,
         _command_buffer: vk::CommandBuffer,
         _face_mask: vk::StencilFaceFlags,
         _compare_mask: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetStencilCompareMaskArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_stencil_compare_mask(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _face_mask: vk::StencilFaceFlags,
+        _compare_mask: u32,
+    ) {
+    }
     fn cmd_set_stencil_write_mask(
         &self,
         _command_buffer: vk::CommandBuffer,
         _face_mask: vk::StencilFaceFlags,
         _write_mask: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetStencilWriteMaskArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_stencil_write_mask(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _face_mask: vk::StencilFaceFlags,
+        _write_mask: u32,
+    ) {
+    }
     fn cmd_set_stencil_reference(
         &self,
         _command_buffer: vk::CommandBuffer,
         _face_mask: vk::StencilFaceFlags,
         _reference: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetStencilReferenceArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_stencil_reference(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _face_mask: vk::StencilFaceFlags,
+        _reference: u32,
+    ) {
+    }
     fn cmd_bind_descriptor_sets(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2004,27 +4255,53 @@ pub trait DeviceHooks: Send + Sync {
         _first_set: u32,
         _p_descriptor_sets: &[vk::DescriptorSet],
         _p_dynamic_offsets: &[u32],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindDescriptorSetsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_descriptor_sets(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_bind_point: vk::PipelineBindPoint,
+        _layout: vk::PipelineLayout,
+        _first_set: u32,
+        _p_descriptor_sets: &[vk::DescriptorSet],
+        _p_dynamic_offsets: &[u32],
+    ) {
+    }
     fn cmd_bind_index_buffer(
         &self,
         _command_buffer: vk::CommandBuffer,
         _buffer: vk::Buffer,
         _offset: vk::DeviceSize,
         _index_type: vk::IndexType,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindIndexBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_index_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _index_type: vk::IndexType,
+    ) {
+    }
     fn cmd_bind_vertex_buffers(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_binding: u32,
         _p_buffers: &[vk::Buffer],
         _p_offsets: &[vk::DeviceSize],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindVertexBuffersArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_vertex_buffers(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_binding: u32,
+        _p_buffers: &[vk::Buffer],
+        _p_offsets: &[vk::DeviceSize],
+    ) {
+    }
     fn cmd_draw(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2032,9 +4309,18 @@ pub trait DeviceHooks: Send + Sync {
         _instance_count: u32,
         _first_vertex: u32,
         _first_instance: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _vertex_count: u32,
+        _instance_count: u32,
+        _first_vertex: u32,
+        _first_instance: u32,
+    ) {
+    }
     fn cmd_draw_indexed(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2043,9 +4329,19 @@ pub trait DeviceHooks: Send + Sync {
         _first_index: u32,
         _vertex_offset: i32,
         _first_instance: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawIndexedArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_indexed(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _index_count: u32,
+        _instance_count: u32,
+        _first_index: u32,
+        _vertex_offset: i32,
+        _first_instance: u32,
+    ) {
+    }
     fn cmd_draw_indirect(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2053,9 +4349,18 @@ pub trait DeviceHooks: Send + Sync {
         _offset: vk::DeviceSize,
         _draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawIndirectArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_indirect(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _draw_count: u32,
+        _stride: u32,
+    ) {
+    }
     fn cmd_draw_indexed_indirect(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2063,35 +4368,67 @@ pub trait DeviceHooks: Send + Sync {
         _offset: vk::DeviceSize,
         _draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawIndexedIndirectArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_indexed_indirect(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _draw_count: u32,
+        _stride: u32,
+    ) {
+    }
     fn cmd_dispatch(
         &self,
         _command_buffer: vk::CommandBuffer,
         _group_countx: u32,
         _group_county: u32,
         _group_countz: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDispatchArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_dispatch(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _group_countx: u32,
+        _group_county: u32,
+        _group_countz: u32,
+    ) {
+    }
     fn cmd_dispatch_indirect(
         &self,
         _command_buffer: vk::CommandBuffer,
         _buffer: vk::Buffer,
         _offset: vk::DeviceSize,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDispatchIndirectArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_dispatch_indirect(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+    ) {
+    }
     fn cmd_copy_buffer(
         &self,
         _command_buffer: vk::CommandBuffer,
         _src_buffer: vk::Buffer,
         _dst_buffer: vk::Buffer,
         _p_regions: &[vk::BufferCopy],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _src_buffer: vk::Buffer,
+        _dst_buffer: vk::Buffer,
+        _p_regions: &[vk::BufferCopy],
+    ) {
+    }
     fn cmd_copy_image(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2100,9 +4437,19 @@ pub trait DeviceHooks: Send + Sync {
         _dst_image: vk::Image,
         _dst_image_layout: vk::ImageLayout,
         _p_regions: &[vk::ImageCopy],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_image(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _src_image: vk::Image,
+        _src_image_layout: vk::ImageLayout,
+        _dst_image: vk::Image,
+        _dst_image_layout: vk::ImageLayout,
+        _p_regions: &[vk::ImageCopy],
+    ) {
+    }
     fn cmd_blit_image(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2112,9 +4459,20 @@ pub trait DeviceHooks: Send + Sync {
         _dst_image_layout: vk::ImageLayout,
         _p_regions: &[vk::ImageBlit],
         _filter: vk::Filter,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBlitImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_blit_image(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _src_image: vk::Image,
+        _src_image_layout: vk::ImageLayout,
+        _dst_image: vk::Image,
+        _dst_image_layout: vk::ImageLayout,
+        _p_regions: &[vk::ImageBlit],
+        _filter: vk::Filter,
+    ) {
+    }
     fn cmd_copy_buffer_to_image(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2122,9 +4480,18 @@ pub trait DeviceHooks: Send + Sync {
         _dst_image: vk::Image,
         _dst_image_layout: vk::ImageLayout,
         _p_regions: &[vk::BufferImageCopy],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyBufferToImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_buffer_to_image(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _src_buffer: vk::Buffer,
+        _dst_image: vk::Image,
+        _dst_image_layout: vk::ImageLayout,
+        _p_regions: &[vk::BufferImageCopy],
+    ) {
+    }
     fn cmd_copy_image_to_buffer(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2132,18 +4499,35 @@ pub trait DeviceHooks: Send + Sync {
         _src_image_layout: vk::ImageLayout,
         _dst_buffer: vk::Buffer,
         _p_regions: &[vk::BufferImageCopy],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyImageToBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_image_to_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _src_image: vk::Image,
+        _src_image_layout: vk::ImageLayout,
+        _dst_buffer: vk::Buffer,
+        _p_regions: &[vk::BufferImageCopy],
+    ) {
+    }
     fn cmd_update_buffer(
         &self,
         _command_buffer: vk::CommandBuffer,
         _dst_buffer: vk::Buffer,
         _dst_offset: vk::DeviceSize,
         _p_data: &[u8],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdUpdateBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_update_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _dst_buffer: vk::Buffer,
+        _dst_offset: vk::DeviceSize,
+        _p_data: &[u8],
+    ) {
+    }
     fn cmd_fill_buffer(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2151,9 +4535,18 @@ pub trait DeviceHooks: Send + Sync {
         _dst_offset: vk::DeviceSize,
         _size: vk::DeviceSize,
         _data: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdFillBufferArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_fill_buffer(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _dst_buffer: vk::Buffer,
+        _dst_offset: vk::DeviceSize,
+        _size: vk::DeviceSize,
+        _data: u32,
+    ) {
+    }
     fn cmd_clear_color_image(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2161,9 +4554,18 @@ pub trait DeviceHooks: Send + Sync {
         _image_layout: vk::ImageLayout,
         _p_color: &vk::ClearColorValue,
         _p_ranges: &[vk::ImageSubresourceRange],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdClearColorImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_clear_color_image(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _image: vk::Image,
+        _image_layout: vk::ImageLayout,
+        _p_color: &vk::ClearColorValue,
+        _p_ranges: &[vk::ImageSubresourceRange],
+    ) {
+    }
     fn cmd_clear_depth_stencil_image(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2171,17 +4573,33 @@ pub trait DeviceHooks: Send + Sync {
         _image_layout: vk::ImageLayout,
         _p_depth_stencil: &vk::ClearDepthStencilValue,
         _p_ranges: &[vk::ImageSubresourceRange],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdClearDepthStencilImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_clear_depth_stencil_image(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _image: vk::Image,
+        _image_layout: vk::ImageLayout,
+        _p_depth_stencil: &vk::ClearDepthStencilValue,
+        _p_ranges: &[vk::ImageSubresourceRange],
+    ) {
+    }
     fn cmd_clear_attachments(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_attachments: &[vk::ClearAttachment],
         _p_rects: &[vk::ClearRect],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdClearAttachmentsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_clear_attachments(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_attachments: &[vk::ClearAttachment],
+        _p_rects: &[vk::ClearRect],
+    ) {
+    }
     fn cmd_resolve_image(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2190,25 +4608,49 @@ pub trait DeviceHooks: Send + Sync {
         _dst_image: vk::Image,
         _dst_image_layout: vk::ImageLayout,
         _p_regions: &[vk::ImageResolve],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdResolveImageArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_resolve_image(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _src_image: vk::Image,
+        _src_image_layout: vk::ImageLayout,
+        _dst_image: vk::Image,
+        _dst_image_layout: vk::ImageLayout,
+        _p_regions: &[vk::ImageResolve],
+    ) {
+    }
     fn cmd_set_event(
         &self,
         _command_buffer: vk::CommandBuffer,
         _event: vk::Event,
         _stage_mask: vk::PipelineStageFlags,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetEventArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_event(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _event: vk::Event,
+        _stage_mask: vk::PipelineStageFlags,
+    ) {
+    }
     fn cmd_reset_event(
         &self,
         _command_buffer: vk::CommandBuffer,
         _event: vk::Event,
         _stage_mask: vk::PipelineStageFlags,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdResetEventArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_reset_event(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _event: vk::Event,
+        _stage_mask: vk::PipelineStageFlags,
+    ) {
+    }
     fn cmd_wait_events(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2218,9 +4660,20 @@ pub trait DeviceHooks: Send + Sync {
         _p_memory_barriers: &[vk::MemoryBarrier],
         _p_buffer_memory_barriers: &[vk::BufferMemoryBarrier],
         _p_image_memory_barriers: &[vk::ImageMemoryBarrier],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWaitEventsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_wait_events(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_events: &[vk::Event],
+        _src_stage_mask: vk::PipelineStageFlags,
+        _dst_stage_mask: vk::PipelineStageFlags,
+        _p_memory_barriers: &[vk::MemoryBarrier],
+        _p_buffer_memory_barriers: &[vk::BufferMemoryBarrier],
+        _p_image_memory_barriers: &[vk::ImageMemoryBarrier],
+    ) {
+    }
     fn cmd_pipeline_barrier(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2230,44 +4683,86 @@ pub trait DeviceHooks: Send + Sync {
         _p_memory_barriers: &[vk::MemoryBarrier],
         _p_buffer_memory_barriers: &[vk::BufferMemoryBarrier],
         _p_image_memory_barriers: &[vk::ImageMemoryBarrier],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdPipelineBarrierArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_pipeline_barrier(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _src_stage_mask: vk::PipelineStageFlags,
+        _dst_stage_mask: vk::PipelineStageFlags,
+        _dependency_flags: vk::DependencyFlags,
+        _p_memory_barriers: &[vk::MemoryBarrier],
+        _p_buffer_memory_barriers: &[vk::BufferMemoryBarrier],
+        _p_image_memory_barriers: &[vk::ImageMemoryBarrier],
+    ) {
+    }
     fn cmd_begin_query(
         &self,
         _command_buffer: vk::CommandBuffer,
         _query_pool: vk::QueryPool,
         _query: u32,
         _flags: vk::QueryControlFlags,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginQueryArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_begin_query(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _query_pool: vk::QueryPool,
+        _query: u32,
+        _flags: vk::QueryControlFlags,
+    ) {
+    }
     fn cmd_end_query(
         &self,
         _command_buffer: vk::CommandBuffer,
         _query_pool: vk::QueryPool,
         _query: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdEndQueryArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_query(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _query_pool: vk::QueryPool,
+        _query: u32,
+    ) {
+    }
     fn cmd_reset_query_pool(
         &self,
         _command_buffer: vk::CommandBuffer,
         _query_pool: vk::QueryPool,
         _first_query: u32,
         _query_count: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdResetQueryPoolArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_reset_query_pool(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _query_pool: vk::QueryPool,
+        _first_query: u32,
+        _query_count: u32,
+    ) {
+    }
     fn cmd_write_timestamp(
         &self,
         _command_buffer: vk::CommandBuffer,
         _pipeline_stage: vk::PipelineStageFlags,
         _query_pool: vk::QueryPool,
         _query: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWriteTimestampArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_write_timestamp(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_stage: vk::PipelineStageFlags,
+        _query_pool: vk::QueryPool,
+        _query: u32,
+    ) {
+    }
     fn cmd_copy_query_pool_results(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2278,9 +4773,21 @@ pub trait DeviceHooks: Send + Sync {
         _dst_offset: vk::DeviceSize,
         _stride: vk::DeviceSize,
         _flags: vk::QueryResultFlags,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyQueryPoolResultsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_query_pool_results(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _query_pool: vk::QueryPool,
+        _first_query: u32,
+        _query_count: u32,
+        _dst_buffer: vk::Buffer,
+        _dst_offset: vk::DeviceSize,
+        _stride: vk::DeviceSize,
+        _flags: vk::QueryResultFlags,
+    ) {
+    }
     fn cmd_push_constants(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2288,61 +4795,114 @@ pub trait DeviceHooks: Send + Sync {
         _stage_flags: vk::ShaderStageFlags,
         _offset: u32,
         _p_values: &[u8],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdPushConstantsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_push_constants(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _layout: vk::PipelineLayout,
+        _stage_flags: vk::ShaderStageFlags,
+        _offset: u32,
+        _p_values: &[u8],
+    ) {
+    }
     fn cmd_begin_render_pass(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_render_pass_begin: &vk::RenderPassBeginInfo,
         _contents: vk::SubpassContents,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginRenderPassArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_begin_render_pass(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_render_pass_begin: &vk::RenderPassBeginInfo,
+        _contents: vk::SubpassContents,
+    ) {
+    }
     fn cmd_next_subpass(
         &self,
         _command_buffer: vk::CommandBuffer,
         _contents: vk::SubpassContents,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdNextSubpassArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_end_render_pass(&self, _command_buffer: vk::CommandBuffer) -> LayerResult<()> {
+    fn after_cmd_next_subpass(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _contents: vk::SubpassContents,
+    ) {
+    }
+    fn cmd_end_render_pass(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+    ) -> LayerResult<(), CmdEndRenderPassArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_render_pass(&self, _command_buffer: vk::CommandBuffer) {}
     fn cmd_execute_commands(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_command_buffers: &[vk::CommandBuffer],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdExecuteCommandsArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_execute_commands(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_command_buffers: &[vk::CommandBuffer],
+    ) {
+    }
     fn bind_buffer_memory2(
         &self,
         _p_bind_infos: &[vk::BindBufferMemoryInfo],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BindBufferMemory2Args> {
         LayerResult::Unhandled
     }
+    fn after_bind_buffer_memory2(
+        &self,
+        _p_bind_infos: &[vk::BindBufferMemoryInfo],
+        _result: vk::Result,
+    ) {
+    }
     fn bind_image_memory2(
         &self,
         _p_bind_infos: &[vk::BindImageMemoryInfo],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BindImageMemory2Args> {
         LayerResult::Unhandled
     }
+    fn after_bind_image_memory2(
+        &self,
+        _p_bind_infos: &[vk::BindImageMemoryInfo],
+        _result: vk::Result,
+    ) {
+    }
     fn get_device_group_peer_memory_features(
         &self,
         _heap_index: u32,
         _local_device_index: u32,
         _remote_device_index: u32,
-    ) -> LayerResult<vk::PeerMemoryFeatureFlags> {
+    ) -> LayerResult<vk::PeerMemoryFeatureFlags, GetDeviceGroupPeerMemoryFeaturesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_group_peer_memory_features(
+        &self,
+        _heap_index: u32,
+        _local_device_index: u32,
+        _remote_device_index: u32,
+        _result: vk::PeerMemoryFeatureFlags,
+    ) {
+    }
     fn cmd_set_device_mask(
         &self,
         _command_buffer: vk::CommandBuffer,
         _device_mask: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDeviceMaskArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_device_mask(&self, _command_buffer: vk::CommandBuffer, _device_mask: u32) {}
     fn cmd_dispatch_base(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2352,23 +4912,46 @@ pub trait DeviceHooks: Send + Sync {
         _group_countx: u32,
         _group_county: u32,
         _group_countz: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDispatchBaseArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_dispatch_base(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _base_groupx: u32,
+        _base_groupy: u32,
+        _base_groupz: u32,
+        _group_countx: u32,
+        _group_county: u32,
+        _group_countz: u32,
+    ) {
+    }
     fn get_image_memory_requirements2(
         &self,
         _p_info: &vk::ImageMemoryRequirementsInfo2,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetImageMemoryRequirements2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_image_memory_requirements2(
+        &self,
+        _p_info: &vk::ImageMemoryRequirementsInfo2,
+        _p_memory_requirements: &vk::MemoryRequirements2,
+    ) {
+    }
     fn get_buffer_memory_requirements2(
         &self,
         _p_info: &vk::BufferMemoryRequirementsInfo2,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetBufferMemoryRequirements2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_buffer_memory_requirements2(
+        &self,
+        _p_info: &vk::BufferMemoryRequirementsInfo2,
+        _p_memory_requirements: &vk::MemoryRequirements2,
+    ) {
+    }
     fn get_image_sparse_memory_requirements2(
         &self,
         _p_info: &vk::ImageSparseMemoryRequirementsInfo2,
@@ -2376,63 +4959,145 @@ pub trait DeviceHooks: Send + Sync {
         _p_sparse_memory_requirements: Option<
             &mut [MaybeUninit<vk::SparseImageMemoryRequirements2>],
         >,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetImageSparseMemoryRequirements2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_image_sparse_memory_requirements2(
+        &self,
+        _p_info: &vk::ImageSparseMemoryRequirementsInfo2,
+        _p_sparse_memory_requirement_count: &u32,
+        _p_sparse_memory_requirements: Option<&[vk::SparseImageMemoryRequirements2]>,
+    ) {
+    }
     fn trim_command_pool(
         &self,
         _command_pool: vk::CommandPool,
         _flags: vk::CommandPoolTrimFlags,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), TrimCommandPoolArgs> {
         LayerResult::Unhandled
     }
-    fn get_device_queue2(&self, _p_queue_info: &vk::DeviceQueueInfo2) -> LayerResult<vk::Queue> {
+    fn after_trim_command_pool(
+        &self,
+        _command_pool: vk::CommandPool,
+        _flags: vk::CommandPoolTrimFlags,
+    ) {
+    }
+    fn get_device_queue2(
+        &self,
+        _p_queue_info: &vk::DeviceQueueInfo2,
+    ) -> LayerResult<vk::Queue, GetDeviceQueue2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_device_queue2(&self, _p_queue_info: &vk::DeviceQueueInfo2, _result: vk::Queue) {}
     fn create_sampler_ycbcr_conversion(
         &self,
         _p_create_info: &vk::SamplerYcbcrConversionCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SamplerYcbcrConversion>> {
+    ) -> LayerResult<VkResult<vk::SamplerYcbcrConversion>, CreateSamplerYcbcrConversionArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_sampler_ycbcr_conversion(
+        &self,
+        _p_create_info: &vk::SamplerYcbcrConversionCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_ycbcr_conversion: Option<vk::SamplerYcbcrConversion>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_sampler_ycbcr_conversion(
         &self,
         _ycbcr_conversion: vk::SamplerYcbcrConversion,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroySamplerYcbcrConversionArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_sampler_ycbcr_conversion(
+        &self,
+        _ycbcr_conversion: vk::SamplerYcbcrConversion,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn create_descriptor_update_template(
         &self,
         _p_create_info: &vk::DescriptorUpdateTemplateCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DescriptorUpdateTemplate>> {
+    ) -> LayerResult<VkResult<vk::DescriptorUpdateTemplate>, CreateDescriptorUpdateTemplateArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_create_descriptor_update_template(
+        &self,
+        _p_create_info: &vk::DescriptorUpdateTemplateCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_descriptor_update_template: Option<vk::DescriptorUpdateTemplate>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_descriptor_update_template(
         &self,
         _descriptor_update_template: vk::DescriptorUpdateTemplate,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyDescriptorUpdateTemplateArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_descriptor_update_template(
+        &self,
+        _descriptor_update_template: vk::DescriptorUpdateTemplate,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn update_descriptor_set_with_template(
         &self,
         _descriptor_set: vk::DescriptorSet,
         _descriptor_update_template: vk::DescriptorUpdateTemplate,
         _p_data: *const c_void,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), UpdateDescriptorSetWithTemplateArgs> {
         LayerResult::Unhandled
     }
+    fn after_update_descriptor_set_with_template(
+        &self,
+        _descriptor_set: vk::DescriptorSet,
+        _descriptor_update_template: vk::DescriptorUpdateTemplate,
+        _p_data: *const c_void,
+    ) {
+    }
     fn get_descriptor_set_layout_support(
         &self,
         _p_create_info: &vk::DescriptorSetLayoutCreateInfo,
         _p_support: &mut MaybeUninit<vk::DescriptorSetLayoutSupport>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetDescriptorSetLayoutSupportArgs> {
+        LayerResult::Unhandled
+    }
+    fn after_get_descriptor_set_layout_support(
+        &self,
+        _p_create_info: &vk::DescriptorSetLayoutCreateInfo,
+        _p_support: &vk::DescriptorSetLayoutSupport,
+    ) {
+    }
+    fn cmd_draw_indirect_count(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _count_buffer: vk::Buffer,
+        _count_buffer_offset: vk::DeviceSize,
+        _max_draw_count: u32,
+        _stride: u32,
+    ) -> LayerResult<(), CmdDrawIndirectCountArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_draw_indirect_count(
+    fn after_cmd_draw_indirect_count(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _count_buffer: vk::Buffer,
+        _count_buffer_offset: vk::DeviceSize,
+        _max_draw_count: u32,
+        _stride: u32,
+    ) {
+    }
+    fn cmd_draw_indexed_indirect_count(
         &self,
         _command_buffer: vk::CommandBuffer,
         _buffer: vk::Buffer,
@@ -2441,10 +5106,10 @@ pub trait DeviceHooks: Send + Sync {
         _count_buffer_offset: vk::DeviceSize,
         _max_draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawIndexedIndirectCountArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_draw_indexed_indirect_count(
+    fn after_cmd_draw_indexed_indirect_count(
         &self,
         _command_buffer: vk::CommandBuffer,
         _buffer: vk::Buffer,
@@ -2453,247 +5118,472 @@ pub trait DeviceHooks: Send + Sync {
         _count_buffer_offset: vk::DeviceSize,
         _max_draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
-        LayerResult::Unhandled
+    ) {
     }
     fn create_render_pass2(
         &self,
         _p_create_info: &vk::RenderPassCreateInfo2,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::RenderPass>> {
+    ) -> LayerResult<VkResult<vk::RenderPass>, CreateRenderPass2Args> {
         LayerResult::Unhandled
     }
+    fn after_create_render_pass2(
+        &self,
+        _p_create_info: &vk::RenderPassCreateInfo2,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_render_pass: Option<vk::RenderPass>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_begin_render_pass2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_render_pass_begin: &vk::RenderPassBeginInfo,
         _p_subpass_begin_info: &vk::SubpassBeginInfo,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginRenderPass2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_begin_render_pass2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_render_pass_begin: &vk::RenderPassBeginInfo,
+        _p_subpass_begin_info: &vk::SubpassBeginInfo,
+    ) {
+    }
     fn cmd_next_subpass2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_subpass_begin_info: &vk::SubpassBeginInfo,
         _p_subpass_end_info: &vk::SubpassEndInfo,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdNextSubpass2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_next_subpass2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_subpass_begin_info: &vk::SubpassBeginInfo,
+        _p_subpass_end_info: &vk::SubpassEndInfo,
+    ) {
+    }
     fn cmd_end_render_pass2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_subpass_end_info: &vk::SubpassEndInfo,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdEndRenderPass2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_render_pass2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_subpass_end_info: &vk::SubpassEndInfo,
+    ) {
+    }
     fn reset_query_pool(
         &self,
         _query_pool: vk::QueryPool,
         _first_query: u32,
         _query_count: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), ResetQueryPoolArgs> {
         LayerResult::Unhandled
     }
-    fn get_semaphore_counter_value(&self, _semaphore: vk::Semaphore) -> LayerResult<VkResult<u64>> {
+    fn after_reset_query_pool(
+        &self,
+        _query_pool: vk::QueryPool,
+        _first_query: u32,
+        _query_count: u32,
+    ) {
+    }
+    fn get_semaphore_counter_value(
+        &self,
+        _semaphore: vk::Semaphore,
+    ) -> LayerResult<VkResult<u64>, GetSemaphoreCounterValueArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_semaphore_counter_value(
+        &self,
+        _semaphore: vk::Semaphore,
+        _p_value: Option<u64>,
+        _result: vk::Result,
+    ) {
+    }
     fn wait_semaphores(
         &self,
         _p_wait_info: &vk::SemaphoreWaitInfo,
         _timeout: u64,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, WaitSemaphoresArgs> {
         LayerResult::Unhandled
     }
+    fn after_wait_semaphores(
+        &self,
+        _p_wait_info: &vk::SemaphoreWaitInfo,
+        _timeout: u64,
+        _result: vk::Result,
+    ) {
+    }
     fn signal_semaphore(
         &self,
         _p_signal_info: &vk::SemaphoreSignalInfo,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, SignalSemaphoreArgs> {
         LayerResult::Unhandled
     }
+    fn after_signal_semaphore(
+        &self,
+        _p_signal_info: &vk::SemaphoreSignalInfo,
+        _result: vk::Result,
+    ) {
+    }
     fn get_buffer_device_address(
         &self,
         _p_info: &vk::BufferDeviceAddressInfo,
-    ) -> LayerResult<vk::DeviceAddress> {
+    ) -> LayerResult<vk::DeviceAddress, GetBufferDeviceAddressArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_buffer_device_address(
+        &self,
+        _p_info: &vk::BufferDeviceAddressInfo,
+        _result: vk::DeviceAddress,
+    ) {
+    }
     fn get_buffer_opaque_capture_address(
         &self,
         _p_info: &vk::BufferDeviceAddressInfo,
-    ) -> LayerResult<u64> {
+    ) -> LayerResult<u64, GetBufferOpaqueCaptureAddressArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_buffer_opaque_capture_address(
+        &self,
+        _p_info: &vk::BufferDeviceAddressInfo,
+        _result: u64,
+    ) {
+    }
     fn get_device_memory_opaque_capture_address(
         &self,
         _p_info: &vk::DeviceMemoryOpaqueCaptureAddressInfo,
-    ) -> LayerResult<u64> {
+    ) -> LayerResult<u64, GetDeviceMemoryOpaqueCaptureAddressArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_memory_opaque_capture_address(
+        &self,
+        _p_info: &vk::DeviceMemoryOpaqueCaptureAddressInfo,
+        _result: u64,
+    ) {
+    }
     fn create_private_data_slot(
         &self,
         _p_create_info: &vk::PrivateDataSlotCreateInfo,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::PrivateDataSlot>> {
+    ) -> LayerResult<VkResult<vk::PrivateDataSlot>, CreatePrivateDataSlotArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_private_data_slot(
+        &self,
+        _p_create_info: &vk::PrivateDataSlotCreateInfo,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_private_data_slot: Option<vk::PrivateDataSlot>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_private_data_slot(
         &self,
         _private_data_slot: vk::PrivateDataSlot,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyPrivateDataSlotArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_private_data_slot(
+        &self,
+        _private_data_slot: vk::PrivateDataSlot,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn set_private_data(
         &self,
         _object_type: vk::ObjectType,
         _object_handle: u64,
         _private_data_slot: vk::PrivateDataSlot,
         _data: u64,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, SetPrivateDataArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_private_data(
+        &self,
+        _object_type: vk::ObjectType,
+        _object_handle: u64,
+        _private_data_slot: vk::PrivateDataSlot,
+        _data: u64,
+        _result: vk::Result,
+    ) {
+    }
     fn get_private_data(
         &self,
         _object_type: vk::ObjectType,
         _object_handle: u64,
         _private_data_slot: vk::PrivateDataSlot,
-    ) -> LayerResult<u64> {
+    ) -> LayerResult<u64, GetPrivateDataArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_private_data(
+        &self,
+        _object_type: vk::ObjectType,
+        _object_handle: u64,
+        _private_data_slot: vk::PrivateDataSlot,
+        _result: u64,
+    ) {
+    }
     fn cmd_set_event2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _event: vk::Event,
         _p_dependency_info: &vk::DependencyInfo,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetEvent2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_event2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _event: vk::Event,
+        _p_dependency_info: &vk::DependencyInfo,
+    ) {
+    }
     fn cmd_reset_event2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _event: vk::Event,
         _stage_mask: vk::PipelineStageFlags2,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdResetEvent2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_reset_event2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _event: vk::Event,
+        _stage_mask: vk::PipelineStageFlags2,
+    ) {
+    }
     fn cmd_wait_events2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_events: &[vk::Event],
         _p_dependency_infos: &[vk::DependencyInfo],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWaitEvents2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_wait_events2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_events: &[vk::Event],
+        _p_dependency_infos: &[vk::DependencyInfo],
+    ) {
+    }
     fn cmd_pipeline_barrier2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_dependency_info: &vk::DependencyInfo,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdPipelineBarrier2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_pipeline_barrier2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_dependency_info: &vk::DependencyInfo,
+    ) {
+    }
     fn cmd_write_timestamp2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _stage: vk::PipelineStageFlags2,
         _query_pool: vk::QueryPool,
         _query: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWriteTimestamp2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_write_timestamp2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _stage: vk::PipelineStageFlags2,
+        _query_pool: vk::QueryPool,
+        _query: u32,
+    ) {
+    }
     fn queue_submit2(
         &self,
         _queue: vk::Queue,
         _p_submits: &[vk::SubmitInfo2],
         _fence: vk::Fence,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, QueueSubmit2Args> {
         LayerResult::Unhandled
     }
+    fn after_queue_submit2(
+        &self,
+        _queue: vk::Queue,
+        _p_submits: &[vk::SubmitInfo2],
+        _fence: vk::Fence,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_copy_buffer2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_copy_buffer_info: &vk::CopyBufferInfo2,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyBuffer2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_buffer2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_copy_buffer_info: &vk::CopyBufferInfo2,
+    ) {
+    }
     fn cmd_copy_image2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_copy_image_info: &vk::CopyImageInfo2,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyImage2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_image2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_copy_image_info: &vk::CopyImageInfo2,
+    ) {
+    }
     fn cmd_copy_buffer_to_image2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_copy_buffer_to_image_info: &vk::CopyBufferToImageInfo2,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyBufferToImage2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_buffer_to_image2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_copy_buffer_to_image_info: &vk::CopyBufferToImageInfo2,
+    ) {
+    }
     fn cmd_copy_image_to_buffer2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_copy_image_to_buffer_info: &vk::CopyImageToBufferInfo2,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyImageToBuffer2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_image_to_buffer2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_copy_image_to_buffer_info: &vk::CopyImageToBufferInfo2,
+    ) {
+    }
     fn cmd_blit_image2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_blit_image_info: &vk::BlitImageInfo2,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBlitImage2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_blit_image2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_blit_image_info: &vk::BlitImageInfo2,
+    ) {
+    }
     fn cmd_resolve_image2(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_resolve_image_info: &vk::ResolveImageInfo2,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdResolveImage2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_resolve_image2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_resolve_image_info: &vk::ResolveImageInfo2,
+    ) {
+    }
     fn cmd_begin_rendering(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_rendering_info: &vk::RenderingInfo,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginRenderingArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_end_rendering(&self, _command_buffer: vk::CommandBuffer) -> LayerResult<()> {
+    fn after_cmd_begin_rendering(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_rendering_info: &vk::RenderingInfo,
+    ) {
+    }
+    fn cmd_end_rendering(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+    ) -> LayerResult<(), CmdEndRenderingArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_rendering(&self, _command_buffer: vk::CommandBuffer) {}
     fn cmd_set_cull_mode(
         &self,
         _command_buffer: vk::CommandBuffer,
         _cull_mode: vk::CullModeFlags,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCullModeArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_cull_mode(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _cull_mode: vk::CullModeFlags,
+    ) {
+    }
     fn cmd_set_front_face(
         &self,
         _command_buffer: vk::CommandBuffer,
         _front_face: vk::FrontFace,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetFrontFaceArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_front_face(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _front_face: vk::FrontFace,
+    ) {
+    }
     fn cmd_set_primitive_topology(
         &self,
         _command_buffer: vk::CommandBuffer,
         _primitive_topology: vk::PrimitiveTopology,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetPrimitiveTopologyArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_primitive_topology(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _primitive_topology: vk::PrimitiveTopology,
+    ) {
+    }
     fn cmd_set_viewport_with_count(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_viewports: &[vk::Viewport],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetViewportWithCountArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_viewport_with_count(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_viewports: &[vk::Viewport],
+    ) {
+    }
     fn cmd_set_scissor_with_count(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_scissors: &[vk::Rect2D],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetScissorWithCountArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_scissor_with_count(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_scissors: &[vk::Rect2D],
+    ) {
+    }
     fn cmd_bind_vertex_buffers2(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2702,44 +5592,84 @@ pub trait DeviceHooks: Send + Sync {
         _p_offsets: &[vk::DeviceSize],
         _p_sizes: Option<&[vk::DeviceSize]>,
         _p_strides: Option<&[vk::DeviceSize]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindVertexBuffers2Args> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_vertex_buffers2(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_binding: u32,
+        _p_buffers: &[vk::Buffer],
+        _p_offsets: &[vk::DeviceSize],
+        _p_sizes: Option<&[vk::DeviceSize]>,
+        _p_strides: Option<&[vk::DeviceSize]>,
+    ) {
+    }
     fn cmd_set_depth_test_enable(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_test_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthTestEnableArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_test_enable(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_test_enable: bool,
+    ) {
+    }
     fn cmd_set_depth_write_enable(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_write_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthWriteEnableArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_write_enable(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_write_enable: bool,
+    ) {
+    }
     fn cmd_set_depth_compare_op(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_compare_op: vk::CompareOp,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthCompareOpArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_compare_op(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_compare_op: vk::CompareOp,
+    ) {
+    }
     fn cmd_set_depth_bounds_test_enable(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_bounds_test_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthBoundsTestEnableArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_bounds_test_enable(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_bounds_test_enable: bool,
+    ) {
+    }
     fn cmd_set_stencil_test_enable(
         &self,
         _command_buffer: vk::CommandBuffer,
         _stencil_test_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetStencilTestEnableArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_stencil_test_enable(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _stencil_test_enable: bool,
+    ) {
+    }
     fn cmd_set_stencil_op(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2748,44 +5678,84 @@ pub trait DeviceHooks: Send + Sync {
         _pass_op: vk::StencilOp,
         _depth_fail_op: vk::StencilOp,
         _compare_op: vk::CompareOp,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetStencilOpArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_stencil_op(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _face_mask: vk::StencilFaceFlags,
+        _fail_op: vk::StencilOp,
+        _pass_op: vk::StencilOp,
+        _depth_fail_op: vk::StencilOp,
+        _compare_op: vk::CompareOp,
+    ) {
+    }
     fn cmd_set_rasterizer_discard_enable(
         &self,
         _command_buffer: vk::CommandBuffer,
         _rasterizer_discard_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetRasterizerDiscardEnableArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_rasterizer_discard_enable(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _rasterizer_discard_enable: bool,
+    ) {
+    }
     fn cmd_set_depth_bias_enable(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_bias_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthBiasEnableArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_bias_enable(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_bias_enable: bool,
+    ) {
+    }
     fn cmd_set_primitive_restart_enable(
         &self,
         _command_buffer: vk::CommandBuffer,
         _primitive_restart_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetPrimitiveRestartEnableArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_primitive_restart_enable(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _primitive_restart_enable: bool,
+    ) {
+    }
     fn get_device_buffer_memory_requirements(
         &self,
         _p_info: &vk::DeviceBufferMemoryRequirements,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetDeviceBufferMemoryRequirementsArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_buffer_memory_requirements(
+        &self,
+        _p_info: &vk::DeviceBufferMemoryRequirements,
+        _p_memory_requirements: &vk::MemoryRequirements2,
+    ) {
+    }
     fn get_device_image_memory_requirements(
         &self,
         _p_info: &vk::DeviceImageMemoryRequirements,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetDeviceImageMemoryRequirementsArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_image_memory_requirements(
+        &self,
+        _p_info: &vk::DeviceImageMemoryRequirements,
+        _p_memory_requirements: &vk::MemoryRequirements2,
+    ) {
+    }
     fn get_device_image_sparse_memory_requirements(
         &self,
         _p_info: &vk::DeviceImageMemoryRequirements,
@@ -2793,202 +5763,409 @@ pub trait DeviceHooks: Send + Sync {
         _p_sparse_memory_requirements: Option<
             &mut [MaybeUninit<vk::SparseImageMemoryRequirements2>],
         >,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetDeviceImageSparseMemoryRequirementsArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_image_sparse_memory_requirements(
+        &self,
+        _p_info: &vk::DeviceImageMemoryRequirements,
+        _p_sparse_memory_requirement_count: &u32,
+        _p_sparse_memory_requirements: Option<&[vk::SparseImageMemoryRequirements2]>,
+    ) {
+    }
     fn create_swapchain_khr(
         &self,
         _p_create_info: &vk::SwapchainCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SwapchainKHR>> {
+    ) -> LayerResult<VkResult<vk::SwapchainKHR>, CreateSwapchainKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_swapchain_khr(
+        &self,
+        _p_create_info: &vk::SwapchainCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_swapchain: Option<vk::SwapchainKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_swapchain_khr(
         &self,
         _swapchain: vk::SwapchainKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroySwapchainKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_swapchain_khr(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_swapchain_images_khr(
         &self,
         _swapchain: vk::SwapchainKHR,
-    ) -> LayerResult<VkResult<Vec<vk::Image>>> {
+    ) -> LayerResult<VkResult<Vec<vk::Image>>, GetSwapchainImagesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_swapchain_images_khr(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _p_swapchain_images: Option<&[vk::Image]>,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_next_image_khr(
         &self,
         _swapchain: vk::SwapchainKHR,
         _timeout: u64,
         _semaphore: vk::Semaphore,
         _fence: vk::Fence,
-    ) -> LayerResult<VkResult<u32>> {
+    ) -> LayerResult<VkResult<u32>, AcquireNextImageKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_acquire_next_image_khr(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _timeout: u64,
+        _semaphore: vk::Semaphore,
+        _fence: vk::Fence,
+        _p_image_index: Option<u32>,
+        _result: vk::Result,
+    ) {
+    }
     fn queue_present_khr(
         &self,
         _queue: vk::Queue,
         _p_present_info: &vk::PresentInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, QueuePresentKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_queue_present_khr(
+        &self,
+        _queue: vk::Queue,
+        _p_present_info: &vk::PresentInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_device_group_present_capabilities_khr(
         &self,
         _p_device_group_present_capabilities: &mut MaybeUninit<
             vk::DeviceGroupPresentCapabilitiesKHR,
         >,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetDeviceGroupPresentCapabilitiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_device_group_present_capabilities_khr(
+        &self,
+        _p_device_group_present_capabilities: Option<&vk::DeviceGroupPresentCapabilitiesKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_device_group_surface_present_modes_khr(
         &self,
         _surface: vk::SurfaceKHR,
-    ) -> LayerResult<VkResult<vk::DeviceGroupPresentModeFlagsKHR>> {
+    ) -> LayerResult<
+        VkResult<vk::DeviceGroupPresentModeFlagsKHR>,
+        GetDeviceGroupSurfacePresentModesKhrArgs,
+    > {
         LayerResult::Unhandled
     }
+    fn after_get_device_group_surface_present_modes_khr(
+        &self,
+        _surface: vk::SurfaceKHR,
+        _p_modes: Option<vk::DeviceGroupPresentModeFlagsKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_next_image2_khr(
         &self,
         _p_acquire_info: &vk::AcquireNextImageInfoKHR,
-    ) -> LayerResult<VkResult<u32>> {
+    ) -> LayerResult<VkResult<u32>, AcquireNextImage2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_acquire_next_image2_khr(
+        &self,
+        _p_acquire_info: &vk::AcquireNextImageInfoKHR,
+        _p_image_index: Option<u32>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_shared_swapchains_khr(
         &self,
         _p_create_infos: &[vk::SwapchainCreateInfoKHR],
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<Vec<vk::SwapchainKHR>>> {
+    ) -> LayerResult<VkResult<Vec<vk::SwapchainKHR>>, CreateSharedSwapchainsKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_shared_swapchains_khr(
+        &self,
+        _p_create_infos: &[vk::SwapchainCreateInfoKHR],
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_swapchains: Option<&[vk::SwapchainKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_video_session_khr(
         &self,
         _p_create_info: &vk::VideoSessionCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::VideoSessionKHR>> {
+    ) -> LayerResult<VkResult<vk::VideoSessionKHR>, CreateVideoSessionKhrArgs> {
+        LayerResult::Unhandled
+    }
+    fn after_create_video_session_khr(
+        &self,
+        _p_create_info: &vk::VideoSessionCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_video_session: Option<vk::VideoSessionKHR>,
+        _result: vk::Result,
+    ) {
+    }
+    fn destroy_video_session_khr(
+        &self,
+        _video_session: vk::VideoSessionKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) -> LayerResult<(), DestroyVideoSessionKhrArgs> {
         LayerResult::Unhandled
     }
-    fn destroy_video_session_khr(
+    fn after_destroy_video_session_khr(
         &self,
         _video_session: vk::VideoSessionKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
-        LayerResult::Unhandled
+    ) {
     }
     fn get_video_session_memory_requirements_khr(
         &self,
         _video_session: vk::VideoSessionKHR,
         _p_memory_requirements_count: &mut MaybeUninit<u32>,
         _p_memory_requirements: Option<&mut [MaybeUninit<vk::VideoSessionMemoryRequirementsKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetVideoSessionMemoryRequirementsKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_video_session_memory_requirements_khr(
+        &self,
+        _video_session: vk::VideoSessionKHR,
+        _p_memory_requirements_count: &u32,
+        _p_memory_requirements: Option<&[vk::VideoSessionMemoryRequirementsKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn bind_video_session_memory_khr(
         &self,
         _video_session: vk::VideoSessionKHR,
         _p_bind_session_memory_infos: &[vk::BindVideoSessionMemoryInfoKHR],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BindVideoSessionMemoryKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_bind_video_session_memory_khr(
+        &self,
+        _video_session: vk::VideoSessionKHR,
+        _p_bind_session_memory_infos: &[vk::BindVideoSessionMemoryInfoKHR],
+        _result: vk::Result,
+    ) {
+    }
     fn create_video_session_parameters_khr(
         &self,
         _p_create_info: &vk::VideoSessionParametersCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::VideoSessionParametersKHR>> {
+    ) -> LayerResult<VkResult<vk::VideoSessionParametersKHR>, CreateVideoSessionParametersKhrArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_create_video_session_parameters_khr(
+        &self,
+        _p_create_info: &vk::VideoSessionParametersCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_video_session_parameters: Option<vk::VideoSessionParametersKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn update_video_session_parameters_khr(
         &self,
         _video_session_parameters: vk::VideoSessionParametersKHR,
         _p_update_info: &vk::VideoSessionParametersUpdateInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, UpdateVideoSessionParametersKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_update_video_session_parameters_khr(
+        &self,
+        _video_session_parameters: vk::VideoSessionParametersKHR,
+        _p_update_info: &vk::VideoSessionParametersUpdateInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_video_session_parameters_khr(
         &self,
         _video_session_parameters: vk::VideoSessionParametersKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyVideoSessionParametersKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_video_session_parameters_khr(
+        &self,
+        _video_session_parameters: vk::VideoSessionParametersKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn cmd_begin_video_coding_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_begin_info: &vk::VideoBeginCodingInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginVideoCodingKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_begin_video_coding_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_begin_info: &vk::VideoBeginCodingInfoKHR,
+    ) {
+    }
     fn cmd_end_video_coding_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_end_coding_info: &vk::VideoEndCodingInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdEndVideoCodingKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_video_coding_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_end_coding_info: &vk::VideoEndCodingInfoKHR,
+    ) {
+    }
     fn cmd_control_video_coding_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_coding_control_info: &vk::VideoCodingControlInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdControlVideoCodingKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_control_video_coding_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_coding_control_info: &vk::VideoCodingControlInfoKHR,
+    ) {
+    }
     fn cmd_decode_video_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_decode_info: &vk::VideoDecodeInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDecodeVideoKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_decode_video_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_decode_info: &vk::VideoDecodeInfoKHR,
+    ) {
+    }
     fn get_memory_win32_handle_khr(
         &self,
         _p_get_win32_handle_info: &vk::MemoryGetWin32HandleInfoKHR,
-    ) -> LayerResult<VkResult<vk::HANDLE>> {
+    ) -> LayerResult<VkResult<vk::HANDLE>, GetMemoryWin32HandleKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_win32_handle_khr(
+        &self,
+        _p_get_win32_handle_info: &vk::MemoryGetWin32HandleInfoKHR,
+        _p_handle: Option<vk::HANDLE>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_memory_win32_handle_properties_khr(
         &self,
         _handle_type: vk::ExternalMemoryHandleTypeFlags,
         _handle: vk::HANDLE,
         _p_memory_win32_handle_properties: &mut MaybeUninit<vk::MemoryWin32HandlePropertiesKHR>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetMemoryWin32HandlePropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_win32_handle_properties_khr(
+        &self,
+        _handle_type: vk::ExternalMemoryHandleTypeFlags,
+        _handle: vk::HANDLE,
+        _p_memory_win32_handle_properties: Option<&vk::MemoryWin32HandlePropertiesKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_memory_fd_khr(
         &self,
         _p_get_fd_info: &vk::MemoryGetFdInfoKHR,
-    ) -> LayerResult<VkResult<c_int>> {
+    ) -> LayerResult<VkResult<c_int>, GetMemoryFdKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_fd_khr(
+        &self,
+        _p_get_fd_info: &vk::MemoryGetFdInfoKHR,
+        _p_fd: Option<c_int>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_memory_fd_properties_khr(
         &self,
         _handle_type: vk::ExternalMemoryHandleTypeFlags,
         _fd: c_int,
         _p_memory_fd_properties: &mut MaybeUninit<vk::MemoryFdPropertiesKHR>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetMemoryFdPropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_fd_properties_khr(
+        &self,
+        _handle_type: vk::ExternalMemoryHandleTypeFlags,
+        _fd: c_int,
+        _p_memory_fd_properties: Option<&vk::MemoryFdPropertiesKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn import_semaphore_win32_handle_khr(
         &self,
         _p_import_semaphore_win32_handle_info: &vk::ImportSemaphoreWin32HandleInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ImportSemaphoreWin32HandleKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_import_semaphore_win32_handle_khr(
+        &self,
+        _p_import_semaphore_win32_handle_info: &vk::ImportSemaphoreWin32HandleInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_semaphore_win32_handle_khr(
         &self,
         _p_get_win32_handle_info: &vk::SemaphoreGetWin32HandleInfoKHR,
-    ) -> LayerResult<VkResult<vk::HANDLE>> {
+    ) -> LayerResult<VkResult<vk::HANDLE>, GetSemaphoreWin32HandleKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_semaphore_win32_handle_khr(
+        &self,
+        _p_get_win32_handle_info: &vk::SemaphoreGetWin32HandleInfoKHR,
+        _p_handle: Option<vk::HANDLE>,
+        _result: vk::Result,
+    ) {
+    }
     fn import_semaphore_fd_khr(
         &self,
         _p_import_semaphore_fd_info: &vk::ImportSemaphoreFdInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ImportSemaphoreFdKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_import_semaphore_fd_khr(
+        &self,
+        _p_import_semaphore_fd_info: &vk::ImportSemaphoreFdInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_semaphore_fd_khr(
         &self,
         _p_get_fd_info: &vk::SemaphoreGetFdInfoKHR,
-    ) -> LayerResult<VkResult<c_int>> {
+    ) -> LayerResult<VkResult<c_int>, GetSemaphoreFdKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_semaphore_fd_khr(
+        &self,
+        _p_get_fd_info: &vk::SemaphoreGetFdInfoKHR,
+        _p_fd: Option<c_int>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_push_descriptor_set_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -2996,9 +6173,18 @@ pub trait DeviceHooks: Send + Sync {
         _layout: vk::PipelineLayout,
         _set: u32,
         _p_descriptor_writes: &[vk::WriteDescriptorSet],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdPushDescriptorSetKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_push_descriptor_set_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_bind_point: vk::PipelineBindPoint,
+        _layout: vk::PipelineLayout,
+        _set: u32,
+        _p_descriptor_writes: &[vk::WriteDescriptorSet],
+    ) {
+    }
     fn cmd_push_descriptor_set_with_template_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3006,108 +6192,216 @@ pub trait DeviceHooks: Send + Sync {
         _layout: vk::PipelineLayout,
         _set: u32,
         _p_data: *const c_void,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdPushDescriptorSetWithTemplateKhrArgs> {
         LayerResult::Unhandled
     }
-    fn get_swapchain_status_khr(&self, _swapchain: vk::SwapchainKHR) -> LayerResult<VkResult<()>> {
+    fn after_cmd_push_descriptor_set_with_template_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _descriptor_update_template: vk::DescriptorUpdateTemplate,
+        _layout: vk::PipelineLayout,
+        _set: u32,
+        _p_data: *const c_void,
+    ) {
+    }
+    fn get_swapchain_status_khr(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+    ) -> LayerResult<VkResult<()>, GetSwapchainStatusKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_swapchain_status_khr(&self, _swapchain: vk::SwapchainKHR, _result: vk::Result) {}
     fn import_fence_win32_handle_khr(
         &self,
         _p_import_fence_win32_handle_info: &vk::ImportFenceWin32HandleInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ImportFenceWin32HandleKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_import_fence_win32_handle_khr(
+        &self,
+        _p_import_fence_win32_handle_info: &vk::ImportFenceWin32HandleInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_fence_win32_handle_khr(
         &self,
         _p_get_win32_handle_info: &vk::FenceGetWin32HandleInfoKHR,
-    ) -> LayerResult<VkResult<vk::HANDLE>> {
+    ) -> LayerResult<VkResult<vk::HANDLE>, GetFenceWin32HandleKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_fence_win32_handle_khr(
+        &self,
+        _p_get_win32_handle_info: &vk::FenceGetWin32HandleInfoKHR,
+        _p_handle: Option<vk::HANDLE>,
+        _result: vk::Result,
+    ) {
+    }
     fn import_fence_fd_khr(
         &self,
         _p_import_fence_fd_info: &vk::ImportFenceFdInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ImportFenceFdKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_import_fence_fd_khr(
+        &self,
+        _p_import_fence_fd_info: &vk::ImportFenceFdInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_fence_fd_khr(
         &self,
         _p_get_fd_info: &vk::FenceGetFdInfoKHR,
-    ) -> LayerResult<VkResult<c_int>> {
+    ) -> LayerResult<VkResult<c_int>, GetFenceFdKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_fence_fd_khr(
+        &self,
+        _p_get_fd_info: &vk::FenceGetFdInfoKHR,
+        _p_fd: Option<c_int>,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_profiling_lock_khr(
         &self,
         _p_info: &vk::AcquireProfilingLockInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, AcquireProfilingLockKhrArgs> {
         LayerResult::Unhandled
     }
-    fn release_profiling_lock_khr(&self) -> LayerResult<()> {
+    fn after_acquire_profiling_lock_khr(
+        &self,
+        _p_info: &vk::AcquireProfilingLockInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
+    fn release_profiling_lock_khr(&self) -> LayerResult<(), ReleaseProfilingLockKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_release_profiling_lock_khr(&self) {}
     fn cmd_set_fragment_shading_rate_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_fragment_size: &vk::Extent2D,
         _combiner_ops: &[vk::FragmentShadingRateCombinerOpKHR; 2],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetFragmentShadingRateKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_fragment_shading_rate_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_fragment_size: &vk::Extent2D,
+        _combiner_ops: &[vk::FragmentShadingRateCombinerOpKHR; 2],
+    ) {
+    }
     fn wait_for_present_khr(
         &self,
         _swapchain: vk::SwapchainKHR,
         _present_id: u64,
         _timeout: u64,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, WaitForPresentKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_wait_for_present_khr(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _present_id: u64,
+        _timeout: u64,
+        _result: vk::Result,
+    ) {
+    }
     fn create_deferred_operation_khr(
         &self,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DeferredOperationKHR>> {
+    ) -> LayerResult<VkResult<vk::DeferredOperationKHR>, CreateDeferredOperationKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_deferred_operation_khr(
+        &self,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_deferred_operation: Option<vk::DeferredOperationKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_deferred_operation_khr(
         &self,
         _operation: vk::DeferredOperationKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyDeferredOperationKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_deferred_operation_khr(
+        &self,
+        _operation: vk::DeferredOperationKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_deferred_operation_max_concurrency_khr(
         &self,
         _operation: vk::DeferredOperationKHR,
-    ) -> LayerResult<u32> {
+    ) -> LayerResult<u32, GetDeferredOperationMaxConcurrencyKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_deferred_operation_max_concurrency_khr(
+        &self,
+        _operation: vk::DeferredOperationKHR,
+        _result: u32,
+    ) {
+    }
     fn get_deferred_operation_result_khr(
         &self,
         _operation: vk::DeferredOperationKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetDeferredOperationResultKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_deferred_operation_result_khr(
+        &self,
+        _operation: vk::DeferredOperationKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn deferred_operation_join_khr(
         &self,
         _operation: vk::DeferredOperationKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, DeferredOperationJoinKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_deferred_operation_join_khr(
+        &self,
+        _operation: vk::DeferredOperationKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_pipeline_executable_properties_khr(
         &self,
         _p_pipeline_info: &vk::PipelineInfoKHR,
         _p_executable_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::PipelineExecutablePropertiesKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPipelineExecutablePropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_pipeline_executable_properties_khr(
+        &self,
+        _p_pipeline_info: &vk::PipelineInfoKHR,
+        _p_executable_count: &u32,
+        _p_properties: Option<&[vk::PipelineExecutablePropertiesKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_pipeline_executable_statistics_khr(
         &self,
         _p_executable_info: &vk::PipelineExecutableInfoKHR,
         _p_statistic_count: &mut MaybeUninit<u32>,
         _p_statistics: Option<&mut [MaybeUninit<vk::PipelineExecutableStatisticKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPipelineExecutableStatisticsKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_pipeline_executable_statistics_khr(
+        &self,
+        _p_executable_info: &vk::PipelineExecutableInfoKHR,
+        _p_statistic_count: &u32,
+        _p_statistics: Option<&[vk::PipelineExecutableStatisticKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_pipeline_executable_internal_representations_khr(
         &self,
         _p_executable_info: &vk::PipelineExecutableInfoKHR,
@@ -3115,16 +6409,30 @@ pub trait DeviceHooks: Send + Sync {
         _p_internal_representations: Option<
             &mut [MaybeUninit<vk::PipelineExecutableInternalRepresentationKHR>],
         >,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPipelineExecutableInternalRepresentationsKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_pipeline_executable_internal_representations_khr(
+        &self,
+        _p_executable_info: &vk::PipelineExecutableInfoKHR,
+        _p_internal_representation_count: &u32,
+        _p_internal_representations: Option<&[vk::PipelineExecutableInternalRepresentationKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_encode_video_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_encode_info: &vk::VideoEncodeInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdEncodeVideoKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_encode_video_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_encode_info: &vk::VideoEncodeInfoKHR,
+    ) {
+    }
     fn cmd_write_buffer_marker2_amd(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3132,86 +6440,172 @@ pub trait DeviceHooks: Send + Sync {
         _dst_buffer: vk::Buffer,
         _dst_offset: vk::DeviceSize,
         _marker: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWriteBufferMarker2AmdArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_write_buffer_marker2_amd(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _stage: vk::PipelineStageFlags2,
+        _dst_buffer: vk::Buffer,
+        _dst_offset: vk::DeviceSize,
+        _marker: u32,
+    ) {
+    }
     fn get_queue_checkpoint_data2_nv(
         &self,
         _queue: vk::Queue,
         _p_checkpoint_data_count: &mut MaybeUninit<u32>,
         _p_checkpoint_data: Option<&mut [MaybeUninit<vk::CheckpointData2NV>]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetQueueCheckpointData2NvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_queue_checkpoint_data2_nv(
+        &self,
+        _queue: vk::Queue,
+        _p_checkpoint_data_count: &u32,
+        _p_checkpoint_data: Option<&[vk::CheckpointData2NV]>,
+    ) {
+    }
     fn cmd_trace_rays_indirect2_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _indirect_device_address: vk::DeviceAddress,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdTraceRaysIndirect2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_trace_rays_indirect2_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _indirect_device_address: vk::DeviceAddress,
+    ) {
+    }
     fn get_swapchain_gralloc_usage_android(
         &self,
         _format: vk::Format,
         _image_usage: vk::ImageUsageFlags,
-    ) -> LayerResult<VkResult<c_int>> {
+    ) -> LayerResult<VkResult<c_int>, GetSwapchainGrallocUsageAndroidArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_swapchain_gralloc_usage_android(
+        &self,
+        _format: vk::Format,
+        _image_usage: vk::ImageUsageFlags,
+        _gralloc_usage: Option<c_int>,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_image_android(
         &self,
         _image: vk::Image,
         _native_fence_fd: c_int,
         _semaphore: vk::Semaphore,
         _fence: vk::Fence,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, AcquireImageAndroidArgs> {
         LayerResult::Unhandled
     }
+    fn after_acquire_image_android(
+        &self,
+        _image: vk::Image,
+        _native_fence_fd: c_int,
+        _semaphore: vk::Semaphore,
+        _fence: vk::Fence,
+        _result: vk::Result,
+    ) {
+    }
     fn queue_signal_release_image_android(
         &self,
         _queue: vk::Queue,
         _p_wait_semaphores: &[vk::Semaphore],
         _image: vk::Image,
-    ) -> LayerResult<VkResult<c_int>> {
+    ) -> LayerResult<VkResult<c_int>, QueueSignalReleaseImageAndroidArgs> {
         LayerResult::Unhandled
     }
+    fn after_queue_signal_release_image_android(
+        &self,
+        _queue: vk::Queue,
+        _p_wait_semaphores: &[vk::Semaphore],
+        _image: vk::Image,
+        _p_native_fence_fd: Option<c_int>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_swapchain_gralloc_usage2_android(
         &self,
         _format: vk::Format,
         _image_usage: vk::ImageUsageFlags,
         _swapchain_image_usage: vk::SwapchainImageUsageFlagsANDROID,
         _gralloc_consumer_usage: &mut MaybeUninit<u64>,
-    ) -> LayerResult<VkResult<u64>> {
+    ) -> LayerResult<VkResult<u64>, GetSwapchainGrallocUsage2AndroidArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_swapchain_gralloc_usage2_android(
+        &self,
+        _format: vk::Format,
+        _image_usage: vk::ImageUsageFlags,
+        _swapchain_image_usage: vk::SwapchainImageUsageFlagsANDROID,
+        _gralloc_consumer_usage: Option<&u64>,
+        _gralloc_producer_usage: Option<u64>,
+        _result: vk::Result,
+    ) {
+    }
     fn debug_marker_set_object_tag_ext(
         &self,
         _p_tag_info: &vk::DebugMarkerObjectTagInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, DebugMarkerSetObjectTagExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_debug_marker_set_object_tag_ext(
+        &self,
+        _p_tag_info: &vk::DebugMarkerObjectTagInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn debug_marker_set_object_name_ext(
         &self,
         _p_name_info: &vk::DebugMarkerObjectNameInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, DebugMarkerSetObjectNameExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_debug_marker_set_object_name_ext(
+        &self,
+        _p_name_info: &vk::DebugMarkerObjectNameInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_debug_marker_begin_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_marker_info: &vk::DebugMarkerMarkerInfoEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDebugMarkerBeginExtArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_debug_marker_end_ext(&self, _command_buffer: vk::CommandBuffer) -> LayerResult<()> {
+    fn after_cmd_debug_marker_begin_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_marker_info: &vk::DebugMarkerMarkerInfoEXT,
+    ) {
+    }
+    fn cmd_debug_marker_end_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+    ) -> LayerResult<(), CmdDebugMarkerEndExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_debug_marker_end_ext(&self, _command_buffer: vk::CommandBuffer) {}
     fn cmd_debug_marker_insert_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_marker_info: &vk::DebugMarkerMarkerInfoEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDebugMarkerInsertExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_debug_marker_insert_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_marker_info: &vk::DebugMarkerMarkerInfoEXT,
+    ) {
+    }
     fn cmd_bind_transform_feedback_buffers_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3219,27 +6613,52 @@ pub trait DeviceHooks: Send + Sync {
         _p_buffers: &[vk::Buffer],
         _p_offsets: &[vk::DeviceSize],
         _p_sizes: Option<&[vk::DeviceSize]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindTransformFeedbackBuffersExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_transform_feedback_buffers_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_binding: u32,
+        _p_buffers: &[vk::Buffer],
+        _p_offsets: &[vk::DeviceSize],
+        _p_sizes: Option<&[vk::DeviceSize]>,
+    ) {
+    }
     fn cmd_begin_transform_feedback_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_counter_buffer: u32,
         _p_counter_buffers: &[vk::Buffer],
         _p_counter_buffer_offsets: Option<&[vk::DeviceSize]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginTransformFeedbackExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_begin_transform_feedback_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_counter_buffer: u32,
+        _p_counter_buffers: &[vk::Buffer],
+        _p_counter_buffer_offsets: Option<&[vk::DeviceSize]>,
+    ) {
+    }
     fn cmd_end_transform_feedback_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_counter_buffer: u32,
         _p_counter_buffers: &[vk::Buffer],
         _p_counter_buffer_offsets: Option<&[vk::DeviceSize]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdEndTransformFeedbackExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_transform_feedback_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_counter_buffer: u32,
+        _p_counter_buffers: &[vk::Buffer],
+        _p_counter_buffer_offsets: Option<&[vk::DeviceSize]>,
+    ) {
+    }
     fn cmd_begin_query_indexed_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3247,18 +6666,35 @@ pub trait DeviceHooks: Send + Sync {
         _query: u32,
         _flags: vk::QueryControlFlags,
         _index: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginQueryIndexedExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_begin_query_indexed_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _query_pool: vk::QueryPool,
+        _query: u32,
+        _flags: vk::QueryControlFlags,
+        _index: u32,
+    ) {
+    }
     fn cmd_end_query_indexed_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _query_pool: vk::QueryPool,
         _query: u32,
         _index: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdEndQueryIndexedExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_query_indexed_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _query_pool: vk::QueryPool,
+        _query: u32,
+        _index: u32,
+    ) {
+    }
     fn cmd_draw_indirect_byte_count_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3268,54 +6704,110 @@ pub trait DeviceHooks: Send + Sync {
         _counter_buffer_offset: vk::DeviceSize,
         _counter_offset: u32,
         _vertex_stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawIndirectByteCountExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_indirect_byte_count_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _instance_count: u32,
+        _first_instance: u32,
+        _counter_buffer: vk::Buffer,
+        _counter_buffer_offset: vk::DeviceSize,
+        _counter_offset: u32,
+        _vertex_stride: u32,
+    ) {
+    }
     fn create_cu_module_nvx(
         &self,
         _p_create_info: &vk::CuModuleCreateInfoNVX,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::CuModuleNVX>> {
+    ) -> LayerResult<VkResult<vk::CuModuleNVX>, CreateCuModuleNvxArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_cu_module_nvx(
+        &self,
+        _p_create_info: &vk::CuModuleCreateInfoNVX,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_module: Option<vk::CuModuleNVX>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_cu_function_nvx(
         &self,
         _p_create_info: &vk::CuFunctionCreateInfoNVX,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::CuFunctionNVX>> {
+    ) -> LayerResult<VkResult<vk::CuFunctionNVX>, CreateCuFunctionNvxArgs> {
+        LayerResult::Unhandled
+    }
+    fn after_create_cu_function_nvx(
+        &self,
+        _p_create_info: &vk::CuFunctionCreateInfoNVX,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_function: Option<vk::CuFunctionNVX>,
+        _result: vk::Result,
+    ) {
+    }
+    fn destroy_cu_module_nvx(
+        &self,
+        _module: vk::CuModuleNVX,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) -> LayerResult<(), DestroyCuModuleNvxArgs> {
         LayerResult::Unhandled
     }
-    fn destroy_cu_module_nvx(
+    fn after_destroy_cu_module_nvx(
         &self,
         _module: vk::CuModuleNVX,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
-        LayerResult::Unhandled
+    ) {
     }
     fn destroy_cu_function_nvx(
         &self,
         _function: vk::CuFunctionNVX,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyCuFunctionNvxArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_cu_function_nvx(
+        &self,
+        _function: vk::CuFunctionNVX,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn cmd_cu_launch_kernel_nvx(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_launch_info: &vk::CuLaunchInfoNVX,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCuLaunchKernelNvxArgs> {
         LayerResult::Unhandled
     }
-    fn get_image_view_handle_nvx(&self, _p_info: &vk::ImageViewHandleInfoNVX) -> LayerResult<u32> {
+    fn after_cmd_cu_launch_kernel_nvx(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_launch_info: &vk::CuLaunchInfoNVX,
+    ) {
+    }
+    fn get_image_view_handle_nvx(
+        &self,
+        _p_info: &vk::ImageViewHandleInfoNVX,
+    ) -> LayerResult<u32, GetImageViewHandleNvxArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_view_handle_nvx(&self, _p_info: &vk::ImageViewHandleInfoNVX, _result: u32) {}
     fn get_image_view_address_nvx(
         &self,
         _image_view: vk::ImageView,
         _p_properties: &mut MaybeUninit<vk::ImageViewAddressPropertiesNVX>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetImageViewAddressNvxArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_view_address_nvx(
+        &self,
+        _image_view: vk::ImageView,
+        _p_properties: Option<&vk::ImageViewAddressPropertiesNVX>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_shader_info_amd(
         &self,
         _pipeline: vk::Pipeline,
@@ -3323,249 +6815,489 @@ pub trait DeviceHooks: Send + Sync {
         _info_type: vk::ShaderInfoTypeAMD,
         _p_info_size: &mut MaybeUninit<usize>,
         _p_info: Option<&mut [MaybeUninit<u8>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetShaderInfoAmdArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_shader_info_amd(
+        &self,
+        _pipeline: vk::Pipeline,
+        _shader_stage: vk::ShaderStageFlags,
+        _info_type: vk::ShaderInfoTypeAMD,
+        _p_info_size: &usize,
+        _p_info: Option<&[u8]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_memory_win32_handle_nv(
         &self,
         _memory: vk::DeviceMemory,
         _handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
-    ) -> LayerResult<VkResult<vk::HANDLE>> {
+    ) -> LayerResult<VkResult<vk::HANDLE>, GetMemoryWin32HandleNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_win32_handle_nv(
+        &self,
+        _memory: vk::DeviceMemory,
+        _handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+        _p_handle: Option<vk::HANDLE>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_begin_conditional_rendering_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_conditional_rendering_begin: &vk::ConditionalRenderingBeginInfoEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginConditionalRenderingExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_begin_conditional_rendering_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_conditional_rendering_begin: &vk::ConditionalRenderingBeginInfoEXT,
+    ) {
+    }
     fn cmd_end_conditional_rendering_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdEndConditionalRenderingExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_conditional_rendering_ext(&self, _command_buffer: vk::CommandBuffer) {}
     fn cmd_set_viewport_w_scaling_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_viewport: u32,
         _p_viewport_w_scalings: &[vk::ViewportWScalingNV],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetViewportWScalingNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_viewport_w_scaling_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_viewport: u32,
+        _p_viewport_w_scalings: &[vk::ViewportWScalingNV],
+    ) {
+    }
     fn display_power_control_ext(
         &self,
         _display: vk::DisplayKHR,
         _p_display_power_info: &vk::DisplayPowerInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, DisplayPowerControlExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_display_power_control_ext(
+        &self,
+        _display: vk::DisplayKHR,
+        _p_display_power_info: &vk::DisplayPowerInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn register_device_event_ext(
         &self,
         _p_device_event_info: &vk::DeviceEventInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Fence>> {
+    ) -> LayerResult<VkResult<vk::Fence>, RegisterDeviceEventExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_register_device_event_ext(
+        &self,
+        _p_device_event_info: &vk::DeviceEventInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_fence: Option<vk::Fence>,
+        _result: vk::Result,
+    ) {
+    }
     fn register_display_event_ext(
         &self,
         _display: vk::DisplayKHR,
         _p_display_event_info: &vk::DisplayEventInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::Fence>> {
+    ) -> LayerResult<VkResult<vk::Fence>, RegisterDisplayEventExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_register_display_event_ext(
+        &self,
+        _display: vk::DisplayKHR,
+        _p_display_event_info: &vk::DisplayEventInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_fence: Option<vk::Fence>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_swapchain_counter_ext(
         &self,
         _swapchain: vk::SwapchainKHR,
         _counter: vk::SurfaceCounterFlagsEXT,
-    ) -> LayerResult<VkResult<u64>> {
+    ) -> LayerResult<VkResult<u64>, GetSwapchainCounterExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_swapchain_counter_ext(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _counter: vk::SurfaceCounterFlagsEXT,
+        _p_counter_value: Option<u64>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_refresh_cycle_duration_google(
         &self,
         _swapchain: vk::SwapchainKHR,
         _p_display_timing_properties: &mut MaybeUninit<vk::RefreshCycleDurationGOOGLE>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetRefreshCycleDurationGoogleArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_refresh_cycle_duration_google(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _p_display_timing_properties: Option<&vk::RefreshCycleDurationGOOGLE>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_past_presentation_timing_google(
         &self,
         _swapchain: vk::SwapchainKHR,
         _p_presentation_timing_count: &mut MaybeUninit<u32>,
         _p_presentation_timings: Option<&mut [MaybeUninit<vk::PastPresentationTimingGOOGLE>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPastPresentationTimingGoogleArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_past_presentation_timing_google(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _p_presentation_timing_count: &u32,
+        _p_presentation_timings: Option<&[vk::PastPresentationTimingGOOGLE]>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_set_discard_rectangle_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_discard_rectangle: u32,
         _p_discard_rectangles: &[vk::Rect2D],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDiscardRectangleExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_discard_rectangle_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_discard_rectangle: u32,
+        _p_discard_rectangles: &[vk::Rect2D],
+    ) {
+    }
     fn set_hdr_metadata_ext(
         &self,
         _p_swapchains: &[vk::SwapchainKHR],
         _p_metadata: &[vk::HdrMetadataEXT],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), SetHdrMetadataExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_hdr_metadata_ext(
+        &self,
+        _p_swapchains: &[vk::SwapchainKHR],
+        _p_metadata: &[vk::HdrMetadataEXT],
+    ) {
+    }
     fn set_debug_utils_object_name_ext(
         &self,
         _p_name_info: &vk::DebugUtilsObjectNameInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, SetDebugUtilsObjectNameExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_debug_utils_object_name_ext(
+        &self,
+        _p_name_info: &vk::DebugUtilsObjectNameInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn set_debug_utils_object_tag_ext(
         &self,
         _p_tag_info: &vk::DebugUtilsObjectTagInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, SetDebugUtilsObjectTagExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_debug_utils_object_tag_ext(
+        &self,
+        _p_tag_info: &vk::DebugUtilsObjectTagInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn queue_begin_debug_utils_label_ext(
         &self,
         _queue: vk::Queue,
         _p_label_info: &vk::DebugUtilsLabelEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), QueueBeginDebugUtilsLabelExtArgs> {
         LayerResult::Unhandled
     }
-    fn queue_end_debug_utils_label_ext(&self, _queue: vk::Queue) -> LayerResult<()> {
+    fn after_queue_begin_debug_utils_label_ext(
+        &self,
+        _queue: vk::Queue,
+        _p_label_info: &vk::DebugUtilsLabelEXT,
+    ) {
+    }
+    fn queue_end_debug_utils_label_ext(
+        &self,
+        _queue: vk::Queue,
+    ) -> LayerResult<(), QueueEndDebugUtilsLabelExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_queue_end_debug_utils_label_ext(&self, _queue: vk::Queue) {}
     fn queue_insert_debug_utils_label_ext(
         &self,
         _queue: vk::Queue,
         _p_label_info: &vk::DebugUtilsLabelEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), QueueInsertDebugUtilsLabelExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_queue_insert_debug_utils_label_ext(
+        &self,
+        _queue: vk::Queue,
+        _p_label_info: &vk::DebugUtilsLabelEXT,
+    ) {
+    }
     fn cmd_begin_debug_utils_label_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_label_info: &vk::DebugUtilsLabelEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBeginDebugUtilsLabelExtArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_end_debug_utils_label_ext(&self, _command_buffer: vk::CommandBuffer) -> LayerResult<()> {
+    fn after_cmd_begin_debug_utils_label_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_label_info: &vk::DebugUtilsLabelEXT,
+    ) {
+    }
+    fn cmd_end_debug_utils_label_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+    ) -> LayerResult<(), CmdEndDebugUtilsLabelExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_end_debug_utils_label_ext(&self, _command_buffer: vk::CommandBuffer) {}
     fn cmd_insert_debug_utils_label_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_label_info: &vk::DebugUtilsLabelEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdInsertDebugUtilsLabelExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_insert_debug_utils_label_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_label_info: &vk::DebugUtilsLabelEXT,
+    ) {
+    }
     fn get_android_hardware_buffer_properties_android(
         &self,
         _buffer: *const vk::AHardwareBuffer,
         _p_properties: &mut MaybeUninit<vk::AndroidHardwareBufferPropertiesANDROID>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetAndroidHardwareBufferPropertiesAndroidArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_android_hardware_buffer_properties_android(
+        &self,
+        _buffer: *const vk::AHardwareBuffer,
+        _p_properties: Option<&vk::AndroidHardwareBufferPropertiesANDROID>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_memory_android_hardware_buffer_android(
         &self,
         _p_info: &vk::MemoryGetAndroidHardwareBufferInfoANDROID,
-    ) -> LayerResult<VkResult<*mut vk::AHardwareBuffer>> {
+    ) -> LayerResult<VkResult<*mut vk::AHardwareBuffer>, GetMemoryAndroidHardwareBufferAndroidArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_get_memory_android_hardware_buffer_android(
+        &self,
+        _p_info: &vk::MemoryGetAndroidHardwareBufferInfoANDROID,
+        _p_buffer: Option<*mut vk::AHardwareBuffer>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_set_sample_locations_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_sample_locations_info: &vk::SampleLocationsInfoEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetSampleLocationsExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_sample_locations_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_sample_locations_info: &vk::SampleLocationsInfoEXT,
+    ) {
+    }
     fn get_image_drm_format_modifier_properties_ext(
         &self,
         _image: vk::Image,
         _p_properties: &mut MaybeUninit<vk::ImageDrmFormatModifierPropertiesEXT>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetImageDrmFormatModifierPropertiesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_drm_format_modifier_properties_ext(
+        &self,
+        _image: vk::Image,
+        _p_properties: Option<&vk::ImageDrmFormatModifierPropertiesEXT>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_validation_cache_ext(
         &self,
         _p_create_info: &vk::ValidationCacheCreateInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::ValidationCacheEXT>> {
+    ) -> LayerResult<VkResult<vk::ValidationCacheEXT>, CreateValidationCacheExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_validation_cache_ext(
+        &self,
+        _p_create_info: &vk::ValidationCacheCreateInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_validation_cache: Option<vk::ValidationCacheEXT>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_validation_cache_ext(
         &self,
         _validation_cache: vk::ValidationCacheEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyValidationCacheExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_validation_cache_ext(
+        &self,
+        _validation_cache: vk::ValidationCacheEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn merge_validation_caches_ext(
         &self,
         _dst_cache: vk::ValidationCacheEXT,
         _p_src_caches: &[vk::ValidationCacheEXT],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, MergeValidationCachesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_merge_validation_caches_ext(
+        &self,
+        _dst_cache: vk::ValidationCacheEXT,
+        _p_src_caches: &[vk::ValidationCacheEXT],
+        _result: vk::Result,
+    ) {
+    }
     fn get_validation_cache_data_ext(
         &self,
         _validation_cache: vk::ValidationCacheEXT,
         _p_data_size: &mut MaybeUninit<usize>,
         _p_data: Option<&mut [MaybeUninit<u8>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetValidationCacheDataExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_validation_cache_data_ext(
+        &self,
+        _validation_cache: vk::ValidationCacheEXT,
+        _p_data_size: &usize,
+        _p_data: Option<&[u8]>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_bind_shading_rate_image_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _image_view: vk::ImageView,
         _image_layout: vk::ImageLayout,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindShadingRateImageNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_shading_rate_image_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _image_view: vk::ImageView,
+        _image_layout: vk::ImageLayout,
+    ) {
+    }
     fn cmd_set_viewport_shading_rate_palette_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_viewport: u32,
         _p_shading_rate_palettes: &[vk::ShadingRatePaletteNV],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetViewportShadingRatePaletteNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_viewport_shading_rate_palette_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_viewport: u32,
+        _p_shading_rate_palettes: &[vk::ShadingRatePaletteNV],
+    ) {
+    }
     fn cmd_set_coarse_sample_order_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _sample_order_type: vk::CoarseSampleOrderTypeNV,
         _p_custom_sample_orders: &[vk::CoarseSampleOrderCustomNV],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCoarseSampleOrderNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_coarse_sample_order_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _sample_order_type: vk::CoarseSampleOrderTypeNV,
+        _p_custom_sample_orders: &[vk::CoarseSampleOrderCustomNV],
+    ) {
+    }
     fn create_acceleration_structure_nv(
         &self,
         _p_create_info: &vk::AccelerationStructureCreateInfoNV,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::AccelerationStructureNV>> {
+    ) -> LayerResult<VkResult<vk::AccelerationStructureNV>, CreateAccelerationStructureNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_acceleration_structure_nv(
+        &self,
+        _p_create_info: &vk::AccelerationStructureCreateInfoNV,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_acceleration_structure: Option<vk::AccelerationStructureNV>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_acceleration_structure_nv(
         &self,
         _acceleration_structure: vk::AccelerationStructureNV,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyAccelerationStructureNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_acceleration_structure_nv(
+        &self,
+        _acceleration_structure: vk::AccelerationStructureNV,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_acceleration_structure_memory_requirements_nv(
         &self,
         _p_info: &vk::AccelerationStructureMemoryRequirementsInfoNV,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements2KHR>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetAccelerationStructureMemoryRequirementsNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_acceleration_structure_memory_requirements_nv(
+        &self,
+        _p_info: &vk::AccelerationStructureMemoryRequirementsInfoNV,
+        _p_memory_requirements: &vk::MemoryRequirements2KHR,
+    ) {
+    }
     fn bind_acceleration_structure_memory_nv(
         &self,
         _p_bind_infos: &[vk::BindAccelerationStructureMemoryInfoNV],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BindAccelerationStructureMemoryNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_bind_acceleration_structure_memory_nv(
+        &self,
+        _p_bind_infos: &[vk::BindAccelerationStructureMemoryInfoNV],
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_build_acceleration_structure_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3577,18 +7309,39 @@ pub trait DeviceHooks: Send + Sync {
         _src: vk::AccelerationStructureNV,
         _scratch: vk::Buffer,
         _scratch_offset: vk::DeviceSize,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBuildAccelerationStructureNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_build_acceleration_structure_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_info: &vk::AccelerationStructureInfoNV,
+        _instance_data: vk::Buffer,
+        _instance_offset: vk::DeviceSize,
+        _update: bool,
+        _dst: vk::AccelerationStructureNV,
+        _src: vk::AccelerationStructureNV,
+        _scratch: vk::Buffer,
+        _scratch_offset: vk::DeviceSize,
+    ) {
+    }
     fn cmd_copy_acceleration_structure_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _dst: vk::AccelerationStructureNV,
         _src: vk::AccelerationStructureNV,
         _mode: vk::CopyAccelerationStructureModeKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyAccelerationStructureNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_acceleration_structure_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _dst: vk::AccelerationStructureNV,
+        _src: vk::AccelerationStructureNV,
+        _mode: vk::CopyAccelerationStructureModeKHR,
+    ) {
+    }
     fn cmd_trace_rays_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3606,33 +7359,77 @@ pub trait DeviceHooks: Send + Sync {
         _width: u32,
         _height: u32,
         _depth: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdTraceRaysNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_trace_rays_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _raygen_shader_binding_table_buffer: vk::Buffer,
+        _raygen_shader_binding_offset: vk::DeviceSize,
+        _miss_shader_binding_table_buffer: vk::Buffer,
+        _miss_shader_binding_offset: vk::DeviceSize,
+        _miss_shader_binding_stride: vk::DeviceSize,
+        _hit_shader_binding_table_buffer: vk::Buffer,
+        _hit_shader_binding_offset: vk::DeviceSize,
+        _hit_shader_binding_stride: vk::DeviceSize,
+        _callable_shader_binding_table_buffer: vk::Buffer,
+        _callable_shader_binding_offset: vk::DeviceSize,
+        _callable_shader_binding_stride: vk::DeviceSize,
+        _width: u32,
+        _height: u32,
+        _depth: u32,
+    ) {
+    }
     fn create_ray_tracing_pipelines_nv(
         &self,
         _pipeline_cache: vk::PipelineCache,
         _p_create_infos: &[vk::RayTracingPipelineCreateInfoNV],
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>> {
+    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>, CreateRayTracingPipelinesNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_ray_tracing_pipelines_nv(
+        &self,
+        _pipeline_cache: vk::PipelineCache,
+        _p_create_infos: &[vk::RayTracingPipelineCreateInfoNV],
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_pipelines: Option<&[vk::Pipeline]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_ray_tracing_shader_group_handles_khr(
         &self,
         _pipeline: vk::Pipeline,
         _first_group: u32,
         _group_count: u32,
         _p_data: &mut [MaybeUninit<u8>],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetRayTracingShaderGroupHandlesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_ray_tracing_shader_group_handles_khr(
+        &self,
+        _pipeline: vk::Pipeline,
+        _first_group: u32,
+        _group_count: u32,
+        _p_data: Option<&[u8]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_acceleration_structure_handle_nv(
         &self,
         _acceleration_structure: vk::AccelerationStructureNV,
         _p_data: &mut [MaybeUninit<u8>],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetAccelerationStructureHandleNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_acceleration_structure_handle_nv(
+        &self,
+        _acceleration_structure: vk::AccelerationStructureNV,
+        _p_data: Option<&[u8]>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_write_acceleration_structures_properties_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3640,24 +7437,48 @@ pub trait DeviceHooks: Send + Sync {
         _query_type: vk::QueryType,
         _query_pool: vk::QueryPool,
         _first_query: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWriteAccelerationStructuresPropertiesNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_write_acceleration_structures_properties_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_acceleration_structures: &[vk::AccelerationStructureNV],
+        _query_type: vk::QueryType,
+        _query_pool: vk::QueryPool,
+        _first_query: u32,
+    ) {
+    }
     fn compile_deferred_nv(
         &self,
         _pipeline: vk::Pipeline,
         _shader: u32,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CompileDeferredNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_compile_deferred_nv(
+        &self,
+        _pipeline: vk::Pipeline,
+        _shader: u32,
+        _result: vk::Result,
+    ) {
+    }
     fn get_memory_host_pointer_properties_ext(
         &self,
         _handle_type: vk::ExternalMemoryHandleTypeFlags,
         _p_host_pointer: *const c_void,
         _p_memory_host_pointer_properties: &mut MaybeUninit<vk::MemoryHostPointerPropertiesEXT>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetMemoryHostPointerPropertiesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_host_pointer_properties_ext(
+        &self,
+        _handle_type: vk::ExternalMemoryHandleTypeFlags,
+        _p_host_pointer: *const c_void,
+        _p_memory_host_pointer_properties: Option<&vk::MemoryHostPointerPropertiesEXT>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_write_buffer_marker_amd(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3665,24 +7486,48 @@ pub trait DeviceHooks: Send + Sync {
         _dst_buffer: vk::Buffer,
         _dst_offset: vk::DeviceSize,
         _marker: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWriteBufferMarkerAmdArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_write_buffer_marker_amd(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_stage: vk::PipelineStageFlags,
+        _dst_buffer: vk::Buffer,
+        _dst_offset: vk::DeviceSize,
+        _marker: u32,
+    ) {
+    }
     fn get_calibrated_timestamps_ext(
         &self,
         _p_timestamp_infos: &[vk::CalibratedTimestampInfoEXT],
         _p_timestamps: &mut [MaybeUninit<u64>],
-    ) -> LayerResult<VkResult<u64>> {
+    ) -> LayerResult<VkResult<u64>, GetCalibratedTimestampsExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_calibrated_timestamps_ext(
+        &self,
+        _p_timestamp_infos: &[vk::CalibratedTimestampInfoEXT],
+        _p_timestamps: Option<&[u64]>,
+        _p_max_deviation: Option<u64>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_draw_mesh_tasks_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _task_count: u32,
         _first_task: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawMeshTasksNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_mesh_tasks_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _task_count: u32,
+        _first_task: u32,
+    ) {
+    }
     fn cmd_draw_mesh_tasks_indirect_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3690,10 +7535,31 @@ pub trait DeviceHooks: Send + Sync {
         _offset: vk::DeviceSize,
         _draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawMeshTasksIndirectNvArgs> {
+        LayerResult::Unhandled
+    }
+    fn after_cmd_draw_mesh_tasks_indirect_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _draw_count: u32,
+        _stride: u32,
+    ) {
+    }
+    fn cmd_draw_mesh_tasks_indirect_count_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _count_buffer: vk::Buffer,
+        _count_buffer_offset: vk::DeviceSize,
+        _max_draw_count: u32,
+        _stride: u32,
+    ) -> LayerResult<(), CmdDrawMeshTasksIndirectCountNvArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_draw_mesh_tasks_indirect_count_nv(
+    fn after_cmd_draw_mesh_tasks_indirect_count_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _buffer: vk::Buffer,
@@ -3702,205 +7568,397 @@ pub trait DeviceHooks: Send + Sync {
         _count_buffer_offset: vk::DeviceSize,
         _max_draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
-        LayerResult::Unhandled
+    ) {
     }
     fn cmd_set_exclusive_scissor_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_exclusive_scissor: u32,
         _p_exclusive_scissors: &[vk::Rect2D],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetExclusiveScissorNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_exclusive_scissor_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_exclusive_scissor: u32,
+        _p_exclusive_scissors: &[vk::Rect2D],
+    ) {
+    }
     fn cmd_set_checkpoint_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_checkpoint_marker: *const c_void,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCheckpointNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_checkpoint_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_checkpoint_marker: *const c_void,
+    ) {
+    }
     fn get_queue_checkpoint_data_nv(
         &self,
         _queue: vk::Queue,
         _p_checkpoint_data_count: &mut MaybeUninit<u32>,
         _p_checkpoint_data: Option<&mut [MaybeUninit<vk::CheckpointDataNV>]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetQueueCheckpointDataNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_queue_checkpoint_data_nv(
+        &self,
+        _queue: vk::Queue,
+        _p_checkpoint_data_count: &u32,
+        _p_checkpoint_data: Option<&[vk::CheckpointDataNV]>,
+    ) {
+    }
     fn initialize_performance_api_intel(
         &self,
         _p_initialize_info: &vk::InitializePerformanceApiInfoINTEL,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, InitializePerformanceApiIntelArgs> {
         LayerResult::Unhandled
     }
-    fn uninitialize_performance_api_intel(&self) -> LayerResult<()> {
+    fn after_initialize_performance_api_intel(
+        &self,
+        _p_initialize_info: &vk::InitializePerformanceApiInfoINTEL,
+        _result: vk::Result,
+    ) {
+    }
+    fn uninitialize_performance_api_intel(
+        &self,
+    ) -> LayerResult<(), UninitializePerformanceApiIntelArgs> {
         LayerResult::Unhandled
     }
+    fn after_uninitialize_performance_api_intel(&self) {}
     fn cmd_set_performance_marker_intel(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_marker_info: &vk::PerformanceMarkerInfoINTEL,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CmdSetPerformanceMarkerIntelArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_performance_marker_intel(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_marker_info: &vk::PerformanceMarkerInfoINTEL,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_set_performance_stream_marker_intel(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_marker_info: &vk::PerformanceStreamMarkerInfoINTEL,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CmdSetPerformanceStreamMarkerIntelArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_performance_stream_marker_intel(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_marker_info: &vk::PerformanceStreamMarkerInfoINTEL,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_set_performance_override_intel(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_override_info: &vk::PerformanceOverrideInfoINTEL,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CmdSetPerformanceOverrideIntelArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_performance_override_intel(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_override_info: &vk::PerformanceOverrideInfoINTEL,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_performance_configuration_intel(
         &self,
         _p_acquire_info: &vk::PerformanceConfigurationAcquireInfoINTEL,
-    ) -> LayerResult<VkResult<vk::PerformanceConfigurationINTEL>> {
+    ) -> LayerResult<
+        VkResult<vk::PerformanceConfigurationINTEL>,
+        AcquirePerformanceConfigurationIntelArgs,
+    > {
         LayerResult::Unhandled
     }
+    fn after_acquire_performance_configuration_intel(
+        &self,
+        _p_acquire_info: &vk::PerformanceConfigurationAcquireInfoINTEL,
+        _p_configuration: Option<vk::PerformanceConfigurationINTEL>,
+        _result: vk::Result,
+    ) {
+    }
     fn release_performance_configuration_intel(
         &self,
         _configuration: vk::PerformanceConfigurationINTEL,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ReleasePerformanceConfigurationIntelArgs> {
         LayerResult::Unhandled
     }
+    fn after_release_performance_configuration_intel(
+        &self,
+        _configuration: vk::PerformanceConfigurationINTEL,
+        _result: vk::Result,
+    ) {
+    }
     fn queue_set_performance_configuration_intel(
         &self,
         _queue: vk::Queue,
         _configuration: vk::PerformanceConfigurationINTEL,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, QueueSetPerformanceConfigurationIntelArgs> {
         LayerResult::Unhandled
     }
+    fn after_queue_set_performance_configuration_intel(
+        &self,
+        _queue: vk::Queue,
+        _configuration: vk::PerformanceConfigurationINTEL,
+        _result: vk::Result,
+    ) {
+    }
     fn get_performance_parameter_intel(
         &self,
         _parameter: vk::PerformanceParameterTypeINTEL,
         _p_value: &mut MaybeUninit<vk::PerformanceValueINTEL>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPerformanceParameterIntelArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_performance_parameter_intel(
+        &self,
+        _parameter: vk::PerformanceParameterTypeINTEL,
+        _p_value: Option<&vk::PerformanceValueINTEL>,
+        _result: vk::Result,
+    ) {
+    }
     fn set_local_dimming_amd(
         &self,
         _swap_chain: vk::SwapchainKHR,
         _local_dimming_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), SetLocalDimmingAmdArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_local_dimming_amd(
+        &self,
+        _swap_chain: vk::SwapchainKHR,
+        _local_dimming_enable: bool,
+    ) {
+    }
     fn acquire_full_screen_exclusive_mode_ext(
         &self,
         _swapchain: vk::SwapchainKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, AcquireFullScreenExclusiveModeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_acquire_full_screen_exclusive_mode_ext(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn release_full_screen_exclusive_mode_ext(
         &self,
         _swapchain: vk::SwapchainKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ReleaseFullScreenExclusiveModeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_release_full_screen_exclusive_mode_ext(
+        &self,
+        _swapchain: vk::SwapchainKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_device_group_surface_present_modes2_ext(
         &self,
         _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
-    ) -> LayerResult<VkResult<vk::DeviceGroupPresentModeFlagsKHR>> {
+    ) -> LayerResult<
+        VkResult<vk::DeviceGroupPresentModeFlagsKHR>,
+        GetDeviceGroupSurfacePresentModes2ExtArgs,
+    > {
         LayerResult::Unhandled
     }
+    fn after_get_device_group_surface_present_modes2_ext(
+        &self,
+        _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
+        _p_modes: Option<vk::DeviceGroupPresentModeFlagsKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_set_line_stipple_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _line_stipple_factor: u32,
         _line_stipple_pattern: u16,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetLineStippleExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_line_stipple_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _line_stipple_factor: u32,
+        _line_stipple_pattern: u16,
+    ) {
+    }
     fn release_swapchain_images_ext(
         &self,
         _p_release_info: &vk::ReleaseSwapchainImagesInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ReleaseSwapchainImagesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_release_swapchain_images_ext(
+        &self,
+        _p_release_info: &vk::ReleaseSwapchainImagesInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn get_generated_commands_memory_requirements_nv(
         &self,
         _p_info: &vk::GeneratedCommandsMemoryRequirementsInfoNV,
         _p_memory_requirements: &mut MaybeUninit<vk::MemoryRequirements2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetGeneratedCommandsMemoryRequirementsNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_generated_commands_memory_requirements_nv(
+        &self,
+        _p_info: &vk::GeneratedCommandsMemoryRequirementsInfoNV,
+        _p_memory_requirements: &vk::MemoryRequirements2,
+    ) {
+    }
     fn cmd_preprocess_generated_commands_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_generated_commands_info: &vk::GeneratedCommandsInfoNV,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdPreprocessGeneratedCommandsNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_preprocess_generated_commands_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_generated_commands_info: &vk::GeneratedCommandsInfoNV,
+    ) {
+    }
     fn cmd_execute_generated_commands_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _is_preprocessed: bool,
         _p_generated_commands_info: &vk::GeneratedCommandsInfoNV,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdExecuteGeneratedCommandsNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_execute_generated_commands_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _is_preprocessed: bool,
+        _p_generated_commands_info: &vk::GeneratedCommandsInfoNV,
+    ) {
+    }
     fn cmd_bind_pipeline_shader_group_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _pipeline_bind_point: vk::PipelineBindPoint,
         _pipeline: vk::Pipeline,
         _group_index: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindPipelineShaderGroupNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_pipeline_shader_group_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_bind_point: vk::PipelineBindPoint,
+        _pipeline: vk::Pipeline,
+        _group_index: u32,
+    ) {
+    }
     fn create_indirect_commands_layout_nv(
         &self,
         _p_create_info: &vk::IndirectCommandsLayoutCreateInfoNV,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::IndirectCommandsLayoutNV>> {
+    ) -> LayerResult<VkResult<vk::IndirectCommandsLayoutNV>, CreateIndirectCommandsLayoutNvArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_create_indirect_commands_layout_nv(
+        &self,
+        _p_create_info: &vk::IndirectCommandsLayoutCreateInfoNV,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_indirect_commands_layout: Option<vk::IndirectCommandsLayoutNV>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_indirect_commands_layout_nv(
         &self,
         _indirect_commands_layout: vk::IndirectCommandsLayoutNV,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyIndirectCommandsLayoutNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_indirect_commands_layout_nv(
+        &self,
+        _indirect_commands_layout: vk::IndirectCommandsLayoutNV,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn export_metal_objects_ext(
         &self,
         _p_metal_objects_info: &mut MaybeUninit<vk::ExportMetalObjectsInfoEXT>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), ExportMetalObjectsExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_export_metal_objects_ext(
+        &self,
+        _p_metal_objects_info: &vk::ExportMetalObjectsInfoEXT,
+    ) {
+    }
     fn get_descriptor_set_layout_size_ext(
         &self,
         _layout: vk::DescriptorSetLayout,
-    ) -> LayerResult<vk::DeviceSize> {
+    ) -> LayerResult<vk::DeviceSize, GetDescriptorSetLayoutSizeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_descriptor_set_layout_size_ext(
+        &self,
+        _layout: vk::DescriptorSetLayout,
+        _result: vk::DeviceSize,
+    ) {
+    }
     fn get_descriptor_set_layout_binding_offset_ext(
         &self,
         _layout: vk::DescriptorSetLayout,
         _binding: u32,
-    ) -> LayerResult<vk::DeviceSize> {
+    ) -> LayerResult<vk::DeviceSize, GetDescriptorSetLayoutBindingOffsetExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_descriptor_set_layout_binding_offset_ext(
+        &self,
+        _layout: vk::DescriptorSetLayout,
+        _binding: u32,
+        _result: vk::DeviceSize,
+    ) {
+    }
     fn get_descriptor_ext(
         &self,
         _p_descriptor_info: &vk::DescriptorGetInfoEXT,
         _p_descriptor: &mut [MaybeUninit<u8>],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetDescriptorExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_descriptor_ext(
+        &self,
+        _p_descriptor_info: &vk::DescriptorGetInfoEXT,
+        _p_descriptor: &[u8],
+    ) {
+    }
     fn cmd_bind_descriptor_buffers_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_binding_infos: &[vk::DescriptorBufferBindingInfoEXT],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindDescriptorBuffersExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_descriptor_buffers_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_binding_infos: &[vk::DescriptorBufferBindingInfoEXT],
+    ) {
+    }
     fn cmd_set_descriptor_buffer_offsets_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -3909,83 +7967,164 @@ pub trait DeviceHooks: Send + Sync {
         _first_set: u32,
         _p_buffer_indices: &[u32],
         _p_offsets: &[vk::DeviceSize],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDescriptorBufferOffsetsExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_descriptor_buffer_offsets_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_bind_point: vk::PipelineBindPoint,
+        _layout: vk::PipelineLayout,
+        _first_set: u32,
+        _p_buffer_indices: &[u32],
+        _p_offsets: &[vk::DeviceSize],
+    ) {
+    }
     fn cmd_bind_descriptor_buffer_embedded_samplers_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _pipeline_bind_point: vk::PipelineBindPoint,
         _layout: vk::PipelineLayout,
         _set: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindDescriptorBufferEmbeddedSamplersExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_descriptor_buffer_embedded_samplers_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_bind_point: vk::PipelineBindPoint,
+        _layout: vk::PipelineLayout,
+        _set: u32,
+    ) {
+    }
     fn get_buffer_opaque_capture_descriptor_data_ext(
         &self,
         _p_info: &vk::BufferCaptureDescriptorDataInfoEXT,
         _p_data: *mut c_void,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetBufferOpaqueCaptureDescriptorDataExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_buffer_opaque_capture_descriptor_data_ext(
+        &self,
+        _p_info: &vk::BufferCaptureDescriptorDataInfoEXT,
+        _p_data: *mut c_void,
+        _result: vk::Result,
+    ) {
+    }
     fn get_image_opaque_capture_descriptor_data_ext(
         &self,
         _p_info: &vk::ImageCaptureDescriptorDataInfoEXT,
         _p_data: *mut c_void,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetImageOpaqueCaptureDescriptorDataExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_opaque_capture_descriptor_data_ext(
+        &self,
+        _p_info: &vk::ImageCaptureDescriptorDataInfoEXT,
+        _p_data: *mut c_void,
+        _result: vk::Result,
+    ) {
+    }
     fn get_image_view_opaque_capture_descriptor_data_ext(
         &self,
         _p_info: &vk::ImageViewCaptureDescriptorDataInfoEXT,
         _p_data: *mut c_void,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetImageViewOpaqueCaptureDescriptorDataExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_view_opaque_capture_descriptor_data_ext(
+        &self,
+        _p_info: &vk::ImageViewCaptureDescriptorDataInfoEXT,
+        _p_data: *mut c_void,
+        _result: vk::Result,
+    ) {
+    }
     fn get_sampler_opaque_capture_descriptor_data_ext(
         &self,
         _p_info: &vk::SamplerCaptureDescriptorDataInfoEXT,
         _p_data: *mut c_void,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetSamplerOpaqueCaptureDescriptorDataExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_sampler_opaque_capture_descriptor_data_ext(
+        &self,
+        _p_info: &vk::SamplerCaptureDescriptorDataInfoEXT,
+        _p_data: *mut c_void,
+        _result: vk::Result,
+    ) {
+    }
     fn get_acceleration_structure_opaque_capture_descriptor_data_ext(
         &self,
         _p_info: &vk::AccelerationStructureCaptureDescriptorDataInfoEXT,
         _p_data: *mut c_void,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetAccelerationStructureOpaqueCaptureDescriptorDataExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_acceleration_structure_opaque_capture_descriptor_data_ext(
+        &self,
+        _p_info: &vk::AccelerationStructureCaptureDescriptorDataInfoEXT,
+        _p_data: *mut c_void,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_set_fragment_shading_rate_enum_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _shading_rate: vk::FragmentShadingRateNV,
         _combiner_ops: &[vk::FragmentShadingRateCombinerOpKHR; 2],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetFragmentShadingRateEnumNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_fragment_shading_rate_enum_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _shading_rate: vk::FragmentShadingRateNV,
+        _combiner_ops: &[vk::FragmentShadingRateCombinerOpKHR; 2],
+    ) {
+    }
     fn get_image_subresource_layout2_ext(
         &self,
         _image: vk::Image,
         _p_subresource: &vk::ImageSubresource2EXT,
         _p_layout: &mut MaybeUninit<vk::SubresourceLayout2EXT>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetImageSubresourceLayout2ExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_image_subresource_layout2_ext(
+        &self,
+        _image: vk::Image,
+        _p_subresource: &vk::ImageSubresource2EXT,
+        _p_layout: &vk::SubresourceLayout2EXT,
+    ) {
+    }
     fn cmd_set_vertex_input_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_vertex_binding_descriptions: &[vk::VertexInputBindingDescription2EXT],
         _p_vertex_attribute_descriptions: &[vk::VertexInputAttributeDescription2EXT],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetVertexInputExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_vertex_input_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_vertex_binding_descriptions: &[vk::VertexInputBindingDescription2EXT],
+        _p_vertex_attribute_descriptions: &[vk::VertexInputAttributeDescription2EXT],
+    ) {
+    }
     fn get_memory_zircon_handle_fuchsia(
         &self,
         _p_get_zircon_handle_info: &vk::MemoryGetZirconHandleInfoFUCHSIA,
-    ) -> LayerResult<VkResult<vk::zx_handle_t>> {
+    ) -> LayerResult<VkResult<vk::zx_handle_t>, GetMemoryZirconHandleFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_zircon_handle_fuchsia(
+        &self,
+        _p_get_zircon_handle_info: &vk::MemoryGetZirconHandleInfoFUCHSIA,
+        _p_zircon_handle: Option<vk::zx_handle_t>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_memory_zircon_handle_properties_fuchsia(
         &self,
         _handle_type: vk::ExternalMemoryHandleTypeFlags,
@@ -3993,108 +8132,214 @@ pub trait DeviceHooks: Send + Sync {
         _p_memory_zircon_handle_properties: &mut MaybeUninit<
             vk::MemoryZirconHandlePropertiesFUCHSIA,
         >,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetMemoryZirconHandlePropertiesFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_zircon_handle_properties_fuchsia(
+        &self,
+        _handle_type: vk::ExternalMemoryHandleTypeFlags,
+        _zircon_handle: vk::zx_handle_t,
+        _p_memory_zircon_handle_properties: Option<&vk::MemoryZirconHandlePropertiesFUCHSIA>,
+        _result: vk::Result,
+    ) {
+    }
     fn import_semaphore_zircon_handle_fuchsia(
         &self,
         _p_import_semaphore_zircon_handle_info: &vk::ImportSemaphoreZirconHandleInfoFUCHSIA,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ImportSemaphoreZirconHandleFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_import_semaphore_zircon_handle_fuchsia(
+        &self,
+        _p_import_semaphore_zircon_handle_info: &vk::ImportSemaphoreZirconHandleInfoFUCHSIA,
+        _result: vk::Result,
+    ) {
+    }
     fn get_semaphore_zircon_handle_fuchsia(
         &self,
         _p_get_zircon_handle_info: &vk::SemaphoreGetZirconHandleInfoFUCHSIA,
-    ) -> LayerResult<VkResult<vk::zx_handle_t>> {
+    ) -> LayerResult<VkResult<vk::zx_handle_t>, GetSemaphoreZirconHandleFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_semaphore_zircon_handle_fuchsia(
+        &self,
+        _p_get_zircon_handle_info: &vk::SemaphoreGetZirconHandleInfoFUCHSIA,
+        _p_zircon_handle: Option<vk::zx_handle_t>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_buffer_collection_fuchsia(
         &self,
         _p_create_info: &vk::BufferCollectionCreateInfoFUCHSIA,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::BufferCollectionFUCHSIA>> {
+    ) -> LayerResult<VkResult<vk::BufferCollectionFUCHSIA>, CreateBufferCollectionFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_buffer_collection_fuchsia(
+        &self,
+        _p_create_info: &vk::BufferCollectionCreateInfoFUCHSIA,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_collection: Option<vk::BufferCollectionFUCHSIA>,
+        _result: vk::Result,
+    ) {
+    }
     fn set_buffer_collection_image_constraints_fuchsia(
         &self,
         _collection: vk::BufferCollectionFUCHSIA,
         _p_image_constraints_info: &vk::ImageConstraintsInfoFUCHSIA,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, SetBufferCollectionImageConstraintsFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_buffer_collection_image_constraints_fuchsia(
+        &self,
+        _collection: vk::BufferCollectionFUCHSIA,
+        _p_image_constraints_info: &vk::ImageConstraintsInfoFUCHSIA,
+        _result: vk::Result,
+    ) {
+    }
     fn set_buffer_collection_buffer_constraints_fuchsia(
         &self,
         _collection: vk::BufferCollectionFUCHSIA,
         _p_buffer_constraints_info: &vk::BufferConstraintsInfoFUCHSIA,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, SetBufferCollectionBufferConstraintsFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_buffer_collection_buffer_constraints_fuchsia(
+        &self,
+        _collection: vk::BufferCollectionFUCHSIA,
+        _p_buffer_constraints_info: &vk::BufferConstraintsInfoFUCHSIA,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_buffer_collection_fuchsia(
         &self,
         _collection: vk::BufferCollectionFUCHSIA,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyBufferCollectionFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_buffer_collection_fuchsia(
+        &self,
+        _collection: vk::BufferCollectionFUCHSIA,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_buffer_collection_properties_fuchsia(
         &self,
         _collection: vk::BufferCollectionFUCHSIA,
         _p_properties: &mut MaybeUninit<vk::BufferCollectionPropertiesFUCHSIA>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetBufferCollectionPropertiesFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_buffer_collection_properties_fuchsia(
+        &self,
+        _collection: vk::BufferCollectionFUCHSIA,
+        _p_properties: Option<&vk::BufferCollectionPropertiesFUCHSIA>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_device_subpass_shading_max_workgroup_size_huawei(
         &self,
         _renderpass: vk::RenderPass,
         _p_max_workgroup_size: &mut MaybeUninit<vk::Extent2D>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetDeviceSubpassShadingMaxWorkgroupSizeHuaweiArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_subpass_shading_huawei(&self, _command_buffer: vk::CommandBuffer) -> LayerResult<()> {
+    fn after_get_device_subpass_shading_max_workgroup_size_huawei(
+        &self,
+        _renderpass: vk::RenderPass,
+        _p_max_workgroup_size: Option<&vk::Extent2D>,
+        _result: vk::Result,
+    ) {
+    }
+    fn cmd_subpass_shading_huawei(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+    ) -> LayerResult<(), CmdSubpassShadingHuaweiArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_subpass_shading_huawei(&self, _command_buffer: vk::CommandBuffer) {}
     fn cmd_bind_invocation_mask_huawei(
         &self,
         _command_buffer: vk::CommandBuffer,
         _image_view: vk::ImageView,
         _image_layout: vk::ImageLayout,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBindInvocationMaskHuaweiArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_bind_invocation_mask_huawei(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _image_view: vk::ImageView,
+        _image_layout: vk::ImageLayout,
+    ) {
+    }
     fn get_memory_remote_address_nv(
         &self,
         _p_memory_get_remote_address_info: &vk::MemoryGetRemoteAddressInfoNV,
-    ) -> LayerResult<VkResult<vk::RemoteAddressNV>> {
+    ) -> LayerResult<VkResult<vk::RemoteAddressNV>, GetMemoryRemoteAddressNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_memory_remote_address_nv(
+        &self,
+        _p_memory_get_remote_address_info: &vk::MemoryGetRemoteAddressInfoNV,
+        _p_address: Option<*mut c_void>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_pipeline_properties_ext(
         &self,
         _p_pipeline_info: &vk::PipelineInfoEXT,
         _p_pipeline_properties: &mut MaybeUninit<vk::BaseOutStructure>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPipelinePropertiesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_pipeline_properties_ext(
+        &self,
+        _p_pipeline_info: &vk::PipelineInfoEXT,
+        _p_pipeline_properties: Option<&vk::BaseOutStructure>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_set_patch_control_points_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _patch_control_points: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetPatchControlPointsExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_patch_control_points_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _patch_control_points: u32,
+    ) {
+    }
     fn cmd_set_logic_op_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _logic_op: vk::LogicOp,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetLogicOpExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_logic_op_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _logic_op: vk::LogicOp,
+    ) {
+    }
     fn cmd_set_color_write_enable_ext<T: Iterator<Item = bool> + 'static>(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_color_write_enables: T,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetColorWriteEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_color_write_enable_ext<T: Iterator<Item = bool> + 'static>(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_color_write_enables: T,
+    ) {
+    }
     fn cmd_draw_multi_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4102,10 +8347,30 @@ pub trait DeviceHooks: Send + Sync {
         _instance_count: u32,
         _first_instance: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawMultiExtArgs> {
+        LayerResult::Unhandled
+    }
+    fn after_cmd_draw_multi_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_vertex_info: &[vk::MultiDrawInfoEXT],
+        _instance_count: u32,
+        _first_instance: u32,
+        _stride: u32,
+    ) {
+    }
+    fn cmd_draw_multi_indexed_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_index_info: &[vk::MultiDrawIndexedInfoEXT],
+        _instance_count: u32,
+        _first_instance: u32,
+        _stride: u32,
+        _p_vertex_offset: Option<&i32>,
+    ) -> LayerResult<(), CmdDrawMultiIndexedExtArgs> {
         LayerResult::Unhandled
     }
-    fn cmd_draw_multi_indexed_ext(
+    fn after_cmd_draw_multi_indexed_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_index_info: &[vk::MultiDrawIndexedInfoEXT],
@@ -4113,88 +8378,162 @@ pub trait DeviceHooks: Send + Sync {
         _first_instance: u32,
         _stride: u32,
         _p_vertex_offset: Option<&i32>,
-    ) -> LayerResult<()> {
-        LayerResult::Unhandled
+    ) {
     }
     fn create_micromap_ext(
         &self,
         _p_create_info: &vk::MicromapCreateInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::MicromapEXT>> {
+    ) -> LayerResult<VkResult<vk::MicromapEXT>, CreateMicromapExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_micromap_ext(
+        &self,
+        _p_create_info: &vk::MicromapCreateInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_micromap: Option<vk::MicromapEXT>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_micromap_ext(
         &self,
         _micromap: vk::MicromapEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyMicromapExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_micromap_ext(
+        &self,
+        _micromap: vk::MicromapEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn cmd_build_micromaps_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_infos: &[vk::MicromapBuildInfoEXT],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdBuildMicromapsExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_build_micromaps_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_infos: &[vk::MicromapBuildInfoEXT],
+    ) {
+    }
     fn build_micromaps_ext(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _p_infos: &[vk::MicromapBuildInfoEXT],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BuildMicromapsExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_build_micromaps_ext(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _p_infos: &[vk::MicromapBuildInfoEXT],
+        _result: vk::Result,
+    ) {
+    }
     fn copy_micromap_ext(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _p_info: &vk::CopyMicromapInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CopyMicromapExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_copy_micromap_ext(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _p_info: &vk::CopyMicromapInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn copy_micromap_to_memory_ext(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _p_info: &vk::CopyMicromapToMemoryInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CopyMicromapToMemoryExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_copy_micromap_to_memory_ext(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _p_info: &vk::CopyMicromapToMemoryInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn copy_memory_to_micromap_ext(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _p_info: &vk::CopyMemoryToMicromapInfoEXT,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CopyMemoryToMicromapExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_copy_memory_to_micromap_ext(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _p_info: &vk::CopyMemoryToMicromapInfoEXT,
+        _result: vk::Result,
+    ) {
+    }
     fn write_micromaps_properties_ext(
         &self,
         _p_micromaps: &[vk::MicromapEXT],
         _query_type: vk::QueryType,
         _p_data: &mut [MaybeUninit<u8>],
         _stride: usize,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, WriteMicromapsPropertiesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_write_micromaps_properties_ext(
+        &self,
+        _p_micromaps: &[vk::MicromapEXT],
+        _query_type: vk::QueryType,
+        _p_data: Option<&[u8]>,
+        _stride: usize,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_copy_micromap_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_info: &vk::CopyMicromapInfoEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyMicromapExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_micromap_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_info: &vk::CopyMicromapInfoEXT,
+    ) {
+    }
     fn cmd_copy_micromap_to_memory_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_info: &vk::CopyMicromapToMemoryInfoEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyMicromapToMemoryExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_micromap_to_memory_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_info: &vk::CopyMicromapToMemoryInfoEXT,
+    ) {
+    }
     fn cmd_copy_memory_to_micromap_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_info: &vk::CopyMemoryToMicromapInfoEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyMemoryToMicromapExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_memory_to_micromap_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_info: &vk::CopyMemoryToMicromapInfoEXT,
+    ) {
+    }
     fn cmd_write_micromaps_properties_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4202,52 +8541,96 @@ pub trait DeviceHooks: Send + Sync {
         _query_type: vk::QueryType,
         _query_pool: vk::QueryPool,
         _first_query: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWriteMicromapsPropertiesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_write_micromaps_properties_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_micromaps: &[vk::MicromapEXT],
+        _query_type: vk::QueryType,
+        _query_pool: vk::QueryPool,
+        _first_query: u32,
+    ) {
+    }
     fn get_device_micromap_compatibility_ext(
         &self,
         _p_version_info: &vk::MicromapVersionInfoEXT,
-    ) -> LayerResult<vk::AccelerationStructureCompatibilityKHR> {
+    ) -> LayerResult<vk::AccelerationStructureCompatibilityKHR, GetDeviceMicromapCompatibilityExtArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_get_device_micromap_compatibility_ext(
+        &self,
+        _p_version_info: &vk::MicromapVersionInfoEXT,
+        _result: vk::AccelerationStructureCompatibilityKHR,
+    ) {
+    }
     fn get_micromap_build_sizes_ext(
         &self,
         _build_type: vk::AccelerationStructureBuildTypeKHR,
         _p_build_info: &vk::MicromapBuildInfoEXT,
         _p_size_info: &mut MaybeUninit<vk::MicromapBuildSizesInfoEXT>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetMicromapBuildSizesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_micromap_build_sizes_ext(
+        &self,
+        _build_type: vk::AccelerationStructureBuildTypeKHR,
+        _p_build_info: &vk::MicromapBuildInfoEXT,
+        _p_size_info: &vk::MicromapBuildSizesInfoEXT,
+    ) {
+    }
     fn set_device_memory_priority_ext(
         &self,
         _memory: vk::DeviceMemory,
         _priority: f32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), SetDeviceMemoryPriorityExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_set_device_memory_priority_ext(&self, _memory: vk::DeviceMemory, _priority: f32) {}
     fn get_descriptor_set_layout_host_mapping_info_valve(
         &self,
         _p_binding_reference: &vk::DescriptorSetBindingReferenceVALVE,
         _p_host_mapping: &mut MaybeUninit<vk::DescriptorSetLayoutHostMappingInfoVALVE>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetDescriptorSetLayoutHostMappingInfoValveArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_descriptor_set_layout_host_mapping_info_valve(
+        &self,
+        _p_binding_reference: &vk::DescriptorSetBindingReferenceVALVE,
+        _p_host_mapping: &vk::DescriptorSetLayoutHostMappingInfoVALVE,
+    ) {
+    }
     fn get_descriptor_set_host_mapping_valve(
         &self,
         _descriptor_set: vk::DescriptorSet,
-    ) -> LayerResult<*mut c_void> {
+    ) -> LayerResult<*mut c_void, GetDescriptorSetHostMappingValveArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_descriptor_set_host_mapping_valve(
+        &self,
+        _descriptor_set: vk::DescriptorSet,
+        _result: *mut c_void,
+    ) {
+    }
     fn cmd_copy_memory_indirect_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _copy_buffer_address: vk::DeviceAddress,
         _copy_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyMemoryIndirectNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_memory_indirect_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _copy_buffer_address: vk::DeviceAddress,
+        _copy_count: u32,
+        _stride: u32,
+    ) {
+    }
     fn cmd_copy_memory_to_image_indirect_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4256,379 +8639,721 @@ pub trait DeviceHooks: Send + Sync {
         _dst_image: vk::Image,
         _dst_image_layout: vk::ImageLayout,
         _p_image_subresources: &[vk::ImageSubresourceLayers],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyMemoryToImageIndirectNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_memory_to_image_indirect_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _copy_buffer_address: vk::DeviceAddress,
+        _stride: u32,
+        _dst_image: vk::Image,
+        _dst_image_layout: vk::ImageLayout,
+        _p_image_subresources: &[vk::ImageSubresourceLayers],
+    ) {
+    }
     fn cmd_decompress_memory_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_decompress_memory_regions: &[vk::DecompressMemoryRegionNV],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDecompressMemoryNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_decompress_memory_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_decompress_memory_regions: &[vk::DecompressMemoryRegionNV],
+    ) {
+    }
     fn cmd_decompress_memory_indirect_count_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _indirect_commands_address: vk::DeviceAddress,
         _indirect_commands_count_address: vk::DeviceAddress,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDecompressMemoryIndirectCountNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_decompress_memory_indirect_count_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _indirect_commands_address: vk::DeviceAddress,
+        _indirect_commands_count_address: vk::DeviceAddress,
+        _stride: u32,
+    ) {
+    }
     fn cmd_set_tessellation_domain_origin_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _domain_origin: vk::TessellationDomainOrigin,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetTessellationDomainOriginExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_tessellation_domain_origin_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _domain_origin: vk::TessellationDomainOrigin,
+    ) {
+    }
     fn cmd_set_depth_clamp_enable_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_clamp_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthClampEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_clamp_enable_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_clamp_enable: bool,
+    ) {
+    }
     fn cmd_set_polygon_mode_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _polygon_mode: vk::PolygonMode,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetPolygonModeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_polygon_mode_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _polygon_mode: vk::PolygonMode,
+    ) {
+    }
     fn cmd_set_rasterization_samples_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _rasterization_samples: vk::SampleCountFlags,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetRasterizationSamplesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_rasterization_samples_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _rasterization_samples: vk::SampleCountFlags,
+    ) {
+    }
     fn cmd_set_sample_mask_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _samples: vk::SampleCountFlags,
         _p_sample_mask: &[vk::SampleMask],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetSampleMaskExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_sample_mask_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _samples: vk::SampleCountFlags,
+        _p_sample_mask: &[vk::SampleMask],
+    ) {
+    }
     fn cmd_set_alpha_to_coverage_enable_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _alpha_to_coverage_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetAlphaToCoverageEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_alpha_to_coverage_enable_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _alpha_to_coverage_enable: bool,
+    ) {
+    }
     fn cmd_set_alpha_to_one_enable_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _alpha_to_one_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetAlphaToOneEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_alpha_to_one_enable_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _alpha_to_one_enable: bool,
+    ) {
+    }
     fn cmd_set_logic_op_enable_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _logic_op_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetLogicOpEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_logic_op_enable_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _logic_op_enable: bool,
+    ) {
+    }
     fn cmd_set_color_blend_enable_ext<T: Iterator<Item = bool> + 'static>(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_attachment: u32,
         _p_color_blend_enables: T,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetColorBlendEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_color_blend_enable_ext<T: Iterator<Item = bool> + 'static>(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_attachment: u32,
+        _p_color_blend_enables: T,
+    ) {
+    }
     fn cmd_set_color_blend_equation_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_attachment: u32,
         _p_color_blend_equations: &[vk::ColorBlendEquationEXT],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetColorBlendEquationExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_color_blend_equation_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_attachment: u32,
+        _p_color_blend_equations: &[vk::ColorBlendEquationEXT],
+    ) {
+    }
     fn cmd_set_color_write_mask_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_attachment: u32,
         _p_color_write_masks: &[vk::ColorComponentFlags],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetColorWriteMaskExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_color_write_mask_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_attachment: u32,
+        _p_color_write_masks: &[vk::ColorComponentFlags],
+    ) {
+    }
     fn cmd_set_rasterization_stream_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _rasterization_stream: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetRasterizationStreamExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_rasterization_stream_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _rasterization_stream: u32,
+    ) {
+    }
     fn cmd_set_conservative_rasterization_mode_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _conservative_rasterization_mode: vk::ConservativeRasterizationModeEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetConservativeRasterizationModeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_conservative_rasterization_mode_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _conservative_rasterization_mode: vk::ConservativeRasterizationModeEXT,
+    ) {
+    }
     fn cmd_set_extra_primitive_overestimation_size_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _extra_primitive_overestimation_size: f32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetExtraPrimitiveOverestimationSizeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_extra_primitive_overestimation_size_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _extra_primitive_overestimation_size: f32,
+    ) {
+    }
     fn cmd_set_depth_clip_enable_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _depth_clip_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthClipEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_clip_enable_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _depth_clip_enable: bool,
+    ) {
+    }
     fn cmd_set_sample_locations_enable_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _sample_locations_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetSampleLocationsEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_sample_locations_enable_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _sample_locations_enable: bool,
+    ) {
+    }
     fn cmd_set_color_blend_advanced_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_attachment: u32,
         _p_color_blend_advanced: &[vk::ColorBlendAdvancedEXT],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetColorBlendAdvancedExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_color_blend_advanced_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_attachment: u32,
+        _p_color_blend_advanced: &[vk::ColorBlendAdvancedEXT],
+    ) {
+    }
     fn cmd_set_provoking_vertex_mode_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _provoking_vertex_mode: vk::ProvokingVertexModeEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetProvokingVertexModeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_provoking_vertex_mode_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _provoking_vertex_mode: vk::ProvokingVertexModeEXT,
+    ) {
+    }
     fn cmd_set_line_rasterization_mode_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _line_rasterization_mode: vk::LineRasterizationModeEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetLineRasterizationModeExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_line_rasterization_mode_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _line_rasterization_mode: vk::LineRasterizationModeEXT,
+    ) {
+    }
     fn cmd_set_line_stipple_enable_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _stippled_line_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetLineStippleEnableExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_line_stipple_enable_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _stippled_line_enable: bool,
+    ) {
+    }
     fn cmd_set_depth_clip_negative_one_to_one_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _negative_one_to_one: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetDepthClipNegativeOneToOneExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_depth_clip_negative_one_to_one_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _negative_one_to_one: bool,
+    ) {
+    }
     fn cmd_set_viewport_w_scaling_enable_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _viewport_w_scaling_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetViewportWScalingEnableNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_viewport_w_scaling_enable_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _viewport_w_scaling_enable: bool,
+    ) {
+    }
     fn cmd_set_viewport_swizzle_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _first_viewport: u32,
         _p_viewport_swizzles: &[vk::ViewportSwizzleNV],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetViewportSwizzleNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_viewport_swizzle_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _first_viewport: u32,
+        _p_viewport_swizzles: &[vk::ViewportSwizzleNV],
+    ) {
+    }
     fn cmd_set_coverage_to_color_enable_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _coverage_to_color_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCoverageToColorEnableNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_coverage_to_color_enable_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _coverage_to_color_enable: bool,
+    ) {
+    }
     fn cmd_set_coverage_to_color_location_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _coverage_to_color_location: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCoverageToColorLocationNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_coverage_to_color_location_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _coverage_to_color_location: u32,
+    ) {
+    }
     fn cmd_set_coverage_modulation_mode_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _coverage_modulation_mode: vk::CoverageModulationModeNV,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCoverageModulationModeNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_coverage_modulation_mode_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _coverage_modulation_mode: vk::CoverageModulationModeNV,
+    ) {
+    }
     fn cmd_set_coverage_modulation_table_enable_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _coverage_modulation_table_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCoverageModulationTableEnableNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_coverage_modulation_table_enable_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _coverage_modulation_table_enable: bool,
+    ) {
+    }
     fn cmd_set_coverage_modulation_table_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_coverage_modulation_table: &[f32],
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCoverageModulationTableNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_coverage_modulation_table_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_coverage_modulation_table: &[f32],
+    ) {
+    }
     fn cmd_set_shading_rate_image_enable_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _shading_rate_image_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetShadingRateImageEnableNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_shading_rate_image_enable_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _shading_rate_image_enable: bool,
+    ) {
+    }
     fn cmd_set_representative_fragment_test_enable_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _representative_fragment_test_enable: bool,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetRepresentativeFragmentTestEnableNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_representative_fragment_test_enable_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _representative_fragment_test_enable: bool,
+    ) {
+    }
     fn cmd_set_coverage_reduction_mode_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _coverage_reduction_mode: vk::CoverageReductionModeNV,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetCoverageReductionModeNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_coverage_reduction_mode_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _coverage_reduction_mode: vk::CoverageReductionModeNV,
+    ) {
+    }
     fn get_shader_module_identifier_ext(
         &self,
         _shader_module: vk::ShaderModule,
         _p_identifier: &mut MaybeUninit<vk::ShaderModuleIdentifierEXT>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetShaderModuleIdentifierExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_shader_module_identifier_ext(
+        &self,
+        _shader_module: vk::ShaderModule,
+        _p_identifier: &vk::ShaderModuleIdentifierEXT,
+    ) {
+    }
     fn get_shader_module_create_info_identifier_ext(
         &self,
         _p_create_info: &vk::ShaderModuleCreateInfo,
         _p_identifier: &mut MaybeUninit<vk::ShaderModuleIdentifierEXT>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetShaderModuleCreateInfoIdentifierExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_shader_module_create_info_identifier_ext(
+        &self,
+        _p_create_info: &vk::ShaderModuleCreateInfo,
+        _p_identifier: &vk::ShaderModuleIdentifierEXT,
+    ) {
+    }
     fn create_optical_flow_session_nv(
         &self,
         _p_create_info: &vk::OpticalFlowSessionCreateInfoNV,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::OpticalFlowSessionNV>> {
+    ) -> LayerResult<VkResult<vk::OpticalFlowSessionNV>, CreateOpticalFlowSessionNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_optical_flow_session_nv(
+        &self,
+        _p_create_info: &vk::OpticalFlowSessionCreateInfoNV,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_session: Option<vk::OpticalFlowSessionNV>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_optical_flow_session_nv(
         &self,
         _session: vk::OpticalFlowSessionNV,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyOpticalFlowSessionNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_optical_flow_session_nv(
+        &self,
+        _session: vk::OpticalFlowSessionNV,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn bind_optical_flow_session_image_nv(
         &self,
         _session: vk::OpticalFlowSessionNV,
         _binding_point: vk::OpticalFlowSessionBindingPointNV,
         _view: vk::ImageView,
         _layout: vk::ImageLayout,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, BindOpticalFlowSessionImageNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_bind_optical_flow_session_image_nv(
+        &self,
+        _session: vk::OpticalFlowSessionNV,
+        _binding_point: vk::OpticalFlowSessionBindingPointNV,
+        _view: vk::ImageView,
+        _layout: vk::ImageLayout,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_optical_flow_execute_nv(
         &self,
         _command_buffer: vk::CommandBuffer,
         _session: vk::OpticalFlowSessionNV,
         _p_execute_info: &vk::OpticalFlowExecuteInfoNV,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdOpticalFlowExecuteNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_optical_flow_execute_nv(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _session: vk::OpticalFlowSessionNV,
+        _p_execute_info: &vk::OpticalFlowExecuteInfoNV,
+    ) {
+    }
     fn get_framebuffer_tile_properties_qcom(
         &self,
         _framebuffer: vk::Framebuffer,
         _p_properties_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::TilePropertiesQCOM>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetFramebufferTilePropertiesQcomArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_framebuffer_tile_properties_qcom(
+        &self,
+        _framebuffer: vk::Framebuffer,
+        _p_properties_count: &u32,
+        _p_properties: Option<&[vk::TilePropertiesQCOM]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_dynamic_rendering_tile_properties_qcom(
         &self,
         _p_rendering_info: &vk::RenderingInfo,
         _p_properties: &mut MaybeUninit<vk::TilePropertiesQCOM>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetDynamicRenderingTilePropertiesQcomArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_dynamic_rendering_tile_properties_qcom(
+        &self,
+        _p_rendering_info: &vk::RenderingInfo,
+        _p_properties: Option<&vk::TilePropertiesQCOM>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_acceleration_structure_khr(
         &self,
         _p_create_info: &vk::AccelerationStructureCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::AccelerationStructureKHR>> {
+    ) -> LayerResult<VkResult<vk::AccelerationStructureKHR>, CreateAccelerationStructureKhrArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_create_acceleration_structure_khr(
+        &self,
+        _p_create_info: &vk::AccelerationStructureCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_acceleration_structure: Option<vk::AccelerationStructureKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_acceleration_structure_khr(
         &self,
         _acceleration_structure: vk::AccelerationStructureKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyAccelerationStructureKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_acceleration_structure_khr(
+        &self,
+        _acceleration_structure: vk::AccelerationStructureKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn copy_acceleration_structure_khr(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _p_info: &vk::CopyAccelerationStructureInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CopyAccelerationStructureKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_copy_acceleration_structure_khr(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _p_info: &vk::CopyAccelerationStructureInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn copy_acceleration_structure_to_memory_khr(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _p_info: &vk::CopyAccelerationStructureToMemoryInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CopyAccelerationStructureToMemoryKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_copy_acceleration_structure_to_memory_khr(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _p_info: &vk::CopyAccelerationStructureToMemoryInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn copy_memory_to_acceleration_structure_khr(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _p_info: &vk::CopyMemoryToAccelerationStructureInfoKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, CopyMemoryToAccelerationStructureKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_copy_memory_to_acceleration_structure_khr(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _p_info: &vk::CopyMemoryToAccelerationStructureInfoKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn write_acceleration_structures_properties_khr(
         &self,
         _p_acceleration_structures: &[vk::AccelerationStructureKHR],
         _query_type: vk::QueryType,
         _p_data: &mut [MaybeUninit<u8>],
         _stride: usize,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, WriteAccelerationStructuresPropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_write_acceleration_structures_properties_khr(
+        &self,
+        _p_acceleration_structures: &[vk::AccelerationStructureKHR],
+        _query_type: vk::QueryType,
+        _p_data: Option<&[u8]>,
+        _stride: usize,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_copy_acceleration_structure_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_info: &vk::CopyAccelerationStructureInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyAccelerationStructureKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_acceleration_structure_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_info: &vk::CopyAccelerationStructureInfoKHR,
+    ) {
+    }
     fn cmd_copy_acceleration_structure_to_memory_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_info: &vk::CopyAccelerationStructureToMemoryInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyAccelerationStructureToMemoryKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_acceleration_structure_to_memory_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_info: &vk::CopyAccelerationStructureToMemoryInfoKHR,
+    ) {
+    }
     fn cmd_copy_memory_to_acceleration_structure_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _p_info: &vk::CopyMemoryToAccelerationStructureInfoKHR,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdCopyMemoryToAccelerationStructureKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_copy_memory_to_acceleration_structure_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_info: &vk::CopyMemoryToAccelerationStructureInfoKHR,
+    ) {
+    }
     fn get_acceleration_structure_device_address_khr(
         &self,
         _p_info: &vk::AccelerationStructureDeviceAddressInfoKHR,
-    ) -> LayerResult<vk::DeviceAddress> {
+    ) -> LayerResult<vk::DeviceAddress, GetAccelerationStructureDeviceAddressKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_acceleration_structure_device_address_khr(
+        &self,
+        _p_info: &vk::AccelerationStructureDeviceAddressInfoKHR,
+        _result: vk::DeviceAddress,
+    ) {
+    }
     fn cmd_write_acceleration_structures_properties_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4636,24 +9361,50 @@ pub trait DeviceHooks: Send + Sync {
         _query_type: vk::QueryType,
         _query_pool: vk::QueryPool,
         _first_query: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdWriteAccelerationStructuresPropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_write_acceleration_structures_properties_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_acceleration_structures: &[vk::AccelerationStructureKHR],
+        _query_type: vk::QueryType,
+        _query_pool: vk::QueryPool,
+        _first_query: u32,
+    ) {
+    }
     fn get_device_acceleration_structure_compatibility_khr(
         &self,
         _p_version_info: &vk::AccelerationStructureVersionInfoKHR,
-    ) -> LayerResult<vk::AccelerationStructureCompatibilityKHR> {
+    ) -> LayerResult<
+        vk::AccelerationStructureCompatibilityKHR,
+        GetDeviceAccelerationStructureCompatibilityKhrArgs,
+    > {
         LayerResult::Unhandled
     }
+    fn after_get_device_acceleration_structure_compatibility_khr(
+        &self,
+        _p_version_info: &vk::AccelerationStructureVersionInfoKHR,
+        _result: vk::AccelerationStructureCompatibilityKHR,
+    ) {
+    }
     fn get_acceleration_structure_build_sizes_khr(
         &self,
         _build_type: vk::AccelerationStructureBuildTypeKHR,
         _p_build_info: &vk::AccelerationStructureBuildGeometryInfoKHR,
         _p_max_primitive_counts: Option<&[u32]>,
         _p_size_info: &mut MaybeUninit<vk::AccelerationStructureBuildSizesInfoKHR>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetAccelerationStructureBuildSizesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_acceleration_structure_build_sizes_khr(
+        &self,
+        _build_type: vk::AccelerationStructureBuildTypeKHR,
+        _p_build_info: &vk::AccelerationStructureBuildGeometryInfoKHR,
+        _p_max_primitive_counts: Option<&[u32]>,
+        _p_size_info: &vk::AccelerationStructureBuildSizesInfoKHR,
+    ) {
+    }
     fn cmd_trace_rays_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4664,27 +9415,58 @@ pub trait DeviceHooks: Send + Sync {
         _width: u32,
         _height: u32,
         _depth: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdTraceRaysKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_trace_rays_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_raygen_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _p_miss_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _p_hit_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _p_callable_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _width: u32,
+        _height: u32,
+        _depth: u32,
+    ) {
+    }
     fn create_ray_tracing_pipelines_khr(
         &self,
         _deferred_operation: vk::DeferredOperationKHR,
         _pipeline_cache: vk::PipelineCache,
         _p_create_infos: &[vk::RayTracingPipelineCreateInfoKHR],
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>> {
+    ) -> LayerResult<VkResult<Vec<vk::Pipeline>>, CreateRayTracingPipelinesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_ray_tracing_pipelines_khr(
+        &self,
+        _deferred_operation: vk::DeferredOperationKHR,
+        _pipeline_cache: vk::PipelineCache,
+        _p_create_infos: &[vk::RayTracingPipelineCreateInfoKHR],
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_pipelines: Option<&[vk::Pipeline]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_ray_tracing_capture_replay_shader_group_handles_khr(
         &self,
         _pipeline: vk::Pipeline,
         _first_group: u32,
         _group_count: u32,
         _p_data: &mut [MaybeUninit<u8>],
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetRayTracingCaptureReplayShaderGroupHandlesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_ray_tracing_capture_replay_shader_group_handles_khr(
+        &self,
+        _pipeline: vk::Pipeline,
+        _first_group: u32,
+        _group_count: u32,
+        _p_data: Option<&[u8]>,
+        _result: vk::Result,
+    ) {
+    }
     fn cmd_trace_rays_indirect_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4693,33 +9475,65 @@ pub trait DeviceHooks: Send + Sync {
         _p_hit_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
         _p_callable_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
         _indirect_device_address: vk::DeviceAddress,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdTraceRaysIndirectKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_trace_rays_indirect_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _p_raygen_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _p_miss_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _p_hit_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _p_callable_shader_binding_table: &vk::StridedDeviceAddressRegionKHR,
+        _indirect_device_address: vk::DeviceAddress,
+    ) {
+    }
     fn get_ray_tracing_shader_group_stack_size_khr(
         &self,
         _pipeline: vk::Pipeline,
         _group: u32,
         _group_shader: vk::ShaderGroupShaderKHR,
-    ) -> LayerResult<vk::DeviceSize> {
+    ) -> LayerResult<vk::DeviceSize, GetRayTracingShaderGroupStackSizeKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_ray_tracing_shader_group_stack_size_khr(
+        &self,
+        _pipeline: vk::Pipeline,
+        _group: u32,
+        _group_shader: vk::ShaderGroupShaderKHR,
+        _result: vk::DeviceSize,
+    ) {
+    }
     fn cmd_set_ray_tracing_pipeline_stack_size_khr(
         &self,
         _command_buffer: vk::CommandBuffer,
         _pipeline_stack_size: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdSetRayTracingPipelineStackSizeKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_set_ray_tracing_pipeline_stack_size_khr(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _pipeline_stack_size: u32,
+    ) {
+    }
     fn cmd_draw_mesh_tasks_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
         _group_countx: u32,
         _group_county: u32,
         _group_countz: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawMeshTasksExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_mesh_tasks_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _group_countx: u32,
+        _group_county: u32,
+        _group_countz: u32,
+    ) {
+    }
     fn cmd_draw_mesh_tasks_indirect_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4727,9 +9541,18 @@ pub trait DeviceHooks: Send + Sync {
         _offset: vk::DeviceSize,
         _draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawMeshTasksIndirectExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_mesh_tasks_indirect_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _draw_count: u32,
+        _stride: u32,
+    ) {
+    }
     fn cmd_draw_mesh_tasks_indirect_count_ext(
         &self,
         _command_buffer: vk::CommandBuffer,
@@ -4739,9 +9562,20 @@ pub trait DeviceHooks: Send + Sync {
         _count_buffer_offset: vk::DeviceSize,
         _max_draw_count: u32,
         _stride: u32,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), CmdDrawMeshTasksIndirectCountExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_cmd_draw_mesh_tasks_indirect_count_ext(
+        &self,
+        _command_buffer: vk::CommandBuffer,
+        _buffer: vk::Buffer,
+        _offset: vk::DeviceSize,
+        _count_buffer: vk::Buffer,
+        _count_buffer_offset: vk::DeviceSize,
+        _max_draw_count: u32,
+        _stride: u32,
+    ) {
+    }
 }
 
 pub trait InstanceHooks: Send + Sync {
@@ -4749,17 +9583,30 @@ pub trait InstanceHooks: Send + Sync {
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_features: &mut MaybeUninit<vk::PhysicalDeviceFeatures>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceFeaturesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_features(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_features: &vk::PhysicalDeviceFeatures,
+    ) {
+    }
     fn get_physical_device_format_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _format: vk::Format,
         _p_format_properties: &mut MaybeUninit<vk::FormatProperties>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceFormatPropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_format_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _format: vk::Format,
+        _p_format_properties: &vk::FormatProperties,
+    ) {
+    }
     fn get_physical_device_image_format_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
@@ -4769,31 +9616,62 @@ pub trait InstanceHooks: Send + Sync {
         _usage: vk::ImageUsageFlags,
         _flags: vk::ImageCreateFlags,
         _p_image_format_properties: &mut MaybeUninit<vk::ImageFormatProperties>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceImageFormatPropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_image_format_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _format: vk::Format,
+        _type: vk::ImageType,
+        _tiling: vk::ImageTiling,
+        _usage: vk::ImageUsageFlags,
+        _flags: vk::ImageCreateFlags,
+        _p_image_format_properties: Option<&vk::ImageFormatProperties>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_properties: &mut MaybeUninit<vk::PhysicalDeviceProperties>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDevicePropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_properties: &vk::PhysicalDeviceProperties,
+    ) {
+    }
     fn get_physical_device_queue_family_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_queue_family_property_count: &mut MaybeUninit<u32>,
         _p_queue_family_properties: Option<&mut [MaybeUninit<vk::QueueFamilyProperties>]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceQueueFamilyPropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_queue_family_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_queue_family_property_count: &u32,
+        _p_queue_family_properties: Option<&[vk::QueueFamilyProperties]>,
+    ) {
+    }
     fn get_physical_device_memory_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_memory_properties: &mut MaybeUninit<vk::PhysicalDeviceMemoryProperties>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceMemoryPropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_memory_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_memory_properties: &vk::PhysicalDeviceMemoryProperties,
+    ) {
+    }
     fn get_instance_proc_addr(&self, _p_name: &str) -> LayerResult<vk::PFN_vkVoidFunction> {
         LayerResult::Unhandled
     }
@@ -4807,6 +9685,16 @@ pub trait InstanceHooks: Send + Sync {
     ) -> LayerResult<VkResult<()>> {
         LayerResult::Unhandled
     }
+    fn after_create_device(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_create_info: &vk::DeviceCreateInfo,
+        _layer_device_link: &VkLayerDeviceLink,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_device: Option<&vk::Device>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_sparse_image_format_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
@@ -4817,183 +9705,365 @@ pub trait InstanceHooks: Send + Sync {
         _tiling: vk::ImageTiling,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::SparseImageFormatProperties>]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceSparseImageFormatPropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_sparse_image_format_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _format: vk::Format,
+        _type: vk::ImageType,
+        _samples: vk::SampleCountFlags,
+        _usage: vk::ImageUsageFlags,
+        _tiling: vk::ImageTiling,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::SparseImageFormatProperties]>,
+    ) {
+    }
     fn get_physical_device_features2(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_features: &mut MaybeUninit<vk::PhysicalDeviceFeatures2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceFeatures2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_features2(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_features: &vk::PhysicalDeviceFeatures2,
+    ) {
+    }
     fn get_physical_device_properties2(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_properties: &mut MaybeUninit<vk::PhysicalDeviceProperties2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceProperties2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_properties2(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_properties: &vk::PhysicalDeviceProperties2,
+    ) {
+    }
     fn get_physical_device_format_properties2(
         &self,
         _physical_device: vk::PhysicalDevice,
         _format: vk::Format,
         _p_format_properties: &mut MaybeUninit<vk::FormatProperties2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceFormatProperties2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_format_properties2(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _format: vk::Format,
+        _p_format_properties: &vk::FormatProperties2,
+    ) {
+    }
     fn get_physical_device_image_format_properties2(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_image_format_info: &vk::PhysicalDeviceImageFormatInfo2,
         _p_image_format_properties: &mut MaybeUninit<vk::ImageFormatProperties2>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceImageFormatProperties2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_image_format_properties2(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_image_format_info: &vk::PhysicalDeviceImageFormatInfo2,
+        _p_image_format_properties: Option<&vk::ImageFormatProperties2>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_queue_family_properties2(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_queue_family_property_count: &mut MaybeUninit<u32>,
         _p_queue_family_properties: Option<&mut [MaybeUninit<vk::QueueFamilyProperties2>]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceQueueFamilyProperties2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_queue_family_properties2(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_queue_family_property_count: &u32,
+        _p_queue_family_properties: Option<&[vk::QueueFamilyProperties2]>,
+    ) {
+    }
     fn get_physical_device_memory_properties2(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_memory_properties: &mut MaybeUninit<vk::PhysicalDeviceMemoryProperties2>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceMemoryProperties2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_memory_properties2(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_memory_properties: &vk::PhysicalDeviceMemoryProperties2,
+    ) {
+    }
     fn get_physical_device_sparse_image_format_properties2(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_format_info: &vk::PhysicalDeviceSparseImageFormatInfo2,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::SparseImageFormatProperties2>]>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceSparseImageFormatProperties2Args> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_sparse_image_format_properties2(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_format_info: &vk::PhysicalDeviceSparseImageFormatInfo2,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::SparseImageFormatProperties2]>,
+    ) {
+    }
     fn get_physical_device_external_buffer_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_external_buffer_info: &vk::PhysicalDeviceExternalBufferInfo,
         _p_external_buffer_properties: &mut MaybeUninit<vk::ExternalBufferProperties>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceExternalBufferPropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_external_buffer_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_external_buffer_info: &vk::PhysicalDeviceExternalBufferInfo,
+        _p_external_buffer_properties: &vk::ExternalBufferProperties,
+    ) {
+    }
     fn get_physical_device_external_fence_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_external_fence_info: &vk::PhysicalDeviceExternalFenceInfo,
         _p_external_fence_properties: &mut MaybeUninit<vk::ExternalFenceProperties>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceExternalFencePropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_external_fence_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_external_fence_info: &vk::PhysicalDeviceExternalFenceInfo,
+        _p_external_fence_properties: &vk::ExternalFenceProperties,
+    ) {
+    }
     fn get_physical_device_external_semaphore_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_external_semaphore_info: &vk::PhysicalDeviceExternalSemaphoreInfo,
         _p_external_semaphore_properties: &mut MaybeUninit<vk::ExternalSemaphoreProperties>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceExternalSemaphorePropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_external_semaphore_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_external_semaphore_info: &vk::PhysicalDeviceExternalSemaphoreInfo,
+        _p_external_semaphore_properties: &vk::ExternalSemaphoreProperties,
+    ) {
+    }
     fn get_physical_device_tool_properties(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_tool_count: &mut MaybeUninit<u32>,
         _p_tool_properties: Option<&mut [MaybeUninit<vk::PhysicalDeviceToolProperties>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceToolPropertiesArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_tool_properties(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_tool_count: &u32,
+        _p_tool_properties: Option<&[vk::PhysicalDeviceToolProperties]>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_surface_khr(
         &self,
         _surface: vk::SurfaceKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroySurfaceKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_surface_khr(
+        &self,
+        _surface: vk::SurfaceKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn get_physical_device_surface_support_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _queue_family_index: u32,
         _surface: vk::SurfaceKHR,
-    ) -> LayerResult<VkResult<bool>> {
+    ) -> LayerResult<VkResult<bool>, GetPhysicalDeviceSurfaceSupportKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_support_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _surface: vk::SurfaceKHR,
+        _p_supported: Option<bool>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_surface_capabilities_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _surface: vk::SurfaceKHR,
         _p_surface_capabilities: &mut MaybeUninit<vk::SurfaceCapabilitiesKHR>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceSurfaceCapabilitiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_capabilities_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _surface: vk::SurfaceKHR,
+        _p_surface_capabilities: Option<&vk::SurfaceCapabilitiesKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_surface_formats_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _surface: vk::SurfaceKHR,
         _p_surface_format_count: &mut MaybeUninit<u32>,
         _p_surface_formats: Option<&mut [MaybeUninit<vk::SurfaceFormatKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceSurfaceFormatsKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_formats_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _surface: vk::SurfaceKHR,
+        _p_surface_format_count: &u32,
+        _p_surface_formats: Option<&[vk::SurfaceFormatKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_surface_present_modes_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _surface: vk::SurfaceKHR,
-    ) -> LayerResult<VkResult<Vec<vk::PresentModeKHR>>> {
+    ) -> LayerResult<VkResult<Vec<vk::PresentModeKHR>>, GetPhysicalDeviceSurfacePresentModesKhrArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_present_modes_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _surface: vk::SurfaceKHR,
+        _p_present_modes: Option<&[vk::PresentModeKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_present_rectangles_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _surface: vk::SurfaceKHR,
         _p_rect_count: &mut MaybeUninit<u32>,
         _p_rects: Option<&mut [MaybeUninit<vk::Rect2D>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDevicePresentRectanglesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_present_rectangles_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _surface: vk::SurfaceKHR,
+        _p_rect_count: &u32,
+        _p_rects: Option<&[vk::Rect2D]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_display_properties_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::DisplayPropertiesKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceDisplayPropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_display_properties_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::DisplayPropertiesKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_display_plane_properties_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::DisplayPlanePropertiesKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceDisplayPlanePropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_display_plane_properties_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::DisplayPlanePropertiesKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_display_plane_supported_displays_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _plane_index: u32,
-    ) -> LayerResult<VkResult<Vec<vk::DisplayKHR>>> {
+    ) -> LayerResult<VkResult<Vec<vk::DisplayKHR>>, GetDisplayPlaneSupportedDisplaysKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_display_plane_supported_displays_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _plane_index: u32,
+        _p_displays: Option<&[vk::DisplayKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_display_mode_properties_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _display: vk::DisplayKHR,
-        _p_property_count: &mut MaybeUninit<u32>,
-        _p_properties: Option<&mut [MaybeUninit<vk::DisplayModePropertiesKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+        _p_property_count: &mut MaybeUninit<u32>,
+        _p_properties: Option<&mut [MaybeUninit<vk::DisplayModePropertiesKHR>]>,
+    ) -> LayerResult<VkResult<()>, GetDisplayModePropertiesKhrArgs> {
+        LayerResult::Unhandled
+    }
+    fn after_get_display_mode_properties_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _display: vk::DisplayKHR,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::DisplayModePropertiesKHR]>,
+        _result: vk::Result,
+    ) {
+    }
+    fn create_display_mode_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _display: vk::DisplayKHR,
+        _p_create_info: &vk::DisplayModeCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) -> LayerResult<VkResult<vk::DisplayModeKHR>, CreateDisplayModeKhrArgs> {
         LayerResult::Unhandled
     }
-    fn create_display_mode_khr(
+    fn after_create_display_mode_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _display: vk::DisplayKHR,
         _p_create_info: &vk::DisplayModeCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DisplayModeKHR>> {
-        LayerResult::Unhandled
+        _p_mode: Option<vk::DisplayModeKHR>,
+        _result: vk::Result,
+    ) {
     }
     fn get_display_plane_capabilities_khr(
         &self,
@@ -5001,101 +10071,208 @@ pub trait InstanceHooks: Send + Sync {
         _mode: vk::DisplayModeKHR,
         _plane_index: u32,
         _p_capabilities: &mut MaybeUninit<vk::DisplayPlaneCapabilitiesKHR>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetDisplayPlaneCapabilitiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_display_plane_capabilities_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _mode: vk::DisplayModeKHR,
+        _plane_index: u32,
+        _p_capabilities: Option<&vk::DisplayPlaneCapabilitiesKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_display_plane_surface_khr(
         &self,
         _p_create_info: &vk::DisplaySurfaceCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateDisplayPlaneSurfaceKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_display_plane_surface_khr(
+        &self,
+        _p_create_info: &vk::DisplaySurfaceCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_xlib_surface_khr(
         &self,
         _p_create_info: &vk::XlibSurfaceCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateXlibSurfaceKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_xlib_surface_khr(
+        &self,
+        _p_create_info: &vk::XlibSurfaceCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_xlib_presentation_support_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _queue_family_index: u32,
         _dpy: &mut MaybeUninit<vk::Display>,
         _visual_id: vk::VisualID,
-    ) -> LayerResult<bool> {
+    ) -> LayerResult<bool, GetPhysicalDeviceXlibPresentationSupportKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_xlib_presentation_support_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _dpy: &vk::Display,
+        _visual_id: vk::VisualID,
+        _result: bool,
+    ) {
+    }
     fn create_xcb_surface_khr(
         &self,
         _p_create_info: &vk::XcbSurfaceCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateXcbSurfaceKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_xcb_surface_khr(
+        &self,
+        _p_create_info: &vk::XcbSurfaceCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_xcb_presentation_support_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _queue_family_index: u32,
         _connection: *mut vk::xcb_connection_t,
         _visual_id: vk::xcb_visualid_t,
-    ) -> LayerResult<bool> {
+    ) -> LayerResult<bool, GetPhysicalDeviceXcbPresentationSupportKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_xcb_presentation_support_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _connection: *mut vk::xcb_connection_t,
+        _visual_id: vk::xcb_visualid_t,
+        _result: bool,
+    ) {
+    }
     fn create_wayland_surface_khr(
         &self,
         _p_create_info: &vk::WaylandSurfaceCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateWaylandSurfaceKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_wayland_surface_khr(
+        &self,
+        _p_create_info: &vk::WaylandSurfaceCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_wayland_presentation_support_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _queue_family_index: u32,
         _display: *mut vk::wl_display,
-    ) -> LayerResult<bool> {
+    ) -> LayerResult<bool, GetPhysicalDeviceWaylandPresentationSupportKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_wayland_presentation_support_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _display: *mut vk::wl_display,
+        _result: bool,
+    ) {
+    }
     fn create_android_surface_khr(
         &self,
         _p_create_info: &vk::AndroidSurfaceCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateAndroidSurfaceKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_android_surface_khr(
+        &self,
+        _p_create_info: &vk::AndroidSurfaceCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_win32_surface_khr(
         &self,
         _p_create_info: &vk::Win32SurfaceCreateInfoKHR,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateWin32SurfaceKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_win32_surface_khr(
+        &self,
+        _p_create_info: &vk::Win32SurfaceCreateInfoKHR,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_win32_presentation_support_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _queue_family_index: u32,
-    ) -> LayerResult<bool> {
+    ) -> LayerResult<bool, GetPhysicalDeviceWin32PresentationSupportKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_win32_presentation_support_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _result: bool,
+    ) {
+    }
     fn get_physical_device_video_capabilities_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_video_profile: &vk::VideoProfileInfoKHR,
         _p_capabilities: &mut MaybeUninit<vk::VideoCapabilitiesKHR>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceVideoCapabilitiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_video_capabilities_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_video_profile: &vk::VideoProfileInfoKHR,
+        _p_capabilities: Option<&vk::VideoCapabilitiesKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_video_format_properties_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_video_format_info: &vk::PhysicalDeviceVideoFormatInfoKHR,
         _p_video_format_property_count: &mut MaybeUninit<u32>,
         _p_video_format_properties: Option<&mut [MaybeUninit<vk::VideoFormatPropertiesKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceVideoFormatPropertiesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_video_format_properties_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_video_format_info: &vk::PhysicalDeviceVideoFormatInfoKHR,
+        _p_video_format_property_count: &u32,
+        _p_video_format_properties: Option<&[vk::VideoFormatPropertiesKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn enumerate_physical_device_queue_family_performance_query_counters_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
@@ -5103,66 +10280,134 @@ pub trait InstanceHooks: Send + Sync {
         _p_counter_count: &mut MaybeUninit<u32>,
         _p_counters: Option<&mut [MaybeUninit<vk::PerformanceCounterKHR>]>,
         _p_counter_descriptions: Option<&mut [MaybeUninit<vk::PerformanceCounterDescriptionKHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKhrArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_enumerate_physical_device_queue_family_performance_query_counters_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _p_counter_count: &u32,
+        _p_counters: Option<&[vk::PerformanceCounterKHR]>,
+        _p_counter_descriptions: Option<&[vk::PerformanceCounterDescriptionKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_queue_family_performance_query_passes_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_performance_query_create_info: &vk::QueryPoolPerformanceCreateInfoKHR,
-    ) -> LayerResult<u32> {
+    ) -> LayerResult<u32, GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_queue_family_performance_query_passes_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_performance_query_create_info: &vk::QueryPoolPerformanceCreateInfoKHR,
+        _result: u32,
+    ) {
+    }
     fn get_physical_device_surface_capabilities2_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
         _p_surface_capabilities: &mut MaybeUninit<vk::SurfaceCapabilities2KHR>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceSurfaceCapabilities2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_capabilities2_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
+        _p_surface_capabilities: Option<&vk::SurfaceCapabilities2KHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_surface_formats2_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
         _p_surface_format_count: &mut MaybeUninit<u32>,
         _p_surface_formats: Option<&mut [MaybeUninit<vk::SurfaceFormat2KHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceSurfaceFormats2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_formats2_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
+        _p_surface_format_count: &u32,
+        _p_surface_formats: Option<&[vk::SurfaceFormat2KHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_display_properties2_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::DisplayProperties2KHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceDisplayProperties2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_display_properties2_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::DisplayProperties2KHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_display_plane_properties2_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::DisplayPlaneProperties2KHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceDisplayPlaneProperties2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_display_plane_properties2_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::DisplayPlaneProperties2KHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_display_mode_properties2_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _display: vk::DisplayKHR,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::DisplayModeProperties2KHR>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetDisplayModeProperties2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_display_mode_properties2_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _display: vk::DisplayKHR,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::DisplayModeProperties2KHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_display_plane_capabilities2_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_display_plane_info: &vk::DisplayPlaneInfo2KHR,
         _p_capabilities: &mut MaybeUninit<vk::DisplayPlaneCapabilities2KHR>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetDisplayPlaneCapabilities2KhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_display_plane_capabilities2_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_display_plane_info: &vk::DisplayPlaneInfo2KHR,
+        _p_capabilities: Option<&vk::DisplayPlaneCapabilities2KHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_fragment_shading_rates_khr(
         &self,
         _physical_device: vk::PhysicalDevice,
@@ -5170,23 +10415,45 @@ pub trait InstanceHooks: Send + Sync {
         _p_fragment_shading_rates: Option<
             &mut [MaybeUninit<vk::PhysicalDeviceFragmentShadingRateKHR>],
         >,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceFragmentShadingRatesKhrArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_fragment_shading_rates_khr(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_fragment_shading_rate_count: &u32,
+        _p_fragment_shading_rates: Option<&[vk::PhysicalDeviceFragmentShadingRateKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_debug_report_callback_ext(
         &self,
         _p_create_info: &vk::DebugReportCallbackCreateInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DebugReportCallbackEXT>> {
+    ) -> LayerResult<VkResult<vk::DebugReportCallbackEXT>, CreateDebugReportCallbackExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_debug_report_callback_ext(
+        &self,
+        _p_create_info: &vk::DebugReportCallbackCreateInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_callback: Option<vk::DebugReportCallbackEXT>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_debug_report_callback_ext(
         &self,
         _callback: vk::DebugReportCallbackEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyDebugReportCallbackExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_debug_report_callback_ext(
+        &self,
+        _callback: vk::DebugReportCallbackEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn debug_report_message_ext(
         &self,
         _flags: vk::DebugReportFlagsEXT,
@@ -5196,16 +10463,35 @@ pub trait InstanceHooks: Send + Sync {
         _message_code: i32,
         _p_layer_prefix: &str,
         _p_message: &str,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DebugReportMessageExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_debug_report_message_ext(
+        &self,
+        _flags: vk::DebugReportFlagsEXT,
+        _object_type: vk::DebugReportObjectTypeEXT,
+        _object: u64,
+        _location: usize,
+        _message_code: i32,
+        _p_layer_prefix: &str,
+        _p_message: &str,
+    ) {
+    }
     fn create_stream_descriptor_surface_ggp(
         &self,
         _p_create_info: &vk::StreamDescriptorSurfaceCreateInfoGGP,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateStreamDescriptorSurfaceGgpArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_stream_descriptor_surface_ggp(
+        &self,
+        _p_create_info: &vk::StreamDescriptorSurfaceCreateInfoGGP,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_external_image_format_properties_nv(
         &self,
         _physical_device: vk::PhysicalDevice,
@@ -5216,201 +10502,424 @@ pub trait InstanceHooks: Send + Sync {
         _flags: vk::ImageCreateFlags,
         _external_handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
         _p_external_image_format_properties: &mut MaybeUninit<vk::ExternalImageFormatPropertiesNV>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceExternalImageFormatPropertiesNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_external_image_format_properties_nv(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _format: vk::Format,
+        _type: vk::ImageType,
+        _tiling: vk::ImageTiling,
+        _usage: vk::ImageUsageFlags,
+        _flags: vk::ImageCreateFlags,
+        _external_handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+        _p_external_image_format_properties: Option<&vk::ExternalImageFormatPropertiesNV>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_vi_surface_nn(
         &self,
         _p_create_info: &vk::ViSurfaceCreateInfoNN,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateViSurfaceNnArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_vi_surface_nn(
+        &self,
+        _p_create_info: &vk::ViSurfaceCreateInfoNN,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn release_display_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _display: vk::DisplayKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, ReleaseDisplayExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_release_display_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _display: vk::DisplayKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_xlib_display_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _dpy: &mut MaybeUninit<vk::Display>,
         _display: vk::DisplayKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, AcquireXlibDisplayExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_acquire_xlib_display_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _dpy: Option<&vk::Display>,
+        _display: vk::DisplayKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_rand_r_output_display_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _dpy: &mut MaybeUninit<vk::Display>,
         _rr_output: vk::RROutput,
-    ) -> LayerResult<VkResult<vk::DisplayKHR>> {
+    ) -> LayerResult<VkResult<vk::DisplayKHR>, GetRandROutputDisplayExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_rand_r_output_display_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _dpy: Option<&vk::Display>,
+        _rr_output: vk::RROutput,
+        _p_display: Option<vk::DisplayKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_surface_capabilities2_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _surface: vk::SurfaceKHR,
         _p_surface_capabilities: &mut MaybeUninit<vk::SurfaceCapabilities2EXT>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceSurfaceCapabilities2ExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_capabilities2_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _surface: vk::SurfaceKHR,
+        _p_surface_capabilities: Option<&vk::SurfaceCapabilities2EXT>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_ios_surface_mvk(
         &self,
         _p_create_info: &vk::IOSSurfaceCreateInfoMVK,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateIosSurfaceMvkArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_ios_surface_mvk(
+        &self,
+        _p_create_info: &vk::IOSSurfaceCreateInfoMVK,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_mac_os_surface_mvk(
         &self,
         _p_create_info: &vk::MacOSSurfaceCreateInfoMVK,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateMacOsSurfaceMvkArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_mac_os_surface_mvk(
+        &self,
+        _p_create_info: &vk::MacOSSurfaceCreateInfoMVK,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_debug_utils_messenger_ext(
         &self,
         _p_create_info: &vk::DebugUtilsMessengerCreateInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::DebugUtilsMessengerEXT>> {
+    ) -> LayerResult<VkResult<vk::DebugUtilsMessengerEXT>, CreateDebugUtilsMessengerExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_debug_utils_messenger_ext(
+        &self,
+        _p_create_info: &vk::DebugUtilsMessengerCreateInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_messenger: Option<vk::DebugUtilsMessengerEXT>,
+        _result: vk::Result,
+    ) {
+    }
     fn destroy_debug_utils_messenger_ext(
         &self,
         _messenger: vk::DebugUtilsMessengerEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), DestroyDebugUtilsMessengerExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_destroy_debug_utils_messenger_ext(
+        &self,
+        _messenger: vk::DebugUtilsMessengerEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+    }
     fn submit_debug_utils_message_ext(
         &self,
         _message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
         _message_types: vk::DebugUtilsMessageTypeFlagsEXT,
         _p_callback_data: &vk::DebugUtilsMessengerCallbackDataEXT,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), SubmitDebugUtilsMessageExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_submit_debug_utils_message_ext(
+        &self,
+        _message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        _message_types: vk::DebugUtilsMessageTypeFlagsEXT,
+        _p_callback_data: &vk::DebugUtilsMessengerCallbackDataEXT,
+    ) {
+    }
     fn get_physical_device_multisample_properties_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _samples: vk::SampleCountFlags,
         _p_multisample_properties: &mut MaybeUninit<vk::MultisamplePropertiesEXT>,
-    ) -> LayerResult<()> {
+    ) -> LayerResult<(), GetPhysicalDeviceMultisamplePropertiesExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_multisample_properties_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _samples: vk::SampleCountFlags,
+        _p_multisample_properties: &vk::MultisamplePropertiesEXT,
+    ) {
+    }
     fn get_physical_device_calibrateable_time_domains_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
-    ) -> LayerResult<VkResult<Vec<vk::TimeDomainEXT>>> {
+    ) -> LayerResult<
+        VkResult<Vec<vk::TimeDomainEXT>>,
+        GetPhysicalDeviceCalibrateableTimeDomainsExtArgs,
+    > {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_calibrateable_time_domains_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_time_domains: Option<&[vk::TimeDomainEXT]>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_image_pipe_surface_fuchsia(
         &self,
         _p_create_info: &vk::ImagePipeSurfaceCreateInfoFUCHSIA,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateImagePipeSurfaceFuchsiaArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_image_pipe_surface_fuchsia(
+        &self,
+        _p_create_info: &vk::ImagePipeSurfaceCreateInfoFUCHSIA,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_metal_surface_ext(
         &self,
         _p_create_info: &vk::MetalSurfaceCreateInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateMetalSurfaceExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_metal_surface_ext(
+        &self,
+        _p_create_info: &vk::MetalSurfaceCreateInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_cooperative_matrix_properties_nv(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_property_count: &mut MaybeUninit<u32>,
         _p_properties: Option<&mut [MaybeUninit<vk::CooperativeMatrixPropertiesNV>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceCooperativeMatrixPropertiesNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_cooperative_matrix_properties_nv(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_property_count: &u32,
+        _p_properties: Option<&[vk::CooperativeMatrixPropertiesNV]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_combination_count: &mut MaybeUninit<u32>,
         _p_combinations: Option<&mut [MaybeUninit<vk::FramebufferMixedSamplesCombinationNV>]>,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<
+        VkResult<()>,
+        GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNvArgs,
+    > {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_combination_count: &u32,
+        _p_combinations: Option<&[vk::FramebufferMixedSamplesCombinationNV]>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_surface_present_modes2_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
-    ) -> LayerResult<VkResult<Vec<vk::PresentModeKHR>>> {
+    ) -> LayerResult<VkResult<Vec<vk::PresentModeKHR>>, GetPhysicalDeviceSurfacePresentModes2ExtArgs>
+    {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_surface_present_modes2_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_surface_info: &vk::PhysicalDeviceSurfaceInfo2KHR,
+        _p_present_modes: Option<&[vk::PresentModeKHR]>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_headless_surface_ext(
         &self,
         _p_create_info: &vk::HeadlessSurfaceCreateInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateHeadlessSurfaceExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_headless_surface_ext(
+        &self,
+        _p_create_info: &vk::HeadlessSurfaceCreateInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_drm_display_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _drm_fd: i32,
         _display: vk::DisplayKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, AcquireDrmDisplayExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_acquire_drm_display_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _drm_fd: i32,
+        _display: vk::DisplayKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_drm_display_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _drm_fd: i32,
         _connector_id: u32,
-    ) -> LayerResult<VkResult<vk::DisplayKHR>> {
+    ) -> LayerResult<VkResult<vk::DisplayKHR>, GetDrmDisplayExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_drm_display_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _drm_fd: i32,
+        _connector_id: u32,
+        _display: Option<vk::DisplayKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn acquire_winrt_display_nv(
         &self,
         _physical_device: vk::PhysicalDevice,
         _display: vk::DisplayKHR,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, AcquireWinrtDisplayNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_acquire_winrt_display_nv(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _display: vk::DisplayKHR,
+        _result: vk::Result,
+    ) {
+    }
     fn get_winrt_display_nv(
         &self,
         _physical_device: vk::PhysicalDevice,
         _device_relative_id: u32,
-    ) -> LayerResult<VkResult<vk::DisplayKHR>> {
+    ) -> LayerResult<VkResult<vk::DisplayKHR>, GetWinrtDisplayNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_winrt_display_nv(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _device_relative_id: u32,
+        _p_display: Option<vk::DisplayKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn create_direct_fb_surface_ext(
         &self,
         _p_create_info: &vk::DirectFBSurfaceCreateInfoEXT,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateDirectFbSurfaceExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_direct_fb_surface_ext(
+        &self,
+        _p_create_info: &vk::DirectFBSurfaceCreateInfoEXT,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_direct_fb_presentation_support_ext(
         &self,
         _physical_device: vk::PhysicalDevice,
         _queue_family_index: u32,
         _dfb: &mut MaybeUninit<vk::IDirectFB>,
-    ) -> LayerResult<bool> {
+    ) -> LayerResult<bool, GetPhysicalDeviceDirectFbPresentationSupportExtArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_direct_fb_presentation_support_ext(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _dfb: &vk::IDirectFB,
+        _result: bool,
+    ) {
+    }
     fn create_screen_surface_qnx(
         &self,
         _p_create_info: &vk::ScreenSurfaceCreateInfoQNX,
         _p_allocator: Option<&vk::AllocationCallbacks>,
-    ) -> LayerResult<VkResult<vk::SurfaceKHR>> {
+    ) -> LayerResult<VkResult<vk::SurfaceKHR>, CreateScreenSurfaceQnxArgs> {
         LayerResult::Unhandled
     }
+    fn after_create_screen_surface_qnx(
+        &self,
+        _p_create_info: &vk::ScreenSurfaceCreateInfoQNX,
+        _p_allocator: Option<&vk::AllocationCallbacks>,
+        _p_surface: Option<vk::SurfaceKHR>,
+        _result: vk::Result,
+    ) {
+    }
     fn get_physical_device_screen_presentation_support_qnx(
         &self,
         _physical_device: vk::PhysicalDevice,
         _queue_family_index: u32,
         _window: *mut vk::_screen_window,
-    ) -> LayerResult<bool> {
+    ) -> LayerResult<bool, GetPhysicalDeviceScreenPresentationSupportQnxArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_screen_presentation_support_qnx(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _window: *mut vk::_screen_window,
+        _result: bool,
+    ) {
+    }
     fn get_physical_device_optical_flow_image_formats_nv(
         &self,
         _physical_device: vk::PhysicalDevice,
@@ -5419,7 +10928,16 @@ pub trait InstanceHooks: Send + Sync {
         _p_image_format_properties: Option<
             &mut [MaybeUninit<vk::OpticalFlowImageFormatPropertiesNV>],
         >,
-    ) -> LayerResult<VkResult<()>> {
+    ) -> LayerResult<VkResult<()>, GetPhysicalDeviceOpticalFlowImageFormatsNvArgs> {
         LayerResult::Unhandled
     }
+    fn after_get_physical_device_optical_flow_image_formats_nv(
+        &self,
+        _physical_device: vk::PhysicalDevice,
+        _p_optical_flow_image_format_info: &vk::OpticalFlowImageFormatInfoNV,
+        _p_format_count: &u32,
+        _p_image_format_properties: Option<&[vk::OpticalFlowImageFormatPropertiesNV]>,
+        _result: vk::Result,
+    ) {
+    }
 }
@@ -93,7 +93,7 @@ impl<T: CheckEmpty> LazyCollection<T> {
     /// // vec1 and vec2 point to the same location.
     /// assert!(std::ptr::eq(&*vec1, &*vec2));
     /// ```
-    pub fn get(&self) -> Cow<T> {
+    pub fn get(&self) -> Cow<'_, T> {
         // The destructor for None is a no-op, while this is not guaranteed for an empty T.
         // Therefore, we can't use &T as the return type and return a reference to a static empty T
         // when the underlying collection is empty.
@@ -60,8 +60,8 @@
 //! use once_cell::sync::Lazy;
 //! use std::sync::Arc;
 //! use vulkan_layer::{
-//!     declare_introspection_queries, Global, Layer, LayerManifest, StubDeviceInfo,
-//!     StubGlobalHooks, StubInstanceInfo,
+//!     declare_introspection_queries, DeviceLoaderDataCallback, Global, Layer, LayerManifest,
+//!     LayerSettings, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
 //! };
 //!
 //! // Define the layer type.
@@ -75,6 +75,9 @@
 //!     type DeviceInfo = StubDeviceInfo;
 //!     type InstanceInfoContainer = StubInstanceInfo;
 //!     type DeviceInfoContainer = StubDeviceInfo;
+//!     type PhysicalDeviceInfo = ();
+//!     type QueueInfo = ();
+//!     type CommandBufferInfo = ();
 //!
 //!     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
 //!         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -95,6 +98,7 @@
 //!         _: Option<&vk::AllocationCallbacks>,
 //!         _: Arc<ash::Instance>,
 //!         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+//!         _: &LayerSettings,
 //!     ) -> Self::InstanceInfoContainer {
 //!         Default::default()
 //!     }
@@ -106,6 +110,7 @@
 //!         _: Option<&vk::AllocationCallbacks>,
 //!         _: Arc<ash::Device>,
 //!         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+//!         _: DeviceLoaderDataCallback,
 //!     ) -> Self::DeviceInfoContainer {
 //!         Default::default()
 //!     }
@@ -181,8 +186,8 @@
 //! use once_cell::sync::Lazy;
 //! use std::sync::Arc;
 //! use vulkan_layer::{
-//!     declare_introspection_queries, Global, Layer, LayerManifest, StubDeviceInfo,
-//!     StubGlobalHooks, StubInstanceInfo,
+//!     declare_introspection_queries, DeviceLoaderDataCallback, Global, Layer, LayerManifest,
+//!     LayerSettings, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
 //! };
 //!
 //! #[derive(Default)]
@@ -194,6 +199,9 @@
 //!     type DeviceInfo = StubDeviceInfo;
 //!     type InstanceInfoContainer = StubInstanceInfo;
 //!     type DeviceInfoContainer = StubDeviceInfo;
+//!     type PhysicalDeviceInfo = ();
+//!     type QueueInfo = ();
+//!     type CommandBufferInfo = ();
 //!
 //!     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
 //!         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -218,6 +226,7 @@
 //!         _: Option<&vk::AllocationCallbacks>,
 //!         _: Arc<ash::Instance>,
 //!         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+//!         _: &LayerSettings,
 //!     ) -> Self::InstanceInfoContainer {
 //!         Default::default()
 //!     }
@@ -229,6 +238,7 @@
 //!         _: Option<&vk::AllocationCallbacks>,
 //!         _: Arc<ash::Device>,
 //!         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+//!         _: DeviceLoaderDataCallback,
 //!     ) -> Self::DeviceInfoContainer {
 //!         println!("Hello from the Rust Vulkan layer!");
 //!         Default::default()
@@ -242,8 +252,8 @@
 //! # use once_cell::sync::Lazy;
 //! # use std::sync::Arc;
 //! # use vulkan_layer::{
-//! #     declare_introspection_queries, Global, Layer, LayerManifest, StubDeviceInfo,
-//! #     StubGlobalHooks, StubInstanceInfo,
+//! #     declare_introspection_queries, DeviceLoaderDataCallback, Global, Layer, LayerManifest,
+//! #     LayerSettings, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
 //! # };
 //! #
 //! # #[derive(Default)]
@@ -255,6 +265,9 @@
 //! #     type DeviceInfo = StubDeviceInfo;
 //! #     type InstanceInfoContainer = StubInstanceInfo;
 //! #     type DeviceInfoContainer = StubDeviceInfo;
+//! #     type PhysicalDeviceInfo = ();
+//! #     type QueueInfo = ();
+//! #     type CommandBufferInfo = ();
 //! #
 //! #     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
 //! #         static GLOBAL: Lazy<Global<MyLayer>> = Lazy::new(Default::default);
@@ -279,6 +292,7 @@
 //! #         _: Option<&vk::AllocationCallbacks>,
 //! #         _: Arc<ash::Instance>,
 //! #         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+//! #         _: &LayerSettings,
 //! #     ) -> Self::InstanceInfoContainer {
 //! #         Default::default()
 //! #     }
@@ -290,6 +304,7 @@
 //! #         _: Option<&vk::AllocationCallbacks>,
 //! #         _: Arc<ash::Device>,
 //! #         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+//! #         _: DeviceLoaderDataCallback,
 //! #     ) -> Self::DeviceInfoContainer {
 //! #         println!("Hello from the Rust Vulkan layer!");
 //! #         Default::default()
@@ -389,7 +404,10 @@
 //!     }
 //! }
 //! ```
-//! This json file will define an explicit layer named `VK_LAYER_VENDOR_rust_example`.
+//! This json file will define an explicit layer named `VK_LAYER_VENDOR_rust_example`. Instead of
+//! writing the file by hand, [`LayerManifest::to_json`] or [`write_layer_manifest_json`] can
+//! generate it from the same [`LayerManifest`] returned by [`Layer::manifest`], e.g. in the build
+//! script.
 //!
 //! Eighth, use [`VkConfig`](https://github.com/LunarG/VulkanTools/blob/main/vkconfig/README.md)
 //! (i.e. Vulkan Configurator) to force enable this explicit layer, and launch the vkcube
@@ -403,6 +421,22 @@
 //!
 //! See [the][Layer#initialization] [document][Layer#global-clean-up] of [`Layer`] for details.
 //!
+//! # Tracing
+//!
+//! With the `tracing` cargo feature, every intercepted Vulkan command that goes through the
+//! generated trampolines emits a [`tracing`](https://crates.io/crates/tracing) span at the `TRACE`
+//! level. The span is named after the command, e.g. `vkCreateImage`, covers the hooks and the call
+//! to the next layer, and carries the following fields:
+//!
+//! * `handle`: the dispatchable handle the command is called with.
+//! * `handled`: whether the layer returned [`LayerResult::Handled`] instead of calling into the
+//!   next layer.
+//! * `result`: the value returned to the caller, if the command returns anything.
+//!
+//! The commands implemented by [`Global`] itself, e.g. `vkCreateInstance`, `vkDestroyDevice`,
+//! `vkEnumeratePhysicalDevices` and `vkGetDeviceProcAddr`, don't emit spans. Without the feature,
+//! the spans are compiled out.
+//!
 //! # Extensions
 //!
 //! TODO
@@ -419,135 +453,370 @@
 //!
 //! TODO
 
-use ash::vk::{self, Handle};
+use ash::{
+    prelude::VkResult,
+    vk::{self, Handle},
+};
 use bytemuck::cast_slice;
-use log::{error, warn};
+use log::{error, info, warn};
 use std::{
     borrow::Borrow,
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeSet, HashMap},
     ffi::{c_char, c_void, CStr, CString},
     ptr::{null, null_mut, NonNull},
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, MutexGuard},
 };
 extern crate self as vulkan_layer;
 
+mod api_dump;
 mod bindings;
+mod command_buffer_state;
+mod command_ir;
+mod command_observer;
+mod dispatch_map;
+mod dispatchable_object;
+mod fault_injection;
 mod global_simple_intercept;
+mod layer_manifest;
+mod layer_settings;
 mod layer_trait;
+// LazyCollection is only exposed in the unstable API.
+#[cfg_attr(not(feature = "unstable"), allow(dead_code))]
 mod lazy_collection;
+mod stats;
 #[cfg(any(feature = "_test", test))]
 pub mod test_utils;
+mod thread_safety;
 
 #[cfg(feature = "unstable")]
 pub mod unstable_api;
+// Only ApiVersion and IsCommandEnabled are used internally, the other utilities are only exposed
+// in the unstable API.
 #[cfg(not(feature = "unstable"))]
+#[allow(unused_imports)]
 mod unstable_api;
 mod vk_utils;
 
-use bindings::vk_layer::{VkLayerDeviceCreateInfo, VkLayerFunction, VkLayerInstanceCreateInfo};
-pub use bindings::vk_layer::{VkLayerDeviceLink, VkLayerInstanceLink};
-pub use global_simple_intercept::Extension;
-use global_simple_intercept::{DeviceDispatchTable, InstanceDispatchTable, VulkanCommand};
+pub use api_dump::{ApiDump, ApiDumpFormat};
+use bindings::vk_layer::{
+    PFN_GetPhysicalDeviceProcAddr, VkLayerDeviceCreateInfo, VkLayerFunction,
+    VkLayerInstanceCreateInfo,
+};
+pub use bindings::vk_layer::{
+    VkChainHeader, VkChainType, VkEnumerateInstanceExtensionPropertiesChain,
+    VkEnumerateInstanceLayerPropertiesChain, VkEnumerateInstanceVersionChain, VkLayerDeviceLink,
+    VkLayerInstanceLink, VkNegotiateLayerInterface, VkNegotiateLayerStructType,
+    VK_CURRENT_CHAIN_VERSION,
+};
+pub use command_buffer_state::{
+    BoundDescriptorSet, CommandBufferState, DepthBias, DynamicState, IndexBufferBinding,
+    RenderScope, StencilFaceValues, StencilOps, VertexBufferBinding,
+};
+pub use command_ir::{DeepCopied, DeepCopy, DeepCopyStorage, RecordedCommand, ReplayError};
+pub use command_observer::{CommandObserver, DispatchableHandle, ObservedCommands};
+use dispatch_map::DispatchMap;
+use dispatchable_object::{DeviceDispatchKey, DispatchableObject, InstanceDispatchKey};
+pub use fault_injection::{FaultInjector, FaultRule, FaultRuleParseError, FaultTrigger};
+pub use global_simple_intercept::{
+    command_args, DeviceDispatchTable, Extension, InstanceDispatchTable,
+};
+use global_simple_intercept::{
+    maybe_uninit_slice_from_raw_parts_mut, HookedCommands, VulkanCommand,
+    DEVICE_COMMAND_LOOKUP_TABLE, INSTANCE_COMMAND_LOOKUP_TABLE,
+};
+pub use layer_manifest::write_layer_manifest_json;
+pub use layer_settings::{
+    layer_settings_prefix, LayerSettingEXT, LayerSettingKind, LayerSettingMetadata,
+    LayerSettingType, LayerSettingTypeEXT, LayerSettingValue, LayerSettings,
+    LayerSettingsCreateInfoEXT, LayerSettingsSchema, EXT_LAYER_SETTINGS_NAME,
+    EXT_LAYER_SETTINGS_SPEC_VERSION, LAYER_SETTINGS_FILE_NAME, LAYER_SETTINGS_PATH_ENV,
+    STRUCTURE_TYPE_LAYER_SETTINGS_CREATE_INFO_EXT,
+};
 pub use layer_trait::{
-    DeviceHooks, DeviceInfo, ExtensionProperties, GlobalHooks, GlobalHooksInfo, InstanceHooks,
-    InstanceInfo, Layer, LayerManifest, LayerResult, VulkanCommand as LayerVulkanCommand,
+    DeviceHooks, DeviceInfo, DeviceLoaderDataCallback, ExtensionProperties, GlobalHooks,
+    GlobalHooksInfo, InstanceHooks, InstanceInfo, InstanceLoaderDataCallback, Layer, LayerManifest,
+    LayerResult, VulkanCommand as LayerVulkanCommand,
+};
+use stats::StatsCollector;
+pub use stats::{CommandStats, CommandStatsMap, LatencyHistogram, StatsSnapshot};
+pub use thread_safety::{
+    ExternSync, ExternSyncObject, ExternSyncParam, ObjectAccess, ThreadSafetyChecker,
+    ThreadSafetyViolation,
 };
-use unstable_api::{ApiVersion, IsCommandEnabled, LazyCollection};
+use unstable_api::{ApiVersion, IsCommandEnabled};
 pub use vk_utils::{fill_vk_out_array, VulkanBaseInStructChain, VulkanBaseOutStructChain};
 use vk_utils::{ptr_as_uninit_mut, slice_from_raw_parts, slice_to_owned_strings};
 pub use vulkan_layer_macros::{
     auto_deviceinfo_impl, auto_globalhooksinfo_impl, auto_instanceinfo_impl,
-    declare_introspection_queries,
+    declare_introspection_queries, LayerSettings,
 };
 
-trait DispatchableObject: vk::Handle + Copy {
-    type DispatchKey: From<usize>;
+struct InstanceInfoWrapper<T: Layer> {
+    get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    get_physical_device_proc_addr: PFN_GetPhysicalDeviceProcAddr,
+    dispatch_table: Arc<InstanceDispatchTable>,
+    api_version: ApiVersion,
+    enabled_extensions: BTreeSet<Extension>,
+    // hooked_instance_commands and hooked_device_commands are recalculated on every
+    // vkCreateInstance, so that the layer can decide which commands to intercept dynamically.
+    hooked_instance_commands: HookedCommands,
+    hooked_device_commands: HookedCommands,
+    is_create_device_hooked: bool,
+    loader_data_callback: InstanceLoaderDataCallback,
+    // None if the statistics are not enabled.
+    stats: Option<StatsCollector>,
+    customized_info: T::InstanceInfoContainer,
+}
+
+struct PhysicalDeviceInfoWrapper<T: Layer> {
+    physical_device: vk::PhysicalDevice,
+    owner_instance: vk::Instance,
+    properties: vk::PhysicalDeviceProperties,
+    customized_info: T::PhysicalDeviceInfo,
+}
 
-    fn get_dispatch_key(&self) -> Self::DispatchKey {
-        assert_eq!(
-            std::mem::size_of::<Self>(),
-            std::mem::size_of::<*const *const c_void>()
-        );
-        // Safe, because all dispatchable objects can be cast to void **. See details at
-        // https://github.com/KhronosGroup/Vulkan-Loader/blob/35b005a5792f6e4c2931d62a37324923f1a71c79/docs/LoaderDriverInterface.md#driver-dispatchable-object-creation.
-        let key = unsafe {
-            // We use transmute instead of Handle::as_raw here to avoid integer to pointer cast, and
-            // allow the miri tests with tree borrows to work with this test. See
-            // https://github.com/ash-rs/ash/issues/996 for details.
-            let dispatch_table_ptr = std::mem::transmute_copy::<Self, *const *const c_void>(self);
-            std::ptr::read(dispatch_table_ptr)
-        };
-        (key as usize).into()
+struct DeviceInfoWrapper<T: Layer> {
+    dispatch_table: Arc<DeviceDispatchTable>,
+    physical_device: vk::PhysicalDevice,
+    get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    api_version: ApiVersion,
+    enabled_extensions: BTreeSet<Extension>,
+    // hooked_device_commands are recalculated on every vkCreateDevice, so that the layer can
+    // decide which commands to intercept dynamically.
+    hooked_device_commands: HookedCommands,
+    loader_data_callback: DeviceLoaderDataCallback,
+    // None if the statistics are not enabled.
+    stats: Option<StatsCollector>,
+    customized_info: T::DeviceInfoContainer,
+}
+
+struct QueueInfoWrapper<T: Layer> {
+    queue: vk::Queue,
+    owner_device: vk::Device,
+    properties: QueueProperties,
+    customized_info: T::QueueInfo,
+}
+
+struct CommandBufferInfoWrapper<T: Layer> {
+    command_buffer: vk::CommandBuffer,
+    owner_device: vk::Device,
+    command_pool: vk::CommandPool,
+    level: vk::CommandBufferLevel,
+    // None if the command buffer state tracking is not enabled.
+    state: Option<Mutex<CommandBufferState>>,
+    // None if the command recording is not enabled.
+    recorded_commands: Option<Mutex<Vec<RecordedCommand>>>,
+    customized_info: T::CommandBufferInfo,
+}
+
+type CommandPoolMap = HashMap<(vk::Device, vk::CommandPool), BTreeSet<vk::CommandBuffer>>;
+
+/// The properties of a `VkQueue` retrieved by `vkGetDeviceQueue` or `vkGetDeviceQueue2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueueProperties {
+    /// The index of the queue family to which the queue belongs.
+    pub queue_family_index: u32,
+    /// The index within this queue family of the queue.
+    pub queue_index: u32,
+    /// The capabilities of the queue family, i.e. `VkQueueFamilyProperties::queueFlags`.
+    pub queue_flags: vk::QueueFlags,
+    /// The flags of the `VkDeviceQueueCreateInfo` that the queue is created with. Always empty for
+    /// the queues retrieved by `vkGetDeviceQueue`.
+    pub create_flags: vk::DeviceQueueCreateFlags,
+}
+
+/// A shared reference to the [`Layer::InstanceInfoContainer`] of a live `VkInstance`.
+///
+/// Obtained through [`Global::instance_info`] or [`Global::instance_infos`]. The reference keeps
+/// the [`Layer::InstanceInfoContainer`] alive after `vkDestroyInstance`, in which case it is
+/// dropped when the last reference is dropped.
+pub struct InstanceInfoRef<T: Layer>(Arc<InstanceInfoWrapper<T>>);
+
+impl<T: Layer> InstanceInfoRef<T> {
+    /// The `VkInstance` handle.
+    pub fn instance(&self) -> vk::Instance {
+        self.0.dispatch_table.core.handle()
     }
 }
 
-#[repr(transparent)]
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct InstanceDispatchKey(usize);
+impl<T: Layer> Clone for InstanceInfoRef<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
 
-impl From<usize> for InstanceDispatchKey {
-    fn from(value: usize) -> Self {
-        Self(value)
+impl<T: Layer> std::ops::Deref for InstanceInfoRef<T> {
+    type Target = T::InstanceInfoContainer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.customized_info
     }
 }
 
-impl DispatchableObject for vk::Instance {
-    type DispatchKey = InstanceDispatchKey;
+/// A shared reference to the [`Layer::DeviceInfoContainer`] of a live `VkDevice`.
+///
+/// Obtained through [`Global::device_info`] or [`Global::device_infos`]. The reference keeps the
+/// [`Layer::DeviceInfoContainer`] alive after `vkDestroyDevice`, in which case it is dropped when
+/// the last reference is dropped.
+pub struct DeviceInfoRef<T: Layer>(Arc<DeviceInfoWrapper<T>>);
+
+impl<T: Layer> DeviceInfoRef<T> {
+    /// The `VkDevice` handle.
+    pub fn device(&self) -> vk::Device {
+        self.0.dispatch_table.core.handle()
+    }
 }
 
-impl DispatchableObject for vk::PhysicalDevice {
-    type DispatchKey = InstanceDispatchKey;
+impl<T: Layer> Clone for DeviceInfoRef<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
 }
 
-#[repr(transparent)]
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct DeviceDispatchKey(usize);
+impl<T: Layer> std::ops::Deref for DeviceInfoRef<T> {
+    type Target = T::DeviceInfoContainer;
 
-impl From<usize> for DeviceDispatchKey {
-    fn from(value: usize) -> Self {
-        Self(value)
+    fn deref(&self) -> &Self::Target {
+        &self.0.customized_info
     }
 }
 
-impl DispatchableObject for vk::Device {
-    type DispatchKey = DeviceDispatchKey;
+/// A shared reference to the [`Layer::PhysicalDeviceInfo`] of a `VkPhysicalDevice`.
+///
+/// Obtained through [`Global::physical_device_info`]. Like [`InstanceInfoRef`], the reference
+/// keeps the [`Layer::PhysicalDeviceInfo`] alive after the owner `VkInstance` is destroyed.
+pub struct PhysicalDeviceInfoRef<T: Layer>(Arc<PhysicalDeviceInfoWrapper<T>>);
+
+impl<T: Layer> PhysicalDeviceInfoRef<T> {
+    /// The `VkPhysicalDevice` handle.
+    pub fn physical_device(&self) -> vk::PhysicalDevice {
+        self.0.physical_device
+    }
+
+    /// The `VkInstance` that the `VkPhysicalDevice` is enumerated from.
+    pub fn instance(&self) -> vk::Instance {
+        self.0.owner_instance
+    }
+
+    /// The properties of the `VkPhysicalDevice` returned by the next layer.
+    pub fn properties(&self) -> &vk::PhysicalDeviceProperties {
+        &self.0.properties
+    }
 }
 
-impl DispatchableObject for vk::CommandBuffer {
-    type DispatchKey = DeviceDispatchKey;
+impl<T: Layer> Clone for PhysicalDeviceInfoRef<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
 }
 
-impl DispatchableObject for vk::Queue {
-    type DispatchKey = DeviceDispatchKey;
+impl<T: Layer> std::ops::Deref for PhysicalDeviceInfoRef<T> {
+    type Target = T::PhysicalDeviceInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.customized_info
+    }
 }
 
-struct InstanceInfoWrapper<T: Layer> {
-    get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
-    dispatch_table: InstanceDispatchTable,
-    api_version: ApiVersion,
-    enabled_extensions: BTreeSet<Extension>,
-    // instance_commands and device_commands are recalculated on every vkCreateInstance, so that
-    // the layer can decide which commands to intercept dynamically.
-    instance_commands: Box<[VulkanCommand]>,
-    device_commands: Box<[VulkanCommand]>,
-    is_create_device_hooked: bool,
-    customized_info: T::InstanceInfoContainer,
+/// A shared reference to the [`Layer::QueueInfo`] of a `VkQueue`.
+///
+/// Obtained through [`Global::queue_info`]. Like [`DeviceInfoRef`], the reference keeps the
+/// [`Layer::QueueInfo`] alive after the owner `VkDevice` is destroyed.
+pub struct QueueInfoRef<T: Layer>(Arc<QueueInfoWrapper<T>>);
+
+impl<T: Layer> QueueInfoRef<T> {
+    /// The `VkQueue` handle.
+    pub fn queue(&self) -> vk::Queue {
+        self.0.queue
+    }
+
+    /// The `VkDevice` that the `VkQueue` is retrieved from.
+    pub fn device(&self) -> vk::Device {
+        self.0.owner_device
+    }
+
+    /// The family index, the queue index and the flags of the `VkQueue`.
+    pub fn properties(&self) -> &QueueProperties {
+        &self.0.properties
+    }
 }
 
-struct PhysicalDeviceInfoWrapper {
-    owner_instance: vk::Instance,
-    properties: vk::PhysicalDeviceProperties,
+impl<T: Layer> Clone for QueueInfoRef<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
 }
 
-struct DeviceInfoWrapper<T: Layer> {
-    dispatch_table: DeviceDispatchTable,
-    get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
-    api_version: ApiVersion,
-    enabled_extensions: BTreeSet<Extension>,
-    // device_commands are recalculated on every vkCreateDevice, so that the layer can decide
-    // which commands to intercept dynamically.
-    device_commands: Box<[VulkanCommand]>,
-    customized_info: T::DeviceInfoContainer,
+impl<T: Layer> std::ops::Deref for QueueInfoRef<T> {
+    type Target = T::QueueInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.customized_info
+    }
+}
+
+/// A shared reference to the [`Layer::CommandBufferInfo`] of a `VkCommandBuffer`.
+///
+/// Obtained through [`Global::command_buffer_info`]. Like [`DeviceInfoRef`], the reference keeps
+/// the [`Layer::CommandBufferInfo`] alive after the `VkCommandBuffer` is freed.
+pub struct CommandBufferInfoRef<T: Layer>(Arc<CommandBufferInfoWrapper<T>>);
+
+impl<T: Layer> CommandBufferInfoRef<T> {
+    /// The `VkCommandBuffer` handle.
+    pub fn command_buffer(&self) -> vk::CommandBuffer {
+        self.0.command_buffer
+    }
+
+    /// The `VkDevice` that the `VkCommandBuffer` is allocated from.
+    pub fn device(&self) -> vk::Device {
+        self.0.owner_device
+    }
+
+    /// The `VkCommandPool` that the `VkCommandBuffer` is allocated from.
+    pub fn command_pool(&self) -> vk::CommandPool {
+        self.0.command_pool
+    }
+
+    /// Whether the `VkCommandBuffer` is a primary or a secondary command buffer.
+    pub fn level(&self) -> vk::CommandBufferLevel {
+        self.0.level
+    }
+
+    /// The [`CommandBufferState`] tracked by the layer framework.
+    ///
+    /// Returns [`None`] if [`Layer::track_command_buffer_state`] returned `false` when the
+    /// `VkCommandBuffer` was allocated. The state is locked until the returned guard is dropped,
+    /// so don't hold the guard across the calls to the next layer.
+    pub fn state(&self) -> Option<MutexGuard<'_, CommandBufferState>> {
+        self.0.state.as_ref().map(|state| state.lock().unwrap())
+    }
+
+    /// The [`RecordedCommand`]s recorded into the `VkCommandBuffer` since it was last reset, in
+    /// the recording order.
+    ///
+    /// Returns [`None`] if [`Layer::record_command_buffers`] returned `false` when the
+    /// `VkCommandBuffer` was allocated. The commands are locked until the returned guard is
+    /// dropped, so don't hold the guard across the calls to the next layer.
+    pub fn recorded_commands(&self) -> Option<MutexGuard<'_, Vec<RecordedCommand>>> {
+        self.0
+            .recorded_commands
+            .as_ref()
+            .map(|recorded_commands| recorded_commands.lock().unwrap())
+    }
+}
+
+impl<T: Layer> Clone for CommandBufferInfoRef<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: Layer> std::ops::Deref for CommandBufferInfoRef<T> {
+    type Target = T::CommandBufferInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.customized_info
+    }
 }
 
 /// A struct that implements all necessarily functions for a layer given a type that implements
@@ -562,10 +831,17 @@ struct DeviceInfoWrapper<T: Layer> {
 /// This is supposed to be a global singleton for a layer to store all necessary data to implement a
 /// Vulkan layer including dispatch tables, maps between the Vulkan objects and their wrappers, etc.
 pub struct Global<T: Layer> {
-    instance_map: Mutex<LazyCollection<BTreeMap<InstanceDispatchKey, Arc<InstanceInfoWrapper<T>>>>>,
-    physical_device_map:
-        Mutex<LazyCollection<BTreeMap<vk::PhysicalDevice, Arc<PhysicalDeviceInfoWrapper>>>>,
-    device_map: Mutex<LazyCollection<BTreeMap<DeviceDispatchKey, Arc<DeviceInfoWrapper<T>>>>>,
+    // The maps are looked up on every Vulkan command, so they must not take a lock on lookup. See
+    // DispatchMap for details.
+    instance_map: DispatchMap<InstanceDispatchKey, InstanceInfoWrapper<T>>,
+    physical_device_map: DispatchMap<vk::PhysicalDevice, PhysicalDeviceInfoWrapper<T>>,
+    device_map: DispatchMap<DeviceDispatchKey, DeviceInfoWrapper<T>>,
+    queue_map: DispatchMap<vk::Queue, QueueInfoWrapper<T>>,
+    command_buffer_map: DispatchMap<vk::CommandBuffer, CommandBufferInfoWrapper<T>>,
+    // The command buffers in command_buffer_map indexed by their pools, so that
+    // vkResetCommandPool and vkDestroyCommandPool don't scan all the command buffers. Only updated
+    // inside command_buffer_map.update to stay consistent with command_buffer_map.
+    command_pool_map: Mutex<CommandPoolMap>,
     /// Access to the underlying `T`.
     // layer_info can't be lazily constructed when the first VkInstance is created, because we want
     // to guarantee that T::default is only called once during the lifetime of Global, so that the
@@ -574,9 +850,14 @@ pub struct Global<T: Layer> {
     // must guarantee that T is also trivially destructible.
     pub layer_info: T,
     get_instance_addr_proc_hooked: bool,
+    // The commands that Layer::command_observer is called for. Empty if there is no observer.
+    observed_commands: HookedCommands,
 }
 
 impl<T: Layer> Global<T> {
+    /// The highest loader-layer interface version supported by the layer framework.
+    const MAX_LOADER_LAYER_INTERFACE_VERSION: u32 = 2;
+
     fn instance() -> impl std::ops::Deref<Target = Self> + 'static {
         T::global_instance()
     }
@@ -585,12 +866,7 @@ impl<T: Layer> Global<T> {
         &self,
         instance: impl DispatchableObject<DispatchKey = InstanceDispatchKey>,
     ) -> Option<Arc<InstanceInfoWrapper<T>>> {
-        self.instance_map
-            .lock()
-            .unwrap()
-            .get()
-            .get(&instance.get_dispatch_key())
-            .map(Arc::clone)
+        self.instance_map.get(&instance.get_dispatch_key())
     }
 
     fn create_physical_device_infos<U: Borrow<vk::PhysicalDevice>>(
@@ -598,54 +874,484 @@ impl<T: Layer> Global<T> {
         instance: vk::Instance,
         physical_devices: impl IntoIterator<Item = U>,
     ) {
-        for physical_device in physical_devices {
-            let mut physical_device_map = self.physical_device_map.lock().unwrap();
-            let mut physical_device_map = physical_device_map.get_mut_or_default();
-            if let Some(physical_device_info) = physical_device_map.get(physical_device.borrow()) {
-                assert_eq!(physical_device_info.owner_instance, instance);
-                continue;
-            }
-            let instance_info = self.get_instance_info(instance).unwrap_or_else(|| {
-                panic!("Unknown VkInstance handle: {:#018x}", instance.as_raw())
-            });
-            let properties = unsafe {
-                instance_info
-                    .dispatch_table
-                    .core
-                    .get_physical_device_properties(*physical_device.borrow())
-            };
-            physical_device_map.insert(
-                *physical_device.borrow(),
+        let new_physical_devices = physical_devices
+            .into_iter()
+            .map(|physical_device| *physical_device.borrow())
+            .filter(
+                |physical_device| match self.get_physical_info(*physical_device) {
+                    Some(physical_device_info) => {
+                        assert_eq!(physical_device_info.owner_instance, instance);
+                        false
+                    }
+                    None => true,
+                },
+            )
+            .collect::<Vec<_>>();
+        // Avoid publishing a new snapshot if there is nothing to insert.
+        if new_physical_devices.is_empty() {
+            return;
+        }
+        // The infos are created outside of DispatchMap::update, which blocks the other writers,
+        // because creating them calls into the next layer and the layer implementation.
+        let instance_info = self
+            .get_instance_info(instance)
+            .unwrap_or_else(|| panic!("Unknown VkInstance handle: {:#018x}", instance.as_raw()));
+        let physical_device_infos = new_physical_devices
+            .into_iter()
+            .map(|physical_device| {
+                let properties = unsafe {
+                    instance_info
+                        .dispatch_table
+                        .core
+                        .get_physical_device_properties(physical_device)
+                };
+                let customized_info = self.layer_info.create_physical_device_info(
+                    instance_info.customized_info.borrow(),
+                    physical_device,
+                    &properties,
+                );
                 Arc::new(PhysicalDeviceInfoWrapper {
+                    physical_device,
                     owner_instance: instance,
                     properties,
-                }),
-            );
-        }
+                    customized_info,
+                })
+            })
+            .collect::<Vec<_>>();
+        self.physical_device_map.update(|physical_device_map| {
+            for physical_device_info in physical_device_infos {
+                // Another thread may have enumerated the same VkPhysicalDevice in the meantime.
+                physical_device_map
+                    .get_or_insert_with(physical_device_info.physical_device, || {
+                        physical_device_info
+                    });
+            }
+        });
     }
 
     fn get_device_info(
         &self,
         device: impl DispatchableObject<DispatchKey = DeviceDispatchKey>,
     ) -> Option<Arc<DeviceInfoWrapper<T>>> {
-        self.device_map
+        self.device_map.get(&device.get_dispatch_key())
+    }
+
+    fn create_queue_info(
+        &self,
+        device: vk::Device,
+        queue: vk::Queue,
+        queue_family_index: u32,
+        queue_index: u32,
+        create_flags: vk::DeviceQueueCreateFlags,
+    ) {
+        // vkGetDeviceQueue returns the same VkQueue every time, so only the first call creates the
+        // queue info.
+        if let Some(queue_info) = self.queue_map.get(&queue) {
+            if queue_info.owner_device != device {
+                error!(
+                    "VkQueue {:?} is retrieved from {:?}, but is owned by {:?}.",
+                    queue, device, queue_info.owner_device
+                );
+            }
+            return;
+        }
+        let Some(device_info) = self.get_device_info(device) else {
+            error!("Unknown VkDevice: {:?}", device);
+            return;
+        };
+        let physical_device_info = self
+            .get_physical_info(device_info.physical_device)
+            .expect("The physical device of this device must be registered.");
+        let instance_info = self
+            .get_instance_info(physical_device_info.owner_instance)
+            .expect("The owner instance of this physical device must be registered.");
+        let queue_family_properties = unsafe {
+            instance_info
+                .dispatch_table
+                .core
+                .get_physical_device_queue_family_properties(device_info.physical_device)
+        };
+        let properties = QueueProperties {
+            queue_family_index,
+            queue_index,
+            queue_flags: queue_family_properties
+                .get(queue_family_index as usize)
+                .map(|queue_family_properties| queue_family_properties.queue_flags)
+                .unwrap_or_default(),
+            create_flags,
+        };
+        let customized_info = self.layer_info.create_queue_info(
+            device_info.customized_info.borrow(),
+            queue,
+            &properties,
+        );
+        self.queue_map.update(|queue_map| {
+            // Another thread may have retrieved the same VkQueue in the meantime.
+            queue_map.get_or_insert_with(queue, || {
+                Arc::new(QueueInfoWrapper {
+                    queue,
+                    owner_device: device,
+                    properties,
+                    customized_info,
+                })
+            });
+        });
+    }
+
+    fn find_instance_command(name: &str) -> Option<&'static VulkanCommand> {
+        INSTANCE_COMMAND_LOOKUP_TABLE.get(Self::INSTANCE_COMMANDS, name)
+    }
+
+    fn find_device_command(name: &str) -> Option<&'static VulkanCommand> {
+        DEVICE_COMMAND_LOOKUP_TABLE.get(Self::DEVICE_COMMANDS, name)
+    }
+
+    fn create_command_buffer_infos(
+        &self,
+        device_info: &DeviceInfoWrapper<T>,
+        device: vk::Device,
+        allocate_info: &vk::CommandBufferAllocateInfo,
+        command_buffers: &[vk::CommandBuffer],
+    ) {
+        let track_command_buffer_state = self.layer_info.track_command_buffer_state();
+        let record_command_buffers = self.layer_info.record_command_buffers();
+        let command_buffer_infos = command_buffers
+            .iter()
+            .map(|command_buffer| {
+                let customized_info = self.layer_info.create_command_buffer_info(
+                    device_info.customized_info.borrow(),
+                    *command_buffer,
+                    allocate_info,
+                );
+                Arc::new(CommandBufferInfoWrapper {
+                    command_buffer: *command_buffer,
+                    owner_device: device,
+                    command_pool: allocate_info.command_pool,
+                    level: allocate_info.level,
+                    state: track_command_buffer_state.then(Default::default),
+                    recorded_commands: record_command_buffers.then(Default::default),
+                    customized_info,
+                })
+            })
+            .collect::<Vec<_>>();
+        self.command_buffer_map.update(|command_buffer_map| {
+            let mut command_pool_map = self.command_pool_map.lock().unwrap();
+            // An info of the same handle that is not removed yet belongs to a command buffer freed
+            // on another thread, and is replaced.
+            for command_buffer_info in command_buffer_infos {
+                let command_buffer = command_buffer_info.command_buffer;
+                let command_pool = command_buffer_info.command_pool;
+                if let Some(replaced) =
+                    command_buffer_map.insert(command_buffer, command_buffer_info)
+                {
+                    Self::remove_from_command_pool_map(&mut command_pool_map, &replaced);
+                }
+                command_pool_map
+                    .entry((device, command_pool))
+                    .or_default()
+                    .insert(command_buffer);
+            }
+        });
+    }
+
+    fn remove_from_command_pool_map(
+        command_pool_map: &mut CommandPoolMap,
+        command_buffer_info: &CommandBufferInfoWrapper<T>,
+    ) {
+        let key = (
+            command_buffer_info.owner_device,
+            command_buffer_info.command_pool,
+        );
+        let Some(command_buffers) = command_pool_map.get_mut(&key) else {
+            return;
+        };
+        command_buffers.remove(&command_buffer_info.command_buffer);
+        if command_buffers.is_empty() {
+            command_pool_map.remove(&key);
+        }
+    }
+
+    fn get_command_buffer_infos_in_pool(
+        &self,
+        device: vk::Device,
+        command_pool: vk::CommandPool,
+    ) -> Vec<Arc<CommandBufferInfoWrapper<T>>> {
+        let command_buffers = self
+            .command_pool_map
             .lock()
             .unwrap()
-            .get()
-            .get(&device.get_dispatch_key())
-            .map(Arc::clone)
+            .get(&(device, command_pool))
+            .cloned()
+            .unwrap_or_default();
+        // An info looked up here can be replaced by a command buffer allocated on another thread
+        // with the same handle, so check the pool again.
+        command_buffers
+            .into_iter()
+            .filter_map(|command_buffer| self.command_buffer_map.get(&command_buffer))
+            .filter(|command_buffer_info| {
+                command_buffer_info.owner_device == device
+                    && command_buffer_info.command_pool == command_pool
+            })
+            .collect()
+    }
+
+    fn reset_command_buffer_info(&self, command_buffer_info: &CommandBufferInfoWrapper<T>) {
+        if let Some(state) = &command_buffer_info.state {
+            state.lock().unwrap().reset();
+        }
+        if let Some(recorded_commands) = &command_buffer_info.recorded_commands {
+            recorded_commands.lock().unwrap().clear();
+        }
+        self.layer_info
+            .reset_command_buffer_info(&command_buffer_info.customized_info);
+    }
+
+    fn remove_command_buffer_infos(
+        &self,
+        command_buffer_infos: &[Arc<CommandBufferInfoWrapper<T>>],
+    ) {
+        if command_buffer_infos.is_empty() {
+            return;
+        }
+        // The driver may reuse a freed handle for a command buffer allocated on another thread
+        // before the info is removed, so only remove the infos looked up before the free.
+        self.command_buffer_map.update(|command_buffer_map| {
+            let mut command_pool_map = self.command_pool_map.lock().unwrap();
+            for command_buffer_info in command_buffer_infos {
+                let command_buffer = command_buffer_info.command_buffer;
+                if command_buffer_map
+                    .get(&command_buffer)
+                    .is_some_and(|entry| Arc::ptr_eq(entry, command_buffer_info))
+                {
+                    command_buffer_map.remove(&command_buffer);
+                    Self::remove_from_command_pool_map(&mut command_pool_map, command_buffer_info);
+                }
+            }
+        });
     }
 
     fn get_physical_info(
         &self,
         physical_device: vk::PhysicalDevice,
-    ) -> Option<Arc<PhysicalDeviceInfoWrapper>> {
-        self.physical_device_map
-            .lock()
-            .unwrap()
-            .get()
-            .get(&physical_device)
-            .map(Arc::clone)
+    ) -> Option<Arc<PhysicalDeviceInfoWrapper<T>>> {
+        self.physical_device_map.get(&physical_device)
+    }
+
+    /// Returns the [`Layer::InstanceInfoContainer`] of the instance that owns `object`.
+    ///
+    /// `object` can be a `VkInstance` or a `VkPhysicalDevice`. Returns [`None`] if the owning
+    /// `VkInstance` is not created with this layer enabled, or is already destroyed. Unlike the
+    /// hooks, this can be called from anywhere, e.g. a background worker thread of the layer, or a
+    /// callback registered with the application. The lookup doesn't take any lock.
+    pub fn instance_info(
+        &self,
+        object: impl DispatchableObject<DispatchKey = InstanceDispatchKey>,
+    ) -> Option<InstanceInfoRef<T>> {
+        self.get_instance_info(object).map(InstanceInfoRef)
+    }
+
+    /// Returns the [`Layer::InstanceInfoContainer`]s of all the live `VkInstance`s created with
+    /// this layer enabled.
+    ///
+    /// The result is a snapshot: `VkInstance`s created or destroyed after this function returns
+    /// are not reflected.
+    pub fn instance_infos(&self) -> impl Iterator<Item = InstanceInfoRef<T>> {
+        self.instance_map.values().into_iter().map(InstanceInfoRef)
+    }
+
+    /// Returns the [`Layer::PhysicalDeviceInfo`] of `physical_device`.
+    ///
+    /// Returns [`None`] if `physical_device` is not enumerated through this layer, or the owner
+    /// `VkInstance` is already destroyed. The [`InstanceHooks`] methods that take a
+    /// `physical_device` parameter can use this function to reach the per physical device state.
+    /// Like [`Global::instance_info`], this can be called from anywhere without taking any lock.
+    pub fn physical_device_info(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> Option<PhysicalDeviceInfoRef<T>> {
+        self.get_physical_info(physical_device)
+            .map(PhysicalDeviceInfoRef)
+    }
+
+    /// Returns the [`Layer::QueueInfo`] of `queue`.
+    ///
+    /// Returns [`None`] if `queue` is not retrieved through this layer with `vkGetDeviceQueue` or
+    /// `vkGetDeviceQueue2`, or the owner `VkDevice` is already destroyed. The hooks of the queue
+    /// commands, e.g. [`DeviceHooks::queue_submit`], [`DeviceHooks::queue_submit2`],
+    /// [`DeviceHooks::queue_present_khr`] and [`DeviceHooks::queue_bind_sparse`], can use this
+    /// function to reach the per queue state, which is not passed to them because [`DeviceHooks`]
+    /// doesn't know the [`Layer`]. Like [`Global::instance_info`], this can be called from anywhere
+    /// without taking any lock.
+    pub fn queue_info(&self, queue: vk::Queue) -> Option<QueueInfoRef<T>> {
+        self.queue_map.get(&queue).map(QueueInfoRef)
+    }
+
+    /// Returns the [`Layer::CommandBufferInfo`] of `command_buffer`.
+    ///
+    /// Returns [`None`] if `command_buffer` is not allocated through this layer, or is already
+    /// freed. This lookup doesn't take any lock, so the hooks of the `vkCmd*` commands, e.g.
+    /// [`DeviceHooks::cmd_draw`], can call it on every recorded command.
+    pub fn command_buffer_info(
+        &self,
+        command_buffer: vk::CommandBuffer,
+    ) -> Option<CommandBufferInfoRef<T>> {
+        self.command_buffer_map
+            .get(&command_buffer)
+            .map(CommandBufferInfoRef)
+    }
+
+    /// Returns the [`Layer::DeviceInfoContainer`] of the device that owns `object`.
+    ///
+    /// `object` can be a `VkDevice`, a `VkQueue` or a `VkCommandBuffer`. Returns [`None`] if the
+    /// owning `VkDevice` is not created with this layer enabled, or is already destroyed. Like
+    /// [`Global::instance_info`], this can be called from anywhere without taking any lock.
+    pub fn device_info(
+        &self,
+        object: impl DispatchableObject<DispatchKey = DeviceDispatchKey>,
+    ) -> Option<DeviceInfoRef<T>> {
+        self.get_device_info(object).map(DeviceInfoRef)
+    }
+
+    /// Returns the [`Layer::DeviceInfoContainer`]s of all the live `VkDevice`s created with this
+    /// layer enabled.
+    ///
+    /// The result is a snapshot: `VkDevice`s created or destroyed after this function returns are
+    /// not reflected.
+    pub fn device_infos(&self) -> impl Iterator<Item = DeviceInfoRef<T>> {
+        self.device_map.values().into_iter().map(DeviceInfoRef)
+    }
+
+    /// Returns the dispatch table of the next layer for the instance that owns `object`.
+    ///
+    /// `object` can be a `VkInstance` or a `VkPhysicalDevice`. Returns [`None`] if the owning
+    /// `VkInstance` is not created with this layer enabled, or is already destroyed. The returned
+    /// table contains all the instance commands that the layer framework loads from the next
+    /// layer, including the commands from the extensions, so that the layer implementation can call
+    /// into the next layer from the hooks without loading its own copy.
+    ///
+    /// The returned table keeps the function pointers alive after `vkDestroyInstance`, but it is
+    /// undefined behavior to call them on a destroyed instance.
+    pub fn next_instance_dispatch(
+        &self,
+        object: impl DispatchableObject<DispatchKey = InstanceDispatchKey>,
+    ) -> Option<Arc<InstanceDispatchTable>> {
+        self.get_instance_info(object)
+            .map(|instance_info| Arc::clone(&instance_info.dispatch_table))
+    }
+
+    /// Returns the dispatch table of the next layer for the device that owns `object`.
+    ///
+    /// `object` can be a `VkDevice`, a `VkQueue` or a `VkCommandBuffer`. Returns [`None`] if the
+    /// owning `VkDevice` is not created with this layer enabled, or is already destroyed. The
+    /// returned table contains all the device commands that the layer framework loads from the next
+    /// layer, including the commands from the extensions.
+    ///
+    /// The returned table keeps the function pointers alive after `vkDestroyDevice`, but it is
+    /// undefined behavior to call them on a destroyed device.
+    pub fn next_device_dispatch(
+        &self,
+        object: impl DispatchableObject<DispatchKey = DeviceDispatchKey>,
+    ) -> Option<Arc<DeviceDispatchTable>> {
+        self.get_device_info(object)
+            .map(|device_info| Arc::clone(&device_info.dispatch_table))
+    }
+
+    /// Returns the [`DeviceLoaderDataCallback`] captured in `vkCreateDevice` for `device`, or `None`
+    /// if `device` is not created with this layer enabled.
+    pub fn device_loader_data_callback(
+        &self,
+        device: vk::Device,
+    ) -> Option<DeviceLoaderDataCallback> {
+        self.get_device_info(device)
+            .map(|device_info| device_info.loader_data_callback.clone())
+    }
+
+    /// Initializes the loader dispatch pointer of a dispatchable object created by the layer
+    /// implementation itself from `device`, e.g. a `VkCommandBuffer` allocated by the layer for
+    /// internal use.
+    ///
+    /// This calls into the `pfnSetDeviceLoaderData` loader callback captured in `vkCreateDevice`.
+    /// See [`DeviceLoaderDataCallback::set_device_loader_data`] for details.
+    /// `VK_ERROR_INITIALIZATION_FAILED` is returned if `device` is not created with this layer
+    /// enabled.
+    ///
+    /// # Safety
+    /// `object` must be a valid `VkQueue` or `VkCommandBuffer` handle created from `device` by the
+    /// layer implementation itself.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn set_device_loader_data(
+        &self,
+        device: vk::Device,
+        object: impl vk::Handle,
+    ) -> VkResult<()> {
+        let Some(device_info) = self.get_device_info(device) else {
+            error!("Unknown VkDevice: {:?}", device);
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        };
+        // Safe because the caller guarantees that object is created from device by the layer.
+        unsafe {
+            device_info
+                .loader_data_callback
+                .set_device_loader_data(object)
+        }
+    }
+
+    /// Initializes the loader dispatch pointer of a dispatchable object created by the layer
+    /// implementation itself from `instance`.
+    ///
+    /// This calls into the `pfnSetInstanceLoaderData` loader callback captured in
+    /// `vkCreateInstance`. See [`InstanceLoaderDataCallback::set_instance_loader_data`] for details.
+    /// `VK_ERROR_INITIALIZATION_FAILED` is returned if `instance` is not created with this layer
+    /// enabled.
+    ///
+    /// # Safety
+    /// `object` must be a valid `VkPhysicalDevice` handle created from `instance` by the layer
+    /// implementation itself.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe fn set_instance_loader_data(
+        &self,
+        instance: vk::Instance,
+        object: impl vk::Handle,
+    ) -> VkResult<()> {
+        let Some(instance_info) = self.get_instance_info(instance) else {
+            error!("Unknown VkInstance: {:?}", instance);
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        };
+        // Safe because the caller guarantees that object is created from instance by the layer.
+        unsafe {
+            instance_info
+                .loader_data_callback
+                .set_instance_loader_data(object)
+        }
+    }
+
+    /// Removes the extensions implemented by this layer from the requested extension list, so
+    /// that the next layer in the call chain won't fail on extensions it doesn't know.
+    fn remove_layer_extensions(
+        requested_extensions: &[String],
+        layer_extensions: &[ExtensionProperties],
+    ) -> Vec<CString> {
+        requested_extensions
+            .iter()
+            .filter_map(|extension_name| {
+                let extension_name_cstring =
+                    CString::new(extension_name.clone()).unwrap_or_else(|e| {
+                        panic!("Failed to create CString from {}: {}", extension_name, e)
+                    });
+                let extension: Extension = match extension_name.as_str().try_into() {
+                    Ok(extension) => extension,
+                    Err(_) => return Some(extension_name_cstring),
+                };
+                if layer_extensions
+                    .iter()
+                    .any(|layer_extension| layer_extension.name == extension)
+                {
+                    None
+                } else {
+                    Some(extension_name_cstring)
+                }
+            })
+            .collect()
     }
 
     extern "system" fn create_instance(
@@ -656,6 +1362,31 @@ impl<T: Layer> Global<T> {
         let p_next_chain = unsafe { create_info.as_ref() }
             .map(|create_info| create_info.p_next as *mut vk::BaseOutStructure)
             .unwrap_or(null_mut());
+        let set_instance_loader_data = {
+            let p_next_chain: VulkanBaseInStructChain =
+                unsafe { (p_next_chain as *const vk::BaseInStructure).as_ref() }.into();
+            p_next_chain
+                .filter_map(|in_struct| {
+                    let in_struct = in_struct as *const vk::BaseInStructure;
+                    let layer_create_info = unsafe {
+                        ash::match_in_struct!(match in_struct {
+                            in_struct @ VkLayerInstanceCreateInfo => {
+                                in_struct
+                            }
+                            _ => {
+                                return None;
+                            }
+                        })
+                    };
+                    if layer_create_info.function == VkLayerFunction::VK_LOADER_DATA_CALLBACK {
+                        Some(unsafe { layer_create_info.u.pfnSetInstanceLoaderData })
+                    } else {
+                        None
+                    }
+                })
+                .next()
+                .flatten()
+        };
         let mut p_next_chain: VulkanBaseOutStructChain = unsafe { p_next_chain.as_mut() }.into();
         let layer_create_info = p_next_chain.find_map(|out_struct| {
             let out_struct = out_struct as *mut vk::BaseOutStructure;
@@ -687,6 +1418,7 @@ impl<T: Layer> Global<T> {
 
         let get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr =
             layer_info.pfnNextGetInstanceProcAddr;
+        let get_physical_device_proc_addr = layer_info.pfnNextGetPhysicalDeviceProcAddr;
 
         let global = Self::instance();
         let hooked =
@@ -715,12 +1447,42 @@ impl<T: Layer> Global<T> {
                 };
                 let entry = vk::EntryFnV1_0::load(get_proc_addr);
 
+                let mut create_info = *unsafe { create_info.as_ref() }.unwrap();
+                let requested_extensions = unsafe {
+                    slice_from_raw_parts(
+                        create_info.pp_enabled_extension_names,
+                        create_info.enabled_extension_count,
+                    )
+                };
+                let requested_extensions =
+                    unsafe { slice_to_owned_strings(requested_extensions) }.collect::<Vec<_>>();
+                let layer_manifest = T::manifest();
+                let mut enabled_extensions = Self::remove_layer_extensions(
+                    &requested_extensions,
+                    layer_manifest.instance_extensions,
+                );
+                if layer_manifest.layer_settings_extension {
+                    enabled_extensions.retain(|extension_name| {
+                        extension_name.as_c_str() != EXT_LAYER_SETTINGS_NAME
+                    });
+                }
+                let enabled_extensions = enabled_extensions
+                    .iter()
+                    .map(|extension_name| extension_name.as_ptr())
+                    .collect::<Vec<_>>();
+                create_info.enabled_extension_count = enabled_extensions.len().try_into().unwrap();
+                create_info.pp_enabled_extension_names = if enabled_extensions.is_empty() {
+                    null()
+                } else {
+                    enabled_extensions.as_ptr()
+                };
                 let ret: vk::Result =
-                    unsafe { (entry.create_instance)(create_info, allocator, p_instance) };
+                    unsafe { (entry.create_instance)(&create_info, allocator, p_instance) };
                 if !matches!(ret, vk::Result::SUCCESS) {
                     return ret;
                 }
             }
+            LayerResult::Continue(args) => match args {},
         };
 
         let instance = *unsafe { p_instance.as_ref() }.unwrap();
@@ -734,6 +1496,8 @@ impl<T: Layer> Global<T> {
         };
 
         let create_info = unsafe { create_info.as_ref() }.unwrap();
+        // Use the original extension list, so that the instance extensions implemented by this
+        // layer are also considered enabled.
         let enabled_extensions = unsafe {
             slice_from_raw_parts(
                 create_info.pp_enabled_extension_names,
@@ -760,23 +1524,40 @@ impl<T: Layer> Global<T> {
         } else {
             api_version.into()
         };
+        // Safe because the caller guarantees that the pNext chain of `create_info` is valid.
+        let layer_settings =
+            unsafe { LayerSettings::from_instance_create_info(T::manifest().name, create_info) };
         let ash_instance = Arc::new(ash_instance);
         let customized_info = global.layer_info.create_instance_info(
             create_info,
             unsafe { allocator.as_ref() },
             Arc::clone(&ash_instance),
             get_instance_proc_addr,
+            &layer_settings,
         );
-        let is_create_device_hooked = global
+        let mut hooked_instance_commands = global
             .layer_info
             .hooked_instance_commands(customized_info.borrow())
-            .any(|command| command == LayerVulkanCommand::CreateDevice);
-        let instance_commands = global.create_instance_commands(customized_info.borrow());
-        let device_commands = global.create_device_commands(customized_info.borrow(), None);
-        {
-            let key = instance.get_dispatch_key();
-            let mut instance_map = global.instance_map.lock().unwrap();
-            let mut instance_map = instance_map.get_mut_or_default();
+            .chain(global.fault_injection_commands())
+            .chain(global.extern_sync_commands())
+            .collect::<HookedCommands>();
+        // vkCreateDevice is not observed, so it is checked before the observed commands are added.
+        let is_create_device_hooked =
+            hooked_instance_commands.contains(&LayerVulkanCommand::CreateDevice);
+        hooked_instance_commands.union(&global.observed_commands);
+        hooked_instance_commands.union(&global.command_stats_commands());
+        let mut hooked_device_commands = global
+            .layer_info
+            .hooked_device_commands(customized_info.borrow(), None)
+            .chain(global.command_buffer_state_commands())
+            .chain(global.command_recording_commands())
+            .chain(global.fault_injection_commands())
+            .chain(global.extern_sync_commands())
+            .collect::<HookedCommands>();
+        hooked_device_commands.union(&global.observed_commands);
+        hooked_device_commands.union(&global.command_stats_commands());
+        let key = instance.get_dispatch_key();
+        global.instance_map.update(|instance_map| {
             if instance_map.contains_key(&key) {
                 error!(
                     "duplicate instances: instance {:?} already exists",
@@ -788,20 +1569,26 @@ impl<T: Layer> Global<T> {
                 key,
                 Arc::new(InstanceInfoWrapper {
                     get_instance_proc_addr,
-                    dispatch_table: InstanceDispatchTable::load(
+                    get_physical_device_proc_addr,
+                    dispatch_table: Arc::new(InstanceDispatchTable::load(
                         get_instance_proc_addr,
                         ash_instance,
-                    ),
+                    )),
                     api_version,
                     enabled_extensions,
-                    instance_commands,
-                    device_commands,
+                    hooked_instance_commands,
+                    hooked_device_commands,
                     is_create_device_hooked,
+                    loader_data_callback: InstanceLoaderDataCallback::new(
+                        instance,
+                        set_instance_loader_data,
+                    ),
+                    stats: global.create_stats_collector(instance),
                     customized_info,
                 }),
             );
-        }
-        vk::Result::SUCCESS
+            vk::Result::SUCCESS
+        })
     }
 
     extern "system" fn destroy_instance(
@@ -815,24 +1602,18 @@ impl<T: Layer> Global<T> {
         let global = Self::instance();
         let instance_info = global
             .instance_map
-            .lock()
-            .unwrap()
-            .get_mut_or_default()
-            .remove(&dispatch_key);
+            .update(|instance_map| instance_map.remove(&dispatch_key));
+        // The instance info can still be referenced by an InstanceInfoRef obtained from
+        // Global::instance_info, in which case the customized info is dropped with the last
+        // reference.
         let instance_info = instance_info.unwrap();
-        let instance_info = match Arc::try_unwrap(instance_info) {
-            Ok(instance_info) => instance_info,
-            Err(_) => panic!(
-                "This should be the sole instance dispatch table reference for instance {:?}.",
-                instance
-            ),
-        };
-        global
-            .physical_device_map
-            .lock()
-            .unwrap()
-            .get_mut_or_default()
-            .retain(|_, physical_device_info| physical_device_info.owner_instance != instance);
+        global.physical_device_map.update(|physical_device_map| {
+            physical_device_map
+                .retain(|_, physical_device_info| physical_device_info.owner_instance != instance)
+        });
+        if let Some(stats) = &instance_info.stats {
+            info!("{}", stats.report());
+        }
         unsafe {
             (instance_info.dispatch_table.core.fp_v1_0().destroy_instance)(instance, allocator)
         };
@@ -910,6 +1691,163 @@ impl<T: Layer> Global<T> {
         res
     }
 
+    extern "system" fn get_device_queue_and_track(
+        device: vk::Device,
+        queue_family_index: u32,
+        queue_index: u32,
+        p_queue: *mut vk::Queue,
+    ) {
+        Self::get_device_queue(device, queue_family_index, queue_index, p_queue);
+        let queue = *unsafe { p_queue.as_ref() }.unwrap();
+        if queue == vk::Queue::null() {
+            return;
+        }
+        Self::instance().create_queue_info(
+            device,
+            queue,
+            queue_family_index,
+            queue_index,
+            vk::DeviceQueueCreateFlags::empty(),
+        );
+    }
+
+    extern "system" fn get_device_queue2_and_track(
+        device: vk::Device,
+        p_queue_info: *const vk::DeviceQueueInfo2,
+        p_queue: *mut vk::Queue,
+    ) {
+        Self::get_device_queue2(device, p_queue_info, p_queue);
+        let queue = *unsafe { p_queue.as_ref() }.unwrap();
+        // vkGetDeviceQueue2 returns VK_NULL_HANDLE if the flags don't match any queue.
+        if queue == vk::Queue::null() {
+            return;
+        }
+        let queue_info = unsafe { p_queue_info.as_ref() }.unwrap();
+        Self::instance().create_queue_info(
+            device,
+            queue,
+            queue_info.queue_family_index,
+            queue_info.queue_index,
+            queue_info.flags,
+        );
+    }
+
+    extern "system" fn allocate_command_buffers_and_track(
+        device: vk::Device,
+        p_allocate_info: *const vk::CommandBufferAllocateInfo,
+        p_command_buffers: *mut vk::CommandBuffer,
+    ) -> vk::Result {
+        let global = Self::instance();
+        // Fail before the next layer allocates anything, so that no command buffer is left
+        // untracked.
+        let Some(device_info) = global.get_device_info(device) else {
+            error!("Unknown VkDevice: {:?}", device);
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        };
+        let res = Self::allocate_command_buffers(device, p_allocate_info, p_command_buffers);
+        if res != vk::Result::SUCCESS {
+            return res;
+        }
+        let allocate_info = unsafe { p_allocate_info.as_ref() }.unwrap();
+        let command_buffers =
+            unsafe { slice_from_raw_parts(p_command_buffers, allocate_info.command_buffer_count) };
+        global.create_command_buffer_infos(&device_info, device, allocate_info, command_buffers);
+        res
+    }
+
+    extern "system" fn begin_command_buffer_and_track(
+        command_buffer: vk::CommandBuffer,
+        p_begin_info: *const vk::CommandBufferBeginInfo,
+    ) -> vk::Result {
+        // vkBeginCommandBuffer implicitly resets the command buffer. Reset the info before calling
+        // the hooks, so that the hooks record into a clean info. The info stays reset even if the
+        // begin fails.
+        let global = Self::instance();
+        let Some(command_buffer_info) = global.command_buffer_map.get(&command_buffer) else {
+            return Self::begin_command_buffer(command_buffer, p_begin_info);
+        };
+        global.reset_command_buffer_info(&command_buffer_info);
+        let res = Self::begin_command_buffer(command_buffer, p_begin_info);
+        if res == vk::Result::SUCCESS {
+            if let Some(state) = &command_buffer_info.state {
+                state.lock().unwrap().begin(
+                    command_buffer_info.level,
+                    unsafe { p_begin_info.as_ref() }.unwrap(),
+                );
+            }
+        }
+        res
+    }
+
+    extern "system" fn reset_command_buffer_and_track(
+        command_buffer: vk::CommandBuffer,
+        flags: vk::CommandBufferResetFlags,
+    ) -> vk::Result {
+        let res = Self::reset_command_buffer(command_buffer, flags);
+        if res != vk::Result::SUCCESS {
+            return res;
+        }
+        let global = Self::instance();
+        if let Some(command_buffer_info) = global.command_buffer_map.get(&command_buffer) {
+            global.reset_command_buffer_info(&command_buffer_info);
+        }
+        res
+    }
+
+    extern "system" fn reset_command_pool_and_track(
+        device: vk::Device,
+        command_pool: vk::CommandPool,
+        flags: vk::CommandPoolResetFlags,
+    ) -> vk::Result {
+        let res = Self::reset_command_pool(device, command_pool, flags);
+        if res != vk::Result::SUCCESS {
+            return res;
+        }
+        let global = Self::instance();
+        for command_buffer_info in global.get_command_buffer_infos_in_pool(device, command_pool) {
+            global.reset_command_buffer_info(&command_buffer_info);
+        }
+        res
+    }
+
+    extern "system" fn free_command_buffers_and_track(
+        device: vk::Device,
+        command_pool: vk::CommandPool,
+        command_buffer_count: u32,
+        p_command_buffers: *const vk::CommandBuffer,
+    ) {
+        let global = Self::instance();
+        let command_buffers =
+            unsafe { slice_from_raw_parts(p_command_buffers, command_buffer_count) };
+        // Null handles are ignored.
+        let command_buffer_infos = command_buffers
+            .iter()
+            .filter_map(|command_buffer| global.command_buffer_map.get(command_buffer))
+            .collect::<Vec<_>>();
+        Self::free_command_buffers(
+            device,
+            command_pool,
+            command_buffer_count,
+            p_command_buffers,
+        );
+        global.remove_command_buffer_infos(&command_buffer_infos);
+    }
+
+    extern "system" fn destroy_command_pool_and_track(
+        device: vk::Device,
+        command_pool: vk::CommandPool,
+        p_allocator: *const vk::AllocationCallbacks,
+    ) {
+        let global = Self::instance();
+        let command_buffer_infos = if command_pool == vk::CommandPool::null() {
+            vec![]
+        } else {
+            global.get_command_buffer_infos_in_pool(device, command_pool)
+        };
+        Self::destroy_command_pool(device, command_pool, p_allocator);
+        global.remove_command_buffer_infos(&command_buffer_infos);
+    }
+
     extern "system" fn create_device(
         physical_device: vk::PhysicalDevice,
         create_info: *const vk::DeviceCreateInfo,
@@ -917,6 +1855,31 @@ impl<T: Layer> Global<T> {
         p_device: *mut vk::Device,
     ) -> vk::Result {
         let create_info = unsafe { create_info.as_ref() }.unwrap();
+        let set_device_loader_data = {
+            let p_next_chain: VulkanBaseInStructChain =
+                unsafe { (create_info.p_next as *const vk::BaseInStructure).as_ref() }.into();
+            p_next_chain
+                .filter_map(|in_struct| {
+                    let in_struct = in_struct as *const vk::BaseInStructure;
+                    let layer_create_info = unsafe {
+                        ash::match_in_struct!(match in_struct {
+                            in_struct @ VkLayerDeviceCreateInfo => {
+                                in_struct
+                            }
+                            _ => {
+                                return None;
+                            }
+                        })
+                    };
+                    if layer_create_info.function == VkLayerFunction::VK_LOADER_DATA_CALLBACK {
+                        Some(unsafe { layer_create_info.u.pfnSetDeviceLoaderData })
+                    } else {
+                        None
+                    }
+                })
+                .next()
+                .flatten()
+        };
         let mut p_next_chain: VulkanBaseOutStructChain =
             unsafe { (create_info.p_next as *mut vk::BaseOutStructure).as_mut() }.into();
         let layer_create_info = p_next_chain.find_map(|out_struct| {
@@ -968,6 +1931,7 @@ impl<T: Layer> Global<T> {
         };
         let next_create_device: vk::PFN_vkCreateDevice =
             unsafe { std::mem::transmute(next_create_device) };
+        let original_create_info = create_info;
         let mut create_info = *create_info;
         let requested_extensions = unsafe {
             slice_from_raw_parts(
@@ -997,28 +1961,10 @@ impl<T: Layer> Global<T> {
             LayerResult::Handled(Ok(())) => {}
             LayerResult::Handled(Err(e)) => return e,
             LayerResult::Unhandled => {
-                let enabled_extensions = requested_extensions
-                    .iter()
-                    .filter_map(|extension_name| {
-                        let extension_name_cstring = CString::new(extension_name.clone())
-                            .unwrap_or_else(|e| {
-                                panic!("Failed to create CString from {}: {}", extension_name, e)
-                            });
-                        let extension: Extension = match extension_name.as_str().try_into() {
-                            Ok(extension) => extension,
-                            Err(_) => return Some(extension_name_cstring),
-                        };
-                        if layer_manifest
-                            .device_extensions
-                            .iter()
-                            .any(|layer_extension| layer_extension.name == extension)
-                        {
-                            None
-                        } else {
-                            Some(extension_name_cstring)
-                        }
-                    })
-                    .collect::<Vec<_>>();
+                let enabled_extensions = Self::remove_layer_extensions(
+                    &requested_extensions,
+                    layer_manifest.device_extensions,
+                );
                 let enabled_extensions = enabled_extensions
                     .iter()
                     .map(|extension_name| extension_name.as_ptr())
@@ -1032,10 +1978,25 @@ impl<T: Layer> Global<T> {
                 let res = unsafe {
                     next_create_device(physical_device, &create_info, p_allocator, p_device)
                 };
+                if instance_info.is_create_device_hooked {
+                    instance_info
+                        .customized_info
+                        .borrow()
+                        .hooks()
+                        .after_create_device(
+                            physical_device,
+                            original_create_info,
+                            layer_link,
+                            unsafe { p_allocator.as_ref() },
+                            (res.as_raw() >= 0).then(|| unsafe { p_device.as_ref() }.unwrap()),
+                            res,
+                        );
+                }
                 if res != vk::Result::SUCCESS {
                     return res;
                 }
             }
+            LayerResult::Continue(args) => match args {},
         }
 
         let device = *unsafe { p_device.as_ref() }.unwrap();
@@ -1065,20 +2026,33 @@ impl<T: Layer> Global<T> {
         let api_version = instance_info
             .api_version
             .min(physical_device_info.properties.api_version.into());
+        let loader_data_callback = DeviceLoaderDataCallback::new(
+            Arc::clone(&ash_device),
+            &create_info,
+            set_device_loader_data,
+        );
         let customized_info = global.layer_info.create_device_info(
             physical_device,
             &create_info,
             unsafe { p_allocator.as_ref() },
             Arc::clone(&ash_device),
             get_device_proc_addr,
+            loader_data_callback.clone(),
         );
-        let device_commands = global.create_device_commands(
-            instance_info.customized_info.borrow(),
-            Some(customized_info.borrow()),
-        );
-        {
-            let mut device_map = global.device_map.lock().unwrap();
-            let mut device_map = device_map.get_mut_or_default();
+        let mut hooked_device_commands = global
+            .layer_info
+            .hooked_device_commands(
+                instance_info.customized_info.borrow(),
+                Some(customized_info.borrow()),
+            )
+            .chain(global.command_buffer_state_commands())
+            .chain(global.command_recording_commands())
+            .chain(global.fault_injection_commands())
+            .chain(global.extern_sync_commands())
+            .collect::<HookedCommands>();
+        hooked_device_commands.union(&global.observed_commands);
+        hooked_device_commands.union(&global.command_stats_commands());
+        global.device_map.update(|device_map| {
             assert!(
                 !device_map.contains_key(&device.get_dispatch_key()),
                 "duplicate VkDevice: {:?}",
@@ -1087,15 +2061,21 @@ impl<T: Layer> Global<T> {
             device_map.insert(
                 device.get_dispatch_key(),
                 Arc::new(DeviceInfoWrapper {
-                    dispatch_table: DeviceDispatchTable::load(get_device_proc_addr, ash_device),
+                    dispatch_table: Arc::new(DeviceDispatchTable::load(
+                        get_device_proc_addr,
+                        ash_device,
+                    )),
+                    physical_device,
                     get_device_proc_addr,
                     api_version,
                     enabled_extensions,
-                    device_commands,
+                    hooked_device_commands,
+                    loader_data_callback,
+                    stats: global.create_stats_collector(device),
                     customized_info,
                 }),
             );
-        }
+        });
         vk::Result::SUCCESS
     }
 
@@ -1107,18 +2087,28 @@ impl<T: Layer> Global<T> {
             return;
         }
         let global = Self::instance();
-        let device_info = match Arc::try_unwrap(
+        // The device info can still be referenced by a DeviceInfoRef obtained from
+        // Global::device_info, in which case the customized info is dropped with the last
+        // reference.
+        let device_info = global
+            .device_map
+            .update(|device_map| device_map.remove(&device.get_dispatch_key()))
+            .expect("device must be registered");
+        global.queue_map.update(|queue_map| {
+            queue_map.retain(|_, queue_info| queue_info.owner_device != device)
+        });
+        global.command_buffer_map.update(|command_buffer_map| {
+            command_buffer_map
+                .retain(|_, command_buffer_info| command_buffer_info.owner_device != device);
             global
-                .device_map
+                .command_pool_map
                 .lock()
                 .unwrap()
-                .get_mut_or_default()
-                .remove(&device.get_dispatch_key())
-                .expect("device must be registered"),
-        ) {
-            Ok(device_info) => device_info,
-            Err(_) => panic!("This should be the sole owner of the device {:?}", device),
-        };
+                .retain(|(owner_device, _), _| *owner_device != device);
+        });
+        if let Some(stats) = &device_info.stats {
+            info!("{}", stats.report());
+        }
         let allocation_callback = unsafe { p_allocator.as_ref() };
         unsafe {
             device_info
@@ -1182,8 +2172,8 @@ impl<T: Layer> Global<T> {
 
     /// The `vkEnumerateInstanceExtensionProperties` entry point provided by the layer framework.
     ///
-    /// Returns an empty list with `VK_SUCCESS` if the layer name matches; returns
-    /// `VK_ERROR_LAYER_NOT_PRESENT` with all the out pointers untouched, according to the
+    /// Returns [`LayerManifest::instance_extensions`] with `VK_SUCCESS` if the layer name matches;
+    /// returns `VK_ERROR_LAYER_NOT_PRESENT` with all the out pointers untouched, according to the
     /// [`LLP_LAYER_15`](<https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#:~:text=LLP_LAYER_15,Conventions%20and%20Rules>)
     /// rule.
     ///
@@ -1194,7 +2184,7 @@ impl<T: Layer> Global<T> {
     pub unsafe extern "system" fn enumerate_instance_extension_properties(
         layer_name: *const c_char,
         property_count: *mut u32,
-        _: *mut vk::ExtensionProperties,
+        properties: *mut vk::ExtensionProperties,
     ) -> vk::Result {
         if !layer_name.is_null() {
             let layer_name = unsafe { CStr::from_ptr(layer_name) }
@@ -1205,18 +2195,202 @@ impl<T: Layer> Global<T> {
                     "if p_layer_name is not NULL, p_layer_name must be a null-terminated UTF-8 \
                      string."
                 ));
-            if layer_name == T::manifest().name {
-                // Safe, because the caller guarantees that if the passed in `property_count` is not
-                // null, it's a valid pointer to u32.
-                if let Some(property_count) = unsafe { property_count.as_mut() } {
-                    *property_count = 0;
+            let layer_manifest = T::manifest();
+            if layer_name == layer_manifest.name {
+                let mut instance_extensions = layer_manifest
+                    .instance_extensions
+                    .iter()
+                    .cloned()
+                    .map(Into::<vk::ExtensionProperties>::into)
+                    .collect::<Vec<_>>();
+                if layer_manifest.layer_settings_extension {
+                    let mut layer_settings_extension = vk::ExtensionProperties {
+                        spec_version: EXT_LAYER_SETTINGS_SPEC_VERSION,
+                        ..Default::default()
+                    };
+                    let name = EXT_LAYER_SETTINGS_NAME.to_bytes();
+                    layer_settings_extension.extension_name[..name.len()]
+                        .copy_from_slice(cast_slice(name));
+                    instance_extensions.push(layer_settings_extension);
                 }
-                return vk::Result::SUCCESS;
+                // Safe, because the caller guarantees that `property_count` is a valid pointer to
+                // u32, and if the value referenced by `property_count` is not 0, and `properties`
+                // is not NULL, `properties` must be a valid pointer to an array of
+                // `property_count` vk::ExtensionProperties structures. See details in
+                // VUID-vkEnumerateInstanceExtensionProperties-pPropertyCount-parameter and
+                // VUID-vkEnumerateInstanceExtensionProperties-pProperties-parameter.
+                return unsafe {
+                    fill_vk_out_array(
+                        &instance_extensions,
+                        NonNull::new(property_count).expect(concat!(
+                            "`property_count` must be a valid pointer to u32 according to ",
+                            "VUID-vkEnumerateInstanceExtensionProperties-pPropertyCount-parameter."
+                        )),
+                        properties,
+                    )
+                };
             }
         }
         vk::Result::ERROR_LAYER_NOT_PRESENT
     }
 
+    /// The pre-instance `vkEnumerateInstanceVersion` entry point provided by the layer framework.
+    ///
+    /// The Vulkan loader calls this function through the `VkEnumerateInstanceVersionChain` before
+    /// any `VkInstance` is created, only if the layer is an implicit layer that declares this
+    /// function in the `"pre_instance_functions"` JSON node of the manifest. Calls into
+    /// [`GlobalHooks::enumerate_instance_version`] if the layer implementation intercepts it,
+    /// otherwise calls into the next layer in the chain. See
+    /// [the Vulkan loader doc](https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#pre-instance-functions)
+    /// for details.
+    ///
+    /// # Safety
+    /// `p_chain` must be a valid pointer to the chain node passed from the loader. See valid usage
+    /// of `vkEnumerateInstanceVersion` at
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceVersion.html>.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe extern "system" fn pre_instance_enumerate_instance_version(
+        p_chain: *const VkEnumerateInstanceVersionChain,
+        p_api_version: *mut u32,
+    ) -> vk::Result {
+        // Safe because the caller guarantees that `p_chain` is a valid pointer.
+        let chain = unsafe { p_chain.as_ref() }.expect("p_chain must be a valid pointer.");
+        let global = Self::instance();
+        let hooked = T::GlobalHooksInfo::hooked_commands()
+            .contains(&LayerVulkanCommand::EnumerateInstanceVersion);
+        let layer_result = if hooked {
+            global.layer_info.global_hooks().enumerate_instance_version(
+                chain,
+                // Safe because the caller guarantees that `p_api_version` is a valid pointer to
+                // u32 according to VUID-vkEnumerateInstanceVersion-pApiVersion-parameter.
+                unsafe { ptr_as_uninit_mut(p_api_version) }.expect(concat!(
+                    "p_api_version must be a valid pointer to u32 according to ",
+                    "VUID-vkEnumerateInstanceVersion-pApiVersion-parameter."
+                )),
+            )
+        } else {
+            LayerResult::Unhandled
+        };
+        match layer_result {
+            LayerResult::Handled(res) => res,
+            // Safe because the caller guarantees that the chain and the arguments are valid.
+            LayerResult::Unhandled => unsafe { chain.call_down(p_api_version) },
+            LayerResult::Continue(args) => match args {},
+        }
+    }
+
+    /// The pre-instance `vkEnumerateInstanceExtensionProperties` entry point provided by the layer
+    /// framework.
+    ///
+    /// Similar to [`Global::pre_instance_enumerate_instance_version`], calls into
+    /// [`GlobalHooks::enumerate_instance_extension_properties`] if the layer implementation
+    /// intercepts it, otherwise calls into the next layer in the chain.
+    ///
+    /// # Safety
+    /// `p_chain` must be a valid pointer to the chain node passed from the loader. See valid usage
+    /// of `vkEnumerateInstanceExtensionProperties` at
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html>.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe extern "system" fn pre_instance_enumerate_instance_extension_properties(
+        p_chain: *const VkEnumerateInstanceExtensionPropertiesChain,
+        p_layer_name: *const c_char,
+        p_property_count: *mut u32,
+        p_properties: *mut vk::ExtensionProperties,
+    ) -> vk::Result {
+        // Safe because the caller guarantees that `p_chain` is a valid pointer.
+        let chain = unsafe { p_chain.as_ref() }.expect("p_chain must be a valid pointer.");
+        let global = Self::instance();
+        let hooked = T::GlobalHooksInfo::hooked_commands()
+            .contains(&LayerVulkanCommand::EnumerateInstanceExtensionProperties);
+        let layer_result = if hooked {
+            // Safe because the caller guarantees that `p_layer_name` is either NULL or a valid
+            // null-terminated string according to
+            // VUID-vkEnumerateInstanceExtensionProperties-pLayerName-parameter.
+            let layer_name = if p_layer_name.is_null() {
+                None
+            } else {
+                Some(unsafe { CStr::from_ptr(p_layer_name) })
+            };
+            global
+                .layer_info
+                .global_hooks()
+                .enumerate_instance_extension_properties(
+                    chain,
+                    layer_name,
+                    // Safe because the caller guarantees that `p_property_count` is a valid pointer
+                    // to u32, and `p_properties` is either NULL or a valid pointer to an array of
+                    // `*p_property_count` vk::ExtensionProperties structures.
+                    unsafe { ptr_as_uninit_mut(p_property_count) }.expect(concat!(
+                        "p_property_count must be a valid pointer to u32 according to ",
+                        "VUID-vkEnumerateInstanceExtensionProperties-pPropertyCount-parameter."
+                    )),
+                    unsafe {
+                        maybe_uninit_slice_from_raw_parts_mut(p_properties, p_property_count)
+                    },
+                )
+        } else {
+            LayerResult::Unhandled
+        };
+        match layer_result {
+            LayerResult::Handled(res) => res,
+            // Safe because the caller guarantees that the chain and the arguments are valid.
+            LayerResult::Unhandled => unsafe {
+                chain.call_down(p_layer_name, p_property_count, p_properties)
+            },
+            LayerResult::Continue(args) => match args {},
+        }
+    }
+
+    /// The pre-instance `vkEnumerateInstanceLayerProperties` entry point provided by the layer
+    /// framework.
+    ///
+    /// Similar to [`Global::pre_instance_enumerate_instance_version`], calls into
+    /// [`GlobalHooks::enumerate_instance_layer_properties`] if the layer implementation intercepts
+    /// it, otherwise calls into the next layer in the chain.
+    ///
+    /// # Safety
+    /// `p_chain` must be a valid pointer to the chain node passed from the loader. See valid usage
+    /// of `vkEnumerateInstanceLayerProperties` at
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html>.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe extern "system" fn pre_instance_enumerate_instance_layer_properties(
+        p_chain: *const VkEnumerateInstanceLayerPropertiesChain,
+        p_property_count: *mut u32,
+        p_properties: *mut vk::LayerProperties,
+    ) -> vk::Result {
+        // Safe because the caller guarantees that `p_chain` is a valid pointer.
+        let chain = unsafe { p_chain.as_ref() }.expect("p_chain must be a valid pointer.");
+        let global = Self::instance();
+        let hooked = T::GlobalHooksInfo::hooked_commands()
+            .contains(&LayerVulkanCommand::EnumerateInstanceLayerProperties);
+        let layer_result = if hooked {
+            global
+                .layer_info
+                .global_hooks()
+                .enumerate_instance_layer_properties(
+                    chain,
+                    // Safe because the caller guarantees that `p_property_count` is a valid pointer
+                    // to u32, and `p_properties` is either NULL or a valid pointer to an array of
+                    // `*p_property_count` vk::LayerProperties structures.
+                    unsafe { ptr_as_uninit_mut(p_property_count) }.expect(concat!(
+                        "p_property_count must be a valid pointer to u32 according to ",
+                        "VUID-vkEnumerateInstanceLayerProperties-pPropertyCount-parameter."
+                    )),
+                    unsafe {
+                        maybe_uninit_slice_from_raw_parts_mut(p_properties, p_property_count)
+                    },
+                )
+        } else {
+            LayerResult::Unhandled
+        };
+        match layer_result {
+            LayerResult::Handled(res) => res,
+            // Safe because the caller guarantees that the chain and the arguments are valid.
+            LayerResult::Unhandled => unsafe { chain.call_down(p_property_count, p_properties) },
+            LayerResult::Continue(args) => match args {},
+        }
+    }
+
     /// The `vkEnumerateDeviceLayerProperties` entry point provided by the layer framework.
     ///
     /// The return value is decided by [`Layer::manifest`]. Only enumerate the layer itself
@@ -1346,10 +2520,10 @@ impl<T: Layer> Global<T> {
     ///    the current layer for all
     ///    [global commands](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetInstanceProcAddr.html#:~:text=The%20global%20commands%20are%3A%20vkEnumerateInstanceVersion%2C%20vkEnumerateInstanceExtensionProperties%2C%20vkEnumerateInstanceLayerProperties%2C%20and%20vkCreateInstance.)
     ///    and `vkGetInstanceProcAddr`; `NULL` is returned for other Vulkan commands. One culprit
-    ///    for now is that currently the layer framework always returns `NULL` for
-    ///    `vkEnumerateInstanceVersion`, but that should be Ok, because the layer framework
-    ///    currently doesn't allow to intercept pre-instance functions anyway, so this function
-    ///    should be completely handled by the Vulkan loader.
+    ///    is that the layer framework always returns `NULL` for `vkEnumerateInstanceVersion`, but
+    ///    that should be Ok, because the Vulkan loader only calls into layers for this function
+    ///    through the pre-instance function chain. See
+    ///    [`Global::pre_instance_enumerate_instance_version`].
     ///
     /// 1. If instance is not null, the situation is more complicated:
     ///
@@ -1378,7 +2552,9 @@ impl<T: Layer> Global<T> {
     ///        can find the correspondent `VkInstance` in `vkCreateDevice`.
     ///
     ///     1. For other core dispatchable commands and enabled instance extension dispatchable
-    ///        commands, if the layer implementation decides to intercept(according to
+    ///        commands(including the instance extensions in
+    ///        [`LayerManifest::instance_extensions`] enabled by the application), if the layer
+    ///        implementation decides to intercept(according to
     ///        [`Layer::hooked_instance_commands`] and [`Layer::hooked_device_commands`]), returns a
     ///        local function pointer, otherwise returns the function pointer from the next layer.
     ///
@@ -1388,7 +2564,8 @@ impl<T: Layer> Global<T> {
     ///        and rely on the next layer to return `NULL`.
     ///
     ///     1. For device extension dispatchable commands directly supported by the layer
-    ///        implementation according to [`LayerManifest::device_extensions`], always returns a
+    ///        implementation according to [`LayerManifest::device_extensions`] or the enabled
+    ///        [`LayerManifest::instance_extensions`], always returns a
     ///        local function pointer if the layer implementation intercepts the command. Otherwise,
     ///        returns the function pointer of the next layer.
     ///
@@ -1461,35 +2638,30 @@ impl<T: Layer> Global<T> {
             _ => {}
         }
         if instance == vk::Instance::null() {
-            // TODO: allow inteception of vkEnumerateInstanceVersion and handle
-            // vkEnumerateInstanceVersion properly. For now, we still return NULL for
-            // vkEnumerateInstanceVersion. The Vulkan loader should cover us for this case.
+            // vkEnumerateInstanceVersion is intercepted through the pre-instance function chain
+            // instead, see Global::pre_instance_enumerate_instance_version. The Vulkan loader never
+            // queries it from layers with vkGetInstanceProcAddr, so we still return NULL here.
             // Per spec, if instance is NULL, and pName is neither NULL nor a global command, NULL
             // should be returned.
             return None;
         }
         let instance_info = global.get_instance_info(instance)?;
         if global.get_instance_addr_proc_hooked {
-            if let LayerResult::Handled(res) = instance_info
+            match instance_info
                 .customized_info
                 .borrow()
                 .hooks()
                 .get_instance_proc_addr(name)
             {
-                return res;
+                LayerResult::Handled(res) => return res,
+                LayerResult::Unhandled => {}
+                LayerResult::Continue(args) => match args {},
             }
         }
         let get_next_proc_addr =
             || unsafe { (instance_info.get_instance_proc_addr)(instance, p_name) };
-        let instance_commands = &instance_info.instance_commands;
-        let instance_command = match instance_commands
-            .binary_search_by_key(&name, |VulkanCommand { name, .. }| name)
-        {
-            Ok(index) => Some(&instance_commands[index]),
-            Err(_) => None,
-        };
-        if let Some(instance_command) = instance_command {
-            if !instance_command.hooked {
+        if let Some(instance_command) = Self::find_instance_command(name) {
+            if !instance_command.is_hooked(&instance_info.hooked_instance_commands) {
                 return get_next_proc_addr();
             }
             let enabled = instance_command.features.is_command_enabled(
@@ -1501,27 +2673,29 @@ impl<T: Layer> Global<T> {
             }
             return instance_command.proc;
         }
-        let device_commands = &instance_info.device_commands;
-        let device_command =
-            match device_commands.binary_search_by_key(&name, |VulkanCommand { name, .. }| name) {
-                Ok(index) => Some(&device_commands[index]),
-                Err(_) => None,
-            };
-        if let Some(device_command) = device_command {
+        if let Some(device_command) = Self::find_device_command(name) {
             let next_proc_addr = get_next_proc_addr();
-            if !device_command.hooked {
+            if !device_command.is_hooked(&instance_info.hooked_device_commands) {
                 return next_proc_addr;
             }
-            let layer_device_extensions: BTreeSet<Extension> = T::manifest()
+            let layer_manifest = T::manifest();
+            // Enabled instance extensions implemented by this layer can also provide device
+            // commands, e.g. vkSetDebugUtilsObjectNameEXT from VK_EXT_debug_utils.
+            let layer_extensions: BTreeSet<Extension> = layer_manifest
                 .device_extensions
                 .iter()
+                .chain(layer_manifest.instance_extensions.iter().filter(
+                    |ExtensionProperties { name, .. }| {
+                        instance_info.enabled_extensions.contains(name)
+                    },
+                ))
                 .map(|ExtensionProperties { name, .. }| name.clone())
                 .collect();
             // If the layer supports the command or the next proc addr can find it, this is an
             // available device command.
             let command_available = device_command
                 .features
-                .is_command_enabled(&instance_info.api_version, &layer_device_extensions)
+                .is_command_enabled(&instance_info.api_version, &layer_extensions)
                 || next_proc_addr.is_some();
             if command_available {
                 return device_command.proc;
@@ -1556,22 +2730,19 @@ impl<T: Layer> Global<T> {
         let name = name.to_str().expect("name should be a valid UTF-8 string.");
         let global = Self::instance();
         let device_info = global.get_device_info(device)?;
-        if let LayerResult::Handled(res) = device_info
+        match device_info
             .customized_info
             .borrow()
             .hooks()
             .get_device_proc_addr(name)
         {
-            return res;
+            LayerResult::Handled(res) => return res,
+            LayerResult::Unhandled => {}
+            LayerResult::Continue(args) => match args {},
         }
         let get_next_device_proc_addr =
             || unsafe { (device_info.get_device_proc_addr)(device, p_name) };
-        let device_commands = &device_info.device_commands;
-        let command = if let Ok(index) =
-            device_commands.binary_search_by_key(&name, |VulkanCommand { name, .. }| name)
-        {
-            &device_commands[index]
-        } else {
+        let Some(command) = Self::find_device_command(name) else {
             return get_next_device_proc_addr();
         };
         if !command
@@ -1580,11 +2751,107 @@ impl<T: Layer> Global<T> {
         {
             return get_next_device_proc_addr();
         }
-        if !command.hooked {
+        if !command.is_hooked(&device_info.hooked_device_commands) {
             return get_next_device_proc_addr();
         }
         command.proc
     }
+
+    /// The `vk_layerGetPhysicalDeviceProcAddr` entry point provided by the layer framework.
+    ///
+    /// The loader only calls this function with the names of physical device commands that it
+    /// doesn't know about, e.g. commands from extensions newer than the loader. See
+    /// [the Vulkan loader doc](<https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#layer-unknown-physical-device-extensions>)
+    /// for details.
+    ///
+    /// If the layer implementation intercepts the command according to
+    /// [`Layer::hooked_instance_commands`], and the command is enabled for the instance, returns
+    /// the local function pointer. Otherwise, calls into the `pfnNextGetPhysicalDeviceProcAddr` of
+    /// the next layer in the call chain, and returns `NULL` if the next layer doesn't provide one.
+    ///
+    /// # Safety
+    /// `instance` must be a valid `VkInstance` handle, and `p_name` must be a null-terminated
+    /// UTF-8 string.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe extern "system" fn get_physical_device_proc_addr(
+        instance: vk::Instance,
+        p_name: *const c_char,
+    ) -> vk::PFN_vkVoidFunction {
+        // Safe because the caller guarantees that p_name is a null-terminated string.
+        let name = unsafe { CStr::from_ptr(p_name) };
+        let name = name.to_str().expect("name should be a valid UTF-8 string.");
+        let global = Self::instance();
+        let instance_info = global.get_instance_info(instance)?;
+        let get_next_proc_addr = || {
+            instance_info.get_physical_device_proc_addr.and_then(
+                // Safe because the caller guarantees that instance is valid and p_name is a
+                // null-terminated string, and the next layer's function is called with the same
+                // arguments.
+                |get_physical_device_proc_addr| unsafe {
+                    get_physical_device_proc_addr(instance, p_name)
+                },
+            )
+        };
+        let Some(instance_command) = Self::find_instance_command(name) else {
+            return get_next_proc_addr();
+        };
+        if !instance_command.is_hooked(&instance_info.hooked_instance_commands) {
+            return get_next_proc_addr();
+        }
+        if !instance_command.features.is_command_enabled(
+            &instance_info.api_version,
+            &instance_info.enabled_extensions,
+        ) {
+            return get_next_proc_addr();
+        }
+        instance_command.proc
+    }
+
+    /// The `vkNegotiateLoaderLayerInterfaceVersion` entry point provided by the layer framework.
+    ///
+    /// The layer framework supports the loader-layer interface up to version 2. The negotiated
+    /// version is the smaller one between the version requested by the loader and 2, and is
+    /// written back to `loaderLayerInterfaceVersion`. If the negotiated version is 2, the
+    /// `pfnGetInstanceProcAddr`, `pfnGetDeviceProcAddr` and `pfnGetPhysicalDeviceProcAddr` fields
+    /// are also filled in, so that the loader doesn't need to look up the exported symbols by
+    /// name. See
+    /// [the Vulkan loader doc](<https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#layer-version-negotiation>)
+    /// for details.
+    ///
+    /// `VK_ERROR_INITIALIZATION_FAILED` is returned if `p_version_struct` is not a
+    /// `VkNegotiateLayerInterface` struct.
+    ///
+    /// # Safety
+    /// `p_version_struct` must be a valid pointer to a [`VkNegotiateLayerInterface`] struct. See
+    /// [`LLP_LOADER_2`](<https://github.com/KhronosGroup/Vulkan-Loader/blob/v1.3.261/docs/LoaderLayerInterface.md#:~:text=LLP_LOADER_2,Conventions%20and%20Rules>)
+    /// for details.
+    #[deny(unsafe_op_in_unsafe_fn)]
+    pub unsafe extern "system" fn negotiate_loader_layer_interface_version(
+        p_version_struct: *mut VkNegotiateLayerInterface,
+    ) -> vk::Result {
+        // Make sure Global is initialized.
+        let _ = Self::instance();
+        // Safe because the caller guarantees that `p_version_struct` is a valid pointer to a
+        // `VkNegotiateLayerInterface` struct.
+        let version_struct = unsafe { p_version_struct.as_mut() }
+            .expect("p_version_struct must be a valid pointer to VkNegotiateLayerInterface.");
+        if version_struct.sType != VkNegotiateLayerStructType::LAYER_NEGOTIATE_INTERFACE_STRUCT {
+            error!(
+                "Unexpected sType for VkNegotiateLayerInterface: {:?}",
+                version_struct.sType
+            );
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        }
+        version_struct.loaderLayerInterfaceVersion = version_struct
+            .loaderLayerInterfaceVersion
+            .min(Self::MAX_LOADER_LAYER_INTERFACE_VERSION);
+        if version_struct.loaderLayerInterfaceVersion >= 2 {
+            version_struct.pfnGetInstanceProcAddr = Self::get_instance_proc_addr;
+            version_struct.pfnGetDeviceProcAddr = Self::get_device_proc_addr;
+            version_struct.pfnGetPhysicalDeviceProcAddr = Some(Self::get_physical_device_proc_addr);
+        }
+        vk::Result::SUCCESS
+    }
 }
 
 impl<T: Layer> Default for Global<T> {
@@ -1592,12 +2859,20 @@ impl<T: Layer> Default for Global<T> {
         let layer_info: T = Default::default();
         let get_instance_addr_proc_hooked = T::GlobalHooksInfo::hooked_commands()
             .contains(&LayerVulkanCommand::GetInstanceProcAddr);
+        let observed_commands = layer_info
+            .command_observer()
+            .map(|observer| HookedCommands::from_observed_commands(observer.observed_commands()))
+            .unwrap_or_default();
         Self {
             instance_map: Default::default(),
             physical_device_map: Default::default(),
             device_map: Default::default(),
+            queue_map: Default::default(),
+            command_buffer_map: Default::default(),
+            command_pool_map: Default::default(),
             layer_info,
             get_instance_addr_proc_hooked,
+            observed_commands,
         }
     }
 }
@@ -1635,7 +2910,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn commands_must_be_sorted() {
+    fn commands_must_be_sorted_and_found_by_lookup_tables() {
         #[derive(Default)]
         struct TestLayer(StubGlobalHooks);
 
@@ -1645,6 +2920,9 @@ mod tests {
             type DeviceInfo = StubDeviceInfo;
             type InstanceInfoContainer = StubInstanceInfo;
             type DeviceInfoContainer = StubDeviceInfo;
+            type PhysicalDeviceInfo = ();
+            type QueueInfo = ();
+            type CommandBufferInfo = ();
 
             fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
                 &*GLOBAL
@@ -1664,6 +2942,7 @@ mod tests {
                 _: Option<&vk::AllocationCallbacks>,
                 _: Arc<ash::Instance>,
                 _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+                _: &LayerSettings,
             ) -> Self::InstanceInfoContainer {
                 Default::default()
             }
@@ -1675,6 +2954,7 @@ mod tests {
                 _: Option<&vk::AllocationCallbacks>,
                 _: Arc<ash::Device>,
                 _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+                _: DeviceLoaderDataCallback,
             ) -> Self::DeviceInfoContainer {
                 Default::default()
             }
@@ -1693,24 +2973,42 @@ mod tests {
             }
         }
 
-        let instance_info = Default::default();
-        let device_commands =
-            Global::<TestLayer>::instance().create_device_commands(&instance_info, None);
-        let mut name_iter = device_commands
+        let mut name_iter = Global::<TestLayer>::DEVICE_COMMANDS
             .iter()
             .map(|VulkanCommand { name, .. }| *name);
         let mut last = name_iter.next().unwrap();
 
         assert!(name_iter.all(check(&mut last)));
 
-        let instance_commands =
-            Global::<TestLayer>::instance().create_instance_commands(&instance_info);
-        let mut name_iter = instance_commands
+        let mut name_iter = Global::<TestLayer>::INSTANCE_COMMANDS
             .iter()
             .map(|VulkanCommand { name, .. }| *name);
         let mut last = name_iter.next().unwrap();
 
         assert!(name_iter.all(check(&mut last)));
+
+        for command in Global::<TestLayer>::DEVICE_COMMANDS {
+            let found = Global::<TestLayer>::find_device_command(command.name);
+            assert!(
+                found.map(|found| found.name) == Some(command.name),
+                "{} is not found in the device command lookup table",
+                command.name
+            );
+        }
+        for command in Global::<TestLayer>::INSTANCE_COMMANDS {
+            let found = Global::<TestLayer>::find_instance_command(command.name);
+            assert!(
+                found.map(|found| found.name) == Some(command.name),
+                "{} is not found in the instance command lookup table",
+                command.name
+            );
+        }
+        for unknown_name in ["", "vkUnknownCommand", "vkCreateDevice2", "vkCmdDra"] {
+            assert!(Global::<TestLayer>::find_device_command(unknown_name).is_none());
+            assert!(Global::<TestLayer>::find_instance_command(unknown_name).is_none());
+        }
+        assert!(Global::<TestLayer>::find_device_command("vkCreateDevice").is_none());
+        assert!(Global::<TestLayer>::find_instance_command("vkCmdDraw").is_none());
     }
 
     #[test]
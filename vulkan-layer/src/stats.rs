@@ -0,0 +1,484 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The per-command call statistics.
+//!
+//! The statistics are enabled by [`Layer::collect_command_stats`]. The layer framework then
+//! intercepts all the commands even if the layer implementation doesn't hook them, and counts the
+//! calls, the error results and the time spent in the layer and in the next layer for every
+//! `VkInstance` and `VkDevice`.
+
+use ash::vk;
+use once_cell::sync::OnceCell;
+use std::{
+    array,
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use crate::{
+    global_simple_intercept::HookedCommands, DispatchableHandle, Global, Layer, LayerVulkanCommand,
+};
+
+/// A histogram of durations with power-of-two buckets.
+///
+/// The first bucket counts the durations shorter than 1 microsecond, and the bucket `i` counts
+/// the durations in `[2^(i - 1), 2^i)` microseconds. The last bucket also counts all the longer
+/// durations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    buckets: [u64; Self::BUCKET_COUNT],
+    total: Duration,
+}
+
+impl LatencyHistogram {
+    /// The number of the buckets.
+    pub const BUCKET_COUNT: usize = 32;
+
+    fn bucket_index(duration: Duration) -> usize {
+        let micros = duration.as_micros();
+        let index = (u128::BITS - micros.leading_zeros()) as usize;
+        index.min(Self::BUCKET_COUNT - 1)
+    }
+
+    fn bucket_upper_bound(index: usize) -> Duration {
+        if index == Self::BUCKET_COUNT - 1 {
+            return Duration::MAX;
+        }
+        Duration::from_micros(1 << index)
+    }
+
+    /// Adds `duration` to the histogram.
+    pub fn record(&mut self, duration: Duration) {
+        self.buckets[Self::bucket_index(duration)] += 1;
+        self.total = self.total.saturating_add(duration);
+    }
+
+    /// The number of the recorded durations.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// The sum of the recorded durations.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// The mean of the recorded durations, or [`None`] if nothing is recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+        Some(Duration::from_nanos(
+            (self.total.as_nanos() / u128::from(count)) as u64,
+        ))
+    }
+
+    /// The exclusive upper bound and the count of every bucket, from the shortest durations to the
+    /// longest. The upper bound of the last bucket is [`Duration::MAX`].
+    pub fn buckets(&self) -> impl Iterator<Item = (Duration, u64)> + '_ {
+        self.buckets
+            .iter()
+            .enumerate()
+            .map(|(index, count)| (Self::bucket_upper_bound(index), *count))
+    }
+
+    /// The upper bound of the bucket that contains the `quantile` of the recorded durations, e.g.
+    /// `0.99` for the 99th percentile, or [`None`] if nothing is recorded.
+    pub fn quantile_upper_bound(&self, quantile: f64) -> Option<Duration> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+        let rank = ((count as f64 * quantile.clamp(0.0, 1.0)).ceil() as u64).max(1);
+        let mut accumulated = 0;
+        self.buckets().find_map(|(upper_bound, bucket_count)| {
+            accumulated += bucket_count;
+            (accumulated >= rank).then_some(upper_bound)
+        })
+    }
+}
+
+/// The statistics of a command called on a `VkInstance` or a `VkDevice`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommandStats {
+    /// The number of the calls.
+    pub calls: u64,
+    /// The number of the calls that returned a negative `VkResult`.
+    pub errors: u64,
+    /// The time spent in the layer, including the hooks of the layer implementation, but
+    /// excluding the next layer.
+    pub layer_time: LatencyHistogram,
+    /// The time spent in the next layer. Nothing is recorded if the next layer is not called,
+    /// e.g. if the hook of the layer implementation returns [`LayerResult::Handled`].
+    ///
+    /// [`LayerResult::Handled`]: crate::LayerResult::Handled
+    pub next_layer_time: LatencyHistogram,
+}
+
+/// The statistics of every command called on a `VkInstance` or a `VkDevice`.
+pub type CommandStatsMap = BTreeMap<LayerVulkanCommand, CommandStats>;
+
+/// The statistics of all the alive `VkInstance`s and `VkDevice`s. Returned by
+/// [`Global::stats_snapshot`].
+///
+/// The commands called on a `VkPhysicalDevice` are accounted to the owner `VkInstance`, and the
+/// commands called on a `VkQueue` or a `VkCommandBuffer` are accounted to the owner `VkDevice`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatsSnapshot {
+    /// The statistics of every `VkInstance`.
+    pub instances: BTreeMap<vk::Instance, CommandStatsMap>,
+    /// The statistics of every `VkDevice`.
+    pub devices: BTreeMap<vk::Device, CommandStatsMap>,
+}
+
+impl Display for StatsSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (instance, commands) in &self.instances {
+            write!(
+                f,
+                "{}",
+                StatsReport::new(DispatchableHandle::Instance(*instance), commands)
+            )?;
+        }
+        for (device, commands) in &self.devices {
+            write!(
+                f,
+                "{}",
+                StatsReport::new(DispatchableHandle::Device(*device), commands)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A human readable report of a [`CommandStatsMap`], with the most called commands first.
+struct StatsReport<'a> {
+    owner: DispatchableHandle,
+    commands: Vec<(&'a LayerVulkanCommand, &'a CommandStats)>,
+}
+
+impl<'a> StatsReport<'a> {
+    fn new(owner: DispatchableHandle, commands: &'a CommandStatsMap) -> Self {
+        let mut commands = commands.iter().collect::<Vec<_>>();
+        commands.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.calls));
+        Self { owner, commands }
+    }
+}
+
+impl Display for StatsReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Command statistics of {:?}:", self.owner)?;
+        for (command, stats) in &self.commands {
+            write!(
+                f,
+                "  {:?}: {} calls, {} errors, layer time mean {:?} p99 < {:?}",
+                command,
+                stats.calls,
+                stats.errors,
+                stats.layer_time.mean().unwrap_or_default(),
+                stats
+                    .layer_time
+                    .quantile_upper_bound(0.99)
+                    .unwrap_or_default(),
+            )?;
+            match stats.next_layer_time.mean() {
+                Some(mean) => writeln!(
+                    f,
+                    ", next layer time mean {:?} p99 < {:?}",
+                    mean,
+                    stats
+                        .next_layer_time
+                        .quantile_upper_bound(0.99)
+                        .unwrap_or_default()
+                )?,
+                None => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The lock-free counterpart of [`LatencyHistogram`], updated concurrently by the intercepted
+/// commands.
+#[derive(Default)]
+struct AtomicLatencyHistogram {
+    buckets: [AtomicU64; LatencyHistogram::BUCKET_COUNT],
+    total_nanos: AtomicU64,
+}
+
+impl AtomicLatencyHistogram {
+    fn record(&self, duration: Duration) {
+        self.buckets[LatencyHistogram::bucket_index(duration)].fetch_add(1, Ordering::Relaxed);
+        let nanos = duration.as_nanos().try_into().unwrap_or(u64::MAX);
+        self.total_nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    fn load(&self) -> LatencyHistogram {
+        LatencyHistogram {
+            buckets: array::from_fn(|index| self.buckets[index].load(Ordering::Relaxed)),
+            total: Duration::from_nanos(self.total_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// The lock-free counterpart of [`CommandStats`].
+#[derive(Default)]
+struct AtomicCommandStats {
+    calls: AtomicU64,
+    errors: AtomicU64,
+    layer_time: AtomicLatencyHistogram,
+    next_layer_time: AtomicLatencyHistogram,
+}
+
+impl AtomicCommandStats {
+    fn load(&self) -> CommandStats {
+        CommandStats {
+            calls: self.calls.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            layer_time: self.layer_time.load(),
+            next_layer_time: self.next_layer_time.load(),
+        }
+    }
+}
+
+/// Collects the statistics of the commands called on a `VkInstance` or a `VkDevice`.
+///
+/// The statistics are stored in a fixed array indexed by the [`LayerVulkanCommand`] discriminant,
+/// and only updated with atomic operations, so concurrent commands never wait for each other. The
+/// statistics of a command are allocated on the first call, because most applications only call a
+/// small portion of the commands.
+pub(crate) struct StatsCollector {
+    owner: DispatchableHandle,
+    commands: Box<[OnceCell<Box<AtomicCommandStats>>; LayerVulkanCommand::COUNT]>,
+}
+
+impl StatsCollector {
+    pub(crate) fn new(owner: impl Into<DispatchableHandle>) -> Self {
+        Self {
+            owner: owner.into(),
+            commands: Box::new(array::from_fn(|_| OnceCell::new())),
+        }
+    }
+
+    pub(crate) fn record(
+        &self,
+        command: LayerVulkanCommand,
+        result: Option<vk::Result>,
+        layer_time: Duration,
+        next_layer_time: Option<Duration>,
+    ) {
+        let stats = self.commands[command as usize].get_or_init(Default::default);
+        stats.calls.fetch_add(1, Ordering::Relaxed);
+        if result.is_some_and(|result| result.as_raw() < 0) {
+            stats.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        stats.layer_time.record(layer_time);
+        if let Some(next_layer_time) = next_layer_time {
+            stats.next_layer_time.record(next_layer_time);
+        }
+    }
+
+    /// The statistics of every command called so far. The statistics of a command can be slightly
+    /// inconsistent, e.g. `calls` can be larger than the count of `layer_time`, if the command is
+    /// being recorded concurrently.
+    pub(crate) fn snapshot(&self) -> CommandStatsMap {
+        LayerVulkanCommand::ALL
+            .iter()
+            .zip(self.commands.iter())
+            .filter_map(|(command, stats)| Some((command.clone(), stats.get()?.load())))
+            .collect()
+    }
+
+    fn add_to_snapshot(&self, snapshot: &mut StatsSnapshot) {
+        let commands = self.snapshot();
+        match self.owner {
+            DispatchableHandle::Instance(instance) => {
+                snapshot.instances.insert(instance, commands);
+            }
+            DispatchableHandle::Device(device) => {
+                snapshot.devices.insert(device, commands);
+            }
+            _ => unreachable!("statistics are only collected per VkInstance and VkDevice"),
+        }
+    }
+
+    /// A human readable report of the statistics collected so far.
+    pub(crate) fn report(&self) -> String {
+        let commands = self.snapshot();
+        StatsReport::new(self.owner, &commands).to_string()
+    }
+}
+
+impl<T: Layer> Global<T> {
+    /// The commands intercepted for the statistics, in addition to the ones hooked by the layer
+    /// implementation.
+    pub(crate) fn command_stats_commands(&self) -> HookedCommands {
+        if self.layer_info.collect_command_stats() {
+            HookedCommands::all()
+        } else {
+            HookedCommands::default()
+        }
+    }
+
+    /// Creates the [`StatsCollector`] for a new `VkInstance` or `VkDevice` if the statistics are
+    /// enabled.
+    pub(crate) fn create_stats_collector(
+        &self,
+        owner: impl Into<DispatchableHandle>,
+    ) -> Option<StatsCollector> {
+        self.layer_info
+            .collect_command_stats()
+            .then(|| StatsCollector::new(owner))
+    }
+
+    /// The statistics of the commands called on all the alive `VkInstance`s and `VkDevice`s.
+    ///
+    /// Empty if [`Layer::collect_command_stats`] returns `false`. The statistics of a `VkInstance`
+    /// or a `VkDevice` are logged and dropped when it is destroyed.
+    pub fn stats_snapshot(&self) -> StatsSnapshot {
+        let mut snapshot = StatsSnapshot::default();
+        for instance_info in self.instance_map.values() {
+            if let Some(stats) = &instance_info.stats {
+                stats.add_to_snapshot(&mut snapshot);
+            }
+        }
+        for device_info in self.device_map.values() {
+            if let Some(stats) = &device_info.stats {
+                stats.add_to_snapshot(&mut snapshot);
+            }
+        }
+        snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_histogram_should_bucket_by_power_of_two_micros() {
+        let mut histogram = LatencyHistogram::default();
+        histogram.record(Duration::from_nanos(500));
+        histogram.record(Duration::from_micros(1));
+        histogram.record(Duration::from_micros(3));
+        histogram.record(Duration::from_secs(1 << 20));
+        let buckets = histogram.buckets().collect::<Vec<_>>();
+        assert_eq!(buckets.len(), LatencyHistogram::BUCKET_COUNT);
+        assert_eq!(buckets[0], (Duration::from_micros(1), 1));
+        assert_eq!(buckets[1], (Duration::from_micros(2), 1));
+        assert_eq!(buckets[2], (Duration::from_micros(4), 1));
+        assert_eq!(
+            buckets[LatencyHistogram::BUCKET_COUNT - 1],
+            (Duration::MAX, 1)
+        );
+        assert_eq!(histogram.count(), 4);
+        assert_eq!(
+            histogram.total(),
+            Duration::from_nanos(4_500) + Duration::from_secs(1 << 20)
+        );
+    }
+
+    #[test]
+    fn test_latency_histogram_quantile_should_return_bucket_upper_bound() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.quantile_upper_bound(0.5), None);
+        assert_eq!(histogram.mean(), None);
+        for _ in 0..99 {
+            histogram.record(Duration::from_nanos(100));
+        }
+        histogram.record(Duration::from_micros(100));
+        assert_eq!(
+            histogram.quantile_upper_bound(0.5),
+            Some(Duration::from_micros(1))
+        );
+        assert_eq!(
+            histogram.quantile_upper_bound(0.99),
+            Some(Duration::from_micros(1))
+        );
+        assert_eq!(
+            histogram.quantile_upper_bound(1.0),
+            Some(Duration::from_micros(128))
+        );
+        assert_eq!(histogram.mean(), Some(Duration::from_nanos(1_099)));
+    }
+
+    #[test]
+    fn test_all_commands_should_be_ordered_by_discriminant() {
+        for (index, command) in LayerVulkanCommand::ALL.iter().enumerate() {
+            assert_eq!(command.clone() as usize, index);
+        }
+    }
+
+    #[test]
+    fn test_stats_collector_should_count_concurrent_calls() {
+        let collector = StatsCollector::new(vk::Device::null());
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..1000 {
+                        collector.record(
+                            LayerVulkanCommand::CmdDraw,
+                            None,
+                            Duration::from_micros(1),
+                            Some(Duration::from_micros(1)),
+                        );
+                    }
+                });
+            }
+        });
+        let snapshot = collector.snapshot();
+        assert_eq!(
+            snapshot.keys().collect::<Vec<_>>(),
+            [&LayerVulkanCommand::CmdDraw]
+        );
+        let stats = &snapshot[&LayerVulkanCommand::CmdDraw];
+        assert_eq!(stats.calls, 4000);
+        assert_eq!(stats.errors, 0);
+        assert_eq!(stats.layer_time.count(), 4000);
+        assert_eq!(stats.layer_time.total(), Duration::from_millis(4));
+        assert_eq!(stats.next_layer_time.count(), 4000);
+    }
+
+    #[test]
+    fn test_stats_collector_should_count_errors() {
+        let collector = StatsCollector::new(vk::Device::null());
+        collector.record(
+            LayerVulkanCommand::QueueSubmit,
+            Some(vk::Result::SUCCESS),
+            Duration::from_micros(1),
+            Some(Duration::from_micros(2)),
+        );
+        collector.record(
+            LayerVulkanCommand::QueueSubmit,
+            Some(vk::Result::ERROR_DEVICE_LOST),
+            Duration::from_micros(1),
+            None,
+        );
+        collector.record(
+            LayerVulkanCommand::QueueSubmit,
+            Some(vk::Result::NOT_READY),
+            Duration::from_micros(1),
+            None,
+        );
+        let stats = &collector.snapshot()[&LayerVulkanCommand::QueueSubmit];
+        assert_eq!(stats.calls, 3);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.layer_time.count(), 3);
+        assert_eq!(stats.next_layer_time.count(), 1);
+    }
+}
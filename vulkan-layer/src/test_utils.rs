@@ -28,7 +28,9 @@
 //! 3. Use [`TestLayer`] as a layer implementation.
 
 use crate::{
-    DeviceInfo, Global, GlobalHooksInfo, InstanceInfo, Layer, LayerManifest, LayerVulkanCommand,
+    CommandObserver, DeviceInfo, DeviceLoaderDataCallback, FaultInjector, Global, GlobalHooksInfo,
+    InstanceInfo, Layer, LayerManifest, LayerSettings, LayerVulkanCommand, QueueProperties,
+    ThreadSafetyChecker,
 };
 use ash::vk;
 use mockall::mock;
@@ -39,7 +41,10 @@ use std::{
     collections::HashMap,
     marker::PhantomData,
     ops::Deref,
-    sync::{Arc, Mutex, MutexGuard, Weak},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard, Weak,
+    },
 };
 
 pub use crate::bindings::vk_layer::{
@@ -176,6 +181,24 @@ pub trait TestLayerMock: 'static {
 
     /// Used to mock [`DeviceInfo::hooked_commands`].
     fn hooked_device_commands(&self) -> &[LayerVulkanCommand];
+
+    /// Used to mock [`Layer::track_command_buffer_state`].
+    fn track_command_buffer_state(&self) -> bool;
+
+    /// Used to mock [`Layer::record_command_buffers`].
+    fn record_command_buffers(&self) -> bool;
+
+    /// Used to mock [`Layer::collect_command_stats`].
+    fn collect_command_stats(&self) -> bool;
+
+    /// Used to mock [`Layer::fault_injector`].
+    fn fault_injector(&self) -> Option<&'static FaultInjector>;
+
+    /// Used to mock [`Layer::thread_safety_checker`].
+    fn thread_safety_checker(&self) -> Option<&'static ThreadSafetyChecker>;
+
+    /// Used to mock [`Layer::command_observer`].
+    fn command_observer(&self) -> Option<&'static dyn CommandObserver>;
 }
 
 /// A mock struct that implements the [`GlobalHooksInfo`] trait.
@@ -209,6 +232,8 @@ impl<T: TestLayerMock> GlobalHooksInfo for MockGlobalHooksInfo<T> {
 pub struct MockInstanceInfo<T: TestLayerMock> {
     /// The mock of the [`InstanceHooks`][crate::InstanceHooks].
     pub mock_hooks: Mutex<MockInstanceHooks>,
+    /// The [`LayerSettings`] passed to [`Layer::create_instance_info`].
+    pub layer_settings: LayerSettings,
     mock_drop: Mutex<Option<MockDrop>>,
     _marker: PhantomData<fn(T)>,
 }
@@ -274,6 +299,70 @@ impl<T: TestLayerMock> DeviceInfo for MockDeviceInfo<T> {
     }
 }
 
+/// A mock struct used as the [`Layer::PhysicalDeviceInfo`] of [`TestLayer`].
+#[derive(Default)]
+pub struct MockPhysicalDeviceInfo {
+    /// The `VkPhysicalDevice` passed to [`Layer::create_physical_device_info`].
+    pub physical_device: vk::PhysicalDevice,
+    mock_drop: Mutex<Option<MockDrop>>,
+}
+
+impl MockPhysicalDeviceInfo {
+    /// Mock the drop behavior.
+    ///
+    /// The expectations can be set through the `f` argument. If this method is never called, the
+    /// struct will be dropped as if the drop is not mocked, i.e. won't check how drop is called.
+    pub fn with_mock_drop(&self, f: impl FnOnce(&mut MockDrop)) {
+        let mut mock_drop = self.mock_drop.lock().unwrap();
+        let mock_drop = mock_drop.get_or_insert_with(Default::default);
+        f(mock_drop);
+    }
+}
+
+/// A mock struct used as the [`Layer::QueueInfo`] of [`TestLayer`].
+#[derive(Default)]
+pub struct MockQueueInfo {
+    /// The `VkQueue` passed to [`Layer::create_queue_info`].
+    pub queue: vk::Queue,
+    /// The [`QueueProperties`] passed to [`Layer::create_queue_info`].
+    pub properties: Option<QueueProperties>,
+    mock_drop: Mutex<Option<MockDrop>>,
+}
+
+impl MockQueueInfo {
+    /// Mock the drop behavior.
+    ///
+    /// The expectations can be set through the `f` argument. If this method is never called, the
+    /// struct will be dropped as if the drop is not mocked, i.e. won't check how drop is called.
+    pub fn with_mock_drop(&self, f: impl FnOnce(&mut MockDrop)) {
+        let mut mock_drop = self.mock_drop.lock().unwrap();
+        let mock_drop = mock_drop.get_or_insert_with(Default::default);
+        f(mock_drop);
+    }
+}
+
+/// A mock struct used as the [`Layer::CommandBufferInfo`] of [`TestLayer`].
+#[derive(Default)]
+pub struct MockCommandBufferInfo {
+    /// The `VkCommandBuffer` passed to [`Layer::create_command_buffer_info`].
+    pub command_buffer: vk::CommandBuffer,
+    /// How many times [`Layer::reset_command_buffer_info`] is called with this info.
+    pub reset_count: AtomicUsize,
+    mock_drop: Mutex<Option<MockDrop>>,
+}
+
+impl MockCommandBufferInfo {
+    /// Mock the drop behavior.
+    ///
+    /// The expectations can be set through the `f` argument. If this method is never called, the
+    /// struct will be dropped as if the drop is not mocked, i.e. won't check how drop is called.
+    pub fn with_mock_drop(&self, f: impl FnOnce(&mut MockDrop)) {
+        let mut mock_drop = self.mock_drop.lock().unwrap();
+        let mock_drop = mock_drop.get_or_insert_with(Default::default);
+        f(mock_drop);
+    }
+}
+
 /// Test layer tags to distinguish different [`TestLayer`]. Different `I` will result in different
 /// types.
 pub struct Tag<const I: usize>;
@@ -337,6 +426,9 @@ impl<T: TestLayerTag> Layer for TestLayer<T> {
     type DeviceInfo = MockDeviceInfo<MockTestLayer<T>>;
     type InstanceInfoContainer = ArcDel<Self::InstanceInfo>;
     type DeviceInfoContainer = ArcDel<Self::DeviceInfo>;
+    type PhysicalDeviceInfo = MockPhysicalDeviceInfo;
+    type QueueInfo = MockQueueInfo;
+    type CommandBufferInfo = MockCommandBufferInfo;
 
     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
         MockTestLayer::<T>::instance()
@@ -350,6 +442,73 @@ impl<T: TestLayerTag> Layer for TestLayer<T> {
         &self.global_hooks_info
     }
 
+    fn create_physical_device_info(
+        &self,
+        _instance_info: &Self::InstanceInfo,
+        physical_device: vk::PhysicalDevice,
+        _properties: &vk::PhysicalDeviceProperties,
+    ) -> MockPhysicalDeviceInfo {
+        MockPhysicalDeviceInfo {
+            physical_device,
+            ..Default::default()
+        }
+    }
+
+    fn create_queue_info(
+        &self,
+        _device_info: &Self::DeviceInfo,
+        queue: vk::Queue,
+        properties: &QueueProperties,
+    ) -> MockQueueInfo {
+        MockQueueInfo {
+            queue,
+            properties: Some(*properties),
+            ..Default::default()
+        }
+    }
+
+    fn track_command_buffer_state(&self) -> bool {
+        MockTestLayer::<T>::mock().track_command_buffer_state()
+    }
+
+    fn record_command_buffers(&self) -> bool {
+        MockTestLayer::<T>::mock().record_command_buffers()
+    }
+
+    fn collect_command_stats(&self) -> bool {
+        MockTestLayer::<T>::mock().collect_command_stats()
+    }
+
+    fn fault_injector(&self) -> Option<&FaultInjector> {
+        MockTestLayer::<T>::mock().fault_injector()
+    }
+
+    fn thread_safety_checker(&self) -> Option<&ThreadSafetyChecker> {
+        MockTestLayer::<T>::mock().thread_safety_checker()
+    }
+
+    fn command_observer(&self) -> Option<&dyn CommandObserver> {
+        MockTestLayer::<T>::mock().command_observer()
+    }
+
+    fn create_command_buffer_info(
+        &self,
+        _device_info: &Self::DeviceInfo,
+        command_buffer: vk::CommandBuffer,
+        _allocate_info: &vk::CommandBufferAllocateInfo,
+    ) -> MockCommandBufferInfo {
+        MockCommandBufferInfo {
+            command_buffer,
+            ..Default::default()
+        }
+    }
+
+    fn reset_command_buffer_info(&self, command_buffer_info: &MockCommandBufferInfo) {
+        command_buffer_info
+            .reset_count
+            .fetch_add(1, Ordering::SeqCst);
+    }
+
     fn create_device_info(
         &self,
         _physical_device: vk::PhysicalDevice,
@@ -357,6 +516,7 @@ impl<T: TestLayerTag> Layer for TestLayer<T> {
         _allocator: Option<&vk::AllocationCallbacks>,
         device: Arc<ash::Device>,
         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        _: DeviceLoaderDataCallback,
     ) -> ArcDel<Self::DeviceInfo> {
         let device_handle = device.handle();
         let device_info = ArcDel::new(Default::default(), move |_| {
@@ -376,9 +536,14 @@ impl<T: TestLayerTag> Layer for TestLayer<T> {
         _allocator: Option<&vk::AllocationCallbacks>,
         instance: Arc<ash::Instance>,
         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+        layer_settings: &LayerSettings,
     ) -> ArcDel<Self::InstanceInfo> {
         let instance_handle = instance.handle();
-        let instance_info = ArcDel::new(Default::default(), move |_| {
+        let instance_info = MockInstanceInfo {
+            layer_settings: layer_settings.clone(),
+            ..Default::default()
+        };
+        let instance_info = ArcDel::new(instance_info, move |_| {
             let layer = &Self::global_instance().layer_info;
             layer.instances.lock().unwrap().remove(&instance_handle);
         });
@@ -403,6 +568,12 @@ mock! {
         fn hooked_global_commands(&self) -> &[LayerVulkanCommand];
         fn hooked_instance_commands(&self) -> &[LayerVulkanCommand];
         fn hooked_device_commands(&self) -> &[LayerVulkanCommand];
+        fn track_command_buffer_state(&self) -> bool;
+        fn record_command_buffers(&self) -> bool;
+        fn collect_command_stats(&self) -> bool;
+        fn fault_injector(&self) -> Option<&'static FaultInjector>;
+        fn thread_safety_checker(&self) -> Option<&'static ThreadSafetyChecker>;
+        fn command_observer(&self) -> Option<&'static dyn CommandObserver>;
     }
 }
 
@@ -415,6 +586,12 @@ impl<T: TestLayerTag> MockTestLayer<T> {
         self.expect_hooked_global_commands().return_const(vec![]);
         self.expect_hooked_instance_commands().return_const(vec![]);
         self.expect_hooked_device_commands().return_const(vec![]);
+        self.expect_track_command_buffer_state().return_const(false);
+        self.expect_record_command_buffers().return_const(false);
+        self.expect_collect_command_stats().return_const(false);
+        self.expect_fault_injector().return_const(None);
+        self.expect_thread_safety_checker().return_const(None);
+        self.expect_command_observer().return_const(None);
     }
 }
 
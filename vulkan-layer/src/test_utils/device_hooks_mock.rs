@@ -14,8 +14,8 @@
 
 use mockall::mock;
 
-use crate::{DeviceHooks, LayerResult};
-use ash::vk;
+use crate::{command_args::*, DeviceHooks, LayerResult};
+use ash::{prelude::VkResult, vk};
 
 mock! {
     pub DeviceHooks {}
@@ -24,18 +24,60 @@ mock! {
             &self,
             _image: vk::Image,
             _p_allocator: Option<&'a vk::AllocationCallbacks>,
-        ) -> LayerResult<()>;
+        ) -> LayerResult<(), DestroyImageArgs>;
+
+        fn after_destroy_image<'a>(
+            &self,
+            _image: vk::Image,
+            _p_allocator: Option<&'a vk::AllocationCallbacks>,
+        );
+
+        fn flush_mapped_memory_ranges<'a>(
+            &self,
+            _p_memory_ranges: &'a [vk::MappedMemoryRange],
+        ) -> LayerResult<VkResult<()>, FlushMappedMemoryRangesArgs>;
+
+        fn after_flush_mapped_memory_ranges<'a>(
+            &self,
+            _p_memory_ranges: &'a [vk::MappedMemoryRange],
+            _result: vk::Result,
+        );
 
         fn destroy_sampler_ycbcr_conversion<'a>(
             &self,
             _ycbcr_conversion: vk::SamplerYcbcrConversion,
             _p_allocator: Option<&'a vk::AllocationCallbacks>,
-        ) -> LayerResult<()>;
+        ) -> LayerResult<(), DestroySamplerYcbcrConversionArgs>;
 
         fn destroy_swapchain_khr<'a>(
             &self,
             _swapchain: vk::SwapchainKHR,
             _p_allocator: Option<&'a vk::AllocationCallbacks>,
-        ) -> LayerResult<()>;
+        ) -> LayerResult<(), DestroySwapchainKhrArgs>;
+
+        fn acquire_next_image_khr(
+            &self,
+            _swapchain: vk::SwapchainKHR,
+            _timeout: u64,
+            _semaphore: vk::Semaphore,
+            _fence: vk::Fence,
+        ) -> LayerResult<VkResult<u32>, AcquireNextImageKhrArgs>;
+
+        fn after_acquire_next_image_khr(
+            &self,
+            _swapchain: vk::SwapchainKHR,
+            _timeout: u64,
+            _semaphore: vk::Semaphore,
+            _fence: vk::Fence,
+            _p_image_index: Option<u32>,
+            _result: vk::Result,
+        );
+
+        fn cmd_set_viewport<'a>(
+            &self,
+            _command_buffer: vk::CommandBuffer,
+            _first_viewport: u32,
+            _p_viewports: &'a [vk::Viewport],
+        ) -> LayerResult<(), CmdSetViewportArgs>;
     }
 }
@@ -14,8 +14,12 @@
 
 use mockall::mock;
 
-use crate::{GlobalHooks, LayerResult, VkLayerInstanceLink};
+use crate::{
+    GlobalHooks, LayerResult, VkEnumerateInstanceExtensionPropertiesChain,
+    VkEnumerateInstanceLayerPropertiesChain, VkEnumerateInstanceVersionChain, VkLayerInstanceLink,
+};
 use ash::{prelude::VkResult, vk};
+use std::{ffi::CStr, mem::MaybeUninit};
 
 mock! {
     pub GlobalHooks {}
@@ -27,5 +31,23 @@ mock! {
             _p_allocator: Option<&'a vk::AllocationCallbacks>,
             _p_instance: *mut vk::Instance,
         ) -> LayerResult<VkResult<()>>;
+        fn enumerate_instance_version(
+            &self,
+            _chain: &VkEnumerateInstanceVersionChain,
+            _p_api_version: &mut MaybeUninit<u32>,
+        ) -> LayerResult<vk::Result>;
+        fn enumerate_instance_extension_properties<'a, 'b>(
+            &self,
+            _chain: &VkEnumerateInstanceExtensionPropertiesChain,
+            _p_layer_name: Option<&'a CStr>,
+            _p_property_count: &mut MaybeUninit<u32>,
+            _p_properties: Option<&'b mut [MaybeUninit<vk::ExtensionProperties>]>,
+        ) -> LayerResult<vk::Result>;
+        fn enumerate_instance_layer_properties<'a>(
+            &self,
+            _chain: &VkEnumerateInstanceLayerPropertiesChain,
+            _p_property_count: &mut MaybeUninit<u32>,
+            _p_properties: Option<&'a mut [MaybeUninit<vk::LayerProperties>]>,
+        ) -> LayerResult<vk::Result>;
     }
 }
@@ -16,7 +16,7 @@ use std::mem::MaybeUninit;
 
 use mockall::mock;
 
-use crate::{InstanceHooks, LayerResult, VkLayerDeviceLink};
+use crate::{command_args::*, InstanceHooks, LayerResult, VkLayerDeviceLink};
 use ash::{prelude::VkResult, vk};
 
 // We don't automock the original trait, because that hurts compilation speed significantly.
@@ -27,7 +27,7 @@ mock! {
             &self,
             surface: vk::SurfaceKHR,
             p_allocator: Option<&'a vk::AllocationCallbacks>,
-        ) -> LayerResult<()>;
+        ) -> LayerResult<(), DestroySurfaceKhrArgs>;
 
         fn create_device<'a>(
             &self,
@@ -37,5 +37,22 @@ mock! {
             _p_allocator: Option<&'a vk::AllocationCallbacks>,
             _p_device: &mut MaybeUninit<vk::Device>,
         ) -> LayerResult<VkResult<()>>;
+
+        fn after_create_device<'a, 'b>(
+            &self,
+            _physical_device: vk::PhysicalDevice,
+            _p_create_info: &vk::DeviceCreateInfo,
+            _layer_device_link: &VkLayerDeviceLink,
+            _p_allocator: Option<&'a vk::AllocationCallbacks>,
+            _p_device: Option<&'b vk::Device>,
+            _result: vk::Result,
+        );
+
+        fn after_get_physical_device_queue_family_properties<'a, 'b>(
+            &self,
+            _physical_device: vk::PhysicalDevice,
+            _p_queue_family_property_count: &'a u32,
+            _p_queue_family_properties: Option<&'b [vk::QueueFamilyProperties]>,
+        );
     }
 }
@@ -0,0 +1,630 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks the external synchronization requirements of the Vulkan commands.
+//!
+//! vk.xml marks the parameters that the application must synchronize externally with the
+//! `externsync` attribute, e.g. the command buffer of all the `vkCmd*` commands, and the queue and
+//! the fence of `vkQueueSubmit`. The attributes are available through
+//! [`LayerVulkanCommand::extern_sync_params`].
+//!
+//! The checker is provided by [`Layer::thread_safety_checker`]. The layer framework then intercepts
+//! all the commands with handle parameters even if the layer implementation doesn't hook them, and
+//! records which threads use each of the handles for the whole command. The externally
+//! synchronized handles are written by the command, and the other handles are only read. A
+//! [`ThreadSafetyViolation`] is reported when a thread writes a handle while another thread reads
+//! or writes it, e.g. when `vkDestroyFence` races `vkWaitForFences` on the same fence. This is
+//! similar to the thread safety check of the Vulkan validation layers, with some limitations:
+//!
+//! * Only the commands that the layer framework intercepts through the generated command tables
+//!   are checked, e.g. `vkDestroyDevice` is not checked.
+//! * The reads of the `VkInstance`, `VkPhysicalDevice` and `VkDevice` parameters are not tracked,
+//!   because they are only written by `vkDestroyInstance` and `vkDestroyDevice`.
+//! * The handles only read from structs, e.g. the command buffers in the `VkSubmitInfo`s of
+//!   `vkQueueSubmit`, are not tracked.
+//! * The handles that are only implicitly synchronized, e.g. all the `VkQueue`s of a `VkDevice` in
+//!   `vkDeviceWaitIdle`, are not checked.
+//! * The handles nested deeper than one level of structs, the ones in the `pNext` chains, and the
+//!   ones identified by a `uint64_t` instead of a handle type are not checked.
+
+use ash::vk::{self, Handle};
+use log::error;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    sync::Mutex,
+    thread::{self, ThreadId},
+};
+
+use crate::{Global, Layer, LayerVulkanCommand};
+
+/// Which handles of an [`ExternSyncParam`] are externally synchronized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExternSync {
+    /// `externsync="true"`: the handle passed as the parameter, or all the handles in the array
+    /// that the parameter points to.
+    Param,
+    /// The handles in the structs that the parameter points to, as the expressions in vk.xml, e.g.
+    /// `pAllocateInfo->commandPool` or `pDescriptorWrites[].dstSet`.
+    Members(&'static [&'static str]),
+}
+
+/// A parameter of a Vulkan command annotated with `externsync` in vk.xml.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExternSyncParam {
+    /// The name of the parameter in vk.xml, e.g. `commandBuffer`.
+    pub name: &'static str,
+    /// Which handles of the parameter are externally synchronized.
+    pub extern_sync: ExternSync,
+}
+
+/// An externally synchronized Vulkan handle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExternSyncObject {
+    /// The type of the handle.
+    pub object_type: vk::ObjectType,
+    /// The raw value of the handle.
+    pub handle: u64,
+}
+
+impl ExternSyncObject {
+    /// Creates an [`ExternSyncObject`] from a Vulkan handle.
+    pub fn new<H: Handle>(handle: H) -> Self {
+        Self {
+            object_type: H::TYPE,
+            handle: handle.as_raw(),
+        }
+    }
+}
+
+impl Display for ExternSyncObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} 0x{:x}", self.object_type, self.handle)
+    }
+}
+
+/// How a command uses a handle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectAccess {
+    /// The handle is only read, e.g. the fences of `vkWaitForFences`. Any number of threads can
+    /// read the same handle at the same time.
+    Read,
+    /// The handle is externally synchronized, e.g. the fence of `vkDestroyFence`. No other thread
+    /// can use the handle at the same time.
+    Write,
+}
+
+impl ObjectAccess {
+    fn verb(self) -> &'static str {
+        match self {
+            Self::Read => "reads",
+            Self::Write => "writes",
+        }
+    }
+}
+
+/// A handle written by a thread while another thread uses it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThreadSafetyViolation {
+    /// The handle used by both threads.
+    pub object: ExternSyncObject,
+    /// The command that started to use the handle while the other thread was using it.
+    pub command: LayerVulkanCommand,
+    /// How [`ThreadSafetyViolation::command`] uses the handle.
+    pub access: ObjectAccess,
+    /// The thread that called [`ThreadSafetyViolation::command`].
+    pub thread: ThreadId,
+    /// The command that was using the handle.
+    pub other_command: LayerVulkanCommand,
+    /// How [`ThreadSafetyViolation::other_command`] uses the handle.
+    pub other_access: ObjectAccess,
+    /// The thread that called [`ThreadSafetyViolation::other_command`].
+    pub other_thread: ThreadId,
+}
+
+impl Display for ThreadSafetyViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} on thread {:?} {} {}, while {:?} on thread {:?} {} it",
+            self.command,
+            self.thread,
+            self.access.verb(),
+            self.object,
+            self.other_command,
+            self.other_thread,
+            self.other_access.verb()
+        )
+    }
+}
+
+struct ObjectUse {
+    thread: ThreadId,
+    command: LayerVulkanCommand,
+    // The same thread may use the same handle more than once at the same time, e.g. when the
+    // layer implementation calls another command on the handle from a hook.
+    count: usize,
+}
+
+/// The threads using a handle.
+#[derive(Default)]
+struct ObjectUses {
+    writer: Option<ObjectUse>,
+    // One entry per thread. Usually short, because few threads read the same handle at the same
+    // time.
+    readers: Vec<ObjectUse>,
+}
+
+impl ObjectUses {
+    /// The use on another thread that conflicts with using the handle with `access` on `thread`.
+    fn find_conflict(
+        &self,
+        thread: ThreadId,
+        access: ObjectAccess,
+    ) -> Option<(&ObjectUse, ObjectAccess)> {
+        if let Some(writer) = self
+            .writer
+            .as_ref()
+            .filter(|writer| writer.thread != thread)
+        {
+            return Some((writer, ObjectAccess::Write));
+        }
+        if access == ObjectAccess::Read {
+            return None;
+        }
+        self.readers
+            .iter()
+            .find(|reader| reader.thread != thread)
+            .map(|reader| (reader, ObjectAccess::Read))
+    }
+
+    fn start_use(&mut self, thread: ThreadId, command: &LayerVulkanCommand, access: ObjectAccess) {
+        let object_use = match access {
+            ObjectAccess::Write => self.writer.get_or_insert_with(|| ObjectUse {
+                thread,
+                command: command.clone(),
+                count: 0,
+            }),
+            ObjectAccess::Read => {
+                match self
+                    .readers
+                    .iter()
+                    .position(|reader| reader.thread == thread)
+                {
+                    Some(index) => &mut self.readers[index],
+                    None => {
+                        self.readers.push(ObjectUse {
+                            thread,
+                            command: command.clone(),
+                            count: 0,
+                        });
+                        self.readers.last_mut().unwrap()
+                    }
+                }
+            }
+        };
+        object_use.count += 1;
+    }
+
+    fn finish_use(&mut self, thread: ThreadId, access: ObjectAccess) {
+        match access {
+            ObjectAccess::Write => {
+                let Some(writer) = &mut self.writer else {
+                    return;
+                };
+                writer.count -= 1;
+                if writer.count == 0 {
+                    self.writer = None;
+                }
+            }
+            ObjectAccess::Read => {
+                let Some(index) = self
+                    .readers
+                    .iter()
+                    .position(|reader| reader.thread == thread)
+                else {
+                    return;
+                };
+                self.readers[index].count -= 1;
+                if self.readers[index].count == 0 {
+                    self.readers.swap_remove(index);
+                }
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.writer.is_none() && self.readers.is_empty()
+    }
+}
+
+/// The number of shards is `1 << SHARD_BITS`.
+const SHARD_BITS: u32 = 6;
+const SHARD_COUNT: usize = 1 << SHARD_BITS;
+
+/// A part of the handles in use, aligned to the cache line, so that commands using different
+/// handles on different threads usually don't touch the same lock.
+#[derive(Default)]
+#[repr(align(64))]
+struct Shard(Mutex<HashMap<ExternSyncObject, ObjectUses>>);
+
+/// Records which threads use each handle, and reports the handles written by a thread while
+/// another thread uses them.
+///
+/// The handles are spread among a fixed number of shards by the hash of the handle, each with its
+/// own lock, so that commands using different handles rarely wait for each other. Every violation
+/// is logged as an error, and kept until [`ThreadSafetyChecker::take_violations`] is called.
+pub struct ThreadSafetyChecker {
+    shards: Box<[Shard]>,
+    violations: Mutex<Vec<ThreadSafetyViolation>>,
+}
+
+impl Default for ThreadSafetyChecker {
+    fn default() -> Self {
+        Self {
+            shards: (0..SHARD_COUNT).map(|_| Shard::default()).collect(),
+            violations: Default::default(),
+        }
+    }
+}
+
+impl ThreadSafetyChecker {
+    /// Creates a [`ThreadSafetyChecker`] without any handle in use.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Takes the violations reported since the last call.
+    pub fn take_violations(&self) -> Vec<ThreadSafetyViolation> {
+        std::mem::take(&mut *self.violations.lock().unwrap())
+    }
+
+    fn shard(&self, object: &ExternSyncObject) -> &Mutex<HashMap<ExternSyncObject, ObjectUses>> {
+        // Handles are usually aligned pointers, so the high bits of the product are used.
+        let hash = object.handle.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        &self.shards[(hash >> (u64::BITS - SHARD_BITS)) as usize].0
+    }
+
+    /// Marks `objects` as used by `command` on the current thread until the returned guard is
+    /// dropped. The objects whose use conflicts with another thread are reported, and not marked.
+    pub(crate) fn start_use(
+        &self,
+        command: LayerVulkanCommand,
+        objects: ExternSyncObjects,
+    ) -> ExternSyncGuard<'_> {
+        let current_thread = thread::current().id();
+        let mut used_objects = Vec::with_capacity(objects.0.len());
+        let mut violations = vec![];
+        for (object, access) in objects.0 {
+            let mut object_uses = self.shard(&object).lock().unwrap();
+            let uses = object_uses.entry(object).or_default();
+            if let Some((other_use, other_access)) = uses.find_conflict(current_thread, access) {
+                violations.push(ThreadSafetyViolation {
+                    object,
+                    command: command.clone(),
+                    access,
+                    thread: current_thread,
+                    other_command: other_use.command.clone(),
+                    other_access,
+                    other_thread: other_use.thread,
+                });
+                continue;
+            }
+            uses.start_use(current_thread, &command, access);
+            used_objects.push((object, access));
+        }
+        if !violations.is_empty() {
+            for violation in &violations {
+                error!("Thread safety violation: {}", violation);
+            }
+            self.violations.lock().unwrap().extend(violations);
+        }
+        ExternSyncGuard {
+            checker: self,
+            thread: current_thread,
+            objects: used_objects,
+        }
+    }
+
+    fn finish_use(&self, thread: ThreadId, objects: &[(ExternSyncObject, ObjectAccess)]) {
+        for (object, access) in objects {
+            let mut object_uses = self.shard(object).lock().unwrap();
+            let Some(uses) = object_uses.get_mut(object) else {
+                continue;
+            };
+            uses.finish_use(thread, *access);
+            if uses.is_empty() {
+                object_uses.remove(object);
+            }
+        }
+    }
+}
+
+/// The handles passed to a command, and how the command uses them. Null handles are ignored.
+#[derive(Default)]
+pub(crate) struct ExternSyncObjects(Vec<(ExternSyncObject, ObjectAccess)>);
+
+impl ExternSyncObjects {
+    fn push(&mut self, handle: impl Handle, access: ObjectAccess) {
+        let object = ExternSyncObject::new(handle);
+        if object.handle != 0 {
+            self.0.push((object, access));
+        }
+    }
+
+    /// Adds an externally synchronized handle.
+    pub(crate) fn add(&mut self, handle: impl Handle) {
+        self.push(handle, ObjectAccess::Write);
+    }
+
+    /// Adds externally synchronized handles.
+    pub(crate) fn add_all<H: Handle>(&mut self, handles: impl IntoIterator<Item = H>) {
+        for handle in handles {
+            self.add(handle);
+        }
+    }
+
+    /// Adds a handle only read by the command.
+    pub(crate) fn add_read(&mut self, handle: impl Handle) {
+        self.push(handle, ObjectAccess::Read);
+    }
+
+    /// Adds handles only read by the command.
+    pub(crate) fn add_all_read<H: Handle>(&mut self, handles: impl IntoIterator<Item = H>) {
+        for handle in handles {
+            self.add_read(handle);
+        }
+    }
+}
+
+/// Marks the handles as no longer used by the current thread when dropped. Created by
+/// [`ThreadSafetyChecker::start_use`].
+pub(crate) struct ExternSyncGuard<'a> {
+    checker: &'a ThreadSafetyChecker,
+    thread: ThreadId,
+    objects: Vec<(ExternSyncObject, ObjectAccess)>,
+}
+
+impl Drop for ExternSyncGuard<'_> {
+    fn drop(&mut self) {
+        self.checker.finish_use(self.thread, &self.objects);
+    }
+}
+
+impl<T: Layer> Global<T> {
+    /// The commands intercepted for the thread safety check, in addition to the ones hooked by the
+    /// layer implementation.
+    pub(crate) fn extern_sync_commands(&self) -> impl Iterator<Item = LayerVulkanCommand> {
+        let enabled = self.layer_info.thread_safety_checker().is_some();
+        Self::EXTERN_SYNC_COMMANDS
+            .iter()
+            .filter(move |_| enabled)
+            .cloned()
+    }
+
+    /// Marks the handles of `command` as used by the current thread until the returned guard is
+    /// dropped. `add_objects` adds the handles, and is only called if the
+    /// thread safety check is enabled.
+    pub(crate) fn enter_extern_sync(
+        &self,
+        command: LayerVulkanCommand,
+        add_objects: impl FnOnce(&mut ExternSyncObjects),
+    ) -> Option<ExternSyncGuard<'_>> {
+        let checker = self.layer_info.thread_safety_checker()?;
+        let mut objects = ExternSyncObjects::default();
+        add_objects(&mut objects);
+        Some(checker.start_use(command, objects))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn objects(handles: &[vk::Fence]) -> ExternSyncObjects {
+        let mut objects = ExternSyncObjects::default();
+        objects.add_all(handles.iter().copied());
+        objects
+    }
+
+    fn read_objects(handles: &[vk::Fence]) -> ExternSyncObjects {
+        let mut objects = ExternSyncObjects::default();
+        objects.add_all_read(handles.iter().copied());
+        objects
+    }
+
+    fn is_idle(checker: &ThreadSafetyChecker) -> bool {
+        checker
+            .shards
+            .iter()
+            .all(|shard| shard.0.lock().unwrap().is_empty())
+    }
+
+    fn use_on_other_thread(
+        checker: &ThreadSafetyChecker,
+        command: LayerVulkanCommand,
+        objects: ExternSyncObjects,
+    ) -> ThreadId {
+        thread::scope(|s| {
+            s.spawn(|| {
+                let _guard = checker.start_use(command, objects);
+                thread::current().id()
+            })
+            .join()
+            .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_should_report_objects_used_by_two_threads() {
+        let checker = ThreadSafetyChecker::new();
+        let fences = [vk::Fence::from_raw(1), vk::Fence::from_raw(2)];
+        let guard = checker.start_use(LayerVulkanCommand::ResetFences, objects(&fences));
+        let main_thread = thread::current().id();
+        let other_thread = thread::scope(|s| {
+            s.spawn(|| {
+                let _guard = checker.start_use(
+                    LayerVulkanCommand::DestroyFence,
+                    objects(&[fences[1], vk::Fence::from_raw(3)]),
+                );
+                thread::current().id()
+            })
+            .join()
+            .unwrap()
+        });
+        assert_eq!(
+            checker.take_violations(),
+            vec![ThreadSafetyViolation {
+                object: ExternSyncObject::new(fences[1]),
+                command: LayerVulkanCommand::DestroyFence,
+                access: ObjectAccess::Write,
+                thread: other_thread,
+                other_command: LayerVulkanCommand::ResetFences,
+                other_access: ObjectAccess::Write,
+                other_thread: main_thread,
+            }]
+        );
+        assert!(checker.take_violations().is_empty());
+
+        // The objects can be used by another thread once the guard is dropped.
+        drop(guard);
+        thread::scope(|s| {
+            s.spawn(|| {
+                let _guard = checker.start_use(LayerVulkanCommand::DestroyFence, objects(&fences));
+            });
+        });
+        assert!(checker.take_violations().is_empty());
+    }
+
+    #[test]
+    fn test_should_allow_nested_use_on_the_same_thread() {
+        let checker = ThreadSafetyChecker::new();
+        let fence = vk::Fence::from_raw(1);
+        let outer_guard = checker.start_use(LayerVulkanCommand::ResetFences, objects(&[fence]));
+        let inner_guard =
+            checker.start_use(LayerVulkanCommand::DestroyFence, objects(&[fence, fence]));
+        drop(inner_guard);
+        // Still used by the outer command.
+        thread::scope(|s| {
+            s.spawn(|| {
+                let _guard = checker.start_use(LayerVulkanCommand::DestroyFence, objects(&[fence]));
+            });
+        });
+        assert_eq!(checker.take_violations().len(), 1);
+        drop(outer_guard);
+        assert!(is_idle(&checker));
+    }
+
+    #[test]
+    fn test_should_report_write_while_other_thread_reads() {
+        let checker = ThreadSafetyChecker::new();
+        let fence = vk::Fence::from_raw(1);
+        let guard = checker.start_use(LayerVulkanCommand::WaitForFences, read_objects(&[fence]));
+        let other_thread = use_on_other_thread(
+            &checker,
+            LayerVulkanCommand::DestroyFence,
+            objects(&[fence]),
+        );
+        assert_eq!(
+            checker.take_violations(),
+            vec![ThreadSafetyViolation {
+                object: ExternSyncObject::new(fence),
+                command: LayerVulkanCommand::DestroyFence,
+                access: ObjectAccess::Write,
+                thread: other_thread,
+                other_command: LayerVulkanCommand::WaitForFences,
+                other_access: ObjectAccess::Read,
+                other_thread: thread::current().id(),
+            }]
+        );
+        drop(guard);
+        assert!(is_idle(&checker));
+    }
+
+    #[test]
+    fn test_should_report_read_while_other_thread_writes() {
+        let checker = ThreadSafetyChecker::new();
+        let fence = vk::Fence::from_raw(1);
+        let guard = checker.start_use(LayerVulkanCommand::DestroyFence, objects(&[fence]));
+        let other_thread = use_on_other_thread(
+            &checker,
+            LayerVulkanCommand::GetFenceStatus,
+            read_objects(&[fence]),
+        );
+        let violations = checker.take_violations();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].access, ObjectAccess::Read);
+        assert_eq!(violations[0].thread, other_thread);
+        assert_eq!(violations[0].other_access, ObjectAccess::Write);
+        drop(guard);
+        assert!(is_idle(&checker));
+    }
+
+    #[test]
+    fn test_should_allow_reads_on_multiple_threads() {
+        let checker = ThreadSafetyChecker::new();
+        let fences = [vk::Fence::from_raw(1), vk::Fence::from_raw(2)];
+        let guard = checker.start_use(LayerVulkanCommand::WaitForFences, read_objects(&fences));
+        use_on_other_thread(
+            &checker,
+            LayerVulkanCommand::GetFenceStatus,
+            read_objects(&fences[1..]),
+        );
+        assert!(checker.take_violations().is_empty());
+
+        // The same thread can write the handle it reads, e.g. from a hook.
+        let inner_guard = checker.start_use(LayerVulkanCommand::ResetFences, objects(&fences));
+        assert!(checker.take_violations().is_empty());
+        drop(inner_guard);
+        drop(guard);
+        assert!(is_idle(&checker));
+    }
+
+    #[test]
+    fn test_extern_sync_params_should_follow_vk_xml() {
+        assert_eq!(
+            LayerVulkanCommand::QueueSubmit.extern_sync_params(),
+            &[
+                ExternSyncParam {
+                    name: "queue",
+                    extern_sync: ExternSync::Param,
+                },
+                ExternSyncParam {
+                    name: "fence",
+                    extern_sync: ExternSync::Param,
+                },
+            ]
+        );
+        assert_eq!(
+            LayerVulkanCommand::AllocateCommandBuffers.extern_sync_params(),
+            &[ExternSyncParam {
+                name: "pAllocateInfo",
+                extern_sync: ExternSync::Members(&["pAllocateInfo->commandPool"]),
+            }]
+        );
+        assert!(LayerVulkanCommand::GetDeviceQueue
+            .extern_sync_params()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_should_ignore_null_handles() {
+        let checker = ThreadSafetyChecker::new();
+        let _guard = checker.start_use(
+            LayerVulkanCommand::ResetFences,
+            objects(&[vk::Fence::null()]),
+        );
+        assert!(is_idle(&checker));
+    }
+}
@@ -18,9 +18,11 @@ use once_cell::sync::Lazy;
 use std::{marker::PhantomData, sync::Arc};
 use vulkan_layer::{
     auto_deviceinfo_impl, auto_globalhooksinfo_impl, auto_instanceinfo_impl,
-    test_utils::LayerManifestExt, DeviceHooks, DeviceInfo, Global, GlobalHooks, GlobalHooksInfo,
-    InstanceHooks, InstanceInfo, Layer, LayerManifest, LayerResult, LayerVulkanCommand,
-    StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
+    command_args::{DestroyImageArgs, DestroySurfaceKhrArgs},
+    test_utils::LayerManifestExt,
+    DeviceHooks, DeviceInfo, DeviceLoaderDataCallback, Global, GlobalHooks, GlobalHooksInfo,
+    InstanceHooks, InstanceInfo, Layer, LayerManifest, LayerResult, LayerSettings,
+    LayerVulkanCommand, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
 };
 
 #[automock]
@@ -50,6 +52,9 @@ where
     type DeviceInfo = V;
     type InstanceInfoContainer = U;
     type DeviceInfoContainer = V;
+    type PhysicalDeviceInfo = ();
+    type QueueInfo = ();
+    type CommandBufferInfo = ();
 
     fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
         MockGlobalInstanceProvider::<Self>::instance()
@@ -70,6 +75,7 @@ where
         _: Option<&vk::AllocationCallbacks>,
         _: Arc<ash::Device>,
         _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        _: DeviceLoaderDataCallback,
     ) -> Self::DeviceInfoContainer {
         Default::default()
     }
@@ -80,6 +86,7 @@ where
         _: Option<&vk::AllocationCallbacks>,
         _: Arc<ash::Instance>,
         _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+        _: &LayerSettings,
     ) -> Self::InstanceInfoContainer {
         Default::default()
     }
@@ -123,7 +130,7 @@ fn test_auto_instanceinfo_should_intercept_hooked_proc() {
             &self,
             _: vk::SurfaceKHR,
             _: Option<&vk::AllocationCallbacks>,
-        ) -> LayerResult<()> {
+        ) -> LayerResult<(), DestroySurfaceKhrArgs> {
             unimplemented!()
         }
     }
@@ -148,7 +155,7 @@ fn test_auto_deviceinfo_should_intercept_hooked_proc() {
             &self,
             _image: vk::Image,
             _p_allocator: Option<&vk::AllocationCallbacks>,
-        ) -> LayerResult<()> {
+        ) -> LayerResult<(), DestroyImageArgs> {
             unimplemented!()
         }
     }
@@ -158,3 +165,52 @@ fn test_auto_deviceinfo_should_intercept_hooked_proc() {
         .collect::<Vec<_>>();
     assert_eq!(hooked_commands, &[LayerVulkanCommand::DestroyImage]);
 }
+
+#[test]
+fn test_auto_deviceinfo_should_intercept_commands_with_after_hooks() {
+    type MockLayer = TestLayer<StubGlobalHooks, StubInstanceInfo, TestDeviceInfo>;
+    static GLOBAL: Lazy<Global<MockLayer>> = Lazy::new(Default::default);
+    let ctx = MockGlobalInstanceProvider::instance_context();
+    ctx.expect().return_const(&*GLOBAL);
+    #[derive(Default)]
+    struct TestDeviceInfo;
+    #[auto_deviceinfo_impl]
+    impl DeviceHooks for TestDeviceInfo {
+        fn destroy_image(
+            &self,
+            _image: vk::Image,
+            _p_allocator: Option<&vk::AllocationCallbacks>,
+        ) -> LayerResult<(), DestroyImageArgs> {
+            unimplemented!()
+        }
+
+        fn after_destroy_image(
+            &self,
+            _image: vk::Image,
+            _p_allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            unimplemented!()
+        }
+
+        fn after_create_image(
+            &self,
+            _p_create_info: &vk::ImageCreateInfo,
+            _p_allocator: Option<&vk::AllocationCallbacks>,
+            _p_image: Option<vk::Image>,
+            _result: vk::Result,
+        ) {
+            unimplemented!()
+        }
+    }
+    let hooked_commands = MockLayer::global_instance()
+        .layer_info
+        .hooked_device_commands(&Default::default(), None)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hooked_commands,
+        &[
+            LayerVulkanCommand::DestroyImage,
+            LayerVulkanCommand::CreateImage
+        ]
+    );
+}
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::ffi::{c_char, CStr, CString};
+use std::{
+    ffi::{c_char, CStr, CString},
+    mem::MaybeUninit,
+};
 
 use ash::vk::{self};
 
 use mockall::automock;
+use vulkan_layer::{
+    VkEnumerateInstanceExtensionPropertiesChain, VkEnumerateInstanceLayerPropertiesChain,
+    VkEnumerateInstanceVersionChain, VkNegotiateLayerInterface,
+};
 use vulkan_layer_macros::declare_introspection_queries;
 
 #[test]
@@ -51,6 +58,28 @@ fn test_declare_introspection_queries_should_just_forward_to_global() {
             device: vk::Device,
             p_name: *const c_char,
         ) -> vk::PFN_vkVoidFunction;
+        unsafe extern "system" fn get_physical_device_proc_addr(
+            instance: vk::Instance,
+            p_name: *const c_char,
+        ) -> vk::PFN_vkVoidFunction;
+        unsafe extern "system" fn negotiate_loader_layer_interface_version(
+            p_version_struct: *mut VkNegotiateLayerInterface,
+        ) -> vk::Result;
+        unsafe extern "system" fn pre_instance_enumerate_instance_version(
+            p_chain: *const VkEnumerateInstanceVersionChain,
+            p_api_version: *mut u32,
+        ) -> vk::Result;
+        unsafe extern "system" fn pre_instance_enumerate_instance_extension_properties(
+            p_chain: *const VkEnumerateInstanceExtensionPropertiesChain,
+            p_layer_name: *const c_char,
+            p_property_count: *mut u32,
+            p_properties: *mut vk::ExtensionProperties,
+        ) -> vk::Result;
+        unsafe extern "system" fn pre_instance_enumerate_instance_layer_properties(
+            p_chain: *const VkEnumerateInstanceLayerPropertiesChain,
+            p_property_count: *mut u32,
+            p_properties: *mut vk::LayerProperties,
+        ) -> vk::Result;
     }
 
     declare_introspection_queries!(MockGlobal);
@@ -188,8 +217,8 @@ fn test_declare_introspection_queries_should_just_forward_to_global() {
             .return_const(fake_create_instance_fp);
 
         assert_eq!(
-            unsafe { vkGetInstanceProcAddr(instance, name.as_ptr()) },
-            fake_create_instance_fp
+            unsafe { vkGetInstanceProcAddr(instance, name.as_ptr()) }.map(|fp| fp as usize),
+            fake_create_instance_fp.map(|fp| fp as usize)
         );
     }
     {
@@ -223,8 +252,160 @@ fn test_declare_introspection_queries_should_just_forward_to_global() {
             .return_const(fake_allocate_memory_fp);
 
         assert_eq!(
-            unsafe { vkGetDeviceProcAddr(device, name.as_ptr()) },
-            fake_allocate_memory_fp
+            unsafe { vkGetDeviceProcAddr(device, name.as_ptr()) }.map(|fp| fp as usize),
+            fake_allocate_memory_fp.map(|fp| fp as usize)
+        );
+    }
+    {
+        // We use transmute instead of Handle::from_raw here to avoid integer to pointer cast, and
+        // allow the miri tests with tree borrows to work with this test. See
+        // https://github.com/ash-rs/ash/issues/996 for details.
+        let instance =
+            unsafe { std::mem::transmute::<*const u8, vk::Instance>(std::ptr::dangling()) };
+        let name = CString::new("vkGetPhysicalDeviceFeatures2").unwrap();
+        extern "system" fn fake_get_physical_device_features2(
+            _: vk::PhysicalDevice,
+            _: *mut vk::PhysicalDeviceFeatures2,
+        ) {
+            unimplemented!()
+        }
+        let fake_get_physical_device_features2_fp: vk::PFN_vkVoidFunction = unsafe {
+            std::mem::transmute(
+                fake_get_physical_device_features2 as vk::PFN_vkGetPhysicalDeviceFeatures2,
+            )
+        };
+
+        let ctx = MockGlobal::get_physical_device_proc_addr_context();
+        ctx.expect()
+            .once()
+            .withf_st({
+                let expect_instance = instance;
+                let expect_name = name.clone();
+                move |instance, name| {
+                    let name = unsafe { CStr::from_ptr(*name) };
+                    (*instance, name) == (expect_instance, expect_name.as_c_str())
+                }
+            })
+            .return_const(fake_get_physical_device_features2_fp);
+
+        assert_eq!(
+            unsafe { vk_layerGetPhysicalDeviceProcAddr(instance, name.as_ptr()) }
+                .map(|fp| fp as usize),
+            fake_get_physical_device_features2_fp.map(|fp| fp as usize)
+        );
+    }
+    {
+        // The mock never dereferences the pointer, so it's fine to leave the struct uninitialized.
+        let mut version_struct = MaybeUninit::<VkNegotiateLayerInterface>::uninit();
+        let return_val = vk::Result::ERROR_INITIALIZATION_FAILED;
+
+        let ctx = MockGlobal::negotiate_loader_layer_interface_version_context();
+        ctx.expect()
+            .once()
+            .withf_st({
+                let expect_p_version_struct = version_struct.as_mut_ptr();
+                move |p_version_struct| *p_version_struct == expect_p_version_struct
+            })
+            .return_const(return_val);
+
+        assert_eq!(
+            unsafe { vkNegotiateLoaderLayerInterfaceVersion(version_struct.as_mut_ptr()) },
+            return_val
+        );
+    }
+    {
+        let chain = VkEnumerateInstanceVersionChain::default();
+        let mut api_version: u32 = 0;
+        let return_val = vk::Result::ERROR_OUT_OF_HOST_MEMORY;
+
+        let ctx = MockGlobal::pre_instance_enumerate_instance_version_context();
+        ctx.expect()
+            .once()
+            .withf_st({
+                let expect_p_chain = &chain as *const _;
+                let expect_p_api_version = &mut api_version as *mut _;
+                move |p_chain, p_api_version| {
+                    (*p_chain, *p_api_version) == (expect_p_chain, expect_p_api_version)
+                }
+            })
+            .return_const(return_val);
+
+        assert_eq!(
+            unsafe { vk_layerEnumerateInstanceVersion(&chain, &mut api_version) },
+            return_val
+        );
+    }
+    {
+        const COUNT: usize = 2;
+        let chain = VkEnumerateInstanceExtensionPropertiesChain::default();
+        let layer_name = CString::new("VK_LAYER_fake").unwrap();
+        let mut properties: [vk::ExtensionProperties; COUNT] = Default::default();
+        let mut out_count: u32 = COUNT as _;
+        let return_val = vk::Result::INCOMPLETE;
+
+        let ctx = MockGlobal::pre_instance_enumerate_instance_extension_properties_context();
+        ctx.expect()
+            .once()
+            .withf_st({
+                let expect_p_chain = &chain as *const _;
+                let expect_layer_name = layer_name.clone();
+                let expect_p_count = &mut out_count as *mut _;
+                let expect_p_properties = properties.as_mut_ptr();
+                move |p_chain, p_layer_name, p_count, p_properties| {
+                    let layer_name = unsafe { CStr::from_ptr(*p_layer_name) };
+                    (*p_chain, layer_name, *p_count, *p_properties)
+                        == (
+                            expect_p_chain,
+                            expect_layer_name.as_c_str(),
+                            expect_p_count,
+                            expect_p_properties,
+                        )
+                }
+            })
+            .return_const(return_val);
+
+        assert_eq!(
+            unsafe {
+                vk_layerEnumerateInstanceExtensionProperties(
+                    &chain,
+                    layer_name.as_ptr(),
+                    &mut out_count,
+                    properties.as_mut_ptr(),
+                )
+            },
+            return_val
+        );
+    }
+    {
+        const COUNT: usize = 2;
+        let chain = VkEnumerateInstanceLayerPropertiesChain::default();
+        let mut properties: [vk::LayerProperties; COUNT] = Default::default();
+        let mut out_count: u32 = COUNT as _;
+        let return_val = vk::Result::INCOMPLETE;
+
+        let ctx = MockGlobal::pre_instance_enumerate_instance_layer_properties_context();
+        ctx.expect()
+            .once()
+            .withf_st({
+                let expect_p_chain = &chain as *const _;
+                let expect_p_count = &mut out_count as *mut _;
+                let expect_p_properties = properties.as_mut_ptr();
+                move |p_chain, p_count, p_properties| {
+                    (*p_chain, *p_count, *p_properties)
+                        == (expect_p_chain, expect_p_count, expect_p_properties)
+                }
+            })
+            .return_const(return_val);
+
+        assert_eq!(
+            unsafe {
+                vk_layerEnumerateInstanceLayerProperties(
+                    &chain,
+                    &mut out_count,
+                    properties.as_mut_ptr(),
+                )
+            },
+            return_val
         );
     }
 }
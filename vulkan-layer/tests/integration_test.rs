@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use ash::vk::{self};
-use mockall::predicate::{always, eq};
+use ash::vk::{self, Handle};
+use mockall::{
+    predicate::{always, eq},
+    Sequence,
+};
 use once_cell::sync::Lazy;
 use parking_lot::{Mutex, MutexGuard};
 use std::{
-    ffi::{CStr, CString},
+    ffi::{c_char, c_void, CStr, CString},
     iter::zip,
     marker::PhantomData,
     mem::MaybeUninit,
@@ -25,14 +28,18 @@ use std::{
     sync::{atomic::AtomicBool, Arc, LazyLock},
 };
 use vulkan_layer::{
+    command_args::{CmdDrawMeshTasksExtArgs, DestroyImageArgs, FlushMappedMemoryRangesArgs},
     test_utils::{
         LayerManifestExt, MockTestLayer, Tag, TestGlobal, TestLayer, VkLayerDeviceCreateInfo,
         VkLayerDeviceLink, VkLayerFunction, VkLayerInstanceCreateInfo,
     },
     unstable_api::ApiVersion,
-    DeviceInfo, Extension, ExtensionProperties, Global, InstanceInfo, Layer, LayerManifest,
-    LayerResult, LayerVulkanCommand, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
-    VkLayerInstanceLink, VulkanBaseInStructChain,
+    ApiDump, ApiDumpFormat, DeepCopied, DeviceHooks, DeviceInfo, DeviceLoaderDataCallback,
+    Extension, ExtensionProperties, Global, InstanceInfo, Layer, LayerManifest, LayerResult,
+    LayerSettingValue, LayerSettings, LayerVulkanCommand, QueueProperties, RecordedCommand,
+    RenderScope, ReplayError, StubDeviceInfo, StubGlobalHooks, StubInstanceInfo,
+    VkLayerInstanceLink, VkNegotiateLayerInterface, VkNegotiateLayerStructType,
+    VulkanBaseInStructChain, EXT_LAYER_SETTINGS_NAME, EXT_LAYER_SETTINGS_SPEC_VERSION,
 };
 
 pub mod utils;
@@ -120,12 +127,33 @@ mod get_instance_proc_addr {
         }
 
         #[test]
-        #[ignore]
         fn test_should_return_null_when_called_with_available_instance_extension_command() {
-            todo!(concat!(
-                "This test is meaningless until we implement the mechanism for the layer to add ",
-                "instance extensions"
-            ))
+            static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+                .set_layer_mock_builder(|| {
+                    let mut mock = MockTestLayer::default();
+                    mock.expect_manifest().return_const({
+                        let mut layer_manifest = LayerManifest::test_default();
+                        layer_manifest.instance_extensions = &[ExtensionProperties {
+                            name: Extension::EXTDebugUtils,
+                            spec_version: 1,
+                        }];
+                        layer_manifest
+                    });
+                    mock.expect_hooked_instance_commands()
+                        .return_const(vec![LayerVulkanCommand::CreateDebugUtilsMessengerExt]);
+                    mock.set_default_expectations();
+                    mock
+                })
+                .build();
+            let _ctx = TEST_GLOBAL.create_context();
+            let entry = create_entry::<TestLayer>();
+            assert!(unsafe {
+                entry.get_instance_proc_addr(
+                    vk::Instance::null(),
+                    c"vkCreateDebugUtilsMessengerEXT".as_ptr(),
+                )
+            }
+            .is_none());
         }
 
         #[test]
@@ -192,8 +220,8 @@ mod get_instance_proc_addr {
             let get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr =
                 unsafe { std::mem::transmute(get_instance_proc_addr) };
             assert_eq!(
-                get_instance_proc_addr,
-                entry.static_fn().get_instance_proc_addr
+                get_instance_proc_addr as usize,
+                entry.static_fn().get_instance_proc_addr as usize
             );
         }
 
@@ -298,7 +326,7 @@ mod get_instance_proc_addr {
                     .unwrap()
                     .expect_destroy_surface_khr()
                     .times(1)
-                    .return_const(LayerResult::Unhandled);
+                    .return_const_st(LayerResult::Unhandled);
                 unsafe { destroy_surface(instance.handle(), vk::SurfaceKHR::null(), null()) };
             }
             // TODO: when we allow customize instance layer extensions, also test with the extension
@@ -542,7 +570,7 @@ mod get_instance_proc_addr {
                     .expect_destroy_swapchain_khr()
                     .with(eq(vk::SwapchainKHR::null()), always())
                     .once()
-                    .return_const(LayerResult::Handled(()));
+                    .return_const_st(LayerResult::Handled(()));
                 let destroy_swapchain_khr = unsafe {
                     instance
                         .get_device_proc_addr(device.handle(), c"vkDestroySwapchainKHR".as_ptr())
@@ -588,7 +616,7 @@ mod get_instance_proc_addr {
             let actual_new_command = unsafe {
                 entry.get_instance_proc_addr(instance.handle(), new_command_name_cstr.as_ptr())
             };
-            assert_eq!(actual_new_command.unwrap(), test_command);
+            assert_eq!(actual_new_command.unwrap() as usize, test_command as usize);
         }
 
         #[test]
@@ -615,6 +643,93 @@ mod get_instance_proc_addr {
             assert!(destroy_swapchain.is_none());
         }
 
+        #[test]
+        fn test_should_return_fp_for_enabled_layer_instance_extension_command() {
+            static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+                .set_layer_mock_builder(|| {
+                    let mut mock = MockTestLayer::default();
+                    mock.expect_manifest().return_const({
+                        let mut layer_manifest = LayerManifest::test_default();
+                        layer_manifest.instance_extensions = &[ExtensionProperties {
+                            name: Extension::EXTDebugUtils,
+                            spec_version: 1,
+                        }];
+                        layer_manifest
+                    });
+                    mock.expect_hooked_instance_commands()
+                        .return_const(vec![LayerVulkanCommand::CreateDebugUtilsMessengerExt]);
+                    mock.expect_hooked_device_commands()
+                        .return_const(vec![LayerVulkanCommand::SetDebugUtilsObjectNameExt]);
+                    mock.set_default_expectations();
+                    mock
+                })
+                .build();
+            let _ctx = TEST_GLOBAL.create_context();
+
+            let enabled_extensions = [vk::ExtDebugUtilsFn::name().as_ptr()];
+            let ctx = vk::InstanceCreateInfo::builder()
+                .enabled_extension_names(&enabled_extensions)
+                .default_instance::<(TestLayer,)>();
+            let InstanceContext {
+                entry,
+                instance,
+                icd_entry,
+                ..
+            } = ctx.as_ref();
+            for command_name in [
+                c"vkCreateDebugUtilsMessengerEXT",
+                c"vkSetDebugUtilsObjectNameEXT",
+            ] {
+                // The next layer doesn't support VK_EXT_debug_utils.
+                assert!(unsafe {
+                    icd_entry.get_instance_proc_addr(instance.handle(), command_name.as_ptr())
+                }
+                .is_none());
+                assert!(
+                    unsafe {
+                        entry.get_instance_proc_addr(instance.handle(), command_name.as_ptr())
+                    }
+                    .is_some(),
+                    "{:?} should be available",
+                    command_name
+                );
+            }
+        }
+
+        #[test]
+        fn test_should_return_null_for_disabled_layer_instance_extension_command() {
+            static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+                .set_layer_mock_builder(|| {
+                    let mut mock = MockTestLayer::default();
+                    mock.expect_manifest().return_const({
+                        let mut layer_manifest = LayerManifest::test_default();
+                        layer_manifest.instance_extensions = &[ExtensionProperties {
+                            name: Extension::EXTDebugUtils,
+                            spec_version: 1,
+                        }];
+                        layer_manifest
+                    });
+                    mock.expect_hooked_instance_commands()
+                        .return_const(vec![LayerVulkanCommand::CreateDebugUtilsMessengerExt]);
+                    mock.set_default_expectations();
+                    mock
+                })
+                .build();
+            let _ctx = TEST_GLOBAL.create_context();
+
+            let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+            let InstanceContext {
+                entry, instance, ..
+            } = ctx.as_ref();
+            assert!(unsafe {
+                entry.get_instance_proc_addr(
+                    instance.handle(),
+                    c"vkCreateDebugUtilsMessengerEXT".as_ptr(),
+                )
+            }
+            .is_none());
+        }
+
         #[test]
         #[ignore]
         fn test_should_return_fp_with_device_command_supported_by_the_layer_extension_only() {
@@ -649,7 +764,7 @@ mod get_instance_proc_addr {
             .expect(
                 "vkCreateInstance should be a valid function pointer with an invalid instance.",
             );
-            assert_eq!(expected, actual);
+            assert_eq!(expected as usize, actual as usize);
         }
     }
 
@@ -666,8 +781,120 @@ mod get_instance_proc_addr {
     }
 }
 
+mod get_physical_device_proc_addr {
+    use super::*;
+
+    #[test]
+    fn test_should_return_local_fp_for_hooked_command() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_instance_commands()
+                    .return_const(vec![LayerVulkanCommand::GetPhysicalDeviceFeatures]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let InstanceContext {
+            entry, instance, ..
+        } = ctx.as_ref();
+        let name = c"vkGetPhysicalDeviceFeatures".as_ptr();
+        let fp =
+            unsafe { Global::<TestLayer>::get_physical_device_proc_addr(instance.handle(), name) }
+                .map(|fp| fp as usize);
+        assert!(fp.is_some());
+        let expected_fp =
+            unsafe { entry.get_instance_proc_addr(instance.handle(), name) }.map(|fp| fp as usize);
+        assert_eq!(fp, expected_fp);
+    }
+
+    #[test]
+    fn test_should_return_null_if_not_hooked_and_next_is_null() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let InstanceContext { instance, .. } = ctx.as_ref();
+        for name in [
+            c"vkGetPhysicalDeviceFeatures",
+            c"vkGetPhysicalDeviceUnknownCommandFromTheFuture",
+        ] {
+            assert!(unsafe {
+                Global::<TestLayer>::get_physical_device_proc_addr(instance.handle(), name.as_ptr())
+            }
+            .is_none());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "https://github.com/google/vk-layer-for-rust/issues/49")]
+    fn test_should_call_into_next_get_physical_device_proc_addr_if_not_hooked() {
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_instance_commands()
+                    .return_const(vec![LayerVulkanCommand::GetPhysicalDeviceFeatures]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>();
+        let InstanceContext { instance, .. } = ctx.as_ref();
+        let name = c"vkGetPhysicalDeviceFeatures".as_ptr();
+        let fp = unsafe {
+            Global::<TestLayer<Tag<0>>>::get_physical_device_proc_addr(instance.handle(), name)
+        }
+        .map(|fp| fp as usize);
+        assert!(fp.is_some());
+        let next_fp = unsafe {
+            Global::<TestLayer<Tag<1>>>::get_physical_device_proc_addr(instance.handle(), name)
+        }
+        .map(|fp| fp as usize);
+        assert_eq!(fp, next_fp);
+    }
+}
+
 mod create_destroy_instance {
     use super::*;
+
+    #[test]
+    fn test_should_remove_layer_instance_extensions() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_manifest().return_const({
+                    let mut layer_manifest = LayerManifest::test_default();
+                    layer_manifest.instance_extensions = &[ExtensionProperties {
+                        name: Extension::EXTDebugUtils,
+                        spec_version: 1,
+                    }];
+                    layer_manifest
+                });
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+
+        let enabled_extensions = [
+            vk::KhrSurfaceFn::name().as_ptr(),
+            vk::ExtDebugUtilsFn::name().as_ptr(),
+        ];
+        let ctx = vk::InstanceCreateInfo::builder()
+            .enabled_extension_names(&enabled_extensions)
+            .default_instance::<(TestLayer,)>();
+        let icd_enabled_extensions = unsafe { InstanceData::from_handle(ctx.instance.handle()) }
+            .enabled_extensions
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(icd_enabled_extensions, vec![Extension::KHRSurface]);
+    }
     #[test]
     #[cfg_attr(miri, ignore = "https://github.com/google/vk-layer-for-rust/issues/49")]
     fn test_should_move_layer_instance_link_forward() {
@@ -1057,7 +1284,7 @@ mod get_device_proc_addr {
             .expect_destroy_sampler_ycbcr_conversion()
             .with(eq(vk::SamplerYcbcrConversion::null()), always())
             .once()
-            .return_const(LayerResult::Unhandled);
+            .return_const_st(LayerResult::Unhandled);
         unsafe {
             destroy_sampler_ycbcr_conversion(
                 device.handle(),
@@ -1334,7 +1561,14 @@ mod device_commands {
             .expect_destroy_image()
             .withf(|image, allocator| *image == vk::Image::null() && allocator.is_none())
             .once()
-            .return_const(LayerResult::Unhandled);
+            .return_const_st(LayerResult::Unhandled);
+        device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_after_destroy_image()
+            .once()
+            .return_const(());
         unsafe { device.destroy_image(vk::Image::null(), None) };
     }
 
@@ -1366,121 +1600,2066 @@ mod device_commands {
             .never();
         unsafe { device.destroy_image(vk::Image::null(), None) };
     }
-}
 
-mod enumerate_device_extensions {
-    use super::*;
     #[test]
-    fn test_should_return_defined_extensions() {
+    fn test_after_hook_should_be_called_after_the_next_layer_if_unhandled() {
         static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
             .set_layer_mock_builder(|| {
                 let mut mock = MockTestLayer::default();
-                mock.expect_manifest().return_const({
-                    let mut layer_manifest = LayerManifest::test_default();
-                    layer_manifest.device_extensions = &[ExtensionProperties {
-                        name: Extension::KHRExternalMemory,
-                        spec_version: 1,
-                    }];
-                    layer_manifest
-                });
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
                 mock.set_default_expectations();
                 mock
             })
             .build();
         let _ctx = TEST_GLOBAL.create_context();
-        let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
-        let InstanceContext { instance, .. } = ctx.as_ref();
-        unsafe { InstanceData::from_handle(instance.handle()) }
-            .set_available_device_extensions(&[]);
-        let physical_device = *unsafe { instance.enumerate_physical_devices() }
-            .unwrap()
-            .first()
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
             .unwrap();
-        let layer_name = CString::new(TestLayer::<Tag<0>>::manifest().name).unwrap();
-        let properties = {
-            let mut property_count = MaybeUninit::uninit();
-            let res = unsafe {
-                (instance.fp_v1_0().enumerate_device_extension_properties)(
-                    physical_device,
-                    layer_name.as_ptr(),
-                    property_count.as_mut_ptr(),
-                    null_mut(),
-                )
-            };
-            assert_eq!(res, vk::Result::SUCCESS);
-            let mut property_count = unsafe { property_count.assume_init() };
-            let mut properties = Vec::<vk::ExtensionProperties>::new();
-            properties.resize_with(property_count.try_into().unwrap(), Default::default);
-            let res = unsafe {
-                (instance.fp_v1_0().enumerate_device_extension_properties)(
-                    physical_device,
-                    layer_name.as_ptr(),
-                    &mut property_count,
-                    properties.as_mut_ptr(),
-                )
-            };
-            assert_eq!(res, vk::Result::SUCCESS);
-            properties.into_boxed_slice()
-        };
-        let expected_properties = TestLayer::<Tag<0>>::manifest()
-            .device_extensions
-            .iter()
-            .cloned()
-            .map(Into::<vk::ExtensionProperties>::into)
-            .collect::<Vec<_>>();
-        assert_eq!(expected_properties.len(), properties.len());
-        for (expected_property, property) in zip(expected_properties.iter(), properties.iter()) {
-            assert_eq!(expected_property.spec_version, property.spec_version);
-            let expected_name_bytes = expected_property
-                .extension_name
-                .iter()
-                .take_while(|byte| **byte != b'\0'.try_into().unwrap())
-                .collect::<Vec<_>>();
-            let name_bytes = property
-                .extension_name
-                .iter()
-                .take_while(|byte| **byte != b'\0'.try_into().unwrap())
-                .collect::<Vec<_>>();
-            assert_eq!(expected_name_bytes, name_bytes);
-        }
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut seq = Sequence::new();
+        let mut mock_hooks = device_info.mock_hooks.lock().unwrap();
+        mock_hooks
+            .expect_destroy_image()
+            .once()
+            .in_sequence(&mut seq)
+            .return_const_st(LayerResult::Unhandled);
+        mock_hooks
+            .expect_after_destroy_image()
+            .withf(|image, allocator| *image == vk::Image::null() && allocator.is_none())
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(());
+        drop(mock_hooks);
+        unsafe { device.destroy_image(vk::Image::null(), None) };
     }
 
-    // TODO: test if the loader will add the implicit layer extension in e2e test.
-
     #[test]
-    fn test_should_call_into_the_next_chain_if_layer_name_doesnt_match() {
+    fn test_after_hook_should_not_be_called_if_handled() {
         static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
             .set_layer_mock_builder(|| {
                 let mut mock = MockTestLayer::default();
-                mock.expect_manifest().return_const({
-                    let mut layer_manifest = LayerManifest::test_default();
-                    layer_manifest.device_extensions = &[ExtensionProperties {
-                        name: Extension::KHRExternalMemory,
-                        spec_version: 1,
-                    }];
-                    layer_manifest
-                });
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
                 mock.set_default_expectations();
                 mock
             })
             .build();
         let _ctx = TEST_GLOBAL.create_context();
-        let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
-        let InstanceContext { instance, .. } = ctx.as_ref();
-        let available_device_extensions = &[Extension::KHRSwapchain];
-        unsafe { InstanceData::from_handle(instance.handle()) }
-            .set_available_device_extensions(available_device_extensions);
-        let physical_device = *unsafe { instance.enumerate_physical_devices() }
-            .unwrap()
-            .first()
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
             .unwrap();
-        let properties = unsafe { instance.enumerate_device_extension_properties(physical_device) }
-            .unwrap()
-            .into_boxed_slice();
-        let property_names = properties
-            .iter()
-            .map(|vk::ExtensionProperties { extension_name, .. }| {
-                unsafe { CStr::from_ptr(extension_name.as_ptr()) }
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut mock_hooks = device_info.mock_hooks.lock().unwrap();
+        mock_hooks
+            .expect_destroy_image()
+            .once()
+            .return_const_st(LayerResult::Handled(()));
+        mock_hooks.expect_after_destroy_image().never();
+        drop(mock_hooks);
+        unsafe { device.destroy_image(vk::Image::null(), None) };
+    }
+
+    #[test]
+    fn test_continue_should_pass_rewritten_arguments_to_the_next_layer() {
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let image = vk::Image::from_raw(0x42);
+
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut seq = Sequence::new();
+        let mut first_mock_hooks = first_device_info.mock_hooks.lock().unwrap();
+        first_mock_hooks
+            .expect_destroy_image()
+            .with(eq(vk::Image::null()), always())
+            .once()
+            .in_sequence(&mut seq)
+            .return_const_st(LayerResult::continue_with(DestroyImageArgs {
+                image,
+                p_allocator: None,
+            }));
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        second_device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_destroy_image()
+            .with(eq(image), always())
+            .once()
+            .in_sequence(&mut seq)
+            .return_const_st(LayerResult::Handled(()));
+        // The after hook observes the arguments passed to the layer.
+        first_mock_hooks
+            .expect_after_destroy_image()
+            .with(eq(vk::Image::null()), always())
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(());
+        drop(first_mock_hooks);
+
+        unsafe { device.destroy_image(vk::Image::null(), None) };
+    }
+
+    #[test]
+    fn test_continue_should_pass_rewritten_slices_with_their_lengths() {
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::FlushMappedMemoryRanges]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::FlushMappedMemoryRanges]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let memory_range = vk::MappedMemoryRange::builder()
+            .memory(vk::DeviceMemory::from_raw(0x42))
+            .size(vk::WHOLE_SIZE)
+            .build();
+
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut first_mock_hooks = first_device_info.mock_hooks.lock().unwrap();
+        first_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .withf(|memory_ranges| memory_ranges.len() == 1)
+            .once()
+            .return_const_st(LayerResult::continue_with(FlushMappedMemoryRangesArgs {
+                p_memory_ranges: vec![memory_range; 2],
+            }));
+        first_mock_hooks
+            .expect_after_flush_mapped_memory_ranges()
+            .withf(|memory_ranges, result| {
+                memory_ranges.len() == 1 && *result == vk::Result::SUCCESS
+            })
+            .once()
+            .return_const(());
+        drop(first_mock_hooks);
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        second_device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_flush_mapped_memory_ranges()
+            .withf(move |memory_ranges| {
+                memory_ranges.len() == 2
+                    && memory_ranges
+                        .iter()
+                        .all(|range| range.memory == memory_range.memory)
+            })
+            .once()
+            .return_const_st(LayerResult::Handled(Ok(())));
+
+        unsafe { device.flush_mapped_memory_ranges(&[memory_range]) }.unwrap();
+    }
+
+    #[test]
+    fn test_after_hook_should_receive_outputs_with_success_codes() {
+        let instance_extensions = [vk::KhrSurfaceFn::name().as_ptr()];
+        let device_extensions = [vk::KhrSwapchainFn::name().as_ptr()];
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::AcquireNextImageKhr]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .enabled_extension_names(&instance_extensions)
+            .default_instance::<(TestLayer,)>()
+            .create_device_context(|create_info_builder, create_device| {
+                let create_info_builder =
+                    create_info_builder.enabled_extension_names(&device_extensions);
+                create_device(create_info_builder);
+            })
+            .unwrap();
+        let DeviceContext {
+            device,
+            instance_context,
+            ..
+        } = ctx.as_ref();
+        let device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut mock_hooks = device_info.mock_hooks.lock().unwrap();
+        mock_hooks
+            .expect_acquire_next_image_khr()
+            .once()
+            .return_const(LayerResult::Unhandled);
+        mock_hooks
+            .expect_after_acquire_next_image_khr()
+            .with(
+                always(),
+                always(),
+                always(),
+                always(),
+                eq(Some(2)),
+                eq(vk::Result::SUBOPTIMAL_KHR),
+            )
+            .once()
+            .return_const(());
+        drop(mock_hooks);
+
+        let acquire_next_image_khr = unsafe {
+            instance_context
+                .instance
+                .get_device_proc_addr(device.handle(), c"vkAcquireNextImageKHR".as_ptr())
+        }
+        .unwrap();
+        let acquire_next_image_khr: vk::PFN_vkAcquireNextImageKHR =
+            unsafe { std::mem::transmute(acquire_next_image_khr) };
+        let mut image_index = 0;
+        let res = unsafe {
+            acquire_next_image_khr(
+                device.handle(),
+                vk::SwapchainKHR::null(),
+                u64::MAX,
+                vk::Semaphore::null(),
+                vk::Fence::null(),
+                &mut image_index,
+            )
+        };
+        assert_eq!(res, vk::Result::SUBOPTIMAL_KHR);
+        assert_eq!(image_index, 2);
+    }
+}
+
+mod next_dispatch {
+    use super::*;
+
+    #[test]
+    fn test_next_instance_dispatch_should_be_found_from_instance_and_physical_device() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let InstanceContext { instance, .. } = instance_ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let next_dispatch = global.next_instance_dispatch(instance.handle()).unwrap();
+        assert_eq!(next_dispatch.core().handle(), instance.handle());
+
+        let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+        let physical_device = *physical_devices.first().unwrap();
+        let next_dispatch = global.next_instance_dispatch(physical_device).unwrap();
+        assert_eq!(next_dispatch.core().handle(), instance.handle());
+    }
+
+    #[test]
+    fn test_next_device_dispatch_should_be_found_from_device() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let next_dispatch = global.next_device_dispatch(device.handle()).unwrap();
+        assert_eq!(next_dispatch.core().handle(), device.handle());
+    }
+
+    #[test]
+    fn test_hooks_should_be_able_to_call_into_the_next_layer() {
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let device_handle = device.handle();
+        let image = vk::Image::from_raw(0x42);
+
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device_handle)
+            .unwrap();
+        first_device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_destroy_image()
+            .with(eq(vk::Image::null()), always())
+            .once()
+            .returning(move |_, _| {
+                let next_dispatch = TestLayer::<Tag<0>>::global_instance()
+                    .next_device_dispatch(device_handle)
+                    .unwrap();
+                unsafe { next_dispatch.core().destroy_image(image, None) };
+                LayerResult::Handled(())
+            });
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device_handle)
+            .unwrap();
+        second_device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_destroy_image()
+            .with(eq(image), always())
+            .once()
+            .return_const_st(LayerResult::Handled(()));
+
+        unsafe { device.destroy_image(vk::Image::null(), None) };
+    }
+}
+
+mod info_lookup {
+    use super::*;
+
+    #[test]
+    fn test_instance_info_should_be_found_from_instance_and_physical_device() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let InstanceContext { instance, .. } = instance_ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let expected_instance_info = global
+            .layer_info
+            .get_instance_info(instance.handle())
+            .unwrap();
+
+        let instance_info = global.instance_info(instance.handle()).unwrap();
+        assert_eq!(instance_info.instance(), instance.handle());
+        assert!(std::ptr::eq(
+            &**(*instance_info).0,
+            &**expected_instance_info
+        ));
+
+        let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+        let physical_device = *physical_devices.first().unwrap();
+        let instance_info = global.instance_info(physical_device).unwrap();
+        assert_eq!(instance_info.instance(), instance.handle());
+
+        let instance_infos = global.instance_infos().collect::<Vec<_>>();
+        assert_eq!(instance_infos.len(), 1);
+        assert_eq!(instance_infos[0].instance(), instance.handle());
+    }
+
+    #[test]
+    fn test_physical_device_info_should_be_created_on_enumeration() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let InstanceContext { instance, .. } = instance_ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+
+        let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+        assert!(!physical_devices.is_empty());
+        for physical_device in physical_devices {
+            let physical_device_info = global.physical_device_info(physical_device).unwrap();
+            assert_eq!(physical_device_info.physical_device(), physical_device);
+            assert_eq!(physical_device_info.instance(), instance.handle());
+            assert_eq!(physical_device_info.physical_device, physical_device);
+            let properties = unsafe { instance.get_physical_device_properties(physical_device) };
+            assert_eq!(
+                physical_device_info.properties().device_id,
+                properties.device_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_physical_device_info_should_be_dropped_with_instance() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let dropped: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let physical_device = {
+            let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+            let InstanceContext { instance, .. } = instance_ctx.as_ref();
+            let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+            let physical_device = *physical_devices.first().unwrap();
+            global
+                .physical_device_info(physical_device)
+                .unwrap()
+                .with_mock_drop(|mock_drop| {
+                    mock_drop.expect_drop().once().return_once({
+                        let dropped = Arc::clone(&dropped);
+                        move || {
+                            dropped.store(true, std::sync::atomic::Ordering::SeqCst);
+                        }
+                    });
+                });
+            physical_device
+            // Calling vkDestroyInstance through RAII.
+        };
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(global.physical_device_info(physical_device).is_none());
+    }
+
+    #[test]
+    fn test_device_info_should_be_found_until_device_is_destroyed() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let device_handle = {
+            let ctx = vk::InstanceCreateInfo::builder()
+                .default_instance::<(TestLayer,)>()
+                .default_device()
+                .unwrap();
+            let DeviceContext { device, .. } = ctx.as_ref();
+            let expected_device_info = global.layer_info.get_device_info(device.handle()).unwrap();
+
+            let device_info = global.device_info(device.handle()).unwrap();
+            assert_eq!(device_info.device(), device.handle());
+            assert!(std::ptr::eq(&**(*device_info).0, &**expected_device_info));
+
+            let device_infos = global.device_infos().collect::<Vec<_>>();
+            assert_eq!(device_infos.len(), 1);
+            assert_eq!(device_infos[0].device(), device.handle());
+            device.handle()
+            // Calling vkDestroyDevice through RAII.
+        };
+        assert!(global.device_info(device_handle).is_none());
+        assert_eq!(global.device_infos().count(), 0);
+    }
+
+    #[test]
+    fn test_device_info_ref_should_keep_device_info_alive() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let dropped: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let device_info = {
+            let ctx = vk::InstanceCreateInfo::builder()
+                .default_instance::<(TestLayer,)>()
+                .default_device()
+                .unwrap();
+            let DeviceContext { device, .. } = ctx.as_ref();
+            let device_info = TestLayer::<Tag<0>>::global_instance()
+                .device_info(device.handle())
+                .unwrap();
+            device_info.0.with_mock_drop(|mock_drop| {
+                mock_drop.expect_drop().once().return_once({
+                    let dropped = Arc::clone(&dropped);
+                    move || {
+                        dropped.store(true, std::sync::atomic::Ordering::SeqCst);
+                    }
+                });
+            });
+            device_info
+            // Calling vkDestroyDevice through RAII.
+        };
+        assert!(!dropped.load(std::sync::atomic::Ordering::SeqCst));
+        drop(device_info);
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_queue_info_should_be_created_on_get_device_queue() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let queue = unsafe { device.get_device_queue(0, 0) };
+        assert_ne!(queue, vk::Queue::null());
+
+        let queue_info = global.queue_info(queue).unwrap();
+        assert_eq!(queue_info.queue(), queue);
+        assert_eq!(queue_info.device(), device.handle());
+        let expected_properties = QueueProperties {
+            queue_family_index: 0,
+            queue_index: 0,
+            queue_flags: vk::QueueFlags::GRAPHICS | vk::QueueFlags::TRANSFER,
+            create_flags: vk::DeviceQueueCreateFlags::empty(),
+        };
+        assert_eq!(queue_info.properties(), &expected_properties);
+        assert_eq!(queue_info.queue, queue);
+        assert_eq!(queue_info.properties, Some(expected_properties));
+
+        // Retrieving the same queue again shouldn't recreate the queue info.
+        assert_eq!(unsafe { device.get_device_queue(0, 0) }, queue);
+        assert!(std::ptr::eq(
+            &*global.queue_info(queue).unwrap(),
+            &*queue_info
+        ));
+    }
+
+    #[test]
+    fn test_queue_info_should_be_dropped_with_device() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let dropped: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let queue = {
+            let ctx = vk::InstanceCreateInfo::builder()
+                .default_instance::<(TestLayer,)>()
+                .default_device()
+                .unwrap();
+            let DeviceContext { device, .. } = ctx.as_ref();
+            let queue = unsafe { device.get_device_queue(0, 0) };
+            global
+                .queue_info(queue)
+                .unwrap()
+                .with_mock_drop(|mock_drop| {
+                    mock_drop.expect_drop().once().return_once({
+                        let dropped = Arc::clone(&dropped);
+                        move || {
+                            dropped.store(true, std::sync::atomic::Ordering::SeqCst);
+                        }
+                    });
+                });
+            queue
+            // Calling vkDestroyDevice through RAII.
+        };
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(global.queue_info(queue).is_none());
+    }
+
+    #[test]
+    fn test_command_buffer_info_should_follow_command_buffer_lifecycle() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+        let command_buffers = unsafe {
+            device.allocate_command_buffers(
+                &vk::CommandBufferAllocateInfo::builder()
+                    .command_pool(command_pool)
+                    .level(vk::CommandBufferLevel::SECONDARY)
+                    .command_buffer_count(2),
+            )
+        }
+        .unwrap();
+        let command_buffer_infos = command_buffers
+            .iter()
+            .map(|command_buffer| global.command_buffer_info(*command_buffer).unwrap())
+            .collect::<Vec<_>>();
+        for (command_buffer, command_buffer_info) in
+            command_buffers.iter().zip(command_buffer_infos.iter())
+        {
+            assert_eq!(command_buffer_info.command_buffer(), *command_buffer);
+            assert_eq!(command_buffer_info.device(), device.handle());
+            assert_eq!(command_buffer_info.command_pool(), command_pool);
+            assert_eq!(
+                command_buffer_info.level(),
+                vk::CommandBufferLevel::SECONDARY
+            );
+            assert_eq!(command_buffer_info.command_buffer, *command_buffer);
+        }
+        let reset_counts = || {
+            command_buffer_infos
+                .iter()
+                .map(|command_buffer_info| {
+                    command_buffer_info
+                        .reset_count
+                        .load(std::sync::atomic::Ordering::SeqCst)
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(reset_counts(), [0, 0]);
+
+        unsafe {
+            device.begin_command_buffer(command_buffers[0], &vk::CommandBufferBeginInfo::default())
+        }
+        .unwrap();
+        assert_eq!(reset_counts(), [1, 0]);
+        unsafe {
+            device.reset_command_buffer(command_buffers[1], vk::CommandBufferResetFlags::empty())
+        }
+        .unwrap();
+        assert_eq!(reset_counts(), [1, 1]);
+        unsafe { device.reset_command_pool(command_pool, vk::CommandPoolResetFlags::empty()) }
+            .unwrap();
+        assert_eq!(reset_counts(), [2, 2]);
+
+        let dropped = [(); 2].map(|_| Arc::new(AtomicBool::new(false)));
+        for (command_buffer_info, dropped) in command_buffer_infos.into_iter().zip(dropped.iter()) {
+            command_buffer_info.with_mock_drop(|mock_drop| {
+                mock_drop.expect_drop().once().return_once({
+                    let dropped = Arc::clone(dropped);
+                    move || {
+                        dropped.store(true, std::sync::atomic::Ordering::SeqCst);
+                    }
+                });
+            });
+        }
+        let is_dropped = || {
+            dropped
+                .each_ref()
+                .map(|dropped| dropped.load(std::sync::atomic::Ordering::SeqCst))
+        };
+        unsafe { device.free_command_buffers(command_pool, &command_buffers[..1]) };
+        assert!(global.command_buffer_info(command_buffers[0]).is_none());
+        assert_eq!(is_dropped(), [true, false]);
+        unsafe { device.destroy_command_pool(command_pool, None) };
+        assert!(global.command_buffer_info(command_buffers[1]).is_none());
+        assert_eq!(is_dropped(), [true, true]);
+    }
+
+    #[test]
+    fn test_command_buffer_info_should_be_dropped_with_device() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let dropped: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let command_buffer = {
+            let ctx = vk::InstanceCreateInfo::builder()
+                .default_instance::<(TestLayer,)>()
+                .default_device()
+                .unwrap();
+            let DeviceContext { device, .. } = ctx.as_ref();
+            let command_pool =
+                unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                    .unwrap();
+            let command_buffer = unsafe {
+                device.allocate_command_buffers(
+                    &vk::CommandBufferAllocateInfo::builder()
+                        .command_pool(command_pool)
+                        .command_buffer_count(1),
+                )
+            }
+            .unwrap()[0];
+            global
+                .command_buffer_info(command_buffer)
+                .unwrap()
+                .with_mock_drop(|mock_drop| {
+                    mock_drop.expect_drop().once().return_once({
+                        let dropped = Arc::clone(&dropped);
+                        move || {
+                            dropped.store(true, std::sync::atomic::Ordering::SeqCst);
+                        }
+                    });
+                });
+            command_buffer
+            // Calling vkDestroyDevice through RAII without destroying the command pool.
+        };
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(global.command_buffer_info(command_buffer).is_none());
+    }
+}
+
+mod command_buffer_state {
+    use super::*;
+
+    pub(super) fn allocate_command_buffer(
+        device: &ash::Device,
+        command_pool: vk::CommandPool,
+        level: vk::CommandBufferLevel,
+    ) -> vk::CommandBuffer {
+        unsafe {
+            device.allocate_command_buffers(
+                &vk::CommandBufferAllocateInfo::builder()
+                    .command_pool(command_pool)
+                    .level(level)
+                    .command_buffer_count(1),
+            )
+        }
+        .unwrap()[0]
+    }
+
+    #[test]
+    fn test_state_should_be_none_if_tracking_is_disabled() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+        let command_buffer =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::PRIMARY);
+        let command_buffer_info = TestLayer::<Tag<0>>::global_instance()
+            .command_buffer_info(command_buffer)
+            .unwrap();
+        assert!(command_buffer_info.state().is_none());
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+
+    #[test]
+    fn test_state_should_track_bound_state_and_render_scopes() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_track_command_buffer_state().return_const(true);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        global
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap()
+            .hooks()
+            .expect_cmd_set_viewport()
+            .return_const(LayerResult::Unhandled);
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+        let render_pass = vk::RenderPass::from_raw(0x1234);
+        let framebuffer = vk::Framebuffer::from_raw(0x5678);
+
+        let secondary =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::SECONDARY);
+        let inheritance_info = vk::CommandBufferInheritanceInfo::builder()
+            .render_pass(render_pass)
+            .subpass(0);
+        unsafe {
+            device.begin_command_buffer(
+                secondary,
+                &vk::CommandBufferBeginInfo::builder()
+                    .flags(vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE)
+                    .inheritance_info(&inheritance_info),
+            )
+        }
+        .unwrap();
+        let secondary_info = global.command_buffer_info(secondary).unwrap();
+        assert!(matches!(
+            secondary_info.state().unwrap().render_scope(),
+            Some(RenderScope::RenderPass {
+                render_pass: inherited_render_pass,
+                render_area: None,
+                ..
+            }) if *inherited_render_pass == render_pass
+        ));
+
+        let primary =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::PRIMARY);
+        let primary_info = global.command_buffer_info(primary).unwrap();
+        unsafe { device.begin_command_buffer(primary, &vk::CommandBufferBeginInfo::default()) }
+            .unwrap();
+        let pipeline = vk::Pipeline::from_raw(0x9abc);
+        let viewport = vk::Viewport::builder().width(64.0).height(32.0).build();
+        unsafe {
+            device.cmd_bind_pipeline(primary, vk::PipelineBindPoint::GRAPHICS, pipeline);
+            device.cmd_set_viewport(primary, 1, &[viewport]);
+        }
+        {
+            let state = primary_info.state().unwrap();
+            assert_eq!(
+                state.pipeline(vk::PipelineBindPoint::GRAPHICS),
+                Some(pipeline)
+            );
+            assert_eq!(state.pipeline(vk::PipelineBindPoint::COMPUTE), None);
+            assert_eq!(state.dynamic_state().viewports[&1].width, 64.0);
+            assert!(state.render_scope().is_none());
+        }
+
+        let render_area = vk::Rect2D::builder()
+            .extent(vk::Extent2D::builder().width(64).height(32).build())
+            .build();
+        unsafe {
+            device.cmd_begin_render_pass(
+                primary,
+                &vk::RenderPassBeginInfo::builder()
+                    .render_pass(render_pass)
+                    .framebuffer(framebuffer)
+                    .render_area(render_area),
+                vk::SubpassContents::SECONDARY_COMMAND_BUFFERS,
+            );
+            device.cmd_execute_commands(primary, &[secondary]);
+        }
+        {
+            let state = primary_info.state().unwrap();
+            // The bound state is unknown after vkCmdExecuteCommands.
+            assert_eq!(state.pipeline(vk::PipelineBindPoint::GRAPHICS), None);
+            assert!(state.dynamic_state().viewports.is_empty());
+            assert!(matches!(
+                state.render_scope(),
+                Some(RenderScope::RenderPass {
+                    framebuffer: active_framebuffer,
+                    subpass: 0,
+                    render_area: Some(_),
+                    ..
+                }) if *active_framebuffer == framebuffer
+            ));
+            assert_eq!(state.executed_command_buffers(), [secondary]);
+        }
+        unsafe { device.cmd_end_render_pass(primary) };
+        assert!(primary_info.state().unwrap().render_scope().is_none());
+
+        // vkResetCommandPool resets the states.
+        unsafe { device.reset_command_pool(command_pool, vk::CommandPoolResetFlags::empty()) }
+            .unwrap();
+        assert!(primary_info
+            .state()
+            .unwrap()
+            .executed_command_buffers()
+            .is_empty());
+        assert!(secondary_info.state().unwrap().render_scope().is_none());
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+
+    #[test]
+    fn test_state_shouldnt_track_commands_handled_by_the_layer() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_track_command_buffer_state().return_const(true);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        {
+            let device_info = global.layer_info.get_device_info(device.handle()).unwrap();
+            let mut hooks = device_info.hooks();
+            hooks
+                .expect_cmd_set_viewport()
+                .withf(|_, first_viewport, _| *first_viewport == 0)
+                .return_const(LayerResult::Handled(()));
+            hooks
+                .expect_cmd_set_viewport()
+                .withf(|_, first_viewport, _| *first_viewport == 1)
+                .return_const(LayerResult::Unhandled);
+        }
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+        let command_buffer =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::PRIMARY);
+        unsafe {
+            device.begin_command_buffer(command_buffer, &vk::CommandBufferBeginInfo::default())
+        }
+        .unwrap();
+        let viewport = vk::Viewport::builder().width(64.0).height(32.0).build();
+        unsafe {
+            device.cmd_set_viewport(command_buffer, 0, &[viewport]);
+            device.cmd_set_viewport(command_buffer, 1, &[viewport]);
+        }
+        let command_buffer_info = global.command_buffer_info(command_buffer).unwrap();
+        let state = command_buffer_info.state().unwrap();
+        // The viewport 0 is set by a command that never reaches the next layer.
+        assert_eq!(
+            state.dynamic_state().viewports.keys().collect::<Vec<_>>(),
+            [&1]
+        );
+        drop(state);
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+}
+
+mod command_recording {
+    use super::{command_buffer_state::allocate_command_buffer, *};
+
+    fn record_test_commands(device: &ash::Device, command_buffer: vk::CommandBuffer) {
+        let viewport = vk::Viewport::builder().width(64.0).height(32.0).build();
+        let clear_values = vec![vk::ClearValue {
+            color: vk::ClearColorValue {
+                float32: [0.25, 0.5, 0.75, 1.0],
+            },
+        }];
+        unsafe {
+            device.cmd_bind_pipeline(
+                command_buffer,
+                vk::PipelineBindPoint::GRAPHICS,
+                vk::Pipeline::from_raw(0x9abc),
+            );
+            device.cmd_set_viewport(command_buffer, 1, &[viewport]);
+            device.cmd_begin_render_pass(
+                command_buffer,
+                &vk::RenderPassBeginInfo::builder()
+                    .render_pass(vk::RenderPass::from_raw(0x1234))
+                    .framebuffer(vk::Framebuffer::from_raw(0x5678))
+                    .clear_values(&clear_values),
+                vk::SubpassContents::INLINE,
+            );
+            device.cmd_end_render_pass(command_buffer);
+        }
+    }
+
+    fn check_test_commands(recorded_commands: &[RecordedCommand]) {
+        assert_eq!(
+            recorded_commands
+                .iter()
+                .map(RecordedCommand::name)
+                .collect::<Vec<_>>(),
+            [
+                "vkCmdBindPipeline",
+                "vkCmdSetViewport",
+                "vkCmdBeginRenderPass",
+                "vkCmdEndRenderPass"
+            ]
+        );
+        let RecordedCommand::CmdSetViewport(args) = &recorded_commands[1] else {
+            panic!("Unexpected command: {}", recorded_commands[1].name());
+        };
+        assert_eq!(args.first_viewport, 1);
+        assert_eq!(args.p_viewports[0].width, 64.0);
+        let RecordedCommand::CmdBeginRenderPass(args) = &recorded_commands[2] else {
+            panic!("Unexpected command: {}", recorded_commands[2].name());
+        };
+        let begin_info = &args.p_render_pass_begin;
+        assert_eq!(begin_info.render_pass, vk::RenderPass::from_raw(0x1234));
+        assert_eq!(begin_info.clear_value_count, 1);
+        // The clear values of the application are dropped, so the copied values are checked.
+        let clear_value = unsafe { &*begin_info.p_clear_values };
+        assert_eq!(unsafe { clear_value.color.float32 }, [0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn test_recorded_commands_should_be_none_if_recording_is_disabled() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+        let command_buffer =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::PRIMARY);
+        let command_buffer_info = TestLayer::<Tag<0>>::global_instance()
+            .command_buffer_info(command_buffer)
+            .unwrap();
+        assert!(command_buffer_info.recorded_commands().is_none());
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+
+    #[test]
+    fn test_should_record_and_replay_commands() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_record_command_buffers().return_const(true);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let global = TestLayer::<Tag<0>>::global_instance();
+        global
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap()
+            .hooks()
+            .expect_cmd_set_viewport()
+            .return_const(LayerResult::Unhandled);
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+
+        let command_buffer =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::PRIMARY);
+        let command_buffer_info = global.command_buffer_info(command_buffer).unwrap();
+        unsafe {
+            device.begin_command_buffer(command_buffer, &vk::CommandBufferBeginInfo::default())
+        }
+        .unwrap();
+        record_test_commands(device, command_buffer);
+        let recorded_commands = command_buffer_info.recorded_commands().unwrap().clone();
+        check_test_commands(&recorded_commands);
+
+        // The replayed commands are recorded into the other command buffer again.
+        let replayed_command_buffer =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::PRIMARY);
+        unsafe {
+            device.begin_command_buffer(
+                replayed_command_buffer,
+                &vk::CommandBufferBeginInfo::default(),
+            )
+        }
+        .unwrap();
+        for recorded_command in &recorded_commands {
+            unsafe { recorded_command.replay(device, replayed_command_buffer) }.unwrap();
+        }
+        check_test_commands(
+            &global
+                .command_buffer_info(replayed_command_buffer)
+                .unwrap()
+                .recorded_commands()
+                .unwrap(),
+        );
+
+        // vkBeginCommandBuffer implicitly resets the command buffer.
+        unsafe {
+            device.begin_command_buffer(command_buffer, &vk::CommandBufferBeginInfo::default())
+        }
+        .unwrap();
+        assert!(command_buffer_info.recorded_commands().unwrap().is_empty());
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+
+    #[test]
+    fn test_replay_should_fail_on_extension_commands() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+        let command_buffer =
+            allocate_command_buffer(device, command_pool, vk::CommandBufferLevel::PRIMARY);
+        let recorded_command = RecordedCommand::CmdDrawMeshTasksExt(unsafe {
+            DeepCopied::new(CmdDrawMeshTasksExtArgs {
+                command_buffer,
+                group_countx: 1,
+                group_county: 1,
+                group_countz: 1,
+            })
+        });
+        assert!(matches!(
+            unsafe { recorded_command.replay(device, command_buffer) },
+            Err(ReplayError::ExtensionCommand("vkCmdDrawMeshTasksEXT"))
+        ));
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+}
+
+mod api_dump {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_should_dump_commands_forwarded_from_after_hooks() {
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::FlushMappedMemoryRanges]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::FlushMappedMemoryRanges]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+
+        let buffer = SharedBuffer::default();
+        let api_dump = ApiDump::to_writer(ApiDumpFormat::Text, buffer.clone());
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut first_mock_hooks = first_device_info.mock_hooks.lock().unwrap();
+        first_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Unhandled);
+        first_mock_hooks
+            .expect_after_flush_mapped_memory_ranges()
+            .returning(move |memory_ranges, result| {
+                api_dump.after_flush_mapped_memory_ranges(memory_ranges, result)
+            });
+        drop(first_mock_hooks);
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut second_mock_hooks = second_device_info.mock_hooks.lock().unwrap();
+        second_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .once()
+            .return_const_st(LayerResult::Handled(Ok(())));
+        second_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Handled(Err(vk::Result::ERROR_DEVICE_LOST)));
+        drop(second_mock_hooks);
+
+        let memory_range = vk::MappedMemoryRange::builder()
+            .memory(vk::DeviceMemory::from_raw(0x42))
+            .offset(64)
+            .size(vk::WHOLE_SIZE)
+            .build();
+        unsafe { device.flush_mapped_memory_ranges(&[memory_range]) }.unwrap();
+        assert_eq!(
+            unsafe { device.flush_mapped_memory_ranges(&[]) },
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+
+        let dumped = String::from_utf8(buffer.0.lock().clone()).unwrap();
+        assert_eq!(
+            dumped.lines().collect::<Vec<_>>(),
+            [
+                concat!(
+                    "vkFlushMappedMemoryRanges(pMemoryRanges: [VkMappedMemoryRange { ",
+                    "sType: MAPPED_MEMORY_RANGE, pNext: null, memory: 0x42, offset: 64, ",
+                    "size: 18446744073709551615 }]) -> SUCCESS"
+                ),
+                "vkFlushMappedMemoryRanges(pMemoryRanges: []) -> ERROR_DEVICE_LOST",
+            ]
+        );
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_spans {
+    use super::*;
+    use std::collections::BTreeMap;
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct RecordedSpan {
+        name: &'static str,
+        parent: Option<u64>,
+        fields: BTreeMap<&'static str, String>,
+    }
+
+    struct FieldRecorder<'a>(&'a mut BTreeMap<&'static str, String>);
+
+    impl Visit for FieldRecorder<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    /// Records all spans created on the current thread. The ID of a span is its index plus 1.
+    #[derive(Default)]
+    struct SpanRecorder {
+        spans: Arc<Mutex<Vec<RecordedSpan>>>,
+        entered: Mutex<Vec<u64>>,
+    }
+
+    impl Subscriber for SpanRecorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let mut span = RecordedSpan {
+                name: attributes.metadata().name(),
+                parent: self.entered.lock().last().copied(),
+                fields: BTreeMap::new(),
+            };
+            attributes.record(&mut FieldRecorder(&mut span.fields));
+            let mut spans = self.spans.lock();
+            spans.push(span);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock();
+            let span = &mut spans[span.into_u64() as usize - 1];
+            values.record(&mut FieldRecorder(&mut span.fields));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            self.entered.lock().push(span.into_u64());
+        }
+
+        fn exit(&self, _: &Id) {
+            self.entered.lock().pop();
+        }
+    }
+
+    #[test]
+    fn test_should_emit_nested_spans_for_each_layer() {
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::FlushMappedMemoryRanges]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::FlushMappedMemoryRanges]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut first_mock_hooks = first_device_info.mock_hooks.lock().unwrap();
+        first_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Unhandled);
+        first_mock_hooks
+            .expect_after_flush_mapped_memory_ranges()
+            .return_const(());
+        drop(first_mock_hooks);
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        second_device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Handled(Err(vk::Result::ERROR_DEVICE_LOST)));
+
+        let recorder = SpanRecorder::default();
+        let spans = recorder.spans.clone();
+        let result = tracing::subscriber::with_default(recorder, || unsafe {
+            device.flush_mapped_memory_ranges(&[])
+        });
+        assert_eq!(result, Err(vk::Result::ERROR_DEVICE_LOST));
+
+        let handle = format!("{:?}", device.handle());
+        let expected_fields = |handled: &str| {
+            BTreeMap::from([
+                ("handle", handle.clone()),
+                ("handled", handled.to_owned()),
+                ("result", "ERROR_DEVICE_LOST".to_owned()),
+            ])
+        };
+        assert_eq!(
+            *spans.lock(),
+            [
+                RecordedSpan {
+                    name: "vkFlushMappedMemoryRanges",
+                    parent: None,
+                    fields: expected_fields("false"),
+                },
+                RecordedSpan {
+                    name: "vkFlushMappedMemoryRanges",
+                    parent: Some(1),
+                    fields: expected_fields("true"),
+                },
+            ]
+        );
+    }
+}
+
+mod command_observer {
+    use super::*;
+    use std::time::Duration;
+    use vulkan_layer::{CommandObserver, DispatchableHandle, ObservedCommands};
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum ObservedEvent {
+        Enter(LayerVulkanCommand, DispatchableHandle),
+        Exit(LayerVulkanCommand, Option<vk::Result>),
+    }
+
+    #[derive(Default)]
+    struct EventRecorder {
+        events: Mutex<Vec<ObservedEvent>>,
+    }
+
+    impl CommandObserver for EventRecorder {
+        fn observed_commands(&self) -> ObservedCommands {
+            ObservedCommands::Only(vec![LayerVulkanCommand::FlushMappedMemoryRanges])
+        }
+
+        fn on_enter(&self, command: LayerVulkanCommand, handle: DispatchableHandle) {
+            self.events
+                .lock()
+                .push(ObservedEvent::Enter(command, handle));
+        }
+
+        fn on_exit(
+            &self,
+            command: LayerVulkanCommand,
+            result: Option<vk::Result>,
+            _elapsed: Duration,
+        ) {
+            self.events
+                .lock()
+                .push(ObservedEvent::Exit(command, result));
+        }
+    }
+
+    #[test]
+    fn test_should_only_observe_listed_commands() {
+        static OBSERVER: LazyLock<EventRecorder> = LazyLock::new(Default::default);
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
+                mock.expect_command_observer()
+                    .return_const(Some(&*OBSERVER as &'static dyn CommandObserver));
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands().return_const(vec![
+                    LayerVulkanCommand::FlushMappedMemoryRanges,
+                    LayerVulkanCommand::DestroyImage,
+                ]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+
+        // vkFlushMappedMemoryRanges is intercepted even though the first layer doesn't hook it,
+        // while vkDestroyImage is hooked but not observed.
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut first_mock_hooks = first_device_info.mock_hooks.lock().unwrap();
+        first_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Unhandled);
+        first_mock_hooks
+            .expect_after_flush_mapped_memory_ranges()
+            .return_const(());
+        first_mock_hooks
+            .expect_destroy_image()
+            .return_const_st(LayerResult::Unhandled);
+        first_mock_hooks
+            .expect_after_destroy_image()
+            .return_const(());
+        drop(first_mock_hooks);
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut second_mock_hooks = second_device_info.mock_hooks.lock().unwrap();
+        second_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Handled(Err(vk::Result::ERROR_DEVICE_LOST)));
+        second_mock_hooks
+            .expect_destroy_image()
+            .return_const_st(LayerResult::Handled(()));
+        drop(second_mock_hooks);
+
+        assert_eq!(
+            unsafe { device.flush_mapped_memory_ranges(&[]) },
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+        unsafe { device.destroy_image(vk::Image::null(), None) };
+
+        let handle = DispatchableHandle::Device(device.handle());
+        assert_eq!(
+            *OBSERVER.events.lock(),
+            [
+                ObservedEvent::Enter(LayerVulkanCommand::FlushMappedMemoryRanges, handle),
+                ObservedEvent::Exit(
+                    LayerVulkanCommand::FlushMappedMemoryRanges,
+                    Some(vk::Result::ERROR_DEVICE_LOST)
+                ),
+            ]
+        );
+    }
+}
+
+mod command_stats {
+    use super::*;
+    use vulkan_layer::StatsSnapshot;
+
+    #[test]
+    fn test_should_count_calls_errors_and_next_layer_time() {
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_collect_command_stats().return_const(true);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands().return_const(vec![
+                    LayerVulkanCommand::FlushMappedMemoryRanges,
+                    LayerVulkanCommand::DestroyImage,
+                ]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let instance_ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>();
+        TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_instance_info(instance_ctx.instance.handle())
+            .unwrap()
+            .hooks()
+            .expect_after_get_physical_device_queue_family_properties()
+            .return_const(());
+        let ctx = instance_ctx.default_device().unwrap();
+        let DeviceContext {
+            device,
+            instance_context,
+            ..
+        } = ctx.as_ref();
+        let instance = instance_context.instance.handle();
+
+        // All the commands are intercepted even though the first layer doesn't hook them.
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut first_mock_hooks = first_device_info.mock_hooks.lock().unwrap();
+        first_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Unhandled);
+        first_mock_hooks
+            .expect_after_flush_mapped_memory_ranges()
+            .return_const(());
+        first_mock_hooks
+            .expect_destroy_image()
+            .return_const_st(LayerResult::Handled(()));
+        drop(first_mock_hooks);
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut second_mock_hooks = second_device_info.mock_hooks.lock().unwrap();
+        second_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .return_const_st(LayerResult::Handled(Err(vk::Result::ERROR_DEVICE_LOST)));
+        drop(second_mock_hooks);
+
+        for _ in 0..2 {
+            assert_eq!(
+                unsafe { device.flush_mapped_memory_ranges(&[]) },
+                Err(vk::Result::ERROR_DEVICE_LOST)
+            );
+        }
+        unsafe { device.destroy_image(vk::Image::null(), None) };
+
+        let global = TestLayer::<Tag<0>>::global_instance();
+        let snapshot = global.stats_snapshot();
+        assert!(snapshot.instances.contains_key(&instance));
+        let device_stats = &snapshot.devices[&device.handle()];
+        let flush_stats = &device_stats[&LayerVulkanCommand::FlushMappedMemoryRanges];
+        assert_eq!(flush_stats.calls, 2);
+        assert_eq!(flush_stats.errors, 2);
+        assert_eq!(flush_stats.layer_time.count(), 2);
+        assert_eq!(flush_stats.next_layer_time.count(), 2);
+        // The next layer is not called if the command is handled by the layer.
+        let destroy_image_stats = &device_stats[&LayerVulkanCommand::DestroyImage];
+        assert_eq!(destroy_image_stats.calls, 1);
+        assert_eq!(destroy_image_stats.errors, 0);
+        assert_eq!(destroy_image_stats.layer_time.count(), 1);
+        assert_eq!(destroy_image_stats.next_layer_time.count(), 0);
+
+        drop(ctx);
+        assert_eq!(global.stats_snapshot(), StatsSnapshot::default());
+    }
+
+    #[test]
+    fn test_should_not_collect_stats_by_default() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let _ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        assert_eq!(
+            TestLayer::<Tag<0>>::global_instance().stats_snapshot(),
+            StatsSnapshot::default()
+        );
+    }
+}
+
+mod fault_injection {
+    use super::*;
+    use vulkan_layer::{FaultInjector, FaultRule};
+
+    #[test]
+    fn test_should_inject_fault_without_calling_hooks_and_next_layer() {
+        static INJECTOR: LazyLock<FaultInjector> = LazyLock::new(|| {
+            let injector = FaultInjector::new();
+            injector.add_rule(
+                "vkFlushMappedMemoryRanges:VK_ERROR_OUT_OF_DEVICE_MEMORY:nth=2"
+                    .parse::<FaultRule>()
+                    .unwrap(),
+            );
+            injector
+        });
+        static TEST_GLOBAL0: TestGlobal<Tag<0>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_fault_injector().return_const(Some(&*INJECTOR));
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL0.create_context();
+        static TEST_GLOBAL1: TestGlobal<Tag<1>> = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::FlushMappedMemoryRanges]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+
+        // The first layer intercepts the command for the fault injection even though it doesn't
+        // hook it.
+        let first_device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut first_mock_hooks = first_device_info.mock_hooks.lock().unwrap();
+        first_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .times(2)
+            .return_const_st(LayerResult::Unhandled);
+        first_mock_hooks
+            .expect_after_flush_mapped_memory_ranges()
+            .times(2)
+            .return_const(());
+        drop(first_mock_hooks);
+        let second_device_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        let mut second_mock_hooks = second_device_info.mock_hooks.lock().unwrap();
+        second_mock_hooks
+            .expect_flush_mapped_memory_ranges()
+            .times(2)
+            .return_const_st(LayerResult::Handled(Ok(())));
+        drop(second_mock_hooks);
+
+        assert_eq!(unsafe { device.flush_mapped_memory_ranges(&[]) }, Ok(()));
+        assert_eq!(
+            unsafe { device.flush_mapped_memory_ranges(&[]) },
+            Err(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY)
+        );
+        assert_eq!(unsafe { device.flush_mapped_memory_ranges(&[]) }, Ok(()));
+    }
+}
+
+mod thread_safety {
+    use super::*;
+    use std::{sync::mpsc, thread};
+    use vulkan_layer::{
+        ExternSyncObject, ObjectAccess, ThreadSafetyChecker, ThreadSafetyViolation,
+    };
+
+    #[test]
+    fn test_should_report_handle_used_by_two_threads_at_the_same_time() {
+        static CHECKER: LazyLock<ThreadSafetyChecker> = LazyLock::new(ThreadSafetyChecker::new);
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::DestroyImage]);
+                mock.expect_thread_safety_checker()
+                    .return_const(Some(&*CHECKER));
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let image = vk::Image::from_raw(0x42);
+
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let (resume_tx, resume_rx) = mpsc::channel::<()>();
+        let device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_destroy_image()
+            .with(eq(image), always())
+            .times(2)
+            .returning(move |_, _| {
+                entered_tx.send(thread::current().id()).unwrap();
+                resume_rx.recv().unwrap();
+                LayerResult::Handled(())
+            });
+
+        thread::scope(|s| {
+            let first_thread = s.spawn(|| unsafe { device.destroy_image(image, None) });
+            let first_thread_id = entered_rx.recv().unwrap();
+            // The second call is checked before it waits for the first call to leave the hook.
+            let second_thread = s.spawn(|| unsafe { device.destroy_image(image, None) });
+            let violations = loop {
+                let violations = CHECKER.take_violations();
+                if !violations.is_empty() {
+                    break violations;
+                }
+                thread::yield_now();
+            };
+            assert_eq!(
+                violations,
+                vec![ThreadSafetyViolation {
+                    object: ExternSyncObject::new(image),
+                    command: LayerVulkanCommand::DestroyImage,
+                    access: ObjectAccess::Write,
+                    thread: second_thread.thread().id(),
+                    other_command: LayerVulkanCommand::DestroyImage,
+                    other_access: ObjectAccess::Write,
+                    other_thread: first_thread_id,
+                }]
+            );
+            resume_tx.send(()).unwrap();
+            first_thread.join().unwrap();
+            assert_eq!(entered_rx.recv().unwrap(), second_thread.thread().id());
+            resume_tx.send(()).unwrap();
+            second_thread.join().unwrap();
+        });
+
+        // Only the overlapping part of the calls is reported.
+        assert!(CHECKER.take_violations().is_empty());
+    }
+
+    #[test]
+    fn test_should_report_handle_read_while_another_thread_writes_it() {
+        static CHECKER: LazyLock<ThreadSafetyChecker> = LazyLock::new(ThreadSafetyChecker::new);
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_device_commands()
+                    .return_const(vec![LayerVulkanCommand::CmdSetViewport]);
+                mock.expect_thread_safety_checker()
+                    .return_const(Some(&*CHECKER));
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let DeviceContext { device, .. } = ctx.as_ref();
+        let command_pool =
+            unsafe { device.create_command_pool(&vk::CommandPoolCreateInfo::default(), None) }
+                .unwrap();
+        let primary = command_buffer_state::allocate_command_buffer(
+            device,
+            command_pool,
+            vk::CommandBufferLevel::PRIMARY,
+        );
+        let secondary = command_buffer_state::allocate_command_buffer(
+            device,
+            command_pool,
+            vk::CommandBufferLevel::SECONDARY,
+        );
+
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let (resume_tx, resume_rx) = mpsc::channel::<()>();
+        let device_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_device_info(device.handle())
+            .unwrap();
+        device_info
+            .mock_hooks
+            .lock()
+            .unwrap()
+            .expect_cmd_set_viewport()
+            .once()
+            .returning(move |_, _, _| {
+                entered_tx.send(thread::current().id()).unwrap();
+                resume_rx.recv().unwrap();
+                LayerResult::Handled(())
+            });
+
+        let viewport = vk::Viewport::builder().width(64.0).height(32.0).build();
+        thread::scope(|s| {
+            let writer = s.spawn(|| unsafe { device.cmd_set_viewport(secondary, 0, &[viewport]) });
+            let writer_id = entered_rx.recv().unwrap();
+            // vkCmdExecuteCommands reads the secondary command buffer, which vkCmdSetViewport is
+            // writing. The check happens before the reader waits for the mock hooks.
+            let reader = s.spawn(|| unsafe { device.cmd_execute_commands(primary, &[secondary]) });
+            let violations = loop {
+                let violations = CHECKER.take_violations();
+                if !violations.is_empty() {
+                    break violations;
+                }
+                thread::yield_now();
+            };
+            assert_eq!(
+                violations,
+                vec![ThreadSafetyViolation {
+                    object: ExternSyncObject::new(secondary),
+                    command: LayerVulkanCommand::CmdExecuteCommands,
+                    access: ObjectAccess::Read,
+                    thread: reader.thread().id(),
+                    other_command: LayerVulkanCommand::CmdSetViewport,
+                    other_access: ObjectAccess::Write,
+                    other_thread: writer_id,
+                }]
+            );
+            resume_tx.send(()).unwrap();
+            reader.join().unwrap();
+            writer.join().unwrap();
+        });
+
+        // Reading the command buffer is fine once the other thread stops writing it.
+        unsafe { device.cmd_execute_commands(primary, &[secondary]) };
+        assert!(CHECKER.take_violations().is_empty());
+        unsafe { device.destroy_command_pool(command_pool, None) };
+    }
+}
+
+mod enumerate_instance_extensions {
+    use super::*;
+
+    #[test]
+    fn test_should_return_defined_extensions() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_manifest().return_const({
+                    let mut layer_manifest = LayerManifest::test_default();
+                    layer_manifest.instance_extensions = &[ExtensionProperties {
+                        name: Extension::EXTDebugUtils,
+                        spec_version: 2,
+                    }];
+                    layer_manifest
+                });
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let entry = create_entry::<TestLayer>();
+        let layer_name = CString::new(TestLayer::<Tag<0>>::manifest().name).unwrap();
+        let properties = entry
+            .enumerate_instance_extension_properties(Some(&layer_name))
+            .unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0].spec_version, 2);
+        assert_eq!(
+            unsafe { CStr::from_ptr(properties[0].extension_name.as_ptr()) },
+            vk::ExtDebugUtilsFn::name()
+        );
+    }
+
+    #[test]
+    fn test_should_return_layer_settings_extension_if_enabled_in_manifest() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_manifest().return_const({
+                    let mut layer_manifest = LayerManifest::test_default();
+                    layer_manifest.instance_extensions = &[ExtensionProperties {
+                        name: Extension::EXTDebugUtils,
+                        spec_version: 2,
+                    }];
+                    layer_manifest.layer_settings_extension = true;
+                    layer_manifest
+                });
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let entry = create_entry::<TestLayer>();
+        let layer_name = CString::new(TestLayer::<Tag<0>>::manifest().name).unwrap();
+        let properties = entry
+            .enumerate_instance_extension_properties(Some(&layer_name))
+            .unwrap();
+        let properties = properties
+            .iter()
+            .map(|property| {
+                (
+                    unsafe { CStr::from_ptr(property.extension_name.as_ptr()) },
+                    property.spec_version,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            properties,
+            [
+                (vk::ExtDebugUtilsFn::name(), 2),
+                (EXT_LAYER_SETTINGS_NAME, EXT_LAYER_SETTINGS_SPEC_VERSION)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_return_layer_not_present_if_layer_name_doesnt_match() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let entry = create_entry::<TestLayer>();
+        let mut property_count = 0;
+        let res = unsafe {
+            (entry.fp_v1_0().enumerate_instance_extension_properties)(
+                c"VK_LAYER_UNKNOWN_unknown".as_ptr(),
+                &mut property_count,
+                null_mut(),
+            )
+        };
+        assert_eq!(res, vk::Result::ERROR_LAYER_NOT_PRESENT);
+    }
+}
+
+mod enumerate_device_extensions {
+    use super::*;
+    #[test]
+    fn test_should_return_defined_extensions() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_manifest().return_const({
+                    let mut layer_manifest = LayerManifest::test_default();
+                    layer_manifest.device_extensions = &[ExtensionProperties {
+                        name: Extension::KHRExternalMemory,
+                        spec_version: 1,
+                    }];
+                    layer_manifest
+                });
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let InstanceContext { instance, .. } = ctx.as_ref();
+        unsafe { InstanceData::from_handle(instance.handle()) }
+            .set_available_device_extensions(&[]);
+        let physical_device = *unsafe { instance.enumerate_physical_devices() }
+            .unwrap()
+            .first()
+            .unwrap();
+        let layer_name = CString::new(TestLayer::<Tag<0>>::manifest().name).unwrap();
+        let properties = {
+            let mut property_count = MaybeUninit::uninit();
+            let res = unsafe {
+                (instance.fp_v1_0().enumerate_device_extension_properties)(
+                    physical_device,
+                    layer_name.as_ptr(),
+                    property_count.as_mut_ptr(),
+                    null_mut(),
+                )
+            };
+            assert_eq!(res, vk::Result::SUCCESS);
+            let mut property_count = unsafe { property_count.assume_init() };
+            let mut properties = Vec::<vk::ExtensionProperties>::new();
+            properties.resize_with(property_count.try_into().unwrap(), Default::default);
+            let res = unsafe {
+                (instance.fp_v1_0().enumerate_device_extension_properties)(
+                    physical_device,
+                    layer_name.as_ptr(),
+                    &mut property_count,
+                    properties.as_mut_ptr(),
+                )
+            };
+            assert_eq!(res, vk::Result::SUCCESS);
+            properties.into_boxed_slice()
+        };
+        let expected_properties = TestLayer::<Tag<0>>::manifest()
+            .device_extensions
+            .iter()
+            .cloned()
+            .map(Into::<vk::ExtensionProperties>::into)
+            .collect::<Vec<_>>();
+        assert_eq!(expected_properties.len(), properties.len());
+        for (expected_property, property) in zip(expected_properties.iter(), properties.iter()) {
+            assert_eq!(expected_property.spec_version, property.spec_version);
+            let expected_name_bytes = expected_property
+                .extension_name
+                .iter()
+                .take_while(|byte| **byte != b'\0'.try_into().unwrap())
+                .collect::<Vec<_>>();
+            let name_bytes = property
+                .extension_name
+                .iter()
+                .take_while(|byte| **byte != b'\0'.try_into().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(expected_name_bytes, name_bytes);
+        }
+    }
+
+    // TODO: test if the loader will add the implicit layer extension in e2e test.
+
+    #[test]
+    fn test_should_call_into_the_next_chain_if_layer_name_doesnt_match() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_manifest().return_const({
+                    let mut layer_manifest = LayerManifest::test_default();
+                    layer_manifest.device_extensions = &[ExtensionProperties {
+                        name: Extension::KHRExternalMemory,
+                        spec_version: 1,
+                    }];
+                    layer_manifest
+                });
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let InstanceContext { instance, .. } = ctx.as_ref();
+        let available_device_extensions = &[Extension::KHRSwapchain];
+        unsafe { InstanceData::from_handle(instance.handle()) }
+            .set_available_device_extensions(available_device_extensions);
+        let physical_device = *unsafe { instance.enumerate_physical_devices() }
+            .unwrap()
+            .first()
+            .unwrap();
+        let properties = unsafe { instance.enumerate_device_extension_properties(physical_device) }
+            .unwrap()
+            .into_boxed_slice();
+        let property_names = properties
+            .iter()
+            .map(|vk::ExtensionProperties { extension_name, .. }| {
+                unsafe { CStr::from_ptr(extension_name.as_ptr()) }
                     .to_str()
                     .unwrap()
                     .try_into()
@@ -1865,12 +4044,22 @@ mod create_destroy_device {
             ))
             .once()
             .return_const(LayerResult::Unhandled);
+        layer1_instance_info
+            .hooks()
+            .expect_after_create_device()
+            .once()
+            .return_const(());
         layer2_instance_info
             .hooks()
             .expect_create_device()
             .withf_st(match_create_device(None, &icd_layer_link))
             .once()
             .return_const(LayerResult::Unhandled);
+        layer2_instance_info
+            .hooks()
+            .expect_after_create_device()
+            .once()
+            .return_const(());
         let device_ctx = instance_ctx.default_device().unwrap();
         layer1_instance_info.hooks().checkpoint();
         layer2_instance_info.hooks().checkpoint();
@@ -1929,6 +4118,11 @@ mod create_destroy_device {
             })
             .once()
             .return_const(LayerResult::Unhandled);
+        instance_info
+            .hooks()
+            .expect_after_create_device()
+            .once()
+            .return_const(());
         let device_ctx = instance_ctx
             .create_device_context(|create_info, create_device| {
                 create_device(create_info.enabled_extension_names(&device_extensions))
@@ -1950,73 +4144,462 @@ mod create_destroy_device {
     }
 
     #[test]
-    fn test_destroy_device_will_actually_destroy_underlying_device_info() {
+    fn test_destroy_device_will_actually_destroy_underlying_device_info() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let dropped: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        {
+            let ctx = vk::InstanceCreateInfo::builder()
+                .default_instance::<(TestLayer,)>()
+                .default_device()
+                .unwrap();
+            let DeviceContext { device, .. } = ctx.as_ref();
+            let device_data = TestLayer::<Tag<0>>::global_instance()
+                .layer_info
+                .get_device_info(device.handle())
+                .unwrap();
+            device_data.with_mock_drop(|mock_drop| {
+                mock_drop.expect_drop().once().return_once({
+                    let dropped = Arc::clone(&dropped);
+                    move || {
+                        dropped.store(true, std::sync::atomic::Ordering::SeqCst);
+                    }
+                });
+            });
+            // Calling vkDestroyDevice through RAII.
+        }
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_after_create_device_should_receive_the_created_device() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_instance_commands()
+                    .return_const(vec![LayerVulkanCommand::CreateDevice]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let instance_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_instance_info(instance_ctx.instance.handle())
+            .unwrap();
+        let created_device = Arc::new(Mutex::new(None));
+        instance_info
+            .hooks()
+            .expect_create_device()
+            .once()
+            .return_const(LayerResult::Unhandled);
+        instance_info
+            .hooks()
+            .expect_after_create_device()
+            .once()
+            .returning({
+                let created_device = Arc::clone(&created_device);
+                move |_, _, _, _, p_device, result| {
+                    assert_eq!(result, vk::Result::SUCCESS);
+                    *created_device.lock() = p_device.copied();
+                }
+            });
+        let device_ctx = instance_ctx.default_device().unwrap();
+        instance_info.hooks().checkpoint();
+        assert_eq!(*created_device.lock(), Some(device_ctx.device.handle()));
+    }
+
+    #[test]
+    fn test_after_hook_should_receive_the_written_part_of_the_output_array() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_instance_commands().return_const(vec![
+                    LayerVulkanCommand::GetPhysicalDeviceQueueFamilyProperties,
+                ]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let instance = &instance_ctx.instance;
+        let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+        let instance_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_instance_info(instance.handle())
+            .unwrap();
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        instance_info
+            .hooks()
+            .expect_after_get_physical_device_queue_family_properties()
+            .times(2)
+            .returning({
+                let observed = Arc::clone(&observed);
+                move |_, p_queue_family_property_count, p_queue_family_properties| {
+                    observed.lock().push((
+                        *p_queue_family_property_count,
+                        p_queue_family_properties.map(<[_]>::to_vec),
+                    ));
+                }
+            });
+        let get_physical_device_queue_family_properties = instance
+            .fp_v1_0()
+            .get_physical_device_queue_family_properties;
+        let mut count = 0;
+        unsafe {
+            get_physical_device_queue_family_properties(
+                physical_device,
+                &mut count,
+                std::ptr::null_mut(),
+            )
+        };
+        // Leave room for more entries than the driver writes, the hook should only see the
+        // written ones.
+        let mut properties = vec![vk::QueueFamilyProperties::default(); count as usize + 1];
+        let mut written_count = count + 1;
+        unsafe {
+            get_physical_device_queue_family_properties(
+                physical_device,
+                &mut written_count,
+                properties.as_mut_ptr(),
+            )
+        };
+        instance_info.hooks().checkpoint();
+        assert_eq!(written_count, count);
+        let observed = observed.lock();
+        assert_eq!(observed[0].0, count);
+        assert!(observed[0].1.is_none());
+        assert_eq!(observed[1].0, count);
+        let observed_properties = observed[1].1.as_ref().unwrap();
+        assert_eq!(observed_properties.len(), count as usize);
+        for (observed, expected) in observed_properties.iter().zip(properties.iter()) {
+            assert_eq!(observed.queue_flags, expected.queue_flags);
+            assert_eq!(observed.queue_count, expected.queue_count);
+        }
+    }
+
+    #[test]
+    fn layer_shouldnt_modify_the_pdevice_pointer_when_calling_create_device() {
+        static TEST_GLOBAL1: TestGlobal<Tag<0>> = TestGlobal::<Tag<0>>::builder().build();
+        static TEST_GLOBAL2: TestGlobal<Tag<1>> = TestGlobal::<Tag<1>>::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_instance_commands()
+                    .return_const(vec![LayerVulkanCommand::CreateDevice]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL1.create_context();
+        let _ctx = TEST_GLOBAL2.create_context();
+        let instance_ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>();
+        let mut device = MaybeUninit::<vk::Device>::uninit();
+        let instance_info = TestLayer::<Tag<1>>::global_instance()
+            .layer_info
+            .get_instance_info(instance_ctx.instance.handle())
+            .unwrap();
+        instance_info
+            .hooks()
+            .expect_create_device()
+            .once()
+            .withf_st({
+                let device_ptr = device.as_ptr();
+                move |_, _, _, _, p_device| p_device.as_ptr() == device_ptr
+            })
+            .return_const(LayerResult::Unhandled);
+        instance_info
+            .hooks()
+            .expect_after_create_device()
+            .once()
+            .withf_st({
+                let device_ptr = device.as_ptr();
+                move |_, _, _, _, p_device, _| p_device.map(std::ptr::from_ref) == Some(device_ptr)
+            })
+            .return_const(());
+        let res = unsafe {
+            instance_ctx.create_device(
+                |create_info_builder, create_device| {
+                    create_device(create_info_builder);
+                },
+                device.as_mut_ptr(),
+            )
+        };
+        let device = unsafe { res.assume_init_on_success(device) }.unwrap();
+        let device = unsafe { ash::Device::load(instance_ctx.instance.fp_v1_0(), device) };
+        unsafe { device.destroy_device(None) };
+    }
+}
+
+mod loader_data_callback {
+    use super::*;
+
+    #[test]
+    fn test_set_device_loader_data_should_call_into_loader_callback() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        static CALLS: Mutex<Vec<(u64, usize)>> = Mutex::new(Vec::new());
+        unsafe extern "C" fn set_device_loader_data(
+            device: vk::Device,
+            object: *mut c_void,
+        ) -> vk::Result {
+            CALLS.lock().push((device.as_raw(), object as usize));
+            vk::Result::SUCCESS
+        }
+
+        let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let mut loader_data_callback_info = VkLayerDeviceCreateInfo {
+            sType: vk::StructureType::LOADER_DEVICE_CREATE_INFO,
+            pNext: null(),
+            function: VkLayerFunction::VK_LOADER_DATA_CALLBACK,
+            u: Default::default(),
+        };
+        loader_data_callback_info.u.pfnSetDeviceLoaderData = Some(set_device_loader_data);
+        let device_ctx = instance_ctx
+            .create_device_context(|create_info, create_device| {
+                create_device(create_info.push_next(&mut loader_data_callback_info))
+            })
+            .unwrap();
+        let device = device_ctx.device.handle();
+
+        let mut object_storage: *const c_void = null();
+        let object = unsafe {
+            std::mem::transmute::<*mut *const c_void, vk::CommandBuffer>(&mut object_storage)
+        };
+        unsafe {
+            TestLayer::<Tag<0>>::global_instance()
+                .set_device_loader_data(device, object)
+                .unwrap()
+        };
+        assert_eq!(
+            *CALLS.lock(),
+            vec![(
+                device.as_raw(),
+                &mut object_storage as *mut *const c_void as usize
+            )]
+        );
+    }
+
+    #[test]
+    fn test_set_device_loader_data_should_fail_without_loader_callback() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let device_ctx = vk::InstanceCreateInfo::builder()
+            .default_instance::<(TestLayer,)>()
+            .default_device()
+            .unwrap();
+        let device = device_ctx.device.handle();
+
+        let mut object_storage: *const c_void = null();
+        let object =
+            unsafe { std::mem::transmute::<*mut *const c_void, vk::Queue>(&mut object_storage) };
+        let res = unsafe {
+            TestLayer::<Tag<0>>::global_instance().set_device_loader_data(device, object)
+        };
+        assert_eq!(res, Err(vk::Result::ERROR_INITIALIZATION_FAILED));
+        assert!(object_storage.is_null());
+    }
+
+    #[test]
+    fn test_get_device_queue_should_initialize_loader_data() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        static CALLS: Mutex<Vec<(u64, u64)>> = Mutex::new(Vec::new());
+        unsafe extern "C" fn set_device_loader_data(
+            device: vk::Device,
+            object: *mut c_void,
+        ) -> vk::Result {
+            CALLS.lock().push((device.as_raw(), object as u64));
+            vk::Result::SUCCESS
+        }
+
+        let instance_ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let mut loader_data_callback_info = VkLayerDeviceCreateInfo {
+            sType: vk::StructureType::LOADER_DEVICE_CREATE_INFO,
+            pNext: null(),
+            function: VkLayerFunction::VK_LOADER_DATA_CALLBACK,
+            u: Default::default(),
+        };
+        loader_data_callback_info.u.pfnSetDeviceLoaderData = Some(set_device_loader_data);
+        let device_ctx = instance_ctx
+            .create_device_context(|create_info, create_device| {
+                create_device(create_info.push_next(&mut loader_data_callback_info))
+            })
+            .unwrap();
+        let device = device_ctx.device.handle();
+        let loader_data_callback = TestLayer::<Tag<0>>::global_instance()
+            .device_loader_data_callback(device)
+            .unwrap();
+
+        let queue = loader_data_callback.get_device_queue(0, 0).unwrap();
+        assert_eq!(queue, unsafe { device_ctx.device.get_device_queue(0, 0) });
+        assert_eq!(*CALLS.lock(), vec![(device.as_raw(), queue.as_raw())]);
+        // The default device is only created with one queue.
+        assert_eq!(
+            loader_data_callback.get_device_queue(0, 1),
+            Err(vk::Result::ERROR_INITIALIZATION_FAILED)
+        );
+        assert_eq!(CALLS.lock().len(), 1);
+    }
+
+    #[test]
+    fn test_set_instance_loader_data_should_call_into_loader_callback() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        static CALLS: Mutex<Vec<(u64, usize)>> = Mutex::new(Vec::new());
+        unsafe extern "C" fn set_instance_loader_data(
+            instance: vk::Instance,
+            object: *mut c_void,
+        ) -> vk::Result {
+            CALLS.lock().push((instance.as_raw(), object as usize));
+            vk::Result::SUCCESS
+        }
+
+        let mut loader_data_callback_info = VkLayerInstanceCreateInfo {
+            sType: vk::StructureType::LOADER_INSTANCE_CREATE_INFO,
+            pNext: null(),
+            function: VkLayerFunction::VK_LOADER_DATA_CALLBACK,
+            u: Default::default(),
+        };
+        loader_data_callback_info.u.pfnSetInstanceLoaderData = Some(set_instance_loader_data);
+        let instance_ctx = vk::InstanceCreateInfo::builder()
+            .push_next(&mut loader_data_callback_info)
+            .default_instance::<(TestLayer,)>();
+        let instance = instance_ctx.instance.handle();
+
+        let mut object_storage: *const c_void = null();
+        let object = unsafe {
+            std::mem::transmute::<*mut *const c_void, vk::PhysicalDevice>(&mut object_storage)
+        };
+        unsafe {
+            TestLayer::<Tag<0>>::global_instance()
+                .set_instance_loader_data(instance, object)
+                .unwrap()
+        };
+        assert_eq!(
+            *CALLS.lock(),
+            vec![(
+                instance.as_raw(),
+                &mut object_storage as *mut *const c_void as usize
+            )]
+        );
+    }
+}
+
+mod negotiate_loader_layer_interface_version {
+    use super::*;
+
+    unsafe extern "system" fn fake_get_instance_proc_addr(
+        _: vk::Instance,
+        _: *const c_char,
+    ) -> vk::PFN_vkVoidFunction {
+        unimplemented!()
+    }
+
+    unsafe extern "system" fn fake_get_device_proc_addr(
+        _: vk::Device,
+        _: *const c_char,
+    ) -> vk::PFN_vkVoidFunction {
+        unimplemented!()
+    }
+
+    fn create_version_struct(loader_layer_interface_version: u32) -> VkNegotiateLayerInterface {
+        VkNegotiateLayerInterface {
+            sType: VkNegotiateLayerStructType::LAYER_NEGOTIATE_INTERFACE_STRUCT,
+            pNext: null_mut(),
+            loaderLayerInterfaceVersion: loader_layer_interface_version,
+            pfnGetInstanceProcAddr: fake_get_instance_proc_addr,
+            pfnGetDeviceProcAddr: fake_get_device_proc_addr,
+            pfnGetPhysicalDeviceProcAddr: None,
+        }
+    }
+
+    #[test]
+    fn test_should_fill_in_proc_addrs_with_version_2() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let mut version_struct = create_version_struct(2);
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::negotiate_loader_layer_interface_version(&mut version_struct)
+            },
+            vk::Result::SUCCESS
+        );
+        assert_eq!(version_struct.loaderLayerInterfaceVersion, 2);
+        assert_eq!(
+            version_struct.pfnGetInstanceProcAddr as usize,
+            Global::<TestLayer>::get_instance_proc_addr as vk::PFN_vkGetInstanceProcAddr as usize
+        );
+        assert_eq!(
+            version_struct.pfnGetDeviceProcAddr as usize,
+            Global::<TestLayer>::get_device_proc_addr as vk::PFN_vkGetDeviceProcAddr as usize
+        );
+        assert_eq!(
+            version_struct
+                .pfnGetPhysicalDeviceProcAddr
+                .map(|fp| fp as usize),
+            Some(
+                Global::<TestLayer>::get_physical_device_proc_addr as vk::PFN_vkGetInstanceProcAddr
+                    as usize
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_clamp_newer_loader_version_to_2() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let mut version_struct = create_version_struct(3);
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::negotiate_loader_layer_interface_version(&mut version_struct)
+            },
+            vk::Result::SUCCESS
+        );
+        assert_eq!(version_struct.loaderLayerInterfaceVersion, 2);
+        assert_eq!(
+            version_struct.pfnGetInstanceProcAddr as usize,
+            Global::<TestLayer>::get_instance_proc_addr as vk::PFN_vkGetInstanceProcAddr as usize
+        );
+    }
+
+    #[test]
+    fn test_should_not_touch_proc_addrs_with_older_loader_version() {
         static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
         let _ctx = TEST_GLOBAL.create_context();
-        let dropped: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
-        {
-            let ctx = vk::InstanceCreateInfo::builder()
-                .default_instance::<(TestLayer,)>()
-                .default_device()
-                .unwrap();
-            let DeviceContext { device, .. } = ctx.as_ref();
-            let device_data = TestLayer::<Tag<0>>::global_instance()
-                .layer_info
-                .get_device_info(device.handle())
-                .unwrap();
-            device_data.with_mock_drop(|mock_drop| {
-                mock_drop.expect_drop().once().return_once({
-                    let dropped = Arc::clone(&dropped);
-                    move || {
-                        dropped.store(true, std::sync::atomic::Ordering::SeqCst);
-                    }
-                });
-            });
-            // Calling vkDestroyDevice through RAII.
-        }
-        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+        let mut version_struct = create_version_struct(1);
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::negotiate_loader_layer_interface_version(&mut version_struct)
+            },
+            vk::Result::SUCCESS
+        );
+        assert_eq!(version_struct.loaderLayerInterfaceVersion, 1);
+        assert_eq!(
+            version_struct.pfnGetInstanceProcAddr as usize,
+            fake_get_instance_proc_addr as vk::PFN_vkGetInstanceProcAddr as usize
+        );
+        assert_eq!(
+            version_struct.pfnGetDeviceProcAddr as usize,
+            fake_get_device_proc_addr as vk::PFN_vkGetDeviceProcAddr as usize
+        );
     }
 
     #[test]
-    fn layer_shouldnt_modify_the_pdevice_pointer_when_calling_create_device() {
-        static TEST_GLOBAL1: TestGlobal<Tag<0>> = TestGlobal::<Tag<0>>::builder().build();
-        static TEST_GLOBAL2: TestGlobal<Tag<1>> = TestGlobal::<Tag<1>>::builder()
-            .set_layer_mock_builder(|| {
-                let mut mock = MockTestLayer::default();
-                mock.expect_hooked_instance_commands()
-                    .return_const(vec![LayerVulkanCommand::CreateDevice]);
-                mock.set_default_expectations();
-                mock
-            })
-            .build();
-        let _ctx = TEST_GLOBAL1.create_context();
-        let _ctx = TEST_GLOBAL2.create_context();
-        let instance_ctx = vk::InstanceCreateInfo::builder()
-            .default_instance::<(TestLayer<Tag<0>>, TestLayer<Tag<1>>)>();
-        let mut device = MaybeUninit::<vk::Device>::uninit();
-        TestLayer::<Tag<1>>::global_instance()
-            .layer_info
-            .get_instance_info(instance_ctx.instance.handle())
-            .unwrap()
-            .hooks()
-            .expect_create_device()
-            .once()
-            .withf_st({
-                let device_ptr = device.as_ptr();
-                move |_, _, _, _, p_device| p_device.as_ptr() == device_ptr
-            })
-            .return_const(LayerResult::Unhandled);
-        let res = unsafe {
-            instance_ctx.create_device(
-                |create_info_builder, create_device| {
-                    create_device(create_info_builder);
-                },
-                device.as_mut_ptr(),
-            )
-        };
-        let device = unsafe { res.assume_init_on_success(device) }.unwrap();
-        let device = unsafe { ash::Device::load(instance_ctx.instance.fp_v1_0(), device) };
-        unsafe { device.destroy_device(None) };
+    fn test_should_fail_with_unexpected_struct_type() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let mut version_struct = create_version_struct(2);
+        version_struct.sType = VkNegotiateLayerStructType::LAYER_NEGOTIATE_UNINTIALIZED;
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::negotiate_loader_layer_interface_version(&mut version_struct)
+            },
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        assert_eq!(version_struct.loaderLayerInterfaceVersion, 2);
     }
 }
 
@@ -2100,6 +4683,9 @@ fn global_should_be_trivially_destructible() {
         type DeviceInfo = StubDeviceInfo;
         type InstanceInfoContainer = StubInstanceInfo;
         type DeviceInfoContainer = StubDeviceInfo;
+        type PhysicalDeviceInfo = ();
+        type QueueInfo = ();
+        type CommandBufferInfo = ();
 
         fn global_instance() -> impl std::ops::Deref<Target = Global<Self>> + 'static {
             MutexGuard::map(GLOBAL.lock(), |global| global.as_mut().unwrap())
@@ -2119,6 +4705,7 @@ fn global_should_be_trivially_destructible() {
             _: Option<&vk::AllocationCallbacks>,
             _: Arc<ash::Instance>,
             _next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+            _: &LayerSettings,
         ) -> Self::InstanceInfoContainer {
             Default::default()
         }
@@ -2130,6 +4717,7 @@ fn global_should_be_trivially_destructible() {
             _: Option<&vk::AllocationCallbacks>,
             _: Arc<ash::Device>,
             _next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+            _: DeviceLoaderDataCallback,
         ) -> Self::DeviceInfoContainer {
             Default::default()
         }
@@ -2182,3 +4770,418 @@ fn global_should_only_call_layer_ctor_once_even_if_multiple_calls_to_global_comm
 fn global_enumerate_device_extension_properties_should_never_call_into_the_next_chain() {
     todo!("Use NULL physical device, and 2 layers with non-null physical device")
 }
+
+mod pre_instance_functions {
+    use super::*;
+    use std::{mem::size_of, ptr::NonNull};
+    use vulkan_layer::{
+        fill_vk_out_array, VkChainHeader, VkChainType, VkEnumerateInstanceExtensionPropertiesChain,
+        VkEnumerateInstanceLayerPropertiesChain, VkEnumerateInstanceVersionChain,
+        VK_CURRENT_CHAIN_VERSION,
+    };
+
+    const NEXT_API_VERSION: u32 = vk::API_VERSION_1_3;
+    const NEXT_INSTANCE_EXTENSIONS: [ExtensionProperties; 2] = [
+        ExtensionProperties {
+            name: Extension::KHRSurface,
+            spec_version: 25,
+        },
+        ExtensionProperties {
+            name: Extension::EXTDebugUtils,
+            spec_version: 2,
+        },
+    ];
+    const NEXT_LAYER_NAME: &CStr = c"VK_LAYER_GOOGLE_next";
+
+    fn chain_header<T>(type_: VkChainType) -> VkChainHeader {
+        VkChainHeader {
+            type_,
+            version: VK_CURRENT_CHAIN_VERSION,
+            size: size_of::<T>() as u32,
+        }
+    }
+
+    unsafe extern "C" fn next_enumerate_instance_version(
+        _: *const VkEnumerateInstanceVersionChain,
+        p_api_version: *mut u32,
+    ) -> vk::Result {
+        unsafe { *p_api_version = NEXT_API_VERSION };
+        vk::Result::SUCCESS
+    }
+
+    unsafe extern "C" fn next_enumerate_instance_extension_properties(
+        _: *const VkEnumerateInstanceExtensionPropertiesChain,
+        _: *const c_char,
+        p_property_count: *mut u32,
+        p_properties: *mut vk::ExtensionProperties,
+    ) -> vk::Result {
+        let properties = NEXT_INSTANCE_EXTENSIONS
+            .iter()
+            .cloned()
+            .map(Into::<vk::ExtensionProperties>::into)
+            .collect::<Vec<_>>();
+        unsafe {
+            fill_vk_out_array(
+                &properties,
+                p_property_count.as_mut().unwrap().into(),
+                p_properties,
+            )
+        }
+    }
+
+    unsafe extern "C" fn next_enumerate_instance_layer_properties(
+        _: *const VkEnumerateInstanceLayerPropertiesChain,
+        p_property_count: *mut u32,
+        p_properties: *mut vk::LayerProperties,
+    ) -> vk::Result {
+        let mut layer_properties = vk::LayerProperties::default();
+        for (dst, src) in zip(
+            layer_properties.layer_name.iter_mut(),
+            NEXT_LAYER_NAME.to_bytes_with_nul(),
+        ) {
+            *dst = *src as c_char;
+        }
+        unsafe {
+            fill_vk_out_array(
+                &[layer_properties],
+                p_property_count.as_mut().unwrap().into(),
+                p_properties,
+            )
+        }
+    }
+
+    fn create_version_chain() -> VkEnumerateInstanceVersionChain {
+        VkEnumerateInstanceVersionChain {
+            header: chain_header::<VkEnumerateInstanceVersionChain>(
+                VkChainType::VK_CHAIN_TYPE_ENUMERATE_INSTANCE_VERSION,
+            ),
+            pfnNextLayer: Some(next_enumerate_instance_version),
+            pNextLink: null(),
+        }
+    }
+
+    fn create_extension_properties_chain() -> VkEnumerateInstanceExtensionPropertiesChain {
+        VkEnumerateInstanceExtensionPropertiesChain {
+            header: chain_header::<VkEnumerateInstanceExtensionPropertiesChain>(
+                VkChainType::VK_CHAIN_TYPE_ENUMERATE_INSTANCE_EXTENSION_PROPERTIES,
+            ),
+            pfnNextLayer: Some(next_enumerate_instance_extension_properties),
+            pNextLink: null(),
+        }
+    }
+
+    fn create_layer_properties_chain() -> VkEnumerateInstanceLayerPropertiesChain {
+        VkEnumerateInstanceLayerPropertiesChain {
+            header: chain_header::<VkEnumerateInstanceLayerPropertiesChain>(
+                VkChainType::VK_CHAIN_TYPE_ENUMERATE_INSTANCE_LAYER_PROPERTIES,
+            ),
+            pfnNextLayer: Some(next_enumerate_instance_layer_properties),
+            pNextLink: null(),
+        }
+    }
+
+    fn enumerate_extension_names(
+        chain: &VkEnumerateInstanceExtensionPropertiesChain,
+    ) -> Vec<CString> {
+        let mut property_count = 0;
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::pre_instance_enumerate_instance_extension_properties(
+                    chain,
+                    null(),
+                    &mut property_count,
+                    null_mut(),
+                )
+            },
+            vk::Result::SUCCESS
+        );
+        let mut properties = vec![vk::ExtensionProperties::default(); property_count as usize];
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::pre_instance_enumerate_instance_extension_properties(
+                    chain,
+                    null(),
+                    &mut property_count,
+                    properties.as_mut_ptr(),
+                )
+            },
+            vk::Result::SUCCESS
+        );
+        properties
+            .iter()
+            .take(property_count as usize)
+            .map(|property| unsafe { CStr::from_ptr(property.extension_name.as_ptr()) }.to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_should_call_down_enumerate_instance_version_if_not_hooked() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let chain = create_version_chain();
+        let mut api_version = 0;
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::pre_instance_enumerate_instance_version(
+                    &chain,
+                    &mut api_version,
+                )
+            },
+            vk::Result::SUCCESS
+        );
+        assert_eq!(api_version, NEXT_API_VERSION);
+    }
+
+    #[test]
+    fn test_should_intercept_enumerate_instance_version_if_hooked() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_global_commands()
+                    .return_const(vec![LayerVulkanCommand::EnumerateInstanceVersion]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .global_hooks()
+            .expect_enumerate_instance_version()
+            .once()
+            .returning(|chain, p_api_version| {
+                let res = unsafe { chain.call_down(p_api_version.as_mut_ptr()) };
+                let api_version = unsafe { p_api_version.assume_init_mut() };
+                *api_version = (*api_version).min(vk::API_VERSION_1_1);
+                LayerResult::Handled(res)
+            });
+        let chain = create_version_chain();
+        let mut api_version = 0;
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::pre_instance_enumerate_instance_version(
+                    &chain,
+                    &mut api_version,
+                )
+            },
+            vk::Result::SUCCESS
+        );
+        assert_eq!(api_version, vk::API_VERSION_1_1);
+    }
+
+    #[test]
+    fn test_should_call_down_enumerate_instance_extension_properties_if_not_hooked() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let chain = create_extension_properties_chain();
+        assert_eq!(
+            enumerate_extension_names(&chain),
+            vec![
+                vk::KhrSurfaceFn::name().to_owned(),
+                vk::ExtDebugUtilsFn::name().to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_filter_instance_extension_properties_if_hooked() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_global_commands().return_const(vec![
+                    LayerVulkanCommand::EnumerateInstanceExtensionProperties,
+                ]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .global_hooks()
+            .expect_enumerate_instance_extension_properties()
+            .times(2)
+            .returning(|chain, p_layer_name, p_property_count, p_properties| {
+                let p_layer_name = p_layer_name.map_or(null(), CStr::as_ptr);
+                let mut property_count = 0;
+                let res = unsafe { chain.call_down(p_layer_name, &mut property_count, null_mut()) };
+                assert_eq!(res, vk::Result::SUCCESS);
+                let mut properties =
+                    vec![vk::ExtensionProperties::default(); property_count as usize];
+                let res = unsafe {
+                    chain.call_down(p_layer_name, &mut property_count, properties.as_mut_ptr())
+                };
+                assert_eq!(res, vk::Result::SUCCESS);
+                properties.retain(|property| {
+                    let name = unsafe { CStr::from_ptr(property.extension_name.as_ptr()) };
+                    name != vk::ExtDebugUtilsFn::name()
+                });
+                let p_out = p_properties.map_or(null_mut(), |properties| {
+                    properties.as_mut_ptr() as *mut vk::ExtensionProperties
+                });
+                LayerResult::Handled(unsafe {
+                    fill_vk_out_array(
+                        &properties,
+                        NonNull::new(p_property_count.as_mut_ptr()).unwrap(),
+                        p_out,
+                    )
+                })
+            });
+        let chain = create_extension_properties_chain();
+        assert_eq!(
+            enumerate_extension_names(&chain),
+            vec![vk::KhrSurfaceFn::name().to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_should_call_down_enumerate_instance_layer_properties_if_not_hooked() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let chain = create_layer_properties_chain();
+        let mut properties = [vk::LayerProperties::default(); 2];
+        let mut property_count = properties.len() as u32;
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::pre_instance_enumerate_instance_layer_properties(
+                    &chain,
+                    &mut property_count,
+                    properties.as_mut_ptr(),
+                )
+            },
+            vk::Result::SUCCESS
+        );
+        assert_eq!(property_count, 1);
+        assert_eq!(
+            unsafe { CStr::from_ptr(properties[0].layer_name.as_ptr()) },
+            NEXT_LAYER_NAME
+        );
+    }
+
+    #[test]
+    fn test_should_not_call_down_enumerate_instance_layer_properties_if_handled() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder()
+            .set_layer_mock_builder(|| {
+                let mut mock = MockTestLayer::default();
+                mock.expect_hooked_global_commands()
+                    .return_const(vec![LayerVulkanCommand::EnumerateInstanceLayerProperties]);
+                mock.set_default_expectations();
+                mock
+            })
+            .build();
+        let _ctx = TEST_GLOBAL.create_context();
+        TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .global_hooks()
+            .expect_enumerate_instance_layer_properties()
+            .once()
+            .returning(|_, p_property_count, _| {
+                p_property_count.write(0);
+                LayerResult::Handled(vk::Result::SUCCESS)
+            });
+        let chain = VkEnumerateInstanceLayerPropertiesChain {
+            pfnNextLayer: None,
+            ..create_layer_properties_chain()
+        };
+        let mut property_count = 1;
+        assert_eq!(
+            unsafe {
+                Global::<TestLayer>::pre_instance_enumerate_instance_layer_properties(
+                    &chain,
+                    &mut property_count,
+                    null_mut(),
+                )
+            },
+            vk::Result::SUCCESS
+        );
+        assert_eq!(property_count, 0);
+    }
+}
+
+mod layer_settings {
+    use super::*;
+    use vulkan_layer::{LayerSettingEXT, LayerSettingTypeEXT, LayerSettingsCreateInfoEXT};
+
+    #[test]
+    fn test_should_pass_settings_of_the_layer_to_create_instance_info() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let layer_name = CString::new(TestLayer::<Tag<0>>::manifest().name).unwrap();
+
+        let log_level = [2u32];
+        let log_file = [c"/tmp/layer.log".as_ptr()];
+        let ratios = [0.25f32, 0.5];
+        let other_layer_verbose = vk::TRUE;
+        let settings = [
+            LayerSettingEXT {
+                p_layer_name: layer_name.as_ptr(),
+                p_setting_name: c"log_level".as_ptr(),
+                ty: LayerSettingTypeEXT::UINT32,
+                value_count: 1,
+                p_values: log_level.as_ptr().cast(),
+            },
+            LayerSettingEXT {
+                p_layer_name: layer_name.as_ptr(),
+                p_setting_name: c"log_file".as_ptr(),
+                ty: LayerSettingTypeEXT::STRING,
+                value_count: 1,
+                p_values: log_file.as_ptr().cast(),
+            },
+            LayerSettingEXT {
+                p_layer_name: c"VK_LAYER_GOOGLE_other".as_ptr(),
+                p_setting_name: c"verbose".as_ptr(),
+                ty: LayerSettingTypeEXT::BOOL32,
+                value_count: 1,
+                p_values: &other_layer_verbose as *const _ as *const c_void,
+            },
+        ];
+        let more_settings = [LayerSettingEXT {
+            p_layer_name: layer_name.as_ptr(),
+            p_setting_name: c"ratios".as_ptr(),
+            ty: LayerSettingTypeEXT::FLOAT32,
+            value_count: ratios.len() as u32,
+            p_values: ratios.as_ptr().cast(),
+        }];
+        let mut settings_create_info = LayerSettingsCreateInfoEXT::new(&settings);
+        let mut more_settings_create_info = LayerSettingsCreateInfoEXT::new(&more_settings);
+        let ctx = vk::InstanceCreateInfo::builder()
+            .push_next(&mut settings_create_info)
+            .push_next(&mut more_settings_create_info)
+            .default_instance::<(TestLayer,)>();
+
+        let instance_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_instance_info(ctx.instance.handle())
+            .unwrap();
+        let layer_settings = &instance_info.layer_settings;
+        assert_eq!(
+            layer_settings
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            ["log_file", "log_level", "ratios"]
+        );
+        assert_eq!(layer_settings.uint32("log_level"), Some(2));
+        assert_eq!(
+            layer_settings.file("log_file"),
+            Some(std::path::Path::new("/tmp/layer.log"))
+        );
+        assert_eq!(
+            layer_settings.get("ratios"),
+            Some(&LayerSettingValue::Float32(vec![0.25, 0.5]))
+        );
+        assert!(!layer_settings.contains("verbose"));
+    }
+
+    #[test]
+    fn test_should_pass_empty_settings_without_layer_settings_create_info() {
+        static TEST_GLOBAL: TestGlobal = TestGlobal::builder().build();
+        let _ctx = TEST_GLOBAL.create_context();
+        let ctx = vk::InstanceCreateInfo::builder().default_instance::<(TestLayer,)>();
+        let instance_info = TestLayer::<Tag<0>>::global_instance()
+            .layer_info
+            .get_instance_info(ctx.instance.handle())
+            .unwrap();
+        assert!(instance_info.layer_settings.is_empty());
+    }
+}
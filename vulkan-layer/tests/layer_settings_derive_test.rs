@@ -0,0 +1,120 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+
+use vulkan_layer::{LayerSettingKind, LayerSettingValue, LayerSettings, LayerSettingsSchema};
+
+#[derive(LayerSettings)]
+struct TestSettings {
+    /// Whether to enable the layer.
+    ///
+    /// Enabled by default.
+    #[layer_setting(default = true)]
+    enabled: bool,
+    #[layer_setting(key = "max_frames", label = "Max frames", default = 16)]
+    frame_count: u32,
+    #[layer_setting(default = -1.5)]
+    bias: f64,
+    output: PathBuf,
+    filters: Vec<String>,
+    r#type: String,
+}
+
+#[test]
+fn test_derive_layer_settings_should_generate_metadata() {
+    let settings = TestSettings::SETTINGS;
+    assert_eq!(settings.len(), 6);
+
+    assert_eq!(settings[0].key, "enabled");
+    assert_eq!(settings[0].label, "enabled");
+    assert_eq!(
+        settings[0].description,
+        "Whether to enable the layer. Enabled by default."
+    );
+    assert_eq!(settings[0].kind, LayerSettingKind::Bool);
+    assert_eq!(settings[0].default, "true");
+
+    assert_eq!(settings[1].key, "max_frames");
+    assert_eq!(settings[1].label, "Max frames");
+    assert_eq!(settings[1].description, "");
+    assert_eq!(settings[1].kind, LayerSettingKind::Int);
+    assert_eq!(settings[1].default, "16");
+
+    assert_eq!(settings[2].kind, LayerSettingKind::Float);
+    assert_eq!(settings[2].default, "-1.5");
+    assert_eq!(settings[3].kind, LayerSettingKind::File);
+    assert_eq!(settings[4].kind, LayerSettingKind::String);
+    assert_eq!(settings[5].key, "type");
+}
+
+#[test]
+fn test_derive_layer_settings_should_use_defaults_for_absent_settings() {
+    let settings = TestSettings::from_layer_settings(&LayerSettings::default());
+    assert!(settings.enabled);
+    assert_eq!(settings.frame_count, 16);
+    assert_eq!(settings.bias, -1.5);
+    assert_eq!(settings.output, PathBuf::new());
+    assert!(settings.filters.is_empty());
+    assert_eq!(settings.r#type, "");
+}
+
+#[test]
+fn test_derive_layer_settings_should_read_settings_by_key() {
+    let mut layer_settings = LayerSettings::default();
+    layer_settings.insert("enabled", LayerSettingValue::Bool(vec![false]));
+    layer_settings.insert("max_frames", LayerSettingValue::Uint32(vec![3]));
+    layer_settings.insert("frame_count", LayerSettingValue::Uint32(vec![42]));
+    layer_settings.insert("bias", LayerSettingValue::String(vec!["0.25".to_owned()]));
+    layer_settings.insert(
+        "output",
+        LayerSettingValue::String(vec!["/tmp/out.txt".to_owned()]),
+    );
+    layer_settings.insert(
+        "filters",
+        LayerSettingValue::String(vec!["a, b".to_owned()]),
+    );
+    layer_settings.insert("type", LayerSettingValue::String(vec!["fast".to_owned()]));
+    let settings = TestSettings::from_layer_settings(&layer_settings);
+    assert!(!settings.enabled);
+    assert_eq!(settings.frame_count, 3);
+    assert_eq!(settings.bias, 0.25);
+    assert_eq!(settings.output, PathBuf::from("/tmp/out.txt"));
+    assert_eq!(settings.filters, vec!["a".to_owned(), "b".to_owned()]);
+    assert_eq!(settings.r#type, "fast");
+}
+
+#[test]
+fn test_derive_layer_settings_should_fall_back_to_default_for_invalid_value() {
+    let mut layer_settings = LayerSettings::default();
+    layer_settings.insert(
+        "max_frames",
+        LayerSettingValue::String(vec!["many".to_owned()]),
+    );
+    let settings = TestSettings::from_layer_settings(&layer_settings);
+    assert_eq!(settings.frame_count, 16);
+}
+
+#[test]
+fn test_derive_layer_settings_load_should_prefer_environment_variables() {
+    const LAYER_NAME: &str = "VK_LAYER_TEST_derive_load";
+    std::env::set_var("VK_TEST_DERIVE_LOAD_MAX_FRAMES", "7");
+    let mut api_settings = LayerSettings::default();
+    api_settings.insert("max_frames", LayerSettingValue::Uint32(vec![3]));
+    api_settings.insert("enabled", LayerSettingValue::Bool(vec![false]));
+    let settings = TestSettings::load(LAYER_NAME, &api_settings);
+    std::env::remove_var("VK_TEST_DERIVE_LOAD_MAX_FRAMES");
+    assert_eq!(settings.frame_count, 7);
+    assert!(!settings.enabled);
+}
@@ -24,7 +24,10 @@ use std::{
     mem::MaybeUninit,
     pin::Pin,
     ptr::{null, null_mut, NonNull},
-    sync::{Arc, Mutex, Weak},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, Weak,
+    },
 };
 use vulkan_layer::{
     fill_vk_out_array,
@@ -182,7 +185,7 @@ pub struct InstanceData {
     base: DispatchableObjectBase<InstanceDispatchTable>,
     version: ApiVersion,
     supported_device_version: Mutex<ApiVersion>,
-    enabled_extensions: BTreeSet<Extension>,
+    pub enabled_extensions: BTreeSet<Extension>,
     physical_devices: Box<[Del<vk::PhysicalDevice>]>,
     available_device_extensions: Mutex<Option<BTreeSet<Extension>>>,
 }
@@ -247,12 +250,37 @@ pub struct DeviceData {
     owner_physical_device: Weak<PhysicalDeviceData>,
     api_version: ApiVersion,
     pub enabled_extensions: BTreeSet<Extension>,
+    // Keyed by the queue family index and the queue index.
+    queues: BTreeMap<(u32, u32), (vk::DeviceQueueCreateFlags, Del<vk::Queue>)>,
+    // The command buffers allocated from each VkCommandPool.
+    command_pools: Mutex<BTreeMap<vk::CommandPool, Vec<Del<vk::CommandBuffer>>>>,
+    next_command_pool: AtomicU64,
 }
 
 impl ToVulkanHandle for DeviceData {
     type Handle = vk::Device;
 }
 
+#[repr(C)]
+struct QueueData {
+    // Should be the same as the owner VkDevice.
+    base: DispatchableObjectBase<DeviceDispatchTable>,
+}
+
+impl ToVulkanHandle for QueueData {
+    type Handle = vk::Queue;
+}
+
+#[repr(C)]
+struct CommandBufferData {
+    // Should be the same as the owner VkDevice.
+    base: DispatchableObjectBase<DeviceDispatchTable>,
+}
+
+impl ToVulkanHandle for CommandBufferData {
+    type Handle = vk::CommandBuffer;
+}
+
 static VULKAN_COMMANDS: Lazy<BTreeMap<VulkanCommandName, VulkanCommand>> = Lazy::new(|| {
     use LayerVulkanCommand::*;
     let commands = [
@@ -422,13 +450,48 @@ static VULKAN_COMMANDS: Lazy<BTreeMap<VulkanCommandName, VulkanCommand>> = Lazy:
                                 return vk::Result::ERROR_EXTENSION_NOT_PRESENT;
                             }
                         }
+                        let queue_create_infos = if device_create_info.queue_create_info_count == 0
+                        {
+                            &[]
+                        } else {
+                            unsafe {
+                                std::slice::from_raw_parts(
+                                    device_create_info.p_queue_create_infos,
+                                    device_create_info
+                                        .queue_create_info_count
+                                        .try_into()
+                                        .unwrap(),
+                                )
+                            }
+                        };
+                        let base = DispatchableObjectBase::<DeviceDispatchTable>::default();
+                        let queues = queue_create_infos
+                            .iter()
+                            .flat_map(|queue_create_info| {
+                                (0..queue_create_info.queue_count)
+                                    .map(|queue_index| {
+                                        (queue_create_info.queue_family_index, queue_index)
+                                    })
+                                    .zip(std::iter::repeat(queue_create_info.flags))
+                            })
+                            .map(|(key, flags)| {
+                                let queue = Del::new(
+                                    Arc::new(QueueData { base: base.clone() }).into_vulkan_handle(),
+                                    |handle| unsafe { QueueData::destroy(*handle) },
+                                );
+                                (key, (flags, queue))
+                            })
+                            .collect();
                         let device_data = Arc::new(DeviceData {
-                            base: Default::default(),
+                            base,
                             owner_physical_device: Arc::downgrade(&physical_device),
                             api_version: instance_data
                                 .version
                                 .min(*instance_data.supported_device_version.lock().unwrap()),
                             enabled_extensions,
+                            queues,
+                            command_pools: Default::default(),
+                            next_command_pool: AtomicU64::new(1),
                         });
                         *unsafe { device.as_mut() }.unwrap() = device_data.into_vulkan_handle();
                         vk::Result::SUCCESS
@@ -443,6 +506,364 @@ static VULKAN_COMMANDS: Lazy<BTreeMap<VulkanCommandName, VulkanCommand>> = Lazy:
                 features: [ApiVersion::V1_0.into()].into(),
             },
         ),
+        (
+            "vkGetDeviceQueue".into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn get_device_queue(
+                        device: vk::Device,
+                        queue_family_index: u32,
+                        queue_index: u32,
+                        p_queue: *mut vk::Queue,
+                    ) {
+                        let device_data = unsafe { DeviceData::from_handle(device) };
+                        let (flags, queue) = device_data
+                            .queues
+                            .get(&(queue_family_index, queue_index))
+                            .expect("The queue must be created with the device.");
+                        assert!(flags.is_empty());
+                        *unsafe { p_queue.as_mut() }.unwrap() = **queue;
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkGetDeviceQueue, vk::PFN_vkVoidFunction>(
+                            get_device_queue,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            "vkGetDeviceQueue2".into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn get_device_queue2(
+                        device: vk::Device,
+                        p_queue_info: *const vk::DeviceQueueInfo2,
+                        p_queue: *mut vk::Queue,
+                    ) {
+                        let device_data = unsafe { DeviceData::from_handle(device) };
+                        let queue_info = unsafe { p_queue_info.as_ref() }.unwrap();
+                        let queue = device_data
+                            .queues
+                            .get(&(queue_info.queue_family_index, queue_info.queue_index))
+                            .filter(|(flags, _)| *flags == queue_info.flags)
+                            .map(|(_, queue)| **queue)
+                            .unwrap_or_default();
+                        *unsafe { p_queue.as_mut() }.unwrap() = queue;
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkGetDeviceQueue2, vk::PFN_vkVoidFunction>(
+                            get_device_queue2,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_1.into()].into(),
+            },
+        ),
+        (
+            CreateCommandPool.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn create_command_pool(
+                        device: vk::Device,
+                        _: *const vk::CommandPoolCreateInfo,
+                        _: *const vk::AllocationCallbacks,
+                        p_command_pool: *mut vk::CommandPool,
+                    ) -> vk::Result {
+                        let device_data = unsafe { DeviceData::from_handle(device) };
+                        let command_pool = vk::CommandPool::from_raw(
+                            device_data
+                                .next_command_pool
+                                .fetch_add(1, Ordering::Relaxed),
+                        );
+                        device_data
+                            .command_pools
+                            .lock()
+                            .unwrap()
+                            .insert(command_pool, vec![]);
+                        *unsafe { p_command_pool.as_mut() }.unwrap() = command_pool;
+                        vk::Result::SUCCESS
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkCreateCommandPool, vk::PFN_vkVoidFunction>(
+                            create_command_pool,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            DestroyCommandPool.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn destroy_command_pool(
+                        device: vk::Device,
+                        command_pool: vk::CommandPool,
+                        _: *const vk::AllocationCallbacks,
+                    ) {
+                        if command_pool == vk::CommandPool::null() {
+                            return;
+                        }
+                        let device_data = unsafe { DeviceData::from_handle(device) };
+                        // Also frees all command buffers allocated from the pool.
+                        device_data
+                            .command_pools
+                            .lock()
+                            .unwrap()
+                            .remove(&command_pool)
+                            .expect("The command pool must be created from the device.");
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkDestroyCommandPool, vk::PFN_vkVoidFunction>(
+                            destroy_command_pool,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            ResetCommandPool.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn reset_command_pool(
+                        device: vk::Device,
+                        command_pool: vk::CommandPool,
+                        _: vk::CommandPoolResetFlags,
+                    ) -> vk::Result {
+                        let device_data = unsafe { DeviceData::from_handle(device) };
+                        assert!(device_data
+                            .command_pools
+                            .lock()
+                            .unwrap()
+                            .contains_key(&command_pool));
+                        vk::Result::SUCCESS
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkResetCommandPool, vk::PFN_vkVoidFunction>(
+                            reset_command_pool,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            AllocateCommandBuffers.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn allocate_command_buffers(
+                        device: vk::Device,
+                        p_allocate_info: *const vk::CommandBufferAllocateInfo,
+                        p_command_buffers: *mut vk::CommandBuffer,
+                    ) -> vk::Result {
+                        let device_data = unsafe { DeviceData::from_handle(device) };
+                        let allocate_info = unsafe { p_allocate_info.as_ref() }.unwrap();
+                        let mut command_pools = device_data.command_pools.lock().unwrap();
+                        let command_buffers = command_pools
+                            .get_mut(&allocate_info.command_pool)
+                            .expect("The command pool must be created from the device.");
+                        for i in 0..allocate_info.command_buffer_count {
+                            let command_buffer = Del::new(
+                                Arc::new(CommandBufferData {
+                                    base: device_data.base.clone(),
+                                })
+                                .into_vulkan_handle(),
+                                |handle| unsafe { CommandBufferData::destroy(*handle) },
+                            );
+                            *unsafe { p_command_buffers.add(i.try_into().unwrap()).as_mut() }
+                                .unwrap() = *command_buffer;
+                            command_buffers.push(command_buffer);
+                        }
+                        vk::Result::SUCCESS
+                    }
+                    unsafe {
+                        std::mem::transmute::<
+                            vk::PFN_vkAllocateCommandBuffers,
+                            vk::PFN_vkVoidFunction,
+                        >(allocate_command_buffers)
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            FreeCommandBuffers.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn free_command_buffers(
+                        device: vk::Device,
+                        command_pool: vk::CommandPool,
+                        command_buffer_count: u32,
+                        p_command_buffers: *const vk::CommandBuffer,
+                    ) {
+                        let device_data = unsafe { DeviceData::from_handle(device) };
+                        let to_free = unsafe {
+                            std::slice::from_raw_parts(
+                                p_command_buffers,
+                                command_buffer_count.try_into().unwrap(),
+                            )
+                        };
+                        let mut command_pools = device_data.command_pools.lock().unwrap();
+                        let command_buffers = command_pools
+                            .get_mut(&command_pool)
+                            .expect("The command pool must be created from the device.");
+                        command_buffers.retain(|command_buffer| !to_free.contains(command_buffer));
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkFreeCommandBuffers, vk::PFN_vkVoidFunction>(
+                            free_command_buffers,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            BeginCommandBuffer.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn begin_command_buffer(
+                        _: vk::CommandBuffer,
+                        _: *const vk::CommandBufferBeginInfo,
+                    ) -> vk::Result {
+                        vk::Result::SUCCESS
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkBeginCommandBuffer, vk::PFN_vkVoidFunction>(
+                            begin_command_buffer,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            ResetCommandBuffer.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn reset_command_buffer(
+                        _: vk::CommandBuffer,
+                        _: vk::CommandBufferResetFlags,
+                    ) -> vk::Result {
+                        vk::Result::SUCCESS
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkResetCommandBuffer, vk::PFN_vkVoidFunction>(
+                            reset_command_buffer,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            CmdBindPipeline.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn cmd_bind_pipeline(
+                        _: vk::CommandBuffer,
+                        _: vk::PipelineBindPoint,
+                        _: vk::Pipeline,
+                    ) {
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkCmdBindPipeline, vk::PFN_vkVoidFunction>(
+                            cmd_bind_pipeline,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            CmdSetViewport.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn cmd_set_viewport(
+                        _: vk::CommandBuffer,
+                        _: u32,
+                        _: u32,
+                        _: *const vk::Viewport,
+                    ) {
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkCmdSetViewport, vk::PFN_vkVoidFunction>(
+                            cmd_set_viewport,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            CmdBeginRenderPass.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn cmd_begin_render_pass(
+                        _: vk::CommandBuffer,
+                        _: *const vk::RenderPassBeginInfo,
+                        _: vk::SubpassContents,
+                    ) {
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkCmdBeginRenderPass, vk::PFN_vkVoidFunction>(
+                            cmd_begin_render_pass,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            CmdEndRenderPass.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn cmd_end_render_pass(_: vk::CommandBuffer) {}
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkCmdEndRenderPass, vk::PFN_vkVoidFunction>(
+                            cmd_end_render_pass,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
+        (
+            CmdExecuteCommands.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn cmd_execute_commands(
+                        _: vk::CommandBuffer,
+                        _: u32,
+                        _: *const vk::CommandBuffer,
+                    ) {
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkCmdExecuteCommands, vk::PFN_vkVoidFunction>(
+                            cmd_execute_commands,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [ApiVersion::V1_0.into()].into(),
+            },
+        ),
         (
             "vkEnumeratePhysicalDeviceGroups".into(),
             VulkanCommand {
@@ -752,6 +1173,31 @@ static VULKAN_COMMANDS: Lazy<BTreeMap<VulkanCommandName, VulkanCommand>> = Lazy:
                 features: [ApiVersion::V1_0.into()].into(),
             },
         ),
+        (
+            AcquireNextImageKhr.into(),
+            VulkanCommand {
+                proc: {
+                    extern "system" fn acquire_next_image_khr(
+                        _: vk::Device,
+                        _: vk::SwapchainKHR,
+                        _: u64,
+                        _: vk::Semaphore,
+                        _: vk::Fence,
+                        image_index: *mut u32,
+                    ) -> vk::Result {
+                        *unsafe { image_index.as_mut() }.unwrap() = 2;
+                        vk::Result::SUBOPTIMAL_KHR
+                    }
+                    unsafe {
+                        std::mem::transmute::<vk::PFN_vkAcquireNextImageKHR, vk::PFN_vkVoidFunction>(
+                            acquire_next_image_khr,
+                        )
+                    }
+                },
+                dispatch_kind: DispatchKind::Device,
+                features: [Extension::KHRSwapchain.into()].into(),
+            },
+        ),
         (
             DestroySwapchainKhr.into(),
             VulkanCommand {
@@ -1091,7 +1537,7 @@ impl<T: Layer> Layers for (T,) {
         VkLayerInstanceLink {
             pNext: null_mut(),
             pfnNextGetInstanceProcAddr: Global::<T>::get_instance_proc_addr,
-            pfnNextGetPhysicalDeviceProcAddr: None,
+            pfnNextGetPhysicalDeviceProcAddr: Some(Global::<T>::get_physical_device_proc_addr),
         }
     }
 }